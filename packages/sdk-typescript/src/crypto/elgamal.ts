@@ -186,14 +186,28 @@ export function deriveKeypair(seed: Uint8Array): ElGamalKeypair {
 export function encrypt(
   publicKey: Uint8Array,
   value: bigint
+): { ciphertext: ElGamalCiphertext; randomness: Uint8Array } {
+  return encryptWithRandomness(publicKey, value, randomBytes(32))
+}
+
+/**
+ * Encrypt a value using twisted ElGamal with caller-supplied randomness
+ *
+ * Deterministic counterpart of `encrypt`, used for cross-SDK test vectors.
+ * Never reuse randomness for real ciphertexts.
+ */
+export function encryptWithRandomness(
+  publicKey: Uint8Array,
+  value: bigint,
+  randomness: Uint8Array
 ): { ciphertext: ElGamalCiphertext; randomness: Uint8Array } {
   if (value < BigInt(0) || value >= BigInt(2) ** BigInt(64)) {
     throw new Error('Value must be between 0 and 2^64 - 1')
   }
-  
-  // Generate random scalar
-  const randomness = randomBytes(32)
-  
+  if (randomness.length !== 32) {
+    throw new Error('Randomness must be 32 bytes')
+  }
+
   // Parse public key - handle both hex string and Uint8Array
   const pubkeyPoint = pointFromBytes(publicKey)
   
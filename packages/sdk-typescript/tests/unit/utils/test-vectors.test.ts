@@ -0,0 +1,235 @@
+/**
+ * Cross-SDK Test Vector Tests
+ *
+ * Checks the SDK against the canonical vectors in test-vectors/vectors.json,
+ * generated by `cargo xtask gen-vectors`. The Rust tests in programs/tests run
+ * the same vectors, so a failure here means the SDK has drifted from the program.
+ */
+
+import { describe, it, expect } from 'vitest'
+import { readFileSync } from 'node:fs'
+import {
+  address,
+  getAddressDecoder,
+  getAddressEncoder,
+  getProgramDerivedAddress
+} from '@solana/kit'
+import { sha256 } from '@noble/hashes/sha256'
+import { keccak_256 } from '@noble/hashes/sha3'
+import { deriveKeypair, encryptWithRandomness } from '../../../src/crypto/elgamal'
+import { deriveAgentPda } from '../../../src/utils/pda'
+import { deriveDidDocumentPda } from '../../../src/modules/did/did-helpers'
+
+interface PdaVector {
+  name: string
+  seeds: string[]
+  address: string
+  bump: number
+}
+
+interface NamedEncoding {
+  name: string
+  fields: Record<string, string | number>
+  data: string
+}
+
+const vectors = JSON.parse(
+  readFileSync(new URL('../../../../../test-vectors/vectors.json', import.meta.url), 'utf8')
+) as {
+  version: number
+  program_id: string
+  pdas: PdaVector[]
+  discriminators: { kind: 'account' | 'event' | 'instruction'; name: string; discriminator: string }[]
+  accounts: NamedEncoding[]
+  events: NamedEncoding[]
+  credential_hashes: {
+    credential_id: string
+    subject: string
+    subject_data_hash: string
+    issued_at: number
+    message: string
+    message_hash: string
+  }[]
+  elgamal: {
+    seed: string
+    secret_key: string
+    public_key: string
+    value: string
+    randomness: string
+    commitment: string
+    handle: string
+  }[]
+}
+
+const programId = address(vectors.program_id)
+const utf8 = new TextEncoder()
+
+function unhex(hex: string): Uint8Array {
+  return new Uint8Array(hex.match(/../g)?.map(b => parseInt(b, 16)) ?? [])
+}
+
+function toHex(bytes: Uint8Array): string {
+  return Array.from(bytes, b => b.toString(16).padStart(2, '0')).join('')
+}
+
+function concat(...parts: Uint8Array[]): Uint8Array {
+  const out = new Uint8Array(parts.reduce((n, p) => n + p.length, 0))
+  let offset = 0
+  for (const part of parts) {
+    out.set(part, offset)
+    offset += part.length
+  }
+  return out
+}
+
+function u64(value: number | bigint): Uint8Array {
+  const out = new Uint8Array(8)
+  new DataView(out.buffer).setBigInt64(0, BigInt(value), true)
+  return out
+}
+
+function discriminator(namespace: string, name: string): Uint8Array {
+  return sha256(utf8.encode(`${namespace}:${name}`)).slice(0, 8)
+}
+
+function pubkey(value: string | number): Uint8Array {
+  return new Uint8Array(getAddressEncoder().encode(address(String(value))))
+}
+
+function borshString(value: string | number): Uint8Array {
+  const bytes = utf8.encode(String(value))
+  const len = new Uint8Array(4)
+  new DataView(len.buffer).setUint32(0, bytes.length, true)
+  return concat(len, bytes)
+}
+
+function find(list: NamedEncoding[], name: string): NamedEncoding {
+  const entry = list.find(v => v.name === name)
+  if (!entry) throw new Error(`missing vector: ${name}`)
+  return entry
+}
+
+describe('Cross-SDK test vectors', () => {
+  it('uses a supported vectors version', () => {
+    expect(vectors.version).toBe(1)
+  })
+
+  describe('PDA derivations', () => {
+    it.each(vectors.pdas.map(p => [p.name, p] as const))('derives %s', async (_name, pda) => {
+      const [derived, bump] = await getProgramDerivedAddress({
+        programAddress: programId,
+        seeds: pda.seeds.map(unhex)
+      })
+      expect(derived).toBe(pda.address)
+      expect(bump).toBe(pda.bump)
+    })
+
+    it('matches the SDK agent and DID helpers', async () => {
+      const agent = vectors.pdas.find(p => p.name === 'agent')!
+      const owner = getAddressDecoder().decode(unhex(agent.seeds[1]))
+      const agentId = new TextDecoder().decode(unhex(agent.seeds[2]))
+
+      const [agentPda, agentBump] = await deriveAgentPda({ owner, agentId, programAddress: programId })
+      expect(agentPda).toBe(agent.address)
+      expect(agentBump).toBe(agent.bump)
+
+      const did = vectors.pdas.find(p => p.name === 'did_document')!
+      const [didPda, didBump] = await deriveDidDocumentPda(programId, owner)
+      expect(didPda).toBe(did.address)
+      expect(didBump).toBe(did.bump)
+    })
+  })
+
+  describe('Discriminators', () => {
+    const namespaces = { account: 'account', event: 'event', instruction: 'global' } as const
+
+    it.each(vectors.discriminators.map(d => [`${d.kind} ${d.name}`, d] as const))(
+      'matches %s',
+      (_label, entry) => {
+        expect(toHex(discriminator(namespaces[entry.kind], entry.name))).toBe(entry.discriminator)
+      }
+    )
+  })
+
+  describe('Account serializations', () => {
+    it('encodes ConfigHistoryEntry', () => {
+      const { fields: f, data } = find(vectors.accounts, 'ConfigHistoryEntry')
+      const encoded = concat(
+        discriminator('account', 'ConfigHistoryEntry'),
+        u64(Number(f.index)),
+        new Uint8Array([Number(f.kind)]),
+        pubkey(f.config_account),
+        unhex(String(f.old_value_hash)),
+        unhex(String(f.new_value_hash)),
+        pubkey(f.proposer),
+        u64(Number(f.slot)),
+        u64(Number(f.timestamp)),
+        pubkey(f.prev_entry),
+        new Uint8Array([Number(f.bump)])
+      )
+      expect(toHex(encoded)).toBe(data)
+    })
+  })
+
+  describe('Event encodings', () => {
+    it('encodes AgentRegisteredEvent', () => {
+      const { fields: f, data } = find(vectors.events, 'AgentRegisteredEvent')
+      const encoded = concat(
+        discriminator('event', 'AgentRegisteredEvent'),
+        pubkey(f.agent),
+        pubkey(f.owner),
+        borshString(f.name),
+        u64(Number(f.timestamp))
+      )
+      expect(toHex(encoded)).toBe(data)
+    })
+
+    it('encodes SourceReputationUpdateFailedEvent', () => {
+      const { fields: f, data } = find(vectors.events, 'SourceReputationUpdateFailedEvent')
+      const encoded = concat(
+        discriminator('event', 'SourceReputationUpdateFailedEvent'),
+        pubkey(f.agent),
+        borshString(f.source_name),
+        new Uint8Array([Number(f.index)]),
+        u64(Number(f.error_code)),
+        u64(Number(f.timestamp))
+      )
+      expect(toHex(encoded)).toBe(data)
+    })
+  })
+
+  describe('Credential hashes', () => {
+    it.each(vectors.credential_hashes.map(c => [c.credential_id, c] as const))(
+      'hashes %s',
+      (_id, c) => {
+        const message = concat(
+          utf8.encode(c.credential_id),
+          pubkey(c.subject),
+          unhex(c.subject_data_hash),
+          u64(c.issued_at)
+        )
+        expect(toHex(message)).toBe(c.message)
+        expect(toHex(keccak_256(message))).toBe(c.message_hash)
+      }
+    )
+  })
+
+  describe('ElGamal ciphertexts', () => {
+    it.each(vectors.elgamal.map(e => [e.value, e] as const))(
+      'encrypts %s with fixed randomness',
+      (_value, e) => {
+        const keypair = deriveKeypair(unhex(e.seed))
+        expect(toHex(keypair.secretKey)).toBe(e.secret_key)
+        expect(toHex(keypair.publicKey)).toBe(e.public_key)
+
+        const { ciphertext } = encryptWithRandomness(
+          keypair.publicKey,
+          BigInt(e.value),
+          unhex(e.randomness)
+        )
+        expect(toHex(ciphertext.commitment.commitment)).toBe(e.commitment)
+        expect(toHex(ciphertext.handle.handle)).toBe(e.handle)
+      }
+    )
+  })
+})
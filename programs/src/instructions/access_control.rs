@@ -1,216 +1,103 @@
 /*!
  * Access Control Instructions
  *
- * Tier-based access control guards for staking tiers.
- * Enforces access restrictions based on GHOST token stake amounts.
+ * Tier-based access control for GHOST stakers. Handlers gate features on
+ * the governed per-action minimums through `ProtocolConfig::require_tier`;
+ * `get_access_tier` reports a staker's tier, quota and distance to the
+ * next tier, and `consume_api_quota` meters the daily API quota each tier
+ * carries.
  */
 
 use anchor_lang::prelude::*;
-use crate::state::staking::{StakingAccount, AccessTier};
-use crate::GhostSpeakError;
-
-// =====================================================
-// ACCESS CONTROL GUARDS
-// =====================================================
-
-/// Require Verified tier or higher (5,000+ GHOST staked)
-/// Use this to gate unlimited verification features
-pub fn require_verified_tier(staking_account: &Account<StakingAccount>) -> Result<()> {
-    require!(
-        staking_account.has_unlimited_verifications(),
-        GhostSpeakError::InsufficientReputation
-    );
-
-    msg!(
-        "Access granted: Verified tier (tier: {:?}, stake: {})",
-        staking_account.tier,
-        staking_account.amount_staked
-    );
-
-    Ok(())
-}
-
-/// Require Pro tier or higher (50,000+ GHOST staked)
-/// Use this to gate API access features
-pub fn require_pro_tier(staking_account: &Account<StakingAccount>) -> Result<()> {
-    require!(
-        staking_account.has_api_access(),
-        GhostSpeakError::InsufficientReputation
-    );
-
-    msg!(
-        "Access granted: Pro tier (tier: {:?}, stake: {}, API limit: {:?})",
-        staking_account.tier,
-        staking_account.amount_staked,
-        staking_account.get_api_request_limit()
-    );
-
-    Ok(())
-}
-
-/// Require Whale tier (500,000+ GHOST staked)
-/// Use this to gate unlimited API access features
-pub fn require_whale_tier(staking_account: &Account<StakingAccount>) -> Result<()> {
-    require!(
-        staking_account.has_unlimited_api(),
-        GhostSpeakError::InsufficientReputation
-    );
-
-    msg!(
-        "Access granted: Whale tier (tier: {:?}, stake: {}, unlimited API)",
-        staking_account.tier,
-        staking_account.amount_staked
-    );
-
-    Ok(())
-}
-
-/// Get revenue share multiplier for a staking account
-/// Returns the multiplier as a float (1.0, 1.5, 2.0, 3.0)
-pub fn get_revenue_multiplier(staking_account: &Account<StakingAccount>) -> f64 {
-    staking_account.get_revenue_multiplier_f64()
-}
-
-/// Get access tier for a staking account
-pub fn get_access_tier(staking_account: &Account<StakingAccount>) -> AccessTier {
-    staking_account.tier
-}
 
-/// Check if account has unlimited verifications without requiring
-/// Returns true for Verified, Pro, or Whale tiers
-pub fn check_verification_access(staking_account: &Account<StakingAccount>) -> bool {
-    staking_account.has_unlimited_verifications()
-}
-
-/// Check if account has API access without requiring
-/// Returns true for Pro or Whale tiers
-pub fn check_api_access(staking_account: &Account<StakingAccount>) -> bool {
-    staking_account.has_api_access()
-}
-
-/// Get API request limit for an account
-/// Returns Some(limit) for Pro tier, None for Whale (unlimited), Some(0) for others
-pub fn get_api_request_limit(staking_account: &Account<StakingAccount>) -> Option<u64> {
-    staking_account.get_api_request_limit()
-}
-
-// =====================================================
-// HELPER FUNCTIONS FOR TIER VALIDATION
-// =====================================================
-
-/// Calculate what tier a given stake amount would qualify for
-/// Useful for preview/estimation on frontend
-pub fn calculate_tier_for_amount(amount: u64) -> AccessTier {
-    if amount >= 500_000_000_000_000 {
-        AccessTier::Whale
-    } else if amount >= 50_000_000_000_000 {
-        AccessTier::Pro
-    } else if amount >= 5_000_000_000_000 {
-        AccessTier::Verified
-    } else if amount >= 1_000_000_000_000 {
-        AccessTier::Basic
-    } else {
-        AccessTier::None
-    }
-}
-
-/// Get revenue multiplier for a given tier
-pub fn get_multiplier_for_tier(tier: AccessTier) -> u16 {
-    match tier {
-        AccessTier::None => 0,
-        AccessTier::Basic => 100,    // 1.0x
-        AccessTier::Verified => 150, // 1.5x
-        AccessTier::Pro => 200,      // 2.0x
-        AccessTier::Whale => 300,    // 3.0x
-    }
-}
-
-/// Get minimum stake amount required for a tier
-pub fn get_min_stake_for_tier(tier: AccessTier) -> u64 {
-    match tier {
-        AccessTier::None => 0,
-        AccessTier::Basic => 1_000_000_000_000,     // 1,000 GHOST (9 decimals)
-        AccessTier::Verified => 5_000_000_000_000,  // 5,000 GHOST
-        AccessTier::Pro => 50_000_000_000_000,      // 50,000 GHOST
-        AccessTier::Whale => 500_000_000_000_000,   // 500,000 GHOST
-    }
-}
+use crate::state::staking::{
+    AccessTier, AccessTierStatus, ApiQuotaConsumedEvent, StakingAccount, StakingConfig,
+};
+use crate::GhostSpeakError;
 
 // =====================================================
-// EXAMPLE USAGE IN INSTRUCTIONS
+// GET ACCESS TIER
 // =====================================================
 
-/*
-EXAMPLE: Gating unlimited verifications
-
+/// Read a staker's tier and quota (read-only)
 #[derive(Accounts)]
-pub struct VerifyAgent<'info> {
+pub struct GetAccessTier<'info> {
     #[account(
-        seeds = [b"staking", agent.key().as_ref()],
+        seeds = [b"staking", staking_account.owner.as_ref()],
         bump = staking_account.bump
     )]
     pub staking_account: Account<'info, StakingAccount>,
 
-    pub agent: Account<'info, Agent>,
-    // ... other accounts
+    /// Source of the tier thresholds
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+}
+
+pub fn get_access_tier(ctx: Context<GetAccessTier>) -> Result<AccessTierStatus> {
+    let schedule = &ctx.accounts.staking_config.tier_schedule;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Report the tier the current thresholds give, even if the stored one lags
+    let mut staking = (*ctx.accounts.staking_account).clone();
+    let tier = staking.current_tier(schedule);
+    staking.tier = tier;
+    let next_tier = tier.next();
+    let stake_to_next_tier = next_tier.map_or(0, |tier| {
+        schedule.tier_thresholds[tier.index()].saturating_sub(staking.amount_staked)
+    });
+
+    Ok(AccessTierStatus {
+        owner: staking.owner,
+        tier,
+        amount_staked: staking.amount_staked,
+        daily_api_limit: staking.get_api_request_limit(),
+        api_calls_available: staking.api_calls_available(now),
+        has_unlimited_verifications: staking.has_unlimited_verifications(),
+        revenue_multiplier_bps: tier.revenue_multiplier_bps(),
+        next_tier,
+        stake_to_next_tier,
+    })
 }
 
-pub fn verify_agent(ctx: Context<VerifyAgent>) -> Result<()> {
-    // Require Verified tier or higher for unlimited verifications
-    require_verified_tier(&ctx.accounts.staking_account)?;
-
-    // Proceed with verification logic...
-    Ok(())
-}
-
----
-
-EXAMPLE: Gating API access
+// =====================================================
+// CONSUME API QUOTA
+// =====================================================
 
+/// Meter API calls against the staker's daily quota (staker only)
 #[derive(Accounts)]
-pub struct CallApi<'info> {
+pub struct ConsumeApiQuota<'info> {
     #[account(
-        seeds = [b"staking", agent.key().as_ref()],
+        mut,
+        seeds = [b"staking", owner.key().as_ref()],
         bump = staking_account.bump
     )]
     pub staking_account: Account<'info, StakingAccount>,
 
-    pub agent: Account<'info, Agent>,
-    // ... other accounts
-}
-
-pub fn call_api(ctx: Context<CallApi>) -> Result<()> {
-    // Require Pro tier or higher for API access
-    require_pro_tier(&ctx.accounts.staking_account)?;
-
-    // Check if they have unlimited or need to track usage
-    let api_limit = get_api_request_limit(&ctx.accounts.staking_account);
-    match api_limit {
-        None => msg!("Unlimited API access"),
-        Some(limit) => msg!("API limit: {} requests/month", limit),
-    }
-
-    // Proceed with API call logic...
-    Ok(())
+    pub owner: Signer<'info>,
 }
 
----
+pub fn consume_api_quota(ctx: Context<ConsumeApiQuota>, calls: u32) -> Result<()> {
+    require!(calls > 0, GhostSpeakError::InvalidAmount);
 
-EXAMPLE: Calculating revenue share
-
-pub fn distribute_revenue(ctx: Context<DistributeRevenue>) -> Result<()> {
-    let staking_account = &ctx.accounts.staking_account;
-
-    // Get user's revenue multiplier
-    let multiplier = get_revenue_multiplier(staking_account);
+    let staking = &mut ctx.accounts.staking_account;
+    require!(
+        staking.tier.meets(AccessTier::Basic),
+        GhostSpeakError::InsufficientStakingTier
+    );
 
-    // Calculate weighted stake
-    let weighted_stake = staking_account.amount_staked as f64 * multiplier;
+    let now = Clock::get()?.unix_timestamp;
+    staking.consume_api_calls(calls, now)?;
 
-    // Calculate user's share of revenue pool
-    let user_share = (weighted_stake / total_weighted_stake) * revenue_pool_amount;
+    let calls_remaining = staking
+        .get_api_request_limit()
+        .map(|_| staking.api_calls_remaining);
+    emit!(ApiQuotaConsumedEvent {
+        owner: staking.owner,
+        tier: staking.tier,
+        calls,
+        calls_remaining,
+        timestamp: now,
+    });
 
-    // Transfer USDC rewards to user...
     Ok(())
 }
-*/
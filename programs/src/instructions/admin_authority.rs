@@ -0,0 +1,185 @@
+/*!
+ * Admin Authority Instructions
+ *
+ * Creates the on-chain protocol admin at deploy and lets the Protocol
+ * multisig replace it behind the multisig's timelock.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::program::GhostspeakMarketplace;
+use crate::security::admin_validation::require_valid_admin;
+use crate::state::admin_authority::*;
+use crate::state::governance::{Multisig, MultisigType};
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE ADMIN AUTHORITY
+// =====================================================
+
+/// Create the admin authority (program upgrade authority only, once)
+#[derive(Accounts)]
+pub struct InitializeAdminAuthority<'info> {
+    #[account(
+        init,
+        payer = upgrade_authority,
+        space = AdminAuthority::LEN,
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    /// Protocol multisig that will govern admin changes
+    pub governance_multisig: Box<Account<'info, Multisig>>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ GhostSpeakError::InvalidInput)]
+    pub program: Program<'info, GhostspeakMarketplace>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_admin_authority(
+    ctx: Context<InitializeAdminAuthority>,
+    admin: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.governance_multisig.multisig_type == MultisigType::Protocol,
+        GhostSpeakError::InvalidAdminMultisig
+    );
+    require_valid_admin(&admin)?;
+
+    let authority = &mut ctx.accounts.admin_authority;
+    authority.admin = admin;
+    authority.multisig = ctx.accounts.governance_multisig.key();
+    authority.pending_admin = Pubkey::default();
+    authority.executable_at = 0;
+    authority.updated_at = Clock::get()?.unix_timestamp;
+    authority.bump = ctx.bumps.admin_authority;
+
+    emit!(AdminAuthorityInitializedEvent {
+        admin,
+        multisig: authority.multisig,
+        upgrade_authority: ctx.accounts.upgrade_authority.key(),
+    });
+
+    msg!(
+        "Admin authority initialized (admin: {}, multisig: {})",
+        admin,
+        authority.multisig
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// ADMIN TRANSFER
+// =====================================================
+
+/// Queue or cancel an admin transfer (governance multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct GovernAdminAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.multisig == governance_multisig.key() @ GhostSpeakError::InvalidAdminMultisig
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    pub governance_multisig: Box<Account<'info, Multisig>>,
+}
+
+/// Queues `new_admin`, who takes over once the multisig's timelock has
+/// passed. A later proposal replaces a pending one and restarts the clock.
+pub fn propose_admin_transfer(ctx: Context<GovernAdminAuthority>, new_admin: Pubkey) -> Result<()> {
+    let multisig = &ctx.accounts.governance_multisig;
+    multisig.require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let authority = &mut ctx.accounts.admin_authority;
+    authority.propose_transfer(new_admin, multisig.execution_timelock(), now)?;
+
+    emit!(AdminTransferProposedEvent {
+        multisig: authority.multisig,
+        current_admin: authority.admin,
+        pending_admin: new_admin,
+        executable_at: authority.executable_at,
+    });
+
+    msg!(
+        "Admin transfer to {} proposed, executable at {}",
+        new_admin,
+        authority.executable_at
+    );
+
+    Ok(())
+}
+
+pub fn cancel_admin_transfer(ctx: Context<GovernAdminAuthority>) -> Result<()> {
+    ctx.accounts
+        .governance_multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let authority = &mut ctx.accounts.admin_authority;
+    let cancelled_admin = authority.cancel_transfer(now)?;
+
+    emit!(AdminTransferCancelledEvent {
+        multisig: authority.multisig,
+        cancelled_admin,
+        timestamp: now,
+    });
+
+    msg!("Admin transfer to {} cancelled", cancelled_admin);
+
+    Ok(())
+}
+
+/// Install a queued admin once its timelock has passed (permissionless,
+/// unless the governing multisig is frozen)
+#[derive(Accounts)]
+pub struct ApplyAdminTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.multisig == governance_multisig.key() @ GhostSpeakError::InvalidAdminMultisig
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    pub governance_multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn apply_admin_transfer(ctx: Context<ApplyAdminTransfer>) -> Result<()> {
+    // A freeze also holds back transfers queued before it
+    ctx.accounts.governance_multisig.require_not_frozen()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let authority = &mut ctx.accounts.admin_authority;
+    let previous_admin = authority.apply_transfer(now)?;
+
+    emit!(AdminTransferredEvent {
+        previous_admin,
+        new_admin: authority.admin,
+        timestamp: now,
+    });
+
+    msg!(
+        "Protocol admin changed from {} to {}",
+        previous_admin,
+        authority.admin
+    );
+
+    Ok(())
+}
@@ -6,7 +6,18 @@
  * comprehensive input sanitization following 2025 Solana best practices.
  */
 
+use crate::instructions::fee_token::{collect_protocol_fee, FeeTokenPayment};
+use crate::instructions::referral::record_referral;
+use crate::state::fee_exemption::{is_fee_exempt, FeeWaivedEvent, FEE_EXEMPTION_LIST_SEED};
+use crate::state::fee_token::{FeePaymentToken, FEE_PAYMENT_TOKEN_SEED};
+use crate::state::fee_vault::{FeeSource, FeeVault, FEE_VAULT_SEED};
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::referral::{ReferralKind, ReferrerStats};
+use crate::state::staking::{AccessTier, StakingConfig};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::agent::AgentV0;
 use crate::state::AgentVerificationData;
+use crate::utils::{read_legacy_layout, write_current_layout};
 use crate::GhostSpeakError;
 use crate::*;
 // Enhanced optimization utilities with 2025 performance patterns
@@ -78,6 +89,64 @@ pub struct RegisterAgent<'info> {
 
     /// Clock sysvar for timestamp validation
     pub clock: Sysvar<'info, Clock>,
+
+    /// Stats of the referrer named at registration (required with a referrer)
+    #[account(mut)]
+    pub referrer_stats: Option<Box<Account<'info, ReferrerStats>>>,
+
+    /// Protocol config (registration fee)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Staking config, whose current thresholds set the owner's fee discount
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// CHECK: Fee exemption list; uninitialized if never created
+    #[account(seeds = [FEE_EXEMPTION_LIST_SEED], bump)]
+    pub fee_exemption_list: UncheckedAccount<'info>,
+
+    /// Fee vault of the token the registration fee is paid in (the SOL
+    /// vault unless `fee_payment_token` is passed); required when a fee is due
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, fee_vault.mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    /// Accepted token to pay the registration fee in instead of SOL; needs
+    /// every account below
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, fee_payment_token.mint.as_ref()],
+        bump = fee_payment_token.bump
+    )]
+    pub fee_payment_token: Option<Box<Account<'info, FeePaymentToken>>>,
+
+    /// SOL entry carrying the SOL/USD feed
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, anchor_lang::system_program::ID.as_ref()],
+        bump = sol_payment_token.bump
+    )]
+    pub sol_payment_token: Option<Box<Account<'info, FeePaymentToken>>>,
+
+    pub fee_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: Pyth SOL/USD price update, validated when read
+    pub sol_price_update: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Pyth token/USD price update, validated when read
+    pub token_price_update: Option<UncheckedAccount<'info>>,
+
+    /// Signer's account for the fee token
+    #[account(mut)]
+    pub payer_fee_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Fee vault's token account
+    #[account(mut)]
+    pub fee_vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 /// Enhanced agent update with 2025 security patterns
@@ -219,6 +288,7 @@ pub fn register_agent(
     metadata_uri: String,
     agent_id: String,
     pricing_model: PricingModel,
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     // Initialize agent registration
     let agent = &mut ctx.accounts.agent_account;
@@ -244,19 +314,145 @@ pub fn register_agent(
     // user_registry.increment_agents_with_rate_limit_check(sys_clock.unix_timestamp)?;
 
     // Initialize agent account with validated inputs - ALL FIELDS MUST BE SET
-    agent.owner = Some(ctx.accounts.signer.key());
+    initialize_agent_account(
+        agent,
+        ctx.accounts.signer.key(),
+        agent_type,
+        name.clone(),
+        description,
+        metadata_uri,
+        agent_id,
+        pricing_model,
+        ctx.bumps.agent_account,
+        sys_clock.unix_timestamp,
+    );
+
+    if let Some(referrer) = referrer {
+        record_referral(
+            ctx.accounts.referrer_stats.as_deref_mut(),
+            referrer,
+            ctx.accounts.signer.key(),
+            agent.key(),
+            ReferralKind::AgentRegistration,
+            sys_clock.unix_timestamp,
+        )?;
+        agent.referrer = Some(referrer);
+    }
+
+    let tier = ctx
+        .accounts
+        .staking_account
+        .current_tier(&ctx.accounts.staking_config.tier_schedule);
+    let token_payment = FeeTokenPayment::from_accounts(
+        ctx.accounts.fee_payment_token.as_deref(),
+        ctx.accounts.sol_payment_token.as_deref(),
+        ctx.accounts.fee_mint.as_deref(),
+        ctx.accounts.sol_price_update.as_deref(),
+        ctx.accounts.token_price_update.as_deref(),
+        ctx.accounts.payer_fee_token_account.as_deref(),
+        ctx.accounts.fee_vault_token_account.as_deref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    charge_registration_fee(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.fee_exemption_list,
+        tier,
+        agent.key(),
+        &ctx.accounts.signer,
+        ctx.accounts.fee_vault.as_deref_mut(),
+        token_payment,
+        &ctx.accounts.system_program,
+        sys_clock.unix_timestamp,
+    )?;
+
+    // Emit optimized event with essential data
+    emit!(crate::AgentRegisteredEvent {
+        agent: agent.key(),
+        owner: agent.owner.unwrap(), // Safe: we just set it
+        name,                        // Use actual validated name
+        timestamp: sys_clock.unix_timestamp,
+    });
+
+    msg!(
+        "Agent registered successfully - Owner: {:?}, Agent: {}",
+        agent.owner,
+        agent.key()
+    );
+    Ok(())
+}
+
+/// Charge the registration fee for `agent`, discounted by the owner's
+/// staking `tier`, in SOL or in `token_payment`'s token
+///
+/// Shared by `register_agent` and `onboard_agent`, so neither path skips it.
+pub(crate) fn charge_registration_fee<'info>(
+    protocol_config: &ProtocolConfig,
+    fee_exemption_list: &AccountInfo,
+    tier: AccessTier,
+    agent: Pubkey,
+    owner: &Signer<'info>,
+    fee_vault: Option<&mut Account<'info, FeeVault>>,
+    token_payment: Option<FeeTokenPayment<'_, 'info>>,
+    system_program: &Program<'info, System>,
+    now: i64,
+) -> Result<()> {
+    let exempt = is_fee_exempt(
+        fee_exemption_list,
+        &[agent, owner.key()],
+        FeeSource::Registration,
+        now,
+    )?;
+    if exempt {
+        let waived = protocol_config.get_registration_fee(tier, false);
+        if waived > 0 {
+            emit!(FeeWaivedEvent {
+                subject: agent,
+                source: FeeSource::Registration,
+                amount: waived,
+                timestamp: now,
+            });
+        }
+    }
+
+    collect_protocol_fee(
+        FeeSource::Registration,
+        protocol_config.get_registration_fee(tier, exempt),
+        owner,
+        fee_vault,
+        token_payment,
+        system_program,
+        now,
+    )
+}
+
+/// Set every field of a freshly created agent account
+///
+/// Shared by `register_agent` and `onboard_agent` so both produce identical accounts.
+pub(crate) fn initialize_agent_account(
+    agent: &mut Agent,
+    owner: Pubkey,
+    agent_type: u8,
+    name: String,
+    description: String,
+    metadata_uri: String,
+    agent_id: String,
+    pricing_model: PricingModel,
+    bump: u8,
+    now: i64,
+) {
+    agent.owner = Some(owner);
     agent.agent_id = agent_id; // CRITICAL: Must initialize agent_id
     agent.agent_type = agent_type; // CRITICAL: Must initialize agent_type
-    agent.name = name.clone();
-    agent.description = description.clone();
+    agent.name = name;
+    agent.description = description;
     agent.capabilities = vec!["general".to_string()]; // Single capability to avoid empty vec
     agent.pricing_model = pricing_model;
     agent.reputation_score = 0;
     agent.total_jobs_completed = 0;
     agent.total_earnings = 0;
     agent.is_active = true;
-    agent.created_at = sys_clock.unix_timestamp;
-    agent.updated_at = sys_clock.unix_timestamp;
+    agent.created_at = now;
+    agent.updated_at = now;
     agent.original_price = 0;
     agent.genome_hash = "".to_string();
     agent.is_replicable = false;
@@ -275,7 +471,7 @@ pub fn register_agent(
     agent.generation = 0;
     // x402 Payment Protocol fields - initialize all to defaults
     agent.x402_enabled = false;
-    agent.x402_payment_address = ctx.accounts.signer.key(); // Default to owner
+    agent.x402_payment_address = owner; // Default to owner
     agent.x402_accepted_tokens = Vec::new();
     agent.x402_price_per_call = 0;
     agent.x402_service_endpoint = "".to_string();
@@ -285,22 +481,9 @@ pub fn register_agent(
     // API Schema fields - initialize to empty
     agent.api_spec_uri = "".to_string();
     agent.api_version = "".to_string();
-    agent.bump = ctx.bumps.agent_account;
-
-    // Emit optimized event with essential data
-    emit!(crate::AgentRegisteredEvent {
-        agent: agent.key(),
-        owner: agent.owner.unwrap(), // Safe: we just set it
-        name,                        // Use actual validated name
-        timestamp: sys_clock.unix_timestamp,
-    });
-
-    msg!(
-        "Agent registered successfully - Owner: {:?}, Agent: {}",
-        agent.owner,
-        agent.key()
-    );
-    Ok(())
+    agent.bump = bump;
+    agent.version = Agent::CURRENT_VERSION;
+    agent.referrer = None;
 }
 
 /// Updates an existing agent's metadata and configuration
@@ -470,3 +653,47 @@ pub fn update_agent_reputation(
     );
     Ok(())
 }
+
+// =====================================================
+// LAYOUT MIGRATION
+// =====================================================
+
+/// Grow an agent created before the layout version (permissionless)
+///
+/// Agents live under more than one PDA scheme (registered and
+/// auto-created), so the handler checks the program owner and
+/// discriminator rather than seeds.
+#[derive(Accounts)]
+pub struct MigrateAgent<'info> {
+    /// CHECK: Agent created before the layout version, whose trailing bytes
+    /// can't be trusted; checked and parsed by the handler
+    #[account(mut)]
+    pub agent_account: UncheckedAccount<'info>,
+
+    /// Anyone may migrate; pays the rent for the added bytes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_agent(ctx: Context<MigrateAgent>) -> Result<()> {
+    let info = ctx.accounts.agent_account.to_account_info();
+    let legacy = read_legacy_layout::<Agent, AgentV0>(&info, Agent::LEN)?;
+    let agent = Agent::from_v0(legacy);
+    write_current_layout(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Agent::LEN,
+        &agent,
+    )?;
+
+    emit!(AgentMigratedEvent {
+        agent: info.key(),
+        version: Agent::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
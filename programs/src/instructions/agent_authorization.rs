@@ -12,6 +12,7 @@
  */
 
 use anchor_lang::prelude::*;
+use crate::security::{RateLimiter, UserRateLimit, OPERATION_REPUTATION_UPDATE};
 use crate::state::{
     AgentReputationAuth,
     AuthorizationUsageRecord,
@@ -148,6 +149,24 @@ pub struct UpdateReputationWithAuth<'info> {
     )]
     pub usage_record: Account<'info, AuthorizationUsageRecord>,
 
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Box<Account<'info, RateLimiter>>,
+
+    /// Per-facilitator rate limit tracking (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authorized_source,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            authorized_source.key().as_ref(),
+            OPERATION_REPUTATION_UPDATE.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Box<Account<'info, UserRateLimit>>,
+
     /// Authorized source (e.g., PayAI facilitator)
     #[account(mut)]
     pub authorized_source: Signer<'info>,
@@ -166,6 +185,13 @@ pub fn update_reputation_with_auth(
     metadata: Option<String>,
     _nonce: Option<String>,
 ) -> Result<()> {
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.authorized_source.key(),
+        OPERATION_REPUTATION_UPDATE,
+        ctx.bumps.user_rate_limit,
+    )?;
+
     let authorization = &mut ctx.accounts.authorization;
     let reputation_metrics = &mut ctx.accounts.reputation_metrics;
     let usage_record = &mut ctx.accounts.usage_record;
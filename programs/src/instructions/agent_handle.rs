@@ -0,0 +1,256 @@
+/*!
+ * Agent Handle Instructions Module
+ *
+ * Registers human-readable handles (`ghost:alice-bot`) that resolve to a DID
+ * document and optional agent account, so marketplaces can reference agents
+ * by name. The owner must control the target DID (and own the agent), both
+ * at registration and when a handle changes hands.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::did::{DidDocument, DidError};
+use crate::state::parameter_registry::{read_parameter, ParameterKey, PARAMETER_REGISTRY_SEED};
+use crate::state::{
+    validate_handle, Agent, AgentHandle, AgentHandleRegisteredEvent, AgentHandleReleasedEvent,
+    AgentHandleRenewedEvent, AgentHandleTransferredEvent, AGENT_HANDLE_NAMESPACE,
+    AGENT_HANDLE_SEED,
+};
+use crate::GhostSpeakError;
+
+// ============================================================================
+// Register
+// ============================================================================
+
+/// Registers `handle` for one registration period, pointing at the owner's
+/// DID document and optional agent.
+pub fn register_agent_handle(ctx: Context<RegisterAgentHandle>, handle: String) -> Result<()> {
+    validate_handle(&handle)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let period = read_parameter(
+        &ctx.accounts.parameter_registry,
+        ParameterKey::HandleRegistrationPeriod,
+    )?;
+    let entry = &mut ctx.accounts.agent_handle;
+    entry.handle = handle.clone();
+    entry.owner = ctx.accounts.owner.key();
+    entry.did_document = ctx.accounts.did_document.key();
+    entry.agent = ctx.accounts.agent.as_ref().map(|agent| agent.key());
+    entry.registered_at = now;
+    entry.expires_at = now
+        .checked_add(period as i64)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    entry.bump = ctx.bumps.agent_handle;
+
+    emit!(AgentHandleRegisteredEvent {
+        handle: handle.clone(),
+        owner: entry.owner,
+        did_document: entry.did_document,
+        agent: entry.agent,
+        expires_at: entry.expires_at,
+    });
+
+    msg!(
+        "Handle {}{} registered to {}",
+        AGENT_HANDLE_NAMESPACE,
+        handle,
+        entry.did_document
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(handle: String)]
+pub struct RegisterAgentHandle<'info> {
+    /// Handle PDA; `init` enforces uniqueness
+    #[account(
+        init,
+        payer = owner,
+        space = AgentHandle::LEN,
+        seeds = [AGENT_HANDLE_SEED, handle.as_bytes()],
+        bump
+    )]
+    pub agent_handle: Account<'info, AgentHandle>,
+
+    /// DID document the handle resolves to
+    #[account(
+        constraint = did_document.controller == owner.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Agent the handle resolves to, if any
+    #[account(
+        constraint = agent.owner == Some(owner.key()) @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub agent: Option<Account<'info, Agent>>,
+
+    /// CHECK: Parameter registry PDA, read for the registration period;
+    /// defaults apply until it is initialized
+    #[account(seeds = [PARAMETER_REGISTRY_SEED], bump)]
+    pub parameter_registry: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// Renew
+// ============================================================================
+
+/// Extends a handle by one registration period. Lapsed handles can still be
+/// renewed by their owner during the grace period.
+pub fn renew_agent_handle(ctx: Context<RenewAgentHandle>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &ctx.accounts.parameter_registry;
+    let period = read_parameter(registry, ParameterKey::HandleRegistrationPeriod)?;
+    let grace_period = read_parameter(registry, ParameterKey::HandleGracePeriod)?;
+    let entry = &mut ctx.accounts.agent_handle;
+    entry.renew(now, period as i64, grace_period as i64)?;
+
+    emit!(AgentHandleRenewedEvent {
+        handle: entry.handle.clone(),
+        expires_at: entry.expires_at,
+    });
+
+    msg!(
+        "Handle {}{} renewed until {}",
+        AGENT_HANDLE_NAMESPACE,
+        entry.handle,
+        entry.expires_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenewAgentHandle<'info> {
+    #[account(
+        mut,
+        seeds = [AGENT_HANDLE_SEED, agent_handle.handle.as_bytes()],
+        bump = agent_handle.bump,
+        has_one = owner @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub agent_handle: Account<'info, AgentHandle>,
+
+    /// CHECK: Parameter registry PDA, read for the renewal and grace
+    /// periods; defaults apply until it is initialized
+    #[account(seeds = [PARAMETER_REGISTRY_SEED], bump)]
+    pub parameter_registry: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// Transfer
+// ============================================================================
+
+/// Hands an active handle to `new_owner`, re-pointing it at their DID
+/// document and optional agent. Both parties sign, so a sale settles
+/// atomically.
+pub fn transfer_agent_handle(ctx: Context<TransferAgentHandle>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let entry = &mut ctx.accounts.agent_handle;
+    require!(entry.is_active(now), GhostSpeakError::HandleExpired);
+
+    let previous_owner = entry.owner;
+    entry.owner = ctx.accounts.new_owner.key();
+    entry.did_document = ctx.accounts.did_document.key();
+    entry.agent = ctx.accounts.agent.as_ref().map(|agent| agent.key());
+
+    emit!(AgentHandleTransferredEvent {
+        handle: entry.handle.clone(),
+        previous_owner,
+        new_owner: entry.owner,
+        did_document: entry.did_document,
+        agent: entry.agent,
+    });
+
+    msg!(
+        "Handle {}{} transferred from {} to {}",
+        AGENT_HANDLE_NAMESPACE,
+        entry.handle,
+        previous_owner,
+        entry.owner
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferAgentHandle<'info> {
+    #[account(
+        mut,
+        seeds = [AGENT_HANDLE_SEED, agent_handle.handle.as_bytes()],
+        bump = agent_handle.bump,
+        has_one = owner @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub agent_handle: Account<'info, AgentHandle>,
+
+    /// New owner's DID document
+    #[account(
+        constraint = did_document.controller == new_owner.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// New owner's agent, if any
+    #[account(
+        constraint = agent.owner == Some(new_owner.key()) @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub agent: Option<Account<'info, Agent>>,
+
+    pub owner: Signer<'info>,
+
+    pub new_owner: Signer<'info>,
+}
+
+// ============================================================================
+// Release
+// ============================================================================
+
+/// Closes a handle that lapsed past its grace period (permissionless),
+/// refunding the rent to its owner so the name can be registered again.
+pub fn release_agent_handle(ctx: Context<ReleaseAgentHandle>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let grace_period = read_parameter(
+        &ctx.accounts.parameter_registry,
+        ParameterKey::HandleGracePeriod,
+    )?;
+    let entry = &ctx.accounts.agent_handle;
+    require!(
+        entry.is_releasable(now, grace_period as i64),
+        GhostSpeakError::HandleNotReleasable
+    );
+
+    emit!(AgentHandleReleasedEvent {
+        handle: entry.handle.clone(),
+        owner: entry.owner,
+        released_at: now,
+    });
+
+    msg!("Handle {}{} released", AGENT_HANDLE_NAMESPACE, entry.handle);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseAgentHandle<'info> {
+    #[account(
+        mut,
+        seeds = [AGENT_HANDLE_SEED, agent_handle.handle.as_bytes()],
+        bump = agent_handle.bump,
+        has_one = owner,
+        close = owner
+    )]
+    pub agent_handle: Account<'info, AgentHandle>,
+
+    /// CHECK: Parameter registry PDA, read for the grace period; defaults
+    /// apply until it is initialized
+    #[account(seeds = [PARAMETER_REGISTRY_SEED], bump)]
+    pub parameter_registry: UncheckedAccount<'info>,
+
+    /// CHECK: Handle owner, receives the rent refund
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+}
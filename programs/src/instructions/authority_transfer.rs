@@ -0,0 +1,343 @@
+/*!
+ * Authority Transfer Instructions
+ *
+ * Two-step handover of the `ProtocolConfig`, `StakingConfig` and
+ * credential-type authorities. The current authority proposes a successor
+ * with an expiry; the successor takes over only by signing an acceptance
+ * before then. The proposer can cancel an open or lapsed proposal to
+ * reclaim its rent.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::authority_transfer::*;
+use crate::state::credential::CredentialType;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::staking::StakingConfig;
+use crate::GhostSpeakError;
+
+// =====================================================
+// PROPOSE
+// =====================================================
+
+/// Propose a new `ProtocolConfig` authority (config authority only)
+#[derive(Accounts)]
+pub struct ProposeProtocolConfigAuthority<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuthorityTransfer::LEN,
+        seeds = [AUTHORITY_TRANSFER_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose a new `StakingConfig` authority (staking authority only)
+#[derive(Accounts)]
+pub struct ProposeStakingConfigAuthority<'info> {
+    #[account(
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuthorityTransfer::LEN,
+        seeds = [AUTHORITY_TRANSFER_SEED, staking_config.key().as_ref()],
+        bump
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose a new owner for a credential type (credential type authority only)
+#[derive(Accounts)]
+pub struct ProposeCredentialTypeAuthority<'info> {
+    #[account(
+        constraint = credential_type.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub credential_type: Box<Account<'info, CredentialType>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuthorityTransfer::LEN,
+        seeds = [AUTHORITY_TRANSFER_SEED, credential_type.key().as_ref()],
+        bump
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record a proposal; a later one for the same target replaces it
+fn propose(
+    transfer: &mut Account<AuthorityTransfer>,
+    target: Pubkey,
+    target_kind: AuthorityTarget,
+    current_authority: Pubkey,
+    new_authority: Pubkey,
+    expires_in: i64,
+    bump: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    transfer.propose(
+        target,
+        target_kind,
+        current_authority,
+        new_authority,
+        expires_in,
+        now,
+        bump,
+    )?;
+
+    emit!(AuthorityTransferProposedEvent {
+        target,
+        target_kind,
+        current_authority,
+        pending_authority: new_authority,
+        expires_at: transfer.expires_at,
+    });
+
+    msg!(
+        "Authority transfer of {} to {} proposed, expires at {}",
+        target,
+        new_authority,
+        transfer.expires_at
+    );
+    Ok(())
+}
+
+pub fn propose_protocol_config_authority_transfer(
+    ctx: Context<ProposeProtocolConfigAuthority>,
+    new_authority: Pubkey,
+    expires_in: i64,
+) -> Result<()> {
+    propose(
+        &mut ctx.accounts.authority_transfer,
+        ctx.accounts.config.key(),
+        AuthorityTarget::ProtocolConfig,
+        ctx.accounts.authority.key(),
+        new_authority,
+        expires_in,
+        ctx.bumps.authority_transfer,
+    )
+}
+
+pub fn propose_staking_config_authority_transfer(
+    ctx: Context<ProposeStakingConfigAuthority>,
+    new_authority: Pubkey,
+    expires_in: i64,
+) -> Result<()> {
+    propose(
+        &mut ctx.accounts.authority_transfer,
+        ctx.accounts.staking_config.key(),
+        AuthorityTarget::StakingConfig,
+        ctx.accounts.authority.key(),
+        new_authority,
+        expires_in,
+        ctx.bumps.authority_transfer,
+    )
+}
+
+pub fn propose_credential_type_authority_transfer(
+    ctx: Context<ProposeCredentialTypeAuthority>,
+    new_authority: Pubkey,
+    expires_in: i64,
+) -> Result<()> {
+    propose(
+        &mut ctx.accounts.authority_transfer,
+        ctx.accounts.credential_type.key(),
+        AuthorityTarget::CredentialType,
+        ctx.accounts.authority.key(),
+        new_authority,
+        expires_in,
+        ctx.bumps.authority_transfer,
+    )
+}
+
+// =====================================================
+// ACCEPT
+// =====================================================
+
+/// Take over `ProtocolConfig` (pending authority only, before expiry)
+#[derive(Accounts)]
+pub struct AcceptProtocolConfigAuthority<'info> {
+    #[account(mut, seeds = [b"protocol_config"], bump = config.bump)]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [AUTHORITY_TRANSFER_SEED, config.key().as_ref()],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.pending_authority == new_authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    /// CHECK: Proposer, refunded the transfer account's rent
+    #[account(mut, address = authority_transfer.current_authority)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub new_authority: Signer<'info>,
+}
+
+/// Take over `StakingConfig` (pending authority only, before expiry)
+#[derive(Accounts)]
+pub struct AcceptStakingConfigAuthority<'info> {
+    #[account(mut, seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [AUTHORITY_TRANSFER_SEED, staking_config.key().as_ref()],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.pending_authority == new_authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    /// CHECK: Proposer, refunded the transfer account's rent
+    #[account(mut, address = authority_transfer.current_authority)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub new_authority: Signer<'info>,
+}
+
+/// Take over a credential type (pending authority only, before expiry)
+#[derive(Accounts)]
+pub struct AcceptCredentialTypeAuthority<'info> {
+    #[account(mut)]
+    pub credential_type: Box<Account<'info, CredentialType>>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [AUTHORITY_TRANSFER_SEED, credential_type.key().as_ref()],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.pending_authority == new_authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    /// CHECK: Proposer, refunded the transfer account's rent
+    #[account(mut, address = authority_transfer.current_authority)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub new_authority: Signer<'info>,
+}
+
+/// Check the proposal and swap in the new authority, returning the old one
+fn accept(transfer: &AuthorityTransfer, authority: &mut Pubkey) -> Result<Pubkey> {
+    let now = Clock::get()?.unix_timestamp;
+    transfer.require_acceptable(authority, now)?;
+
+    let previous_authority = *authority;
+    *authority = transfer.pending_authority;
+
+    emit!(AuthorityTransferAcceptedEvent {
+        target: transfer.target,
+        target_kind: transfer.target_kind,
+        previous_authority,
+        new_authority: transfer.pending_authority,
+        timestamp: now,
+    });
+
+    msg!(
+        "Authority of {} changed from {} to {}",
+        transfer.target,
+        previous_authority,
+        transfer.pending_authority
+    );
+    Ok(previous_authority)
+}
+
+pub fn accept_protocol_config_authority_transfer(
+    ctx: Context<AcceptProtocolConfigAuthority>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    accept(&ctx.accounts.authority_transfer, &mut config.authority)?;
+    config.updated_at = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+pub fn accept_staking_config_authority_transfer(
+    ctx: Context<AcceptStakingConfigAuthority>,
+) -> Result<()> {
+    accept(
+        &ctx.accounts.authority_transfer,
+        &mut ctx.accounts.staking_config.authority,
+    )?;
+    Ok(())
+}
+
+pub fn accept_credential_type_authority_transfer(
+    ctx: Context<AcceptCredentialTypeAuthority>,
+) -> Result<()> {
+    accept(
+        &ctx.accounts.authority_transfer,
+        &mut ctx.accounts.credential_type.authority,
+    )?;
+    Ok(())
+}
+
+// =====================================================
+// CANCEL
+// =====================================================
+
+/// Withdraw an open or lapsed proposal (proposer only)
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [AUTHORITY_TRANSFER_SEED, authority_transfer.target.as_ref()],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.current_authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let transfer = &ctx.accounts.authority_transfer;
+    let now = Clock::get()?.unix_timestamp;
+
+    emit!(AuthorityTransferCancelledEvent {
+        target: transfer.target,
+        target_kind: transfer.target_kind,
+        cancelled_authority: transfer.pending_authority,
+        timestamp: now,
+    });
+
+    msg!(
+        "Authority transfer of {} to {} cancelled",
+        transfer.target,
+        transfer.pending_authority
+    );
+    Ok(())
+}
@@ -0,0 +1,236 @@
+/*!
+ * Auto-Execution Trigger Instructions
+ *
+ * Lets the authority over a passed proposal or an execution queue attach
+ * a trigger that fires its execution once time, slot or oracle conditions
+ * hold. Anyone can crank `evaluate_trigger`; the conditions, not the
+ * caller, decide whether the target runs.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::instructions::execution_queue::process_batch;
+use crate::state::governance::{
+    AutoExecutionTrigger, ExecutionQueue, GovernanceProposal, TriggerCondition, TriggerType,
+    AUTO_TRIGGER_SEED, MAX_TRIGGER_CONDITIONS,
+};
+use crate::GhostSpeakError;
+
+/// A trigger target, read from its program-owned account
+enum TriggerTarget {
+    Proposal(Box<GovernanceProposal>),
+    Queue(Box<ExecutionQueue>),
+}
+
+impl TriggerTarget {
+    fn load(info: &AccountInfo) -> Result<Self> {
+        require!(
+            info.owner == &crate::ID,
+            GhostSpeakError::InvalidAccountOwner
+        );
+        let data = info.try_borrow_data()?;
+        if let Ok(proposal) = GovernanceProposal::try_deserialize(&mut &data[..]) {
+            return Ok(Self::Proposal(Box::new(proposal)));
+        }
+        let queue = ExecutionQueue::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(GhostSpeakError::InvalidInput))?;
+        Ok(Self::Queue(Box::new(queue)))
+    }
+
+    fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        match self {
+            Self::Proposal(proposal) => proposal.try_serialize(&mut &mut data[..]),
+            Self::Queue(queue) => queue.try_serialize(&mut &mut data[..]),
+        }
+    }
+
+    /// Who may attach triggers: a proposal's execution authority (its
+    /// proposer when unset) or a queue's executor
+    fn authority(&self) -> Pubkey {
+        match self {
+            Self::Proposal(proposal) => {
+                let authority = proposal.execution_params.execution_authority;
+                if authority == Pubkey::default() {
+                    proposal.proposer
+                } else {
+                    authority
+                }
+            }
+            Self::Queue(queue) => queue.executor,
+        }
+    }
+}
+
+// =====================================================
+// CREATE TRIGGER
+// =====================================================
+
+/// Attach an auto-execution trigger to a proposal or execution queue
+/// (target authority only)
+#[derive(Accounts)]
+#[instruction(trigger_id: u64)]
+pub struct CreateTrigger<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = AutoExecutionTrigger::space(),
+        seeds = [AUTO_TRIGGER_SEED, target.key().as_ref(), trigger_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub trigger: Box<Account<'info, AutoExecutionTrigger>>,
+
+    /// CHECK: Governance proposal or execution queue, deserialized in the handler
+    pub target: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_trigger(
+    ctx: Context<CreateTrigger>,
+    trigger_id: u64,
+    trigger_type: TriggerType,
+    conditions: Vec<TriggerCondition>,
+    max_triggers: u32,
+) -> Result<()> {
+    let target = ctx.accounts.target.key();
+    require!(
+        TriggerTarget::load(&ctx.accounts.target)?.authority() == ctx.accounts.authority.key(),
+        GhostSpeakError::UnauthorizedAccess
+    );
+    require!(
+        !conditions.is_empty() && conditions.len() <= MAX_TRIGGER_CONDITIONS,
+        GhostSpeakError::InvalidTriggerCondition
+    );
+    for condition in &conditions {
+        condition.validate()?;
+    }
+
+    let trigger = &mut ctx.accounts.trigger;
+    trigger.trigger_id = trigger_id;
+    trigger.target = target;
+    trigger.trigger_type = trigger_type;
+    trigger.conditions = conditions
+        .into_iter()
+        .map(|condition| TriggerCondition {
+            met: false,
+            last_checked_at: None,
+            ..condition
+        })
+        .collect();
+    trigger.active = true;
+    trigger.created_at = Clock::get()?.unix_timestamp;
+    trigger.last_triggered_at = None;
+    trigger.max_triggers = max_triggers;
+    trigger.trigger_count = 0;
+
+    emit!(AutoTriggerCreatedEvent {
+        trigger: trigger.key(),
+        target: trigger.target,
+        trigger_id,
+        trigger_type,
+        condition_count: trigger.conditions.len() as u8,
+        max_triggers,
+    });
+
+    msg!(
+        "Auto-execution trigger {} created for {}",
+        trigger_id,
+        trigger.target
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// EVALUATE TRIGGER
+// =====================================================
+
+/// Check a trigger's conditions and fire its target when they all hold
+/// (permissionless crank)
+///
+/// Oracle accounts named by the conditions, and for queue targets the
+/// queued proposal accounts, are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct EvaluateTrigger<'info> {
+    #[account(
+        mut,
+        seeds = [AUTO_TRIGGER_SEED, trigger.target.as_ref(), trigger.trigger_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = trigger.active @ GhostSpeakError::TriggerInactive
+    )]
+    pub trigger: Box<Account<'info, AutoExecutionTrigger>>,
+
+    /// CHECK: The trigger's target, deserialized when the trigger fires
+    #[account(mut, address = trigger.target)]
+    pub target: UncheckedAccount<'info>,
+}
+
+pub fn evaluate_trigger<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EvaluateTrigger<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let trigger = &mut ctx.accounts.trigger;
+
+    let fired = trigger.evaluate(now, clock.slot, ctx.remaining_accounts)?;
+    if fired {
+        let info = ctx.accounts.target.to_account_info();
+        let mut target = TriggerTarget::load(&info)?;
+        match &mut target {
+            TriggerTarget::Proposal(proposal) => proposal.mark_executed(now)?,
+            TriggerTarget::Queue(queue) => {
+                process_batch(queue, info.key(), ctx.remaining_accounts, now)?;
+            }
+        }
+        target.store(&info)?;
+        trigger.record_firing(now);
+    }
+
+    emit!(TriggerEvaluatedEvent {
+        trigger: trigger.key(),
+        target: trigger.target,
+        fired,
+        conditions_met: trigger.conditions.iter().filter(|c| c.met).count() as u8,
+        trigger_count: trigger.trigger_count,
+        active: trigger.active,
+        timestamp: now,
+    });
+
+    msg!(
+        "Trigger {} evaluated (fired: {}, count: {})",
+        trigger.trigger_id,
+        fired,
+        trigger.trigger_count
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// EVENTS
+// =====================================================
+
+#[event]
+pub struct AutoTriggerCreatedEvent {
+    pub trigger: Pubkey,
+    pub target: Pubkey,
+    pub trigger_id: u64,
+    pub trigger_type: TriggerType,
+    pub condition_count: u8,
+    pub max_triggers: u32,
+}
+
+#[event]
+pub struct TriggerEvaluatedEvent {
+    pub trigger: Pubkey,
+    pub target: Pubkey,
+    pub fired: bool,
+    pub conditions_met: u8,
+    pub trigger_count: u32,
+    pub active: bool,
+    pub timestamp: i64,
+}
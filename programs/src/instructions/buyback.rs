@@ -0,0 +1,366 @@
+/*!
+ * Buyback Instructions
+ *
+ * Sets up the buyback vaults, lets the Protocol multisig tune the buyback
+ * settings and lets the buyback operator swap vaulted USDC for GHOST
+ * through the whitelisted swap program. The swap's accounts and data come
+ * from the operator's route (Jupiter, Whirlpool); the program checks the
+ * vault balances before and after rather than trusting the route, and
+ * prices the GHOST received against the Pyth USDC and GHOST feeds rather
+ * than the operator's quote.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::buyback::*;
+use crate::state::fee_token::{convert_token_amount, FeePaymentToken, FEE_PAYMENT_TOKEN_SEED};
+use crate::state::governance::{Multisig, MultisigType};
+use crate::state::staking::StakingConfig;
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE BUYBACK
+// =====================================================
+
+/// Create the buyback config and its vaults (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeBuyback<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = BuybackConfig::LEN,
+        seeds = [BUYBACK_CONFIG_SEED],
+        bump
+    )]
+    pub buyback_config: Box<Account<'info, BuybackConfig>>,
+
+    /// USDC awaiting buyback; set as the protocol config's buyback pool
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = buyback_config,
+        seeds = [BUYBACK_USDC_VAULT_SEED, buyback_config.key().as_ref()],
+        bump
+    )]
+    pub usdc_vault: Box<Account<'info, TokenAccount>>,
+
+    /// GHOST received from swaps, burned or restaked in the same instruction
+    #[account(
+        init,
+        payer = authority,
+        token::mint = ghost_mint,
+        token::authority = buyback_config,
+        seeds = [BUYBACK_GHOST_VAULT_SEED, buyback_config.key().as_ref()],
+        bump
+    )]
+    pub ghost_vault: Box<Account<'info, TokenAccount>>,
+
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    /// GHOST token mint, pinned to the staking vault's mint
+    #[account(address = staking_vault.mint @ GhostSpeakError::InvalidInput)]
+    pub ghost_mint: Box<Account<'info, Mint>>,
+
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    #[account(
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol multisig that will govern the settings
+    #[account(
+        constraint = multisig.multisig_type == MultisigType::Protocol @ GhostSpeakError::InvalidConfiguration
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_buyback(ctx: Context<InitializeBuyback>, params: BuybackParams) -> Result<()> {
+    params.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.buyback_config;
+    config.multisig = ctx.accounts.multisig.key();
+    config.params = params;
+    config.usdc_mint = ctx.accounts.usdc_mint.key();
+    config.ghost_mint = ctx.accounts.ghost_mint.key();
+    config.usdc_vault = ctx.accounts.usdc_vault.key();
+    config.ghost_vault = ctx.accounts.ghost_vault.key();
+    config.epoch_start = now;
+    config.epoch_spent = 0;
+    config.total_usdc_spent = 0;
+    config.total_ghost_burned = 0;
+    config.total_ghost_restaked = 0;
+    config.updated_at = now;
+    config.bump = ctx.bumps.buyback_config;
+
+    emit_config_updated(config, now);
+
+    msg!("Buyback initialized (USDC vault: {})", config.usdc_vault);
+    Ok(())
+}
+
+// =====================================================
+// UPDATE BUYBACK CONFIG
+// =====================================================
+
+/// Change the buyback settings (Protocol multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct UpdateBuybackConfig<'info> {
+    #[account(
+        mut,
+        seeds = [BUYBACK_CONFIG_SEED],
+        bump = buyback_config.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub buyback_config: Box<Account<'info, BuybackConfig>>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn update_buyback_config(
+    ctx: Context<UpdateBuybackConfig>,
+    params: BuybackParams,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+    params.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.buyback_config;
+    config.params = params;
+    config.updated_at = now;
+
+    emit_config_updated(config, now);
+
+    msg!("Buyback config updated");
+    Ok(())
+}
+
+// =====================================================
+// EXECUTE BUYBACK
+// =====================================================
+
+/// Swap vaulted USDC for GHOST and burn or restake it (buyback operator only)
+///
+/// The swap route's accounts are passed as remaining accounts, with the
+/// buyback config as the token owner.
+#[derive(Accounts)]
+pub struct ExecuteBuyback<'info> {
+    #[account(
+        mut,
+        seeds = [BUYBACK_CONFIG_SEED],
+        bump = buyback_config.bump,
+        constraint = buyback_config.params.operator == operator.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub buyback_config: Box<Account<'info, BuybackConfig>>,
+
+    #[account(mut, address = buyback_config.usdc_vault)]
+    pub usdc_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = buyback_config.ghost_vault)]
+    pub ghost_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = buyback_config.ghost_mint)]
+    pub ghost_mint: Box<Account<'info, Mint>>,
+
+    #[account(address = buyback_config.usdc_mint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    /// USDC's fee payment token entry, for its USD price feed
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, buyback_config.usdc_mint.as_ref()],
+        bump = usdc_payment_token.bump
+    )]
+    pub usdc_payment_token: Box<Account<'info, FeePaymentToken>>,
+
+    /// GHOST's fee payment token entry, for its USD price feed
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, buyback_config.ghost_mint.as_ref()],
+        bump = ghost_payment_token.bump
+    )]
+    pub ghost_payment_token: Box<Account<'info, FeePaymentToken>>,
+
+    /// CHECK: Pyth USDC/USD price update, validated when read
+    pub usdc_price_update: UncheckedAccount<'info>,
+
+    /// CHECK: Pyth GHOST/USD price update, validated when read
+    pub ghost_price_update: UncheckedAccount<'info>,
+
+    /// Staking config, whose reward reserve receives restaked GHOST
+    #[account(mut, seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_reward_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Whitelisted swap program
+    #[account(
+        executable,
+        address = buyback_config.params.swap_program @ GhostSpeakError::BuybackSwapMismatch
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Spends at most `amount_in` USDC through the swap described by
+/// `swap_data`, requiring at least what the USDC spent is worth in GHOST at
+/// the oracle prices, less the configured slippage.
+pub fn execute_buyback<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteBuyback<'info>>,
+    amount_in: u64,
+    swap_data: Vec<u8>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config_key = ctx.accounts.buyback_config.key();
+    ctx.accounts
+        .buyback_config
+        .reserve_epoch_spend(amount_in, now)?;
+
+    let usdc_before = ctx.accounts.usdc_vault.amount;
+    let ghost_before = ctx.accounts.ghost_vault.amount;
+
+    let swap_ix = Instruction {
+        program_id: ctx.accounts.swap_program.key(),
+        accounts: ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == config_key,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: swap_data,
+    };
+    let bump = ctx.accounts.buyback_config.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[BUYBACK_CONFIG_SEED, &[bump]]];
+    let mut swap_accounts = ctx.remaining_accounts.to_vec();
+    swap_accounts.push(ctx.accounts.swap_program.to_account_info());
+    invoke_signed(&swap_ix, &swap_accounts, signer_seeds)?;
+
+    ctx.accounts.usdc_vault.reload()?;
+    ctx.accounts.ghost_vault.reload()?;
+    let spent = usdc_before.saturating_sub(ctx.accounts.usdc_vault.amount);
+    let received = ctx.accounts.ghost_vault.amount.saturating_sub(ghost_before);
+
+    let usdc_price = ctx
+        .accounts
+        .usdc_payment_token
+        .read_price(&ctx.accounts.usdc_price_update, now)?;
+    let ghost_price = ctx
+        .accounts
+        .ghost_payment_token
+        .read_price(&ctx.accounts.ghost_price_update, now)?;
+    let oracle_out = convert_token_amount(
+        spent,
+        &usdc_price,
+        ctx.accounts.usdc_mint.decimals,
+        &ghost_price,
+        ctx.accounts.ghost_mint.decimals,
+    )?;
+    ctx.accounts
+        .buyback_config
+        .record_swap(amount_in, spent, received, oracle_out, now)?;
+
+    let disposition = ctx.accounts.buyback_config.params.disposition;
+    match disposition {
+        BuybackDisposition::Burn => {
+            let cpi_accounts = Burn {
+                mint: ctx.accounts.ghost_mint.to_account_info(),
+                from: ctx.accounts.ghost_vault.to_account_info(),
+                authority: ctx.accounts.buyback_config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::burn(cpi_ctx, received)?;
+        }
+        BuybackDisposition::Restake => {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.ghost_vault.to_account_info(),
+                to: ctx.accounts.staking_reward_vault.to_account_info(),
+                authority: ctx.accounts.buyback_config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, received)?;
+
+            // Release what was owed under the old reserve before topping it up
+            let staking_config = &mut ctx.accounts.staking_config;
+            staking_config.accrue_rewards(now)?;
+            staking_config.reward_reserve = staking_config
+                .reward_reserve
+                .checked_add(received)
+                .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        }
+    }
+
+    emit!(BuybackExecutedEvent {
+        operator: ctx.accounts.operator.key(),
+        usdc_spent: spent,
+        ghost_received: received,
+        oracle_out,
+        disposition,
+        epoch_spent: ctx.accounts.buyback_config.epoch_spent,
+        timestamp: now,
+    });
+
+    msg!(
+        "Buyback swapped {} USDC for {} GHOST ({:?})",
+        spent,
+        received,
+        disposition
+    );
+    Ok(())
+}
+
+fn emit_config_updated(config: &BuybackConfig, now: i64) {
+    emit!(BuybackConfigUpdatedEvent {
+        multisig: config.multisig,
+        operator: config.params.operator,
+        swap_program: config.params.swap_program,
+        max_slippage_bps: config.params.max_slippage_bps,
+        epoch_duration: config.params.epoch_duration,
+        epoch_cap: config.params.epoch_cap,
+        disposition: config.params.disposition,
+        timestamp: now,
+    });
+}
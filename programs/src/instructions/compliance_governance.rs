@@ -7,17 +7,25 @@
  */
 
 use crate::state::audit::{
-    AuditAction, AuditConfig, AuditEntry, AuditTrail, ComplianceReport, ReportType, RiskThresholds,
+    AuditAction, AuditConfig, AuditContext, AuditEntry, AuditEntryPage, AuditTrail,
+    ComplianceFlags, ComplianceReport, ReportType, RiskThresholds, AUDIT_ENTRY_PAGE_SEED,
 };
+use crate::state::agent::Agent;
 use crate::state::governance::{
-    EmergencyConfig, ExecutionParams, GovernanceProposal, Multisig, MultisigConfig,
-    MultisigType, MultisigTypeConfig, ProposalMetadata, ProposalStatus, ProposalType,
-    QuorumRequirements, TransactionType,
+    calculate_enhanced_voting_power, EmergencyConfig, ExecutionParams, GovernanceProposal,
+    Multisig, MultisigConfig, MultisigType, MultisigTypeConfig, PendingTransactionPage,
+    ProposalDepositConfig, ProposalMetadata, ProposalStatus, ProposalType, ProposalVote,
+    QuorumRequirements, SnapshotStrategy, SpendIntent, TransactionPriority, TransactionStatus,
+    TransactionType, VoteChoice, VotingPowerInput, VotingPowerSnapshot, MAX_DAILY_LIMITS,
+    PROPOSAL_DEPOSIT_CONFIG_SEED, PROPOSAL_VOTE_SEED, VOTING_POWER_SNAPSHOT_SEED,
 };
+use crate::state::staking::StakingAccount;
+use crate::utils::pagination::open_page;
 use crate::state::security_governance::{
     AccessAuditConfig, EmergencyAccessConfig, RbacConfig, Role, SecurityPolicies,
 };
 use crate::*;
+use anchor_lang::system_program;
 use sha3::{Digest, Keccak256};
 
 /// Helper function to hash multiple byte slices using Keccak256
@@ -84,7 +92,7 @@ pub struct CreateMultisig<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Initialize governance proposal
+/// Initialize governance proposal, escrowing the proposer's deposit
 #[derive(Accounts)]
 #[instruction(proposal_id: u64)]
 pub struct InitializeGovernanceProposal<'info> {
@@ -97,6 +105,12 @@ pub struct InitializeGovernanceProposal<'info> {
     )]
     pub proposal: Account<'info, GovernanceProposal>,
 
+    #[account(
+        seeds = [PROPOSAL_DEPOSIT_CONFIG_SEED],
+        bump = deposit_config.bump
+    )]
+    pub deposit_config: Account<'info, ProposalDepositConfig>,
+
     #[account(mut)]
     pub proposer: Signer<'info>,
 
@@ -144,6 +158,261 @@ pub struct GenerateComplianceReport<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Append an entry to an audit trail's paged history
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct AppendAuditEntry<'info> {
+    #[account(
+        mut,
+        constraint = audit_trail.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub audit_trail: Account<'info, AuditTrail>,
+
+    /// Tail page (opened on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuditEntryPage::space(),
+        seeds = [AUDIT_ENTRY_PAGE_SEED, audit_trail.key().as_ref(), page_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub page: Box<Account<'info, AuditEntryPage>>,
+
+    /// Current tail page, required when opening a page after the first
+    #[account(
+        mut,
+        constraint = previous_page.audit_trail == audit_trail.key() @ GhostSpeakError::InvalidPageIndex
+    )]
+    pub previous_page: Option<Box<Account<'info, AuditEntryPage>>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a transaction in a multisig's paged pending queue
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct QueueMultisigTransaction<'info> {
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+
+    /// Tail page (opened on first use)
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = PendingTransactionPage::space(),
+        seeds = [b"multisig_tx_page", multisig.key().as_ref(), page_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub page: Box<Account<'info, PendingTransactionPage>>,
+
+    /// Current tail page, required when opening a page after the first
+    #[account(
+        mut,
+        constraint = previous_page.multisig == multisig.key() @ GhostSpeakError::InvalidPageIndex
+    )]
+    pub previous_page: Option<Box<Account<'info, PendingTransactionPage>>>,
+
+    /// Multisig signer proposing the transaction
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record a voter's voting power as of the proposal's snapshot point (voter only)
+#[derive(Accounts)]
+pub struct SnapshotVotingPower<'info> {
+    #[account(
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    /// `init` allows one snapshot per voter
+    #[account(
+        init,
+        payer = voter,
+        space = VotingPowerSnapshot::space(),
+        seeds = [VOTING_POWER_SNAPSHOT_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, VotingPowerSnapshot>,
+
+    /// Voter's stake, counted with its lock multiplier
+    #[account(
+        seeds = [b"staking", voter.key().as_ref()],
+        bump = staking_account.bump
+    )]
+    pub staking_account: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// Voter's agent, whose reputation counts once verified
+    #[account(
+        constraint = agent.owner == Some(voter.key()) @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub agent: Option<Box<Account<'info, Agent>>>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Vote on an active governance proposal (one vote per voter)
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    /// `init` refuses a second vote from the same voter
+    #[account(
+        init,
+        payer = voter,
+        space = ProposalVote::space(),
+        seeds = [PROPOSAL_VOTE_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, ProposalVote>,
+
+    /// Voting power recorded by `snapshot_voting_power`
+    #[account(
+        seeds = [VOTING_POWER_SNAPSHOT_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump = snapshot.bump
+    )]
+    pub snapshot: Account<'info, VotingPowerSnapshot>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Close voting on a proposal after its voting period (permissionless)
+#[derive(Accounts)]
+pub struct TallyVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+}
+
+/// Mark a passed proposal executed after its execution delay
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    /// Must be the proposal's execution authority, unless none was set
+    pub executor: Signer<'info>,
+}
+
+/// Approve or execute a transaction queued in a multisig page (multisig signer)
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct SignMultisigTransaction<'info> {
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig_tx_page", multisig.key().as_ref(), page_index.to_le_bytes().as_ref()],
+        bump = page.bump
+    )]
+    pub page: Box<Account<'info, PendingTransactionPage>>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Execute an approved transaction from a multisig page (multisig signer)
+///
+/// Multisigs created before daily spend tracking existed are grown to the
+/// current size at the executor's cost.
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct ExecuteMultisigTransaction<'info> {
+    #[account(
+        mut,
+        realloc = Multisig::space(),
+        realloc::payer = signer,
+        realloc::zero = false
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig_tx_page", multisig.key().as_ref(), page_index.to_le_bytes().as_ref()],
+        bump = page.bump
+    )]
+    pub page: Box<Account<'info, PendingTransactionPage>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// System program (required by realloc)
+    pub system_program: Program<'info, System>,
+}
+
+/// Veto a queued multisig transaction
+///
+/// Vetoing signers are passed as remaining accounts and must meet the
+/// multisig's veto quorum.
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct CancelMultisigTransaction<'info> {
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig_tx_page", multisig.key().as_ref(), page_index.to_le_bytes().as_ref()],
+        bump = page.bump
+    )]
+    pub page: Box<Account<'info, PendingTransactionPage>>,
+}
+
+/// Change a multisig's signers or threshold
+///
+/// Current signers are passed as remaining accounts and must meet the
+/// multisig's signer change threshold.
+#[derive(Accounts)]
+pub struct UpdateMultisigSigners<'info> {
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+}
+
+/// Set or clear a multisig's signer weights (signer change threshold required)
+///
+/// Current signers are passed as remaining accounts. Multisigs created
+/// before weights existed are grown to the current size at `payer`'s cost.
+#[derive(Accounts)]
+pub struct SetSignerWeights<'info> {
+    #[account(
+        mut,
+        realloc = Multisig::space(),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program (required by realloc)
+    pub system_program: Program<'info, System>,
+}
+
 // =====================================================
 // INSTRUCTION HANDLERS
 // =====================================================
@@ -196,6 +465,289 @@ pub fn initialize_audit_trail(
     Ok(())
 }
 
+/// Append an entry to the audit trail's tail page
+pub fn append_audit_entry(
+    ctx: Context<AppendAuditEntry>,
+    page_index: u32,
+    action: AuditAction,
+    target: Option<Pubkey>,
+    context: AuditContext,
+    compliance_flags: ComplianceFlags,
+) -> Result<()> {
+    let audit_trail = &mut ctx.accounts.audit_trail;
+    let page = &mut ctx.accounts.page;
+    let clock = Clock::get()?;
+
+    // A zeroed trail key means the page was just created
+    if page.audit_trail == Pubkey::default() {
+        page.audit_trail = audit_trail.key();
+        page.entries = Vec::new();
+        page.bump = ctx.bumps.page;
+        open_page(
+            &mut audit_trail.entry_pages,
+            page.key(),
+            &mut page.link,
+            ctx.accounts.previous_page.as_mut().map(|p| &mut p.link),
+            page_index,
+        )?;
+    }
+
+    let entry_id = audit_trail.append_paged_entry(
+        page,
+        action,
+        ctx.accounts.authority.key(),
+        target,
+        context,
+        compliance_flags,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(AuditEntryAppendedEvent {
+        audit_trail: audit_trail.key(),
+        page: page.key(),
+        entry_id,
+        action,
+        entry_hash: audit_trail.last_entry_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Queue a transaction in the multisig's tail page
+pub fn queue_multisig_transaction(
+    ctx: Context<QueueMultisigTransaction>,
+    page_index: u32,
+    transaction_type: TransactionType,
+    target: Pubkey,
+    data_hash: [u8; 32],
+    priority: TransactionPriority,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let page = &mut ctx.accounts.page;
+    let clock = Clock::get()?;
+
+    // A zeroed multisig key means the page was just created
+    if page.multisig == Pubkey::default() {
+        page.multisig = multisig.key();
+        page.transactions = Vec::new();
+        page.bump = ctx.bumps.page;
+        open_page(
+            &mut multisig.pending_pages,
+            page.key(),
+            &mut page.link,
+            ctx.accounts.previous_page.as_mut().map(|p| &mut p.link),
+            page_index,
+        )?;
+    }
+
+    let transaction_id = multisig.queue_paged_transaction(
+        page,
+        ctx.accounts.proposer.key(),
+        transaction_type,
+        target,
+        data_hash,
+        priority,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(MultisigTransactionQueuedEvent {
+        multisig: multisig.key(),
+        page: page.key(),
+        transaction_id,
+        transaction_type,
+        proposer: ctx.accounts.proposer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    emit_if_timelocked(multisig, page, transaction_id)?;
+
+    Ok(())
+}
+
+/// Approve a queued transaction, starting its timelock once fully approved
+pub fn approve_multisig_transaction(
+    ctx: Context<SignMultisigTransaction>,
+    _page_index: u32,
+    transaction_id: u64,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let page = &mut ctx.accounts.page;
+    let signer = ctx.accounts.signer.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let fully_approved = multisig.approve_paged_transaction(page, transaction_id, signer, now)?;
+    let approvals = page.transaction_mut(transaction_id)?.approvals.len() as u8;
+
+    emit!(MultisigTransactionApprovedEvent {
+        multisig: multisig.key(),
+        page: page.key(),
+        transaction_id,
+        signer,
+        approvals,
+        fully_approved,
+        timestamp: now,
+    });
+    emit_if_timelocked(multisig, page, transaction_id)?;
+
+    Ok(())
+}
+
+/// Execute an approved transaction once its timelock has passed
+///
+/// The transaction data must hash to the `data_hash` it was queued with.
+/// Transfer-type data starts with a Borsh-encoded `SpendIntent`, which is
+/// checked against the multisig's daily limits.
+pub fn execute_multisig_transaction(
+    ctx: Context<ExecuteMultisigTransaction>,
+    _page_index: u32,
+    transaction_id: u64,
+    data: Vec<u8>,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let page = &mut ctx.accounts.page;
+    let executor = ctx.accounts.signer.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let data_hash = hashv(&[&data]).to_bytes();
+    let spend = SpendIntent::deserialize(&mut data.as_slice()).ok();
+    multisig.execute_paged_transaction(page, transaction_id, executor, data_hash, spend, now)?;
+    let page_key = page.key();
+    let entry = page.transaction_mut(transaction_id)?;
+
+    emit!(MultisigTransactionExecutedEvent {
+        multisig: multisig.key(),
+        page: page_key,
+        transaction_id,
+        transaction_type: entry.transaction_type,
+        target: entry.target,
+        data_hash,
+        executor,
+        timestamp: now,
+    });
+
+    msg!("Multisig {} executed transaction {}", multisig.key(), transaction_id);
+    Ok(())
+}
+
+/// Veto a queued transaction, at the latest while its timelock is running
+pub fn cancel_multisig_transaction(
+    ctx: Context<CancelMultisigTransaction>,
+    _page_index: u32,
+    transaction_id: u64,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let page = &mut ctx.accounts.page;
+    let now = Clock::get()?.unix_timestamp;
+
+    multisig.cancel_paged_transaction(page, transaction_id, ctx.remaining_accounts, now)?;
+
+    emit!(MultisigTransactionCancelledEvent {
+        multisig: multisig.key(),
+        page: page.key(),
+        transaction_id,
+        vetoes: multisig.approval_weight(ctx.remaining_accounts) as u8,
+        timestamp: now,
+    });
+
+    msg!("Multisig {} cancelled transaction {}", multisig.key(), transaction_id);
+    Ok(())
+}
+
+/// Set how many signers it takes to veto a queued transaction
+pub fn set_veto_quorum(ctx: Context<UpdateMultisigSigners>, veto_quorum: u8) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer_change_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.set_veto_quorum(veto_quorum, now)?;
+
+    emit!(MultisigVetoQuorumUpdatedEvent {
+        multisig: multisig.key(),
+        veto_quorum: multisig.veto_quorum(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Multisig {} veto quorum set to {}",
+        multisig.key(),
+        multisig.veto_quorum()
+    );
+    Ok(())
+}
+
+/// Weight signers unequally, with the threshold restated in weight units
+pub fn set_signer_weights(
+    ctx: Context<SetSignerWeights>,
+    weights: Vec<u8>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer_change_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.set_signer_weights(weights, threshold, now)?;
+
+    emit!(MultisigSignerWeightsUpdatedEvent {
+        multisig: multisig.key(),
+        weights: multisig.signer_weights.clone(),
+        threshold,
+        total_weight: multisig.total_weight() as u8,
+        timestamp: now,
+    });
+
+    msg!(
+        "Multisig {} threshold set to {} of {} weight",
+        multisig.key(),
+        threshold,
+        multisig.total_weight()
+    );
+    Ok(())
+}
+
+/// Add a signer to the multisig
+pub fn add_signer(ctx: Context<UpdateMultisigSigners>, signer: Pubkey) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer_change_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.add_signer(signer, now)?;
+
+    emit_signers_updated(multisig, TransactionType::SignerAddition, Some(signer), now);
+    msg!("Signer {} added to multisig {}", signer, multisig.key());
+    Ok(())
+}
+
+/// Remove a signer from the multisig
+pub fn remove_signer(ctx: Context<UpdateMultisigSigners>, signer: Pubkey) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer_change_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.remove_signer(signer, now)?;
+
+    emit_signers_updated(multisig, TransactionType::SignerRemoval, Some(signer), now);
+    msg!("Signer {} removed from multisig {}", signer, multisig.key());
+    Ok(())
+}
+
+/// Set the multisig's approval threshold
+pub fn change_threshold(ctx: Context<UpdateMultisigSigners>, threshold: u8) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer_change_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.change_threshold(threshold, now)?;
+
+    emit_signers_updated(multisig, TransactionType::ThresholdUpdate, None, now);
+    msg!(
+        "Multisig {} threshold set to {} of {}",
+        multisig.key(),
+        threshold,
+        multisig.signers.len()
+    );
+    Ok(())
+}
+
 /// Create a new multisig account
 pub fn create_multisig(
     ctx: Context<CreateMultisig>,
@@ -238,6 +790,11 @@ pub fn create_multisig_with_type(
         GhostSpeakError::InvalidConfiguration
     );
 
+    require!(
+        config.daily_limits.len() <= MAX_DAILY_LIMITS,
+        GhostSpeakError::InvalidConfiguration
+    );
+
     // Validate signer count against type requirements
     let signer_count = signers.len() as u8;
     require!(
@@ -304,6 +861,7 @@ pub fn initialize_governance_proposal(
     description: String,
     proposal_type: ProposalType,
     execution_params: ExecutionParams,
+    snapshot_strategy: SnapshotStrategy,
 ) -> Result<()> {
     require!(title.len() <= 100, GhostSpeakError::TitleTooLong);
 
@@ -324,6 +882,7 @@ pub fn initialize_governance_proposal(
     proposal.created_at = clock.unix_timestamp;
     proposal.voting_starts_at = clock.unix_timestamp;
     proposal.voting_ends_at = clock.unix_timestamp + 7 * 24 * 60 * 60; // 7 days
+    proposal.set_snapshot(snapshot_strategy)?;
     proposal.execution_timestamp = None;
     proposal.voting_results = VotingResults {
         votes_for: 0,
@@ -353,10 +912,171 @@ pub fn initialize_governance_proposal(
         implementation_timeline: None,
     };
 
+    // The deposit sits on the proposal account until it's decided
+    let deposit = ctx.accounts.deposit_config.minimum_deposit;
+    if deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.proposer.to_account_info(),
+                    to: proposal.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+    proposal.deposit = deposit;
+
     msg!("Governance proposal {} created", proposal_id);
     Ok(())
 }
 
+/// Record a voter's enhanced voting power as of the proposal's snapshot point
+///
+/// Only stake held unchanged since the snapshot point (staking again or
+/// migrating the stake moves `held_since`) and the reputation of an agent registered before it count.
+/// Liquid balances are left out because tokens could be moved to snapshot
+/// again from another wallet, and 30-day x402 volume isn't tracked on-chain.
+pub fn snapshot_voting_power(ctx: Context<SnapshotVotingPower>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let voter = ctx.accounts.voter.key();
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        proposal.status == ProposalStatus::Active,
+        GhostSpeakError::ProposalNotActive
+    );
+    require!(now < proposal.voting_ends_at, GhostSpeakError::VotingEnded);
+
+    let mut inputs = VotingPowerInput::default();
+    if let Some(staking) = &ctx.accounts.staking_account {
+        if staking.held_since() <= proposal.snapshot_at {
+            inputs.staked_balance = staking.amount_staked;
+            inputs.lockup_duration = staking.lock_duration;
+        }
+    }
+    if let Some(agent) = &ctx.accounts.agent {
+        if agent.created_at <= proposal.snapshot_at {
+            // Agent reputation is on a 0-100 scale
+            inputs.reputation_score = (agent.reputation_score.min(100) * 100) as u16;
+            inputs.is_verified_agent = agent.is_verified;
+        }
+    }
+
+    let power = calculate_enhanced_voting_power(&inputs);
+    require!(power.can_vote, GhostSpeakError::InsufficientVotingPower);
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.proposal = proposal.key();
+    snapshot.voter = voter;
+    snapshot.inputs = inputs;
+    snapshot.taken_at = now;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(VotingPowerSnapshotEvent {
+        proposal: proposal.key(),
+        voter,
+        snapshot_at: proposal.snapshot_at,
+        voting_power: power.effective_power,
+        staking_power: power.staking_power,
+        reputation_power: power.reputation_power,
+    });
+
+    msg!(
+        "Voting power {} snapshotted for proposal {}",
+        power.effective_power,
+        proposal.proposal_id
+    );
+    Ok(())
+}
+
+/// Vote on a proposal with the power recorded in the voter's snapshot
+pub fn cast_vote(ctx: Context<CastVote>, choice: VoteChoice) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let voter = ctx.accounts.voter.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let power = calculate_enhanced_voting_power(&ctx.accounts.snapshot.inputs);
+    require!(power.can_vote, GhostSpeakError::InsufficientVotingPower);
+
+    proposal.record_vote(choice, power.effective_power, now)?;
+
+    let vote = &mut ctx.accounts.vote;
+    vote.proposal = proposal.key();
+    vote.voter = voter;
+    vote.choice = choice;
+    vote.voting_power = power.effective_power;
+    vote.voted_at = now;
+    vote.bump = ctx.bumps.vote;
+
+    emit!(VoteCastEvent {
+        proposal: proposal.key(),
+        voter,
+        choice,
+        voting_power: power.effective_power,
+        staking_power: power.staking_power,
+        reputation_power: power.reputation_power,
+        timestamp: now,
+    });
+
+    msg!(
+        "Vote {:?} cast on proposal {} with power {}",
+        choice,
+        proposal.proposal_id,
+        power.effective_power
+    );
+    Ok(())
+}
+
+/// Decide a proposal once voting has ended
+pub fn tally_votes(ctx: Context<TallyVotes>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    let status = proposal.tally(now)?;
+    let results = &proposal.voting_results;
+
+    emit!(ProposalTalliedEvent {
+        proposal: proposal.key(),
+        status,
+        votes_for: results.votes_for,
+        votes_against: results.votes_against,
+        votes_abstain: results.votes_abstain,
+        quorum_reached: results.quorum_reached,
+        execution_timestamp: proposal.execution_timestamp,
+    });
+
+    msg!("Proposal {} tallied: {:?}", proposal.proposal_id, status);
+    Ok(())
+}
+
+/// Mark a passed proposal executed
+///
+/// The execution authority carries out the proposal's instructions; this
+/// records that the execution delay was respected.
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let executor = ctx.accounts.executor.key();
+    let authority = proposal.execution_params.execution_authority;
+    require!(
+        authority == Pubkey::default() || authority == executor,
+        GhostSpeakError::UnauthorizedAccess
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    proposal.mark_executed(now)?;
+
+    emit!(ProposalExecutedEvent {
+        proposal: proposal.key(),
+        executor,
+        instruction_count: proposal.execution_params.instructions.len() as u8,
+        timestamp: now,
+    });
+
+    msg!("Proposal {} executed", proposal.proposal_id);
+    Ok(())
+}
+
 /// Initialize RBAC configuration
 pub fn initialize_rbac_config(
     ctx: Context<InitializeRbacConfig>,
@@ -596,6 +1316,45 @@ pub fn verify_compliance_report_signature(
     Ok(first_half_valid && second_half_valid)
 }
 
+/// Emit the queued event for a transaction whose timelock just started
+fn emit_if_timelocked(
+    multisig: &Account<Multisig>,
+    page: &mut Account<PendingTransactionPage>,
+    transaction_id: u64,
+) -> Result<()> {
+    let page_key = page.key();
+    let entry = page.transaction_mut(transaction_id)?;
+    if entry.status != TransactionStatus::FullyApproved {
+        return Ok(());
+    }
+    if let Some(time_lock) = &entry.time_lock {
+        emit!(MultisigTransactionTimelockQueuedEvent {
+            multisig: multisig.key(),
+            page: page_key,
+            transaction_id,
+            locked_at: time_lock.locked_at,
+            unlocks_at: time_lock.unlocks_at,
+        });
+    }
+    Ok(())
+}
+
+fn emit_signers_updated(
+    multisig: &Account<Multisig>,
+    change: TransactionType,
+    signer: Option<Pubkey>,
+    timestamp: i64,
+) {
+    emit!(MultisigSignersUpdatedEvent {
+        multisig: multisig.key(),
+        change,
+        signer,
+        threshold: multisig.threshold,
+        signer_count: multisig.signers.len() as u8,
+        timestamp,
+    });
+}
+
 // =====================================================
 // EVENTS
 // =====================================================
@@ -639,3 +1398,131 @@ pub struct ComplianceReportGeneratedEvent {
     pub report_type: ReportType,
     pub compliance_rate: u64,
 }
+
+#[event]
+pub struct AuditEntryAppendedEvent {
+    pub audit_trail: Pubkey,
+    pub page: Pubkey,
+    pub entry_id: u64,
+    pub action: AuditAction,
+    pub entry_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionQueuedEvent {
+    pub multisig: Pubkey,
+    pub page: Pubkey,
+    pub transaction_id: u64,
+    pub transaction_type: TransactionType,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigSignersUpdatedEvent {
+    pub multisig: Pubkey,
+    pub change: TransactionType,
+    pub signer: Option<Pubkey>,
+    pub threshold: u8,
+    pub signer_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionApprovedEvent {
+    pub multisig: Pubkey,
+    pub page: Pubkey,
+    pub transaction_id: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub fully_approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionTimelockQueuedEvent {
+    pub multisig: Pubkey,
+    pub page: Pubkey,
+    pub transaction_id: u64,
+    pub locked_at: i64,
+    pub unlocks_at: i64,
+}
+
+#[event]
+pub struct MultisigTransactionExecutedEvent {
+    pub multisig: Pubkey,
+    pub page: Pubkey,
+    pub transaction_id: u64,
+    pub transaction_type: TransactionType,
+    pub target: Pubkey,
+    pub data_hash: [u8; 32],
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionCancelledEvent {
+    pub multisig: Pubkey,
+    pub page: Pubkey,
+    pub transaction_id: u64,
+    pub vetoes: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigVetoQuorumUpdatedEvent {
+    pub multisig: Pubkey,
+    pub veto_quorum: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigSignerWeightsUpdatedEvent {
+    pub multisig: Pubkey,
+    /// Empty when signers went back to equal weight
+    pub weights: Vec<u8>,
+    pub threshold: u8,
+    pub total_weight: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingPowerSnapshotEvent {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub snapshot_at: i64,
+    pub voting_power: u64,
+    pub staking_power: u64,
+    pub reputation_power: u64,
+}
+
+#[event]
+pub struct VoteCastEvent {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub choice: VoteChoice,
+    pub voting_power: u64,
+    pub staking_power: u64,
+    pub reputation_power: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalTalliedEvent {
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub votes_abstain: u64,
+    pub quorum_reached: bool,
+    pub execution_timestamp: Option<i64>,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub instruction_count: u8,
+    pub timestamp: i64,
+}
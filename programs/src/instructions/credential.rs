@@ -7,13 +7,40 @@
  */
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use sha3::{Digest, Keccak256};
 
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::state::did::{DidDocument, DidError, VerificationRelationship, DID_DOCUMENT_SEED};
 use crate::state::{
-    Credential, CredentialKind, CredentialStatus, CredentialTemplate, CredentialType,
-    CrossChainStatus, MAX_CREDENTIAL_ID, MAX_CREDENTIAL_NAME, MAX_SCHEMA_URI,
-    CREDENTIAL_TYPE_SEED, CREDENTIAL_TEMPLATE_SEED, CREDENTIAL_SEED,
+    AccessTier, Credential, CredentialAcceptedEvent, CredentialClosedEvent, CredentialCoSignedEvent,
+    CredentialExpiredEvent, CredentialIssuanceFeePaidEvent, CredentialKind, CredentialPresentation,
+    CredentialPresentedEvent, CredentialReboundEvent, CredentialSchema,
+    CredentialSchemaDeprecatedEvent, CredentialSchemaPublishedEvent, CredentialStatus,
+    CredentialStatusList, CredentialTemplate, CredentialTemplateForkedEvent, CredentialType,
+    CredentialVerification, CredentialVerifiedEvent, CrossChainAttestation,
+    CrossChainAttestationRecordedEvent, CrossChainStatus, CrossChainStatusChangedEvent,
+    CrossChainUpdate, CustomCredentialKind, CustomCredentialKindRegisteredEvent,
+    DelegatedCredentialIssuedEvent, GatedAction, HolderPolicy, IssuanceDelegatedEvent, IssuanceDelegation,
+    IssuerRegistry, IssuerRegistryUpdatedEvent, IssuerTrustTier, ProtocolConfig, RegisteredIssuer,
+    StakingAccount, StakingConfig, SubjectCredentialIndex, SubjectCredentialPage, TemplateRoyaltyPaidEvent,
+    CREDENTIAL_PRESENTATION_SEED, CREDENTIAL_SCHEMA_SEED, CREDENTIAL_SEED,
+    CREDENTIAL_STATUS_LIST_SEED, CREDENTIAL_TEMPLATE_SEED, CREDENTIAL_TYPE_SEED,
+    CREDENTIAL_VERIFICATION_SEED, CROSSCHAIN_RECORD_SEED, CUSTOM_CREDENTIAL_KIND_SEED,
+    HOLDER_POLICY_SEED, ISSUANCE_DELEGATION_SEED, ISSUER_REGISTRY_SEED, MAX_CO_ISSUERS,
+    MAX_CREDENTIAL_ID, MAX_CREDENTIAL_NAME, MAX_MIGRATION_NOTES, MAX_PRESENTATION_PURPOSE,
+    MAX_SCHEMA_URI, MAX_TEMPLATE_ROYALTY_LAMPORTS, SUBJECT_CREDENTIAL_INDEX_SEED,
+    SUBJECT_CREDENTIAL_PAGE_SEED,
 };
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::denylist::DENYLIST_ENTRY_SEED;
+use crate::state::fee_exemption::{is_fee_exempt, FeeWaivedEvent, FEE_EXEMPTION_LIST_SEED};
+use crate::state::fee_vault::{FeeAccruedEvent, FeeSource, FeeVault, FEE_VAULT_SEED};
+use crate::security::{RateLimiter, UserRateLimit, OPERATION_CREDENTIAL_ISSUANCE};
+use crate::state::governance::Multisig;
+use crate::state::Agent;
+use crate::utils::{require_ed25519_signature, verified_ed25519_signature};
 use crate::GhostSpeakError;
 
 /// Hash output wrapper
@@ -32,6 +59,53 @@ fn hash(data: &[u8]) -> HashOutput {
     HashOutput(hasher.finalize().into())
 }
 
+/// Require that `holder` controls the credential subject `subject`
+///
+/// The holder is the subject itself or, when the subject is an agent, the
+/// agent's owner (passed as `subject_agent`).
+fn require_holder(
+    subject: &Pubkey,
+    holder: &Pubkey,
+    subject_agent: Option<&Account<Agent>>,
+) -> Result<()> {
+    let holds_via_agent = subject_agent
+        .is_some_and(|agent| agent.key() == *subject && agent.owner == Some(*holder));
+    require!(
+        subject == holder || holds_via_agent,
+        GhostSpeakError::UnauthorizedAccess
+    );
+    Ok(())
+}
+
+/// Moves lamports from a signer with a system program transfer
+fn transfer_lamports<'info>(
+    system_program: &Program<'info, System>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer { from, to },
+        ),
+        amount,
+    )
+}
+
+/// Whether the subject's holder policy holds new credentials for acceptance
+///
+/// Subjects that never created a policy accept credentials directly.
+pub(crate) fn requires_acceptance(holder_policy: &UncheckedAccount) -> Result<bool> {
+    let info = holder_policy.to_account_info();
+    if info.data_is_empty() {
+        return Ok(false);
+    }
+    require!(info.owner == &crate::ID, GhostSpeakError::InvalidAccountOwner);
+    let policy = HolderPolicy::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    Ok(policy.require_acceptance)
+}
+
 // ============================================================================
 // Create Credential Type
 // ============================================================================
@@ -49,6 +123,15 @@ pub fn create_credential_type(
     require!(schema_uri.len() <= MAX_SCHEMA_URI, GhostSpeakError::InvalidMetadataUri);
     require!(description.len() <= 256, GhostSpeakError::DescriptionTooLong);
 
+    // Registered custom kinds belong to the ecosystem that registered them
+    if kind.registered_custom_id().is_some() {
+        ctx.accounts
+            .custom_kind
+            .as_ref()
+            .ok_or(GhostSpeakError::CustomKindNotRegistered)?
+            .authorize(kind, &ctx.accounts.authority.key())?;
+    }
+
     let clock = Clock::get()?;
     let credential_type = &mut ctx.accounts.credential_type;
 
@@ -60,8 +143,19 @@ pub fn create_credential_type(
     credential_type.is_active = true;
     credential_type.total_issued = 0;
     credential_type.created_at = clock.unix_timestamp;
+    credential_type.schema_version = CredentialSchema::INITIAL_VERSION;
     credential_type.bump = ctx.bumps.credential_type;
 
+    let schema = &mut ctx.accounts.schema;
+    schema.credential_type = credential_type.key();
+    schema.version = CredentialSchema::INITIAL_VERSION;
+    schema.schema_uri = credential_type.schema_uri.clone();
+    schema.migration_notes = String::new();
+    schema.deprecated = false;
+    schema.published_at = clock.unix_timestamp;
+    schema.deprecated_at = None;
+    schema.bump = ctx.bumps.schema;
+
     msg!("Created credential type: {}", credential_type.name);
     Ok(())
 }
@@ -78,12 +172,155 @@ pub struct CreateCredentialType<'info> {
     )]
     pub credential_type: Account<'info, CredentialType>,
 
+    /// Version 1 entry in the schema registry
+    #[account(
+        init,
+        payer = authority,
+        space = CredentialSchema::LEN,
+        seeds = [
+            CREDENTIAL_SCHEMA_SEED,
+            credential_type.key().as_ref(),
+            &CredentialSchema::INITIAL_VERSION.to_le_bytes()
+        ],
+        bump
+    )]
+    pub schema: Account<'info, CredentialSchema>,
+
+    /// Registry entry for `CredentialKind::Custom` IDs other than 0
+    pub custom_kind: Option<Account<'info, CustomCredentialKind>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// Schema Registry
+// ============================================================================
+
+/// Publishes the next schema version of a credential type and makes it current.
+///
+/// Credentials already issued keep their recorded `schema_version`.
+pub fn publish_credential_schema(
+    ctx: Context<PublishCredentialSchema>,
+    version: u16,
+    schema_uri: String,
+    migration_notes: String,
+) -> Result<()> {
+    require!(schema_uri.len() <= MAX_SCHEMA_URI, GhostSpeakError::InvalidMetadataUri);
+    require!(
+        migration_notes.len() <= MAX_MIGRATION_NOTES,
+        GhostSpeakError::DescriptionTooLong
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let credential_type = &mut ctx.accounts.credential_type;
+    require!(
+        Some(version) == credential_type.schema_version.checked_add(1),
+        GhostSpeakError::InvalidSchemaVersion
+    );
+
+    let schema = &mut ctx.accounts.schema;
+    schema.credential_type = credential_type.key();
+    schema.version = version;
+    schema.schema_uri = schema_uri.clone();
+    schema.migration_notes = migration_notes;
+    schema.deprecated = false;
+    schema.published_at = now;
+    schema.deprecated_at = None;
+    schema.bump = ctx.bumps.schema;
+
+    credential_type.schema_uri = schema_uri.clone();
+    credential_type.schema_version = version;
+
+    emit!(CredentialSchemaPublishedEvent {
+        credential_type: credential_type.key(),
+        schema: schema.key(),
+        version,
+        schema_uri,
+        timestamp: now,
+    });
+
+    msg!(
+        "Published schema v{} for credential type {}",
+        version,
+        credential_type.name
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(version: u16)]
+pub struct PublishCredentialSchema<'info> {
+    #[account(
+        mut,
+        constraint = credential_type.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub credential_type: Account<'info, CredentialType>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CredentialSchema::LEN,
+        seeds = [
+            CREDENTIAL_SCHEMA_SEED,
+            credential_type.key().as_ref(),
+            &version.to_le_bytes()
+        ],
+        bump
+    )]
+    pub schema: Account<'info, CredentialSchema>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Flags a superseded schema version as deprecated.
+///
+/// The current version cannot be deprecated; publish its successor first.
+pub fn deprecate_credential_schema(ctx: Context<DeprecateCredentialSchema>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let schema = &mut ctx.accounts.schema;
+
+    require!(
+        schema.version != ctx.accounts.credential_type.schema_version,
+        GhostSpeakError::CannotDeprecateCurrentSchema
+    );
+    require!(!schema.deprecated, GhostSpeakError::InvalidState);
+
+    schema.deprecated = true;
+    schema.deprecated_at = Some(now);
+
+    emit!(CredentialSchemaDeprecatedEvent {
+        credential_type: schema.credential_type,
+        schema: schema.key(),
+        version: schema.version,
+        timestamp: now,
+    });
+
+    msg!("Deprecated schema v{}", schema.version);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeprecateCredentialSchema<'info> {
+    #[account(
+        constraint = credential_type.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub credential_type: Account<'info, CredentialType>,
+
+    #[account(
+        mut,
+        constraint = schema.credential_type == credential_type.key() @ GhostSpeakError::InvalidInput
+    )]
+    pub schema: Account<'info, CredentialSchema>,
+
+    pub authority: Signer<'info>,
+}
+
 // ============================================================================
 // Create Credential Template
 // ============================================================================
@@ -94,6 +331,7 @@ pub fn create_credential_template(
     name: String,
     image_uri: String,
     crossmint_template_id: Option<String>,
+    issuance_fee_lamports: u64,
 ) -> Result<()> {
     require!(name.len() <= MAX_CREDENTIAL_NAME, GhostSpeakError::NameTooLong);
     require!(image_uri.len() <= MAX_SCHEMA_URI, GhostSpeakError::InvalidMetadataUri);
@@ -112,6 +350,12 @@ pub fn create_credential_template(
     template.total_issued = 0;
     template.created_at = clock.unix_timestamp;
     template.crossmint_template_id = crossmint_template_id;
+    template.forked_from = None;
+    template.original_author = None;
+    template.royalty_lamports = 0;
+    template.fork_count = 0;
+    template.co_issuers = Vec::new();
+    template.issuance_fee_lamports = issuance_fee_lamports;
     template.bump = ctx.bumps.credential_template;
 
     msg!("Created credential template: {}", template.name);
@@ -145,6 +389,101 @@ pub struct CreateCredentialTemplate<'info> {
     pub system_program: Program<'info, System>,
 }
 
+// ============================================================================
+// Fork Credential Template
+// ============================================================================
+
+/// Creates a template that reuses another template's credential type.
+///
+/// Provenance is recorded on the fork; `royalty_lamports` (optional, capped)
+/// is paid by the fork's issuer to the root template author on each issuance.
+pub fn fork_credential_template(
+    ctx: Context<ForkCredentialTemplate>,
+    name: String,
+    image_uri: String,
+    crossmint_template_id: Option<String>,
+    royalty_lamports: u64,
+) -> Result<()> {
+    require!(name.len() <= MAX_CREDENTIAL_NAME, GhostSpeakError::NameTooLong);
+    require!(image_uri.len() <= MAX_SCHEMA_URI, GhostSpeakError::InvalidMetadataUri);
+    require!(
+        royalty_lamports <= MAX_TEMPLATE_ROYALTY_LAMPORTS,
+        GhostSpeakError::TemplateRoyaltyTooLarge
+    );
+
+    let clock = Clock::get()?;
+    let source = &mut ctx.accounts.source_template;
+    let template = &mut ctx.accounts.credential_template;
+    let original_author = source.root_author();
+
+    template.credential_type = source.credential_type;
+    template.name = name;
+    template.image_uri = image_uri;
+    template.issuer = ctx.accounts.issuer.key();
+    template.is_active = true;
+    template.total_issued = 0;
+    template.created_at = clock.unix_timestamp;
+    template.crossmint_template_id = crossmint_template_id;
+    template.forked_from = Some(source.key());
+    template.original_author = Some(original_author);
+    template.royalty_lamports = royalty_lamports;
+    template.fork_count = 0;
+    template.co_issuers = Vec::new();
+    template.issuance_fee_lamports = 0;
+    template.bump = ctx.bumps.credential_template;
+
+    source.fork_count = source
+        .fork_count
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    emit!(CredentialTemplateForkedEvent {
+        template: template.key(),
+        forked_from: source.key(),
+        original_author,
+        issuer: template.issuer,
+        royalty_lamports,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Forked credential template {} from {}", template.name, source.name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct ForkCredentialTemplate<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = CredentialTemplate::LEN,
+        seeds = [
+            CREDENTIAL_TEMPLATE_SEED,
+            credential_type.key().as_ref(),
+            name.as_bytes()
+        ],
+        bump
+    )]
+    pub credential_template: Account<'info, CredentialTemplate>,
+
+    #[account(
+        mut,
+        constraint = source_template.is_active @ GhostSpeakError::InvalidState,
+        constraint = source_template.credential_type == credential_type.key()
+    )]
+    pub source_template: Account<'info, CredentialTemplate>,
+
+    #[account(
+        constraint = credential_type.is_active @ GhostSpeakError::InvalidState
+    )]
+    pub credential_type: Account<'info, CredentialType>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 // ============================================================================
 // Issue Credential
 // ============================================================================
@@ -161,15 +500,68 @@ pub fn issue_credential(
 ) -> Result<()> {
     require!(credential_id.len() <= MAX_CREDENTIAL_ID, GhostSpeakError::InvalidInput);
     require!(subject_data_uri.len() <= MAX_SCHEMA_URI, GhostSpeakError::InvalidMetadataUri);
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.issuer.key(),
+        OPERATION_CREDENTIAL_ISSUANCE,
+        ctx.bumps.user_rate_limit,
+    )?;
 
     let clock = Clock::get()?;
     let template = &mut ctx.accounts.credential_template;
     let credential_type = &mut ctx.accounts.credential_type;
+    let status_list = &mut ctx.accounts.status_list;
     let credential = &mut ctx.accounts.credential;
 
     require!(template.is_active, GhostSpeakError::InvalidState);
     require!(credential_type.is_active, GhostSpeakError::InvalidState);
 
+    // Operators issue on behalf of the template issuer within their caps
+    if let Some(delegation) = ctx.accounts.issuance_delegation.as_mut() {
+        let remaining = delegation.consume(clock.unix_timestamp)?;
+        emit!(DelegatedCredentialIssuedEvent {
+            delegation: delegation.key(),
+            credential: credential.key(),
+            operator: delegation.operator,
+            remaining,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Protocol-reserved kinds are checked against the governed issuer registry
+    if credential_type.kind.is_protocol_reserved() {
+        ctx.accounts
+            .issuer_registry
+            .as_ref()
+            .ok_or(GhostSpeakError::IssuerRegistryRequired)?
+            .authorize_issuance(&template.issuer, credential_type.kind)?;
+    }
+
+    // The template issuer's stake must meet the governed tier gate
+    let tier_schedule = &ctx.accounts.staking_config.tier_schedule;
+    let issuer_tier = ctx
+        .accounts
+        .issuer_staking
+        .as_ref()
+        .map_or(AccessTier::None, |staking| staking.current_tier(tier_schedule));
+    ctx.accounts
+        .protocol_config
+        .require_tier(GatedAction::IssueCredential, issuer_tier)?;
+
+    status_list.initialize_if_needed(credential_type.key(), ctx.bumps.status_list);
+    let status_index = status_list.allocate()?;
+
+    let subject_index = &mut ctx.accounts.subject_index;
+    let subject_index_page = &mut ctx.accounts.subject_index_page;
+    subject_index.initialize_if_needed(ctx.accounts.subject.key(), ctx.bumps.subject_index);
+    subject_index_page.initialize_if_needed(
+        ctx.accounts.subject.key(),
+        subject_index.open_page(),
+        ctx.bumps.subject_index_page,
+    );
+    subject_index_page.push(credential.key())?;
+    subject_index.record_append()?;
+
     // Validate expiry is in the future if set
     if let Some(exp) = expires_at {
         require!(exp > clock.unix_timestamp, GhostSpeakError::InvalidInput);
@@ -190,11 +582,11 @@ pub fn issue_credential(
     
     credential.template = template.key();
     credential.subject = ctx.accounts.subject.key();
-    credential.issuer = ctx.accounts.issuer.key();
+    credential.issuer = template.issuer;
     credential.credential_id = credential_id.clone();
     credential.subject_data_hash = subject_data_hash;
     credential.subject_data_uri = subject_data_uri;
-    credential.status = CredentialStatus::Active;
+    credential.status = CredentialStatus::Pending;
     credential.signature = signature;
     credential.issued_at = clock.unix_timestamp;
     credential.expires_at = expires_at;
@@ -202,7 +594,129 @@ pub fn issue_credential(
     credential.cross_chain_status = CrossChainStatus::NotSynced;
     credential.crossmint_credential_id = None;
     credential.source_account = source_account;
+    credential.cross_chain_attempts = 0;
+    credential.cross_chain_retry_at = 0;
+    credential.cross_chain_failure = None;
+    credential.status_list = status_list.key();
+    credential.status_index = status_index;
+    credential.schema_version = credential_type.schema_version;
+    credential.pending_co_issuers = template.co_issuers.clone();
+    credential.awaiting_acceptance = requires_acceptance(&ctx.accounts.holder_policy)?;
+    credential.subject_index_page = subject_index_page.key();
     credential.bump = ctx.bumps.credential;
+    credential.activate_if_ready();
+
+    // Forked templates pay their pledged royalty to the original author
+    if let Some((author, amount)) = template.royalty_due() {
+        let recipient = ctx
+            .accounts
+            .royalty_recipient
+            .as_ref()
+            .filter(|r| r.key() == author)
+            .ok_or(GhostSpeakError::RoyaltyRecipientRequired)?;
+
+        transfer_lamports(
+            &ctx.accounts.system_program,
+            ctx.accounts.issuer.to_account_info(),
+            recipient.to_account_info(),
+            amount,
+        )?;
+
+        emit!(TemplateRoyaltyPaidEvent {
+            template: template.key(),
+            credential: credential.key(),
+            original_author: author,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Template issuance fee, split between the creator and the protocol fee vault
+    if template.issuance_fee_lamports > 0 {
+        let payer = ctx
+            .accounts
+            .fee_payer
+            .as_ref()
+            .ok_or(GhostSpeakError::IssuanceFeePayerRequired)?;
+        require_holder(
+            &ctx.accounts.subject.key(),
+            &payer.key(),
+            ctx.accounts.subject_agent.as_deref(),
+        )?;
+        let protocol_config = &ctx.accounts.protocol_config;
+        let exempt = is_fee_exempt(
+            &ctx.accounts.fee_exemption_list,
+            &[template.issuer],
+            FeeSource::CredentialIssuance,
+            clock.unix_timestamp,
+        )?;
+        let (creator_amount, treasury_amount) = protocol_config.calculate_credential_fee(
+            template.issuance_fee_lamports,
+            issuer_tier,
+            exempt,
+        );
+
+        if exempt {
+            let (_, waived) = protocol_config.calculate_credential_fee(
+                template.issuance_fee_lamports,
+                issuer_tier,
+                false,
+            );
+            if waived > 0 {
+                emit!(FeeWaivedEvent {
+                    subject: template.issuer,
+                    source: FeeSource::CredentialIssuance,
+                    amount: waived,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+        }
+
+        if treasury_amount > 0 {
+            let fee_vault = ctx
+                .accounts
+                .fee_vault
+                .as_mut()
+                .ok_or(GhostSpeakError::ProtocolTreasuryRequired)?;
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                payer.to_account_info(),
+                fee_vault.to_account_info(),
+                treasury_amount,
+            )?;
+            fee_vault.accrue(
+                FeeSource::CredentialIssuance,
+                treasury_amount,
+                clock.unix_timestamp,
+            )?;
+
+            emit!(FeeAccruedEvent {
+                fee_vault: fee_vault.key(),
+                source: FeeSource::CredentialIssuance,
+                amount: treasury_amount,
+                total_for_source: fee_vault.accrued[FeeSource::CredentialIssuance.index()],
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        if creator_amount > 0 {
+            transfer_lamports(
+                &ctx.accounts.system_program,
+                payer.to_account_info(),
+                ctx.accounts.template_issuer.to_account_info(),
+                creator_amount,
+            )?;
+        }
+
+        emit!(CredentialIssuanceFeePaidEvent {
+            template: template.key(),
+            credential: credential.key(),
+            payer: payer.key(),
+            creator: template.issuer,
+            creator_amount,
+            treasury_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     // Update counters
     template.total_issued += 1;
@@ -232,7 +746,11 @@ pub struct IssueCredential<'info> {
     #[account(
         mut,
         constraint = credential_template.is_active @ GhostSpeakError::InvalidState,
-        constraint = credential_template.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess
+        constraint = credential_template.issuer == issuer.key()
+            || issuance_delegation
+                .as_ref()
+                .is_some_and(|d| d.delegator == credential_template.issuer)
+            @ GhostSpeakError::UnauthorizedAccess
     )]
     pub credential_template: Account<'info, CredentialTemplate>,
 
@@ -243,84 +761,991 @@ pub struct IssueCredential<'info> {
     )]
     pub credential_type: Account<'info, CredentialType>,
 
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = CredentialStatusList::LEN,
+        seeds = [CREDENTIAL_STATUS_LIST_SEED, credential_type.key().as_ref()],
+        bump
+    )]
+    pub status_list: Account<'info, CredentialStatusList>,
+
     /// CHECK: The subject receiving the credential, no signature required
     pub subject: UncheckedAccount<'info>,
 
+    /// CHECK: Subject's holder policy PDA; uninitialized if never created
+    #[account(seeds = [HOLDER_POLICY_SEED, subject.key().as_ref()], bump)]
+    pub holder_policy: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = SubjectCredentialIndex::LEN,
+        seeds = [SUBJECT_CREDENTIAL_INDEX_SEED, subject.key().as_ref()],
+        bump
+    )]
+    pub subject_index: Account<'info, SubjectCredentialIndex>,
+
+    /// Page the credential is listed on (the index's open page)
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = SubjectCredentialPage::LEN,
+        seeds = [
+            SUBJECT_CREDENTIAL_PAGE_SEED,
+            subject.key().as_ref(),
+            &subject_index.open_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub subject_index_page: Account<'info, SubjectCredentialPage>,
+
+    /// CHECK: Original template author; required when the template owes a royalty
     #[account(mut)]
-    pub issuer: Signer<'info>,
+    pub royalty_recipient: Option<UncheckedAccount<'info>>,
 
-    pub system_program: Program<'info, System>,
-}
+    /// Trusted issuer registry; required for protocol-reserved kinds
+    #[account(seeds = [ISSUER_REGISTRY_SEED], bump = issuer_registry.bump)]
+    pub issuer_registry: Option<Account<'info, IssuerRegistry>>,
 
-// ============================================================================
-// Revoke Credential
-// ============================================================================
+    /// Requester paying the template's issuance fee; required when it charges one
+    ///
+    /// Must be the subject or, when the subject is an agent, its owner.
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
 
-/// Revokes an issued credential. Only the original issuer can revoke.
-pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
-    let clock = Clock::get()?;
-    let credential = &mut ctx.accounts.credential;
+    /// Agent named as the subject, when the fee payer is its owner
+    pub subject_agent: Option<Box<Account<'info, Agent>>>,
 
-    require!(
-        credential.status == CredentialStatus::Active,
-        GhostSpeakError::InvalidState
-    );
+    /// CHECK: Template issuer, credited with the creator share of the issuance fee
+    #[account(mut, address = credential_template.issuer @ GhostSpeakError::UnauthorizedAccess)]
+    pub template_issuer: UncheckedAccount<'info>,
 
-    credential.revoke(clock.unix_timestamp);
+    /// Protocol config (staking tier gate and issuance fee split)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
 
-    msg!("Revoked credential {}", credential.credential_id);
-    Ok(())
-}
+    /// Staking config, whose current thresholds set the issuer's tier
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
 
-#[derive(Accounts)]
-pub struct RevokeCredential<'info> {
+    /// Template issuer's staking account, if any (checked against the tier gate)
+    #[account(
+        seeds = [b"staking", credential_template.issuer.as_ref()],
+        bump = issuer_staking.bump
+    )]
+    pub issuer_staking: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// SOL fee vault; required when the protocol takes a share of the fee
     #[account(
         mut,
-        constraint = credential.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess,
-        constraint = credential.status == CredentialStatus::Active @ GhostSpeakError::InvalidState
+        seeds = [FEE_VAULT_SEED, system_program::ID.as_ref()],
+        bump = fee_vault.bump
     )]
-    pub credential: Account<'info, Credential>,
+    pub fee_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    /// CHECK: Fee exemption list; uninitialized if never created
+    #[account(seeds = [FEE_EXEMPTION_LIST_SEED], bump)]
+    pub fee_exemption_list: UncheckedAccount<'info>,
+
+    /// Delegation letting `issuer` act as an operator for the template issuer
+    #[account(
+        mut,
+        seeds = [ISSUANCE_DELEGATION_SEED, credential_type.key().as_ref(), issuer.key().as_ref()],
+        bump = issuance_delegation.bump
+    )]
+    pub issuance_delegation: Option<Account<'info, IssuanceDelegation>>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Box<Account<'info, RateLimiter>>,
+
+    /// Per-issuer rate limit tracking (created on first use)
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            issuer.key().as_ref(),
+            OPERATION_CREDENTIAL_ISSUANCE.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Box<Account<'info, UserRateLimit>>,
+
+    /// CHECK: Subject's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, subject.key().as_ref()],
+        bump,
+        constraint = subject_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub subject_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Template issuer's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, credential_template.issuer.as_ref()],
+        bump,
+        constraint = template_issuer_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub template_issuer_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Signing issuer's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, issuer.key().as_ref()],
+        bump,
+        constraint = issuer_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub issuer_denylist_entry: UncheckedAccount<'info>,
 
+    /// Template issuer, or an operator holding an issuance delegation
     #[account(mut)]
     pub issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 // ============================================================================
-// Update Cross-Chain Status
+// Issuance Delegation
 // ============================================================================
 
-/// Updates the cross-chain sync status after syncing to Crossmint.
-pub fn update_crosschain_status(
-    ctx: Context<UpdateCrossChainStatus>,
-    crossmint_credential_id: String,
-    status: CrossChainStatus,
+/// Lets `operator` issue credentials of a type from the authority's templates,
+/// up to `max_issuances` and until `expires_at`.
+pub fn delegate_issuance(
+    ctx: Context<DelegateIssuance>,
+    max_issuances: u64,
+    expires_at: Option<i64>,
 ) -> Result<()> {
-    let credential = &mut ctx.accounts.credential;
-
-    if status == CrossChainStatus::SyncedToCrossmint {
-        credential.set_crossmint_synced(crossmint_credential_id);
-    } else {
-        credential.cross_chain_status = status;
+    let now = Clock::get()?.unix_timestamp;
+    require!(max_issuances > 0, GhostSpeakError::InvalidInput);
+    if let Some(exp) = expires_at {
+        require!(exp > now, GhostSpeakError::InvalidInput);
     }
 
+    let delegation = &mut ctx.accounts.issuance_delegation;
+    delegation.credential_type = ctx.accounts.credential_type.key();
+    delegation.delegator = ctx.accounts.authority.key();
+    delegation.operator = ctx.accounts.operator.key();
+    delegation.max_issuances = max_issuances;
+    delegation.issued = 0;
+    delegation.expires_at = expires_at;
+    delegation.created_at = now;
+    delegation.bump = ctx.bumps.issuance_delegation;
+
+    emit!(IssuanceDelegatedEvent {
+        delegation: delegation.key(),
+        credential_type: delegation.credential_type,
+        delegator: delegation.delegator,
+        operator: delegation.operator,
+        max_issuances,
+        expires_at,
+        timestamp: now,
+    });
+
     msg!(
-        "Updated cross-chain status for {} to {:?}",
-        credential.credential_id,
-        credential.cross_chain_status
+        "Delegated issuance of {} to operator {}",
+        ctx.accounts.credential_type.name,
+        delegation.operator
     );
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct UpdateCrossChainStatus<'info> {
+pub struct DelegateIssuance<'info> {
     #[account(
-        mut,
-        constraint = credential.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess
-    )]
-    pub credential: Account<'info, Credential>,
-
-    #[account(mut)]
+        init,
+        payer = authority,
+        space = IssuanceDelegation::LEN,
+        seeds = [
+            ISSUANCE_DELEGATION_SEED,
+            credential_type.key().as_ref(),
+            operator.key().as_ref()
+        ],
+        bump
+    )]
+    pub issuance_delegation: Account<'info, IssuanceDelegation>,
+
+    #[account(
+        constraint = credential_type.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub credential_type: Account<'info, CredentialType>,
+
+    /// CHECK: Operator key receiving issuance rights, no signature required
+    pub operator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Revokes an issuance delegation, returning its rent to the delegator.
+pub fn revoke_issuance_delegation(ctx: Context<RevokeIssuanceDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.issuance_delegation;
+    msg!(
+        "Revoked issuance delegation for operator {} ({} issued)",
+        delegation.operator,
+        delegation.issued
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeIssuanceDelegation<'info> {
+    #[account(
+        mut,
+        close = delegator,
+        has_one = delegator @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub issuance_delegation: Account<'info, IssuanceDelegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+// ============================================================================
+// Holder Acceptance
+// ============================================================================
+
+/// Sets whether credentials issued to a subject must be accepted first.
+pub fn set_holder_policy(ctx: Context<SetHolderPolicy>, require_acceptance: bool) -> Result<()> {
+    let subject = ctx.accounts.subject.key();
+    require_holder(
+        &subject,
+        &ctx.accounts.holder.key(),
+        ctx.accounts.subject_agent.as_ref(),
+    )?;
+
+    let policy = &mut ctx.accounts.holder_policy;
+    policy.subject = subject;
+    policy.require_acceptance = require_acceptance;
+    policy.updated_at = Clock::get()?.unix_timestamp;
+    policy.bump = ctx.bumps.holder_policy;
+
+    msg!(
+        "Holder policy for {}: require_acceptance = {}",
+        subject,
+        require_acceptance
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetHolderPolicy<'info> {
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = HolderPolicy::LEN,
+        seeds = [HOLDER_POLICY_SEED, subject.key().as_ref()],
+        bump
+    )]
+    pub holder_policy: Account<'info, HolderPolicy>,
+
+    /// CHECK: Credential subject the policy applies to
+    pub subject: UncheckedAccount<'info>,
+
+    /// Agent named as the subject, when the holder is its owner
+    pub subject_agent: Option<Account<'info, Agent>>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accepts a credential held for acceptance under the subject's policy.
+///
+/// The credential becomes active once any required co-issuers approved too.
+pub fn accept_credential(ctx: Context<AcceptCredential>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let holder = ctx.accounts.holder.key();
+    let credential = &mut ctx.accounts.credential;
+
+    require_holder(&credential.subject, &holder, ctx.accounts.subject_agent.as_ref())?;
+    require!(credential.awaiting_acceptance, GhostSpeakError::InvalidState);
+    if let Some(expires) = credential.expires_at {
+        require!(now < expires, GhostSpeakError::InvalidState);
+    }
+
+    credential.awaiting_acceptance = false;
+    credential.activate_if_ready();
+
+    emit!(CredentialAcceptedEvent {
+        credential: credential.key(),
+        subject: credential.subject,
+        holder,
+        timestamp: now,
+    });
+
+    msg!("Accepted credential {}", credential.credential_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptCredential<'info> {
+    #[account(
+        mut,
+        constraint = credential.status == CredentialStatus::Pending @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    /// Agent named as the credential subject, when the holder is its owner
+    pub subject_agent: Option<Account<'info, Agent>>,
+
+    pub holder: Signer<'info>,
+}
+
+// ============================================================================
+// Co-Issued Credentials
+// ============================================================================
+
+/// Sets the co-issuers who must approve every credential issued from a template.
+///
+/// Applies to credentials issued afterwards; an empty list disables co-issuance.
+pub fn set_template_co_issuers(
+    ctx: Context<SetTemplateCoIssuers>,
+    co_issuers: Vec<Pubkey>,
+) -> Result<()> {
+    let template = &mut ctx.accounts.credential_template;
+
+    require!(co_issuers.len() <= MAX_CO_ISSUERS, GhostSpeakError::TooManyCoIssuers);
+    for (i, co_issuer) in co_issuers.iter().enumerate() {
+        require!(
+            *co_issuer != template.issuer && !co_issuers[..i].contains(co_issuer),
+            GhostSpeakError::InvalidCoIssuers
+        );
+    }
+
+    template.co_issuers = co_issuers;
+
+    msg!(
+        "Template {} now requires {} co-issuer(s)",
+        template.name,
+        template.co_issuers.len()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTemplateCoIssuers<'info> {
+    #[account(
+        mut,
+        constraint = credential_template.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub credential_template: Account<'info, CredentialTemplate>,
+
+    pub issuer: Signer<'info>,
+}
+
+/// Records a co-issuer's approval of a pending credential.
+///
+/// The credential becomes active with the last approval, unless the holder
+/// still has to accept it.
+pub fn cosign_credential(ctx: Context<CosignCredential>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let co_issuer = ctx.accounts.co_issuer.key();
+    let credential = &mut ctx.accounts.credential;
+
+    credential.record_co_issuer_approval(&co_issuer)?;
+
+    emit!(CredentialCoSignedEvent {
+        credential: credential.key(),
+        co_issuer,
+        remaining: credential.pending_co_issuers.len() as u8,
+        activated: credential.status == CredentialStatus::Active,
+        timestamp: now,
+    });
+
+    msg!("Co-signed credential {}", credential.credential_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CosignCredential<'info> {
+    #[account(
+        mut,
+        constraint = credential.status == CredentialStatus::Pending @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    pub co_issuer: Signer<'info>,
+}
+
+// ============================================================================
+// Re-bind Credential
+// ============================================================================
+
+/// Re-binds an active credential to a new subject (e.g. after a DID or owner
+/// key rotation), signed jointly by the issuer and the current holder.
+///
+/// The credential keeps its address, ID, signature and issuance timestamps;
+/// the previous subject is recorded on the credential and in the event.
+pub fn rebind_credential(ctx: Context<RebindCredential>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let holder = ctx.accounts.holder.key();
+    let new_subject = ctx.accounts.new_subject.key();
+    let credential = &mut ctx.accounts.credential;
+
+    require_holder(&credential.subject, &holder, ctx.accounts.subject_agent.as_ref())?;
+    require!(credential.is_valid(now), GhostSpeakError::InvalidState);
+
+    let previous_subject = credential.subject;
+    credential.rebind(new_subject, now)?;
+
+    emit!(CredentialReboundEvent {
+        credential: credential.key(),
+        previous_subject,
+        new_subject,
+        issuer: credential.issuer,
+        holder,
+        timestamp: now,
+    });
+
+    msg!(
+        "Re-bound credential {} from {} to {}",
+        credential.credential_id,
+        previous_subject,
+        new_subject
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RebindCredential<'info> {
+    #[account(
+        mut,
+        constraint = credential.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = credential.status == CredentialStatus::Active @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    /// Agent named as the current subject, when the holder is its owner
+    pub subject_agent: Option<Account<'info, Agent>>,
+
+    /// CHECK: New subject (e.g. the agent's new DID or owner key)
+    pub new_subject: UncheckedAccount<'info>,
+
     pub issuer: Signer<'info>,
+
+    pub holder: Signer<'info>,
+}
+
+// ============================================================================
+// Revoke Credential
+// ============================================================================
+
+/// Revokes an issued credential. Only the original issuer can revoke.
+///
+/// Also sets the credential's bit in its type's status list.
+pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credential = &mut ctx.accounts.credential;
+
+    require!(
+        credential.status == CredentialStatus::Active,
+        GhostSpeakError::InvalidState
+    );
+
+    credential.revoke(clock.unix_timestamp);
+    ctx.accounts.status_list.set_revoked(credential.status_index)?;
+    ctx.accounts.subject_index_page.remove(&credential.key());
+
+    msg!("Revoked credential {}", credential.credential_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        mut,
+        constraint = credential.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = credential.status == CredentialStatus::Active @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    #[account(mut, address = credential.status_list)]
+    pub status_list: Account<'info, CredentialStatusList>,
+
+    #[account(mut, address = credential.subject_index_page)]
+    pub subject_index_page: Account<'info, SubjectCredentialPage>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+}
+
+// ============================================================================
+// Close Credential
+// ============================================================================
+
+/// Closes a revoked or expired credential, returning its rent to the issuer.
+///
+/// The issuer can close at any time; the holder only after the grace period.
+/// Expired credentials get their status list bit set too, so every closed
+/// credential still resolves as invalid once the account is gone.
+pub fn close_credential(ctx: Context<CloseCredential>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &ctx.accounts.credential;
+    let authority = ctx.accounts.authority.key();
+
+    let by_issuer = authority == credential.issuer;
+    if !by_issuer {
+        require_holder(
+            &credential.subject,
+            &authority,
+            ctx.accounts.subject_agent.as_ref(),
+        )?;
+    }
+    credential.require_closable(by_issuer, now)?;
+
+    // Tombstone: the bit outlives the account
+    ctx.accounts.status_list.set_revoked(credential.status_index)?;
+    ctx.accounts.subject_index_page.remove(&credential.key());
+
+    emit!(CredentialClosedEvent {
+        credential: credential.key(),
+        subject: credential.subject,
+        issuer: credential.issuer,
+        closed_by: authority,
+        status_list: credential.status_list,
+        status_index: credential.status_index,
+        timestamp: now,
+    });
+
+    msg!("Closed credential {}", credential.credential_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseCredential<'info> {
+    #[account(mut, close = issuer)]
+    pub credential: Account<'info, Credential>,
+
+    #[account(mut, address = credential.status_list)]
+    pub status_list: Account<'info, CredentialStatusList>,
+
+    #[account(mut, address = credential.subject_index_page)]
+    pub subject_index_page: Account<'info, SubjectCredentialPage>,
+
+    /// CHECK: Original issuer, receives the reclaimed rent
+    #[account(mut, address = credential.issuer)]
+    pub issuer: UncheckedAccount<'info>,
+
+    /// Agent named as the credential subject, when the holder is its owner
+    pub subject_agent: Option<Account<'info, Agent>>,
+
+    /// Issuer, or the holder once the grace period has passed
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// Expire Credential
+// ============================================================================
+
+/// Marks a credential expired once its `expires_at` has passed.
+///
+/// Permissionless, so indexers and marketplaces can rely on the on-chain
+/// status instead of comparing timestamps themselves.
+pub fn expire_credential(ctx: Context<ExpireCredential>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &mut ctx.accounts.credential;
+
+    let expired_at = credential.expire(now)?;
+
+    emit!(CredentialExpiredEvent {
+        credential: credential.key(),
+        subject: credential.subject,
+        issuer: credential.issuer,
+        expired_at,
+        timestamp: now,
+    });
+
+    msg!("Expired credential {}", credential.credential_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireCredential<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+
+    /// Anyone can crank expiry
+    pub cranker: Signer<'info>,
+}
+
+// ============================================================================
+// Update Cross-Chain Status
+// ============================================================================
+
+/// Starts a Crossmint sync attempt (issuer only).
+///
+/// Allowed for credentials that were never synced, or that failed with a
+/// retryable reason once the backoff has elapsed.
+pub fn request_crosschain_sync(ctx: Context<RequestCrossChainSync>) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+    let now = Clock::get()?.unix_timestamp;
+
+    credential.request_crosschain_sync(now)?;
+
+    emit!(CrossChainStatusChangedEvent {
+        credential: credential.key(),
+        status: credential.cross_chain_status,
+        attempt: credential.cross_chain_attempts,
+        crossmint_credential_id: credential.crossmint_credential_id.clone(),
+        failure: None,
+        retry_at: None,
+        timestamp: now,
+    });
+
+    msg!(
+        "Cross-chain sync requested for {} (attempt {})",
+        credential.credential_id,
+        credential.cross_chain_attempts
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestCrossChainSync<'info> {
+    #[account(
+        mut,
+        constraint = credential.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = credential.status == CredentialStatus::Active @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    pub issuer: Signer<'info>,
+}
+
+/// Applies an attested Crossmint status change to the current sync attempt.
+///
+/// The transaction must carry an Ed25519 instruction, immediately before
+/// this one, in which the configured attestor signs
+/// `Credential::crosschain_attestation_message`. Anyone may relay it.
+pub fn update_crosschain_status(
+    ctx: Context<UpdateCrossChainStatus>,
+    update: CrossChainUpdate,
+) -> Result<()> {
+    let attestor = ctx.accounts.protocol_config.crosschain_attestor;
+    require!(
+        attestor != Pubkey::default(),
+        GhostSpeakError::CrossChainAttestorNotSet
+    );
+
+    let credential = &mut ctx.accounts.credential;
+    let message = credential.crosschain_attestation_message(&credential.key(), &update)?;
+    require_ed25519_signature(&ctx.accounts.instructions_sysvar, &attestor, &message)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    credential.apply_crosschain_update(update, now)?;
+
+    let failed = credential.cross_chain_status == CrossChainStatus::SyncFailed;
+    emit!(CrossChainStatusChangedEvent {
+        credential: credential.key(),
+        status: credential.cross_chain_status,
+        attempt: credential.cross_chain_attempts,
+        crossmint_credential_id: credential.crossmint_credential_id.clone(),
+        failure: credential.cross_chain_failure,
+        retry_at: failed.then_some(credential.cross_chain_retry_at),
+        timestamp: now,
+    });
+
+    msg!(
+        "Updated cross-chain status for {} to {:?}",
+        credential.credential_id,
+        credential.cross_chain_status
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCrossChainStatus<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Instructions sysvar, used to find the attestor's Ed25519 instruction
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Relayer submitting the attestation
+    pub relayer: Signer<'info>,
+}
+
+// ============================================================================
+// Cross-Chain Attestation Records
+// ============================================================================
+
+/// Records an attested mirror of a credential on an EVM chain.
+///
+/// Generalizes the Crossmint status flow to any chain and registry (e.g.
+/// ERC-8004 on Base or Ethereum). The transaction must carry an Ed25519
+/// instruction, immediately before this one, in which the configured
+/// cross-chain attestor signs `CrossChainAttestation::message` for the next
+/// revision. Anyone may relay it; the signature is stored as provenance.
+pub fn record_crosschain_attestation(
+    ctx: Context<RecordCrossChainAttestation>,
+    chain_id: u64,
+    registry: [u8; 20],
+    attestation_tx_hash: [u8; 32],
+) -> Result<()> {
+    require!(chain_id != 0, GhostSpeakError::InvalidInput);
+    let attestor = ctx.accounts.protocol_config.crosschain_attestor;
+    require!(
+        attestor != Pubkey::default(),
+        GhostSpeakError::CrossChainAttestorNotSet
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &ctx.accounts.credential;
+    require!(credential.is_valid(now), GhostSpeakError::InvalidState);
+
+    let record = &mut ctx.accounts.attestation;
+    let revision = record
+        .revision
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    let message = CrossChainAttestation::message(
+        &credential.key(),
+        chain_id,
+        revision,
+        &registry,
+        &attestation_tx_hash,
+        &credential.subject_data_hash,
+    );
+    let signature =
+        verified_ed25519_signature(&ctx.accounts.instructions_sysvar, &attestor, &message)?;
+
+    record.credential = credential.key();
+    record.chain_id = chain_id;
+    record.registry = registry;
+    record.attestation_tx_hash = attestation_tx_hash;
+    record.subject_data_hash = credential.subject_data_hash;
+    record.attestor = attestor;
+    record.attestor_signature = signature;
+    record.revision = revision;
+    record.attested_at = now;
+    record.bump = ctx.bumps.attestation;
+
+    emit!(CrossChainAttestationRecordedEvent {
+        credential: credential.key(),
+        chain_id,
+        registry,
+        attestation_tx_hash,
+        revision,
+        timestamp: now,
+    });
+
+    msg!(
+        "Recorded chain {} attestation r{} for {}",
+        chain_id,
+        revision,
+        credential.credential_id
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct RecordCrossChainAttestation<'info> {
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = CrossChainAttestation::LEN,
+        seeds = [
+            CROSSCHAIN_RECORD_SEED,
+            credential.key().as_ref(),
+            &chain_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, CrossChainAttestation>,
+
+    pub credential: Account<'info, Credential>,
+
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Instructions sysvar, used to find the attestor's Ed25519 instruction
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Relayer submitting the attestation
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// Verify Credential
+// ============================================================================
+
+/// Verifies the issuer's Ed25519 signature over a credential's subject data hash.
+///
+/// `signing_key` must be the issuer's DID controller key or an active
+/// AssertionMethod key in the issuer's DID document, and the transaction must
+/// carry an Ed25519 instruction, immediately before this one, in which that
+/// key signs `subject_data_hash`. The result is recorded in a
+/// `CredentialVerification` PDA (refreshed on re-verification).
+pub fn verify_credential(ctx: Context<VerifyCredential>, signing_key: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &ctx.accounts.credential;
+    let issuer_did = &ctx.accounts.issuer_did;
+
+    require!(credential.is_valid(now), GhostSpeakError::InvalidState);
+    require!(
+        issuer_did.can_perform_action(&signing_key, VerificationRelationship::AssertionMethod),
+        GhostSpeakError::NotAssertionMethod
+    );
+    require_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &signing_key,
+        &credential.subject_data_hash,
+    )?;
+
+    let method_id = issuer_did
+        .find_method(&signing_key, VerificationRelationship::AssertionMethod)
+        .map(|method| method.id.clone());
+
+    let verification = &mut ctx.accounts.credential_verification;
+    verification.credential = credential.key();
+    verification.issuer = credential.issuer;
+    verification.signing_key = signing_key;
+    verification.method_id = method_id.clone();
+    verification.subject_data_hash = credential.subject_data_hash;
+    verification.verifier = ctx.accounts.verifier.key();
+    verification.verified_at = now;
+    verification.bump = ctx.bumps.credential_verification;
+
+    emit!(CredentialVerifiedEvent {
+        credential: credential.key(),
+        issuer: credential.issuer,
+        signing_key,
+        method_id,
+        verifier: verification.verifier,
+        timestamp: now,
+    });
+
+    msg!("Verified credential {}", credential.credential_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyCredential<'info> {
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = CredentialVerification::LEN,
+        seeds = [CREDENTIAL_VERIFICATION_SEED, credential.key().as_ref()],
+        bump
+    )]
+    pub credential_verification: Account<'info, CredentialVerification>,
+
+    pub credential: Account<'info, Credential>,
+
+    /// Issuer's DID document, holding the assertion keys
+    #[account(
+        seeds = [DID_DOCUMENT_SEED, credential.issuer.as_ref()],
+        bump = issuer_did.bump,
+        constraint = issuer_did.is_active() @ DidError::DidDeactivated
+    )]
+    pub issuer_did: Account<'info, DidDocument>,
+
+    /// CHECK: Instructions sysvar, used to find the issuer's Ed25519 instruction
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// Present Credential
+// ============================================================================
+
+/// Records a holder presenting a valid credential to a verifier.
+///
+/// Only the purpose and an optional selective-disclosure commitment are
+/// stored; the subject data stays off-chain.
+pub fn present_credential(
+    ctx: Context<PresentCredential>,
+    nonce: u64,
+    purpose: String,
+    disclosure_commitment: Option<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        !purpose.is_empty() && purpose.len() <= MAX_PRESENTATION_PURPOSE,
+        GhostSpeakError::InvalidInput
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &ctx.accounts.credential;
+    let holder = ctx.accounts.holder.key();
+    require!(credential.is_valid(now), GhostSpeakError::InvalidState);
+
+    require_holder(&credential.subject, &holder, ctx.accounts.subject_agent.as_ref())?;
+
+    let presentation = &mut ctx.accounts.presentation;
+    presentation.credential = credential.key();
+    presentation.holder = holder;
+    presentation.verifier = ctx.accounts.verifier.key();
+    presentation.purpose = purpose.clone();
+    presentation.disclosure_commitment = disclosure_commitment;
+    presentation.nonce = nonce;
+    presentation.presented_at = now;
+    presentation.bump = ctx.bumps.presentation;
+
+    emit!(CredentialPresentedEvent {
+        presentation: presentation.key(),
+        credential: credential.key(),
+        holder,
+        verifier: presentation.verifier,
+        purpose,
+        disclosure_commitment,
+        timestamp: now,
+    });
+
+    msg!(
+        "Presented credential {} to {}",
+        credential.credential_id,
+        presentation.verifier
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PresentCredential<'info> {
+    #[account(
+        init,
+        payer = holder,
+        space = CredentialPresentation::LEN,
+        seeds = [
+            CREDENTIAL_PRESENTATION_SEED,
+            credential.key().as_ref(),
+            verifier.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub presentation: Account<'info, CredentialPresentation>,
+
+    pub credential: Account<'info, Credential>,
+
+    /// Agent named as the credential subject, when the holder is its owner
+    pub subject_agent: Option<Account<'info, Agent>>,
+
+    /// CHECK: Verifier the credential is presented to, no signature required
+    pub verifier: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 // ============================================================================
@@ -372,3 +1797,204 @@ pub struct DeactivateCredentialTemplate<'info> {
     #[account(mut)]
     pub issuer: Signer<'info>,
 }
+
+// ============================================================================
+// Custom Credential Kinds
+// ============================================================================
+
+/// Registers a custom credential kind ID, making the caller its owner.
+///
+/// ID 0 is the generic custom kind and cannot be registered.
+pub fn register_custom_credential_kind(
+    ctx: Context<RegisterCustomCredentialKind>,
+    kind_id: u16,
+    name: String,
+) -> Result<()> {
+    require!(kind_id != 0, GhostSpeakError::InvalidInput);
+    require!(
+        !name.is_empty() && name.len() <= MAX_CREDENTIAL_NAME,
+        GhostSpeakError::NameTooLong
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let entry = &mut ctx.accounts.custom_kind;
+    entry.kind_id = kind_id;
+    entry.name = name.clone();
+    entry.owner = ctx.accounts.owner.key();
+    entry.created_at = now;
+    entry.bump = ctx.bumps.custom_kind;
+
+    emit!(CustomCredentialKindRegisteredEvent {
+        kind_id,
+        name,
+        owner: entry.owner,
+        timestamp: now,
+    });
+
+    msg!("Registered custom credential kind {}: {}", kind_id, entry.name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(kind_id: u16)]
+pub struct RegisterCustomCredentialKind<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = CustomCredentialKind::LEN,
+        seeds = [CUSTOM_CREDENTIAL_KIND_SEED, &kind_id.to_le_bytes()],
+        bump
+    )]
+    pub custom_kind: Account<'info, CustomCredentialKind>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Hands a custom credential kind to a new owner (current owner only).
+pub fn transfer_custom_credential_kind(
+    ctx: Context<TransferCustomCredentialKind>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    let entry = &mut ctx.accounts.custom_kind;
+    entry.owner = new_owner;
+
+    msg!("Custom credential kind {} transferred to {}", entry.kind_id, new_owner);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferCustomCredentialKind<'info> {
+    #[account(
+        mut,
+        constraint = custom_kind.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub custom_kind: Account<'info, CustomCredentialKind>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// Issuer Registry
+// ============================================================================
+
+/// Creates the trusted issuer registry governed by `multisig` (protocol admin only).
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+pub fn initialize_issuer_registry(
+    ctx: Context<InitializeIssuerRegistry>,
+    enforce_reserved_kinds: bool,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let registry = &mut ctx.accounts.issuer_registry;
+    registry.multisig = ctx.accounts.multisig.key();
+    registry.enforce_reserved_kinds = enforce_reserved_kinds;
+    registry.issuers = Vec::new();
+    registry.updated_at = Clock::get()?.unix_timestamp;
+    registry.bump = ctx.bumps.issuer_registry;
+
+    msg!(
+        "Issuer registry initialized (multisig: {}, enforced: {})",
+        registry.multisig,
+        enforce_reserved_kinds
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeIssuerRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = IssuerRegistry::LEN,
+        seeds = [ISSUER_REGISTRY_SEED],
+        bump
+    )]
+    pub issuer_registry: Account<'info, IssuerRegistry>,
+
+    /// Multisig that will govern the registry
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds, updates or deactivates an approved issuer (multisig threshold required).
+pub fn set_registered_issuer(
+    ctx: Context<ManageIssuerRegistry>,
+    issuer: Pubkey,
+    kind: CredentialKind,
+    tier: IssuerTrustTier,
+    is_active: bool,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let registry = &mut ctx.accounts.issuer_registry;
+    registry.upsert_issuer(RegisteredIssuer {
+        issuer,
+        kind,
+        tier,
+        is_active,
+    })?;
+    registry.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(IssuerRegistryUpdatedEvent {
+        issuer,
+        kind,
+        tier,
+        is_active,
+        timestamp: registry.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Turns issuer enforcement for protocol-reserved kinds on or off
+/// (multisig threshold required).
+pub fn set_issuer_registry_enforcement(
+    ctx: Context<ManageIssuerRegistry>,
+    enforce_reserved_kinds: bool,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let registry = &mut ctx.accounts.issuer_registry;
+    registry.enforce_reserved_kinds = enforce_reserved_kinds;
+    registry.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Issuer registry enforcement set to {}", enforce_reserved_kinds);
+    Ok(())
+}
+
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ManageIssuerRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [ISSUER_REGISTRY_SEED],
+        bump = issuer_registry.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub issuer_registry: Account<'info, IssuerRegistry>,
+
+    pub multisig: Account<'info, Multisig>,
+}
@@ -0,0 +1,344 @@
+/*!
+ * Compressed Credential Instructions
+ *
+ * Mass issuance path for credentials (e.g. thousands of job-completion
+ * credentials): instead of a rent-paying `Credential` account per issuance,
+ * the credential is hashed into a leaf of a concurrent Merkle tree owned by
+ * an issuer's tree config PDA, mirroring `register_agent_compressed`.
+ *
+ * Compressed credentials have no account to revoke, accept or co-sign, so
+ * they are limited to templates without co-issuers, royalties or fees, subjects
+ * that accept credentials directly, and should carry an expiry.
+ */
+
+use crate::instructions::credential::requires_acceptance;
+use crate::security::{RateLimiter, UserRateLimit, OPERATION_CREDENTIAL_ISSUANCE};
+use crate::state::*;
+use crate::GhostSpeakError;
+use anchor_lang::prelude::*;
+use sha3::{Digest, Keccak256};
+
+pub const CREDENTIAL_TREE_CONFIG_SEED: &[u8] = b"credential_tree_config";
+
+/// Issue a credential as a compressed Merkle tree leaf
+#[derive(Accounts)]
+pub struct IssueCredentialCompressed<'info> {
+    /// Issuer's tree config PDA, the authority of `merkle_tree`
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = CredentialTreeConfig::LEN,
+        seeds = [CREDENTIAL_TREE_CONFIG_SEED, issuer.key().as_ref()],
+        bump
+    )]
+    pub tree_config: Account<'info, CredentialTreeConfig>,
+
+    /// The Merkle tree account that stores compressed credentials
+    /// CHECK: This account is validated by the compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = credential_template.is_active @ GhostSpeakError::InvalidState,
+        constraint = credential_template.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub credential_template: Account<'info, CredentialTemplate>,
+
+    #[account(
+        mut,
+        constraint = credential_type.is_active @ GhostSpeakError::InvalidState,
+        constraint = credential_template.credential_type == credential_type.key()
+    )]
+    pub credential_type: Account<'info, CredentialType>,
+
+    /// CHECK: The subject receiving the credential, no signature required
+    pub subject: UncheckedAccount<'info>,
+
+    /// CHECK: Subject's holder policy PDA; uninitialized if never created
+    #[account(seeds = [HOLDER_POLICY_SEED, subject.key().as_ref()], bump)]
+    pub holder_policy: UncheckedAccount<'info>,
+
+    /// Trusted issuer registry; required for protocol-reserved kinds
+    #[account(seeds = [ISSUER_REGISTRY_SEED], bump = issuer_registry.bump)]
+    pub issuer_registry: Option<Account<'info, IssuerRegistry>>,
+
+    /// Protocol config (staking tier gate)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Staking config, whose current thresholds set the issuer's tier
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// Issuer's staking account, if any (checked against the tier gate)
+    #[account(seeds = [b"staking", issuer.key().as_ref()], bump = issuer_staking.bump)]
+    pub issuer_staking: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Box<Account<'info, RateLimiter>>,
+
+    /// Per-issuer rate limit tracking, shared with `issue_credential`
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            issuer.key().as_ref(),
+            OPERATION_CREDENTIAL_ISSUANCE.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Box<Account<'info, UserRateLimit>>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    /// CHECK: Subject's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, subject.key().as_ref()],
+        bump,
+        constraint = subject_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub subject_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Issuer's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, issuer.key().as_ref()],
+        bump,
+        constraint = issuer_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub issuer_denylist_entry: UncheckedAccount<'info>,
+
+    /// SPL Account Compression program
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_account_compression::ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL Noop program for logging
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_noop::ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Credential Tree Configuration for compressed storage
+#[account]
+pub struct CredentialTreeConfig {
+    /// Issuer whose credentials the tree holds
+    pub issuer: Pubkey,
+    /// Merkle tree bound on first issuance
+    pub merkle_tree: Pubkey,
+    /// Number of credentials appended to the tree
+    pub num_minted: u64,
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl CredentialTreeConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // issuer
+        32 + // merkle_tree
+        8 +  // num_minted
+        1; // bump
+}
+
+/// Compressed credential structure hashed into the Merkle tree leaf
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompressedCredentialLeaf {
+    pub credential_type: Pubkey,
+    pub template: Pubkey,
+    pub subject: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_id: String,
+    pub subject_data_hash: [u8; 32],
+    pub subject_data_uri: String,
+    pub schema_version: u16,
+    pub issued_at: i64,
+    pub expires_at: Option<i64>,
+    pub source_account: Option<Pubkey>,
+}
+
+/// Issue a credential as a compressed leaf
+pub fn issue_credential_compressed(
+    ctx: Context<IssueCredentialCompressed>,
+    credential_id: String,
+    subject_data_hash: [u8; 32],
+    subject_data_uri: String,
+    expires_at: Option<i64>,
+    source_account: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.issuer.key(),
+        OPERATION_CREDENTIAL_ISSUANCE,
+        ctx.bumps.user_rate_limit,
+    )?;
+
+    require!(credential_id.len() <= MAX_CREDENTIAL_ID, GhostSpeakError::InvalidInput);
+    require!(subject_data_uri.len() <= MAX_SCHEMA_URI, GhostSpeakError::InvalidMetadataUri);
+
+    let clock = Clock::get()?;
+    let template = &mut ctx.accounts.credential_template;
+    let credential_type = &mut ctx.accounts.credential_type;
+
+    if let Some(exp) = expires_at {
+        require!(exp > clock.unix_timestamp, GhostSpeakError::InvalidInput);
+    }
+
+    // Anything that needs a per-credential account cannot be compressed
+    require!(
+        template.co_issuers.is_empty()
+            && template.royalty_due().is_none()
+            && template.issuance_fee_lamports == 0
+            && !requires_acceptance(&ctx.accounts.holder_policy)?,
+        GhostSpeakError::CompressedIssuanceUnsupported
+    );
+
+    if credential_type.kind.is_protocol_reserved() {
+        ctx.accounts
+            .issuer_registry
+            .as_ref()
+            .ok_or(GhostSpeakError::IssuerRegistryRequired)?
+            .authorize_issuance(&ctx.accounts.issuer.key(), credential_type.kind)?;
+    }
+
+    let tier_schedule = &ctx.accounts.staking_config.tier_schedule;
+    let issuer_tier = ctx
+        .accounts
+        .issuer_staking
+        .as_ref()
+        .map_or(AccessTier::None, |staking| staking.current_tier(tier_schedule));
+    ctx.accounts
+        .protocol_config
+        .require_tier(GatedAction::IssueCredential, issuer_tier)?;
+
+    // Initialize tree config if needed; the first tree used stays bound
+    let tree_config = &mut ctx.accounts.tree_config;
+    if tree_config.issuer == Pubkey::default() {
+        tree_config.issuer = ctx.accounts.issuer.key();
+        tree_config.merkle_tree = ctx.accounts.merkle_tree.key();
+        tree_config.num_minted = 0;
+        tree_config.bump = ctx.bumps.tree_config;
+    }
+    require!(
+        tree_config.merkle_tree == ctx.accounts.merkle_tree.key(),
+        GhostSpeakError::CredentialTreeMismatch
+    );
+
+    let leaf = CompressedCredentialLeaf {
+        credential_type: credential_type.key(),
+        template: template.key(),
+        subject: ctx.accounts.subject.key(),
+        issuer: ctx.accounts.issuer.key(),
+        credential_id: credential_id.clone(),
+        subject_data_hash,
+        subject_data_uri: subject_data_uri.clone(),
+        schema_version: credential_type.schema_version,
+        issued_at: clock.unix_timestamp,
+        expires_at,
+        source_account,
+    };
+
+    let mut hasher = Keccak256::new();
+    hasher.update(leaf.try_to_vec()?);
+    let data_hash: [u8; 32] = hasher.finalize().into();
+
+    // Raw invoke for the same anchor-lang version reason as
+    // `register_agent_compressed`
+    let append_ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: spl_account_compression::ID,
+        accounts: vec![
+            anchor_lang::solana_program::instruction::AccountMeta::new(
+                ctx.accounts.merkle_tree.key(),
+                false,
+            ),
+            anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
+                tree_config.key(),
+                true,
+            ),
+            anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
+                ctx.accounts.log_wrapper.key(),
+                false,
+            ),
+        ],
+        // Instruction data: append discriminator (8 bytes) + leaf (32 bytes)
+        data: {
+            let mut data = vec![163, 52, 200, 231, 140, 3, 69, 186];
+            data.extend_from_slice(&data_hash);
+            data
+        },
+    };
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &append_ix,
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            tree_config.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+        ],
+        &[&[
+            CREDENTIAL_TREE_CONFIG_SEED,
+            ctx.accounts.issuer.key().as_ref(),
+            &[tree_config.bump],
+        ]],
+    )?;
+
+    let leaf_index = tree_config.num_minted;
+    tree_config.num_minted = leaf_index
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    template.total_issued += 1;
+    credential_type.total_issued += 1;
+
+    emit!(CompressedCredentialIssuedEvent {
+        credential_id: credential_id.clone(),
+        credential_type: leaf.credential_type,
+        template: leaf.template,
+        subject: leaf.subject,
+        issuer: leaf.issuer,
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        data_hash,
+        leaf_index,
+        subject_data_hash,
+        subject_data_uri,
+        schema_version: leaf.schema_version,
+        expires_at,
+        source_account,
+        issued_at: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Issued compressed credential {} to {} (leaf {})",
+        credential_id,
+        leaf.subject,
+        leaf_index
+    );
+    Ok(())
+}
+
+/// Event emitted when a compressed credential is issued
+///
+/// Carries everything needed to rebuild the leaf, so indexers can serve
+/// proofs without any account reads.
+#[event]
+pub struct CompressedCredentialIssuedEvent {
+    pub credential_id: String,
+    pub credential_type: Pubkey,
+    pub template: Pubkey,
+    pub subject: Pubkey,
+    pub issuer: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub data_hash: [u8; 32],
+    pub leaf_index: u64,
+    pub subject_data_hash: [u8; 32],
+    pub subject_data_uri: String,
+    pub schema_version: u16,
+    pub expires_at: Option<i64>,
+    pub source_account: Option<Pubkey>,
+    pub issued_at: i64,
+}
@@ -0,0 +1,260 @@
+/*!
+ * Credential Dispute Instructions Module
+ *
+ * Lets third parties flag a credential as fraudulent by escrowing a bond.
+ * The dispute multisig reviews each flag: upheld flags force-revoke the
+ * credential and return the bond, dismissed flags forfeit it to the treasury.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::governance::Multisig;
+use crate::state::{
+    Credential, CredentialDisputeConfig, CredentialFlag, CredentialFlagResolvedEvent,
+    CredentialFlagStatus, CredentialFlaggedEvent, CredentialStatus, CredentialStatusList,
+    SubjectCredentialPage, CREDENTIAL_DISPUTE_CONFIG_SEED, CREDENTIAL_FLAG_SEED,
+    MAX_FLAG_EVIDENCE_URI,
+};
+use crate::GhostSpeakError;
+
+// ============================================================================
+// Dispute Config
+// ============================================================================
+
+/// Creates the dispute config governed by `multisig` (protocol admin only).
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+pub fn initialize_credential_dispute_config(
+    ctx: Context<InitializeCredentialDisputeConfig>,
+    min_bond_lamports: u64,
+    treasury: Pubkey,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    require!(min_bond_lamports > 0, GhostSpeakError::InvalidInput);
+
+    let config = &mut ctx.accounts.dispute_config;
+    config.multisig = ctx.accounts.multisig.key();
+    config.min_bond_lamports = min_bond_lamports;
+    config.treasury = treasury;
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.dispute_config;
+
+    msg!(
+        "Credential dispute config initialized (multisig: {}, min bond: {})",
+        config.multisig,
+        min_bond_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeCredentialDisputeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CredentialDisputeConfig::LEN,
+        seeds = [CREDENTIAL_DISPUTE_CONFIG_SEED],
+        bump
+    )]
+    pub dispute_config: Account<'info, CredentialDisputeConfig>,
+
+    /// Multisig that will review flags
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// Flag Credential
+// ============================================================================
+
+/// Flags an active credential as fraudulent, escrowing `bond_lamports` in
+/// the flag account until the dispute multisig reviews it.
+pub fn flag_credential(
+    ctx: Context<FlagCredential>,
+    evidence_uri: String,
+    bond_lamports: u64,
+) -> Result<()> {
+    require!(
+        evidence_uri.len() <= MAX_FLAG_EVIDENCE_URI,
+        GhostSpeakError::InvalidMetadataUri
+    );
+    require!(
+        bond_lamports >= ctx.accounts.dispute_config.min_bond_lamports,
+        GhostSpeakError::FlagBondTooLow
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.flagger.to_account_info(),
+                to: ctx.accounts.credential_flag.to_account_info(),
+            },
+        ),
+        bond_lamports,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let flag = &mut ctx.accounts.credential_flag;
+    flag.credential = ctx.accounts.credential.key();
+    flag.flagger = ctx.accounts.flagger.key();
+    flag.evidence_uri = evidence_uri.clone();
+    flag.bond_lamports = bond_lamports;
+    flag.status = CredentialFlagStatus::Open;
+    flag.flagged_at = now;
+    flag.resolved_at = None;
+    flag.bump = ctx.bumps.credential_flag;
+
+    emit!(CredentialFlaggedEvent {
+        credential: flag.credential,
+        flagger: flag.flagger,
+        evidence_uri,
+        bond_lamports,
+        timestamp: now,
+    });
+
+    msg!(
+        "Credential {} flagged by {}",
+        ctx.accounts.credential.credential_id,
+        flag.flagger
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FlagCredential<'info> {
+    #[account(seeds = [CREDENTIAL_DISPUTE_CONFIG_SEED], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, CredentialDisputeConfig>,
+
+    #[account(
+        constraint = credential.status == CredentialStatus::Active @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    #[account(
+        init,
+        payer = flagger,
+        space = CredentialFlag::LEN,
+        seeds = [CREDENTIAL_FLAG_SEED, credential.key().as_ref(), flagger.key().as_ref()],
+        bump
+    )]
+    pub credential_flag: Account<'info, CredentialFlag>,
+
+    /// Anyone can flag by posting the bond
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// Resolve Flag
+// ============================================================================
+
+/// Resolves an open flag (dispute multisig threshold required).
+///
+/// Upholding force-revokes the credential, bypassing the issuer, and returns
+/// the bond to the flagger. Dismissing forfeits the bond to the treasury.
+pub fn resolve_credential_flag(ctx: Context<ResolveCredentialFlag>, uphold: bool) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let flag = &mut ctx.accounts.credential_flag;
+    flag.resolve(uphold, now)?;
+
+    let credential = &mut ctx.accounts.credential;
+    if uphold && credential.status != CredentialStatus::Revoked {
+        credential.revoke(now);
+        ctx.accounts.status_list.set_revoked(credential.status_index)?;
+        ctx.accounts.subject_index_page.remove(&credential.key());
+    }
+
+    let bond_recipient = if uphold {
+        ctx.accounts.flagger.to_account_info()
+    } else {
+        ctx.accounts.treasury.to_account_info()
+    };
+    let bond_lamports = flag.bond_lamports;
+    flag.sub_lamports(bond_lamports)?;
+    bond_recipient.add_lamports(bond_lamports)?;
+
+    emit!(CredentialFlagResolvedEvent {
+        credential: credential.key(),
+        flagger: flag.flagger,
+        status: flag.status,
+        bond_recipient: bond_recipient.key(),
+        bond_lamports,
+        timestamp: now,
+    });
+
+    msg!(
+        "Flag on credential {} {}",
+        credential.credential_id,
+        if uphold { "upheld" } else { "dismissed" }
+    );
+    Ok(())
+}
+
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ResolveCredentialFlag<'info> {
+    #[account(
+        seeds = [CREDENTIAL_DISPUTE_CONFIG_SEED],
+        bump = dispute_config.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess,
+        has_one = treasury @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub dispute_config: Account<'info, CredentialDisputeConfig>,
+
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            CREDENTIAL_FLAG_SEED,
+            credential.key().as_ref(),
+            credential_flag.flagger.as_ref()
+        ],
+        bump = credential_flag.bump,
+        has_one = credential,
+        has_one = flagger
+    )]
+    pub credential_flag: Account<'info, CredentialFlag>,
+
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+
+    #[account(mut, address = credential.status_list)]
+    pub status_list: Account<'info, CredentialStatusList>,
+
+    #[account(mut, address = credential.subject_index_page)]
+    pub subject_index_page: Account<'info, SubjectCredentialPage>,
+
+    /// CHECK: Flagger recorded on the flag, receives the bond if upheld
+    #[account(mut)]
+    pub flagger: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury recorded on the dispute config, receives forfeited bonds
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+}
@@ -0,0 +1,227 @@
+/*!
+ * Credential Token Instructions Module
+ *
+ * Mirrors issued credentials as soulbound Token-2022 tokens so wallets and
+ * marketplaces that only understand tokens can display them. Each token has
+ * its own mint (NonTransferable + MetadataPointer, metadata stored on the
+ * mint itself) with a supply of exactly one.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint;
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, SetAuthority, Token2022};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_interface::{
+    metadata_pointer_initialize, non_transferable_mint_initialize, token_metadata_initialize,
+    MetadataPointerInitialize, NonTransferableMintInitialize, TokenMetadataInitialize,
+};
+
+use crate::state::{
+    Credential, CredentialStatus, CredentialTemplate, CredentialTokenMintedEvent,
+    CREDENTIAL_MINT_SEED, CREDENTIAL_TOKEN_SYMBOL,
+};
+use crate::GhostSpeakError;
+
+/// Mints a non-transferable Token-2022 token representing an active credential.
+///
+/// The mint is a PDA of the credential, so each credential can be tokenized
+/// once. The token goes to the subject's associated token account; the mint
+/// authority is then dropped so supply stays at one. The mint PDA remains
+/// the freeze and metadata update authority.
+pub fn mint_credential_token(ctx: Context<MintCredentialToken>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &ctx.accounts.credential;
+    let mint = &ctx.accounts.mint;
+
+    require!(
+        mint.data_is_empty(),
+        GhostSpeakError::CredentialTokenAlreadyMinted
+    );
+
+    let credential_key = credential.key();
+    let mint_seeds: &[&[u8]] = &[
+        CREDENTIAL_MINT_SEED,
+        credential_key.as_ref(),
+        &[ctx.bumps.mint],
+    ];
+    let signer_seeds = &[mint_seeds];
+
+    let name = ctx.accounts.credential_template.name.clone();
+    let symbol = CREDENTIAL_TOKEN_SYMBOL.to_string();
+    let uri = credential.subject_data_uri.clone();
+
+    // Token-2022 reallocs the mint for the metadata, so fund it up front
+    let mint_len = ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let metadata_len = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(mint.key()))?,
+        mint: mint.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: Vec::new(),
+    }
+    .tlv_size_of()?;
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.issuer.to_account_info(),
+                to: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Rent::get()?.minimum_balance(mint_len + metadata_len),
+        mint_len as u64,
+        &token_2022::ID,
+    )?;
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint.to_account_info(),
+        },
+    ))?;
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.to_account_info(),
+            },
+        ),
+        Some(mint.key()),
+        Some(mint.key()),
+    )?;
+
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: mint.to_account_info(),
+            },
+        ),
+        0,
+        &mint.key(),
+        Some(&mint.key()),
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program.clone(),
+                metadata: mint.to_account_info(),
+                update_authority: mint.to_account_info(),
+                mint_authority: mint.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.issuer.to_account_info(),
+            associated_token: ctx.accounts.subject_token_account.to_account_info(),
+            authority: ctx.accounts.subject.to_account_info(),
+            mint: mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: mint.to_account_info(),
+                to: ctx.accounts.subject_token_account.to_account_info(),
+                authority: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            SetAuthority {
+                current_authority: mint.to_account_info(),
+                account_or_mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    emit!(CredentialTokenMintedEvent {
+        credential: credential_key,
+        mint: mint.key(),
+        subject: credential.subject,
+        token_account: ctx.accounts.subject_token_account.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Minted credential token {} for {}",
+        mint.key(),
+        credential.credential_id
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MintCredentialToken<'info> {
+    #[account(
+        constraint = credential.issuer == issuer.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = credential.status == CredentialStatus::Active @ GhostSpeakError::InvalidState
+    )]
+    pub credential: Account<'info, Credential>,
+
+    /// Template the credential was issued from (names the token)
+    #[account(address = credential.template)]
+    pub credential_template: Account<'info, CredentialTemplate>,
+
+    /// CHECK: Credential mint PDA, created and initialized by this instruction
+    #[account(
+        mut,
+        seeds = [CREDENTIAL_MINT_SEED, credential.key().as_ref()],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Credential subject, owner of the token account
+    #[account(address = credential.subject)]
+    pub subject: UncheckedAccount<'info>,
+
+    /// CHECK: Subject's associated token account, validated and created by the
+    /// associated token program
+    #[account(mut)]
+    pub subject_token_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,183 @@
+/*!
+ * Denylist Instructions
+ *
+ * Creates the sanctions denylist and lets its multisig list and delist
+ * agent PDAs and owner wallets. Escrow creation, credential issuance and
+ * rating submission check each party's entry PDA and refuse while it
+ * exists.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::denylist::*;
+use crate::state::governance::{Multisig, MultisigType};
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE DENYLIST
+// =====================================================
+
+/// Create the empty denylist (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeDenylist<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Denylist::LEN,
+        seeds = [DENYLIST_SEED],
+        bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+
+    /// Protocol or DAO multisig that will manage the denylist
+    #[account(
+        constraint = matches!(multisig.multisig_type, MultisigType::Protocol | MultisigType::Dao) @ GhostSpeakError::InvalidConfiguration
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_denylist(ctx: Context<InitializeDenylist>) -> Result<()> {
+    let denylist = &mut ctx.accounts.denylist;
+    denylist.multisig = ctx.accounts.multisig.key();
+    denylist.entry_count = 0;
+    denylist.updated_at = Clock::get()?.unix_timestamp;
+    denylist.bump = ctx.bumps.denylist;
+
+    msg!("Denylist created (multisig: {})", denylist.multisig);
+    Ok(())
+}
+
+// =====================================================
+// MANAGE DENYLIST
+// =====================================================
+
+/// List an agent PDA or owner (denylist multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold; `payer` only funds the entry.
+#[derive(Accounts)]
+#[instruction(subject: Pubkey)]
+pub struct AddToDenylist<'info> {
+    #[account(
+        mut,
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub denylist: Account<'info, Denylist>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = DenylistEntry::LEN,
+        seeds = [DENYLIST_ENTRY_SEED, subject.as_ref()],
+        bump
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_to_denylist(
+    ctx: Context<AddToDenylist>,
+    subject: Pubkey,
+    reason: DenylistReason,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+    require!(
+        subject != Pubkey::default(),
+        GhostSpeakError::InvalidDenylistSubject
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let entry = &mut ctx.accounts.denylist_entry;
+    entry.subject = subject;
+    entry.reason = reason;
+    entry.rent_payer = ctx.accounts.payer.key();
+    entry.listed_at = now;
+    entry.bump = ctx.bumps.denylist_entry;
+
+    let denylist = &mut ctx.accounts.denylist;
+    denylist.entry_count = denylist.entry_count.saturating_add(1);
+    denylist.updated_at = now;
+
+    emit!(DenylistEntryAddedEvent {
+        subject,
+        reason,
+        multisig: ctx.accounts.multisig.key(),
+        timestamp: now,
+    });
+
+    msg!("Denylisted {} ({:?})", subject, reason);
+    Ok(())
+}
+
+/// Delist an agent PDA or owner (denylist multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct RemoveFromDenylist<'info> {
+    #[account(
+        mut,
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub denylist: Account<'info, Denylist>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [DENYLIST_ENTRY_SEED, denylist_entry.subject.as_ref()],
+        bump = denylist_entry.bump
+    )]
+    pub denylist_entry: Account<'info, DenylistEntry>,
+
+    /// CHECK: Original funder of the entry, refunded its rent
+    #[account(mut, address = denylist_entry.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let subject = ctx.accounts.denylist_entry.subject;
+    let denylist = &mut ctx.accounts.denylist;
+    denylist.entry_count = denylist.entry_count.saturating_sub(1);
+    denylist.updated_at = now;
+
+    emit!(DenylistEntryRemovedEvent {
+        subject,
+        multisig: ctx.accounts.multisig.key(),
+        timestamp: now,
+    });
+
+    msg!("Removed {} from the denylist", subject);
+    Ok(())
+}
@@ -2,10 +2,12 @@
  * DID (Decentralized Identifier) Instructions
  *
  * Implements instruction handlers for DID operations following the did:sol specification.
- * All handlers use canonical PDA validation and comprehensive security checks.
+ * Documents are created at canonical PDAs; later operations authorize against
+ * the stored controller, which guardian recovery can replace.
  */
 
 use crate::state::did::*;
+use crate::state::{IssuerRegistry, ISSUER_REGISTRY_SEED};
 use crate::*;
 
 /// Create a new DID document
@@ -39,219 +41,2019 @@ pub struct CreateDidDocument<'info> {
 /// Update an existing DID document
 #[derive(Accounts)]
 pub struct UpdateDidDocument<'info> {
-    /// DID document account with canonical PDA validation
+    /// DID document account; its controller may differ from the PDA seed
+    /// after a guardian recovery
     #[account(
         mut,
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar for timestamp validation
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Deactivate a DID document
+#[derive(Accounts)]
+pub struct DeactivateDidDocument<'info> {
+    /// DID document account; its controller may differ from the PDA seed
+    /// after a guardian recovery
+    #[account(
+        mut,
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::AlreadyDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar for timestamp validation
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Rotate a verification key, signed by both the outgoing and incoming key
+#[derive(Accounts)]
+pub struct RotateDidKey<'info> {
+    /// DID document account; its controller may differ from the PDA seed
+    /// after a guardian recovery
+    #[account(
+        mut,
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Rotation record, keyed by the version being replaced
+    #[account(
+        init,
+        payer = controller,
+        space = DidKeyRotation::LEN,
+        seeds = [
+            DID_KEY_ROTATION_SEED,
+            did_document.key().as_ref(),
+            did_document.version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub rotation: Account<'info, DidKeyRotation>,
+
+    /// Key being rotated out
+    pub old_key: Signer<'info>,
+
+    /// Key being rotated in
+    pub new_key: Signer<'info>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Install or change the controller set of a DID document
+///
+/// The first call must be signed by the document's single controller as
+/// `authority`; later calls need the current set's threshold, with approving
+/// controllers passed as remaining accounts.
+#[derive(Accounts)]
+pub struct SetDidControllers<'info> {
+    /// DID document account
+    #[account(
+        mut,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = authority,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller set PDA for the document
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DidControllerSet::LEN,
+        seeds = [
+            DID_CONTROLLERS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump
+    )]
+    pub controller_set: Account<'info, DidControllerSet>,
+
+    /// Single controller on first install, otherwise any fee payer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Update a multi-controller DID document
+///
+/// Approving controllers are passed as remaining accounts and must meet the
+/// controller set threshold.
+#[derive(Accounts)]
+pub struct UpdateDidDocumentByControllers<'info> {
+    /// DID document account governed by `controller_set`
+    #[account(
+        mut,
+        constraint = did_document.controller == controller_set.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = payer,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller set PDA for the document
+    #[account(
+        seeds = [
+            DID_CONTROLLERS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = controller_set.bump
+    )]
+    pub controller_set: Account<'info, DidControllerSet>,
+
+    /// Pays for the version entry
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Deactivate a multi-controller DID document
+///
+/// Approving controllers are passed as remaining accounts and must meet the
+/// controller set threshold.
+#[derive(Accounts)]
+pub struct DeactivateDidDocumentByControllers<'info> {
+    /// DID document account governed by `controller_set`
+    #[account(
+        mut,
+        constraint = did_document.controller == controller_set.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::AlreadyDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = payer,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller set PDA for the document
+    #[account(
+        seeds = [
+            DID_CONTROLLERS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = controller_set.bump
+    )]
+    pub controller_set: Account<'info, DidControllerSet>,
+
+    /// Pays for the version entry
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Reactivate a DID document within its grace period
+#[derive(Accounts)]
+pub struct ReactivateDidDocument<'info> {
+    /// DID document account
+    #[account(
+        mut,
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = !did_document.is_active() @ DidError::NotDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Reactivate a multi-controller DID document within its grace period
+///
+/// Approving controllers are passed as remaining accounts and must meet the
+/// controller set threshold.
+#[derive(Accounts)]
+pub struct ReactivateDidDocumentByControllers<'info> {
+    /// DID document account governed by `controller_set`
+    #[account(
+        mut,
+        constraint = did_document.controller == controller_set.key() @ DidError::UnauthorizedDidOperation,
+        constraint = !did_document.is_active() @ DidError::NotDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = payer,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Controller set PDA for the document
+    #[account(
+        seeds = [
+            DID_CONTROLLERS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = controller_set.bump
+    )]
+    pub controller_set: Account<'info, DidControllerSet>,
+
+    /// Pays for the version entry
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Shrink a tombstoned DID document, refunding its rent to the controller
+#[derive(Accounts)]
+pub struct CloseDidDocument<'info> {
+    /// DID document account
+    #[account(
+        mut,
+        realloc = DidDocument::TOMBSTONE_LEN,
+        realloc::payer = controller,
+        realloc::zero = false
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// CHECK: DID controller recorded on the document, receives the rent
+    #[account(mut, address = did_document.controller)]
+    pub controller: UncheckedAccount<'info>,
+
+    /// System program (required by realloc)
+    pub system_program: Program<'info, System>,
+}
+
+/// Designate or replace the recovery guardians of a DID document
+#[derive(Accounts)]
+pub struct SetDidGuardians<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Guardian set PDA for the document
+    #[account(
+        init_if_needed,
+        payer = controller,
+        space = DidGuardianSet::LEN,
+        seeds = [
+            DID_GUARDIANS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump
+    )]
+    pub guardian_set: Account<'info, DidGuardianSet>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Start a guardian recovery of a DID document
+///
+/// `initiator` must be a guardian; further approving guardians are passed as
+/// remaining accounts.
+#[derive(Accounts)]
+pub struct InitiateDidRecovery<'info> {
+    /// DID document account
+    #[account(constraint = did_document.is_active() @ DidError::DidDeactivated)]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Guardian set PDA for the document
+    #[account(
+        seeds = [
+            DID_GUARDIANS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = guardian_set.bump,
+        has_one = did_document
+    )]
+    pub guardian_set: Account<'info, DidGuardianSet>,
+
+    /// Recovery request PDA (one pending request per document)
+    #[account(
+        init,
+        payer = initiator,
+        space = DidRecoveryRequest::LEN,
+        seeds = [
+            DID_RECOVERY_SEED,
+            did_document.key().as_ref()
+        ],
+        bump
+    )]
+    pub recovery_request: Account<'info, DidRecoveryRequest>,
+
+    /// Guardian paying for the request
+    #[account(mut)]
+    pub initiator: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a pending recovery (current controller only)
+#[derive(Accounts)]
+pub struct CancelDidRecovery<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Recovery request PDA, closed to its initiator
+    #[account(
+        mut,
+        seeds = [
+            DID_RECOVERY_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = recovery_request.bump,
+        has_one = did_document,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub recovery_request: Account<'info, DidRecoveryRequest>,
+
+    /// Controller of the DID (must match document controller)
+    pub controller: Signer<'info>,
+
+    /// CHECK: Request initiator, receives the rent refund
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>,
+}
+
+/// Execute a recovery once its challenge window has elapsed (permissionless)
+#[derive(Accounts)]
+pub struct ExecuteDidRecovery<'info> {
+    /// DID document account
+    #[account(
+        mut,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = payer,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Recovery request PDA, closed to its initiator
+    #[account(
+        mut,
+        seeds = [
+            DID_RECOVERY_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = recovery_request.bump,
+        has_one = did_document,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub recovery_request: Account<'info, DidRecoveryRequest>,
+
+    /// Guardian set PDA, carried over to the new controller
+    #[account(
+        mut,
+        seeds = [
+            DID_GUARDIANS_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = guardian_set.bump
+    )]
+    pub guardian_set: Account<'info, DidGuardianSet>,
+
+    /// CHECK: Request initiator, receives the rent refund
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>,
+
+    /// Pays for the version entry
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose a new controller for a DID document
+#[derive(Accounts)]
+pub struct TransferDidController<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Pending transfer PDA; a new proposal replaces the previous one
+    #[account(
+        init_if_needed,
+        payer = controller,
+        space = DidControllerTransfer::LEN,
+        seeds = [
+            DID_CONTROLLER_TRANSFER_SEED,
+            did_document.key().as_ref()
+        ],
+        bump
+    )]
+    pub controller_transfer: Account<'info, DidControllerTransfer>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Accept a proposed controller transfer
+#[derive(Accounts)]
+pub struct AcceptDidController<'info> {
+    /// DID document account; must still have the proposing controller
+    #[account(
+        mut,
+        constraint = did_document.controller == controller_transfer.current_controller @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = new_controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Pending transfer PDA, closed to the proposing controller
+    #[account(
+        mut,
+        seeds = [
+            DID_CONTROLLER_TRANSFER_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = controller_transfer.bump,
+        has_one = did_document,
+        has_one = current_controller,
+        constraint = controller_transfer.proposed_controller == new_controller.key() @ DidError::UnauthorizedDidOperation,
+        close = current_controller
+    )]
+    pub controller_transfer: Account<'info, DidControllerTransfer>,
+
+    /// Proposed controller accepting the DID
+    #[account(mut)]
+    pub new_controller: Signer<'info>,
+
+    /// CHECK: Proposing controller, receives the rent refund
+    #[account(mut)]
+    pub current_controller: UncheckedAccount<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a proposed controller transfer
+#[derive(Accounts)]
+pub struct CancelDidControllerTransfer<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Pending transfer PDA, closed to the controller
+    #[account(
+        mut,
+        seeds = [
+            DID_CONTROLLER_TRANSFER_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = controller_transfer.bump,
+        has_one = did_document,
+        close = controller
+    )]
+    pub controller_transfer: Account<'info, DidControllerTransfer>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+}
+
+/// Link a DID document and an agent account to each other
+#[derive(Accounts)]
+pub struct LinkDidToAgent<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == owner.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Agent account (must be owned by the DID controller)
+    #[account(
+        mut,
+        constraint = agent.owner == Some(owner.key()) @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub agent: Account<'info, Agent>,
+
+    /// DID → agent link PDA; relinking overwrites it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = DidAgentLink::LEN,
+        seeds = [
+            DID_AGENT_LINK_SEED,
+            did_document.key().as_ref()
+        ],
+        bump
+    )]
+    pub did_agent_link: Account<'info, DidAgentLink>,
+
+    /// DID controller and agent owner
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Check a DID/agent binding (read-only operation)
+#[derive(Accounts)]
+pub struct VerifyDidAgentLink<'info> {
+    /// DID document account
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Agent account
+    pub agent: Account<'info, Agent>,
+
+    /// DID → agent link PDA
+    #[account(
+        seeds = [
+            DID_AGENT_LINK_SEED,
+            did_document.key().as_ref()
+        ],
+        bump = did_agent_link.bump
+    )]
+    pub did_agent_link: Account<'info, DidAgentLink>,
+}
+
+/// Record an attestor-verified link to an external DID
+#[derive(Accounts)]
+#[instruction(external_did: String)]
+pub struct AttestExternalDidLink<'info> {
+    /// DID document account
+    #[account(constraint = did_document.is_active() @ DidError::DidDeactivated)]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// External link PDA; re-attesting refreshes it
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = DidExternalLink::LEN,
+        seeds = [
+            DID_EXTERNAL_LINK_SEED,
+            did_document.key().as_ref(),
+            DidExternalLink::seed_hash(&external_did).as_ref()
+        ],
+        bump
+    )]
+    pub external_link: Account<'info, DidExternalLink>,
+
+    /// Trusted issuer registry listing the attestor
+    #[account(seeds = [ISSUER_REGISTRY_SEED], bump = issuer_registry.bump)]
+    pub issuer_registry: Account<'info, IssuerRegistry>,
+
+    /// Registered agent-identity issuer vouching for the link
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Record a link to an external `did:key` proven by a signed challenge
+#[derive(Accounts)]
+#[instruction(external_did: String)]
+pub struct ProveExternalDidLink<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// External link PDA; re-proving refreshes it
+    #[account(
+        init_if_needed,
+        payer = controller,
+        space = DidExternalLink::LEN,
+        seeds = [
+            DID_EXTERNAL_LINK_SEED,
+            did_document.key().as_ref(),
+            DidExternalLink::seed_hash(&external_did).as_ref()
+        ],
+        bump
+    )]
+    pub external_link: Account<'info, DidExternalLink>,
+
+    /// CHECK: Instructions sysvar, used to find the external key's Ed25519 instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Revoke a link to an external DID
+#[derive(Accounts)]
+pub struct RevokeExternalDidLink<'info> {
+    /// DID document account
+    #[account(
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// External link PDA
+    #[account(
+        mut,
+        seeds = [
+            DID_EXTERNAL_LINK_SEED,
+            did_document.key().as_ref(),
+            DidExternalLink::seed_hash(&external_link.external_did).as_ref()
+        ],
+        bump = external_link.bump,
+        has_one = did_document
+    )]
+    pub external_link: Account<'info, DidExternalLink>,
+
+    /// Controller of the DID (must match document controller)
+    pub controller: Signer<'info>,
+}
+
+/// Add a verification method for a scoped delegate key
+#[derive(Accounts)]
+#[instruction(method: VerificationMethod, delegate: Pubkey)]
+pub struct AddDelegateVerificationMethod<'info> {
+    /// DID document account
+    #[account(
+        mut,
+        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
+        constraint = did_document.is_active() @ DidError::DidDeactivated
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Scope record for the delegate key
+    #[account(
+        init,
+        payer = controller,
+        space = DidDelegateKey::LEN,
         seeds = [
-            DID_DOCUMENT_SEED,
-            controller.key().as_ref()
+            VERIFICATION_METHOD_SEED,
+            did_document.key().as_ref(),
+            delegate.as_ref()
         ],
-        bump = did_document.bump,
+        bump
+    )]
+    pub delegate_key: Account<'info, DidDelegateKey>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Remove a scoped delegate key and its verification method
+#[derive(Accounts)]
+pub struct RemoveDelegateVerificationMethod<'info> {
+    /// DID document account
+    #[account(
+        mut,
         constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
         constraint = did_document.is_active() @ DidError::DidDeactivated
     )]
     pub did_document: Account<'info, DidDocument>,
 
-    /// Controller of the DID (must match document controller)
-    #[account(mut)]
-    pub controller: Signer<'info>,
+    /// History entry for the version this change produces
+    #[account(
+        init,
+        payer = controller,
+        space = DidVersionEntry::LEN,
+        seeds = [
+            DID_VERSION_SEED,
+            did_document.key().as_ref(),
+            did_document.next_version().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub version_entry: Account<'info, DidVersionEntry>,
+
+    /// Scope record, closed to the controller
+    #[account(
+        mut,
+        seeds = [
+            VERIFICATION_METHOD_SEED,
+            did_document.key().as_ref(),
+            delegate_key.delegate.as_ref()
+        ],
+        bump = delegate_key.bump,
+        close = controller
+    )]
+    pub delegate_key: Account<'info, DidDelegateKey>,
+
+    /// Controller of the DID (must match document controller)
+    #[account(mut)]
+    pub controller: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Resolve a DID document (read-only operation)
+#[derive(Accounts)]
+pub struct ResolveDidDocument<'info> {
+    /// DID document account
+    #[account(
+        seeds = [
+            DID_DOCUMENT_SEED,
+            did_controller.key().as_ref()
+        ],
+        bump = did_document.bump
+    )]
+    pub did_document: Account<'info, DidDocument>,
+
+    /// Controller public key (used for PDA derivation)
+    /// CHECK: This is safe as we only use it for PDA seeds
+    pub did_controller: UncheckedAccount<'info>,
+}
+
+/// Create a DID document for an agent or user
+///
+/// # Arguments
+/// * `ctx` - Accounts context
+/// * `did_string` - The DID string (e.g., "did:sol:devnet:...")
+/// * `verification_methods` - Initial verification methods
+/// * `service_endpoints` - Initial service endpoints
+///
+/// # Security
+/// - Validates DID format
+/// - Ensures controller authorization
+/// - Initializes with secure defaults
+pub fn create_did_document(
+    ctx: Context<CreateDidDocument>,
+    did_string: String,
+    verification_methods: Vec<VerificationMethod>,
+    service_endpoints: Vec<ServiceEndpoint>,
+) -> Result<()> {
+    let did_document = &mut ctx.accounts.did_document;
+    let clock = Clock::get()?;
+
+    initialize_did_account(
+        did_document,
+        did_string.clone(),
+        ctx.accounts.controller.key(),
+        verification_methods,
+        service_endpoints,
+        ctx.bumps.did_document,
+        clock.unix_timestamp,
+    )?;
+
+    msg!(
+        "DID document created: {} by controller: {}",
+        did_string,
+        ctx.accounts.controller.key()
+    );
+
+    Ok(())
+}
+
+/// Validate inputs and populate a freshly created DID document
+///
+/// Shared by `create_did_document` and `onboard_agent`.
+pub(crate) fn initialize_did_account(
+    did_document: &mut DidDocument,
+    did_string: String,
+    controller: Pubkey,
+    verification_methods: Vec<VerificationMethod>,
+    service_endpoints: Vec<ServiceEndpoint>,
+    bump: u8,
+    now: i64,
+) -> Result<()> {
+    // Validate DID format (did:sol:network:pubkey)
+    require!(
+        did_string.starts_with("did:sol:"),
+        DidError::InvalidDidFormat
+    );
+
+    // Validate verification methods count
+    require!(
+        verification_methods.len() <= MAX_VERIFICATION_METHODS,
+        DidError::TooManyVerificationMethods
+    );
+
+    // Validate service endpoints count
+    require!(
+        service_endpoints.len() <= MAX_SERVICE_ENDPOINTS,
+        DidError::TooManyServiceEndpoints
+    );
+    for service in &service_endpoints {
+        service.validate()?;
+    }
+
+    // Initialize DID document
+    did_document.did = did_string;
+    did_document.controller = controller;
+    did_document.verification_methods = verification_methods;
+    did_document.service_endpoints = service_endpoints;
+
+    // Set W3C context
+    did_document.context = vec![
+        "https://www.w3.org/ns/did/v1".to_string(),
+        "https://w3id.org/security/suites/ed25519-2020/v1".to_string(),
+    ];
+
+    did_document.also_known_as = Vec::new();
+    did_document.created_at = now;
+    did_document.updated_at = now;
+    did_document.version = 1;
+    did_document.deactivated = false;
+    did_document.deactivated_at = None;
+    did_document.bump = bump;
+    did_document.reactivation_deadline = 0;
+
+    Ok(())
+}
+
+/// Update a DID document by adding/removing verification methods or services
+///
+/// # Arguments
+/// * `add_verification_methods` - Verification methods to add
+/// * `remove_verification_method_ids` - Method IDs to remove
+/// * `add_service_endpoints` - Service endpoints to add
+/// * `remove_service_endpoint_ids` - Service IDs to remove
+///
+/// Removals are applied before additions, so an ID can be replaced in one
+/// call.
+///
+/// # Security
+/// - Only controller can update
+/// - Updated document must fit the account's allocated space
+/// - Version number incremented once per call
+/// - Timestamp updated
+pub fn update_did_document(
+    ctx: Context<UpdateDidDocument>,
+    add_verification_methods: Vec<VerificationMethod>,
+    remove_verification_method_ids: Vec<String>,
+    add_service_endpoints: Vec<ServiceEndpoint>,
+    remove_service_endpoint_ids: Vec<String>,
+) -> Result<()> {
+    let prior_state_hash = ctx.accounts.did_document.state_hash()?;
+
+    apply_did_update(
+        &mut ctx.accounts.did_document,
+        add_verification_methods,
+        remove_verification_method_ids,
+        add_service_endpoints,
+        remove_service_endpoint_ids,
+    )?;
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Update,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Apply an update to a DID document
+///
+/// Shared by `update_did_document` and `update_did_document_by_controllers`.
+fn apply_did_update(
+    did_document: &mut Account<DidDocument>,
+    add_verification_methods: Vec<VerificationMethod>,
+    remove_verification_method_ids: Vec<String>,
+    add_service_endpoints: Vec<ServiceEndpoint>,
+    remove_service_endpoint_ids: Vec<String>,
+) -> Result<()> {
+    let next_version = did_document.next_version();
+
+    // Removals first, so replacements can reuse IDs
+    for method_id in remove_verification_method_ids {
+        did_document.remove_verification_method(&method_id)?;
+    }
+    for service_id in remove_service_endpoint_ids {
+        did_document.remove_service_endpoint(&service_id)?;
+    }
+
+    for method in add_verification_methods {
+        did_document.add_verification_method(method)?;
+    }
+    for service in add_service_endpoints {
+        did_document.add_service_endpoint(service)?;
+    }
+
+    // Whole document must still fit the account
+    let serialized_len = 8 + did_document.try_to_vec()?.len();
+    require!(
+        serialized_len <= did_document.to_account_info().data_len(),
+        DidError::DocumentTooLarge
+    );
+
+    // One version per update, however many changes it carries
+    did_document.version = next_version;
+    did_document.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "DID document updated: {} (version: {})",
+        did_document.did,
+        did_document.version
+    );
+
+    Ok(())
+}
+
+/// Deactivate a DID document
+///
+/// # Arguments
+/// * `grace_period` - Seconds during which the controller can reactivate
+///   (up to 90 days; zero makes deactivation final)
+///
+/// # Security
+/// - Only controller can deactivate
+/// - Irreversible once the grace period ends
+/// - Timestamp recorded
+pub fn deactivate_did_document(
+    ctx: Context<DeactivateDidDocument>,
+    grace_period: i64,
+) -> Result<()> {
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+
+    // Deactivate the DID
+    did_document.deactivate(grace_period)?;
+
+    msg!(
+        "DID document deactivated: {} at {}",
+        did_document.did,
+        did_document.deactivated_at.unwrap()
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Deactivate,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Install or change the controller set of a DID document
+///
+/// # Security
+/// - First install requires the single controller's signature and hands
+///   control to the set (the document controller becomes the set PDA)
+/// - Later changes require the current set's m-of-n approval
+pub fn set_did_controllers(
+    ctx: Context<SetDidControllers>,
+    controllers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let set_key = ctx.accounts.controller_set.key();
+    let did_document = &mut ctx.accounts.did_document;
+    let controller_set = &mut ctx.accounts.controller_set;
+    let prior_state_hash = did_document.state_hash()?;
+
+    if did_document.controller == set_key {
+        controller_set.require_approvals(ctx.remaining_accounts)?;
+    } else {
+        require!(
+            did_document.controller == ctx.accounts.authority.key(),
+            DidError::UnauthorizedDidOperation
+        );
+        did_document.controller = set_key;
+        controller_set.did_document = did_document.key();
+        controller_set.bump = ctx.bumps.controller_set;
+    }
+
+    DidControllerSet::validate(&controllers, threshold, &set_key)?;
+
+    controller_set.controllers = controllers.clone();
+    controller_set.threshold = threshold;
+    controller_set.updated_at = now;
+
+    did_document.updated_at = now;
+    did_document.version += 1;
+
+    emit!(DidControllersUpdatedEvent {
+        did_document: did_document.key(),
+        controller_set: set_key,
+        controllers,
+        threshold,
+        timestamp: now,
+    });
+
+    msg!(
+        "DID controllers set: {} ({}-of-{})",
+        did_document.did,
+        threshold,
+        controller_set.controllers.len()
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::ControllersChanged,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Update a multi-controller DID document
+///
+/// Takes the same changes as `update_did_document`.
+///
+/// # Security
+/// - Controller set threshold must approve
+pub fn update_did_document_by_controllers(
+    ctx: Context<UpdateDidDocumentByControllers>,
+    add_verification_methods: Vec<VerificationMethod>,
+    remove_verification_method_ids: Vec<String>,
+    add_service_endpoints: Vec<ServiceEndpoint>,
+    remove_service_endpoint_ids: Vec<String>,
+) -> Result<()> {
+    ctx.accounts
+        .controller_set
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let prior_state_hash = ctx.accounts.did_document.state_hash()?;
+
+    apply_did_update(
+        &mut ctx.accounts.did_document,
+        add_verification_methods,
+        remove_verification_method_ids,
+        add_service_endpoints,
+        remove_service_endpoint_ids,
+    )?;
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Update,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Deactivate a multi-controller DID document
+///
+/// Takes the same grace period as `deactivate_did_document`.
+///
+/// # Security
+/// - Controller set threshold must approve
+/// - Irreversible once the grace period ends
+pub fn deactivate_did_document_by_controllers(
+    ctx: Context<DeactivateDidDocumentByControllers>,
+    grace_period: i64,
+) -> Result<()> {
+    ctx.accounts
+        .controller_set
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+    did_document.deactivate(grace_period)?;
+
+    msg!(
+        "DID document deactivated by controllers: {}",
+        did_document.did
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Deactivate,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Reactivate a deactivated DID document
+///
+/// # Security
+/// - Only controller can reactivate
+/// - Only before the reactivation deadline set at deactivation
+pub fn reactivate_did_document(ctx: Context<ReactivateDidDocument>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+    did_document.reactivate(now)?;
+
+    msg!(
+        "DID document reactivated: {} (version: {})",
+        did_document.did,
+        did_document.version
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Reactivate,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Reactivate a deactivated multi-controller DID document
+///
+/// # Security
+/// - Controller set threshold must approve
+/// - Only before the reactivation deadline set at deactivation
+pub fn reactivate_did_document_by_controllers(
+    ctx: Context<ReactivateDidDocumentByControllers>,
+) -> Result<()> {
+    ctx.accounts
+        .controller_set
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+    did_document.reactivate(now)?;
+
+    msg!(
+        "DID document reactivated by controllers: {}",
+        did_document.did
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Reactivate,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Reclaim the rent of a tombstoned DID document (permissionless)
+///
+/// The account is shrunk to a tombstone (DID string, controller, version and
+/// deactivation time) rather than deleted, so the PDA cannot be re-created
+/// over its version history. Tombstoning is not a new version: the document
+/// resolves as deactivated either way.
+///
+/// # Security
+/// - Only after the reactivation grace period has ended
+/// - Rent goes to the recorded controller
+pub fn close_did_document(ctx: Context<CloseDidDocument>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let did_document = &mut ctx.accounts.did_document;
+    require!(did_document.is_tombstoned(now), DidError::DidNotTombstoned);
+
+    did_document.verification_methods.clear();
+    did_document.service_endpoints.clear();
+    did_document.context.clear();
+    did_document.also_known_as.clear();
 
-    /// Clock sysvar for timestamp validation
-    pub clock: Sysvar<'info, Clock>,
+    msg!("DID document tombstoned: {}", did_document.did);
+
+    Ok(())
 }
 
-/// Deactivate a DID document
-#[derive(Accounts)]
-pub struct DeactivateDidDocument<'info> {
-    /// DID document account with canonical PDA validation
-    #[account(
-        mut,
-        seeds = [
-            DID_DOCUMENT_SEED,
-            controller.key().as_ref()
-        ],
-        bump = did_document.bump,
-        constraint = did_document.controller == controller.key() @ DidError::UnauthorizedDidOperation,
-        constraint = did_document.is_active() @ DidError::AlreadyDeactivated
-    )]
-    pub did_document: Account<'info, DidDocument>,
+/// Designate or replace the recovery guardians of a DID document
+///
+/// # Security
+/// - Only controller can set guardians
+/// - The controller cannot be its own guardian
+/// - Recovery delay must be at least `MIN_DID_RECOVERY_DELAY`
+pub fn set_did_guardians(
+    ctx: Context<SetDidGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    recovery_delay: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    DidGuardianSet::validate(
+        &guardians,
+        threshold,
+        recovery_delay,
+        &ctx.accounts.controller.key(),
+    )?;
 
-    /// Controller of the DID (must match document controller)
-    #[account(mut)]
-    pub controller: Signer<'info>,
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.did_document = ctx.accounts.did_document.key();
+    guardian_set.controller = ctx.accounts.controller.key();
+    guardian_set.guardians = guardians;
+    guardian_set.threshold = threshold;
+    guardian_set.recovery_delay = recovery_delay;
+    guardian_set.updated_at = now;
+    guardian_set.bump = ctx.bumps.guardian_set;
 
-    /// Clock sysvar for timestamp validation
-    pub clock: Sysvar<'info, Clock>,
+    msg!(
+        "DID guardians set: {} ({}-of-{}, delay {}s)",
+        ctx.accounts.did_document.did,
+        threshold,
+        guardian_set.guardians.len(),
+        recovery_delay
+    );
+
+    Ok(())
 }
 
-/// Resolve a DID document (read-only operation)
-#[derive(Accounts)]
-pub struct ResolveDidDocument<'info> {
-    /// DID document account
-    #[account(
-        seeds = [
-            DID_DOCUMENT_SEED,
-            did_controller.key().as_ref()
-        ],
-        bump = did_document.bump
-    )]
-    pub did_document: Account<'info, DidDocument>,
+/// Start a guardian recovery of a DID document
+///
+/// # Security
+/// - Guardian threshold must approve (initiator counts)
+/// - Replacement executes only after the challenge window, during which the
+///   current controller can cancel
+pub fn initiate_did_recovery(
+    ctx: Context<InitiateDidRecovery>,
+    new_controller: Pubkey,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let guardian_set = &ctx.accounts.guardian_set;
+    let did_document = &ctx.accounts.did_document;
+    let initiator = ctx.accounts.initiator.key();
 
-    /// Controller public key (used for PDA derivation)
-    /// CHECK: This is safe as we only use it for PDA seeds
-    pub did_controller: UncheckedAccount<'info>,
+    require!(
+        guardian_set.controller == did_document.controller,
+        DidError::GuardianSetLapsed
+    );
+    require!(
+        guardian_set.guardians.contains(&initiator),
+        DidError::UnauthorizedDidOperation
+    );
+    require!(
+        new_controller != did_document.controller,
+        GhostSpeakError::InvalidInput
+    );
+
+    let mut approvals = approving_keys(&guardian_set.guardians, ctx.remaining_accounts);
+    if !approvals.contains(&initiator) {
+        approvals.push(initiator);
+    }
+    require!(
+        approvals.len() >= guardian_set.threshold as usize,
+        DidError::InsufficientGuardianApprovals
+    );
+
+    let executable_at = now
+        .checked_add(guardian_set.recovery_delay)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    let request = &mut ctx.accounts.recovery_request;
+    request.did_document = did_document.key();
+    request.previous_controller = did_document.controller;
+    request.new_controller = new_controller;
+    request.approvals = approvals.clone();
+    request.initiator = initiator;
+    request.initiated_at = now;
+    request.executable_at = executable_at;
+    request.bump = ctx.bumps.recovery_request;
+
+    emit!(DidRecoveryInitiatedEvent {
+        did_document: request.did_document,
+        new_controller,
+        approvals,
+        executable_at,
+        timestamp: now,
+    });
+
+    msg!(
+        "DID recovery initiated: {} (executable at {})",
+        did_document.did,
+        executable_at
+    );
+
+    Ok(())
 }
 
-/// Create a DID document for an agent or user
+/// Cancel a pending recovery
 ///
-/// # Arguments
-/// * `ctx` - Accounts context
-/// * `did_string` - The DID string (e.g., "did:sol:devnet:...")
-/// * `verification_methods` - Initial verification methods
-/// * `service_endpoints` - Initial service endpoints
+/// # Security
+/// - Only the current controller can cancel
+pub fn cancel_did_recovery(ctx: Context<CancelDidRecovery>) -> Result<()> {
+    let request = &ctx.accounts.recovery_request;
+
+    emit!(DidRecoveryResolvedEvent {
+        did_document: request.did_document,
+        previous_controller: request.previous_controller,
+        new_controller: request.new_controller,
+        executed: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("DID recovery cancelled: {}", ctx.accounts.did_document.did);
+
+    Ok(())
+}
+
+/// Execute a recovery, replacing the controller
 ///
 /// # Security
-/// - Validates DID format
-/// - Ensures controller authorization
-/// - Initializes with secure defaults
-pub fn create_did_document(
-    ctx: Context<CreateDidDocument>,
-    did_string: String,
-    verification_methods: Vec<VerificationMethod>,
-    service_endpoints: Vec<ServiceEndpoint>,
+/// - Challenge window must have elapsed
+/// - Controller must be unchanged since the request was made
+pub fn execute_did_recovery(ctx: Context<ExecuteDidRecovery>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let request = &ctx.accounts.recovery_request;
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+
+    require!(
+        now >= request.executable_at,
+        DidError::RecoveryChallengeActive
+    );
+    require!(
+        did_document.controller == request.previous_controller,
+        DidError::UnauthorizedDidOperation
+    );
+
+    did_document.controller = request.new_controller;
+    ctx.accounts.guardian_set.controller = request.new_controller;
+    did_document.updated_at = now;
+    did_document.version += 1;
+
+    emit!(DidRecoveryResolvedEvent {
+        did_document: did_document.key(),
+        previous_controller: request.previous_controller,
+        new_controller: request.new_controller,
+        executed: true,
+        timestamp: now,
+    });
+
+    msg!(
+        "DID recovery executed: {} (new controller: {})",
+        did_document.did,
+        request.new_controller
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Recovery,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Propose a new controller for a DID document
+///
+/// The transfer completes when `new_controller` accepts. Proposing again
+/// replaces any pending proposal.
+///
+/// # Security
+/// - Only controller can propose
+pub fn transfer_did_controller(
+    ctx: Context<TransferDidController>,
+    new_controller: Pubkey,
 ) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let controller = ctx.accounts.controller.key();
+
+    require!(new_controller != controller, GhostSpeakError::InvalidInput);
+
+    let transfer = &mut ctx.accounts.controller_transfer;
+    transfer.did_document = ctx.accounts.did_document.key();
+    transfer.current_controller = controller;
+    transfer.proposed_controller = new_controller;
+    transfer.proposed_at = now;
+    transfer.bump = ctx.bumps.controller_transfer;
+
+    emit!(DidControllerTransferProposedEvent {
+        did_document: transfer.did_document,
+        current_controller: controller,
+        proposed_controller: new_controller,
+        timestamp: now,
+    });
+
+    msg!(
+        "DID controller transfer proposed: {} -> {}",
+        ctx.accounts.did_document.did,
+        new_controller
+    );
+
+    Ok(())
+}
+
+/// Accept a proposed controller transfer
+///
+/// Guardians appointed by the previous controller lapse; the new controller
+/// appoints its own with `set_did_guardians`.
+///
+/// # Security
+/// - Only the proposed controller can accept
+/// - Proposal is void if the controller changed since it was made
+pub fn accept_did_controller(ctx: Context<AcceptDidController>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let new_controller = ctx.accounts.new_controller.key();
     let did_document = &mut ctx.accounts.did_document;
-    let clock = Clock::get()?;
+    let prior_state_hash = did_document.state_hash()?;
+    let previous_controller = did_document.controller;
+
+    did_document.controller = new_controller;
+    did_document.updated_at = now;
+    did_document.version += 1;
+
+    emit!(DidControllerTransferResolvedEvent {
+        did_document: did_document.key(),
+        previous_controller,
+        proposed_controller: new_controller,
+        accepted: true,
+        timestamp: now,
+    });
+
+    msg!(
+        "DID controller transferred: {} -> {}",
+        did_document.did,
+        new_controller
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::ControllerTransfer,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Cancel a proposed controller transfer
+///
+/// # Security
+/// - Only the current controller can cancel
+pub fn cancel_did_controller_transfer(ctx: Context<CancelDidControllerTransfer>) -> Result<()> {
+    let transfer = &ctx.accounts.controller_transfer;
+
+    emit!(DidControllerTransferResolvedEvent {
+        did_document: transfer.did_document,
+        previous_controller: transfer.current_controller,
+        proposed_controller: transfer.proposed_controller,
+        accepted: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "DID controller transfer cancelled: {}",
+        ctx.accounts.did_document.did
+    );
+
+    Ok(())
+}
+
+/// Rotate a verification key
+///
+/// Replaces `old_method_id` with `new_method` in one update and records the
+/// rotation so verifiers can follow the key chain.
+///
+/// # Security
+/// - Only controller can rotate
+/// - Old and new keys must both sign, proving continuity
+/// - Both methods must be Ed25519 keys matching their signers
+pub fn rotate_did_key(
+    ctx: Context<RotateDidKey>,
+    old_method_id: String,
+    new_method: VerificationMethod,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let old_key = ctx.accounts.old_key.key();
+    let new_key = ctx.accounts.new_key.key();
 
-    // Validate DID format (did:sol:network:pubkey)
     require!(
-        did_string.starts_with("did:sol:"),
+        new_method.id.len() <= MAX_METHOD_ID,
         DidError::InvalidDidFormat
     );
+    require!(
+        old_key != new_key
+            && new_method.method_type == VerificationMethodType::Ed25519VerificationKey2020
+            && new_method.public_key_multibase == ed25519_multibase(&new_key)
+            && !new_method.revoked,
+        DidError::RotationKeyMismatch
+    );
+
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+    let from_version = did_document.version;
+    let new_method_id = new_method.id.clone();
+    let old_method = did_document.rotate_verification_method(&old_method_id, new_method, now)?;
 
-    // Validate verification methods count
     require!(
-        verification_methods.len() <= MAX_VERIFICATION_METHODS,
-        DidError::TooManyVerificationMethods
+        old_method.method_type == VerificationMethodType::Ed25519VerificationKey2020
+            && old_method.public_key_multibase == ed25519_multibase(&old_key)
+            && !old_method.revoked,
+        DidError::RotationKeyMismatch
     );
 
-    // Validate service endpoints count
+    let rotation = &mut ctx.accounts.rotation;
+    rotation.did_document = did_document.key();
+    rotation.from_version = from_version;
+    rotation.old_method_id = old_method_id;
+    rotation.old_key = old_key;
+    rotation.new_method_id = new_method_id;
+    rotation.new_key = new_key;
+    rotation.rotated_at = now;
+    rotation.bump = ctx.bumps.rotation;
+
+    emit!(DidKeyRotatedEvent {
+        did_document: rotation.did_document,
+        rotation: rotation.key(),
+        old_key,
+        new_key,
+        from_version,
+        timestamp: now,
+    });
+
+    msg!(
+        "DID key rotated: {} (version: {})",
+        did_document.did,
+        did_document.version
+    );
+
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::KeyRotation,
+        ctx.bumps.version_entry,
+    )
+}
+
+/// Link a DID document and an agent account to each other
+///
+/// Writes both halves of the binding: `Agent::did_address` and the
+/// `DidAgentLink` PDA. Any previous link of either side is superseded; stale
+/// halves fail `verify_did_agent_link`.
+///
+/// # Security
+/// - DID controller must own the agent and sign
+pub fn link_did_to_agent(ctx: Context<LinkDidToAgent>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let did_key = ctx.accounts.did_document.key();
+    let agent = &mut ctx.accounts.agent;
+
+    agent.did_address = Some(did_key);
+    agent.updated_at = now;
+
+    let link = &mut ctx.accounts.did_agent_link;
+    link.did_document = did_key;
+    link.agent = agent.key();
+    link.linked_at = now;
+    link.bump = ctx.bumps.did_agent_link;
+
+    emit!(DidAgentLinkedEvent {
+        did_document: did_key,
+        agent: link.agent,
+        controller: ctx.accounts.owner.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "DID {} linked to agent {}",
+        ctx.accounts.did_document.did,
+        link.agent
+    );
+
+    Ok(())
+}
+
+/// Check that a DID and agent are reciprocally linked
+///
+/// Fails unless the link PDA and `Agent::did_address` point at each other,
+/// the DID is active and its controller owns the agent. Issuers can call
+/// this (or CPI it) before trusting the binding.
+pub fn verify_did_agent_link(ctx: Context<VerifyDidAgentLink>) -> Result<()> {
+    ctx.accounts.did_agent_link.require_consistent(
+        &ctx.accounts.did_document.key(),
+        &ctx.accounts.did_document,
+        &ctx.accounts.agent.key(),
+        &ctx.accounts.agent,
+    )
+}
+
+/// Record an attestor-verified link to an external DID
+///
+/// For DIDs whose control cannot be proven on-chain (did:web, did:ethr) a
+/// registered agent-identity issuer checks the external document off-chain
+/// and vouches for the link.
+///
+/// # Security
+/// - Attestor must be an active `AgentIdentity` issuer in the registry
+pub fn attest_external_did_link(
+    ctx: Context<AttestExternalDidLink>,
+    external_did: String,
+) -> Result<()> {
+    let method = ExternalDidMethod::parse(&external_did)?;
+    let attestor = ctx.accounts.attestor.key();
     require!(
-        service_endpoints.len() <= MAX_SERVICE_ENDPOINTS,
-        DidError::TooManyServiceEndpoints
+        ctx.accounts
+            .issuer_registry
+            .get_active_issuer(&attestor, CredentialKind::AgentIdentity)
+            .is_some(),
+        GhostSpeakError::IssuerNotRegistered
     );
 
-    // Initialize DID document
-    did_document.did = did_string.clone();
-    did_document.controller = ctx.accounts.controller.key();
-    did_document.verification_methods = verification_methods;
-    did_document.service_endpoints = service_endpoints;
+    record_external_did_link(
+        &ctx.accounts.did_document,
+        &mut ctx.accounts.external_link,
+        external_did,
+        method,
+        ExternalLinkProof::Attestor(attestor),
+        ctx.bumps.external_link,
+    )
+}
 
-    // Set W3C context
-    did_document.context = vec![
-        "https://www.w3.org/ns/did/v1".to_string(),
-        "https://w3id.org/security/suites/ed25519-2020/v1".to_string(),
-    ];
+/// Record a link to an external `did:key` proven by a signed challenge
+///
+/// The transaction must carry an Ed25519 instruction, immediately before
+/// this one, in which the `did:key`'s key signs
+/// `DidExternalLink::challenge` for the next revision.
+///
+/// # Security
+/// - Only controller can link
+/// - Only Ed25519 `did:key` identifiers can prove control this way
+pub fn prove_external_did_link(
+    ctx: Context<ProveExternalDidLink>,
+    external_did: String,
+) -> Result<()> {
+    let method = ExternalDidMethod::parse(&external_did)?;
+    let external_key = did_key_ed25519(&external_did).ok_or(DidError::ExternalDidUnprovable)?;
 
-    did_document.also_known_as = Vec::new();
-    did_document.created_at = clock.unix_timestamp;
-    did_document.updated_at = clock.unix_timestamp;
-    did_document.version = 1;
-    did_document.deactivated = false;
-    did_document.deactivated_at = None;
-    did_document.bump = ctx.bumps.did_document;
+    let message = DidExternalLink::challenge(
+        &ctx.accounts.did_document.key(),
+        ctx.accounts.external_link.next_revision()?,
+        &external_did,
+    );
+    require_ed25519_signature(&ctx.accounts.instructions_sysvar, &external_key, &message)?;
+
+    record_external_did_link(
+        &ctx.accounts.did_document,
+        &mut ctx.accounts.external_link,
+        external_did,
+        method,
+        ExternalLinkProof::KeyChallenge,
+        ctx.bumps.external_link,
+    )
+}
+
+/// Write a verified external link
+///
+/// Shared by `attest_external_did_link` and `prove_external_did_link`.
+fn record_external_did_link(
+    did_document: &Account<DidDocument>,
+    link: &mut Account<DidExternalLink>,
+    external_did: String,
+    method: ExternalDidMethod,
+    proof: ExternalLinkProof,
+    bump: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    link.revision = link.next_revision()?;
+    link.did_document = did_document.key();
+    link.external_did = external_did;
+    link.method = method;
+    link.proof = proof;
+    link.linked_at = now;
+    link.revoked = false;
+    link.bump = bump;
+
+    emit!(DidExternalLinkUpdatedEvent {
+        did_document: link.did_document,
+        external_did: link.external_did.clone(),
+        method,
+        proof,
+        revision: link.revision,
+        revoked: false,
+        timestamp: now,
+    });
+
+    msg!("DID {} linked to {}", did_document.did, link.external_did);
+
+    Ok(())
+}
+
+/// Revoke a link to an external DID
+///
+/// The link PDA is kept so its revision keeps old challenges from being
+/// replayed.
+///
+/// # Security
+/// - Only controller can revoke
+pub fn revoke_external_did_link(ctx: Context<RevokeExternalDidLink>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let link = &mut ctx.accounts.external_link;
+    link.revoked = true;
+
+    emit!(DidExternalLinkUpdatedEvent {
+        did_document: link.did_document,
+        external_did: link.external_did.clone(),
+        method: link.method,
+        proof: link.proof,
+        revision: link.revision,
+        revoked: true,
+        timestamp: now,
+    });
 
     msg!(
-        "DID document created: {} by controller: {}",
-        did_string,
-        ctx.accounts.controller.key()
+        "DID {} unlinked from {}",
+        ctx.accounts.did_document.did,
+        link.external_did
     );
 
     Ok(())
 }
 
-/// Update a DID document by adding/removing verification methods or services
+/// Add a verification method for a scoped delegate key
 ///
-/// # Arguments
-/// * `add_verification_method` - Optional verification method to add
-/// * `remove_verification_method_id` - Optional method ID to remove
-/// * `add_service_endpoint` - Optional service endpoint to add
-/// * `remove_service_endpoint_id` - Optional service ID to remove
+/// Operator keys get an Ed25519 method on the DID plus a `DidDelegateKey`
+/// record listing what they may do for the DID's linked agent (e.g. submit
+/// deliveries). Downstream instructions accept the key only within those
+/// scopes.
 ///
 /// # Security
-/// - Only controller can update
-/// - Version number incremented
-/// - Timestamp updated
-pub fn update_did_document(
-    ctx: Context<UpdateDidDocument>,
-    add_verification_method: Option<VerificationMethod>,
-    remove_verification_method_id: Option<String>,
-    add_service_endpoint: Option<ServiceEndpoint>,
-    remove_service_endpoint_id: Option<String>,
+/// - Only controller can add delegates
+/// - Method must be an Ed25519 key matching `delegate`
+/// - Delegates never get capability invocation or delegation, so they
+///   cannot update the DID document
+pub fn add_delegate_verification_method(
+    ctx: Context<AddDelegateVerificationMethod>,
+    method: VerificationMethod,
+    delegate: Pubkey,
+    scopes: Vec<DidKeyScope>,
+    expires_at: Option<i64>,
 ) -> Result<()> {
-    let did_document = &mut ctx.accounts.did_document;
+    let now = Clock::get()?.unix_timestamp;
 
-    // Add verification method if provided
-    if let Some(method) = add_verification_method {
-        did_document.add_verification_method(method)?;
-    }
+    require!(method.id.len() <= MAX_METHOD_ID, DidError::InvalidDidFormat);
+    require!(
+        method.method_type == VerificationMethodType::Ed25519VerificationKey2020
+            && method.public_key_multibase == ed25519_multibase(&delegate)
+            && !method.revoked
+            && !method.relationships.iter().any(|relationship| matches!(
+                relationship,
+                VerificationRelationship::CapabilityInvocation
+                    | VerificationRelationship::CapabilityDelegation
+            )),
+        DidError::InvalidDelegateKey
+    );
+    require!(
+        !scopes.is_empty()
+            && scopes.len() <= MAX_DELEGATE_SCOPES
+            && expires_at.is_none_or(|expires_at| expires_at > now),
+        DidError::InvalidDelegateKey
+    );
 
-    // Remove verification method if provided
-    if let Some(method_id) = remove_verification_method_id {
-        did_document.remove_verification_method(&method_id)?;
-    }
+    let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
+    let method_id = method.id.clone();
+    did_document.add_verification_method(method)?;
 
-    // Add service endpoint if provided
-    if let Some(service) = add_service_endpoint {
-        did_document.add_service_endpoint(service)?;
-    }
+    let delegate_key = &mut ctx.accounts.delegate_key;
+    delegate_key.did_document = did_document.key();
+    delegate_key.delegate = delegate;
+    delegate_key.method_id = method_id;
+    delegate_key.scopes = scopes;
+    delegate_key.expires_at = expires_at;
+    delegate_key.created_at = now;
+    delegate_key.bump = ctx.bumps.delegate_key;
 
-    // Remove service endpoint if provided
-    if let Some(service_id) = remove_service_endpoint_id {
-        did_document.remove_service_endpoint(&service_id)?;
-    }
+    emit!(DidDelegateKeyUpdatedEvent {
+        did_document: delegate_key.did_document,
+        delegate,
+        method_id: delegate_key.method_id.clone(),
+        scopes: delegate_key.scopes.clone(),
+        expires_at,
+        removed: false,
+        timestamp: now,
+    });
 
     msg!(
-        "DID document updated: {} (version: {})",
+        "Delegate key {} added to DID {} (version: {})",
+        delegate,
         did_document.did,
         did_document.version
     );
 
-    Ok(())
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Update,
+        ctx.bumps.version_entry,
+    )
 }
 
-/// Deactivate a DID document
+/// Remove a scoped delegate key
+///
+/// Drops the delegate's verification method from the DID and closes its
+/// scope record, refunding the rent to the controller.
 ///
 /// # Security
-/// - Only controller can deactivate
-/// - Deactivation is irreversible
-/// - Timestamp recorded
-pub fn deactivate_did_document(ctx: Context<DeactivateDidDocument>) -> Result<()> {
+/// - Only controller can remove delegates
+pub fn remove_delegate_verification_method(
+    ctx: Context<RemoveDelegateVerificationMethod>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let delegate_key = &ctx.accounts.delegate_key;
     let did_document = &mut ctx.accounts.did_document;
+    let prior_state_hash = did_document.state_hash()?;
 
-    // Deactivate the DID
-    did_document.deactivate()?;
+    did_document
+        .verification_methods
+        .retain(|method| method.id != delegate_key.method_id);
+    did_document.updated_at = now;
+    did_document.version = did_document.next_version();
+
+    emit!(DidDelegateKeyUpdatedEvent {
+        did_document: did_document.key(),
+        delegate: delegate_key.delegate,
+        method_id: delegate_key.method_id.clone(),
+        scopes: delegate_key.scopes.clone(),
+        expires_at: delegate_key.expires_at,
+        removed: true,
+        timestamp: now,
+    });
 
     msg!(
-        "DID document deactivated: {} at {}",
+        "Delegate key {} removed from DID {} (version: {})",
+        delegate_key.delegate,
         did_document.did,
-        did_document.deactivated_at.unwrap()
+        did_document.version
     );
 
-    Ok(())
+    ctx.accounts.version_entry.record(
+        &ctx.accounts.did_document,
+        prior_state_hash,
+        DidChangeType::Update,
+        ctx.bumps.version_entry,
+    )
 }
 
 /// Resolve a DID document (read-only query)
 ///
-/// This is primarily used for off-chain resolution.
-/// The actual resolution is done by reading the account data.
+/// Lets CPI consumers and simulators read a document without knowing the
+/// account layout. Documents larger than the return data limit are split
+/// into chunks; callers fetch chunk 0 and then the remaining
+/// `total_chunks - 1`, checking the reassembled bytes against `state_hash`.
 ///
 /// # Returns
-/// The DID document data is returned via the account context
-pub fn resolve_did_document(_ctx: Context<ResolveDidDocument>) -> Result<()> {
-    // Resolution is implicit - the account data is returned
-    // This instruction exists for compatibility with standard DID resolution flows
-    Ok(())
+/// A `DidResolutionChunk` via return data
+pub fn resolve_did_document(
+    ctx: Context<ResolveDidDocument>,
+    chunk_index: u16,
+) -> Result<DidResolutionChunk> {
+    ctx.accounts
+        .did_document
+        .resolution_chunk(&ctx.accounts.did_document.key(), chunk_index)
 }
 
 /// Helper function to generate a did:sol string from a public key
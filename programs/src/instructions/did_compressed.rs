@@ -0,0 +1,483 @@
+/*!
+ * Compressed DID Instructions
+ *
+ * Identity path for fleets of lightweight agents: instead of a rent-paying
+ * `DidDocument` account per agent, the document is hashed into a leaf of a
+ * concurrent Merkle tree owned by an operator's tree config PDA, mirroring
+ * `issue_credential_compressed`.
+ *
+ * Updates and resolution take the current leaf plus its Merkle proof (as
+ * remaining accounts); the program checks the proof through the compression
+ * program before replacing or vouching for the leaf. Indexers rebuild leaves
+ * from the emitted events.
+ */
+
+use crate::state::did::*;
+use crate::GhostSpeakError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use sha3::{Digest, Keccak256};
+
+pub const DID_TREE_CONFIG_SEED: &[u8] = b"did_tree_config";
+
+/// spl-account-compression `append` discriminator
+const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+/// spl-account-compression `replace_leaf` discriminator
+const REPLACE_LEAF_DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+/// spl-account-compression `verify_leaf` discriminator
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+/// Create a DID document as a compressed Merkle tree leaf
+#[derive(Accounts)]
+pub struct CreateDidCompressed<'info> {
+    /// Operator's tree config PDA, the authority of `merkle_tree`
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = DidTreeConfig::LEN,
+        seeds = [DID_TREE_CONFIG_SEED, operator.key().as_ref()],
+        bump
+    )]
+    pub tree_config: Account<'info, DidTreeConfig>,
+
+    /// The Merkle tree account that stores compressed DID documents
+    /// CHECK: This account is validated by the compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Fleet operator paying for the tree config
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// SPL Account Compression program
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_account_compression::ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL Noop program for logging
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_noop::ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Update or deactivate a compressed DID document
+///
+/// The leaf's Merkle proof is passed as remaining accounts.
+#[derive(Accounts)]
+pub struct UpdateDidCompressed<'info> {
+    /// Tree config PDA of the operator that created the document
+    #[account(
+        seeds = [DID_TREE_CONFIG_SEED, tree_config.operator.as_ref()],
+        bump = tree_config.bump,
+        constraint = tree_config.merkle_tree == merkle_tree.key() @ GhostSpeakError::DidTreeMismatch
+    )]
+    pub tree_config: Account<'info, DidTreeConfig>,
+
+    /// The Merkle tree account that stores compressed DID documents
+    /// CHECK: This account is validated by the compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Controller recorded in the current leaf
+    pub controller: Signer<'info>,
+
+    /// SPL Account Compression program
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_account_compression::ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL Noop program for logging
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_noop::ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+}
+
+/// Resolve a compressed DID document against its Merkle proof
+///
+/// The leaf's Merkle proof is passed as remaining accounts.
+#[derive(Accounts)]
+pub struct ResolveDidCompressed<'info> {
+    #[account(
+        seeds = [DID_TREE_CONFIG_SEED, tree_config.operator.as_ref()],
+        bump = tree_config.bump,
+        constraint = tree_config.merkle_tree == merkle_tree.key() @ GhostSpeakError::DidTreeMismatch
+    )]
+    pub tree_config: Account<'info, DidTreeConfig>,
+
+    /// The Merkle tree account that stores compressed DID documents
+    /// CHECK: This account is validated by the compression program
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// SPL Account Compression program
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_account_compression::ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// DID Tree Configuration for compressed storage
+#[account]
+pub struct DidTreeConfig {
+    /// Operator whose fleet's documents the tree holds
+    pub operator: Pubkey,
+    /// Merkle tree bound on first creation
+    pub merkle_tree: Pubkey,
+    /// Number of documents appended to the tree
+    pub num_minted: u64,
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl DidTreeConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // operator
+        32 + // merkle_tree
+        8 +  // num_minted
+        1; // bump
+}
+
+/// Compressed DID document hashed into the Merkle tree leaf
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompressedDidLeaf {
+    pub did: String,
+    pub controller: Pubkey,
+    pub verification_methods: Vec<VerificationMethod>,
+    pub service_endpoints: Vec<ServiceEndpoint>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub version: u32,
+    pub deactivated: bool,
+}
+
+impl CompressedDidLeaf {
+    /// Keccak256 of the serialized leaf
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.try_to_vec()?);
+        Ok(hasher.finalize().into())
+    }
+
+    /// Apply a batched update the same way `update_did_document` does:
+    /// removals first, then additions, one version per call
+    fn apply_update(
+        &mut self,
+        add_verification_methods: Vec<VerificationMethod>,
+        remove_verification_method_ids: Vec<String>,
+        add_service_endpoints: Vec<ServiceEndpoint>,
+        remove_service_endpoint_ids: Vec<String>,
+        now: i64,
+    ) -> Result<()> {
+        for method_id in remove_verification_method_ids {
+            let initial_len = self.verification_methods.len();
+            self.verification_methods.retain(|m| m.id != method_id);
+            require!(
+                self.verification_methods.len() < initial_len,
+                DidError::MethodNotFound
+            );
+        }
+        for service_id in remove_service_endpoint_ids {
+            let initial_len = self.service_endpoints.len();
+            self.service_endpoints.retain(|s| s.id != service_id);
+            require!(
+                self.service_endpoints.len() < initial_len,
+                DidError::ServiceNotFound
+            );
+        }
+
+        for method in add_verification_methods {
+            require!(
+                !self.verification_methods.iter().any(|m| m.id == method.id),
+                DidError::DuplicateMethodId
+            );
+            self.verification_methods.push(method);
+        }
+        for service in add_service_endpoints {
+            service.validate()?;
+            require!(
+                !self.service_endpoints.iter().any(|s| s.id == service.id),
+                DidError::DuplicateServiceId
+            );
+            self.service_endpoints.push(service);
+        }
+
+        self.validate()?;
+        self.updated_at = now;
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.did.starts_with("did:sol:") && self.did.len() <= MAX_DID_STRING,
+            DidError::InvalidDidFormat
+        );
+        require!(
+            self.verification_methods.len() <= MAX_VERIFICATION_METHODS,
+            DidError::TooManyVerificationMethods
+        );
+        require!(
+            self.service_endpoints.len() <= MAX_SERVICE_ENDPOINTS,
+            DidError::TooManyServiceEndpoints
+        );
+        for service in &self.service_endpoints {
+            service.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Create a DID document as a compressed leaf
+pub fn create_did_compressed(
+    ctx: Context<CreateDidCompressed>,
+    did_string: String,
+    controller: Pubkey,
+    verification_methods: Vec<VerificationMethod>,
+    service_endpoints: Vec<ServiceEndpoint>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let leaf = CompressedDidLeaf {
+        did: did_string,
+        controller,
+        verification_methods,
+        service_endpoints,
+        created_at: now,
+        updated_at: now,
+        version: 1,
+        deactivated: false,
+    };
+    leaf.validate()?;
+
+    // Initialize tree config if needed; the first tree used stays bound
+    let tree_config = &mut ctx.accounts.tree_config;
+    if tree_config.operator == Pubkey::default() {
+        tree_config.operator = ctx.accounts.operator.key();
+        tree_config.merkle_tree = ctx.accounts.merkle_tree.key();
+        tree_config.num_minted = 0;
+        tree_config.bump = ctx.bumps.tree_config;
+    }
+    require!(
+        tree_config.merkle_tree == ctx.accounts.merkle_tree.key(),
+        GhostSpeakError::DidTreeMismatch
+    );
+
+    let data_hash = leaf.hash()?;
+
+    // Raw invoke for the same anchor-lang version reason as
+    // `register_agent_compressed`
+    let mut data = APPEND_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&data_hash);
+    let append_ix = Instruction {
+        program_id: spl_account_compression::ID,
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+            AccountMeta::new_readonly(tree_config.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.log_wrapper.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &append_ix,
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            tree_config.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+        ],
+        &[&[
+            DID_TREE_CONFIG_SEED,
+            tree_config.operator.as_ref(),
+            &[tree_config.bump],
+        ]],
+    )?;
+
+    let leaf_index = tree_config.num_minted;
+    tree_config.num_minted = leaf_index
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    emit!(CompressedDidUpdatedEvent {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        leaf_index,
+        data_hash,
+        leaf: leaf.clone(),
+    });
+
+    msg!("Created compressed DID {} (leaf {})", leaf.did, leaf_index);
+    Ok(())
+}
+
+/// Update a compressed DID document
+///
+/// # Arguments
+/// * `root` - Tree root the proof was built against
+/// * `leaf_index` - Index of the document's leaf
+/// * `current` - Current document; must hash to the leaf at `leaf_index`
+/// * remaining arguments as in `update_did_document`
+///
+/// # Security
+/// - Only the controller recorded in the leaf can update
+/// - The compression program rejects stale leaves and invalid proofs
+#[allow(clippy::too_many_arguments)]
+pub fn update_did_compressed<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateDidCompressed<'info>>,
+    root: [u8; 32],
+    leaf_index: u32,
+    current: CompressedDidLeaf,
+    add_verification_methods: Vec<VerificationMethod>,
+    remove_verification_method_ids: Vec<String>,
+    add_service_endpoints: Vec<ServiceEndpoint>,
+    remove_service_endpoint_ids: Vec<String>,
+) -> Result<()> {
+    let mut leaf = current.clone();
+    leaf.apply_update(
+        add_verification_methods,
+        remove_verification_method_ids,
+        add_service_endpoints,
+        remove_service_endpoint_ids,
+        Clock::get()?.unix_timestamp,
+    )?;
+    replace_did_leaf(ctx, root, leaf_index, &current, leaf)
+}
+
+/// Deactivate a compressed DID document
+///
+/// # Security
+/// - Only the controller recorded in the leaf can deactivate
+/// - Deactivation is irreversible
+pub fn deactivate_did_compressed<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateDidCompressed<'info>>,
+    root: [u8; 32],
+    leaf_index: u32,
+    current: CompressedDidLeaf,
+) -> Result<()> {
+    let mut leaf = current.clone();
+    leaf.deactivated = true;
+    leaf.updated_at = Clock::get()?.unix_timestamp;
+    leaf.version = leaf.version.saturating_add(1);
+    replace_did_leaf(ctx, root, leaf_index, &current, leaf)
+}
+
+/// Swap `current` for `leaf` in the tree, signed by the tree config PDA
+fn replace_did_leaf<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateDidCompressed<'info>>,
+    root: [u8; 32],
+    leaf_index: u32,
+    current: &CompressedDidLeaf,
+    leaf: CompressedDidLeaf,
+) -> Result<()> {
+    require!(
+        current.controller == ctx.accounts.controller.key(),
+        DidError::UnauthorizedDidOperation
+    );
+    require!(!current.deactivated, DidError::DidDeactivated);
+
+    let previous_hash = current.hash()?;
+    let data_hash = leaf.hash()?;
+    let tree_config = &ctx.accounts.tree_config;
+
+    let mut data = REPLACE_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&previous_hash);
+    data.extend_from_slice(&data_hash);
+    data.extend_from_slice(&leaf_index.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+        AccountMeta::new_readonly(tree_config.key(), true),
+        AccountMeta::new_readonly(ctx.accounts.log_wrapper.key(), false),
+    ];
+    let mut account_infos = vec![
+        ctx.accounts.merkle_tree.to_account_info(),
+        tree_config.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+    ];
+    for node in ctx.remaining_accounts {
+        accounts.push(AccountMeta::new_readonly(node.key(), false));
+        account_infos.push(node.clone());
+    }
+
+    invoke_signed(
+        &Instruction {
+            program_id: spl_account_compression::ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+        &[&[
+            DID_TREE_CONFIG_SEED,
+            tree_config.operator.as_ref(),
+            &[tree_config.bump],
+        ]],
+    )?;
+
+    emit!(CompressedDidUpdatedEvent {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        leaf_index: leaf_index as u64,
+        data_hash,
+        leaf: leaf.clone(),
+    });
+
+    msg!(
+        "Compressed DID {} updated (version: {})",
+        leaf.did,
+        leaf.version
+    );
+    Ok(())
+}
+
+/// Resolve a compressed DID document (read-only)
+///
+/// Fails unless `leaf` is the document at `leaf_index` under `root`, so
+/// callers (or CPI users) can trust its contents.
+pub fn resolve_did_compressed<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveDidCompressed<'info>>,
+    root: [u8; 32],
+    leaf_index: u32,
+    leaf: CompressedDidLeaf,
+) -> Result<()> {
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf.hash()?);
+    data.extend_from_slice(&leaf_index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(
+        ctx.accounts.merkle_tree.key(),
+        false,
+    )];
+    let mut account_infos = vec![ctx.accounts.merkle_tree.to_account_info()];
+    for node in ctx.remaining_accounts {
+        accounts.push(AccountMeta::new_readonly(node.key(), false));
+        account_infos.push(node.clone());
+    }
+
+    invoke(
+        &Instruction {
+            program_id: spl_account_compression::ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+    )?;
+
+    msg!(
+        "Resolved compressed DID {} (version: {}, deactivated: {})",
+        leaf.did,
+        leaf.version,
+        leaf.deactivated
+    );
+    Ok(())
+}
+
+/// Event emitted when a compressed DID document is created or changed
+///
+/// Carries the full leaf, so indexers can serve documents and proofs
+/// without any account reads.
+#[event]
+pub struct CompressedDidUpdatedEvent {
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u64,
+    pub data_hash: [u8; 32],
+    pub leaf: CompressedDidLeaf,
+}
@@ -0,0 +1,224 @@
+/*!
+ * Emergency Freeze Instructions
+ *
+ * Puts `EmergencyConfig` to work: once a multisig enables freezing, its
+ * emergency quorum (signers and emergency contacts) can freeze it, or
+ * pause the whole protocol through the circuit breaker if the multisig is
+ * one of the breaker's authorities. The breaker lives in this program, so
+ * it is updated directly rather than through a CPI.
+ *
+ * A frozen multisig queues no transactions, changes no signers and
+ * co-signs nothing through `require_approvals`, and a queued admin
+ * transfer it governs cannot be applied. Freezes
+ * lift early by emergency quorum, or by anyone once the multisig's
+ * `auto_unfreeze_duration` has passed.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::security::CircuitBreaker;
+use crate::state::governance::Multisig;
+use crate::GhostSpeakError;
+
+// =====================================================
+// EMERGENCY CONFIG
+// =====================================================
+
+/// Change who may freeze a multisig and for how long
+///
+/// Current signers are passed as remaining accounts and must meet the
+/// multisig's signer change threshold.
+pub fn set_emergency_config(
+    ctx: Context<crate::instructions::UpdateMultisigSigners>,
+    emergency_contacts: Vec<Pubkey>,
+    emergency_threshold: u8,
+    freeze_enabled: bool,
+    auto_unfreeze_duration: Option<i64>,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_signer_change_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.set_emergency_config(
+        emergency_contacts,
+        emergency_threshold,
+        freeze_enabled,
+        auto_unfreeze_duration,
+        now,
+    )?;
+
+    let emergency = &multisig.emergency_config;
+    emit!(EmergencyConfigUpdatedEvent {
+        multisig: multisig.key(),
+        contact_count: emergency.emergency_contacts.len() as u8,
+        emergency_threshold,
+        freeze_enabled,
+        auto_unfreeze_duration,
+        timestamp: now,
+    });
+
+    msg!(
+        "Multisig {} emergency config updated (freeze enabled: {})",
+        multisig.multisig_id,
+        freeze_enabled
+    );
+    Ok(())
+}
+
+// =====================================================
+// MULTISIG FREEZE
+// =====================================================
+
+/// Freeze or unfreeze a multisig (emergency quorum required)
+///
+/// Signers and emergency contacts are passed as remaining accounts and
+/// must meet the emergency threshold.
+#[derive(Accounts)]
+pub struct EmergencyFreeze<'info> {
+    #[account(mut)]
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn emergency_freeze_multisig(ctx: Context<EmergencyFreeze>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_emergency_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.freeze(now)?;
+
+    let unfreezes_at = multisig
+        .emergency_config
+        .auto_unfreeze_duration
+        .map(|duration| now.saturating_add(duration));
+    emit!(MultisigFrozenEvent {
+        multisig: multisig.key(),
+        frozen_at: now,
+        unfreezes_at,
+    });
+
+    msg!("Multisig {} frozen", multisig.multisig_id);
+    Ok(())
+}
+
+pub fn emergency_unfreeze_multisig(ctx: Context<EmergencyFreeze>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.require_emergency_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    multisig.unfreeze(now)?;
+
+    emit!(MultisigUnfrozenEvent {
+        multisig: multisig.key(),
+        expired: false,
+        timestamp: now,
+    });
+
+    msg!("Multisig {} unfrozen", multisig.multisig_id);
+    Ok(())
+}
+
+/// Lift a freeze that has outlived its auto-unfreeze duration (permissionless crank)
+#[derive(Accounts)]
+pub struct ExpireMultisigFreeze<'info> {
+    #[account(mut)]
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn expire_multisig_freeze(ctx: Context<ExpireMultisigFreeze>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        multisig.freeze_expired(now),
+        GhostSpeakError::FreezeNotExpired
+    );
+    multisig.unfreeze(now)?;
+
+    emit!(MultisigUnfrozenEvent {
+        multisig: multisig.key(),
+        expired: true,
+        timestamp: now,
+    });
+
+    msg!("Multisig {} freeze expired", multisig.multisig_id);
+    Ok(())
+}
+
+// =====================================================
+// PROTOCOL PAUSE
+// =====================================================
+
+/// Pause the whole protocol through the circuit breaker (emergency quorum
+/// of a breaker authority multisig required)
+///
+/// Signers and emergency contacts are passed as remaining accounts and
+/// must meet the emergency threshold. The breaker admin unpauses.
+#[derive(Accounts)]
+pub struct EmergencyPauseProtocol<'info> {
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker"],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.multisig_authorities.contains(&multisig.key()) @ GhostSpeakError::UnauthorizedAccess,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn emergency_pause_protocol(
+    ctx: Context<EmergencyPauseProtocol>,
+    reason: String,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    multisig.require_emergency_approvals(ctx.remaining_accounts)?;
+
+    let breaker = &mut ctx.accounts.circuit_breaker;
+    breaker.pause_all(reason)?;
+
+    emit!(ProtocolEmergencyPausedEvent {
+        multisig: multisig.key(),
+        reason: breaker.pause_reason.clone(),
+        timestamp: breaker.last_paused_at,
+    });
+
+    Ok(())
+}
+
+// =====================================================
+// EVENTS
+// =====================================================
+
+#[event]
+pub struct EmergencyConfigUpdatedEvent {
+    pub multisig: Pubkey,
+    pub contact_count: u8,
+    pub emergency_threshold: u8,
+    pub freeze_enabled: bool,
+    pub auto_unfreeze_duration: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigFrozenEvent {
+    pub multisig: Pubkey,
+    pub frozen_at: i64,
+    /// When the freeze can be lifted by anyone (None = only by quorum)
+    pub unfreezes_at: Option<i64>,
+}
+
+#[event]
+pub struct MultisigUnfrozenEvent {
+    pub multisig: Pubkey,
+    /// True when lifted by the expiry crank rather than the quorum
+    pub expired: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolEmergencyPausedEvent {
+    pub multisig: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
@@ -0,0 +1,323 @@
+/*!
+ * Execution Queue Instructions
+ *
+ * Batches the execution of passed governance proposals. An executor
+ * queues proposals by priority and processes them once their execution
+ * delay is over; a proposal not executed within the queue's execution
+ * window expires.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::governance::{
+    ExecutionQueue, ExecutionQueueStatus, ExecutionResult, ExecutionStatus, GovernanceProposal,
+    ProposalStatus, TransactionPriority, EXECUTION_QUEUE_SEED, MAX_QUEUED_PROPOSALS,
+};
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE EXECUTION QUEUE
+// =====================================================
+
+/// Create an execution queue (caller becomes the executor)
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct InitializeExecutionQueue<'info> {
+    #[account(
+        init,
+        payer = executor,
+        space = ExecutionQueue::space(),
+        seeds = [EXECUTION_QUEUE_SEED, executor.key().as_ref(), batch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub execution_queue: Account<'info, ExecutionQueue>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_execution_queue(
+    ctx: Context<InitializeExecutionQueue>,
+    batch_id: u64,
+    max_batch_size: u8,
+    execution_window: i64,
+) -> Result<()> {
+    require!(
+        max_batch_size > 0 && max_batch_size as usize <= MAX_QUEUED_PROPOSALS,
+        GhostSpeakError::InvalidConfiguration
+    );
+    require!(execution_window >= 0, GhostSpeakError::InvalidConfiguration);
+
+    let queue = &mut ctx.accounts.execution_queue;
+    queue.batch_id = batch_id;
+    queue.executor = ctx.accounts.executor.key();
+    queue.created_at = Clock::get()?.unix_timestamp;
+    queue.last_execution_at = None;
+    queue.status = ExecutionQueueStatus::Pending;
+    queue.queued_proposals = Vec::new();
+    queue.max_batch_size = max_batch_size;
+    queue.auto_execute = false;
+    queue.execution_window = execution_window;
+
+    emit!(ExecutionQueueInitializedEvent {
+        execution_queue: queue.key(),
+        executor: queue.executor,
+        batch_id,
+        max_batch_size,
+        execution_window,
+    });
+
+    msg!(
+        "Execution queue {} created for executor {}",
+        batch_id,
+        queue.executor
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// ENQUEUE PROPOSAL
+// =====================================================
+
+/// Queue a passed proposal for batched execution (queue executor only)
+#[derive(Accounts)]
+pub struct EnqueueProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            EXECUTION_QUEUE_SEED,
+            execution_queue.executor.as_ref(),
+            execution_queue.batch_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = executor @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub execution_queue: Box<Account<'info, ExecutionQueue>>,
+
+    /// Must leave execution to this queue's executor, or to anyone
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = proposal.execution_params.execution_authority == Pubkey::default()
+            || proposal.execution_params.execution_authority == executor.key()
+            @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn enqueue_proposal(
+    ctx: Context<EnqueueProposal>,
+    priority: TransactionPriority,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.mark_queued()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let queue = &mut ctx.accounts.execution_queue;
+    queue.enqueue(proposal.key(), proposal.proposal_id, priority, now)?;
+
+    emit!(ProposalEnqueuedEvent {
+        execution_queue: queue.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        priority,
+        execution_timestamp: proposal.execution_timestamp,
+    });
+
+    msg!(
+        "Proposal {} queued with {:?} priority ({} in queue)",
+        proposal.proposal_id,
+        priority,
+        queue.queued_proposals.len()
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// PROCESS QUEUE
+// =====================================================
+
+/// Execute due proposals in priority order (queue executor only)
+///
+/// Queued proposal accounts are passed as writable remaining accounts.
+#[derive(Accounts)]
+pub struct ProcessQueue<'info> {
+    #[account(
+        mut,
+        seeds = [
+            EXECUTION_QUEUE_SEED,
+            execution_queue.executor.as_ref(),
+            execution_queue.batch_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = executor @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub execution_queue: Box<Account<'info, ExecutionQueue>>,
+
+    pub executor: Signer<'info>,
+}
+
+/// Walks the queue in priority order, settling up to `max_batch_size`
+/// proposals. Proposals still inside their execution delay are passed over;
+/// processing stops at the first queued proposal whose account wasn't
+/// supplied, so lower-priority proposals can't jump ahead of it.
+pub fn process_queue<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessQueue<'info>>) -> Result<()> {
+    let queue_key = ctx.accounts.execution_queue.key();
+    let now = Clock::get()?.unix_timestamp;
+    let processed = process_batch(
+        &mut ctx.accounts.execution_queue,
+        queue_key,
+        ctx.remaining_accounts,
+        now,
+    )?;
+
+    let queue = &ctx.accounts.execution_queue;
+    msg!(
+        "Processed {} proposals from execution queue {} ({:?})",
+        processed,
+        queue.batch_id,
+        queue.status
+    );
+
+    Ok(())
+}
+
+/// Settle one batch of due proposals, whose accounts are looked up in
+/// `accounts`; shared with auto-execution triggers
+pub(crate) fn process_batch<'info>(
+    queue: &mut ExecutionQueue,
+    queue_key: Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<usize> {
+    let max_batch_size = queue.max_batch_size as usize;
+    let execution_window = queue.execution_window;
+
+    let mut processed = 0;
+    for entry in queue
+        .queued_proposals
+        .iter_mut()
+        .filter(|entry| entry.execution_status == ExecutionStatus::Queued)
+    {
+        if processed == max_batch_size {
+            break;
+        }
+        let Some(info) = accounts.iter().find(|info| info.key == &entry.proposal) else {
+            break;
+        };
+
+        let mut proposal: Account<'info, GovernanceProposal> = Account::try_from(info)?;
+        let Some(result) = entry.settle(&mut proposal, execution_window, now) else {
+            continue;
+        };
+        proposal.exit(&crate::ID)?;
+        processed += 1;
+
+        emit!(ExecutionResultEvent {
+            execution_queue: queue_key,
+            proposal: entry.proposal,
+            proposal_id: entry.proposal_id,
+            status: proposal.status,
+            result,
+        });
+    }
+
+    queue.finish_batch(processed, now);
+    Ok(processed)
+}
+
+// =====================================================
+// CANCEL QUEUED PROPOSAL
+// =====================================================
+
+/// Cancel a queued proposal that allows it (queue executor only)
+#[derive(Accounts)]
+pub struct CancelQueued<'info> {
+    #[account(
+        mut,
+        seeds = [
+            EXECUTION_QUEUE_SEED,
+            execution_queue.executor.as_ref(),
+            execution_queue.batch_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = executor @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub execution_queue: Box<Account<'info, ExecutionQueue>>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn cancel_queued(ctx: Context<CancelQueued>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let queue = &mut ctx.accounts.execution_queue;
+    queue.cancel(proposal.key())?;
+    proposal.cancel_queued()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    queue.finish_batch(0, now);
+
+    emit!(QueuedProposalCancelledEvent {
+        execution_queue: queue.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        cancelled_at: now,
+    });
+
+    msg!("Queued proposal {} cancelled", proposal.proposal_id);
+
+    Ok(())
+}
+
+// =====================================================
+// EVENTS
+// =====================================================
+
+#[event]
+pub struct ExecutionQueueInitializedEvent {
+    pub execution_queue: Pubkey,
+    pub executor: Pubkey,
+    pub batch_id: u64,
+    pub max_batch_size: u8,
+    pub execution_window: i64,
+}
+
+#[event]
+pub struct ProposalEnqueuedEvent {
+    pub execution_queue: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub priority: TransactionPriority,
+    pub execution_timestamp: Option<i64>,
+}
+
+#[event]
+pub struct ExecutionResultEvent {
+    pub execution_queue: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    pub result: ExecutionResult,
+}
+
+#[event]
+pub struct QueuedProposalCancelledEvent {
+    pub execution_queue: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub cancelled_at: i64,
+}
@@ -0,0 +1,132 @@
+/*!
+ * Fee Exemption Instructions
+ *
+ * Creates the fee exemption allowlist and lets its multisig grant and
+ * revoke exemptions for strategic partners, grants-program agents and
+ * protocol-owned agents.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::fee_exemption::*;
+use crate::state::governance::{Multisig, MultisigType};
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE FEE EXEMPTION LIST
+// =====================================================
+
+/// Create the empty exemption list (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeFeeExemptionList<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = FeeExemptionList::LEN,
+        seeds = [FEE_EXEMPTION_LIST_SEED],
+        bump
+    )]
+    pub fee_exemption_list: Box<Account<'info, FeeExemptionList>>,
+
+    /// Protocol or DAO multisig that will manage the list
+    #[account(
+        constraint = matches!(multisig.multisig_type, MultisigType::Protocol | MultisigType::Dao) @ GhostSpeakError::InvalidConfiguration
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_fee_exemption_list(ctx: Context<InitializeFeeExemptionList>) -> Result<()> {
+    let list = &mut ctx.accounts.fee_exemption_list;
+    list.multisig = ctx.accounts.multisig.key();
+    list.exemptions = Vec::new();
+    list.updated_at = Clock::get()?.unix_timestamp;
+    list.bump = ctx.bumps.fee_exemption_list;
+
+    msg!("Fee exemption list created (multisig: {})", list.multisig);
+    Ok(())
+}
+
+// =====================================================
+// MANAGE FEE EXEMPTIONS
+// =====================================================
+
+/// Grant or revoke a fee exemption (list multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ManageFeeExemption<'info> {
+    #[account(
+        mut,
+        seeds = [FEE_EXEMPTION_LIST_SEED],
+        bump = fee_exemption_list.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub fee_exemption_list: Box<Account<'info, FeeExemptionList>>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+pub fn set_fee_exemption(
+    ctx: Context<ManageFeeExemption>,
+    subject: Pubkey,
+    sources: u8,
+    reason: FeeExemptionReason,
+    expires_at: i64,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.fee_exemption_list.upsert(FeeExemption {
+        subject,
+        sources,
+        reason,
+        expires_at,
+        granted_at: now,
+    })?;
+
+    emit!(FeeExemptionSetEvent {
+        subject,
+        sources,
+        reason,
+        expires_at,
+        multisig: ctx.accounts.multisig.key(),
+        timestamp: now,
+    });
+
+    msg!("Fee exemption set for {} ({:?})", subject, reason);
+    Ok(())
+}
+
+pub fn remove_fee_exemption(ctx: Context<ManageFeeExemption>, subject: Pubkey) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.fee_exemption_list.remove(&subject, now)?;
+
+    emit!(FeeExemptionRemovedEvent {
+        subject,
+        multisig: ctx.accounts.multisig.key(),
+        timestamp: now,
+    });
+
+    msg!("Fee exemption removed for {}", subject);
+    Ok(())
+}
@@ -0,0 +1,326 @@
+/*!
+ * Fee Payment Token Instructions
+ *
+ * Registers the tokens (USDC, GHOST) SOL-denominated protocol fees may be
+ * paid in, with their Pyth price feeds, and quotes a fee in a token at the
+ * current oracle rate. `collect_protocol_fee` charges a fee in SOL, or
+ * converts it with the same quote and takes it in the token, and pays it
+ * into the matching fee vault.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::fee_token::*;
+use crate::state::fee_vault::{FeeAccruedEvent, FeeSource, FeeVault, FEE_VAULT_SEED};
+use crate::state::protocol_config::ProtocolConfig;
+use crate::GhostSpeakError;
+
+// =====================================================
+// SET FEE PAYMENT TOKEN
+// =====================================================
+
+/// Accept a token for protocol fees or change its oracle settings
+/// (config authority only)
+#[derive(Accounts)]
+pub struct SetFeePaymentToken<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = FeePaymentToken::LEN,
+        seeds = [FEE_PAYMENT_TOKEN_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub fee_payment_token: Box<Account<'info, FeePaymentToken>>,
+
+    /// CHECK: Token mint, or the system program for SOL; must have a fee vault
+    pub mint: UncheckedAccount<'info>,
+
+    /// Fee vault the token's payments go to
+    #[account(seeds = [FEE_VAULT_SEED, mint.key().as_ref()], bump = fee_vault.bump)]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_fee_payment_token(
+    ctx: Context<SetFeePaymentToken>,
+    price_feed_id: [u8; 32],
+    max_price_age: u32,
+    max_confidence_bps: u16,
+    enabled: bool,
+) -> Result<()> {
+    FeePaymentToken::validate(max_price_age, max_confidence_bps)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let payment_token = &mut ctx.accounts.fee_payment_token;
+    payment_token.mint = ctx.accounts.mint.key();
+    payment_token.price_feed_id = price_feed_id;
+    payment_token.max_price_age = max_price_age;
+    payment_token.max_confidence_bps = max_confidence_bps;
+    payment_token.enabled = enabled;
+    payment_token.updated_at = now;
+    payment_token.bump = ctx.bumps.fee_payment_token;
+
+    emit!(FeePaymentTokenUpdatedEvent {
+        mint: payment_token.mint,
+        price_feed_id,
+        max_price_age,
+        max_confidence_bps,
+        enabled,
+        timestamp: now,
+    });
+
+    msg!(
+        "Fee payment token {} {}",
+        payment_token.mint,
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+// =====================================================
+// QUOTE FEE IN TOKEN
+// =====================================================
+
+/// Convert a SOL-denominated fee into a payment token
+#[derive(Accounts)]
+pub struct QuoteFeeInToken<'info> {
+    /// SOL entry carrying the SOL/USD feed
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, system_program::ID.as_ref()],
+        bump = sol_payment_token.bump
+    )]
+    pub sol_payment_token: Box<Account<'info, FeePaymentToken>>,
+
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, payment_token.mint.as_ref()],
+        bump = payment_token.bump,
+        constraint = payment_token.enabled @ GhostSpeakError::FeeTokenNotAccepted
+    )]
+    pub payment_token: Box<Account<'info, FeePaymentToken>>,
+
+    #[account(address = payment_token.mint @ GhostSpeakError::FeeTokenNotAccepted)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Pyth SOL/USD price update, validated when read
+    pub sol_price_update: UncheckedAccount<'info>,
+
+    /// CHECK: Pyth token/USD price update, validated when read
+    pub token_price_update: UncheckedAccount<'info>,
+}
+
+/// Quote `lamports` in the payment token. The quote is returned via return
+/// data.
+pub fn quote_fee_in_token(ctx: Context<QuoteFeeInToken>, lamports: u64) -> Result<FeeTokenQuote> {
+    let now = Clock::get()?.unix_timestamp;
+    let quote = quote_lamports(
+        &ctx.accounts.sol_payment_token,
+        &ctx.accounts.payment_token,
+        &ctx.accounts.mint,
+        &ctx.accounts.sol_price_update,
+        &ctx.accounts.token_price_update,
+        lamports,
+        now,
+    )?;
+
+    msg!(
+        "{} lamports = {} of {}",
+        lamports,
+        quote.token_amount,
+        quote.mint
+    );
+    Ok(quote)
+}
+
+/// Quote `lamports` in `payment_token` from the two Pyth price updates
+fn quote_lamports(
+    sol_payment_token: &FeePaymentToken,
+    payment_token: &FeePaymentToken,
+    mint: &Account<Mint>,
+    sol_price_update: &AccountInfo,
+    token_price_update: &AccountInfo,
+    lamports: u64,
+    now: i64,
+) -> Result<FeeTokenQuote> {
+    require!(
+        sol_payment_token.mint == system_program::ID
+            && payment_token.enabled
+            && payment_token.mint == mint.key(),
+        GhostSpeakError::FeeTokenNotAccepted
+    );
+
+    let sol_price = sol_payment_token.read_price(sol_price_update, now)?;
+    let token_price = payment_token.read_price(token_price_update, now)?;
+    let token_amount = lamports_to_token_amount(lamports, &sol_price, &token_price, mint.decimals)?;
+
+    Ok(FeeTokenQuote {
+        mint: mint.key(),
+        lamports,
+        token_amount,
+        sol_price,
+        token_price,
+    })
+}
+
+// =====================================================
+// FEE COLLECTION
+// =====================================================
+
+/// Accounts for paying a SOL-denominated fee in an accepted token
+pub(crate) struct FeeTokenPayment<'a, 'info> {
+    pub payment_token: &'a Account<'info, FeePaymentToken>,
+    /// SOL entry carrying the SOL/USD feed
+    pub sol_payment_token: &'a Account<'info, FeePaymentToken>,
+    pub mint: &'a Account<'info, Mint>,
+    pub sol_price_update: &'a AccountInfo<'info>,
+    pub token_price_update: &'a AccountInfo<'info>,
+    pub payer_token_account: &'a Account<'info, TokenAccount>,
+    /// Fee vault's token account
+    pub vault_token_account: &'a Account<'info, TokenAccount>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> FeeTokenPayment<'a, 'info> {
+    /// Gather the optional token payment accounts an instruction was passed.
+    /// Without a payment token the fee is paid in SOL; with one, every other
+    /// account is required.
+    pub fn from_accounts(
+        payment_token: Option<&'a Account<'info, FeePaymentToken>>,
+        sol_payment_token: Option<&'a Account<'info, FeePaymentToken>>,
+        mint: Option<&'a Account<'info, Mint>>,
+        sol_price_update: Option<&'a AccountInfo<'info>>,
+        token_price_update: Option<&'a AccountInfo<'info>>,
+        payer_token_account: Option<&'a Account<'info, TokenAccount>>,
+        vault_token_account: Option<&'a Account<'info, TokenAccount>>,
+        token_program: Option<&'a Program<'info, Token>>,
+    ) -> Result<Option<Self>> {
+        let Some(payment_token) = payment_token else {
+            return Ok(None);
+        };
+        let (
+            Some(sol_payment_token),
+            Some(mint),
+            Some(sol_price_update),
+            Some(token_price_update),
+            Some(payer_token_account),
+            Some(vault_token_account),
+            Some(token_program),
+        ) = (
+            sol_payment_token,
+            mint,
+            sol_price_update,
+            token_price_update,
+            payer_token_account,
+            vault_token_account,
+            token_program,
+        )
+        else {
+            return err!(GhostSpeakError::FeeTokenAccountsRequired);
+        };
+
+        Ok(Some(Self {
+            payment_token,
+            sol_payment_token,
+            mint,
+            sol_price_update,
+            token_price_update,
+            payer_token_account,
+            vault_token_account,
+            token_program,
+        }))
+    }
+}
+
+/// Charge a SOL-denominated protocol fee of `lamports` from `source` and
+/// pay it into `fee_vault`: in SOL, or in `token` at the rate
+/// `quote_fee_in_token` would quote
+pub(crate) fn collect_protocol_fee<'info>(
+    source: FeeSource,
+    lamports: u64,
+    payer: &Signer<'info>,
+    fee_vault: Option<&mut Account<'info, FeeVault>>,
+    token: Option<FeeTokenPayment<'_, 'info>>,
+    system_program: &Program<'info, System>,
+    now: i64,
+) -> Result<()> {
+    if lamports == 0 {
+        return Ok(());
+    }
+    let fee_vault = fee_vault.ok_or(GhostSpeakError::ProtocolTreasuryRequired)?;
+
+    let amount = match token {
+        None => {
+            require!(fee_vault.is_native(), GhostSpeakError::InvalidFeeVaultAccount);
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: fee_vault.to_account_info(),
+                    },
+                ),
+                lamports,
+            )?;
+            lamports
+        }
+        Some(token) => {
+            let quote = quote_lamports(
+                token.sol_payment_token,
+                token.payment_token,
+                token.mint,
+                token.sol_price_update,
+                token.token_price_update,
+                lamports,
+                now,
+            )?;
+            require!(
+                fee_vault.mint == quote.mint
+                    && fee_vault.token_account == token.vault_token_account.key(),
+                GhostSpeakError::InvalidFeeVaultAccount
+            );
+            token::transfer(
+                CpiContext::new(
+                    token.token_program.to_account_info(),
+                    Transfer {
+                        from: token.payer_token_account.to_account_info(),
+                        to: token.vault_token_account.to_account_info(),
+                        authority: payer.to_account_info(),
+                    },
+                ),
+                quote.token_amount,
+            )?;
+
+            emit!(FeePaidInTokenEvent {
+                payer: payer.key(),
+                source,
+                mint: quote.mint,
+                lamports,
+                token_amount: quote.token_amount,
+                timestamp: now,
+            });
+            quote.token_amount
+        }
+    };
+
+    fee_vault.accrue(source, amount, now)?;
+    emit!(FeeAccruedEvent {
+        fee_vault: fee_vault.key(),
+        source,
+        amount,
+        total_for_source: fee_vault.accrued[source.index()],
+        timestamp: now,
+    });
+    Ok(())
+}
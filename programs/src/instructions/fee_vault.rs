@@ -0,0 +1,195 @@
+/*!
+ * Fee Vault Instructions
+ *
+ * Creates the per-token vaults protocol fees are paid into and lets the
+ * treasury multisig withdraw from them. Fee-collecting instructions credit
+ * the vault and record the source with `FeeVault::accrue`.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::fee_vault::*;
+use crate::state::governance::{Multisig, MultisigType};
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE FEE VAULT
+// =====================================================
+
+/// Create the fee vault for a token (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = FeeVault::LEN,
+        seeds = [FEE_VAULT_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    /// CHECK: Token mint of the vault, or the system program for SOL;
+    /// pinned by the vault token account's mint
+    pub mint: UncheckedAccount<'info>,
+
+    /// Token account owned by the vault PDA; required for SPL token vaults
+    pub vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Protocol or DAO multisig that approves withdrawals
+    #[account(
+        constraint = matches!(multisig.multisig_type, MultisigType::Protocol | MultisigType::Dao) @ GhostSpeakError::InvalidConfiguration
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+    let fee_vault_key = ctx.accounts.fee_vault.key();
+    let mint = ctx.accounts.mint.key();
+    let token_account = if mint == anchor_lang::system_program::ID {
+        require!(
+            ctx.accounts.vault_token_account.is_none(),
+            GhostSpeakError::InvalidFeeVaultAccount
+        );
+        Pubkey::default()
+    } else {
+        let vault_token_account = ctx
+            .accounts
+            .vault_token_account
+            .as_ref()
+            .ok_or(GhostSpeakError::InvalidFeeVaultAccount)?;
+        require!(
+            vault_token_account.mint == mint && vault_token_account.owner == fee_vault_key,
+            GhostSpeakError::InvalidFeeVaultAccount
+        );
+        vault_token_account.key()
+    };
+
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.mint = mint;
+    fee_vault.token_account = token_account;
+    fee_vault.multisig = ctx.accounts.multisig.key();
+    fee_vault.accrued = [0; FeeSource::COUNT];
+    fee_vault.withdrawn = 0;
+    fee_vault.updated_at = Clock::get()?.unix_timestamp;
+    fee_vault.bump = ctx.bumps.fee_vault;
+
+    emit!(FeeVaultInitializedEvent {
+        fee_vault: fee_vault_key,
+        mint,
+        token_account,
+        multisig: fee_vault.multisig,
+    });
+
+    msg!("Fee vault created for mint {}", mint);
+    Ok(())
+}
+
+// =====================================================
+// WITHDRAW TREASURY
+// =====================================================
+
+/// Withdraw protocol fees from a vault (treasury multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, fee_vault.mint.as_ref()],
+        bump = fee_vault.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    /// Vault's token account; required for SPL token vaults
+    #[account(
+        mut,
+        address = fee_vault.token_account @ GhostSpeakError::InvalidFeeVaultAccount
+    )]
+    pub vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Receives the withdrawal: any account for SOL, a token account
+    /// of the vault's mint otherwise (checked by the token program)
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    multisig.require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    if fee_vault.is_native() {
+        // Lamports above rent exemption are fees
+        let info = fee_vault.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(info.data_len());
+        let balance = info.lamports().saturating_sub(rent_exempt);
+        fee_vault.withdraw(amount, balance, now)?;
+
+        **info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+    } else {
+        let vault_token_account = ctx
+            .accounts
+            .vault_token_account
+            .as_ref()
+            .ok_or(GhostSpeakError::InvalidFeeVaultAccount)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GhostSpeakError::InvalidFeeVaultAccount)?;
+        fee_vault.withdraw(amount, vault_token_account.amount, now)?;
+
+        let seeds = &[FEE_VAULT_SEED, fee_vault.mint.as_ref(), &[fee_vault.bump]];
+        let signer_seeds = &[&seeds[..]];
+        let cpi_accounts = Transfer {
+            from: vault_token_account.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: fee_vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    emit!(TreasuryWithdrawnEvent {
+        fee_vault: fee_vault.key(),
+        multisig: multisig.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        total_withdrawn: fee_vault.withdrawn,
+        timestamp: now,
+    });
+
+    msg!(
+        "Withdrew {} from the {} fee vault to {}",
+        amount,
+        fee_vault.mint,
+        ctx.accounts.destination.key()
+    );
+    Ok(())
+}
@@ -381,6 +381,7 @@ pub fn claim_ghost(
     did_document.deactivated = false;
     did_document.deactivated_at = None;
     did_document.bump = ctx.bumps.did_document;
+    did_document.reactivation_deadline = 0;
 
     // Add default W3C context
     did_document.context = vec![
@@ -405,18 +406,19 @@ pub fn claim_ghost(
 
     did_document.verification_methods = vec![primary_verification_method];
 
-    // Add agent service endpoint if available
-    if !agent.service_endpoint.is_empty() {
-        let service_endpoint = ServiceEndpoint {
-            id: "agent-service".to_string(),
-            service_type: ServiceEndpointType::AIAgentService,
-            service_endpoint: agent.service_endpoint.clone(),
-            description: format!("AI Agent Service: {}", agent.name),
-        };
-        did_document.service_endpoints = vec![service_endpoint];
+    // Add the agent's service endpoint if it is a valid agent service URI;
+    // a malformed legacy endpoint must not block the claim
+    let service_endpoint = ServiceEndpoint {
+        id: "agent-service".to_string(),
+        service_type: ServiceEndpointType::AIAgentService,
+        service_endpoint: agent.service_endpoint.clone(),
+        description: format!("AI Agent Service: {}", agent.name),
+    };
+    did_document.service_endpoints = if service_endpoint.validate().is_ok() {
+        vec![service_endpoint]
     } else {
-        did_document.service_endpoints = Vec::new();
-    }
+        Vec::new()
+    };
 
     did_document.also_known_as = Vec::new();
 
@@ -5,10 +5,29 @@
  */
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::ghost_protect::*;
+use crate::state::parameter_registry::{read_parameter, ParameterKey, PARAMETER_REGISTRY_SEED};
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::denylist::DENYLIST_ENTRY_SEED;
+use crate::state::session_key::{SessionKey, SessionScope};
+use crate::state::referral::{ReferralKind, ReferrerStats, REFERRER_STATS_SEED};
+use crate::security::{
+    record_anomaly, AccountLock, AnomalyKind, CircuitBreaker, RateLimiter, UserRateLimit,
+    ACCOUNT_LOCK_SEED, ESCROW_RELEASE_LOCK, OPERATION_DISPUTE_FILING,
+};
+use crate::instructions::referral::{record_referral, route_referral_fee};
+use crate::state::did::{DidDelegateKey, DidDocument, DidKeyScope};
 use crate::state::Agent;
-use crate::state::staking::StakingAccount;
+use crate::state::protocol_config::{
+    read_escrow_fee_curve, GatedAction, ProtocolConfig, ESCROW_FEE_CURVE_SEED,
+};
+use crate::state::fee_exemption::{is_fee_exempt, FeeWaivedEvent, FEE_EXEMPTION_LIST_SEED};
+use crate::state::fee_vault::{FeeAccruedEvent, FeeSource, FeeVault, FEE_VAULT_SEED};
+use crate::state::staking::{AccessTier, StakingAccount, StakingConfig};
+use crate::utils::{read_legacy_layout, write_current_layout};
 use crate::GhostSpeakError;
 
 // =====================================================
@@ -52,8 +71,46 @@ pub struct CreateEscrow<'info> {
     #[account(mut)]
     pub client: Signer<'info>,
 
+    /// Client reputation record (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientReputation::LEN,
+        seeds = [b"client_reputation", client.key().as_ref()],
+        bump
+    )]
+    pub client_reputation: Account<'info, ClientReputation>,
+
+    /// CHECK: Agent's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.key().as_ref()],
+        bump,
+        constraint = agent_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub agent_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.owner.unwrap_or_default().as_ref()],
+        bump,
+        constraint = owner_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub owner_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Client's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, client.key().as_ref()],
+        bump,
+        constraint = client_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub client_denylist_entry: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// Stats of the referrer named on the escrow (required with a referrer)
+    #[account(mut)]
+    pub referrer_stats: Option<Box<Account<'info, ReferrerStats>>>,
 }
 
 pub fn create_escrow(
@@ -62,6 +119,7 @@ pub fn create_escrow(
     amount: u64,
     job_description: String,
     deadline: i64,
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     let escrow = &mut ctx.accounts.escrow;
     let clock = Clock::get()?;
@@ -87,20 +145,252 @@ pub fn create_escrow(
     token::transfer(cpi_ctx, amount)?;
 
     // Initialize escrow
+    initialize_escrow_account(
+        escrow,
+        escrow_id,
+        ctx.accounts.client.key(),
+        ctx.accounts.agent.key(),
+        amount,
+        ctx.accounts.token_mint.key(),
+        job_description,
+        deadline,
+        ctx.bumps.escrow,
+        clock.unix_timestamp,
+    );
+
+    if let Some(referrer) = referrer {
+        record_referral(
+            ctx.accounts.referrer_stats.as_deref_mut(),
+            referrer,
+            ctx.accounts.client.key(),
+            escrow.key(),
+            ReferralKind::EscrowCreation,
+            clock.unix_timestamp,
+        )?;
+        escrow.referrer = Some(referrer);
+    }
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.initialize_if_needed(
+        ctx.accounts.client.key(),
+        ctx.bumps.client_reputation,
+        clock.unix_timestamp,
+    );
+    client_reputation.record_escrow_created(amount, clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow_id);
+
+    emit!(EscrowCreatedEvent {
+        escrow_id,
+        client: ctx.accounts.client.key(),
+        agent: ctx.accounts.agent.key(),
+        amount,
+        deadline,
+    });
+
+    msg!("Escrow created: {} for agent: {}", escrow_id, ctx.accounts.agent.key());
+
+    Ok(())
+}
+
+/// Populate a freshly created escrow (shared by regular and trial escrows)
+pub(crate) fn initialize_escrow_account(
+    escrow: &mut GhostProtectEscrow,
+    escrow_id: u64,
+    client: Pubkey,
+    agent: Pubkey,
+    amount: u64,
+    token_mint: Pubkey,
+    job_description: String,
+    deadline: i64,
+    bump: u8,
+    now: i64,
+) {
     escrow.escrow_id = escrow_id;
-    escrow.client = ctx.accounts.client.key();
-    escrow.agent = ctx.accounts.agent.key();
+    escrow.client = client;
+    escrow.agent = agent;
     escrow.amount = amount;
-    escrow.token_mint = ctx.accounts.token_mint.key();
+    escrow.token_mint = token_mint;
     escrow.status = EscrowStatus::Active;
-    escrow.job_description = job_description.clone();
+    escrow.job_description = job_description;
     escrow.delivery_proof = None;
     escrow.deadline = deadline;
-    escrow.created_at = clock.unix_timestamp;
+    escrow.created_at = now;
     escrow.completed_at = None;
     escrow.dispute_reason = None;
     escrow.arbitrator_decision = None;
-    escrow.bump = ctx.bumps.escrow;
+    escrow.bump = bump;
+    escrow.version = GhostProtectEscrow::CURRENT_VERSION;
+    escrow.upfront_amount = 0;
+    escrow.trial_bond = 0;
+    escrow.bond_clawed_back = false;
+    escrow.origin_program = None;
+    escrow.referrer = None;
+    escrow.dispute_bond = 0;
+}
+
+// =====================================================
+// CREATE ESCROW VIA CPI
+// =====================================================
+
+/// Create an escrow whose client is a PDA of another program (DAO treasury,
+/// Squads vault, protocol), invoked through CPI
+///
+/// The client still signs (via `invoke_signed`), but rent is paid by a
+/// separate `payer` since data-carrying PDAs cannot fund system transfers.
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct CreateEscrowViaCpi<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = GhostProtectEscrow::LEN,
+        seeds = [
+            b"ghost_protect",
+            client.key().as_ref(),
+            &escrow_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, GhostProtectEscrow>,
+
+    #[account(
+        constraint = agent.is_active @ GhostSpeakError::AgentNotActive
+    )]
+    pub agent: Account<'info, Agent>,
+
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key()
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Token mint for payment
+    pub token_mint: AccountInfo<'info>,
+
+    /// Program-derived client, signing through the origin program
+    pub client: Signer<'info>,
+
+    /// CHECK: Program the client PDA is derived from; verified against client_seeds
+    #[account(executable)]
+    pub origin_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Client reputation record (created on first use)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClientReputation::LEN,
+        seeds = [b"client_reputation", client.key().as_ref()],
+        bump
+    )]
+    pub client_reputation: Account<'info, ClientReputation>,
+
+    /// CHECK: Agent's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.key().as_ref()],
+        bump,
+        constraint = agent_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub agent_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.owner.unwrap_or_default().as_ref()],
+        bump,
+        constraint = owner_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub owner_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Client's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, client.key().as_ref()],
+        bump,
+        constraint = client_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub client_denylist_entry: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Open an escrow on behalf of a program-owned client
+///
+/// `client_seeds` (including the bump) must derive the client from
+/// `origin_program`, and the call must arrive through CPI, so a user wallet
+/// can never be recorded as a program client.
+pub fn create_escrow_via_cpi(
+    ctx: Context<CreateEscrowViaCpi>,
+    escrow_id: u64,
+    amount: u64,
+    job_description: String,
+    deadline: i64,
+    client_seeds: Vec<Vec<u8>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT,
+        GhostSpeakError::NotInvokedViaCpi
+    );
+    require!(
+        client_seeds.len() <= GhostProtectEscrow::MAX_CPI_CLIENT_SEEDS,
+        GhostSpeakError::ClientNotProgramDerived
+    );
+    let seeds: Vec<&[u8]> = client_seeds.iter().map(Vec::as_slice).collect();
+    let origin_program = ctx.accounts.origin_program.key();
+    let derived = Pubkey::create_program_address(&seeds, &origin_program)
+        .map_err(|_| GhostSpeakError::ClientNotProgramDerived)?;
+    require_keys_eq!(
+        derived,
+        ctx.accounts.client.key(),
+        GhostSpeakError::ClientNotProgramDerived
+    );
+
+    require!(
+        job_description.len() <= GhostProtectEscrow::MAX_DESCRIPTION_LEN,
+        GhostSpeakError::DescriptionTooLong
+    );
+    require!(deadline > clock.unix_timestamp, GhostSpeakError::InvalidDeadline);
+    require!(amount > 0, GhostSpeakError::InvalidAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.client_token_account.to_account_info(),
+        to: ctx.accounts.escrow_vault.to_account_info(),
+        authority: ctx.accounts.client.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        amount,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    initialize_escrow_account(
+        escrow,
+        escrow_id,
+        ctx.accounts.client.key(),
+        ctx.accounts.agent.key(),
+        amount,
+        ctx.accounts.token_mint.key(),
+        job_description,
+        deadline,
+        ctx.bumps.escrow,
+        clock.unix_timestamp,
+    );
+    escrow.origin_program = Some(origin_program);
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.initialize_if_needed(
+        ctx.accounts.client.key(),
+        ctx.bumps.client_reputation,
+        clock.unix_timestamp,
+    );
+    client_reputation.record_escrow_created(amount, clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow_id);
 
     emit!(EscrowCreatedEvent {
         escrow_id,
@@ -109,8 +399,211 @@ pub fn create_escrow(
         amount,
         deadline,
     });
+    emit!(EscrowCreatedViaCpiEvent {
+        escrow_id,
+        client: ctx.accounts.client.key(),
+        origin_program,
+        payer: ctx.accounts.payer.key(),
+    });
 
-    msg!("Escrow created: {} for agent: {}", escrow_id, ctx.accounts.agent.key());
+    msg!(
+        "Escrow created via CPI: {} for agent: {} (origin program {})",
+        escrow_id,
+        ctx.accounts.agent.key(),
+        origin_program
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// CREATE TRIAL ESCROW
+// =====================================================
+
+/// Create a trial escrow: a small upfront share goes to the agent immediately,
+/// backed by a reserved portion of the agent's stake
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct CreateTrialEscrow<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = GhostProtectEscrow::LEN,
+        seeds = [
+            b"ghost_protect",
+            client.key().as_ref(),
+            &escrow_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub escrow: Box<Account<'info, GhostProtectEscrow>>,
+
+    #[account(
+        constraint = agent.is_active @ GhostSpeakError::AgentNotActive
+    )]
+    pub agent: Box<Account<'info, Agent>>,
+
+    /// Agent's staking account, which reserves the trial bond
+    #[account(
+        mut,
+        seeds = [b"staking", agent.key().as_ref()],
+        bump = agent_staking.bump,
+    )]
+    pub agent_staking: Box<Account<'info, StakingAccount>>,
+
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key()
+    )]
+    pub client_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub escrow_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Receives the upfront share
+    #[account(
+        mut,
+        constraint = agent.owner == Some(agent_token_account.owner) @ GhostSpeakError::InvalidAgentOwner
+    )]
+    pub agent_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Token mint for payment
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client reputation record (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientReputation::LEN,
+        seeds = [b"client_reputation", client.key().as_ref()],
+        bump
+    )]
+    pub client_reputation: Box<Account<'info, ClientReputation>>,
+
+    /// CHECK: Parameter registry PDA, read for the upfront cap; defaults apply until
+    /// it is initialized
+    #[account(seeds = [PARAMETER_REGISTRY_SEED], bump)]
+    pub parameter_registry: UncheckedAccount<'info>,
+
+    /// CHECK: Agent's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.key().as_ref()],
+        bump,
+        constraint = agent_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub agent_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.owner.unwrap_or_default().as_ref()],
+        bump,
+        constraint = owner_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub owner_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Client's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, client.key().as_ref()],
+        bump,
+        constraint = client_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub client_denylist_entry: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_trial_escrow(
+    ctx: Context<CreateTrialEscrow>,
+    escrow_id: u64,
+    amount: u64,
+    upfront_bps: u16,
+    trial_bond: u64,
+    job_description: String,
+    deadline: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        job_description.len() <= GhostProtectEscrow::MAX_DESCRIPTION_LEN,
+        GhostSpeakError::DescriptionTooLong
+    );
+    require!(deadline > clock.unix_timestamp, GhostSpeakError::InvalidDeadline);
+    require!(amount > 0, GhostSpeakError::InvalidAmount);
+    let max_upfront_bps = read_parameter(
+        &ctx.accounts.parameter_registry,
+        ParameterKey::TrialMaxUpfrontBps,
+    )?;
+    let (upfront_amount, held_amount) = trial_split(amount, upfront_bps, max_upfront_bps)?;
+
+    // The bond is reserved out of free stake, which stays locked for the
+    // life of the job
+    let staking = &mut ctx.accounts.agent_staking;
+    require!(staking.unlock_at >= deadline, GhostSpeakError::TrialBondNotLocked);
+    staking.reserve_bond(trial_bond)?;
+
+    // Remainder to the vault, upfront share straight to the agent
+    for (to, transfer_amount) in [
+        (ctx.accounts.escrow_vault.to_account_info(), held_amount),
+        (ctx.accounts.agent_token_account.to_account_info(), upfront_amount),
+    ] {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.client_token_account.to_account_info(),
+            to,
+            authority: ctx.accounts.client.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts
+        );
+        token::transfer(cpi_ctx, transfer_amount)?;
+    }
+
+    let escrow = &mut ctx.accounts.escrow;
+    initialize_escrow_account(
+        escrow,
+        escrow_id,
+        ctx.accounts.client.key(),
+        ctx.accounts.agent.key(),
+        amount,
+        ctx.accounts.token_mint.key(),
+        job_description,
+        deadline,
+        ctx.bumps.escrow,
+        clock.unix_timestamp,
+    );
+    escrow.upfront_amount = upfront_amount;
+    escrow.trial_bond = trial_bond;
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.initialize_if_needed(
+        ctx.accounts.client.key(),
+        ctx.bumps.client_reputation,
+        clock.unix_timestamp,
+    );
+    client_reputation.record_escrow_created(amount, clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow_id);
+
+    emit!(EscrowCreatedEvent {
+        escrow_id,
+        client: ctx.accounts.client.key(),
+        agent: ctx.accounts.agent.key(),
+        amount,
+        deadline,
+    });
+    emit!(TrialEscrowCreatedEvent {
+        escrow_id,
+        client: ctx.accounts.client.key(),
+        agent: ctx.accounts.agent.key(),
+        amount,
+        upfront_amount,
+        trial_bond,
+    });
+
+    msg!("Trial escrow created: {} ({} released upfront)", escrow_id, upfront_amount);
 
     Ok(())
 }
@@ -130,23 +623,62 @@ pub struct SubmitDelivery<'info> {
             &escrow.escrow_id.to_le_bytes()
         ],
         bump = escrow.bump,
-        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState
+        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
     )]
     pub escrow: Account<'info, GhostProtectEscrow>,
 
     #[account(
         constraint = agent.key() == escrow.agent @ GhostSpeakError::InvalidAgent,
-        constraint = agent.owner == Some(agent_owner.key()) @ GhostSpeakError::InvalidAgentOwner
+        constraint = agent.owner == Some(agent_owner.key())
+            || delegate_key.is_some()
+            || session_key.is_some() @ GhostSpeakError::InvalidAgentOwner
     )]
     pub agent: Account<'info, Agent>,
 
+    /// Agent owner, or a delegate or session key scoped to submit deliveries
     pub agent_owner: Signer<'info>,
+
+    /// DID linked to the agent; required with `delegate_key`
+    pub did_document: Option<Account<'info, DidDocument>>,
+
+    /// Scope record authorizing `agent_owner` as a delegate of `did_document`
+    pub delegate_key: Option<Account<'info, DidDelegateKey>>,
+
+    /// Session authorizing `agent_owner` for the agent (counts one action)
+    #[account(mut)]
+    pub session_key: Option<Account<'info, SessionKey>>,
 }
 
 pub fn submit_delivery(
     ctx: Context<SubmitDelivery>,
     delivery_proof: String,
 ) -> Result<()> {
+    if let Some(session_key) = ctx.accounts.session_key.as_mut() {
+        session_key.authorize(
+            &ctx.accounts.agent.key(),
+            ctx.accounts.agent.owner,
+            &ctx.accounts.agent_owner.key(),
+            SessionScope::SubmitDeliveries,
+            Clock::get()?.unix_timestamp,
+        )?;
+    } else if ctx.accounts.agent.owner != Some(ctx.accounts.agent_owner.key()) {
+        let (Some(did_document), Some(delegate_key)) =
+            (&ctx.accounts.did_document, &ctx.accounts.delegate_key)
+        else {
+            return err!(GhostSpeakError::InvalidAgentOwner);
+        };
+        delegate_key.authorize(
+            &did_document.key(),
+            did_document,
+            &ctx.accounts.agent,
+            &ctx.accounts.agent_owner.key(),
+            DidKeyScope::SubmitDeliveries,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
     let escrow = &mut ctx.accounts.escrow;
 
     require!(
@@ -182,10 +714,20 @@ pub struct ApproveDelivery<'info> {
             &escrow.escrow_id.to_le_bytes()
         ],
         bump = escrow.bump,
-        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState
+        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
     )]
     pub escrow: Account<'info, GhostProtectEscrow>,
 
+    /// Escrowed agent, whose referrer shares the escrow fee
+    #[account(
+        address = escrow.agent @ GhostSpeakError::InvalidAgent,
+        constraint = agent.is_current(agent.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub agent: Box<Account<'info, Agent>>,
+
     #[account(mut)]
     pub escrow_vault: Account<'info, TokenAccount>,
 
@@ -193,19 +735,127 @@ pub struct ApproveDelivery<'info> {
     pub agent_token_account: Account<'info, TokenAccount>,
 
     #[account(
+        mut,
         constraint = client.key() == escrow.client @ GhostSpeakError::UnauthorizedAccess
     )]
     pub client: Signer<'info>,
 
+    /// Client reputation record (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientReputation::LEN,
+        seeds = [b"client_reputation", client.key().as_ref()],
+        bump
+    )]
+    pub client_reputation: Account<'info, ClientReputation>,
+
+    /// Agent's settled volume, which sets its escrow fee rate (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = AgentSettlementStats::LEN,
+        seeds = [AGENT_SETTLEMENT_STATS_SEED, escrow.agent.as_ref()],
+        bump
+    )]
+    pub agent_settlement_stats: Box<Account<'info, AgentSettlementStats>>,
+
+    /// Agent's staking account, whose bond is released (trial escrows only)
+    /// and whose tier discounts the escrow fee
+    #[account(mut, seeds = [b"staking", escrow.agent.as_ref()], bump = agent_staking.bump)]
+    pub agent_staking: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// Protocol config (escrow fee rate and tier discount)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Staking config, whose current thresholds set the agent's fee discount
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// CHECK: Escrow fee curve; uninitialized if never created
+    #[account(seeds = [ESCROW_FEE_CURVE_SEED], bump)]
+    pub escrow_fee_curve: UncheckedAccount<'info>,
+
+    /// CHECK: Fee exemption list; uninitialized if never created
+    #[account(seeds = [FEE_EXEMPTION_LIST_SEED], bump)]
+    pub fee_exemption_list: UncheckedAccount<'info>,
+
+    /// Fee vault for the escrow token; required when an escrow fee is due
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, escrow.token_mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    /// Fee vault's token account; checked against the vault in the handler
+    #[account(mut)]
+    pub fee_vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Stats of the escrow's referrer (else the agent's); with its token
+    /// account, required when a referrer is owed a share of the fee
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS_SEED, referrer_stats.referrer.as_ref()],
+        bump = referrer_stats.bump
+    )]
+    pub referrer_stats: Option<Box<Account<'info, ReferrerStats>>>,
+
+    /// Referrer's account for the escrow token
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Per-escrow payout lock, held while this instruction moves funds and
+    /// closed back to the client once it is done
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = AccountLock::LEN,
+        seeds = [ACCOUNT_LOCK_SEED, escrow.key().as_ref(), ESCROW_RELEASE_LOCK.as_bytes()],
+        bump
+    )]
+    pub account_lock: Box<Account<'info, AccountLock>>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn approve_delivery(ctx: Context<ApproveDelivery>) -> Result<()> {
-    let escrow = &mut ctx.accounts.escrow;
     let clock = Clock::get()?;
+    ctx.accounts.account_lock.acquire(
+        ctx.accounts.escrow.key(),
+        ESCROW_RELEASE_LOCK,
+        ctx.accounts.client.key(),
+        clock.unix_timestamp,
+        ctx.bumps.account_lock,
+    )?;
+    let escrow = &mut ctx.accounts.escrow;
 
     require!(escrow.delivery_proof.is_some(), GhostSpeakError::InvalidWorkDelivery);
 
+    // The protocol's escrow fee comes out of the agent's payment
+    let amount = escrow.held_amount();
+    let agent_tier = ctx
+        .accounts
+        .agent_staking
+        .as_ref()
+        .map_or(AccessTier::None, |staking| {
+            staking.current_tier(&ctx.accounts.staking_config.tier_schedule)
+        });
+    let charge = escrow_fee(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.escrow_fee_curve,
+        &ctx.accounts.fee_exemption_list,
+        ctx.accounts.agent_settlement_stats.trailing_volume(clock.unix_timestamp),
+        escrow.agent,
+        amount,
+        agent_tier,
+        clock.unix_timestamp,
+    )?;
+    charge.emit_waiver(escrow.agent, FeeSource::Escrow, clock.unix_timestamp);
+    let fee = charge.fee;
+
     // Transfer payment to agent
     let client_key = escrow.client;
     let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
@@ -217,6 +867,21 @@ pub fn approve_delivery(ctx: Context<ApproveDelivery>) -> Result<()> {
     ];
     let signer_seeds = &[&seeds[..]];
 
+    pay_escrow_fee(
+        fee,
+        &ctx.accounts.protocol_config,
+        escrow,
+        ctx.accounts.agent.referrer,
+        &ctx.accounts.escrow_vault,
+        ctx.accounts.fee_vault.as_deref_mut(),
+        ctx.accounts.fee_vault_token_account.as_deref(),
+        ctx.accounts.referrer_stats.as_deref_mut(),
+        ctx.accounts.referrer_token_account.as_deref(),
+        &ctx.accounts.token_program,
+        signer_seeds,
+        clock.unix_timestamp,
+    )?;
+
     let cpi_accounts = Transfer {
         from: ctx.accounts.escrow_vault.to_account_info(),
         to: ctx.accounts.agent_token_account.to_account_info(),
@@ -227,11 +892,29 @@ pub fn approve_delivery(ctx: Context<ApproveDelivery>) -> Result<()> {
         cpi_accounts,
         signer_seeds
     );
-    token::transfer(cpi_ctx, escrow.amount)?;
+    token::transfer(cpi_ctx, amount - fee)?;
 
     // Update escrow
     escrow.status = EscrowStatus::Completed;
     escrow.completed_at = Some(clock.unix_timestamp);
+    release_trial_bond(escrow, ctx.accounts.agent_staking.as_deref_mut())?;
+
+    record_agent_settlement(
+        &mut ctx.accounts.agent_settlement_stats,
+        ctx.bumps.agent_settlement_stats,
+        escrow,
+        amount,
+        clock.unix_timestamp,
+    );
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.initialize_if_needed(
+        client_key,
+        ctx.bumps.client_reputation,
+        clock.unix_timestamp,
+    );
+    client_reputation.record_escrow_completed(clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow.escrow_id);
 
     emit!(EscrowCompletedEvent {
         escrow_id: escrow.escrow_id,
@@ -241,6 +924,48 @@ pub fn approve_delivery(ctx: Context<ApproveDelivery>) -> Result<()> {
 
     msg!("Escrow completed: {}", escrow.escrow_id);
 
+    ctx.accounts
+        .account_lock
+        .close(ctx.accounts.client.to_account_info())?;
+    Ok(())
+}
+
+// =====================================================
+// DISPUTE QUEUE
+// =====================================================
+
+/// Create the global dispute queue (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeDisputeQueue<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = DisputeQueue::LEN,
+        seeds = [DISPUTE_QUEUE_SEED],
+        bump
+    )]
+    pub dispute_queue: Account<'info, DisputeQueue>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_dispute_queue(ctx: Context<InitializeDisputeQueue>) -> Result<()> {
+    let queue = &mut ctx.accounts.dispute_queue;
+    queue.entries = Vec::new();
+    queue.updated_at = Clock::get()?.unix_timestamp;
+    queue.bump = ctx.bumps.dispute_queue;
+
+    msg!("Dispute queue initialized");
     Ok(())
 }
 
@@ -259,36 +984,164 @@ pub struct FileDispute<'info> {
             &escrow.escrow_id.to_le_bytes()
         ],
         bump = escrow.bump,
-        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState
+        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
     )]
     pub escrow: Account<'info, GhostProtectEscrow>,
 
     #[account(
+        mut,
         constraint = client.key() == escrow.client @ GhostSpeakError::UnauthorizedAccess
     )]
     pub client: Signer<'info>,
+
+    /// Client reputation record (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientReputation::LEN,
+        seeds = [b"client_reputation", client.key().as_ref()],
+        bump
+    )]
+    pub client_reputation: Account<'info, ClientReputation>,
+
+    /// Source of the per-tier queue head starts and SLAs
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Client's staking account, if any (sets the dispute's priority tier)
+    #[account(seeds = [b"staking", client.key().as_ref()], bump = client_staking.bump)]
+    pub client_staking: Option<Account<'info, StakingAccount>>,
+
+    #[account(mut, seeds = [DISPUTE_QUEUE_SEED], bump = dispute_queue.bump)]
+    pub dispute_queue: Account<'info, DisputeQueue>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Box<Account<'info, RateLimiter>>,
+
+    /// Per-client rate limit tracking (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            client.key().as_ref(),
+            OPERATION_DISPUTE_FILING.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Box<Account<'info, UserRateLimit>>,
+
+    /// Circuit breaker; filings are refused while paused and count toward
+    /// its dispute threshold
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker"],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub circuit_breaker: Box<Account<'info, CircuitBreaker>>,
+
+    /// CHECK: Parameter registry PDA, read for the dispute bond; defaults apply until
+    /// it is initialized
+    #[account(seeds = [PARAMETER_REGISTRY_SEED], bump)]
+    pub parameter_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn file_dispute(
     ctx: Context<FileDispute>,
     reason: String,
 ) -> Result<()> {
+    ctx.accounts.circuit_breaker.check_not_paused()?;
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.client.key(),
+        OPERATION_DISPUTE_FILING,
+        ctx.bumps.user_rate_limit,
+    )?;
+
     let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
 
     require!(
         reason.len() <= GhostProtectEscrow::MAX_DISPUTE_REASON_LEN,
         GhostSpeakError::InputTooLong
     );
 
+    // Bond the dispute on the escrow account until the ruling
+    let bond = read_parameter(
+        &ctx.accounts.parameter_registry,
+        ParameterKey::DisputeBondLamports,
+    )?;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.client.to_account_info(),
+                to: escrow.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+
     escrow.status = EscrowStatus::Disputed;
     escrow.dispute_reason = Some(reason.clone());
+    escrow.dispute_bond = bond;
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.initialize_if_needed(
+        ctx.accounts.client.key(),
+        ctx.bumps.client_reputation,
+        clock.unix_timestamp,
+    );
+    client_reputation.record_dispute_filed(clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow.escrow_id);
+
+    // Queue for arbitration by the client's staking tier
+    let staking_config = &ctx.accounts.staking_config;
+    let tier = ctx
+        .accounts
+        .client_staking
+        .as_ref()
+        .map_or(AccessTier::None, |staking| staking.current_tier(&staking_config.tier_schedule));
+    let sla_deadline = staking_config.dispute_sla_deadline(tier, clock.unix_timestamp);
+    let position = ctx.accounts.dispute_queue.insert(
+        DisputeQueueEntry {
+            escrow: escrow.key(),
+            tier,
+            filed_at: clock.unix_timestamp,
+            priority_key: staking_config.dispute_priority_key(tier, clock.unix_timestamp),
+            sla_deadline,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    emit!(DisputeQueuedEvent {
+        escrow_id: escrow.escrow_id,
+        tier,
+        position: position as u8,
+        sla_deadline,
+    });
 
     emit!(DisputeFiledEvent {
         escrow_id: escrow.escrow_id,
         client: ctx.accounts.client.key(),
         reason,
+        bond_lamports: bond,
     });
 
+    record_anomaly(
+        &mut ctx.accounts.circuit_breaker,
+        AnomalyKind::DisputeFiled,
+        clock.unix_timestamp,
+    )?;
+
     msg!("Dispute filed for escrow: {}", escrow.escrow_id);
 
     Ok(())
@@ -309,14 +1162,32 @@ pub struct ArbitrateDispute<'info> {
             &escrow.escrow_id.to_le_bytes()
         ],
         bump = escrow.bump,
-        constraint = escrow.status == EscrowStatus::Disputed @ GhostSpeakError::InvalidState
+        constraint = escrow.status == EscrowStatus::Disputed @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
     )]
     pub escrow: Account<'info, GhostProtectEscrow>,
 
-    #[account(mut)]
+    /// Escrowed agent, whose owner receives the payout and a frivolous dispute's bond
+    #[account(
+        address = escrow.agent @ GhostSpeakError::InvalidAgent,
+        constraint = agent.is_current(agent.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub agent: Box<Account<'info, Agent>>,
+
+    /// Vault holding the escrowed tokens, owned by the escrow PDA
+    #[account(
+        mut,
+        constraint = escrow_vault.owner == escrow.key()
+            && escrow_vault.mint == escrow.token_mint @ GhostSpeakError::InvalidTokenAccount
+    )]
     pub escrow_vault: Account<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = agent.owner == Some(agent_token_account.owner) @ GhostSpeakError::InvalidAgentOwner
+    )]
     pub agent_token_account: Account<'info, TokenAccount>,
 
     #[account(mut)]
@@ -330,29 +1201,169 @@ pub struct ArbitrateDispute<'info> {
     )]
     pub agent_staking: Account<'info, StakingAccount>,
 
+    /// Client reputation record (created when the dispute was filed)
+    #[account(
+        mut,
+        seeds = [b"client_reputation", escrow.client.as_ref()],
+        bump = client_reputation.bump,
+    )]
+    pub client_reputation: Account<'info, ClientReputation>,
+
+    /// Vault holding all stake, owned by the staking config; checked against
+    /// its PDA in the handler (trial bond clawback only)
+    #[account(mut)]
+    pub staking_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Client's account for the staking token (trial bond clawback only)
+    #[account(
+        mut,
+        constraint = client_bond_token_account.owner == escrow.client @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub client_bond_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Staking config: current tier thresholds for the arbitrator gate, and
+    /// reward settlement on a trial bond clawback
+    #[account(mut, seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// Disputes must be ruled on in queue order unless past their SLA
+    #[account(mut, seeds = [DISPUTE_QUEUE_SEED], bump = dispute_queue.bump)]
+    pub dispute_queue: Account<'info, DisputeQueue>,
+
+    /// Protocol config (staking tier gate for arbitrators)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Arbitrator's staking account, if any (checked against the tier gate)
+    #[account(seeds = [b"staking", arbitrator.key().as_ref()], bump = arbitrator_staking.bump)]
+    pub arbitrator_staking: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// Agent's settled volume, which sets its escrow fee rate (created on first use)
+    #[account(
+        init_if_needed,
+        payer = arbitrator,
+        space = AgentSettlementStats::LEN,
+        seeds = [AGENT_SETTLEMENT_STATS_SEED, escrow.agent.as_ref()],
+        bump
+    )]
+    pub agent_settlement_stats: Box<Account<'info, AgentSettlementStats>>,
+
+    /// CHECK: Escrow fee curve; uninitialized if never created
+    #[account(seeds = [ESCROW_FEE_CURVE_SEED], bump)]
+    pub escrow_fee_curve: UncheckedAccount<'info>,
+
+    /// CHECK: Fee exemption list; uninitialized if never created
+    #[account(seeds = [FEE_EXEMPTION_LIST_SEED], bump)]
+    pub fee_exemption_list: UncheckedAccount<'info>,
+
+    /// Fee vault for the escrow token; required when a dispute or escrow fee is due
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, escrow.token_mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    /// Fee vault's token account; checked against the vault in the handler
+    #[account(mut)]
+    pub fee_vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Stats of the escrow's referrer (else the agent's); with its token
+    /// account, required when a referrer is owed a share of the fee
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS_SEED, referrer_stats.referrer.as_ref()],
+        bump = referrer_stats.bump
+    )]
+    pub referrer_stats: Option<Box<Account<'info, ReferrerStats>>>,
+
+    /// Referrer's account for the escrow token
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Circuit breaker; rulings are refused while paused, and rulings past
+    /// the dispute's SLA count toward its failed arbitration threshold
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker"],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub circuit_breaker: Box<Account<'info, CircuitBreaker>>,
+
     /// CHECK: Arbitrator authority (validated by protocol)
+    #[account(mut)]
     pub arbitrator: Signer<'info>,
 
+    /// CHECK: Escrow client, refunded the dispute bond if the dispute is upheld
+    #[account(mut, address = escrow.client @ GhostSpeakError::UnauthorizedAccess)]
+    pub client: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner, paid a frivolous dispute's bond
+    #[account(
+        mut,
+        constraint = agent.owner == Some(agent_wallet.key()) @ GhostSpeakError::InvalidAgentOwner
+    )]
+    pub agent_wallet: UncheckedAccount<'info>,
+
+    /// Per-escrow payout lock, held while this instruction moves funds and
+    /// closed back to the arbitrator once it is done
+    #[account(
+        init_if_needed,
+        payer = arbitrator,
+        space = AccountLock::LEN,
+        seeds = [ACCOUNT_LOCK_SEED, escrow.key().as_ref(), ESCROW_RELEASE_LOCK.as_bytes()],
+        bump
+    )]
+    pub account_lock: Box<Account<'info, AccountLock>>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn arbitrate_dispute(
     ctx: Context<ArbitrateDispute>,
     decision: ArbitratorDecision,
 ) -> Result<()> {
-    let escrow = &mut ctx.accounts.escrow;
+    ctx.accounts.circuit_breaker.check_not_paused()?;
     let clock = Clock::get()?;
+    ctx.accounts.account_lock.acquire(
+        ctx.accounts.escrow.key(),
+        ESCROW_RELEASE_LOCK,
+        ctx.accounts.arbitrator.key(),
+        clock.unix_timestamp,
+        ctx.bumps.account_lock,
+    )?;
+    let escrow = &mut ctx.accounts.escrow;
 
-    // Calculate payment distribution
-    let (client_amount, agent_amount) = match &decision {
-        ArbitratorDecision::FavorClient { .. } => (escrow.amount, 0u64),
-        ArbitratorDecision::FavorAgent { .. } => (0u64, escrow.amount),
-        ArbitratorDecision::Split { client_percentage, .. } => {
-            let client_amt = (escrow.amount as u128 * *client_percentage as u128 / 100) as u64;
-            let agent_amt = escrow.amount - client_amt;
-            (client_amt, agent_amt)
-        }
-    };
+    let settlement = settle_arbitration(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.staking_config,
+        ctx.accounts.arbitrator_staking.as_deref(),
+        &ctx.accounts.dispute_queue,
+        escrow,
+        &ctx.accounts.agent_staking,
+        &ctx.accounts.escrow_fee_curve,
+        &ctx.accounts.fee_exemption_list,
+        ctx.accounts.agent_settlement_stats.trailing_volume(clock.unix_timestamp),
+        &decision,
+        clock.unix_timestamp,
+    )?;
+    let queued = ctx
+        .accounts
+        .dispute_queue
+        .take_for_ruling(&escrow.key(), clock.unix_timestamp)?;
+    settlement
+        .dispute_fee
+        .emit_waiver(escrow.agent, FeeSource::Dispute, clock.unix_timestamp);
+    settlement
+        .escrow_fee
+        .emit_waiver(escrow.agent, FeeSource::Escrow, clock.unix_timestamp);
+    let client_amount = settlement.client_amount;
+    let agent_amount = settlement.agent_amount;
+    let resolution_fee = settlement.dispute_fee.fee;
+    let fee = settlement.escrow_fee.fee;
 
     // Transfer funds
     let client_key = escrow.client;
@@ -380,8 +1391,34 @@ pub fn arbitrate_dispute(
         token::transfer(cpi_ctx, client_amount)?;
     }
 
+    pay_into_fee_vault(
+        FeeSource::Dispute,
+        resolution_fee,
+        escrow,
+        &ctx.accounts.escrow_vault,
+        ctx.accounts.fee_vault.as_deref_mut(),
+        ctx.accounts.fee_vault_token_account.as_deref(),
+        &ctx.accounts.token_program,
+        signer_seeds,
+        clock.unix_timestamp,
+    )?;
+    pay_escrow_fee(
+        fee,
+        &ctx.accounts.protocol_config,
+        escrow,
+        ctx.accounts.agent.referrer,
+        &ctx.accounts.escrow_vault,
+        ctx.accounts.fee_vault.as_deref_mut(),
+        ctx.accounts.fee_vault_token_account.as_deref(),
+        ctx.accounts.referrer_stats.as_deref_mut(),
+        ctx.accounts.referrer_token_account.as_deref(),
+        &ctx.accounts.token_program,
+        signer_seeds,
+        clock.unix_timestamp,
+    )?;
+
     // Send to agent if any
-    if agent_amount > 0 {
+    if agent_amount > fee {
         let cpi_accounts = Transfer {
             from: ctx.accounts.escrow_vault.to_account_info(),
             to: ctx.accounts.agent_token_account.to_account_info(),
@@ -392,7 +1429,58 @@ pub fn arbitrate_dispute(
             cpi_accounts,
             signer_seeds
         );
-        token::transfer(cpi_ctx, agent_amount)?;
+        token::transfer(cpi_ctx, agent_amount - fee)?;
+    }
+
+    // The trial is over either way, so its bond no longer reserves stake;
+    // on total non-delivery the upfront share is recovered from it
+    let staking = &mut ctx.accounts.agent_staking;
+    if escrow.is_trial() {
+        staking.release_bond(escrow.trial_bond);
+    }
+    let clawback = settlement.clawback;
+    if clawback > 0 {
+        let (Some(staking_vault), Some(client_bond_token_account)) = (
+            ctx.accounts.staking_vault.as_ref(),
+            ctx.accounts.client_bond_token_account.as_ref(),
+        ) else {
+            return err!(GhostSpeakError::TrialBondAccountsRequired);
+        };
+        let staking_config = &mut ctx.accounts.staking_config;
+        let (vault_key, _) = Pubkey::find_program_address(
+            &[b"staking_vault", staking_config.key().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(staking_vault.key(), vault_key, GhostSpeakError::UnauthorizedAccess);
+
+        // The staking config owns the vault, so it signs the clawback
+        let config_seeds = &[b"staking_config".as_ref(), &[staking_config.bump]];
+        let config_signer = &[&config_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: staking_vault.to_account_info(),
+            to: client_bond_token_account.to_account_info(),
+            authority: staking_config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            config_signer
+        );
+        token::transfer(cpi_ctx, clawback)?;
+
+        let remaining = staking.amount_staked.saturating_sub(clawback);
+        staking_config.update_stake(staking, remaining, clock.unix_timestamp)?;
+        staking.total_slashed = staking.total_slashed.saturating_add(clawback);
+        staking.calculate_boost(&staking_config.tier_schedule);
+        escrow.bond_clawed_back = true;
+
+        emit!(TrialBondClawedBackEvent {
+            escrow_id: escrow.escrow_id,
+            client: escrow.client,
+            agent: escrow.agent,
+            amount: clawback,
+        });
     }
 
     // Update escrow
@@ -400,13 +1488,652 @@ pub fn arbitrate_dispute(
     escrow.completed_at = Some(clock.unix_timestamp);
     escrow.arbitrator_decision = Some(decision.clone());
 
+    if agent_amount > 0 {
+        record_agent_settlement(
+            &mut ctx.accounts.agent_settlement_stats,
+            ctx.bumps.agent_settlement_stats,
+            escrow,
+            agent_amount,
+            clock.unix_timestamp,
+        );
+    }
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.record_dispute_resolved(&decision, clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow.escrow_id);
+
     emit!(DisputeResolvedEvent {
         escrow_id: escrow.escrow_id,
         decision: decision.clone(),
         arbitrator: ctx.accounts.arbitrator.key(),
     });
 
+    // Refund the dispute bond if upheld, otherwise forfeit it to the agent
+    let bond = escrow.dispute_bond;
+    if bond > 0 {
+        let refunded = decision.upholds_dispute();
+        let recipient = if refunded {
+            ctx.accounts.client.to_account_info()
+        } else {
+            ctx.accounts.agent_wallet.to_account_info()
+        };
+        escrow.sub_lamports(bond)?;
+        recipient.add_lamports(bond)?;
+        escrow.dispute_bond = 0;
+
+        emit!(DisputeBondSettledEvent {
+            escrow_id: escrow.escrow_id,
+            client: escrow.client,
+            refunded,
+            recipient: recipient.key(),
+            amount: bond,
+        });
+    }
+
+    // A ruling past the dispute's SLA is a failed arbitration
+    if clock.unix_timestamp > queued.sla_deadline {
+        record_anomaly(
+            &mut ctx.accounts.circuit_breaker,
+            AnomalyKind::ArbitrationFailed,
+            clock.unix_timestamp,
+        )?;
+    }
+
     msg!("Dispute resolved for escrow: {} - Decision: {:?}", escrow.escrow_id, decision);
 
+    ctx.accounts
+        .account_lock
+        .close(ctx.accounts.arbitrator.to_account_info())?;
+    Ok(())
+}
+
+// =====================================================
+// CANCEL ESCROW
+// =====================================================
+
+/// Client cancels an escrow before any delivery and is refunded
+#[derive(Accounts)]
+pub struct CancelEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"ghost_protect",
+            escrow.client.as_ref(),
+            &escrow.escrow_id.to_le_bytes()
+        ],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub escrow: Account<'info, GhostProtectEscrow>,
+
+    #[account(mut)]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = client_token_account.owner == client.key()
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = client.key() == escrow.client @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub client: Signer<'info>,
+
+    /// Client reputation record (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = ClientReputation::LEN,
+        seeds = [b"client_reputation", client.key().as_ref()],
+        bump
+    )]
+    pub client_reputation: Account<'info, ClientReputation>,
+
+    /// Agent's staking account, whose bond is released (trial escrows only)
+    #[account(mut, seeds = [b"staking", escrow.agent.as_ref()], bump = agent_staking.bump)]
+    pub agent_staking: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// Per-escrow payout lock, held while this instruction moves funds and
+    /// closed back to the client once it is done
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = AccountLock::LEN,
+        seeds = [ACCOUNT_LOCK_SEED, escrow.key().as_ref(), ESCROW_RELEASE_LOCK.as_bytes()],
+        bump
+    )]
+    pub account_lock: Box<Account<'info, AccountLock>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.account_lock.acquire(
+        ctx.accounts.escrow.key(),
+        ESCROW_RELEASE_LOCK,
+        ctx.accounts.client.key(),
+        clock.unix_timestamp,
+        ctx.bumps.account_lock,
+    )?;
+    let escrow = &mut ctx.accounts.escrow;
+
+    // Once work is delivered the client must approve or dispute
+    require!(escrow.delivery_proof.is_none(), GhostSpeakError::InvalidState);
+
+    // Refund the client
+    let client_key = escrow.client;
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"ghost_protect",
+        client_key.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.escrow_vault.to_account_info(),
+        to: ctx.accounts.client_token_account.to_account_info(),
+        authority: escrow.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds
+    );
+    token::transfer(cpi_ctx, escrow.held_amount())?;
+
+    escrow.status = EscrowStatus::Cancelled;
+    escrow.completed_at = Some(clock.unix_timestamp);
+    release_trial_bond(escrow, ctx.accounts.agent_staking.as_deref_mut())?;
+
+    let client_reputation = &mut ctx.accounts.client_reputation;
+    client_reputation.initialize_if_needed(
+        client_key,
+        ctx.bumps.client_reputation,
+        clock.unix_timestamp,
+    );
+    client_reputation.record_cancellation(clock.unix_timestamp);
+    emit_client_reputation(client_reputation, escrow.escrow_id);
+
+    emit!(EscrowCancelledEvent {
+        escrow_id: escrow.escrow_id,
+        client: client_key,
+        amount: escrow.held_amount(),
+    });
+
+    msg!("Escrow cancelled: {}", escrow.escrow_id);
+
+    ctx.accounts
+        .account_lock
+        .close(ctx.accounts.client.to_account_info())?;
+    Ok(())
+}
+
+/// Fee due on a payment, and the part of it a fee exemption waived
+#[derive(Clone, Copy)]
+struct FeeCharge {
+    fee: u64,
+    waived: u64,
+}
+
+impl FeeCharge {
+    fn new(fee: u64, exempt: bool) -> Self {
+        if exempt {
+            Self { fee: 0, waived: fee }
+        } else {
+            Self { fee, waived: 0 }
+        }
+    }
+
+    /// Record the waived part, if any
+    fn emit_waiver(&self, subject: Pubkey, source: FeeSource, now: i64) {
+        if self.waived > 0 {
+            emit!(FeeWaivedEvent {
+                subject,
+                source,
+                amount: self.waived,
+                timestamp: now,
+            });
+        }
+    }
+}
+
+/// Protocol fee on an agent payment of `amount`, at the rate the agent's
+/// trailing volume earns on the escrow fee curve (before this settlement)
+fn escrow_fee(
+    protocol_config: &ProtocolConfig,
+    escrow_fee_curve: &AccountInfo,
+    fee_exemption_list: &AccountInfo,
+    trailing_volume: u64,
+    agent: Pubkey,
+    amount: u64,
+    tier: AccessTier,
+    now: i64,
+) -> Result<FeeCharge> {
+    let curve = read_escrow_fee_curve(escrow_fee_curve)?;
+    let fee_bps = protocol_config.escrow_fee_bps_for(curve.as_ref(), trailing_volume);
+    let (agent_amount, _, _) = protocol_config.calculate_escrow_fee(amount, fee_bps, tier, false);
+    let exempt = is_fee_exempt(fee_exemption_list, &[agent], FeeSource::Escrow, now)?;
+    Ok(FeeCharge::new(amount - agent_amount, exempt))
+}
+
+/// Dispute resolution fee on a disputed escrow holding `amount`
+fn dispute_fee(
+    protocol_config: &ProtocolConfig,
+    fee_exemption_list: &AccountInfo,
+    agent: Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<FeeCharge> {
+    let fee = protocol_config.calculate_dispute_fee(amount);
+    let exempt = fee > 0 && is_fee_exempt(fee_exemption_list, &[agent], FeeSource::Dispute, now)?;
+    Ok(FeeCharge::new(fee, exempt))
+}
+
+/// Payouts of a ruling on a disputed escrow
+struct ArbitrationSettlement {
+    /// Dispute fee, taken off the held amount before the split
+    dispute_fee: FeeCharge,
+    client_amount: u64,
+    /// Agent's share, before the escrow fee
+    agent_amount: u64,
+    /// Escrow fee, taken out of the agent's share
+    escrow_fee: FeeCharge,
+    /// Trial bond stake returned to the client
+    clawback: u64,
+}
+
+/// Check that `arbitrator_staking`'s owner may rule on `escrow` now and work
+/// out what ruling `decision` pays; shared by `arbitrate_dispute` and its
+/// dry run so the two cannot drift
+fn settle_arbitration(
+    protocol_config: &ProtocolConfig,
+    staking_config: &StakingConfig,
+    arbitrator_staking: Option<&Account<StakingAccount>>,
+    dispute_queue: &DisputeQueue,
+    escrow: &Account<GhostProtectEscrow>,
+    agent_staking: &StakingAccount,
+    escrow_fee_curve: &AccountInfo,
+    fee_exemption_list: &AccountInfo,
+    trailing_volume: u64,
+    decision: &ArbitratorDecision,
+    now: i64,
+) -> Result<ArbitrationSettlement> {
+    // Only arbitrators staked at the governed tier may rule
+    let arbitrator_tier = arbitrator_staking.map_or(AccessTier::None, |staking| {
+        staking.current_tier(&staking_config.tier_schedule)
+    });
+    protocol_config.require_tier(GatedAction::Arbitrate, arbitrator_tier)?;
+
+    dispute_queue.ruling_position(&escrow.key(), now)?;
+
+    // The dispute fee comes off the escrowed amount before the ruling splits
+    // it, and the escrow fee comes out of the agent's share
+    let resolution_fee = dispute_fee(
+        protocol_config,
+        fee_exemption_list,
+        escrow.agent,
+        escrow.held_amount(),
+        now,
+    )?;
+    let (client_amount, agent_amount) =
+        decision.payout_split(escrow.held_amount() - resolution_fee.fee)?;
+    let fee = escrow_fee(
+        protocol_config,
+        escrow_fee_curve,
+        fee_exemption_list,
+        trailing_volume,
+        escrow.agent,
+        agent_amount,
+        agent_staking.current_tier(&staking_config.tier_schedule),
+        now,
+    )?;
+
+    // On total non-delivery the upfront share is recovered from the trial bond
+    let clawback = escrow.clawback_amount(decision).min(agent_staking.amount_staked);
+
+    Ok(ArbitrationSettlement {
+        dispute_fee: resolution_fee,
+        client_amount,
+        agent_amount,
+        escrow_fee: fee,
+        clawback,
+    })
+}
+
+/// Pay an escrow fee out of the escrow vault: the referrer's cut (the
+/// escrow's referrer, else `agent_referrer`) first, the rest into the
+/// token's fee vault
+fn pay_escrow_fee<'info>(
+    fee: u64,
+    protocol_config: &ProtocolConfig,
+    escrow: &Account<'info, GhostProtectEscrow>,
+    agent_referrer: Option<Pubkey>,
+    escrow_vault: &Account<'info, TokenAccount>,
+    fee_vault: Option<&mut Account<'info, FeeVault>>,
+    fee_vault_token_account: Option<&Account<'info, TokenAccount>>,
+    referrer_stats: Option<&mut Account<'info, ReferrerStats>>,
+    referrer_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    signer_seeds: &[&[&[u8]]],
+    now: i64,
+) -> Result<()> {
+    let (_, referrer_share) = protocol_config.split_referral_fee(fee);
+    // What is left after the referrer's cut goes to the fee vault
+    let fee = match escrow.referrer.or(agent_referrer) {
+        Some(referrer) if referrer_share > 0 => {
+            let (Some(stats), Some(token_account)) = (referrer_stats, referrer_token_account)
+            else {
+                return err!(GhostSpeakError::ReferrerNotRegistered);
+            };
+            require_keys_eq!(stats.referrer, referrer, GhostSpeakError::InvalidReferrer);
+            route_referral_fee(
+                protocol_config,
+                stats,
+                token_account,
+                escrow_vault.to_account_info(),
+                escrow.to_account_info(),
+                token_program.to_account_info(),
+                signer_seeds,
+                FeeSource::Escrow,
+                fee,
+            )?
+        }
+        _ => fee,
+    };
+    pay_into_fee_vault(
+        FeeSource::Escrow,
+        fee,
+        escrow,
+        escrow_vault,
+        fee_vault,
+        fee_vault_token_account,
+        token_program,
+        signer_seeds,
+        now,
+    )
+}
+
+/// Move a fee from `source` out of the escrow vault into the token's fee vault
+fn pay_into_fee_vault<'info>(
+    source: FeeSource,
+    fee: u64,
+    escrow: &Account<'info, GhostProtectEscrow>,
+    escrow_vault: &Account<'info, TokenAccount>,
+    fee_vault: Option<&mut Account<'info, FeeVault>>,
+    fee_vault_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    signer_seeds: &[&[&[u8]]],
+    now: i64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    let fee_vault = fee_vault.ok_or(GhostSpeakError::ProtocolTreasuryRequired)?;
+    let vault_token_account =
+        fee_vault_token_account.ok_or(GhostSpeakError::InvalidFeeVaultAccount)?;
+    require_keys_eq!(
+        vault_token_account.key(),
+        fee_vault.token_account,
+        GhostSpeakError::InvalidFeeVaultAccount
+    );
+
+    let cpi_accounts = Transfer {
+        from: escrow_vault.to_account_info(),
+        to: vault_token_account.to_account_info(),
+        authority: escrow.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds
+    );
+    token::transfer(cpi_ctx, fee)?;
+    fee_vault.accrue(source, fee, now)?;
+
+    emit!(FeeAccruedEvent {
+        fee_vault: fee_vault.key(),
+        source,
+        amount: fee,
+        total_for_source: fee_vault.accrued[source.index()],
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Free a settled trial escrow's bond in the agent's stake
+fn release_trial_bond(
+    escrow: &GhostProtectEscrow,
+    agent_staking: Option<&mut Account<StakingAccount>>,
+) -> Result<()> {
+    if escrow.is_trial() {
+        let staking = agent_staking.ok_or(GhostSpeakError::TrialStakeRequired)?;
+        staking.release_bond(escrow.trial_bond);
+    }
+    Ok(())
+}
+
+fn record_agent_settlement(
+    stats: &mut AgentSettlementStats,
+    bump: u8,
+    escrow: &GhostProtectEscrow,
+    amount: u64,
+    now: i64,
+) {
+    stats.initialize_if_needed(escrow.agent, bump, now);
+    stats.record_settlement(amount, now);
+
+    emit!(AgentSettlementRecordedEvent {
+        agent: escrow.agent,
+        escrow_id: escrow.escrow_id,
+        amount,
+        trailing_volume: stats.trailing_volume(now),
+    });
+}
+
+fn emit_client_reputation(client_reputation: &ClientReputation, escrow_id: u64) {
+    emit!(ClientReputationUpdatedEvent {
+        client: client_reputation.client,
+        escrow_id,
+        total_escrows: client_reputation.total_escrows,
+        payment_reliability_bps: client_reputation.payment_reliability_bps(),
+        dispute_rate_bps: client_reputation.dispute_rate_bps(),
+        cancellation_rate_bps: client_reputation.cancellation_rate_bps(),
+    });
+}
+
+// =====================================================
+// SIMULATE ARBITRATION (DRY RUN)
+// =====================================================
+
+/// Read-only preview of a dispute resolution (no state changes)
+#[derive(Accounts)]
+pub struct SimulateArbitrateDispute<'info> {
+    #[account(
+        seeds = [
+            b"ghost_protect",
+            escrow.client.as_ref(),
+            &escrow.escrow_id.to_le_bytes()
+        ],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Disputed @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub escrow: Account<'info, GhostProtectEscrow>,
+
+    /// Escrowed agent, whose owner would receive a frivolous dispute's bond
+    #[account(
+        address = escrow.agent @ GhostSpeakError::InvalidAgent,
+        constraint = agent.is_current(agent.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub agent: Box<Account<'info, Agent>>,
+
+    /// Vault holding the escrowed tokens, owned by the escrow PDA
+    #[account(
+        constraint = escrow_vault.owner == escrow.key()
+            && escrow_vault.mint == escrow.token_mint @ GhostSpeakError::InvalidTokenAccount
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    /// Agent's staking account (trial bond clawback and escrow fee discount)
+    #[account(seeds = [b"staking", escrow.agent.as_ref()], bump = agent_staking.bump)]
+    pub agent_staking: Box<Account<'info, StakingAccount>>,
+
+    /// Staking config: current tier thresholds
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// Disputes must be ruled on in queue order unless past their SLA
+    #[account(seeds = [DISPUTE_QUEUE_SEED], bump = dispute_queue.bump)]
+    pub dispute_queue: Box<Account<'info, DisputeQueue>>,
+
+    /// Protocol config (arbitrator tier gate and fee rates)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Arbitrator's staking account, if any (checked against the tier gate)
+    #[account(seeds = [b"staking", arbitrator.key().as_ref()], bump = arbitrator_staking.bump)]
+    pub arbitrator_staking: Option<Box<Account<'info, StakingAccount>>>,
+
+    /// CHECK: Agent's settled volume PDA; uninitialized if the agent was never paid
+    #[account(seeds = [AGENT_SETTLEMENT_STATS_SEED, escrow.agent.as_ref()], bump)]
+    pub agent_settlement_stats: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow fee curve; uninitialized if never created
+    #[account(seeds = [ESCROW_FEE_CURVE_SEED], bump)]
+    pub escrow_fee_curve: UncheckedAccount<'info>,
+
+    /// CHECK: Fee exemption list; uninitialized if never created
+    #[account(seeds = [FEE_EXEMPTION_LIST_SEED], bump)]
+    pub fee_exemption_list: UncheckedAccount<'info>,
+
+    /// Circuit breaker; rulings are refused while paused
+    #[account(
+        seeds = [b"circuit_breaker"],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub circuit_breaker: Box<Account<'info, CircuitBreaker>>,
+
+    /// CHECK: Arbitrator authority (validated by protocol)
+    pub arbitrator: Signer<'info>,
+}
+
+/// Compute the payouts `arbitrate_dispute` would make for `decision`
+/// without moving funds. The preview is returned via return data.
+pub fn simulate_arbitrate_dispute(
+    ctx: Context<SimulateArbitrateDispute>,
+    decision: ArbitratorDecision,
+) -> Result<ArbitrationPreview> {
+    ctx.accounts.circuit_breaker.check_not_paused()?;
+    let now = Clock::get()?.unix_timestamp;
+    let escrow = &ctx.accounts.escrow;
+    let vault_balance = ctx.accounts.escrow_vault.amount;
+
+    let trailing_volume = read_agent_settlement_stats(&ctx.accounts.agent_settlement_stats)?
+        .map_or(0, |stats| stats.trailing_volume(now));
+    let settlement = settle_arbitration(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.staking_config,
+        ctx.accounts.arbitrator_staking.as_deref(),
+        &ctx.accounts.dispute_queue,
+        escrow,
+        &ctx.accounts.agent_staking,
+        &ctx.accounts.escrow_fee_curve,
+        &ctx.accounts.fee_exemption_list,
+        trailing_volume,
+        &decision,
+        now,
+    )?;
+    let client_amount = settlement.client_amount;
+    let agent_amount = settlement.agent_amount - settlement.escrow_fee.fee;
+
+    msg!("Dry run: escrow {} would pay client {} and agent {}",
+        escrow.escrow_id, client_amount, agent_amount);
+
+    let bond_refunded = decision.upholds_dispute();
+    let bond_recipient = if bond_refunded {
+        escrow.client
+    } else {
+        ctx.accounts.agent.owner.ok_or(GhostSpeakError::InvalidAgentOwner)?
+    };
+
+    Ok(ArbitrationPreview {
+        escrow_id: escrow.escrow_id,
+        client: escrow.client,
+        agent: escrow.agent,
+        client_amount,
+        agent_amount,
+        dispute_fee: settlement.dispute_fee.fee,
+        escrow_fee: settlement.escrow_fee.fee,
+        trial_bond_clawback: settlement.clawback,
+        vault_balance_before: vault_balance,
+        vault_balance_after: vault_balance.saturating_sub(escrow.held_amount()),
+        dispute_bond: escrow.dispute_bond,
+        bond_refunded,
+        bond_recipient,
+    })
+}
+
+// =====================================================
+// LAYOUT MIGRATION
+// =====================================================
+
+/// Grow an escrow created before the layout version (permissionless)
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct MigrateEscrow<'info> {
+    /// CHECK: Escrow created before the layout version, whose trailing
+    /// bytes can't be trusted; checked and parsed by the handler
+    #[account(
+        mut,
+        seeds = [
+            b"ghost_protect",
+            client.key().as_ref(),
+            &escrow_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow client; only keys the escrow PDA
+    pub client: UncheckedAccount<'info>,
+
+    /// Anyone may migrate; pays the rent for the added bytes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_escrow(ctx: Context<MigrateEscrow>, escrow_id: u64) -> Result<()> {
+    let info = ctx.accounts.escrow.to_account_info();
+    let legacy =
+        read_legacy_layout::<GhostProtectEscrow, GhostProtectEscrowV0>(&info, GhostProtectEscrow::LEN)?;
+    let escrow = GhostProtectEscrow::from_v0(legacy);
+    write_current_layout(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        GhostProtectEscrow::LEN,
+        &escrow,
+    )?;
+
+    emit!(EscrowMigratedEvent {
+        escrow_id,
+        client: escrow.client,
+        version: GhostProtectEscrow::CURRENT_VERSION,
+    });
+
     Ok(())
 }
@@ -0,0 +1,276 @@
+/*!
+ * Insurance Pool Instructions
+ *
+ * Sets up the pool that receives finalized slashes and lets fraud victims
+ * claim compensation from it, subject to review by the slash review
+ * multisig.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::governance::Multisig;
+use crate::state::insurance_pool::*;
+use crate::state::staking::{StakingAccount, StakingConfig};
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE INSURANCE POOL
+// =====================================================
+
+/// Initialize the slash-funded insurance pool (staking authority only)
+#[derive(Accounts)]
+pub struct InitializeInsurancePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = InsurancePool::LEN,
+        seeds = [INSURANCE_POOL_SEED],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    /// GHOST vault owned by the pool
+    #[account(
+        init,
+        payer = authority,
+        token::mint = ghost_mint,
+        token::authority = insurance_pool,
+        seeds = [INSURANCE_VAULT_SEED, insurance_pool.key().as_ref()],
+        bump
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: GHOST token mint, pinned to the staking vault's mint
+    #[account(address = staking_vault.mint @ GhostSpeakError::InvalidInput)]
+    pub ghost_mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_insurance_pool(ctx: Context<InitializeInsurancePool>) -> Result<()> {
+    let pool = &mut ctx.accounts.insurance_pool;
+    pool.authority = ctx.accounts.authority.key();
+    pool.ghost_mint = ctx.accounts.ghost_mint.key();
+    pool.vault = ctx.accounts.insurance_vault.key();
+    pool.total_deposited = 0;
+    pool.total_paid = 0;
+    pool.claims_filed = 0;
+    pool.open_claims = 0;
+    pool.bump = ctx.bumps.insurance_pool;
+
+    emit!(InsurancePoolInitializedEvent {
+        authority: pool.authority,
+        ghost_mint: pool.ghost_mint,
+        vault: pool.vault,
+    });
+
+    msg!(
+        "Insurance pool initialized (mint: {}, vault: {})",
+        pool.ghost_mint,
+        pool.vault
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// FILE INSURANCE CLAIM
+// =====================================================
+
+/// Claim compensation for fraud by a slashed agent (victim only)
+#[derive(Accounts)]
+#[instruction(accused: Pubkey)]
+pub struct FileInsuranceClaim<'info> {
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    /// `init` allows one claim per claimant and accused
+    #[account(
+        init,
+        payer = claimant,
+        space = InsuranceClaim::LEN,
+        seeds = [INSURANCE_CLAIM_SEED, claimant.key().as_ref(), accused.as_ref()],
+        bump
+    )]
+    pub insurance_claim: Account<'info, InsuranceClaim>,
+
+    /// Accused agent owner's stake, which must have been slashed
+    #[account(
+        seeds = [b"staking", accused.as_ref()],
+        bump = accused_staking_account.bump,
+        constraint = accused_staking_account.total_slashed > 0 @ GhostSpeakError::AccusedNotSlashed
+    )]
+    pub accused_staking_account: Account<'info, StakingAccount>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn file_insurance_claim(
+    ctx: Context<FileInsuranceClaim>,
+    accused: Pubkey,
+    amount_requested: u64,
+    evidence_uri: String,
+) -> Result<()> {
+    let claimant = ctx.accounts.claimant.key();
+    require!(accused != claimant, GhostSpeakError::InvalidInput);
+    require!(amount_requested > 0, GhostSpeakError::InvalidAmount);
+    require!(
+        !evidence_uri.is_empty() && evidence_uri.len() <= MAX_INSURANCE_EVIDENCE_URI,
+        GhostSpeakError::InvalidMetadataUri
+    );
+
+    let claim = &mut ctx.accounts.insurance_claim;
+    claim.claimant = claimant;
+    claim.accused = accused;
+    claim.amount_requested = amount_requested;
+    claim.amount_paid = 0;
+    claim.evidence_uri = evidence_uri;
+    claim.filed_at = Clock::get()?.unix_timestamp;
+    claim.reviewed_at = 0;
+    claim.status = InsuranceClaimStatus::Pending;
+    claim.bump = ctx.bumps.insurance_claim;
+
+    ctx.accounts.insurance_pool.record_claim();
+
+    emit!(InsuranceClaimFiledEvent {
+        claimant,
+        accused,
+        amount_requested,
+        evidence_uri: claim.evidence_uri.clone(),
+    });
+
+    msg!(
+        "Insurance claim of {} GHOST filed by {} against {}",
+        amount_requested,
+        claimant,
+        accused
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// REVIEW INSURANCE CLAIM
+// =====================================================
+
+/// Pay out or reject an insurance claim (review multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ReviewInsuranceClaim<'info> {
+    #[account(
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.slash_review_multisig == review_multisig.key() @ GhostSpeakError::InvalidSlashReviewMultisig
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    pub review_multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(mut, address = insurance_pool.vault)]
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            INSURANCE_CLAIM_SEED,
+            insurance_claim.claimant.as_ref(),
+            insurance_claim.accused.as_ref()
+        ],
+        bump = insurance_claim.bump
+    )]
+    pub insurance_claim: Box<Account<'info, InsuranceClaim>>,
+
+    #[account(
+        mut,
+        constraint = claimant_token_account.owner == insurance_claim.claimant @ GhostSpeakError::UnauthorizedAccess,
+        constraint = claimant_token_account.mint == insurance_pool.ghost_mint @ GhostSpeakError::InvalidInput
+    )]
+    pub claimant_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays `payout` (at most the amount requested) to the claimant; zero
+/// rejects the claim.
+pub fn review_insurance_claim(ctx: Context<ReviewInsuranceClaim>, payout: u64) -> Result<()> {
+    ctx.accounts
+        .review_multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let claim = &mut ctx.accounts.insurance_claim;
+    claim.review(payout, ctx.accounts.insurance_vault.amount, now)?;
+
+    let pool = &mut ctx.accounts.insurance_pool;
+    if payout > 0 {
+        let seeds = &[INSURANCE_POOL_SEED, &[pool.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.insurance_vault.to_account_info(),
+            to: ctx.accounts.claimant_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, payout)?;
+    }
+    pool.record_payout(payout);
+
+    emit!(InsuranceClaimReviewedEvent {
+        claimant: claim.claimant,
+        accused: claim.accused,
+        multisig: ctx.accounts.review_multisig.key(),
+        amount_requested: claim.amount_requested,
+        amount_paid: payout,
+        status: claim.status,
+    });
+
+    msg!(
+        "Insurance claim by {} against {} reviewed, {} of {} GHOST paid",
+        claim.claimant,
+        claim.accused,
+        payout,
+        claim.amount_requested
+    );
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+/*!
+ * Leaderboard Instructions
+ *
+ * Create per-category top-N boards and lazily submit agents to them.
+ */
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::leaderboard::*;
+use crate::state::ReputationMetrics;
+use crate::{GhostSpeakError, *};
+
+/// Create a leaderboard for a category (protocol admin only)
+#[derive(Accounts)]
+#[instruction(category: String, capacity: u8)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Leaderboard::space(capacity),
+        seeds = [LEADERBOARD_SEED, category.as_bytes()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Submit an agent's current score to a leaderboard (permissionless)
+#[derive(Accounts)]
+pub struct UpdateLeaderboard<'info> {
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, leaderboard.category.as_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    pub agent: Account<'info, Agent>,
+
+    #[account(
+        seeds = [b"reputation_metrics", agent.key().as_ref()],
+        bump = reputation_metrics.bump,
+        constraint = reputation_metrics.agent == agent.key() @ GhostSpeakError::InvalidAgent
+    )]
+    pub reputation_metrics: Account<'info, ReputationMetrics>,
+}
+
+pub fn initialize_leaderboard(
+    ctx: Context<InitializeLeaderboard>,
+    category: String,
+    capacity: u8,
+) -> Result<()> {
+    require!(
+        !category.is_empty() && category.len() <= Leaderboard::MAX_CATEGORY_LEN,
+        GhostSpeakError::InvalidInput
+    );
+    require!(
+        capacity > 0 && capacity <= Leaderboard::MAX_CAPACITY,
+        GhostSpeakError::InvalidInput
+    );
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.category = category;
+    leaderboard.capacity = capacity;
+    leaderboard.entries = Vec::new();
+    leaderboard.updated_at = Clock::get()?.unix_timestamp;
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    msg!(
+        "Leaderboard created: {} (top {})",
+        leaderboard.category,
+        capacity
+    );
+
+    Ok(())
+}
+
+/// Re-rank the agent if it is on the board, or insert it if its score beats
+/// the floor. Agents that are inactive or no longer carry the category's
+/// skill tag are dropped.
+pub fn update_leaderboard(ctx: Context<UpdateLeaderboard>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let agent = &ctx.accounts.agent;
+    let metrics = &ctx.accounts.reputation_metrics;
+    let now = Clock::get()?.unix_timestamp;
+
+    let eligible = agent.is_active
+        && (leaderboard.category == GLOBAL_LEADERBOARD
+            || metrics.skill_tags.contains(&leaderboard.category));
+    let score = metrics.ghost_score();
+
+    let rank = if eligible {
+        leaderboard.submit(agent.key(), score, now)
+    } else {
+        leaderboard.remove(&agent.key(), now);
+        None
+    };
+
+    emit!(LeaderboardUpdatedEvent {
+        category: leaderboard.category.clone(),
+        agent: agent.key(),
+        score,
+        rank: rank.map(|r| r as u8),
+        floor: leaderboard.floor(),
+    });
+
+    Ok(())
+}
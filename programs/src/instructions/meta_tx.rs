@@ -0,0 +1,229 @@
+/*!
+ * Meta-Transaction Instructions
+ *
+ * Prepaid relayer balances and relayed execution of owner-signed agent
+ * actions.
+ */
+
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program;
+
+use crate::security::{MetaNonce, META_NONCE_SEED};
+use crate::state::meta_tx::*;
+use crate::utils::{require_ed25519_signature, validate_url};
+use crate::{GhostSpeakError, *};
+
+/// Create the prepaid balance account for an agent (owner only)
+#[derive(Accounts)]
+pub struct InitializeMetaTxAccount<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = MetaTxAccount::LEN,
+        seeds = [META_TX_ACCOUNT_SEED, agent.key().as_ref()],
+        bump
+    )]
+    pub meta_tx_account: Account<'info, MetaTxAccount>,
+
+    #[account(constraint = agent.owner == Some(owner.key()) @ GhostSpeakError::InvalidAgentOwner)]
+    pub agent: Account<'info, Agent>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Top up an agent's prepaid balance (anyone may fund it)
+#[derive(Accounts)]
+pub struct DepositMetaTxBalance<'info> {
+    #[account(
+        mut,
+        seeds = [META_TX_ACCOUNT_SEED, meta_tx_account.agent.as_ref()],
+        bump = meta_tx_account.bump
+    )]
+    pub meta_tx_account: Account<'info, MetaTxAccount>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw unused prepaid balance (owner only)
+#[derive(Accounts)]
+pub struct WithdrawMetaTxBalance<'info> {
+    #[account(
+        mut,
+        seeds = [META_TX_ACCOUNT_SEED, agent.key().as_ref()],
+        bump = meta_tx_account.bump
+    )]
+    pub meta_tx_account: Account<'info, MetaTxAccount>,
+
+    #[account(constraint = agent.owner == Some(owner.key()) @ GhostSpeakError::InvalidAgentOwner)]
+    pub agent: Account<'info, Agent>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Relay an owner-signed agent action
+#[derive(Accounts)]
+pub struct ExecuteMetaTx<'info> {
+    #[account(
+        mut,
+        seeds = [META_TX_ACCOUNT_SEED, agent.key().as_ref()],
+        bump = meta_tx_account.bump
+    )]
+    pub meta_tx_account: Account<'info, MetaTxAccount>,
+
+    #[account(
+        mut,
+        constraint = agent.owner == Some(owner.key()) @ GhostSpeakError::InvalidAgentOwner
+    )]
+    pub agent: Account<'info, Agent>,
+
+    /// Owner's replay-protection nonce
+    #[account(
+        mut,
+        seeds = [META_NONCE_SEED, owner.key().as_ref()],
+        bump = meta_nonce.bump
+    )]
+    pub meta_nonce: Account<'info, MetaNonce>,
+
+    /// CHECK: Agent owner; signs off-chain, verified through the Ed25519 instruction
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, used to find the owner's Ed25519 instruction
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Relayer paying the transaction fee, reimbursed from the prepaid balance
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+}
+
+pub fn initialize_meta_tx_account(ctx: Context<InitializeMetaTxAccount>) -> Result<()> {
+    let account = &mut ctx.accounts.meta_tx_account;
+    account.agent = ctx.accounts.agent.key();
+    account.balance = 0;
+    account.executed = 0;
+    account.bump = ctx.bumps.meta_tx_account;
+
+    msg!(
+        "Meta-transaction account created for agent {}",
+        account.agent
+    );
+    Ok(())
+}
+
+pub fn deposit_meta_tx_balance(ctx: Context<DepositMetaTxBalance>, amount: u64) -> Result<()> {
+    require!(amount > 0, GhostSpeakError::InvalidAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.meta_tx_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let account = &mut ctx.accounts.meta_tx_account;
+    account.balance = account
+        .balance
+        .checked_add(amount)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    emit!(MetaTxBalanceChangedEvent {
+        agent: account.agent,
+        delta: amount as i64,
+        balance: account.balance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn withdraw_meta_tx_balance(ctx: Context<WithdrawMetaTxBalance>, amount: u64) -> Result<()> {
+    require!(amount > 0, GhostSpeakError::InvalidAmount);
+    require!(
+        amount <= ctx.accounts.meta_tx_account.balance,
+        GhostSpeakError::InsufficientMetaTxBalance
+    );
+
+    ctx.accounts.meta_tx_account.sub_lamports(amount)?;
+    ctx.accounts.owner.add_lamports(amount)?;
+
+    let account = &mut ctx.accounts.meta_tx_account;
+    account.balance -= amount;
+
+    emit!(MetaTxBalanceChangedEvent {
+        agent: account.agent,
+        delta: -(amount as i64),
+        balance: account.balance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Verify the owner's signature over `payload`, consume its nonce, pay the
+/// relayer from the prepaid balance and apply the action to the agent
+pub fn execute_meta_tx(ctx: Context<ExecuteMetaTx>, payload: MetaTxPayload) -> Result<()> {
+    let agent_key = ctx.accounts.agent.key();
+    let owner = ctx.accounts.owner.key();
+
+    let message = MetaTxAccount::signing_message(&agent_key, &payload)?;
+    require_ed25519_signature(&ctx.accounts.instructions_sysvar, &owner, &message)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .meta_nonce
+        .consume(payload.nonce, payload.expires_at, now)?;
+    ctx.accounts.meta_tx_account.charge(payload.fee)?;
+
+    if payload.fee > 0 {
+        ctx.accounts.meta_tx_account.sub_lamports(payload.fee)?;
+        ctx.accounts.relayer.add_lamports(payload.fee)?;
+    }
+
+    let agent = &mut ctx.accounts.agent;
+    match &payload.action {
+        MetaAction::SetActive { is_active } => {
+            require!(
+                agent.is_active != *is_active,
+                GhostSpeakError::InvalidStatusTransition
+            );
+            agent.is_active = *is_active;
+        }
+        MetaAction::UpdateMetadataUri { metadata_uri } => {
+            validate_url(metadata_uri)?;
+            agent.metadata_uri = metadata_uri.clone();
+        }
+        MetaAction::UpdateServiceEndpoint { service_endpoint } => {
+            validate_url(service_endpoint)?;
+            agent.service_endpoint = service_endpoint.clone();
+        }
+        MetaAction::SetX402Price { price_per_call } => {
+            agent.x402_price_per_call = *price_per_call;
+        }
+    }
+    agent.updated_at = now;
+
+    let account = &ctx.accounts.meta_tx_account;
+    emit!(MetaTxExecutedEvent {
+        agent: agent_key,
+        owner,
+        relayer: ctx.accounts.relayer.key(),
+        action: payload.action,
+        nonce: payload.nonce,
+        fee: payload.fee,
+        balance: account.balance,
+        timestamp: now,
+    });
+
+    Ok(())
+}
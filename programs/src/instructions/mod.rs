@@ -5,36 +5,96 @@
  */
 
 // Core agent modules
+pub mod access_control; // Staking tier guards, tier status and API quota metering
+pub mod admin_authority; // Governed protocol admin
+pub mod authority_transfer; // Two-step config and credential-type authority handover
 pub mod agent;
 pub mod agent_authorization; // Trustless pre-authorization for reputation updates
 pub mod agent_compressed;
+pub mod agent_handle; // Human-readable handles mapped to DIDs
 pub mod agent_management;
+pub mod auto_execution; // Condition-driven proposal and queue execution
+pub mod buyback; // Protocol GHOST buybacks
 
 // Governance and compliance modules
 pub mod compliance_governance;
 pub mod credential;
+pub mod credential_compressed; // Merkle-tree credentials for mass issuance
+pub mod credential_dispute; // Bonded fraud flags reviewed by the dispute multisig
+pub mod credential_token; // Soulbound Token-2022 credential mirrors
+pub mod denylist; // Governed sanctions denylist
 pub mod did; // W3C-compliant decentralized identifiers (did:sol)
+pub mod did_compressed; // Merkle-tree DID documents for agent fleets
+pub mod emergency_freeze; // Emergency quorum freezes and protocol pause
+pub mod execution_queue; // Batched execution of passed proposals
+pub mod fee_exemption; // Governed fee exemption allowlist
+pub mod fee_token; // Oracle-priced fee payment tokens
+pub mod fee_vault; // Protocol fee vaults and treasury withdrawals
 pub mod ghost; // Ghost identity management (NEW FOR GHOST)
 pub mod ghost_protect; // B2C escrow with dispute resolution
+pub mod insurance_pool; // Slash-funded fraud insurance
+pub mod leaderboard; // Top-N agent rankings per category
+pub mod meta_tx; // Relayed owner-signed agent actions
+pub mod onboarding; // One-transaction agent setup
+pub mod parameter_registry; // Governed runtime parameters
+pub mod proposal_deposit; // Anti-spam deposits on governance proposals
 pub mod protocol_config;
+pub mod referral; // Referrer enrollment and fee sharing
 pub mod reputation; // Multi-source reputation aggregation
+pub mod revenue_distribution; // USDC revenue share for stakers
+pub mod security_council; // Council veto over passed proposals
 pub mod security_init;
+pub mod session_key; // Scoped ephemeral keys for agent automation
+pub mod signed_authorization; // Ed25519-verified off-chain consents
+pub mod stake_receipt; // Token-2022 liquid staking receipts
 pub mod staking; // GHOST token staking for reputation boost
+pub mod sybil; // Funder-fingerprint sybil heuristics
+pub mod tag_registry; // Governed reputation tag taxonomy
 
 // Re-export all instruction handlers (2025 Anchor best practice)
+pub use access_control::*;
+pub use admin_authority::*;
+pub use authority_transfer::*;
 pub use agent::*;
 pub use agent_authorization::*;
 pub use agent_compressed::*;
+pub use agent_handle::*;
 pub use agent_management::*;
+pub use auto_execution::*;
+pub use buyback::*;
 pub use compliance_governance::*;
 pub use credential::*;
+pub use credential_compressed::*;
+pub use credential_dispute::*;
+pub use credential_token::*;
+pub use denylist::*;
 pub use did::*;
+pub use did_compressed::*;
+pub use emergency_freeze::*;
+pub use execution_queue::*;
+pub use fee_exemption::*;
+pub use fee_token::*;
+pub use fee_vault::*;
 pub use ghost::*; // Ghost identity instructions (NEW FOR GHOST)
 pub use ghost_protect::*;
+pub use insurance_pool::*;
+pub use leaderboard::*;
+pub use meta_tx::*;
+pub use onboarding::*;
+pub use parameter_registry::*;
+pub use proposal_deposit::*;
+pub use referral::*;
 pub use protocol_config::*;
 pub use reputation::*;
+pub use revenue_distribution::*;
+pub use security_council::*;
 pub use security_init::*;
+pub use session_key::*;
+pub use signed_authorization::*;
+pub use stake_receipt::*;
 pub use staking::*;
+pub use sybil::*;
+pub use tag_registry::*;
 
 use anchor_lang::prelude::*;
 
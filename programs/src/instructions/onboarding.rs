@@ -0,0 +1,333 @@
+/*!
+ * Onboarding Instructions
+ *
+ * Composite instruction that performs the full agent setup in one
+ * transaction: stake GHOST, register the agent, initialize its reputation
+ * metrics and create the owner's DID document. An owner onboarding a
+ * further agent keeps the DID document created with the first one.
+ *
+ * Each step reuses the same initialization helpers as the standalone
+ * instructions, so accounts created here are indistinguishable from ones
+ * created step by step, and all PDAs use the canonical seeds.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::instructions::agent::{
+    charge_registration_fee, initialize_agent_account, validate_agent_registration_inputs,
+};
+use crate::instructions::fee_token::FeeTokenPayment;
+use crate::instructions::did::initialize_did_account;
+use crate::instructions::reputation::{
+    initialize_metrics_account, ReputationMetricsInitializedEvent,
+};
+use crate::instructions::staking::record_stake;
+use crate::state::did::*;
+use crate::state::fee_exemption::FEE_EXEMPTION_LIST_SEED;
+use crate::state::fee_token::{FeePaymentToken, FEE_PAYMENT_TOKEN_SEED};
+use crate::state::fee_vault::{FeeVault, FEE_VAULT_SEED};
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::staking::{StakingAccount, StakingConfig};
+use crate::{Agent, GhostSpeakError, PricingModel, ReputationMetrics};
+
+/// Register a fully set-up agent with a single signature
+///
+/// **Sybil Resistance:** The owner's stake (existing plus `stake_amount`) must
+/// reach the tier required by `register_agent`.
+#[derive(Accounts)]
+#[instruction(
+    agent_type: u8,
+    name: String,
+    description: String,
+    metadata_uri: String,
+    agent_id: String,
+    pricing_model: PricingModel,
+    stake_amount: u64,
+    lock_duration: i64,
+    did_string: String
+)]
+pub struct OnboardAgent<'info> {
+    /// Agent account (same PDA as register_agent)
+    #[account(
+        init,
+        payer = owner,
+        space = Agent::LEN,
+        seeds = [
+            b"agent",
+            owner.key().as_ref(),
+            agent_id.as_bytes()
+        ],
+        bump
+    )]
+    pub agent_account: Box<Account<'info, Agent>>,
+
+    /// Reputation metrics for the new agent
+    #[account(
+        init,
+        payer = owner,
+        space = ReputationMetrics::LEN,
+        seeds = [
+            b"reputation_metrics",
+            agent_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub reputation_metrics: Box<Account<'info, ReputationMetrics>>,
+
+    /// DID document controlled by the owner (created on first onboarding)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = DidDocument::LEN,
+        seeds = [
+            DID_DOCUMENT_SEED,
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub did_document: Box<Account<'info, DidDocument>>,
+
+    /// Owner staking account (created on first stake)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = StakingAccount::LEN,
+        seeds = [
+            b"staking",
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub staking_account: Box<Account<'info, StakingAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// Staking vault holding all staked GHOST tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = ghost_mint,
+        token::authority = staking_config,
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key(),
+        constraint = owner_token_account.mint == ghost_mint.key()
+    )]
+    pub owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: GHOST token mint address
+    pub ghost_mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    /// Protocol config (registration fee)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// CHECK: Fee exemption list; uninitialized if never created
+    #[account(seeds = [FEE_EXEMPTION_LIST_SEED], bump)]
+    pub fee_exemption_list: UncheckedAccount<'info>,
+
+    /// Fee vault of the token the registration fee is paid in (the SOL
+    /// vault unless `fee_payment_token` is passed); required when a fee is due
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, fee_vault.mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    /// Accepted token to pay the registration fee in instead of SOL; needs
+    /// every account below
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, fee_payment_token.mint.as_ref()],
+        bump = fee_payment_token.bump
+    )]
+    pub fee_payment_token: Option<Box<Account<'info, FeePaymentToken>>>,
+
+    /// SOL entry carrying the SOL/USD feed
+    #[account(
+        seeds = [FEE_PAYMENT_TOKEN_SEED, anchor_lang::system_program::ID.as_ref()],
+        bump = sol_payment_token.bump
+    )]
+    pub sol_payment_token: Option<Box<Account<'info, FeePaymentToken>>>,
+
+    pub fee_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: Pyth SOL/USD price update, validated when read
+    pub sol_price_update: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Pyth token/USD price update, validated when read
+    pub token_price_update: Option<UncheckedAccount<'info>>,
+
+    /// Owner's account for the fee token
+    #[account(mut)]
+    pub payer_fee_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Fee vault's token account
+    #[account(mut)]
+    pub fee_vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+}
+
+/// Stake, register the agent, initialize reputation metrics and create the DID
+///
+/// Pass `stake_amount = 0` to reuse an existing stake. If the owner already
+/// has a DID document it is left as is, and the DID arguments are unused.
+/// Any failing step reverts the whole transaction.
+pub fn onboard_agent(
+    ctx: Context<OnboardAgent>,
+    agent_type: u8,
+    name: String,
+    description: String,
+    metadata_uri: String,
+    agent_id: String,
+    pricing_model: PricingModel,
+    stake_amount: u64,
+    lock_duration: i64,
+    did_string: String,
+    verification_methods: Vec<VerificationMethod>,
+    service_endpoints: Vec<ServiceEndpoint>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
+
+    validate_agent_registration_inputs(agent_type, &name, &description, &metadata_uri, &agent_id)?;
+
+    // Step 1: stake (optional when an existing stake is sufficient)
+    if stake_amount > 0 {
+        let config = &ctx.accounts.staking_config;
+        require!(
+            stake_amount >= config.min_stake,
+            GhostSpeakError::ValueBelowMinimum
+        );
+        require!(
+            lock_duration >= config.min_lock_duration,
+            GhostSpeakError::InvalidInput
+        );
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.owner_token_account.to_account_info(),
+            to: ctx.accounts.staking_vault.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, stake_amount)?;
+
+        record_stake(
+            &mut ctx.accounts.staking_config,
+            &mut ctx.accounts.staking_account,
+            owner,
+            stake_amount,
+            lock_duration,
+            ctx.bumps.staking_account,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // Same Sybil gate as register_agent
+    let staking = &ctx.accounts.staking_account;
+    require!(staking.owner == owner, GhostSpeakError::InvalidAgentOwner);
+    require!(staking.has_api_access(), GhostSpeakError::InsufficientStake);
+
+    // Step 2: register the agent, paying the same fee as register_agent
+    let agent_key = ctx.accounts.agent_account.key();
+    let tier = staking.current_tier(&ctx.accounts.staking_config.tier_schedule);
+    let token_payment = FeeTokenPayment::from_accounts(
+        ctx.accounts.fee_payment_token.as_deref(),
+        ctx.accounts.sol_payment_token.as_deref(),
+        ctx.accounts.fee_mint.as_deref(),
+        ctx.accounts.sol_price_update.as_deref(),
+        ctx.accounts.token_price_update.as_deref(),
+        ctx.accounts.payer_fee_token_account.as_deref(),
+        ctx.accounts.fee_vault_token_account.as_deref(),
+        Some(&ctx.accounts.token_program),
+    )?;
+    charge_registration_fee(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.fee_exemption_list,
+        tier,
+        agent_key,
+        &ctx.accounts.owner,
+        ctx.accounts.fee_vault.as_deref_mut(),
+        token_payment,
+        &ctx.accounts.system_program,
+        clock.unix_timestamp,
+    )?;
+    initialize_agent_account(
+        &mut ctx.accounts.agent_account,
+        owner,
+        agent_type,
+        name.clone(),
+        description,
+        metadata_uri,
+        agent_id,
+        pricing_model,
+        ctx.bumps.agent_account,
+        clock.unix_timestamp,
+    );
+
+    emit!(crate::AgentRegisteredEvent {
+        agent: agent_key,
+        owner,
+        name,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Step 3: reputation metrics
+    initialize_metrics_account(
+        &mut ctx.accounts.reputation_metrics,
+        agent_key,
+        ctx.bumps.reputation_metrics,
+        clock.unix_timestamp,
+    );
+
+    emit!(ReputationMetricsInitializedEvent {
+        agent: agent_key,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Step 4: DID document, unless an earlier onboarding created it
+    let did_document = &mut ctx.accounts.did_document;
+    if did_document.controller == Pubkey::default() {
+        initialize_did_account(
+            did_document,
+            did_string,
+            owner,
+            verification_methods,
+            service_endpoints,
+            ctx.bumps.did_document,
+            clock.unix_timestamp,
+        )?;
+    } else {
+        require_keys_eq!(
+            did_document.controller,
+            owner,
+            DidError::UnauthorizedDidOperation
+        );
+    }
+
+    msg!(
+        "Agent onboarded - Owner: {}, Agent: {}, DID: {}",
+        owner,
+        agent_key,
+        ctx.accounts.did_document.key()
+    );
+
+    Ok(())
+}
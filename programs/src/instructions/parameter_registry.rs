@@ -0,0 +1,124 @@
+/*!
+ * Parameter Registry Instructions
+ *
+ * Creates the governed parameter registry and applies parameter changes
+ * carried by executed ParameterUpdate proposals. A proposal changes a
+ * parameter by including the `apply_parameter_change` instruction itself
+ * among its execution instructions; once the proposal is executed anyone
+ * may submit it.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::governance::{GovernanceProposal, ProposalStatus, ProposalType};
+use crate::state::parameter_registry::*;
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE PARAMETER REGISTRY
+// =====================================================
+
+/// Create the registry with every parameter at its default (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeParameterRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ParameterRegistry::LEN,
+        seeds = [PARAMETER_REGISTRY_SEED],
+        bump
+    )]
+    pub parameter_registry: Box<Account<'info, ParameterRegistry>>,
+
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_parameter_registry(ctx: Context<InitializeParameterRegistry>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.parameter_registry;
+    registry.initialize(ctx.bumps.parameter_registry, now);
+
+    msg!(
+        "Parameter registry created with {} parameters",
+        registry.entries.len()
+    );
+    Ok(())
+}
+
+// =====================================================
+// APPLY PARAMETER CHANGE
+// =====================================================
+
+/// Apply a parameter change from an executed proposal (permissionless)
+#[derive(Accounts)]
+pub struct ApplyParameterChange<'info> {
+    #[account(
+        mut,
+        seeds = [PARAMETER_REGISTRY_SEED],
+        bump = parameter_registry.bump
+    )]
+    pub parameter_registry: Box<Account<'info, ParameterRegistry>>,
+
+    #[account(
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = proposal.proposal_type == ProposalType::ParameterUpdate @ GhostSpeakError::ParameterChangeNotProposed,
+        constraint = proposal.status == ProposalStatus::Executed @ GhostSpeakError::ProposalNotPassed
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+}
+
+/// Sets `key` to `value`, which the proposal must carry as an
+/// `apply_parameter_change` instruction with the same arguments.
+pub fn apply_parameter_change(
+    ctx: Context<ApplyParameterChange>,
+    key: ParameterKey,
+    value: u64,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let expected = crate::instruction::ApplyParameterChange { key, value }.data();
+    require!(
+        proposal
+            .execution_params
+            .instructions
+            .iter()
+            .any(|ix| ix.program_id == crate::ID && ix.data == expected),
+        GhostSpeakError::ParameterChangeNotProposed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.parameter_registry;
+    let previous_value =
+        registry.apply(key, value, proposal.key(), proposal.voting_ends_at, now)?;
+
+    emit!(ParameterChangedEvent {
+        key,
+        kind: key.spec().kind,
+        previous_value,
+        value,
+        proposal: proposal.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Parameter {:?} changed from {} to {} by proposal {}",
+        key,
+        previous_value,
+        value,
+        proposal.proposal_id
+    );
+    Ok(())
+}
@@ -0,0 +1,238 @@
+/*!
+ * Proposal Deposit Instructions
+ *
+ * Proposers escrow a lamport deposit on each governance proposal to deter
+ * spam. Once the proposal is decided, anyone can release the deposit:
+ * proposals that reached quorum refund it to the proposer, while cancelled
+ * proposals and those that never reached quorum forfeit it to the treasury.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::governance::{
+    GovernanceProposal, Multisig, ProposalDepositConfig, ProposalStatus,
+    PROPOSAL_DEPOSIT_CONFIG_SEED,
+};
+use crate::GhostSpeakError;
+
+// =====================================================
+// DEPOSIT CONFIG
+// =====================================================
+
+/// Create the proposal deposit config governed by `multisig` (protocol admin only)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct InitializeProposalDepositConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProposalDepositConfig::space(),
+        seeds = [PROPOSAL_DEPOSIT_CONFIG_SEED],
+        bump
+    )]
+    pub deposit_config: Account<'info, ProposalDepositConfig>,
+
+    /// Multisig that will set the deposit
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_proposal_deposit_config(
+    ctx: Context<InitializeProposalDepositConfig>,
+    minimum_deposit: u64,
+    treasury: Pubkey,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let config = &mut ctx.accounts.deposit_config;
+    config.multisig = ctx.accounts.multisig.key();
+    config.minimum_deposit = minimum_deposit;
+    config.treasury = treasury;
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.deposit_config;
+
+    emit!(ProposalDepositConfigUpdatedEvent {
+        multisig: config.multisig,
+        minimum_deposit,
+        treasury,
+        timestamp: config.updated_at,
+    });
+
+    msg!(
+        "Proposal deposit config initialized (multisig: {}, deposit: {})",
+        config.multisig,
+        minimum_deposit
+    );
+    Ok(())
+}
+
+/// Change the proposal deposit or treasury (deposit multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold. Deposits already escrowed keep their amount.
+#[derive(Accounts)]
+pub struct UpdateProposalDepositConfig<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_DEPOSIT_CONFIG_SEED],
+        bump = deposit_config.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub deposit_config: Account<'info, ProposalDepositConfig>,
+
+    pub multisig: Account<'info, Multisig>,
+}
+
+pub fn update_proposal_deposit_config(
+    ctx: Context<UpdateProposalDepositConfig>,
+    minimum_deposit: u64,
+    treasury: Pubkey,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let config = &mut ctx.accounts.deposit_config;
+    config.minimum_deposit = minimum_deposit;
+    config.treasury = treasury;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProposalDepositConfigUpdatedEvent {
+        multisig: config.multisig,
+        minimum_deposit,
+        treasury,
+        timestamp: config.updated_at,
+    });
+
+    msg!("Proposal deposit set to {}", minimum_deposit);
+    Ok(())
+}
+
+// =====================================================
+// CANCEL PROPOSAL
+// =====================================================
+
+/// Withdraw an active proposal, forfeiting its deposit (proposer only)
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = proposer @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    pub proposer: Signer<'info>,
+}
+
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.cancel()?;
+
+    msg!(
+        "Proposal {} cancelled by its proposer",
+        proposal.proposal_id
+    );
+    Ok(())
+}
+
+// =====================================================
+// RELEASE DEPOSIT
+// =====================================================
+
+/// Refund or forfeit a decided proposal's deposit (permissionless crank)
+#[derive(Accounts)]
+pub struct ReleaseProposalDeposit<'info> {
+    #[account(
+        seeds = [PROPOSAL_DEPOSIT_CONFIG_SEED],
+        bump = deposit_config.bump,
+        has_one = treasury @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub deposit_config: Account<'info, ProposalDepositConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = proposer @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    /// CHECK: Proposer recorded on the proposal, refunded when quorum was reached
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury recorded on the deposit config, receives forfeited deposits
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn release_proposal_deposit(ctx: Context<ReleaseProposalDeposit>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let (amount, refunded) = proposal.release_deposit()?;
+
+    let recipient = if refunded {
+        ctx.accounts.proposer.to_account_info()
+    } else {
+        ctx.accounts.treasury.to_account_info()
+    };
+    proposal.sub_lamports(amount)?;
+    recipient.add_lamports(amount)?;
+
+    emit!(ProposalDepositReleasedEvent {
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        status: proposal.status,
+        recipient: recipient.key(),
+        amount,
+        refunded,
+    });
+
+    msg!(
+        "Proposal {} deposit of {} lamports {}",
+        proposal.proposal_id,
+        amount,
+        if refunded { "refunded" } else { "forfeited" }
+    );
+    Ok(())
+}
+
+// =====================================================
+// EVENTS
+// =====================================================
+
+#[event]
+pub struct ProposalDepositConfigUpdatedEvent {
+    pub multisig: Pubkey,
+    pub minimum_deposit: u64,
+    pub treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalDepositReleasedEvent {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub status: ProposalStatus,
+    /// Proposer if refunded, treasury if forfeited
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+}
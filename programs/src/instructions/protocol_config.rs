@@ -8,7 +8,16 @@
  * Fees will be enabled via governance after mainnet deployment.
  */
 
-use crate::state::protocol_config::{ProtocolConfig, ProtocolConfigUpdatedEvent};
+use crate::state::config_history::{
+    append_config_history, ConfigHistoryEntry, ConfigHistoryHead, ConfigKind,
+    CONFIG_HISTORY_ENTRY_SEED, CONFIG_HISTORY_HEAD_SEED,
+};
+use crate::state::protocol_config::{
+    CredentialFeeShareUpdatedEvent, CrossChainAttestorUpdatedEvent, EscrowFeeCurve, EscrowFeeCurveUpdatedEvent, FeeDiscountsUpdatedEvent, GatedAction, ProtocolConfig, ProtocolConfigUpdatedEvent,
+    ReferralShareUpdatedEvent, ReputationDecayConfigUpdatedEvent, TierGatesUpdatedEvent, VolumeFeeTier, ESCROW_FEE_CURVE_SEED,
+};
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::staking::AccessTier;
 use crate::GhostSpeakError;
 use anchor_lang::prelude::*;
 
@@ -31,6 +40,14 @@ pub struct InitializeProtocolConfig<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
     /// Authority who will control the config (typically DAO or multisig)
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -51,6 +68,8 @@ pub struct InitializeProtocolConfig<'info> {
 }
 
 /// Update protocol configuration (authority only)
+///
+/// Every update appends an entry to the config history chain.
 #[derive(Accounts)]
 pub struct UpdateProtocolConfig<'info> {
     #[account(
@@ -61,7 +80,33 @@ pub struct UpdateProtocolConfig<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Enable production fees (authority only)
@@ -78,7 +123,82 @@ pub struct EnableProtocolFees<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the escrow fee curve (authority only)
+#[derive(Accounts)]
+pub struct SetEscrowFeeCurve<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// Fee curve (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EscrowFeeCurve::LEN,
+        seeds = [ESCROW_FEE_CURVE_SEED],
+        bump
+    )]
+    pub escrow_fee_curve: Account<'info, EscrowFeeCurve>,
+
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 // =====================================================
@@ -104,6 +224,7 @@ pub struct EnableProtocolFees<'info> {
 /// | Agent Registration | 0.01 SOL | Treasury |
 /// | Marketplace Listing | 0.001 SOL | Treasury |
 /// | Dispute Resolution | 1% | Moderator Pool |
+/// | Credential Issuance | 10% of template fee | Treasury |
 pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
     msg!("Initializing protocol configuration (fees disabled for devnet)");
 
@@ -140,8 +261,21 @@ pub fn enable_protocol_fees(ctx: Context<EnableProtocolFees>) -> Result<()> {
     msg!("Enabling production fees for mainnet");
 
     let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
     config.enable_production_fees()?;
 
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ProtocolFees,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
     emit!(ProtocolConfigUpdatedEvent {
         authority: ctx.accounts.authority.key(),
         fees_enabled: true,
@@ -188,6 +322,7 @@ pub fn update_protocol_config(
     moderator_pool: Option<Pubkey>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
 
     if let Some(fee) = escrow_fee_bps {
         require!(fee <= 1000, GhostSpeakError::InvalidConfiguration); // Max 10%
@@ -225,6 +360,18 @@ pub fn update_protocol_config(
 
     config.updated_at = Clock::get()?.unix_timestamp;
 
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ProtocolFees,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
     emit!(ProtocolConfigUpdatedEvent {
         authority: ctx.accounts.authority.key(),
         fees_enabled: config.fees_enabled,
@@ -238,3 +385,341 @@ pub fn update_protocol_config(
     msg!("Protocol config updated");
     Ok(())
 }
+
+/// Updates inactivity decay parameters for aggregate reputation
+///
+/// Agents with no recorded payments or ratings for longer than the grace
+/// period lose `bps_per_day` of their reputation score per inactive day,
+/// never dropping below `floor`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing config account
+/// * `enabled` - Toggle decay on or off
+/// * `grace_days` - Inactive days before decay starts
+/// * `bps_per_day` - Daily decay in basis points (max 1000 = 10%)
+/// * `floor` - Minimum score (0-100) decay can reach
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn update_reputation_decay_config(
+    ctx: Context<UpdateProtocolConfig>,
+    enabled: Option<bool>,
+    grace_days: Option<u16>,
+    bps_per_day: Option<u16>,
+    floor: Option<u32>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
+
+    if let Some(enabled) = enabled {
+        config.reputation_decay_enabled = enabled;
+    }
+
+    if let Some(days) = grace_days {
+        require!(days > 0, GhostSpeakError::InvalidConfiguration);
+        config.reputation_decay_grace_days = days;
+    }
+
+    if let Some(bps) = bps_per_day {
+        require!(
+            bps <= ProtocolConfig::MAX_DECAY_BPS_PER_DAY,
+            GhostSpeakError::InvalidConfiguration
+        );
+        config.reputation_decay_bps_per_day = bps;
+    }
+
+    if let Some(floor) = floor {
+        require!(floor <= 100, GhostSpeakError::InvalidConfiguration);
+        config.reputation_decay_floor = floor;
+    }
+
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ReputationParams,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(ReputationDecayConfigUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        enabled: config.reputation_decay_enabled,
+        grace_days: config.reputation_decay_grace_days,
+        bps_per_day: config.reputation_decay_bps_per_day,
+        floor: config.reputation_decay_floor,
+        timestamp: config.updated_at,
+    });
+
+    msg!(
+        "Reputation decay config updated (enabled: {}, grace: {}d, rate: {}bps/day, floor: {})",
+        config.reputation_decay_enabled,
+        config.reputation_decay_grace_days,
+        config.reputation_decay_bps_per_day,
+        config.reputation_decay_floor
+    );
+    Ok(())
+}
+
+/// Sets the protocol share of credential template issuance fees
+///
+/// The rest of each fee goes to the template creator. Like other fees, the
+/// share only applies while `fees_enabled` is set.
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn update_credential_fee_share(
+    ctx: Context<UpdateProtocolConfig>,
+    credential_fee_bps: u16,
+) -> Result<()> {
+    require!(
+        credential_fee_bps <= ProtocolConfig::MAX_CREDENTIAL_FEE_BPS,
+        GhostSpeakError::InvalidConfiguration
+    );
+
+    let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
+
+    config.credential_fee_bps = credential_fee_bps;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ProtocolFees,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(CredentialFeeShareUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        credential_fee_bps,
+        timestamp: config.updated_at,
+    });
+
+    msg!("Credential fee share set to {}bps", credential_fee_bps);
+    Ok(())
+}
+
+/// Sets the oracle key that attests Crossmint credential mirroring
+///
+/// The attestor verifies Crossmint webhooks off-chain and signs each status
+/// change; `update_crosschain_status` only accepts changes carrying its
+/// Ed25519 signature. Setting the default key disables status updates.
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn update_crosschain_attestor(
+    ctx: Context<UpdateProtocolConfig>,
+    attestor: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
+
+    config.crosschain_attestor = attestor;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::CrossChainAttestor,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(CrossChainAttestorUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        attestor,
+        timestamp: config.updated_at,
+    });
+
+    msg!("Cross-chain attestor set to {}", attestor);
+    Ok(())
+}
+
+/// Sets the fee discount for each payer staking tier
+///
+/// `fee_discount_bps` is indexed by `AccessTier` and applies to escrow,
+/// registration, listing and credential issuance fees. Higher tiers may not
+/// be discounted less than lower ones.
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn update_fee_discounts(
+    ctx: Context<UpdateProtocolConfig>,
+    fee_discount_bps: [u16; AccessTier::COUNT],
+) -> Result<()> {
+    ProtocolConfig::validate_fee_discounts(&fee_discount_bps)?;
+
+    let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
+
+    config.fee_discount_bps = fee_discount_bps;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ProtocolFees,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(FeeDiscountsUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        fee_discount_bps,
+        timestamp: config.updated_at,
+    });
+
+    msg!("Fee discounts set to {:?}bps", fee_discount_bps);
+    Ok(())
+}
+
+/// Sets the referrer share of the protocol's cut of referred fees
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn update_referral_share(
+    ctx: Context<UpdateProtocolConfig>,
+    referral_share_bps: u16,
+) -> Result<()> {
+    require!(
+        referral_share_bps <= ProtocolConfig::MAX_REFERRAL_SHARE_BPS,
+        GhostSpeakError::InvalidConfiguration
+    );
+
+    let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
+
+    config.referral_share_bps = referral_share_bps;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ProtocolFees,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(ReferralShareUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        referral_share_bps,
+        timestamp: config.updated_at,
+    });
+
+    msg!("Referral share set to {}bps", referral_share_bps);
+    Ok(())
+}
+
+/// Sets the escrow fee curve by trailing 30-day settled volume
+///
+/// Each step gives the escrow fee for agents whose settled volume reaches
+/// its `min_volume`; agents below the first step, and every agent while the
+/// curve is empty, pay the base `escrow_fee_bps`. Steps never raise the
+/// rate above the base.
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn set_escrow_fee_curve(
+    ctx: Context<SetEscrowFeeCurve>,
+    tiers: Vec<VolumeFeeTier>,
+) -> Result<()> {
+    EscrowFeeCurve::validate(&tiers)?;
+
+    let curve = &mut ctx.accounts.escrow_fee_curve;
+    let old_value = curve.try_to_vec()?;
+
+    curve.tiers = tiers.clone();
+    curve.updated_at = Clock::get()?.unix_timestamp;
+    curve.bump = ctx.bumps.escrow_fee_curve;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::ProtocolFees,
+        curve.key(),
+        &old_value,
+        &curve.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(EscrowFeeCurveUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        tiers,
+        timestamp: curve.updated_at,
+    });
+
+    msg!("Escrow fee curve set with {} steps", curve.tiers.len());
+    Ok(())
+}
+
+/// Sets the minimum staking tier for each gated action
+///
+/// `tier_gates` is indexed by `GatedAction`; `AccessTier::None` leaves an
+/// action ungated.
+///
+/// # Security
+///
+/// Only the config authority (DAO/multisig) can call this.
+pub fn update_tier_gates(
+    ctx: Context<UpdateProtocolConfig>,
+    tier_gates: [AccessTier; GatedAction::COUNT],
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_value = config.try_to_vec()?;
+
+    config.tier_gates = tier_gates;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::TierGates,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(TierGatesUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        tier_gates,
+        timestamp: config.updated_at,
+    });
+
+    msg!("Tier gates set to {:?}", tier_gates);
+    Ok(())
+}
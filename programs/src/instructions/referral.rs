@@ -0,0 +1,137 @@
+/*!
+ * Referral Instructions
+ *
+ * Enrolls referrers and routes their share of collected fees. Agent
+ * registration and escrow creation name a referrer by passing its
+ * `ReferrerStats`; escrow settlement pays the referrer's cut of the escrow
+ * fee (the escrow's referrer, else the agent's) with `route_referral_fee`
+ * before crediting the fee vault with the rest.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+use crate::state::fee_vault::FeeSource;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::referral::*;
+use crate::GhostSpeakError;
+
+// =====================================================
+// REGISTER REFERRER
+// =====================================================
+
+/// Create the caller's referrer accounting PDA
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS_SEED, referrer.key().as_ref()],
+        bump
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stats = &mut ctx.accounts.referrer_stats;
+    stats.referrer = ctx.accounts.referrer.key();
+    stats.referred_agents = 0;
+    stats.referred_escrows = 0;
+    stats.earned = [0; FeeSource::COUNT];
+    stats.payouts = 0;
+    stats.last_paid_at = 0;
+    stats.created_at = now;
+    stats.bump = ctx.bumps.referrer_stats;
+
+    emit!(ReferrerRegisteredEvent {
+        referrer: stats.referrer,
+        timestamp: now,
+    });
+
+    msg!("Referrer registered: {}", stats.referrer);
+    Ok(())
+}
+
+// =====================================================
+// REFERRAL HELPERS
+// =====================================================
+
+/// Attach `referrer` to a new agent or escrow (`account`) created by
+/// `referred`; the referrer must be enrolled and pass its stats
+pub(crate) fn record_referral(
+    stats: Option<&mut Account<ReferrerStats>>,
+    referrer: Pubkey,
+    referred: Pubkey,
+    account: Pubkey,
+    kind: ReferralKind,
+    now: i64,
+) -> Result<()> {
+    let stats = stats.ok_or(GhostSpeakError::ReferrerNotRegistered)?;
+    stats.record_referral(&referrer, &referred, kind)?;
+
+    emit!(ReferralRecordedEvent {
+        referrer,
+        referred,
+        account,
+        kind,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Pay the referrer's cut of the protocol fee `fee` from `source` out of
+/// `from` into the referrer's token account and record it. Returns the
+/// protocol's remaining share, to be paid into the fee vault.
+#[allow(clippy::too_many_arguments)]
+pub fn route_referral_fee<'info>(
+    config: &ProtocolConfig,
+    stats: &mut Account<'info, ReferrerStats>,
+    referrer_token_account: &Account<'info, TokenAccount>,
+    from: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    source: FeeSource,
+    fee: u64,
+) -> Result<u64> {
+    require!(
+        referrer_token_account.owner == stats.referrer,
+        GhostSpeakError::InvalidReferrer
+    );
+
+    let (protocol_share, referrer_share) = config.split_referral_fee(fee);
+    if referrer_share == 0 {
+        return Ok(protocol_share);
+    }
+
+    let cpi_accounts = Transfer {
+        from,
+        to: referrer_token_account.to_account_info(),
+        authority,
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds),
+        referrer_share,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    stats.record_payout(source, referrer_share, now)?;
+
+    emit!(ReferralFeePaidEvent {
+        referrer: stats.referrer,
+        token_account: referrer_token_account.key(),
+        source,
+        amount: referrer_share,
+        total_for_source: stats.earned[source.index()],
+        timestamp: now,
+    });
+
+    Ok(protocol_share)
+}
@@ -5,7 +5,26 @@
  * based on payment performance, response time, and service quality.
  */
 
-use crate::state::ReputationMetrics;
+use anchor_lang::system_program;
+use crate::security::{
+    RateLimiter, RatingCommitment, UserRateLimit, OPERATION_PAYAI_PAYMENT,
+    OPERATION_REPUTATION_UPDATE, OPERATION_SERVICE_RATING, RATING_COMMITMENT_SEED,
+};
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::denylist::DENYLIST_ENTRY_SEED;
+use crate::state::session_key::{SessionKey, SessionScope};
+use crate::state::ghost_protect::{EscrowStatus, GhostProtectEscrow};
+use crate::state::sybil::{
+    dampened_rating, FunderCluster, FunderFingerprint, RatingFlaggedEvent, SybilConfig,
+    FUNDER_FINGERPRINT_SEED, SYBIL_CONFIG_SEED,
+};
+use crate::state::tag_registry::{TagCategory, TagRef, TagRegistry, TAG_REGISTRY_SEED};
+use crate::state::governance::Multisig;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::{
+    ApprovedSource, ReputationMetrics, ReputationSourceRegistry, ReputationWindowSummary,
+    ReputationWindows, PAYAI_SOURCE,
+};
 use crate::{GhostSpeakError, *};
 
 /// Context for initializing reputation metrics
@@ -60,11 +79,54 @@ pub struct RecordX402PaymentReputation<'info> {
     #[account(mut)]
     pub agent: Account<'info, Agent>,
 
-    /// Authority (can be the payment verifier or agent owner)
+    /// Rolling windows (optional; blends recent performance into the headline score)
+    #[account(
+        mut,
+        seeds = [b"reputation_windows", agent.key().as_ref()],
+        bump = reputation_windows.bump
+    )]
+    pub reputation_windows: Option<Account<'info, ReputationWindows>>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Account<'info, RateLimiter>,
+
+    /// Per-payer rate limit tracking (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            authority.key().as_ref(),
+            OPERATION_PAYAI_PAYMENT.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    /// Authority: the agent owner, a session key holder, or the payment
+    /// verifier (the registered updater of the PayAI source)
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     /// Clock for timestamps
     pub clock: Sysvar<'info, Clock>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Session authorizing `authority` for the agent, when it records as one
+    /// (counts one action)
+    #[account(mut)]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// Source registry naming the payment verifier, when it records
+    #[account(
+        seeds = [b"reputation_source_registry"],
+        bump = source_registry.bump
+    )]
+    pub source_registry: Option<Account<'info, ReputationSourceRegistry>>,
 }
 
 /// Context for submitting a rating for an x402 service (legacy reputation module)
@@ -85,11 +147,255 @@ pub struct SubmitX402RatingReputation<'info> {
     #[account(mut)]
     pub agent: Account<'info, Agent>,
 
+    /// Rolling windows (optional; blends recent performance into the headline score)
+    #[account(
+        mut,
+        seeds = [b"reputation_windows", agent.key().as_ref()],
+        bump = reputation_windows.bump
+    )]
+    pub reputation_windows: Option<Account<'info, ReputationWindows>>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Account<'info, RateLimiter>,
+
+    /// Per-client rate limit tracking (created on first use)
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            client.key().as_ref(),
+            OPERATION_SERVICE_RATING.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    /// Sybil heuristic parameters
+    #[account(seeds = [SYBIL_CONFIG_SEED], bump = sybil_config.bump)]
+    pub sybil_config: Account<'info, SybilConfig>,
+
+    /// CHECK: Client's funder fingerprint PDA; uninitialized if never recorded
+    #[account(seeds = [FUNDER_FINGERPRINT_SEED, client.key().as_ref()], bump)]
+    pub rater_fingerprint: UncheckedAccount<'info>,
+
+    /// Cluster of the client's first funder (required once the client is fingerprinted)
+    #[account(mut)]
+    pub funder_cluster: Option<Account<'info, FunderCluster>>,
+
+    /// CHECK: Agent's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.key().as_ref()],
+        bump,
+        constraint = agent_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub agent_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.owner.unwrap_or_default().as_ref()],
+        bump,
+        constraint = owner_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub owner_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Client's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, client.key().as_ref()],
+        bump,
+        constraint = client_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub client_denylist_entry: UncheckedAccount<'info>,
+
     /// Client submitting the rating
+    #[account(mut)]
     pub client: Signer<'info>,
 
     /// Clock for timestamps
     pub clock: Sysvar<'info, Clock>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for committing a hidden rating on an active escrow
+#[derive(Accounts)]
+pub struct CommitServiceRating<'info> {
+    #[account(
+        seeds = [
+            b"ghost_protect",
+            escrow.client.as_ref(),
+            &escrow.escrow_id.to_le_bytes()
+        ],
+        bump = escrow.bump,
+        constraint = escrow.client == client.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = escrow.status == EscrowStatus::Active @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub escrow: Account<'info, GhostProtectEscrow>,
+
+    /// Hidden rating (one per escrow)
+    #[account(
+        init,
+        payer = client,
+        space = RatingCommitment::LEN,
+        seeds = [RATING_COMMITMENT_SEED, escrow.key().as_ref()],
+        bump
+    )]
+    pub rating_commitment: Account<'info, RatingCommitment>,
+
+    /// Escrow client
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for revealing a committed rating after the escrow completes
+#[derive(Accounts)]
+pub struct RevealServiceRating<'info> {
+    #[account(
+        seeds = [
+            b"ghost_protect",
+            escrow.client.as_ref(),
+            &escrow.escrow_id.to_le_bytes()
+        ],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Completed @ GhostSpeakError::InvalidState,
+        constraint = escrow.is_current(escrow.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub escrow: Account<'info, GhostProtectEscrow>,
+
+    /// Commitment being opened (closed back to the client)
+    #[account(
+        mut,
+        close = client,
+        seeds = [RATING_COMMITMENT_SEED, escrow.key().as_ref()],
+        bump = rating_commitment.bump,
+        has_one = client @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub rating_commitment: Account<'info, RatingCommitment>,
+
+    /// Reputation metrics account
+    #[account(
+        mut,
+        seeds = [
+            b"reputation_metrics",
+            agent.key().as_ref()
+        ],
+        bump = reputation_metrics.bump
+    )]
+    pub reputation_metrics: Account<'info, ReputationMetrics>,
+
+    /// Agent that served the escrow
+    #[account(
+        mut,
+        constraint = agent.key() == escrow.agent @ GhostSpeakError::InvalidAgent
+    )]
+    pub agent: Account<'info, Agent>,
+
+    /// Rolling windows (optional; blends recent performance into the headline score)
+    #[account(
+        mut,
+        seeds = [b"reputation_windows", agent.key().as_ref()],
+        bump = reputation_windows.bump
+    )]
+    pub reputation_windows: Option<Account<'info, ReputationWindows>>,
+
+    /// CHECK: Agent's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.key().as_ref()],
+        bump,
+        constraint = agent_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub agent_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, agent.owner.unwrap_or_default().as_ref()],
+        bump,
+        constraint = owner_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub owner_denylist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Client's denylist entry PDA; must not exist
+    #[account(
+        seeds = [DENYLIST_ENTRY_SEED, client.key().as_ref()],
+        bump,
+        constraint = client_denylist_entry.data_is_empty() @ GhostSpeakError::SubjectDenylisted
+    )]
+    pub client_denylist_entry: UncheckedAccount<'info>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Account<'info, RateLimiter>,
+
+    /// Per-client rate limit tracking, shared with `submit_x402_rating`
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            client.key().as_ref(),
+            OPERATION_SERVICE_RATING.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    /// Escrow client revealing the rating
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for initializing rolling reputation windows
+#[derive(Accounts)]
+pub struct InitializeReputationWindows<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ReputationWindows::LEN,
+        seeds = [b"reputation_windows", agent.key().as_ref()],
+        bump
+    )]
+    pub reputation_windows: Account<'info, ReputationWindows>,
+
+    /// Agent account
+    #[account(
+        constraint = agent.owner == Some(authority.key()) @ GhostSpeakError::InvalidAgentOwner
+    )]
+    pub agent: Account<'info, Agent>,
+
+    /// Authority (agent owner)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for reading recent vs lifetime performance
+#[derive(Accounts)]
+pub struct GetReputationWindows<'info> {
+    #[account(
+        seeds = [b"reputation_metrics", reputation_windows.agent.as_ref()],
+        bump = reputation_metrics.bump
+    )]
+    pub reputation_metrics: Account<'info, ReputationMetrics>,
+
+    #[account(
+        seeds = [b"reputation_windows", reputation_windows.agent.as_ref()],
+        bump = reputation_windows.bump
+    )]
+    pub reputation_windows: Account<'info, ReputationWindows>,
 }
 
 /// Context for updating reputation tags
@@ -114,6 +420,10 @@ pub struct UpdateReputationTags<'info> {
     /// Authority (can be the agent owner or authorized updater)
     pub authority: Signer<'info>,
 
+    /// Tag taxonomy; when supplied, every tag must resolve to a registered tag
+    #[account(seeds = [TAG_REGISTRY_SEED], bump = tag_registry.bump)]
+    pub tag_registry: Option<Account<'info, TagRegistry>>,
+
     /// Clock for timestamps
     pub clock: Sysvar<'info, Clock>,
 }
@@ -123,7 +433,31 @@ pub fn initialize_reputation_metrics(ctx: Context<InitializeReputationMetrics>)
     let reputation_metrics = &mut ctx.accounts.reputation_metrics;
     let clock = &ctx.accounts.clock;
 
-    reputation_metrics.agent = ctx.accounts.agent.key();
+    initialize_metrics_account(
+        reputation_metrics,
+        ctx.accounts.agent.key(),
+        ctx.bumps.reputation_metrics,
+        clock.unix_timestamp,
+    );
+
+    emit!(ReputationMetricsInitializedEvent {
+        agent: ctx.accounts.agent.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Reset a freshly created metrics account to its initial state
+///
+/// Shared by `initialize_reputation_metrics` and `onboard_agent`.
+pub(crate) fn initialize_metrics_account(
+    reputation_metrics: &mut ReputationMetrics,
+    agent: Pubkey,
+    bump: u8,
+    now: i64,
+) {
+    reputation_metrics.agent = agent;
     reputation_metrics.successful_payments = 0;
     reputation_metrics.failed_payments = 0;
     reputation_metrics.total_response_time = 0;
@@ -133,40 +467,67 @@ pub fn initialize_reputation_metrics(ctx: Context<InitializeReputationMetrics>)
     reputation_metrics.total_rating = 0;
     reputation_metrics.total_ratings_count = 0;
     reputation_metrics.payment_history_7d = [0; 7];
-    reputation_metrics.created_at = clock.unix_timestamp;
-    reputation_metrics.updated_at = clock.unix_timestamp;
+    reputation_metrics.created_at = now;
+    reputation_metrics.updated_at = now;
 
     // Initialize tag fields
     reputation_metrics.skill_tags = Vec::new();
     reputation_metrics.behavior_tags = Vec::new();
     reputation_metrics.compliance_tags = Vec::new();
     reputation_metrics.tag_scores = Vec::new();
-    reputation_metrics.tag_updated_at = clock.unix_timestamp;
+    reputation_metrics.tag_updated_at = now;
 
     // Initialize multi-source reputation fields
     reputation_metrics.source_scores = Vec::new();
-    reputation_metrics.primary_source = "payai".to_string();
-    reputation_metrics.last_aggregation = clock.unix_timestamp;
+    reputation_metrics.primary_source = PAYAI_SOURCE.to_string();
+    reputation_metrics.last_aggregation = now;
     reputation_metrics.conflict_flags = Vec::new();
 
-    reputation_metrics.bump = ctx.bumps.reputation_metrics;
-
-    emit!(ReputationMetricsInitializedEvent {
-        agent: ctx.accounts.agent.key(),
-        timestamp: clock.unix_timestamp,
-    });
+    reputation_metrics.bump = bump;
+    reputation_metrics.version = ReputationMetrics::CURRENT_VERSION;
 
-    Ok(())
+    // Inactivity decay clock starts at creation
+    reputation_metrics.last_activity_at = now;
+    reputation_metrics.last_decay_at = now;
 }
 
-/// Record an x402 payment and update reputation metrics
-pub fn record_x402_payment(
+/// Record a PayAI payment and update reputation metrics
+pub fn record_payai_payment(
     ctx: Context<RecordX402PaymentReputation>,
     payment_signature: String,
     amount: u64,
     response_time_ms: u64,
     success: bool,
 ) -> Result<()> {
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.authority.key(),
+        OPERATION_PAYAI_PAYMENT,
+        ctx.bumps.user_rate_limit,
+    )?;
+
+    // Only the agent owner, its session key or the payment verifier may record
+    let authority = ctx.accounts.authority.key();
+    if let Some(session_key) = ctx.accounts.session_key.as_mut() {
+        session_key.authorize(
+            &ctx.accounts.agent.key(),
+            ctx.accounts.agent.owner,
+            &authority,
+            SessionScope::RecordPayments,
+            ctx.accounts.clock.unix_timestamp,
+        )?;
+    } else {
+        let is_verifier = ctx
+            .accounts
+            .source_registry
+            .as_ref()
+            .is_some_and(|registry| registry.is_active_updater(PAYAI_SOURCE, &authority));
+        require!(
+            ctx.accounts.agent.owner == Some(authority) || is_verifier,
+            GhostSpeakError::UnauthorizedAccess
+        );
+    }
+
     let reputation_metrics = &mut ctx.accounts.reputation_metrics;
     let agent = &mut ctx.accounts.agent;
     let clock = &ctx.accounts.clock;
@@ -206,9 +567,14 @@ pub fn record_x402_payment(
         reputation_metrics.response_time_count.saturating_add(1);
 
     // Calculate and update reputation score
-    let reputation_score = calculate_x402_reputation_score(reputation_metrics)?;
+    let mut reputation_score = calculate_x402_reputation_score(reputation_metrics)?;
+    if let Some(windows) = ctx.accounts.reputation_windows.as_mut() {
+        windows.record_payment(amount, response_time_ms, success, clock.unix_timestamp);
+        reputation_score = windows.blended_score(reputation_score, clock.unix_timestamp);
+    }
     agent.reputation_score = (reputation_score / 100) as u32; // Convert basis points to 0-100 scale
 
+    reputation_metrics.record_activity(clock.unix_timestamp);
     reputation_metrics.updated_at = clock.unix_timestamp;
 
     emit!(ReputationPaymentRecordedEvent {
@@ -230,13 +596,149 @@ pub fn submit_x402_rating(
     rating: u8,
     _payment_signature: String,
 ) -> Result<()> {
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.client.key(),
+        OPERATION_SERVICE_RATING,
+        ctx.bumps.user_rate_limit,
+    )?;
+
+    // Validate rating (1-5 scale)
+    require!(rating >= 1 && rating <= 5, GhostSpeakError::InvalidRating);
+
+    let weight_bps = sybil_rating_weight(ctx.accounts)?;
     let reputation_metrics = &mut ctx.accounts.reputation_metrics;
     let agent = &mut ctx.accounts.agent;
     let clock = &ctx.accounts.clock;
 
-    // Validate rating (1-5 scale)
+    let applied_rating = dampened_rating(
+        reputation_metrics.total_rating,
+        reputation_metrics.total_ratings_count,
+        rating,
+        weight_bps,
+    );
+    let flagged_cluster = ctx
+        .accounts
+        .funder_cluster
+        .as_mut()
+        .filter(|_| weight_bps < 10_000);
+    if let Some(cluster) = flagged_cluster {
+        cluster.flagged_ratings = cluster.flagged_ratings.saturating_add(1);
+        emit!(RatingFlaggedEvent {
+            agent: agent.key(),
+            rater: ctx.accounts.client.key(),
+            funder: cluster.funder,
+            cluster_size: cluster.wallet_count,
+            rating,
+            applied_rating,
+            weight_bps,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    let Some(rating) = applied_rating else {
+        msg!("Rating ignored: rater belongs to a confirmed sybil cluster");
+        return Ok(());
+    };
+
+    let reputation_score = apply_rating(
+        reputation_metrics,
+        agent,
+        ctx.accounts.reputation_windows.as_mut(),
+        rating,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ReputationRatingSubmittedEvent {
+        agent: agent.key(),
+        client: ctx.accounts.client.key(),
+        rating,
+        new_reputation_score: reputation_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Commit to a hidden rating while an escrow job is in progress
+///
+/// `commitment` is `rating_commitment(escrow, rating, salt)` computed off-chain
+/// with a random 32-byte salt. The agent cannot learn the rating until it is
+/// revealed after completion.
+pub fn commit_service_rating(
+    ctx: Context<CommitServiceRating>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let now = Clock::get()?.unix_timestamp;
+
+    let rating_commitment = &mut ctx.accounts.rating_commitment;
+    rating_commitment.escrow = escrow.key();
+    rating_commitment.client = escrow.client;
+    rating_commitment.agent = escrow.agent;
+    rating_commitment.commitment = commitment;
+    rating_commitment.committed_at = now;
+    rating_commitment.bump = ctx.bumps.rating_commitment;
+
+    emit!(RatingCommittedEvent {
+        escrow: escrow.key(),
+        client: escrow.client,
+        agent: escrow.agent,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Reveal a committed rating once the escrow has completed
+///
+/// Applies the rating to the agent's reputation and closes the commitment.
+pub fn reveal_service_rating(
+    ctx: Context<RevealServiceRating>,
+    rating: u8,
+    salt: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.client.key(),
+        OPERATION_SERVICE_RATING,
+        ctx.bumps.user_rate_limit,
+    )?;
+
     require!(rating >= 1 && rating <= 5, GhostSpeakError::InvalidRating);
+    require!(
+        ctx.accounts.rating_commitment.matches(rating, &salt),
+        GhostSpeakError::RatingRevealMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let agent = &mut ctx.accounts.agent;
+    let reputation_score = apply_rating(
+        &mut ctx.accounts.reputation_metrics,
+        agent,
+        ctx.accounts.reputation_windows.as_mut(),
+        rating,
+        now,
+    )?;
+
+    emit!(ReputationRatingSubmittedEvent {
+        agent: agent.key(),
+        client: ctx.accounts.client.key(),
+        rating,
+        new_reputation_score: reputation_score,
+        timestamp: now,
+    });
 
+    Ok(())
+}
+
+/// Add a rating to the agent's metrics and refresh its headline score
+fn apply_rating(
+    reputation_metrics: &mut ReputationMetrics,
+    agent: &mut Agent,
+    reputation_windows: Option<&mut Account<ReputationWindows>>,
+    rating: u8,
+    now: i64,
+) -> Result<u64> {
     // Update rating metrics
     reputation_metrics.total_rating = reputation_metrics
         .total_rating
@@ -245,22 +747,63 @@ pub fn submit_x402_rating(
         reputation_metrics.total_ratings_count.saturating_add(1);
 
     // Recalculate reputation score
-    let reputation_score = calculate_x402_reputation_score(reputation_metrics)?;
+    let mut reputation_score = calculate_x402_reputation_score(reputation_metrics)?;
+    if let Some(windows) = reputation_windows {
+        windows.record_rating(rating, now);
+        reputation_score = windows.blended_score(reputation_score, now);
+    }
     agent.reputation_score = (reputation_score / 100) as u32;
 
-    reputation_metrics.updated_at = clock.unix_timestamp;
+    reputation_metrics.record_activity(now);
+    reputation_metrics.updated_at = now;
 
-    emit!(ReputationRatingSubmittedEvent {
-        agent: agent.key(),
-        client: ctx.accounts.client.key(),
-        rating,
-        new_reputation_score: reputation_score,
-        timestamp: clock.unix_timestamp,
-    });
+    Ok(reputation_score)
+}
+
+/// Weight (basis points) for the client's rating under the sybil heuristic
+///
+/// Clients without a funder fingerprint rate at full weight. Fingerprinted
+/// clients must pass their funder's cluster account.
+fn sybil_rating_weight(accounts: &SubmitX402RatingReputation) -> Result<u16> {
+    let info = accounts.rater_fingerprint.to_account_info();
+    if info.data_is_empty() {
+        return Ok(10_000);
+    }
+    require!(info.owner == &crate::ID, GhostSpeakError::InvalidAccountOwner);
+    let fingerprint = FunderFingerprint::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    let cluster = accounts
+        .funder_cluster
+        .as_ref()
+        .filter(|c| c.funder == fingerprint.first_funder)
+        .ok_or(GhostSpeakError::SybilClusterRequired)?;
+
+    Ok(accounts.sybil_config.rating_weight_bps(cluster))
+}
+
+/// Initialize rolling 30/90-day reputation windows for an agent
+pub fn initialize_reputation_windows(ctx: Context<InitializeReputationWindows>) -> Result<()> {
+    let windows = &mut ctx.accounts.reputation_windows;
+    windows.agent = ctx.accounts.agent.key();
+    windows.buckets = Default::default();
+    windows.updated_at = Clock::get()?.unix_timestamp;
+    windows.bump = ctx.bumps.reputation_windows;
+
+    msg!("Reputation windows initialized for agent: {}", windows.agent);
 
     Ok(())
 }
 
+/// Recent (30d/90d) and lifetime performance with the blended headline score,
+/// returned via return data
+pub fn get_reputation_windows(ctx: Context<GetReputationWindows>) -> Result<ReputationWindowSummary> {
+    let lifetime_score = calculate_x402_reputation_score(&ctx.accounts.reputation_metrics)?;
+    Ok(ctx
+        .accounts
+        .reputation_windows
+        .summary(lifetime_score, Clock::get()?.unix_timestamp))
+}
+
 /// Calculate x402-based reputation score (0-10000 basis points)
 ///
 /// Formula: Reputation = (
@@ -329,14 +872,19 @@ fn calculate_x402_reputation_score(metrics: &ReputationMetrics) -> Result<u64> {
 /// Adds or updates reputation tags with confidence scores.
 /// This is typically called after calculating tags off-chain using the
 /// ReputationTagEngine.
+///
+/// With the tag registry supplied, tags may be given by registry ID or by
+/// name/alias and are stored under their canonical name. Without it, only
+/// free-form names are accepted and stored as given.
 pub fn update_reputation_tags(
     ctx: Context<UpdateReputationTags>,
-    skill_tags: Vec<String>,
-    behavior_tags: Vec<String>,
-    compliance_tags: Vec<String>,
+    skill_tags: Vec<TagRef>,
+    behavior_tags: Vec<TagRef>,
+    compliance_tags: Vec<TagRef>,
     tag_scores: Vec<crate::state::TagScore>,
 ) -> Result<()> {
     let reputation_metrics = &mut ctx.accounts.reputation_metrics;
+    let registry = ctx.accounts.tag_registry.as_deref();
     let clock = &ctx.accounts.clock;
 
     // Remove stale tags first
@@ -344,23 +892,30 @@ pub fn update_reputation_tags(
 
     // Update skill tags
     for tag in skill_tags {
+        let tag = resolve_tag(registry, TagCategory::Skill, tag)?;
         reputation_metrics.add_skill_tag(tag)?;
     }
 
     // Update behavior tags
     for tag in behavior_tags {
+        let tag = resolve_tag(registry, TagCategory::Behavior, tag)?;
         reputation_metrics.add_behavior_tag(tag)?;
     }
 
     // Update compliance tags
     for tag in compliance_tags {
+        let tag = resolve_tag(registry, TagCategory::Compliance, tag)?;
         reputation_metrics.add_compliance_tag(tag)?;
     }
 
     // Update tag confidence scores
     for tag_score in tag_scores {
+        let tag_name = match registry {
+            Some(registry) => registry.resolve_name(&tag_score.tag_name)?,
+            None => tag_score.tag_name,
+        };
         reputation_metrics.update_tag_confidence(
-            tag_score.tag_name,
+            tag_name,
             tag_score.confidence,
             tag_score.evidence_count,
             clock.unix_timestamp,
@@ -382,6 +937,19 @@ pub fn update_reputation_tags(
     Ok(())
 }
 
+/// Canonical name for `tag`, or the name as given when there is no registry
+fn resolve_tag(
+    registry: Option<&TagRegistry>,
+    category: TagCategory,
+    tag: TagRef,
+) -> Result<String> {
+    match (registry, tag) {
+        (Some(registry), tag) => registry.resolve(category, &tag),
+        (None, TagRef::Name(name)) => Ok(name),
+        (None, TagRef::Id(_)) => err!(GhostSpeakError::UnknownTag),
+    }
+}
+
 /// Apply tag decay to remove stale tags
 ///
 /// This should be called periodically to clean up old tags that are
@@ -426,11 +994,39 @@ pub struct UpdateSourceReputation<'info> {
     #[account(mut)]
     pub agent: Account<'info, Agent>,
 
-    /// Authority (can be oracle, agent owner, or authorized updater)
+    /// Registry of approved sources and their updaters
+    #[account(
+        seeds = [b"reputation_source_registry"],
+        bump = source_registry.bump
+    )]
+    pub source_registry: Account<'info, ReputationSourceRegistry>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Account<'info, RateLimiter>,
+
+    /// Per-updater rate limit tracking (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            authority.key().as_ref(),
+            OPERATION_REPUTATION_UPDATE.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    /// Authorized updater for the source being written
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     /// Clock for timestamps
     pub clock: Sysvar<'info, Clock>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Update reputation from a specific source
@@ -442,10 +1038,48 @@ pub fn update_source_reputation(
     data_points: u32,
     reliability: u16,
 ) -> Result<()> {
-    let reputation_metrics = &mut ctx.accounts.reputation_metrics;
-    let agent = &mut ctx.accounts.agent;
-    let clock = &ctx.accounts.clock;
+    ctx.accounts.user_rate_limit.enforce(
+        &ctx.accounts.rate_limiter,
+        ctx.accounts.authority.key(),
+        OPERATION_REPUTATION_UPDATE,
+        ctx.bumps.user_rate_limit,
+    )?;
+
+    // Only approved sources, written by their registered updater, within max weight
+    ctx.accounts.source_registry.authorize_update(
+        &source_name,
+        &ctx.accounts.authority.key(),
+        weight,
+    )?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    apply_source_update(
+        &mut ctx.accounts.reputation_metrics,
+        &mut ctx.accounts.agent,
+        source_name,
+        score,
+        weight,
+        data_points,
+        reliability,
+        now,
+    )?;
 
+    Ok(())
+}
+
+/// Write one source score, re-aggregate and emit the update event
+///
+/// Shared by the single and batch source update instructions.
+fn apply_source_update(
+    reputation_metrics: &mut ReputationMetrics,
+    agent: &mut Account<Agent>,
+    source_name: String,
+    score: u16,
+    weight: u16,
+    data_points: u32,
+    reliability: u16,
+    now: i64,
+) -> Result<()> {
     // Update source score
     reputation_metrics.update_source_score(
         source_name.clone(),
@@ -453,11 +1087,11 @@ pub fn update_source_reputation(
         weight,
         data_points,
         reliability,
-        clock.unix_timestamp,
+        now,
     )?;
 
     // Detect conflicts
-    let has_conflict = reputation_metrics.detect_conflicts(clock.unix_timestamp);
+    let has_conflict = reputation_metrics.detect_conflicts(now);
 
     // Calculate new weighted aggregate score
     let weighted_score = reputation_metrics.calculate_weighted_score();
@@ -466,8 +1100,8 @@ pub fn update_source_reputation(
     agent.reputation_score = (weighted_score / 100) as u32;
 
     // Update last aggregation timestamp
-    reputation_metrics.last_aggregation = clock.unix_timestamp;
-    reputation_metrics.updated_at = clock.unix_timestamp;
+    reputation_metrics.last_aggregation = now;
+    reputation_metrics.updated_at = now;
 
     // Prune old conflict flags
     reputation_metrics.prune_conflict_flags();
@@ -479,12 +1113,521 @@ pub fn update_source_reputation(
         weighted_aggregate_score: weighted_score,
         has_conflict,
         total_sources: reputation_metrics.source_scores.len() as u32,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Context for recomputing an agent's aggregate score (permissionless)
+#[derive(Accounts)]
+pub struct RecomputeReputationAggregate<'info> {
+    /// Reputation metrics account
+    #[account(
+        mut,
+        seeds = [
+            b"reputation_metrics",
+            agent.key().as_ref()
+        ],
+        bump = reputation_metrics.bump,
+        constraint = reputation_metrics.agent == agent.key() @ GhostSpeakError::InvalidAgent
+    )]
+    pub reputation_metrics: Account<'info, ReputationMetrics>,
+
+    /// Agent account
+    #[account(mut)]
+    pub agent: Account<'info, Agent>,
+
+    /// Registry of approved sources; scores from revoked sources are dropped
+    #[account(
+        seeds = [b"reputation_source_registry"],
+        bump = source_registry.bump
+    )]
+    pub source_registry: Account<'info, ReputationSourceRegistry>,
+
+    /// Anyone may crank the recomputation
+    pub cranker: Signer<'info>,
+}
+
+/// Re-derive the weighted aggregate from all approved sources
+///
+/// Drops scores from sources revoked in the registry, re-runs conflict
+/// detection and emits ReputationConflictDetectedEvent when the highest and
+/// lowest sources diverge by more than CONFLICT_THRESHOLD.
+pub fn recompute_reputation_aggregate(ctx: Context<RecomputeReputationAggregate>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let reputation_metrics = &mut ctx.accounts.reputation_metrics;
+    let agent = &mut ctx.accounts.agent;
+
+    let sources_dropped =
+        reputation_metrics.drop_unapproved_sources(&ctx.accounts.source_registry);
+    let has_conflict = reputation_metrics.detect_conflicts(now);
+    let weighted_score = reputation_metrics.calculate_weighted_score();
+
+    let previous_score = agent.reputation_score;
+    agent.reputation_score = (weighted_score / 100) as u32;
+
+    reputation_metrics.last_aggregation = now;
+    reputation_metrics.updated_at = now;
+    reputation_metrics.prune_conflict_flags();
+
+    if has_conflict {
+        if let Some((max, min)) = reputation_metrics.source_divergence() {
+            emit!(ReputationConflictDetectedEvent {
+                agent: agent.key(),
+                max_source: max.source_name.clone(),
+                max_score: max.score,
+                min_source: min.source_name.clone(),
+                min_score: min.score,
+                divergence: max.score - min.score,
+                threshold: ReputationMetrics::CONFLICT_THRESHOLD,
+                weighted_aggregate_score: weighted_score,
+                timestamp: now,
+            });
+        }
+    }
+
+    emit!(ReputationAggregateRecomputedEvent {
+        agent: agent.key(),
+        previous_score,
+        reputation_score: agent.reputation_score,
+        weighted_aggregate_score: weighted_score,
+        has_conflict,
+        total_sources: reputation_metrics.source_scores.len() as u32,
+        sources_dropped,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Maximum entries in a single batch source update
+pub const MAX_SOURCE_BATCH_SIZE: usize = 10;
+
+/// One agent's score in a batch source update
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SourceReputationEntry {
+    /// Agent the score applies to
+    pub agent: Pubkey,
+    /// Reputation score from source (0-1000)
+    pub score: u16,
+    /// Source weight in basis points (0-10000)
+    pub weight: u16,
+    /// Number of metrics contributing to score
+    pub data_points: u32,
+    /// Source reliability in basis points (0-10000)
+    pub reliability: u16,
+}
+
+/// Context for updating one source's score for many agents
+///
+/// For each entry, the agent's reputation metrics and agent accounts (both
+/// writable) are passed as consecutive pairs in remaining accounts.
+#[derive(Accounts)]
+pub struct UpdateSourceReputationBatch<'info> {
+    /// Registry of approved sources and their updaters
+    #[account(
+        seeds = [b"reputation_source_registry"],
+        bump = source_registry.bump
+    )]
+    pub source_registry: Account<'info, ReputationSourceRegistry>,
+
+    /// Global rate limiter configuration
+    #[account(seeds = [b"rate_limiter"], bump = rate_limiter.bump)]
+    pub rate_limiter: Account<'info, RateLimiter>,
+
+    /// Per-updater rate limit tracking, shared with `update_source_reputation`
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = UserRateLimit::LEN,
+        seeds = [
+            b"user_rate_limit",
+            authority.key().as_ref(),
+            OPERATION_REPUTATION_UPDATE.as_bytes()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    /// Authorized updater for the source being written
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Clock for timestamps
+    pub clock: Sysvar<'info, Clock>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Update reputation from one source for many agents
+///
+/// Source authorization applies to the whole batch, and every entry counts
+/// as one update against the updater's rate limit. Individual entries that
+/// fail (bad accounts, invalid values) are skipped and reported through
+/// `SourceReputationUpdateFailedEvent` instead of reverting the batch.
+pub fn update_source_reputation_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateSourceReputationBatch<'info>>,
+    source_name: String,
+    entries: Vec<SourceReputationEntry>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_SOURCE_BATCH_SIZE,
+        GhostSpeakError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * 2,
+        GhostSpeakError::InvalidInput
+    );
+
+    for _ in &entries {
+        ctx.accounts.user_rate_limit.enforce(
+            &ctx.accounts.rate_limiter,
+            ctx.accounts.authority.key(),
+            OPERATION_REPUTATION_UPDATE,
+            ctx.bumps.user_rate_limit,
+        )?;
+    }
+
+    let max_weight = entries.iter().map(|e| e.weight).max().unwrap_or(0);
+    ctx.accounts.source_registry.authorize_update(
+        &source_name,
+        &ctx.accounts.authority.key(),
+        max_weight,
+    )?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    let mut succeeded: u8 = 0;
+
+    for (index, (entry, pair)) in entries
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(2))
+        .enumerate()
+    {
+        match apply_batch_entry(&pair[0], &pair[1], entry, &source_name, now) {
+            Ok(()) => succeeded += 1,
+            Err(err) => {
+                emit!(SourceReputationUpdateFailedEvent {
+                    agent: entry.agent,
+                    source_name: source_name.clone(),
+                    index: index as u8,
+                    error_code: u64::from(ProgramError::from(err)),
+                    timestamp: now,
+                });
+            }
+        }
+    }
+
+    emit!(SourceReputationBatchProcessedEvent {
+        source_name,
+        authority: ctx.accounts.authority.key(),
+        total: entries.len() as u8,
+        succeeded,
+        failed: entries.len() as u8 - succeeded,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Validate one batch entry's accounts, apply it and persist the result
+fn apply_batch_entry<'info>(
+    metrics_info: &'info AccountInfo<'info>,
+    agent_info: &'info AccountInfo<'info>,
+    entry: &SourceReputationEntry,
+    source_name: &str,
+    now: i64,
+) -> Result<()> {
+    require!(
+        metrics_info.is_writable && agent_info.is_writable,
+        GhostSpeakError::InvalidInput
+    );
+    require_keys_eq!(agent_info.key(), entry.agent, GhostSpeakError::InvalidAgent);
+
+    let mut reputation_metrics = Account::<ReputationMetrics>::try_from(metrics_info)?;
+    let mut agent = Account::<Agent>::try_from(agent_info)?;
+
+    require_keys_eq!(
+        reputation_metrics.agent,
+        agent.key(),
+        GhostSpeakError::InvalidAgent
+    );
+    let expected_metrics = Pubkey::create_program_address(
+        &[
+            b"reputation_metrics",
+            agent.key().as_ref(),
+            &[reputation_metrics.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| GhostSpeakError::InvalidAgent)?;
+    require_keys_eq!(
+        reputation_metrics.key(),
+        expected_metrics,
+        GhostSpeakError::InvalidAgent
+    );
+
+    apply_source_update(
+        &mut reputation_metrics,
+        &mut agent,
+        source_name.to_string(),
+        entry.score,
+        entry.weight,
+        entry.data_points,
+        entry.reliability,
+        now,
+    )?;
+
+    reputation_metrics.exit(&crate::ID)?;
+    agent.exit(&crate::ID)?;
+
+    Ok(())
+}
+
+/// Context for creating the reputation source registry (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeReputationSourceRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ReputationSourceRegistry::LEN,
+        seeds = [b"reputation_source_registry"],
+        bump
+    )]
+    pub source_registry: Account<'info, ReputationSourceRegistry>,
+
+    /// Multisig that will govern the registry
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for adding, updating or deactivating an approved source
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ManageReputationSource<'info> {
+    #[account(
+        mut,
+        seeds = [b"reputation_source_registry"],
+        bump = source_registry.bump,
+        has_one = multisig @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub source_registry: Account<'info, ReputationSourceRegistry>,
+
+    pub multisig: Account<'info, Multisig>,
+}
+
+/// Create the reputation source registry governed by `multisig`
+pub fn initialize_reputation_source_registry(
+    ctx: Context<InitializeReputationSourceRegistry>,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let registry = &mut ctx.accounts.source_registry;
+    registry.multisig = ctx.accounts.multisig.key();
+    registry.sources = Vec::new();
+    registry.updated_at = Clock::get()?.unix_timestamp;
+    registry.bump = ctx.bumps.source_registry;
+
+    msg!(
+        "Reputation source registry initialized (multisig: {})",
+        registry.multisig
+    );
+    Ok(())
+}
+
+/// Add or update an approved reputation source (multisig threshold required)
+pub fn set_reputation_source(
+    ctx: Context<ManageReputationSource>,
+    source_name: String,
+    updater: Pubkey,
+    max_weight: u16,
+    is_active: bool,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let registry = &mut ctx.accounts.source_registry;
+    registry.upsert_source(ApprovedSource {
+        source_name: source_name.clone(),
+        updater,
+        max_weight,
+        is_active,
+    })?;
+    registry.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ReputationSourceRegistryUpdatedEvent {
+        source_name,
+        updater,
+        max_weight,
+        is_active,
+        timestamp: registry.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Context for applying inactivity decay to an agent's aggregate reputation
+#[derive(Accounts)]
+pub struct ApplyReputationDecay<'info> {
+    /// Reputation metrics account
+    #[account(
+        mut,
+        seeds = [
+            b"reputation_metrics",
+            agent.key().as_ref()
+        ],
+        bump = reputation_metrics.bump,
+        constraint = reputation_metrics.agent == agent.key() @ GhostSpeakError::InvalidAgent,
+        constraint = reputation_metrics.is_current(reputation_metrics.to_account_info().data_len())
+            @ GhostSpeakError::ReputationMetricsOutdated
+    )]
+    pub reputation_metrics: Account<'info, ReputationMetrics>,
+
+    /// Agent account
+    #[account(mut)]
+    pub agent: Account<'info, Agent>,
+
+    /// Protocol config holding decay parameters
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Anyone can crank decay
+    pub cranker: Signer<'info>,
+
+    /// Clock for timestamps
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Apply inactivity decay to an agent's aggregate reputation score
+///
+/// Permissionless crank. Agents with no payments or ratings for longer than
+/// the configured grace period lose a fixed percentage of their score per
+/// inactive day, so stale agents don't outrank active ones indefinitely.
+pub fn apply_reputation_decay(ctx: Context<ApplyReputationDecay>) -> Result<()> {
+    let config = &ctx.accounts.protocol_config;
+    let reputation_metrics = &mut ctx.accounts.reputation_metrics;
+    let agent = &mut ctx.accounts.agent;
+    let clock = &ctx.accounts.clock;
+
+    require!(
+        config.reputation_decay_enabled,
+        GhostSpeakError::FeatureNotEnabled
+    );
+
+    let old_score = agent.reputation_score;
+    let (new_score, days_applied) = reputation_metrics.apply_inactivity_decay(
+        old_score,
+        clock.unix_timestamp,
+        config.reputation_decay_grace_seconds(),
+        config.reputation_decay_bps_per_day,
+        config.reputation_decay_floor,
+    );
+
+    if days_applied == 0 {
+        msg!("No reputation decay due for agent {}", agent.key());
+        return Ok(());
+    }
+
+    agent.reputation_score = new_score;
+    reputation_metrics.updated_at = clock.unix_timestamp;
+
+    emit!(ReputationDecayAppliedEvent {
+        agent: agent.key(),
+        old_score,
+        new_score,
+        days_applied,
+        last_activity_at: reputation_metrics.last_activity_at,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
 
+/// Context for migrating reputation metrics to the current layout
+#[derive(Accounts)]
+pub struct MigrateReputationMetrics<'info> {
+    /// Reputation metrics account created before the layout version
+    #[account(
+        mut,
+        seeds = [
+            b"reputation_metrics",
+            reputation_metrics.agent.as_ref()
+        ],
+        bump = reputation_metrics.bump
+    )]
+    pub reputation_metrics: Account<'info, ReputationMetrics>,
+
+    /// Anyone may migrate; pays the rent for the added bytes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow pre-version reputation metrics to the current layout
+///
+/// Permissionless. Accounts created before the version field have no
+/// trustworthy bytes behind `bump`, so the decay clock is restarted from
+/// the last metrics update rather than read from them.
+pub fn migrate_reputation_metrics(ctx: Context<MigrateReputationMetrics>) -> Result<()> {
+    let info = ctx.accounts.reputation_metrics.to_account_info();
+    require!(
+        info.data_len() < ReputationMetrics::LEN,
+        GhostSpeakError::ReputationMetricsCurrent
+    );
+
+    let rent_due = Rent::get()?
+        .minimum_balance(ReputationMetrics::LEN)
+        .saturating_sub(info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    info.resize(ReputationMetrics::LEN)?;
+
+    let reputation_metrics = &mut ctx.accounts.reputation_metrics;
+    reputation_metrics.version = ReputationMetrics::CURRENT_VERSION;
+    reputation_metrics.last_activity_at = reputation_metrics.updated_at;
+    reputation_metrics.last_decay_at = reputation_metrics.updated_at;
+
+    emit!(ReputationMetricsMigratedEvent {
+        agent: reputation_metrics.agent,
+        version: ReputationMetrics::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 /// Events
 #[event]
 pub struct ReputationMetricsInitializedEvent {
@@ -492,6 +1635,13 @@ pub struct ReputationMetricsInitializedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ReputationMetricsMigratedEvent {
+    pub agent: Pubkey,
+    pub version: u8,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ReputationPaymentRecordedEvent {
     pub agent: Pubkey,
@@ -512,6 +1662,14 @@ pub struct ReputationRatingSubmittedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct RatingCommittedEvent {
+    pub escrow: Pubkey,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ReputationTagsUpdatedEvent {
     pub agent: Pubkey,
@@ -541,3 +1699,68 @@ pub struct SourceReputationUpdatedEvent {
     pub total_sources: u32,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ReputationAggregateRecomputedEvent {
+    pub agent: Pubkey,
+    pub previous_score: u32,
+    pub reputation_score: u32,
+    pub weighted_aggregate_score: u64,
+    pub has_conflict: bool,
+    pub total_sources: u32,
+    /// Scores dropped because their source was revoked
+    pub sources_dropped: u32,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReputationConflictDetectedEvent {
+    pub agent: Pubkey,
+    pub max_source: String,
+    pub max_score: u16,
+    pub min_source: String,
+    pub min_score: u16,
+    pub divergence: u16,
+    pub threshold: u16,
+    pub weighted_aggregate_score: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SourceReputationUpdateFailedEvent {
+    pub agent: Pubkey,
+    pub source_name: String,
+    pub index: u8,
+    pub error_code: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SourceReputationBatchProcessedEvent {
+    pub source_name: String,
+    pub authority: Pubkey,
+    pub total: u8,
+    pub succeeded: u8,
+    pub failed: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReputationDecayAppliedEvent {
+    pub agent: Pubkey,
+    pub old_score: u32,
+    pub new_score: u32,
+    pub days_applied: u64,
+    pub last_activity_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReputationSourceRegistryUpdatedEvent {
+    pub source_name: String,
+    pub updater: Pubkey,
+    pub max_weight: u16,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
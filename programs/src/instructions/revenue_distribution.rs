@@ -0,0 +1,362 @@
+/*!
+ * Revenue Distribution Instructions
+ *
+ * Deposits protocol USDC revenue into the revenue pool and lets stakers
+ * claim their share, weighted by stake and tier multiplier.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::revenue_pool::*;
+use crate::state::staking::StakingConfig;
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE REVENUE POOL
+// =====================================================
+
+/// Initialize the global revenue pool (staking authority only)
+#[derive(Accounts)]
+pub struct InitializeRevenuePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = RevenuePool::LEN,
+        seeds = [REVENUE_POOL_SEED],
+        bump
+    )]
+    pub revenue_pool: Account<'info, RevenuePool>,
+
+    /// USDC vault owned by the pool
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = revenue_pool,
+        seeds = [REVENUE_VAULT_SEED, revenue_pool.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_revenue_pool(ctx: Context<InitializeRevenuePool>) -> Result<()> {
+    let pool = &mut ctx.accounts.revenue_pool;
+    pool.authority = ctx.accounts.authority.key();
+    pool.usdc_mint = ctx.accounts.usdc_mint.key();
+    pool.vault = ctx.accounts.revenue_vault.key();
+    pool.acc_revenue_per_weight = 0;
+    pool.total_weighted_stake = 0;
+    pool.total_distributed = 0;
+    pool.total_claimed = 0;
+    pool.distributed_by_source = [0; RevenueSource::COUNT];
+    pool.period_index = 0;
+    pool.period_started_at = Clock::get()?.unix_timestamp;
+    pool.period_revenue = 0;
+    pool.bump = ctx.bumps.revenue_pool;
+
+    emit!(RevenuePoolInitializedEvent {
+        authority: pool.authority,
+        usdc_mint: pool.usdc_mint,
+        vault: pool.vault,
+    });
+
+    msg!(
+        "Revenue pool initialized (mint: {}, vault: {})",
+        pool.usdc_mint,
+        pool.vault
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// DISTRIBUTE REVENUE
+// =====================================================
+
+/// Deposit protocol revenue for stakers (pool authority only)
+#[derive(Accounts)]
+pub struct DistributeRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [REVENUE_POOL_SEED],
+        bump = revenue_pool.bump,
+        has_one = authority @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub revenue_pool: Account<'info, RevenuePool>,
+
+    #[account(mut, address = revenue_pool.vault)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+
+    /// Account the revenue is paid from
+    #[account(
+        mut,
+        constraint = source_token_account.owner == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = source_token_account.mint == revenue_pool.usdc_mint @ GhostSpeakError::InvalidInput
+    )]
+    pub source_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn distribute_revenue(
+    ctx: Context<DistributeRevenue>,
+    amount: u64,
+    source: RevenueSource,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.revenue_pool;
+    pool.distribute(amount, source)?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.source_token_account.to_account_info(),
+        to: ctx.accounts.revenue_vault.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(RevenueDistributedEvent {
+        amount,
+        source,
+        total_weighted_stake: pool.total_weighted_stake,
+        acc_revenue_per_weight: pool.acc_revenue_per_weight,
+        period_index: pool.period_index,
+    });
+
+    msg!(
+        "Distributed {} USDC of {:?} revenue across {} weighted stake",
+        amount,
+        source,
+        pool.total_weighted_stake
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// CLAIM REWARDS
+// =====================================================
+
+/// Claim accumulated USDC revenue (staker only)
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        seeds = [REVENUE_POOL_SEED],
+        bump = revenue_pool.bump
+    )]
+    pub revenue_pool: Account<'info, RevenuePool>,
+
+    #[account(mut, address = revenue_pool.vault)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = RevenuePosition::LEN,
+        seeds = [REVENUE_POSITION_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub revenue_position: Account<'info, RevenuePosition>,
+
+    /// CHECK: Owner's staking PDA; may already be closed or migrated away,
+    /// which leaves revenue earned before then claimable
+    #[account(
+        seeds = [b"staking", owner.key().as_ref()],
+        bump
+    )]
+    pub staking_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = owner_token_account.mint == revenue_pool.usdc_mint @ GhostSpeakError::InvalidInput
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Settles the owner's position, re-weighs it from their current stake and
+/// pays out everything pending.
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let pool = &mut ctx.accounts.revenue_pool;
+    let position = &mut ctx.accounts.revenue_position;
+    position.owner = ctx.accounts.owner.key();
+    position.bump = ctx.bumps.revenue_position;
+    position.last_synced_at = Clock::get()?.unix_timestamp;
+    let staking = read_staking_account(&ctx.accounts.staking_account)?;
+    pool.sync_position(position, staking.as_ref())?;
+
+    let amount = position.pending;
+    require!(amount > 0, GhostSpeakError::NoStakingRewards);
+
+    let seeds = &[REVENUE_POOL_SEED, &[pool.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.revenue_vault.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    position.pending = 0;
+    position.claimed = position.claimed.saturating_add(amount);
+    pool.total_claimed = pool.total_claimed.saturating_add(amount);
+
+    emit!(RevenueClaimedEvent {
+        owner: position.owner,
+        amount,
+        claimed: position.claimed,
+    });
+
+    msg!(
+        "Claimed {} USDC of revenue for owner: {}",
+        amount,
+        position.owner
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// RECALCULATE GLOBAL WEIGHTED STAKE
+// =====================================================
+
+/// Re-weigh one staker's position in the global weighted stake (permissionless)
+#[derive(Accounts)]
+pub struct UpdateGlobalWeightedStake<'info> {
+    #[account(
+        mut,
+        seeds = [REVENUE_POOL_SEED],
+        bump = revenue_pool.bump
+    )]
+    pub revenue_pool: Account<'info, RevenuePool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RevenuePosition::LEN,
+        seeds = [REVENUE_POSITION_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub revenue_position: Account<'info, RevenuePosition>,
+
+    /// CHECK: Owner's staking PDA; once closed or migrated away the position
+    /// is re-weighed to zero
+    #[account(
+        seeds = [b"staking", owner.key().as_ref()],
+        bump
+    )]
+    pub staking_account: UncheckedAccount<'info>,
+
+    /// CHECK: Staker whose position is re-weighed; only keys the PDAs
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Settles the position at its old weight and re-weighs it from the staking
+/// account, so stake changes (including slashes, unstakes, closes and
+/// migrations) reach the pool's total before the next distribution.
+pub fn recalculate_global_weighted_stake(ctx: Context<UpdateGlobalWeightedStake>) -> Result<()> {
+    let pool = &mut ctx.accounts.revenue_pool;
+    let position = &mut ctx.accounts.revenue_position;
+    position.owner = ctx.accounts.owner.key();
+    position.bump = ctx.bumps.revenue_position;
+    position.last_synced_at = Clock::get()?.unix_timestamp;
+    let staking = read_staking_account(&ctx.accounts.staking_account)?;
+    pool.sync_position(position, staking.as_ref())?;
+
+    emit!(RevenuePositionSyncedEvent {
+        owner: position.owner,
+        weighted_stake: position.weighted_stake,
+        total_weighted_stake: pool.total_weighted_stake,
+        pending: position.pending,
+    });
+
+    msg!(
+        "Revenue weight for owner: {} is {} (global: {})",
+        position.owner,
+        position.weighted_stake,
+        pool.total_weighted_stake
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// RESET PERIOD
+// =====================================================
+
+/// Close the current revenue reporting period (pool authority only)
+#[derive(Accounts)]
+pub struct ResetPeriod<'info> {
+    #[account(
+        mut,
+        seeds = [REVENUE_POOL_SEED],
+        bump = revenue_pool.bump,
+        has_one = authority @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub revenue_pool: Account<'info, RevenuePool>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn reset_period(ctx: Context<ResetPeriod>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.revenue_pool;
+
+    emit!(RevenuePeriodClosedEvent {
+        period_index: pool.period_index,
+        started_at: pool.period_started_at,
+        ended_at: now,
+        revenue: pool.period_revenue,
+    });
+
+    msg!(
+        "Revenue period {} closed with {} USDC distributed",
+        pool.period_index,
+        pool.period_revenue
+    );
+
+    pool.period_index = pool
+        .period_index
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    pool.period_started_at = now;
+    pool.period_revenue = 0;
+
+    Ok(())
+}
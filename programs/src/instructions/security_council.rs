@@ -0,0 +1,257 @@
+/*!
+ * Security Council Instructions
+ *
+ * Lets the protocol admin name the Protocol multisig acting as security
+ * council and grant its powers, and lets the council veto a passed
+ * community proposal with a supermajority while its execution timelock
+ * runs. Each veto must be answered by a community review proposal before
+ * the council can veto again.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::governance::{
+    CouncilPowers, GovernanceProposal, Multisig, MultisigType, ProposalType,
+};
+use crate::state::security_council::*;
+use crate::GhostSpeakError;
+
+// =====================================================
+// COUNCIL SETUP
+// =====================================================
+
+/// Name the security council and grant its powers (protocol admin only)
+#[derive(Accounts)]
+pub struct InitializeSecurityCouncil<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SecurityCouncil::LEN,
+        seeds = [SECURITY_COUNCIL_SEED],
+        bump
+    )]
+    pub security_council: Box<Account<'info, SecurityCouncil>>,
+
+    /// Protocol multisig acting as the council
+    #[account(
+        constraint = council.multisig_type == MultisigType::Protocol @ GhostSpeakError::InvalidConfiguration
+    )]
+    pub council: Box<Account<'info, Multisig>>,
+
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_security_council(
+    ctx: Context<InitializeSecurityCouncil>,
+    powers: CouncilPowers,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let security_council = &mut ctx.accounts.security_council;
+    security_council.council = ctx.accounts.council.key();
+    security_council.pending_review = None;
+    security_council.veto_count = 0;
+    security_council.bump = ctx.bumps.security_council;
+    security_council.set_powers(powers, now)?;
+
+    emit!(SecurityCouncilUpdatedEvent {
+        council: security_council.council,
+        veto_power: security_council.powers.veto_power,
+        timestamp: now,
+    });
+
+    msg!("Security council set to {}", security_council.council);
+    Ok(())
+}
+
+/// Change the council's powers (protocol admin only)
+#[derive(Accounts)]
+pub struct UpdateCouncilPowers<'info> {
+    #[account(
+        mut,
+        seeds = [SECURITY_COUNCIL_SEED],
+        bump = security_council.bump
+    )]
+    pub security_council: Box<Account<'info, SecurityCouncil>>,
+
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn update_council_powers(
+    ctx: Context<UpdateCouncilPowers>,
+    powers: CouncilPowers,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let security_council = &mut ctx.accounts.security_council;
+    security_council.set_powers(powers, now)?;
+
+    emit!(SecurityCouncilUpdatedEvent {
+        council: security_council.council,
+        veto_power: security_council.powers.veto_power,
+        timestamp: now,
+    });
+
+    msg!(
+        "Security council powers updated (veto: {})",
+        security_council.powers.veto_power
+    );
+    Ok(())
+}
+
+// =====================================================
+// VETO
+// =====================================================
+
+/// Veto a passed proposal during its execution timelock (council
+/// supermajority required)
+///
+/// Council signers are passed as remaining accounts and must hold a
+/// supermajority of the council's signer weight.
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    #[account(
+        mut,
+        seeds = [SECURITY_COUNCIL_SEED],
+        bump = security_council.bump,
+        has_one = council @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub security_council: Box<Account<'info, SecurityCouncil>>,
+
+    pub council: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, GovernanceProposal>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CouncilVeto::LEN,
+        seeds = [COUNCIL_VETO_SEED, proposal.key().as_ref()],
+        bump
+    )]
+    pub veto: Account<'info, CouncilVeto>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Vetoes the proposal, recording `rationale_hash` (the hash of the
+/// published reasoning) on-chain.
+pub fn veto_proposal(ctx: Context<VetoProposal>, rationale_hash: [u8; 32]) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let approval_weight = council.approval_weight(ctx.remaining_accounts);
+    require!(
+        approval_weight >= council_supermajority(council),
+        GhostSpeakError::InsufficientSigners
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    ctx.accounts
+        .security_council
+        .record_veto(proposal.key(), now)?;
+    proposal.veto(now)?;
+
+    let veto = &mut ctx.accounts.veto;
+    veto.proposal = proposal.key();
+    veto.council = council.key();
+    veto.rationale_hash = rationale_hash;
+    veto.vetoed_at = now;
+    veto.review_proposal = None;
+    veto.bump = ctx.bumps.veto;
+
+    emit!(ProposalVetoedEvent {
+        proposal: veto.proposal,
+        council: veto.council,
+        rationale_hash,
+        approval_weight: approval_weight as u16,
+        timestamp: now,
+    });
+
+    msg!(
+        "Proposal {} vetoed by the security council",
+        proposal.proposal_id
+    );
+    Ok(())
+}
+
+// =====================================================
+// VETO REVIEW
+// =====================================================
+
+/// Attach the community proposal reviewing a veto (permissionless)
+///
+/// The review must be a governance update proposal created after the veto.
+/// Filing it lets the council veto again.
+#[derive(Accounts)]
+pub struct FileVetoReview<'info> {
+    #[account(
+        mut,
+        seeds = [SECURITY_COUNCIL_SEED],
+        bump = security_council.bump
+    )]
+    pub security_council: Box<Account<'info, SecurityCouncil>>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_VETO_SEED, veto.proposal.as_ref()],
+        bump = veto.bump,
+        constraint = veto.review_proposal.is_none() @ GhostSpeakError::InvalidState
+    )]
+    pub veto: Account<'info, CouncilVeto>,
+
+    #[account(
+        seeds = [b"governance_proposal", review_proposal.proposal_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = review_proposal.proposal_type == ProposalType::GovernanceUpdate
+            && review_proposal.created_at >= veto.vetoed_at @ GhostSpeakError::InvalidVetoReview
+    )]
+    pub review_proposal: Box<Account<'info, GovernanceProposal>>,
+}
+
+pub fn file_veto_review(ctx: Context<FileVetoReview>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let veto = &mut ctx.accounts.veto;
+    let review_proposal = ctx.accounts.review_proposal.key();
+    veto.review_proposal = Some(review_proposal);
+    ctx.accounts
+        .security_council
+        .clear_review(veto.proposal, now);
+
+    emit!(VetoReviewFiledEvent {
+        vetoed_proposal: veto.proposal,
+        review_proposal,
+        timestamp: now,
+    });
+
+    msg!(
+        "Review proposal {} filed for vetoed proposal {}",
+        review_proposal,
+        veto.proposal
+    );
+    Ok(())
+}
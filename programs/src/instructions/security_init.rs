@@ -2,97 +2,90 @@
  * Security Initialization Instructions
  * 
  * Provides instructions for initializing security infrastructure
- * including the global reentrancy guard.
+ * including the global rate limiter and meta-transaction nonces.
  */
 
 use anchor_lang::prelude::*;
-use crate::security::{ReentrancyGuard, ReentrancyState};
+use crate::security::{
+    AdvanceMetaNonce, InitializeMetaNonce, InitializeRateLimiter, MetaNonceAdvancedEvent,
+    OperationLimit, OperationRateLimitSetEvent, RateLimitConfig, RateLimiter,
+    SetOperationRateLimit,
+};
 
-/// Account context for initializing the reentrancy guard
-#[derive(Accounts)]
-pub struct InitReentrancyGuard<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = ReentrancyGuard::LEN,
-        seeds = [b"reentrancy_guard"],
-        bump
-    )]
-    pub reentrancy_guard: Account<'info, ReentrancyGuard>,
+/// Initialize the global rate limiter PDA
+///
+/// Seeds the per-signer limits for service ratings, payment recording,
+/// authorized reputation updates, dispute filing and credential issuance;
+/// other operations fall back to `config.default_limit`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing the rate limiter account
+/// * `config` - Global window, penalty and burst settings
+pub fn init_rate_limiter(ctx: Context<InitializeRateLimiter>, config: RateLimitConfig) -> Result<()> {
+    require!(
+        config.default_limit > 0 && config.window_duration > 0 && config.penalty_duration >= 0,
+        crate::GhostSpeakError::InvalidConfiguration
+    );
+
+    let rate_limiter = &mut ctx.accounts.rate_limiter;
+    rate_limiter.initialize(ctx.accounts.authority.key(), config, ctx.bumps.rate_limiter)?;
+    rate_limiter.operation_limits = RateLimiter::default_operation_limits();
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    msg!("Rate limiter initialized");
+    msg!("Authority: {}", ctx.accounts.authority.key());
 
-    pub system_program: Program<'info, System>,
+    Ok(())
 }
 
-/// Initialize the global reentrancy guard PDA
-/// 
-/// This must be called once by a program admin before any 
-/// reentrancy-protected instructions can be used.
-/// 
+/// Set the per-signer limit for one operation
+///
 /// # Arguments
-/// 
-/// * `ctx` - The context containing the reentrancy guard account
-/// 
-/// # Returns
-/// 
-/// * `Ok(())` on successful initialization
-pub fn init_reentrancy_guard(ctx: Context<InitReentrancyGuard>) -> Result<()> {
-    let guard = &mut ctx.accounts.reentrancy_guard;
-    let clock = Clock::get()?;
-    
-    guard.state = ReentrancyState::Unlocked;
-    guard.nonce = 0;
-    guard.last_interaction = clock.unix_timestamp;
-    guard.authority = ctx.accounts.authority.key();
-    guard.bump = ctx.bumps.reentrancy_guard;
-    
-    msg!("Reentrancy guard initialized successfully");
-    msg!("Authority: {}", ctx.accounts.authority.key());
-    msg!("Bump: {}", ctx.bumps.reentrancy_guard);
-    
+///
+/// * `ctx` - The context containing the rate limiter account
+/// * `limit` - Operation key, requests per window, window (0 = global) and burst check
+pub fn set_operation_rate_limit(ctx: Context<SetOperationRateLimit>, limit: OperationLimit) -> Result<()> {
+    ctx.accounts.rate_limiter.set_operation_limit(limit.clone())?;
+
+    emit!(OperationRateLimitSetEvent {
+        operation: limit.operation.clone(),
+        limit: limit.limit,
+        window_duration: limit.window_duration,
+        allow_burst: limit.allow_burst,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    msg!(
+        "Rate limit for {} set to {} per {}s",
+        limit.operation,
+        limit.limit,
+        limit.window_duration
+    );
+
     Ok(())
 }
 
-/// Account context for resetting a stuck reentrancy guard
-#[derive(Accounts)]
-pub struct ResetReentrancyGuard<'info> {
-    #[account(
-        mut,
-        seeds = [b"reentrancy_guard"],
-        bump = reentrancy_guard.bump,
-        constraint = reentrancy_guard.authority == authority.key() @ crate::GhostSpeakError::UnauthorizedAccess
-    )]
-    pub reentrancy_guard: Account<'info, ReentrancyGuard>,
-
-    #[account(mut)]
-    pub authority: Signer<'info>,
+/// Create the replay-protection nonce account for an off-chain signer
+pub fn initialize_meta_nonce(ctx: Context<InitializeMetaNonce>) -> Result<()> {
+    let meta_nonce = &mut ctx.accounts.meta_nonce;
+    meta_nonce.signer = ctx.accounts.signer.key();
+    meta_nonce.last_nonce = 0;
+    meta_nonce.used = 0;
+    meta_nonce.updated_at = Clock::get()?.unix_timestamp;
+    meta_nonce.bump = ctx.bumps.meta_nonce;
+    Ok(())
 }
 
-/// Reset a stuck reentrancy guard
-/// 
-/// This can only be called by the original authority that initialized the guard.
-/// Use this to recover from stuck states after failed transactions.
-/// 
-/// # Security
-/// 
-/// - Only the original authority can reset the guard
-/// - This should only be used for recovery, not normal operations
-pub fn reset_reentrancy_guard(ctx: Context<ResetReentrancyGuard>) -> Result<()> {
-    let guard = &mut ctx.accounts.reentrancy_guard;
-    let clock = Clock::get()?;
-    
-    // Log the reset for audit purposes
-    msg!("Resetting reentrancy guard");
-    msg!("Previous state: {:?}", guard.state);
-    msg!("Reset by authority: {}", ctx.accounts.authority.key());
-    
-    guard.state = ReentrancyState::Unlocked;
-    guard.last_interaction = clock.unix_timestamp;
-    guard.nonce = guard.nonce.saturating_add(1); // Increment nonce to invalidate any pending operations
-    
-    msg!("Reentrancy guard reset successfully");
-    
+/// Skip a signer's nonce forward, revoking its outstanding signed payloads
+pub fn advance_meta_nonce(ctx: Context<AdvanceMetaNonce>, nonce: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let meta_nonce = &mut ctx.accounts.meta_nonce;
+    meta_nonce.advance(nonce, now)?;
+
+    emit!(MetaNonceAdvancedEvent {
+        signer: meta_nonce.signer,
+        last_nonce: nonce,
+        timestamp: now,
+    });
     Ok(())
 }
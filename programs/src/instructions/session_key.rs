@@ -0,0 +1,121 @@
+/*!
+ * Session Key Instructions
+ *
+ * Lets an agent owner authorize an ephemeral session key for automation,
+ * scoped to delivery submission and payment recording, and revoke it. The
+ * key is used by passing its `SessionKey` to `submit_delivery` or
+ * `record_payai_payment` and signing in the owner's place.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::session_key::*;
+use crate::state::Agent;
+use crate::GhostSpeakError;
+
+// =====================================================
+// CREATE SESSION KEY
+// =====================================================
+
+/// Authorize a session key for an agent (agent owner only)
+#[derive(Accounts)]
+#[instruction(signer: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    #[account(
+        constraint = agent.owner == Some(owner.key()) @ GhostSpeakError::InvalidAgentOwner
+    )]
+    pub agent: Account<'info, Agent>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SessionKey::LEN,
+        seeds = [SESSION_KEY_SEED, agent.key().as_ref(), signer.as_ref()],
+        bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_session_key(
+    ctx: Context<CreateSessionKey>,
+    signer: Pubkey,
+    scopes: u8,
+    expires_at: i64,
+    max_actions: u32,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    SessionKey::validate(&owner, &signer, scopes, expires_at, max_actions, now)?;
+
+    let session = &mut ctx.accounts.session_key;
+    session.agent = ctx.accounts.agent.key();
+    session.owner = owner;
+    session.signer = signer;
+    session.scopes = scopes;
+    session.expires_at = expires_at;
+    session.max_actions = max_actions;
+    session.actions_used = 0;
+    session.created_at = now;
+    session.last_used_at = 0;
+    session.bump = ctx.bumps.session_key;
+
+    emit!(SessionKeyCreatedEvent {
+        agent: session.agent,
+        owner,
+        signer,
+        scopes,
+        expires_at,
+        max_actions,
+    });
+
+    msg!(
+        "Session key {} authorized for agent {} until {}",
+        signer,
+        session.agent,
+        expires_at
+    );
+    Ok(())
+}
+
+// =====================================================
+// REVOKE SESSION KEY
+// =====================================================
+
+/// Revoke a session key and reclaim its rent (granting owner only)
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SESSION_KEY_SEED, session_key.agent.as_ref(), session_key.signer.as_ref()],
+        bump = session_key.bump,
+        has_one = owner @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+    let session = &ctx.accounts.session_key;
+
+    emit!(SessionKeyRevokedEvent {
+        agent: session.agent,
+        signer: session.signer,
+        actions_used: session.actions_used,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Session key {} revoked for agent {}",
+        session.signer,
+        session.agent
+    );
+    Ok(())
+}
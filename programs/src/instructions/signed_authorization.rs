@@ -0,0 +1,103 @@
+/*!
+ * Signed Authorization Instructions
+ *
+ * Verifies an Ed25519-signed `AuthorizationPayload` through instructions
+ * sysvar introspection and records it. The signer's `MetaNonce` is
+ * consumed, and the record lives at a PDA keyed by signer and nonce, so a
+ * signed payload can be recorded only once.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::security::{MetaNonce, META_NONCE_SEED};
+use crate::state::signed_authorization::*;
+use crate::utils::verified_ed25519_signature;
+use crate::GhostSpeakError;
+
+/// Record an off-chain signed authorization (anyone may submit and pay)
+#[derive(Accounts)]
+#[instruction(payload: AuthorizationPayload)]
+pub struct VerifySignedAuthorization<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = SignedAuthorization::LEN,
+        seeds = [
+            SIGNED_AUTHORIZATION_SEED,
+            signer.key().as_ref(),
+            &payload.nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub signed_authorization: Account<'info, SignedAuthorization>,
+
+    /// Signer's replay-protection nonce
+    #[account(
+        mut,
+        seeds = [META_NONCE_SEED, signer.key().as_ref()],
+        bump = meta_nonce.bump
+    )]
+    pub meta_nonce: Account<'info, MetaNonce>,
+
+    /// CHECK: Authorizing key; signs off-chain, verified through the Ed25519 instruction
+    pub signer: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, used to find the signer's Ed25519 instruction
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Verify the signer's signature over `payload`, consume its nonce and
+/// store the authorization
+pub fn verify_signed_authorization(
+    ctx: Context<VerifySignedAuthorization>,
+    payload: AuthorizationPayload,
+) -> Result<()> {
+    require!(
+        payload.action.target() != Pubkey::default(),
+        GhostSpeakError::InvalidSignedAuthorization
+    );
+
+    let signer = ctx.accounts.signer.key();
+    let message = SignedAuthorization::signing_message(&payload)?;
+    let signature =
+        verified_ed25519_signature(&ctx.accounts.instructions_sysvar, &signer, &message)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .meta_nonce
+        .consume(payload.nonce, payload.expires_at, now)?;
+
+    let record = &mut ctx.accounts.signed_authorization;
+    record.signer = signer;
+    record.action = payload.action;
+    record.nonce = payload.nonce;
+    record.expires_at = payload.expires_at;
+    record.signature = signature;
+    record.rent_payer = ctx.accounts.payer.key();
+    record.verified_at = now;
+    record.bump = ctx.bumps.signed_authorization;
+
+    emit!(SignedAuthorizationVerifiedEvent {
+        signer,
+        action: payload.action,
+        nonce: payload.nonce,
+        expires_at: payload.expires_at,
+        submitted_by: record.rent_payer,
+        timestamp: now,
+    });
+
+    msg!(
+        "Authorization {:?} verified for {} (nonce {})",
+        payload.action,
+        signer,
+        payload.nonce
+    );
+    Ok(())
+}
@@ -0,0 +1,188 @@
+/*!
+ * Stake Receipt Instructions
+ *
+ * Liquid staking receipts: a Token-2022 token minted one-for-one against
+ * staked GHOST so a position can be used as collateral elsewhere. The
+ * staking config PDA is the mint authority. Receipted stake can't be
+ * unstaked until the owner burns the receipts again.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, Burn, MintTo, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::state::staking::*;
+use crate::GhostSpeakError;
+
+// =====================================================
+// INITIALIZE RECEIPT MINT
+// =====================================================
+
+/// Create the stake receipt mint (staking authority only)
+#[derive(Accounts)]
+pub struct InitializeStakeReceiptMint<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Receipt mint, with the same decimals as GHOST
+    #[account(
+        init,
+        payer = authority,
+        seeds = [STAKE_RECEIPT_MINT_SEED],
+        bump,
+        mint::decimals = ghost_mint.decimals,
+        mint::authority = staking_config,
+        mint::token_program = token_program
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// GHOST token mint, pinned to the staking vault's mint
+    #[account(address = staking_vault.mint @ GhostSpeakError::InvalidInput)]
+    pub ghost_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_stake_receipt_mint(ctx: Context<InitializeStakeReceiptMint>) -> Result<()> {
+    let config = &mut ctx.accounts.staking_config;
+    config.receipt_mint = ctx.accounts.receipt_mint.key();
+    config.receipt_supply = 0;
+
+    emit!(StakeReceiptMintInitializedEvent {
+        authority: ctx.accounts.authority.key(),
+        receipt_mint: config.receipt_mint,
+        decimals: ctx.accounts.receipt_mint.decimals,
+    });
+
+    msg!("Stake receipt mint initialized: {}", config.receipt_mint);
+
+    Ok(())
+}
+
+// =====================================================
+// MINT / BURN RECEIPTS
+// =====================================================
+
+/// Accounts shared by minting and burning stake receipts (staker only)
+#[derive(Accounts)]
+pub struct StakeReceipt<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking", owner.key().as_ref()],
+        bump = staking_account.bump,
+        constraint = staking_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_account: Account<'info, StakingAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        address = staking_config.receipt_mint @ GhostSpeakError::InvalidInput
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_receipt_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Mints `amount` receipts against stake that isn't already receipted.
+pub fn mint_stake_receipt(ctx: Context<StakeReceipt>, amount: u64) -> Result<()> {
+    let config = &mut ctx.accounts.staking_config;
+    let staking = &mut ctx.accounts.staking_account;
+    config.mint_receipt(staking, amount)?;
+
+    let seeds = &[b"staking_config".as_ref(), &[config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.receipt_mint.to_account_info(),
+        to: ctx.accounts.owner_receipt_account.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_2022::mint_to(cpi_ctx, amount)?;
+
+    emit!(StakeReceiptMintedEvent {
+        owner: staking.owner,
+        amount,
+        receipt_amount: staking.receipt_amount,
+        receipt_supply: config.receipt_supply,
+    });
+
+    msg!(
+        "Minted {} stake receipts for owner: {} (receipted: {} of {})",
+        amount,
+        staking.owner,
+        staking.receipt_amount,
+        staking.amount_staked
+    );
+
+    Ok(())
+}
+
+/// Burns `amount` of the owner's receipts, freeing that much stake to be
+/// unstaked.
+pub fn burn_stake_receipt(ctx: Context<StakeReceipt>, amount: u64) -> Result<()> {
+    let config = &mut ctx.accounts.staking_config;
+    let staking = &mut ctx.accounts.staking_account;
+    config.burn_receipt(staking, amount)?;
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.receipt_mint.to_account_info(),
+        from: ctx.accounts.owner_receipt_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token_2022::burn(cpi_ctx, amount)?;
+
+    emit!(StakeReceiptBurnedEvent {
+        owner: staking.owner,
+        amount,
+        receipt_amount: staking.receipt_amount,
+        receipt_supply: config.receipt_supply,
+    });
+
+    msg!(
+        "Burned {} stake receipts for owner: {} (receipted: {} of {})",
+        amount,
+        staking.owner,
+        staking.receipt_amount,
+        staking.amount_staked
+    );
+
+    Ok(())
+}
@@ -5,8 +5,21 @@
  */
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::config_history::{
+    append_config_history, ConfigHistoryEntry, ConfigHistoryHead, ConfigKind,
+    CONFIG_HISTORY_ENTRY_SEED, CONFIG_HISTORY_HEAD_SEED,
+};
+use crate::state::governance::{Multisig, MultisigType};
+use crate::state::insurance_pool::{InsurancePool, INSURANCE_POOL_SEED};
+use crate::state::parameter_registry::{read_parameter, ParameterKey, PARAMETER_REGISTRY_SEED};
+use crate::security::{record_anomaly, AnomalyKind, CircuitBreaker};
+use crate::state::revenue_pool::{
+    RevenuePool, RevenuePosition, REVENUE_POOL_SEED, REVENUE_POSITION_SEED,
+};
 use crate::state::staking::*;
+use crate::utils::{read_legacy_layout, write_current_layout};
 use crate::GhostSpeakError;
 
 const THIRTY_DAYS: i64 = 30 * 24 * 60 * 60;
@@ -27,6 +40,14 @@ pub struct InitializeStakingConfig<'info> {
     )]
     pub staking_config: Account<'info, StakingConfig>,
 
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -47,12 +68,110 @@ pub fn initialize_staking_config(
     config.dispute_slash_bps = 1000; // 10%
     config.treasury = treasury;
     config.bump = ctx.bumps.staking_config;
+    config.version = StakingConfig::CURRENT_VERSION;
+    config.dispute_head_start_secs = StakingConfig::DEFAULT_DISPUTE_HEAD_START_SECS;
+    config.dispute_sla_secs = StakingConfig::DEFAULT_DISPUTE_SLA_SECS;
+    config.reward_rate = 0;
+    config.acc_reward_per_share = 0;
+    config.last_reward_update = Clock::get()?.unix_timestamp;
+    config.total_staked = 0;
+    config.reward_reserve = 0;
+    config.slash_review_multisig = Pubkey::default();
+    config.slash_appeal_window = StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW;
+    config.receipt_mint = Pubkey::default();
+    config.receipt_supply = 0;
+    config.tier_schedule = TierSchedule::default();
+    config.governance_multisig = Pubkey::default();
 
     msg!("Staking config initialized with min_stake: {}", min_stake);
 
     Ok(())
 }
 
+// =====================================================
+// UPDATE DISPUTE PRIORITY
+// =====================================================
+
+/// Update the per-tier dispute queue parameters (authority only)
+#[derive(Accounts)]
+pub struct UpdateDisputePriority<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_dispute_priority(
+    ctx: Context<UpdateDisputePriority>,
+    head_start_secs: [u32; AccessTier::COUNT],
+    sla_secs: [u32; AccessTier::COUNT],
+) -> Result<()> {
+    StakingConfig::validate_dispute_priority(&head_start_secs, &sla_secs)?;
+
+    let config = &mut ctx.accounts.staking_config;
+    let old_value = config.try_to_vec()?;
+
+    config.dispute_head_start_secs = head_start_secs;
+    config.dispute_sla_secs = sla_secs;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::StakingConfig,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(DisputePriorityUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        head_start_secs,
+        sla_secs,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Dispute priority updated - head starts: {:?}, SLAs: {:?}",
+        head_start_secs,
+        sla_secs
+    );
+    Ok(())
+}
+
 // =====================================================
 // STAKE GHOST TOKENS
 // =====================================================
@@ -91,6 +210,11 @@ pub struct StakeGhost<'info> {
     )]
     pub staking_vault: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
     pub staking_config: Account<'info, StakingConfig>,
 
     /// CHECK: GHOST token mint address
@@ -109,7 +233,7 @@ pub fn stake_ghost(
     amount: u64,
     lock_duration: i64,
 ) -> Result<()> {
-    let config = &ctx.accounts.staking_config;
+    let config = &mut ctx.accounts.staking_config;
     let staking = &mut ctx.accounts.staking_account;
     let clock = Clock::get()?;
 
@@ -129,23 +253,54 @@ pub fn stake_ghost(
     );
     token::transfer(cpi_ctx, amount)?;
 
+    record_stake(
+        config,
+        staking,
+        ctx.accounts.owner.key(),
+        amount,
+        lock_duration,
+        ctx.bumps.staking_account,
+        clock.unix_timestamp,
+    )?;
+
+    msg!("Staked {} GHOST for owner: {} (tier: {:?}, boost: {}bps, API calls/day: {}, voting power: {})",
+        amount, ctx.accounts.owner.key(), staking.tier,
+        staking.reputation_boost_bps, staking.get_daily_api_limit(), staking.voting_power);
+
+    Ok(())
+}
+
+/// Record a deposit that has already been transferred into the vault
+///
+/// Updates the staking account, recalculates the tier and emits the stake
+/// events. Shared by `stake_ghost` and `onboard_agent`.
+pub(crate) fn record_stake(
+    config: &mut StakingConfig,
+    staking: &mut StakingAccount,
+    owner: Pubkey,
+    amount: u64,
+    lock_duration: i64,
+    bump: u8,
+    now: i64,
+) -> Result<()> {
     // Track old tier for event emission
     let old_tier = staking.tier;
 
     // Update staking account
-    staking.owner = ctx.accounts.owner.key();
-    staking.amount_staked = staking.amount_staked.saturating_add(amount);
-    staking.staked_at = clock.unix_timestamp;
+    staking.owner = owner;
+    config.update_stake(staking, staking.amount_staked.saturating_add(amount), now)?;
+    staking.staked_at = now;
     staking.lock_duration = lock_duration;
-    staking.unlock_at = clock.unix_timestamp + lock_duration;
-    staking.calculate_boost(); // Sets tier, voting_power, api_calls_remaining
-    staking.last_quota_reset = clock.unix_timestamp; // Initialize quota timer
-    staking.bump = ctx.bumps.staking_account;
+    staking.unlock_at = now + lock_duration;
+    staking.calculate_boost(&config.tier_schedule); // Sets tier, voting_power, api_calls_remaining
+    staking.last_quota_reset = now; // Initialize quota timer
+    staking.bump = bump;
+    staking.version = StakingAccount::CURRENT_VERSION;
 
     // Emit tier update event if tier changed
     if old_tier != staking.tier {
         emit!(TierUpdatedEvent {
-            agent: ctx.accounts.owner.key(),
+            agent: owner,
             old_tier,
             new_tier: staking.tier,
             total_staked: staking.amount_staked,
@@ -155,7 +310,7 @@ pub fn stake_ghost(
     }
 
     emit!(GhostStakedEvent {
-        agent: ctx.accounts.owner.key(),
+        agent: owner,
         amount,
         unlock_at: staking.unlock_at,
         reputation_boost_bps: staking.reputation_boost_bps,
@@ -164,9 +319,49 @@ pub fn stake_ghost(
         voting_power: staking.voting_power,
     });
 
-    msg!("Staked {} GHOST for owner: {} (tier: {:?}, boost: {}bps, API calls/day: {}, voting power: {})",
-        amount, ctx.accounts.owner.key(), staking.tier,
-        staking.reputation_boost_bps, staking.get_daily_api_limit(), staking.voting_power);
+    Ok(())
+}
+
+// =====================================================
+// EXTEND LOCK
+// =====================================================
+
+/// Lengthen the lock on an existing stake
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking", owner.key().as_ref()],
+        bump = staking_account.bump,
+        constraint = staking_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_account: Account<'info, StakingAccount>,
+
+    /// Source of the lock multipliers
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn extend_lock(ctx: Context<ExtendLock>, lock_duration: i64) -> Result<()> {
+    let schedule = &ctx.accounts.staking_config.tier_schedule;
+    let staking = &mut ctx.accounts.staking_account;
+    let old_lock_duration = staking.lock_duration;
+
+    staking.extend_lock(lock_duration, Clock::get()?.unix_timestamp, schedule)?;
+
+    emit!(StakeLockExtendedEvent {
+        owner: staking.owner,
+        old_lock_duration,
+        lock_duration,
+        unlock_at: staking.unlock_at,
+        lock_multiplier_bps: staking.lock_multiplier_bps(schedule),
+        voting_power: staking.voting_power,
+    });
+
+    msg!("Extended lock for owner: {} until {} (voting power: {})",
+        staking.owner, staking.unlock_at, staking.voting_power);
 
     Ok(())
 }
@@ -175,7 +370,7 @@ pub fn stake_ghost(
 // UNSTAKE GHOST TOKENS
 // =====================================================
 
-/// Unstake GHOST tokens after lock period
+/// Unstake some or all GHOST tokens after lock period
 #[derive(Accounts)]
 pub struct UnstakeGhost<'info> {
     #[account(
@@ -186,9 +381,11 @@ pub struct UnstakeGhost<'info> {
     )]
     pub staking_account: Account<'info, StakingAccount>,
 
+    /// Owns the staking vault, so signs the withdrawal
     #[account(
+        mut,
         seeds = [b"staking_config"],
-        bump
+        bump = staking_config.bump
     )]
     pub staking_config: Account<'info, StakingConfig>,
 
@@ -211,7 +408,7 @@ pub struct UnstakeGhost<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn unstake_ghost(ctx: Context<UnstakeGhost>) -> Result<()> {
+pub fn unstake_ghost(ctx: Context<UnstakeGhost>, amount: u64) -> Result<()> {
     let staking = &mut ctx.accounts.staking_account;
     let clock = Clock::get()?;
 
@@ -220,22 +417,29 @@ pub fn unstake_ghost(ctx: Context<UnstakeGhost>) -> Result<()> {
         clock.unix_timestamp >= staking.unlock_at,
         GhostSpeakError::InvalidState
     );
+    require!(
+        amount <= staking.unreceipted_stake(),
+        GhostSpeakError::StakeReceiptOutstanding
+    );
+    require!(
+        amount <= staking.free_stake(),
+        GhostSpeakError::TrialBondReserved
+    );
 
-    let amount = staking.amount_staked;
+    let remaining = ctx
+        .accounts
+        .staking_config
+        .remaining_after_unstake(staking.amount_staked, amount)?;
 
-    // Transfer tokens back
+    // Transfer tokens back; the vault is owned by the staking config
     let owner_key = ctx.accounts.owner.key();
-    let seeds = &[
-        b"staking",
-        owner_key.as_ref(),
-        &[staking.bump]
-    ];
+    let seeds = &[b"staking_config".as_ref(), &[ctx.accounts.staking_config.bump]];
     let signer_seeds = &[&seeds[..]];
 
     let cpi_accounts = Transfer {
         from: ctx.accounts.staking_vault.to_account_info(),
         to: ctx.accounts.owner_token_account.to_account_info(),
-        authority: staking.to_account_info(),
+        authority: ctx.accounts.staking_config.to_account_info(),
     };
     let cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
@@ -244,21 +448,81 @@ pub fn unstake_ghost(ctx: Context<UnstakeGhost>) -> Result<()> {
     );
     token::transfer(cpi_ctx, amount)?;
 
-    // Reset staking
-    staking.amount_staked = 0;
-    staking.reputation_boost_bps = 0;
-    staking.has_verified_badge = false;
-    staking.has_premium_benefits = false;
-    staking.tier = AccessTier::None;
-    staking.api_calls_remaining = 0;
-    staking.voting_power = 0;
+    // Track old tier and today's usage for the recalculation
+    let old_tier = staking.tier;
+    let api_calls_remaining = staking.api_calls_remaining;
+
+    // Update staking (earned rewards stay claimable)
+    ctx.accounts
+        .staking_config
+        .update_stake(staking, remaining, clock.unix_timestamp)?;
+    staking.calculate_boost(&ctx.accounts.staking_config.tier_schedule);
+    // Dropping a tier must not refill the day's quota
+    staking.api_calls_remaining = staking.api_calls_remaining.min(api_calls_remaining);
+
+    if old_tier != staking.tier {
+        emit!(TierUpdatedEvent {
+            agent: owner_key,
+            old_tier,
+            new_tier: staking.tier,
+            total_staked: staking.amount_staked,
+            daily_api_calls: staking.get_daily_api_limit(),
+            voting_power: staking.voting_power,
+        });
+    }
 
     emit!(GhostUnstakedEvent {
         agent: owner_key,
         amount,
+        remaining_stake: remaining,
+    });
+
+    msg!("Unstaked {} GHOST for owner: {} (remaining: {}, tier: {:?})",
+        amount, owner_key, remaining, staking.tier);
+
+    Ok(())
+}
+
+// =====================================================
+// CLOSE STAKING ACCOUNT
+// =====================================================
+
+/// Close a fully unstaked staking account and reclaim its rent (owner only)
+#[derive(Accounts)]
+pub struct CloseStakingAccount<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking", owner.key().as_ref()],
+        bump = staking_account.bump,
+        constraint = staking_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = staking_account.is_closable() @ GhostSpeakError::StakingAccountNotEmpty,
+        close = owner
+    )]
+    pub staking_account: Account<'info, StakingAccount>,
+
+    /// CHECK: Owner's slash record PDA; must not exist, since an upheld
+    /// appeal refunds into the staking account
+    #[account(
+        seeds = [SLASH_RECORD_SEED, owner.key().as_ref()],
+        bump,
+        constraint = slash_record.data_is_empty() @ GhostSpeakError::StakingAccountNotEmpty
+    )]
+    pub slash_record: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn close_staking_account(ctx: Context<CloseStakingAccount>) -> Result<()> {
+    let staking = &ctx.accounts.staking_account;
+
+    emit!(StakingAccountClosedEvent {
+        owner: staking.owner,
+        rewards_claimed: staking.rewards_claimed,
+        total_slashed: staking.total_slashed,
     });
 
-    msg!("Unstaked {} GHOST for owner: {}", amount, owner_key);
+    msg!("Staking account closed for owner: {}", staking.owner);
 
     Ok(())
 }
@@ -267,7 +531,7 @@ pub fn unstake_ghost(ctx: Context<UnstakeGhost>) -> Result<()> {
 // SLASH STAKED TOKENS
 // =====================================================
 
-/// Slash staked tokens (admin only, for fraud/disputes)
+/// Slash staked tokens into an appealable escrow (admin only, for fraud/disputes)
 #[derive(Accounts)]
 #[instruction(owner: Pubkey)]
 pub struct SlashStake<'info> {
@@ -276,27 +540,64 @@ pub struct SlashStake<'info> {
         seeds = [b"staking", owner.as_ref()],
         bump = staking_account.bump
     )]
-    pub staking_account: Account<'info, StakingAccount>,
+    pub staking_account: Box<Account<'info, StakingAccount>>,
 
     #[account(
+        mut,
         seeds = [b"staking_config"],
         bump,
         constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
     )]
-    pub staking_config: Account<'info, StakingConfig>,
+    pub staking_config: Box<Account<'info, StakingConfig>>,
 
     #[account(
         mut,
         seeds = [b"staking_vault", staking_config.key().as_ref()],
         bump
     )]
-    pub staking_vault: Account<'info, TokenAccount>,
+    pub staking_vault: Box<Account<'info, TokenAccount>>,
 
-    #[account(mut)]
-    pub treasury: Account<'info, TokenAccount>,
+    /// Appeal record; `init` allows one open slash per owner
+    #[account(
+        init,
+        payer = authority,
+        space = SlashRecord::LEN,
+        seeds = [SLASH_RECORD_SEED, owner.as_ref()],
+        bump
+    )]
+    pub slash_record: Box<Account<'info, SlashRecord>>,
+
+    /// Holds the slashed tokens until the slash is finalized
+    #[account(
+        init,
+        payer = authority,
+        token::mint = ghost_mint,
+        token::authority = staking_config,
+        seeds = [SLASH_ESCROW_SEED, slash_record.key().as_ref()],
+        bump
+    )]
+    pub slash_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: GHOST token mint, pinned to the staking vault's mint
+    #[account(address = staking_vault.mint @ GhostSpeakError::InvalidInput)]
+    pub ghost_mint: AccountInfo<'info>,
+
+    /// Circuit breaker; slashing is refused while paused and counts toward
+    /// its slash threshold
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker"],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub circuit_breaker: Box<Account<'info, CircuitBreaker>>,
 
+    #[account(mut)]
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn slash_stake(
@@ -305,39 +606,23 @@ pub fn slash_stake(
     reason: SlashReason,
     custom_amount: Option<u64>,
 ) -> Result<()> {
+    ctx.accounts.circuit_breaker.check_not_paused()?;
     let staking = &mut ctx.accounts.staking_account;
-    let config = &ctx.accounts.staking_config;
+    let config = &mut ctx.accounts.staking_config;
+    let now = Clock::get()?.unix_timestamp;
 
     // Calculate slash amount
-    let slash_bps = match reason {
-        SlashReason::Fraud => config.fraud_slash_bps,
-        SlashReason::DisputeLoss => config.dispute_slash_bps,
-        SlashReason::Custom => {
-            require!(custom_amount.is_some(), GhostSpeakError::InvalidInput);
-            0 // Will use custom_amount
-        }
-    };
-
-    let slash_amount = if let Some(custom) = custom_amount {
-        custom
-    } else {
-        (staking.amount_staked as u128 * slash_bps as u128 / 10000) as u64
-    };
-
-    require!(slash_amount <= staking.amount_staked, GhostSpeakError::InvalidAmount);
+    let slash_amount =
+        config.calculate_slash_amount(staking.amount_staked, reason, custom_amount)?;
 
-    // Transfer slashed tokens to treasury
-    let seeds = &[
-        b"staking",
-        owner.as_ref(),
-        &[staking.bump]
-    ];
+    // Move slashed tokens into escrow (the vault is owned by the config PDA)
+    let seeds = &[b"staking_config".as_ref(), &[config.bump]];
     let signer_seeds = &[&seeds[..]];
 
     let cpi_accounts = Transfer {
         from: ctx.accounts.staking_vault.to_account_info(),
-        to: ctx.accounts.treasury.to_account_info(),
-        authority: staking.to_account_info(),
+        to: ctx.accounts.slash_escrow.to_account_info(),
+        authority: config.to_account_info(),
     };
     let cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
@@ -349,10 +634,25 @@ pub fn slash_stake(
     // Track old tier for event emission
     let old_tier = staking.tier;
 
-    // Update staking account
-    staking.amount_staked = staking.amount_staked.saturating_sub(slash_amount);
+    // Update staking account (rewards earned before the slash are kept)
+    let remaining = staking.amount_staked.saturating_sub(slash_amount);
+    config.update_stake(staking, remaining, now)?;
     staking.total_slashed = staking.total_slashed.saturating_add(slash_amount);
-    staking.calculate_boost(); // Recalculate benefits
+    staking.calculate_boost(&config.tier_schedule); // Recalculate benefits
+
+    let record = &mut ctx.accounts.slash_record;
+    record.owner = owner;
+    record.slasher = ctx.accounts.authority.key();
+    record.reason = reason;
+    record.amount = slash_amount;
+    record.refunded = 0;
+    record.slashed_at = now;
+    record.appeal_deadline = now
+        .checked_add(config.slash_appeal_window)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    record.status = SlashStatus::Pending;
+    record.appeal_uri = String::new();
+    record.bump = ctx.bumps.slash_record;
 
     // Emit tier update event if tier changed due to slash
     if old_tier != staking.tier {
@@ -371,10 +671,1389 @@ pub fn slash_stake(
         amount: slash_amount,
         reason,
         new_tier: staking.tier,
+        appeal_deadline: record.appeal_deadline,
+    });
+
+    record_anomaly(&mut ctx.accounts.circuit_breaker, AnomalyKind::SlashExecuted, now)?;
+
+    msg!("Slashed {} GHOST from owner: {} (reason: {:?}, new tier: {:?}, appealable until {})",
+        slash_amount, owner, reason, staking.tier, record.appeal_deadline);
+
+    Ok(())
+}
+
+// =====================================================
+// SLASH APPEALS
+// =====================================================
+
+/// Set who reviews slash appeals and how long owners have to appeal (authority only)
+#[derive(Accounts)]
+pub struct UpdateSlashAppealConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Protocol or Dispute multisig that will review appeals
+    pub review_multisig: Box<Account<'info, Multisig>>,
+
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_slash_appeal_config(
+    ctx: Context<UpdateSlashAppealConfig>,
+    appeal_window: i64,
+) -> Result<()> {
+    require!(
+        matches!(
+            ctx.accounts.review_multisig.multisig_type,
+            MultisigType::Protocol | MultisigType::Dispute
+        ),
+        GhostSpeakError::InvalidSlashReviewMultisig
+    );
+    require!(
+        (1..=StakingConfig::MAX_SLASH_APPEAL_WINDOW).contains(&appeal_window),
+        GhostSpeakError::InvalidConfiguration
+    );
+
+    let config = &mut ctx.accounts.staking_config;
+    let old_value = config.try_to_vec()?;
+
+    config.slash_review_multisig = ctx.accounts.review_multisig.key();
+    config.slash_appeal_window = appeal_window;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::StakingConfig,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(SlashAppealConfigUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        review_multisig: config.slash_review_multisig,
+        appeal_window,
+        timestamp: Clock::get()?.unix_timestamp,
     });
 
-    msg!("Slashed {} GHOST from owner: {} (reason: {:?}, new tier: {:?})",
-        slash_amount, owner, reason, staking.tier);
+    msg!(
+        "Slash appeals reviewed by {} within {}s",
+        config.slash_review_multisig,
+        appeal_window
+    );
+    Ok(())
+}
+
+/// Contest a slash within its appeal window (slashed owner only)
+#[derive(Accounts)]
+pub struct AppealSlash<'info> {
+    #[account(
+        mut,
+        seeds = [SLASH_RECORD_SEED, owner.key().as_ref()],
+        bump = slash_record.bump,
+        has_one = owner @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.slash_review_multisig != Pubkey::default() @ GhostSpeakError::InvalidSlashReviewMultisig
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn appeal_slash(ctx: Context<AppealSlash>, appeal_uri: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.slash_record;
+    record.appeal(appeal_uri, now)?;
+
+    emit!(SlashAppealedEvent {
+        owner: record.owner,
+        amount: record.amount,
+        appeal_uri: record.appeal_uri.clone(),
+        timestamp: now,
+    });
+
+    msg!("Slash of {} GHOST appealed by owner: {}", record.amount, record.owner);
+    Ok(())
+}
+
+/// Rule on an appealed slash (review multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ReviewSlashAppeal<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.slash_review_multisig == review_multisig.key() @ GhostSpeakError::InvalidSlashReviewMultisig
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    pub review_multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [SLASH_RECORD_SEED, slash_record.owner.as_ref()],
+        bump = slash_record.bump
+    )]
+    pub slash_record: Box<Account<'info, SlashRecord>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking", slash_record.owner.as_ref()],
+        bump = staking_account.bump
+    )]
+    pub staking_account: Box<Account<'info, StakingAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SLASH_ESCROW_SEED, slash_record.key().as_ref()],
+        bump
+    )]
+    pub slash_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Returns `refund_amount` of the slash to the owner's stake: the full
+/// amount reverses the slash, less reduces it, zero upholds it.
+pub fn review_slash_appeal(ctx: Context<ReviewSlashAppeal>, refund_amount: u64) -> Result<()> {
+    ctx.accounts
+        .review_multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.slash_record;
+    record.review(refund_amount)?;
+
+    if refund_amount > 0 {
+        let config = &mut ctx.accounts.staking_config;
+        let seeds = &[b"staking_config".as_ref(), &[config.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.slash_escrow.to_account_info(),
+            to: ctx.accounts.staking_vault.to_account_info(),
+            authority: config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds
+        );
+        token::transfer(cpi_ctx, refund_amount)?;
+
+        // Restore the refunded stake, keeping today's quota usage
+        let staking = &mut ctx.accounts.staking_account;
+        let old_tier = staking.tier;
+        let api_calls_remaining = staking.api_calls_remaining;
+        let restored = staking
+            .amount_staked
+            .checked_add(refund_amount)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        config.update_stake(staking, restored, now)?;
+        staking.total_slashed = staking.total_slashed.saturating_sub(refund_amount);
+        staking.calculate_boost(&config.tier_schedule);
+        if old_tier == staking.tier {
+            staking.api_calls_remaining = api_calls_remaining;
+        } else {
+            emit!(TierUpdatedEvent {
+                agent: staking.owner,
+                old_tier,
+                new_tier: staking.tier,
+                total_staked: staking.amount_staked,
+                daily_api_calls: staking.get_daily_api_limit(),
+                voting_power: staking.voting_power,
+            });
+        }
+    }
+
+    emit!(SlashAppealReviewedEvent {
+        owner: record.owner,
+        multisig: ctx.accounts.review_multisig.key(),
+        amount: record.amount,
+        refunded: refund_amount,
+        timestamp: now,
+    });
+
+    msg!("Slash appeal for owner: {} reviewed, {} of {} GHOST refunded",
+        record.owner, refund_amount, record.amount);
+    Ok(())
+}
+
+/// Send the forfeited part of a final slash to the insurance pool (permissionless)
+#[derive(Accounts)]
+pub struct FinalizeSlash<'info> {
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        seeds = [SLASH_RECORD_SEED, slash_record.owner.as_ref()],
+        bump = slash_record.bump,
+        has_one = slasher,
+        close = slasher
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+
+    #[account(
+        mut,
+        seeds = [SLASH_ESCROW_SEED, slash_record.key().as_ref()],
+        bump
+    )]
+    pub slash_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(mut, address = insurance_pool.vault)]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Slasher recorded on the slash record, receives the rent refunds
+    #[account(mut)]
+    pub slasher: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn finalize_slash(ctx: Context<FinalizeSlash>) -> Result<()> {
+    let record = &ctx.accounts.slash_record;
+    require!(
+        record.is_final(Clock::get()?.unix_timestamp),
+        GhostSpeakError::SlashAppealOpen
+    );
+
+    let config = &ctx.accounts.staking_config;
+    let seeds = &[b"staking_config".as_ref(), &[config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Sweep the whole balance (normally `record.forfeited()`) so stray
+    // deposits can't block closing the escrow
+    let forfeited = ctx.accounts.slash_escrow.amount;
+    if forfeited > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.slash_escrow.to_account_info(),
+            to: ctx.accounts.insurance_vault.to_account_info(),
+            authority: config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds
+        );
+        token::transfer(cpi_ctx, forfeited)?;
+        ctx.accounts.insurance_pool.record_deposit(forfeited);
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.slash_escrow.to_account_info(),
+        destination: ctx.accounts.slasher.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds
+    );
+    token::close_account(cpi_ctx)?;
+
+    emit!(SlashFinalizedEvent {
+        owner: record.owner,
+        forfeited,
+        refunded: record.refunded,
+        insurance_vault: ctx.accounts.insurance_vault.key(),
+    });
+
+    msg!("Slash of owner: {} finalized, {} GHOST to insurance pool", record.owner, forfeited);
+    Ok(())
+}
+
+// =====================================================
+// SIMULATE SLASH (DRY RUN)
+// =====================================================
+
+/// Read-only preview of a slash (no state changes)
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct SimulateSlashStake<'info> {
+    #[account(
+        seeds = [b"staking", owner.as_ref()],
+        bump = staking_account.bump
+    )]
+    pub staking_account: Account<'info, StakingAccount>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Compute the effects of `slash_stake` without transferring tokens or
+/// mutating the staking account. The preview is returned via return data.
+pub fn simulate_slash_stake(
+    ctx: Context<SimulateSlashStake>,
+    owner: Pubkey,
+    reason: SlashReason,
+    custom_amount: Option<u64>,
+) -> Result<SlashPreview> {
+    let staking = &ctx.accounts.staking_account;
+    let config = &ctx.accounts.staking_config;
+
+    let slash_amount =
+        config.calculate_slash_amount(staking.amount_staked, reason, custom_amount)?;
+
+    // Project the post-slash account on a copy
+    let mut projected = (**staking).clone();
+    projected.amount_staked = projected.amount_staked.saturating_sub(slash_amount);
+    projected.total_slashed = projected.total_slashed.saturating_add(slash_amount);
+    projected.calculate_boost(&config.tier_schedule);
+
+    msg!("Dry run: slashing {} GHOST from owner: {} (reason: {:?}, new tier: {:?})",
+        slash_amount, owner, reason, projected.tier);
+
+    Ok(SlashPreview {
+        owner,
+        reason,
+        slash_amount,
+        remaining_stake: projected.amount_staked,
+        total_slashed_after: projected.total_slashed,
+        old_tier: staking.tier,
+        new_tier: projected.tier,
+        new_reputation_boost_bps: projected.reputation_boost_bps,
+        new_voting_power: projected.voting_power,
+    })
+}
+
+// =====================================================
+// FUND STAKING REWARDS
+// =====================================================
+
+/// Deposit GHOST into the staking reward pool (treasury or fee sweeps)
+#[derive(Accounts)]
+pub struct FundStakingRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Staked principal; fixes the reward mint to the staking mint
+    #[account(
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Account<'info, TokenAccount>,
+
+    /// Reward pool, kept apart from staked principal
+    #[account(
+        init_if_needed,
+        payer = funder,
+        token::mint = ghost_mint,
+        token::authority = staking_config,
+        seeds = [b"staking_reward_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == funder.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = funder_token_account.mint == ghost_mint.key() @ GhostSpeakError::InvalidInput
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: GHOST token mint, pinned to the staking vault's mint
+    #[account(address = staking_vault.mint @ GhostSpeakError::InvalidInput)]
+    pub ghost_mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn fund_staking_rewards(ctx: Context<FundStakingRewards>, amount: u64) -> Result<()> {
+    require!(amount > 0, GhostSpeakError::InvalidAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.staking_reward_vault.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // Release what was owed under the old reserve before topping it up
+    let config = &mut ctx.accounts.staking_config;
+    config.accrue_rewards(Clock::get()?.unix_timestamp)?;
+    config.reward_reserve = config
+        .reward_reserve
+        .checked_add(amount)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    emit!(StakingRewardsFundedEvent {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        reward_reserve: config.reward_reserve,
+    });
+
+    msg!(
+        "Funded {} GHOST of staking rewards (reserve: {})",
+        amount,
+        config.reward_reserve
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// SET STAKING REWARD RATE
+// =====================================================
+
+/// Set the per-second staking reward rate (authority only)
+#[derive(Accounts)]
+pub struct SetStakingRewardRate<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_staking_reward_rate(ctx: Context<SetStakingRewardRate>, reward_rate: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.staking_config;
+
+    // Time before the change is paid at the old rate
+    config.accrue_rewards(now)?;
+    let old_value = config.try_to_vec()?;
+    let old_rate = config.reward_rate;
+    config.reward_rate = reward_rate;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::StakingConfig,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(StakingRewardRateUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        old_rate,
+        new_rate: reward_rate,
+        timestamp: now,
+    });
+
+    msg!(
+        "Staking reward rate updated from {} to {} per second",
+        old_rate,
+        reward_rate
+    );
+    Ok(())
+}
+
+// =====================================================
+// CLAIM STAKING REWARDS
+// =====================================================
+
+/// Withdraw accrued staking rewards
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking", owner.key().as_ref()],
+        bump = staking_account.bump,
+        constraint = staking_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_account: Account<'info, StakingAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_reward_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess,
+        constraint = owner_token_account.mint == staking_reward_vault.mint @ GhostSpeakError::InvalidInput
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+    let config = &mut ctx.accounts.staking_config;
+    let staking = &mut ctx.accounts.staking_account;
+
+    config.accrue_rewards(Clock::get()?.unix_timestamp)?;
+    staking.settle_rewards(config.acc_reward_per_share)?;
+
+    let amount = staking.pending_rewards;
+    require!(amount > 0, GhostSpeakError::NoStakingRewards);
+
+    let seeds = &[b"staking_config".as_ref(), &[config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.staking_reward_vault.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    staking.pending_rewards = 0;
+    staking.rewards_claimed = staking.rewards_claimed.saturating_add(amount);
+
+    emit!(StakingRewardsClaimedEvent {
+        owner: staking.owner,
+        amount,
+        rewards_claimed: staking.rewards_claimed,
+    });
+
+    msg!(
+        "Claimed {} GHOST of staking rewards for owner: {}",
+        amount,
+        staking.owner
+    );
+
+    Ok(())
+}
+
+// =====================================================
+// COMPOUND STAKING REWARDS
+// =====================================================
+
+/// Restake accrued staking rewards as principal
+#[derive(Accounts)]
+pub struct CompoundRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking", owner.key().as_ref()],
+        bump = staking_account.bump,
+        constraint = staking_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_account: Box<Account<'info, StakingAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_reward_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_vault", staking_config.key().as_ref()],
+        bump
+    )]
+    pub staking_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Revenue pool, to re-weigh the owner's revenue share in the same step
+    #[account(mut, seeds = [REVENUE_POOL_SEED], bump = revenue_pool.bump)]
+    pub revenue_pool: Option<Box<Account<'info, RevenuePool>>>,
+
+    /// Owner's revenue position (re-weighed when passed with the pool)
+    #[account(
+        mut,
+        seeds = [REVENUE_POSITION_SEED, owner.key().as_ref()],
+        bump = revenue_position.bump
+    )]
+    pub revenue_position: Option<Box<Account<'info, RevenuePosition>>>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn compound_rewards(ctx: Context<CompoundRewards>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.staking_config;
+    let staking = &mut ctx.accounts.staking_account;
+
+    config.accrue_rewards(now)?;
+    staking.settle_rewards(config.acc_reward_per_share)?;
+
+    let amount = staking.pending_rewards;
+    require!(amount > 0, GhostSpeakError::NoStakingRewards);
+
+    // Move the rewards from the reward pool into staked principal
+    let seeds = &[b"staking_config".as_ref(), &[config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.staking_reward_vault.to_account_info(),
+        to: ctx.accounts.staking_vault.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    staking.pending_rewards = 0;
+    staking.rewards_claimed = staking.rewards_claimed.saturating_add(amount);
+
+    // Restake, keeping the lock and today's quota usage
+    let old_tier = staking.tier;
+    let api_calls_remaining = staking.api_calls_remaining;
+    let compounded = staking
+        .amount_staked
+        .checked_add(amount)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    config.update_stake(staking, compounded, now)?;
+    staking.calculate_boost(&config.tier_schedule);
+    if old_tier == staking.tier {
+        staking.api_calls_remaining = api_calls_remaining;
+    } else {
+        emit!(TierUpdatedEvent {
+            agent: staking.owner,
+            old_tier,
+            new_tier: staking.tier,
+            total_staked: staking.amount_staked,
+            daily_api_calls: staking.get_daily_api_limit(),
+            voting_power: staking.voting_power,
+        });
+    }
+
+    if let (Some(pool), Some(position)) = (
+        ctx.accounts.revenue_pool.as_mut(),
+        ctx.accounts.revenue_position.as_mut(),
+    ) {
+        position.last_synced_at = now;
+        pool.sync_position(position, Some(staking))?;
+    }
+
+    emit!(StakingRewardsCompoundedEvent {
+        owner: staking.owner,
+        amount,
+        amount_staked: staking.amount_staked,
+        tier: staking.tier,
+        voting_power: staking.voting_power,
+    });
+
+    msg!("Compounded {} GHOST of rewards for owner: {} (staked: {}, tier: {:?})",
+        amount, staking.owner, staking.amount_staked, staking.tier);
+
+    Ok(())
+}
+
+// =====================================================
+// STAKE MIGRATION
+// =====================================================
+
+/// Offer the stake to a new owner key (current owner only)
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct ProposeStakeMigration<'info> {
+    #[account(
+        seeds = [b"staking", owner.key().as_ref()],
+        bump = staking_account.bump,
+        constraint = staking_account.owner == owner.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub staking_account: Account<'info, StakingAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = StakeMigration::LEN,
+        seeds = [STAKE_MIGRATION_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub stake_migration: Account<'info, StakeMigration>,
+
+    /// CHECK: Parameter registry PDA, read for the acceptance window; defaults apply until
+    /// it is initialized
+    #[account(seeds = [PARAMETER_REGISTRY_SEED], bump)]
+    pub parameter_registry: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_stake_migration(
+    ctx: Context<ProposeStakeMigration>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require!(
+        new_owner != owner && new_owner != Pubkey::default(),
+        GhostSpeakError::InvalidInput
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let migration = &mut ctx.accounts.stake_migration;
+    migration.owner = owner;
+    migration.new_owner = new_owner;
+    migration.proposed_at = now;
+    let window = read_parameter(
+        &ctx.accounts.parameter_registry,
+        ParameterKey::StakeMigrationWindow,
+    )?;
+    migration.expires_at = now + window as i64;
+    migration.bump = ctx.bumps.stake_migration;
+
+    emit!(StakeMigrationProposedEvent {
+        owner,
+        new_owner,
+        expires_at: migration.expires_at,
+    });
+
+    msg!("Stake migration from {} to {} proposed", owner, new_owner);
+
+    Ok(())
+}
+
+/// Take over an offered stake (new owner only)
+///
+/// The old staking account is closed and recreated under the new owner's
+/// key. Pass both revenue positions to carry the owner's revenue share
+/// across as well.
+#[derive(Accounts)]
+pub struct AcceptStakeMigration<'info> {
+    #[account(
+        mut,
+        seeds = [STAKE_MIGRATION_SEED, old_owner.key().as_ref()],
+        bump = stake_migration.bump,
+        has_one = new_owner @ GhostSpeakError::UnauthorizedAccess,
+        constraint = stake_migration.owner == old_owner.key() @ GhostSpeakError::UnauthorizedAccess,
+        close = old_owner
+    )]
+    pub stake_migration: Box<Account<'info, StakeMigration>>,
+
+    /// Stake bonded to open trial escrows can't move until they settle
+    #[account(
+        mut,
+        seeds = [b"staking", old_owner.key().as_ref()],
+        bump = old_staking_account.bump,
+        constraint = old_staking_account.bonded_amount == 0 @ GhostSpeakError::TrialBondReserved,
+        close = old_owner
+    )]
+    pub old_staking_account: Box<Account<'info, StakingAccount>>,
+
+    /// `init` refuses a new owner who already has a stake
+    #[account(
+        init,
+        payer = new_owner,
+        space = StakingAccount::LEN,
+        seeds = [b"staking", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_staking_account: Box<Account<'info, StakingAccount>>,
+
+    /// CHECK: Old owner's slash record PDA; must not exist, so an open
+    /// slash can't be escaped by moving the stake
+    #[account(
+        seeds = [SLASH_RECORD_SEED, old_owner.key().as_ref()],
+        bump,
+        constraint = old_slash_record.data_is_empty() @ GhostSpeakError::StakeMigrationBlocked
+    )]
+    pub old_slash_record: UncheckedAccount<'info>,
+
+    /// Old owner's revenue position, closed once carried across
+    #[account(
+        mut,
+        seeds = [REVENUE_POSITION_SEED, old_owner.key().as_ref()],
+        bump = old_revenue_position.bump,
+        close = old_owner
+    )]
+    pub old_revenue_position: Option<Box<Account<'info, RevenuePosition>>>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = RevenuePosition::LEN,
+        seeds = [REVENUE_POSITION_SEED, new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_revenue_position: Option<Box<Account<'info, RevenuePosition>>>,
+
+    /// CHECK: Proposing owner recorded on the migration, receives the rent refunds
+    #[account(mut)]
+    pub old_owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn accept_stake_migration(ctx: Context<AcceptStakeMigration>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now <= ctx.accounts.stake_migration.expires_at,
+        GhostSpeakError::StakeMigrationExpired
+    );
+
+    let old_owner = ctx.accounts.old_owner.key();
+    let new_owner = ctx.accounts.new_owner.key();
+
+    // Same stake, lock and reward history under the new key, held from
+    // now on for voting snapshots
+    let migrated = ctx.accounts.old_staking_account.migrated_to(
+        new_owner,
+        ctx.bumps.new_staking_account,
+        now,
+    );
+    ctx.accounts.new_staking_account.set_inner(migrated);
+
+    match (
+        ctx.accounts.old_revenue_position.as_ref(),
+        ctx.accounts.new_revenue_position.as_mut(),
+    ) {
+        (Some(old_position), Some(new_position)) => {
+            let mut position = (***old_position).clone();
+            position.owner = new_owner;
+            position.bump = ctx
+                .bumps
+                .new_revenue_position
+                .ok_or(GhostSpeakError::InvalidInput)?;
+            new_position.set_inner(position);
+        }
+        (None, None) => {}
+        _ => return err!(GhostSpeakError::InvalidInput),
+    }
+
+    let staking = &ctx.accounts.new_staking_account;
+    emit!(StakeMigratedEvent {
+        old_owner,
+        new_owner,
+        amount_staked: staking.amount_staked,
+        unlock_at: staking.unlock_at,
+        tier: staking.tier,
+    });
+
+    msg!("Stake of {} GHOST migrated from {} to {}",
+        staking.amount_staked, old_owner, new_owner);
+
+    Ok(())
+}
+
+/// Withdraw a pending stake migration offer (current owner only)
+#[derive(Accounts)]
+pub struct CancelStakeMigration<'info> {
+    #[account(
+        mut,
+        seeds = [STAKE_MIGRATION_SEED, owner.key().as_ref()],
+        bump = stake_migration.bump,
+        has_one = owner @ GhostSpeakError::UnauthorizedAccess,
+        close = owner
+    )]
+    pub stake_migration: Account<'info, StakeMigration>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn cancel_stake_migration(ctx: Context<CancelStakeMigration>) -> Result<()> {
+    let migration = &ctx.accounts.stake_migration;
+
+    emit!(StakeMigrationCancelledEvent {
+        owner: migration.owner,
+        new_owner: migration.new_owner,
+    });
+
+    msg!("Stake migration from {} to {} cancelled",
+        migration.owner, migration.new_owner);
+
+    Ok(())
+}
+
+// =====================================================
+// STAKING GOVERNANCE
+// =====================================================
+
+/// Hand staking parameter updates over to a Protocol multisig (authority only, once)
+#[derive(Accounts)]
+pub struct SetStakingGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Protocol multisig that will approve parameter updates
+    pub governance_multisig: Box<Account<'info, Multisig>>,
+
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Account<'info, ConfigHistoryHead>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Account<'info, ConfigHistoryEntry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_staking_governance(ctx: Context<SetStakingGovernance>) -> Result<()> {
+    require!(
+        ctx.accounts.governance_multisig.multisig_type == MultisigType::Protocol,
+        GhostSpeakError::InvalidStakingGovernanceMultisig
+    );
+
+    let config = &mut ctx.accounts.staking_config;
+    require!(
+        config.governance_multisig == Pubkey::default(),
+        GhostSpeakError::InvalidState
+    );
+
+    let old_value = config.try_to_vec()?;
+    config.governance_multisig = ctx.accounts.governance_multisig.key();
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::StakingConfig,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        ctx.accounts.authority.key(),
+    )?;
+
+    emit!(StakingGovernanceSetEvent {
+        authority: ctx.accounts.authority.key(),
+        governance_multisig: config.governance_multisig,
+    });
+
+    msg!("Staking parameters now governed by multisig: {}", config.governance_multisig);
+
+    Ok(())
+}
+
+/// Queue a staking parameter update behind the multisig's timelock
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct ProposeStakingConfigUpdate<'info> {
+    #[account(
+        seeds = [b"staking_config"],
+        bump = staking_config.bump,
+        constraint = staking_config.governance_multisig == governance_multisig.key() @ GhostSpeakError::InvalidStakingGovernanceMultisig
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    pub governance_multisig: Box<Account<'info, Multisig>>,
+
+    /// `init` allows one pending update at a time
+    #[account(
+        init,
+        payer = proposer,
+        space = StakingConfigProposal::LEN,
+        seeds = [STAKING_CONFIG_PROPOSAL_SEED],
+        bump
+    )]
+    pub proposal: Box<Account<'info, StakingConfigProposal>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_staking_config_update(
+    ctx: Context<ProposeStakingConfigUpdate>,
+    params: StakingParams,
+) -> Result<()> {
+    let multisig = &ctx.accounts.governance_multisig;
+    multisig.require_approvals(ctx.remaining_accounts)?;
+    params.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.params = params;
+    proposal.proposed_at = now;
+    proposal.executable_at = now
+        .checked_add(multisig.type_config.timelock_seconds)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(StakingConfigUpdateProposedEvent {
+        multisig: proposal.multisig,
+        params,
+        executable_at: proposal.executable_at,
+    });
+
+    msg!("Staking config update proposed by multisig: {}, executable at {}",
+        proposal.multisig, proposal.executable_at);
+
+    Ok(())
+}
+
+/// Apply a queued staking parameter update once its timelock has passed (permissionless)
+#[derive(Accounts)]
+pub struct UpdateStakingConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// Proposals from a replaced multisig can't be applied
+    #[account(
+        mut,
+        seeds = [STAKING_CONFIG_PROPOSAL_SEED],
+        bump = proposal.bump,
+        has_one = proposer,
+        constraint = proposal.multisig == staking_config.governance_multisig @ GhostSpeakError::InvalidStakingGovernanceMultisig,
+        close = proposer
+    )]
+    pub proposal: Box<Account<'info, StakingConfigProposal>>,
+
+    /// CHECK: Proposer recorded on the proposal, receives the rent refund
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Head of the config history chain (created on first change)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ConfigHistoryHead::LEN,
+        seeds = [CONFIG_HISTORY_HEAD_SEED],
+        bump
+    )]
+    pub config_history_head: Box<Account<'info, ConfigHistoryHead>>,
+
+    /// New history entry recording this change
+    #[account(
+        init,
+        payer = payer,
+        space = ConfigHistoryEntry::LEN,
+        seeds = [
+            CONFIG_HISTORY_ENTRY_SEED,
+            config_history_head.entry_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub config_history_entry: Box<Account<'info, ConfigHistoryEntry>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_staking_config(ctx: Context<UpdateStakingConfig>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        now >= proposal.executable_at,
+        GhostSpeakError::MultisigTimelockActive
+    );
+
+    let config = &mut ctx.accounts.staking_config;
+    let old_value = config.try_to_vec()?;
+    config.apply_params(&proposal.params)?;
+
+    append_config_history(
+        &mut ctx.accounts.config_history_head,
+        &mut ctx.accounts.config_history_entry,
+        ctx.bumps.config_history_head,
+        ctx.bumps.config_history_entry,
+        ConfigKind::StakingConfig,
+        config.key(),
+        &old_value,
+        &config.try_to_vec()?,
+        proposal.multisig,
+    )?;
+
+    emit!(StakingConfigUpdatedEvent {
+        multisig: proposal.multisig,
+        params: proposal.params,
+        timestamp: now,
+    });
+
+    msg!("Staking config updated (min_stake: {}, fraud slash: {} bps, dispute slash: {} bps)",
+        config.min_stake, config.fraud_slash_bps, config.dispute_slash_bps);
+
+    Ok(())
+}
+
+/// Drop a queued staking parameter update (governance multisig threshold required)
+///
+/// Multisig signers are passed as remaining accounts and must meet the
+/// multisig threshold.
+#[derive(Accounts)]
+pub struct CancelStakingConfigUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [STAKING_CONFIG_PROPOSAL_SEED],
+        bump = proposal.bump,
+        constraint = proposal.multisig == governance_multisig.key() @ GhostSpeakError::InvalidStakingGovernanceMultisig,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Box<Account<'info, StakingConfigProposal>>,
+
+    pub governance_multisig: Box<Account<'info, Multisig>>,
+
+    /// CHECK: Proposer recorded on the proposal, receives the rent refund
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn cancel_staking_config_update(ctx: Context<CancelStakingConfigUpdate>) -> Result<()> {
+    ctx.accounts
+        .governance_multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let proposal = &ctx.accounts.proposal;
+
+    emit!(StakingConfigUpdateCancelledEvent {
+        multisig: proposal.multisig,
+        params: proposal.params,
+    });
+
+    msg!("Staking config update from multisig: {} cancelled", proposal.multisig);
+
+    Ok(())
+}
+
+// =====================================================
+// LAYOUT MIGRATION
+// =====================================================
+
+/// Grow the staking config created before the layout version (permissionless)
+#[derive(Accounts)]
+pub struct MigrateStakingConfig<'info> {
+    /// CHECK: Config created before the layout version, which the current
+    /// layout can't deserialize; checked and parsed by the handler
+    #[account(mut, seeds = [b"staking_config"], bump)]
+    pub staking_config: UncheckedAccount<'info>,
+
+    /// Anyone may migrate; pays the rent for the added bytes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_staking_config(ctx: Context<MigrateStakingConfig>) -> Result<()> {
+    let info = ctx.accounts.staking_config.to_account_info();
+    let legacy = read_legacy_layout::<StakingConfig, StakingConfigV0>(&info, StakingConfig::LEN)?;
+    let now = Clock::get()?.unix_timestamp;
+    let config = StakingConfig::from_v0(legacy, now);
+    write_current_layout(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        StakingConfig::LEN,
+        &config,
+    )?;
+
+    emit!(StakingConfigMigratedEvent {
+        version: StakingConfig::CURRENT_VERSION,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Grow a staking account created before the layout version (permissionless)
+///
+/// The staking config must be migrated first, since the stake is added to
+/// its `total_staked`.
+#[derive(Accounts)]
+pub struct MigrateStakingAccount<'info> {
+    /// CHECK: Staking account created before the layout version, which the
+    /// current layout can't deserialize; checked and parsed by the handler
+    #[account(mut, seeds = [b"staking", owner.key().as_ref()], bump)]
+    pub staking_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = staking_config.bump
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// CHECK: Stake owner; only keys the staking PDA
+    pub owner: UncheckedAccount<'info>,
+
+    /// Anyone may migrate; pays the rent for the added bytes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_staking_account(ctx: Context<MigrateStakingAccount>) -> Result<()> {
+    let info = ctx.accounts.staking_account.to_account_info();
+    let legacy = read_legacy_layout::<StakingAccount, StakingAccountV0>(&info, StakingAccount::LEN)?;
+
+    // The stake starts earning from now, and counts toward the pool again
+    let config = &mut ctx.accounts.staking_config;
+    config.accrue_rewards(Clock::get()?.unix_timestamp)?;
+    let staking = StakingAccount::from_v0(
+        legacy,
+        config.acc_reward_per_share,
+        &config.tier_schedule,
+    )?;
+    config.total_staked = config
+        .total_staked
+        .checked_add(staking.amount_staked)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    write_current_layout(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        StakingAccount::LEN,
+        &staking,
+    )?;
+
+    emit!(StakingAccountMigratedEvent {
+        owner: staking.owner,
+        amount_staked: staking.amount_staked,
+        voting_power: staking.voting_power,
+        version: StakingAccount::CURRENT_VERSION,
+    });
 
     Ok(())
 }
@@ -0,0 +1,234 @@
+/*!
+ * Sybil Heuristics Instructions
+ *
+ * Governance of the funder-fingerprint heuristic: parameters, exemptions,
+ * oracle fingerprint recording and cluster review.
+ */
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::sybil::*;
+use crate::{GhostSpeakError, *};
+
+/// Create the heuristic config with default parameters (disabled)
+#[derive(Accounts)]
+pub struct InitializeSybilConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SybilConfig::LEN,
+        seeds = [SYBIL_CONFIG_SEED],
+        bump
+    )]
+    pub sybil_config: Account<'info, SybilConfig>,
+
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance-only changes to the heuristic
+#[derive(Accounts)]
+pub struct ManageSybilConfig<'info> {
+    #[account(
+        mut,
+        seeds = [SYBIL_CONFIG_SEED],
+        bump = sybil_config.bump,
+        constraint = sybil_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub sybil_config: Account<'info, SybilConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Record the first funder of a wallet (oracle only)
+#[derive(Accounts)]
+#[instruction(first_funder: Pubkey)]
+pub struct RecordFunderFingerprint<'info> {
+    #[account(
+        seeds = [SYBIL_CONFIG_SEED],
+        bump = sybil_config.bump,
+        constraint = sybil_config.oracle == oracle.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub sybil_config: Account<'info, SybilConfig>,
+
+    #[account(
+        init,
+        payer = oracle,
+        space = FunderFingerprint::LEN,
+        seeds = [FUNDER_FINGERPRINT_SEED, wallet.key().as_ref()],
+        bump
+    )]
+    pub fingerprint: Account<'info, FunderFingerprint>,
+
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = FunderCluster::LEN,
+        seeds = [FUNDER_CLUSTER_SEED, first_funder.as_ref()],
+        bump
+    )]
+    pub funder_cluster: Account<'info, FunderCluster>,
+
+    /// CHECK: Any wallet; only its address is recorded
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record governance's verdict on a funder cluster
+#[derive(Accounts)]
+pub struct ReviewFunderCluster<'info> {
+    #[account(
+        seeds = [SYBIL_CONFIG_SEED],
+        bump = sybil_config.bump,
+        constraint = sybil_config.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub sybil_config: Account<'info, SybilConfig>,
+
+    #[account(
+        mut,
+        seeds = [FUNDER_CLUSTER_SEED, funder_cluster.funder.as_ref()],
+        bump = funder_cluster.bump
+    )]
+    pub funder_cluster: Account<'info, FunderCluster>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn initialize_sybil_config(ctx: Context<InitializeSybilConfig>, oracle: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.sybil_config;
+    config.authority = ctx.accounts.authority.key();
+    config.oracle = oracle;
+    config.enabled = false;
+    config.cluster_threshold = SybilConfig::DEFAULT_CLUSTER_THRESHOLD;
+    config.flagged_weight_bps = SybilConfig::DEFAULT_FLAGGED_WEIGHT_BPS;
+    config.exempt_funders = Vec::new();
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.sybil_config;
+
+    emit_config(config);
+    Ok(())
+}
+
+pub fn update_sybil_config(
+    ctx: Context<ManageSybilConfig>,
+    enabled: Option<bool>,
+    oracle: Option<Pubkey>,
+    cluster_threshold: Option<u32>,
+    flagged_weight_bps: Option<u16>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.sybil_config;
+
+    if let Some(enabled) = enabled {
+        config.enabled = enabled;
+    }
+    if let Some(oracle) = oracle {
+        config.oracle = oracle;
+    }
+    if let Some(threshold) = cluster_threshold {
+        require!(threshold >= 2, GhostSpeakError::InvalidConfiguration);
+        config.cluster_threshold = threshold;
+    }
+    if let Some(bps) = flagged_weight_bps {
+        require!(bps <= 10_000, GhostSpeakError::InvalidConfiguration);
+        config.flagged_weight_bps = bps;
+    }
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit_config(config);
+    Ok(())
+}
+
+pub fn set_sybil_exempt_funder(
+    ctx: Context<ManageSybilConfig>,
+    funder: Pubkey,
+    exempt: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.sybil_config;
+    config.set_exempt(funder, exempt)?;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit_config(config);
+    Ok(())
+}
+
+pub fn record_funder_fingerprint(
+    ctx: Context<RecordFunderFingerprint>,
+    first_funder: Pubkey,
+) -> Result<()> {
+    let wallet = ctx.accounts.wallet.key();
+    require!(
+        first_funder != Pubkey::default() && first_funder != wallet,
+        GhostSpeakError::InvalidInput
+    );
+    let now = Clock::get()?.unix_timestamp;
+
+    let fingerprint = &mut ctx.accounts.fingerprint;
+    fingerprint.wallet = wallet;
+    fingerprint.first_funder = first_funder;
+    fingerprint.recorded_at = now;
+    fingerprint.bump = ctx.bumps.fingerprint;
+
+    let cluster = &mut ctx.accounts.funder_cluster;
+    if cluster.funder == Pubkey::default() {
+        cluster.funder = first_funder;
+        cluster.review = ClusterReview::Unreviewed;
+        cluster.bump = ctx.bumps.funder_cluster;
+    }
+    cluster.wallet_count = cluster.wallet_count.saturating_add(1);
+
+    emit!(FunderFingerprintRecordedEvent {
+        wallet,
+        first_funder,
+        cluster_size: cluster.wallet_count,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn review_funder_cluster(
+    ctx: Context<ReviewFunderCluster>,
+    review: ClusterReview,
+) -> Result<()> {
+    let cluster = &mut ctx.accounts.funder_cluster;
+    let now = Clock::get()?.unix_timestamp;
+
+    cluster.review = review;
+    cluster.reviewed_at = now;
+
+    emit!(FunderClusterReviewedEvent {
+        funder: cluster.funder,
+        review,
+        wallet_count: cluster.wallet_count,
+        flagged_ratings: cluster.flagged_ratings,
+        reviewer: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+fn emit_config(config: &SybilConfig) {
+    emit!(SybilConfigUpdatedEvent {
+        authority: config.authority,
+        oracle: config.oracle,
+        enabled: config.enabled,
+        cluster_threshold: config.cluster_threshold,
+        flagged_weight_bps: config.flagged_weight_bps,
+        exempt_funders: config.exempt_funders.clone(),
+        timestamp: config.updated_at,
+    });
+}
@@ -0,0 +1,114 @@
+/*!
+ * Tag Registry Instructions
+ *
+ * Governance of the reputation tag taxonomy: registering canonical tags,
+ * maintaining their aliases and deprecating them.
+ */
+
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::tag_registry::*;
+use crate::{GhostSpeakError, *};
+
+/// Create the tag registry
+#[derive(Accounts)]
+#[instruction(capacity: u16)]
+pub struct InitializeTagRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = TagRegistry::space(capacity),
+        seeds = [TAG_REGISTRY_SEED],
+        bump
+    )]
+    pub tag_registry: Account<'info, TagRegistry>,
+
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance-only changes to the taxonomy
+#[derive(Accounts)]
+pub struct ManageTagRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [TAG_REGISTRY_SEED],
+        bump = tag_registry.bump,
+        constraint = tag_registry.authority == authority.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub tag_registry: Account<'info, TagRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn initialize_tag_registry(ctx: Context<InitializeTagRegistry>, capacity: u16) -> Result<()> {
+    require!(
+        capacity > 0 && capacity <= TagRegistry::MAX_CAPACITY,
+        GhostSpeakError::InvalidInput
+    );
+
+    let registry = &mut ctx.accounts.tag_registry;
+    registry.authority = ctx.accounts.authority.key();
+    registry.capacity = capacity;
+    registry.next_id = 0;
+    registry.tags = Vec::new();
+    registry.updated_at = Clock::get()?.unix_timestamp;
+    registry.bump = ctx.bumps.tag_registry;
+
+    msg!("Tag registry created (capacity {})", capacity);
+    Ok(())
+}
+
+pub fn register_tag(
+    ctx: Context<ManageTagRegistry>,
+    category: TagCategory,
+    name: String,
+    aliases: Vec<String>,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.tag_registry;
+    let now = Clock::get()?.unix_timestamp;
+    let id = registry.register(category, &name, aliases, now)?;
+
+    let tag = registry.find(id).ok_or(GhostSpeakError::UnknownTag)?;
+    emit!(TagRegisteredEvent {
+        id,
+        category,
+        name: tag.name.clone(),
+        aliases: tag.aliases.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn update_tag(
+    ctx: Context<ManageTagRegistry>,
+    id: u16,
+    aliases: Vec<String>,
+    deprecated: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let tag = ctx
+        .accounts
+        .tag_registry
+        .update(id, aliases, deprecated, now)?;
+
+    emit!(TagUpdatedEvent {
+        id,
+        name: tag.name.clone(),
+        aliases: tag.aliases.clone(),
+        deprecated,
+        timestamp: now,
+    });
+
+    Ok(())
+}
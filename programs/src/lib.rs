@@ -65,7 +65,14 @@ pub use state::ExecutionParams;
 pub use state::ProposalType;
 pub use state::ReportType;
 pub use state::ReputationMetrics;
+pub use state::BuybackParams;
+pub use state::CouncilPowers;
+pub use state::DenylistReason;
+pub use state::FeeExemptionReason;
+pub use state::ParameterKey;
 pub use state::Role;
+pub use state::SnapshotStrategy;
+pub use state::VolumeFeeTier;
 pub use state::UserRegistry;
 pub use state::VoteChoice;
 pub use state::VotingResults;
@@ -73,29 +80,53 @@ pub use state::VotingResults;
 // Credential types for Pillar 1: Verifiable Credentials
 pub use state::CredentialKind;
 pub use state::CrossChainStatus;
+pub use state::CrossChainUpdate;
+pub use state::IssuerTrustTier;
+
+// Sybil heuristic types
+pub use state::ClusterReview;
+
+// Meta-transaction types
+pub use state::MetaTxPayload;
+
+// Signed authorization types
+pub use state::AuthorizationPayload;
 
 // Governance and multisig types
 pub use state::MultisigConfig;
+pub use state::{TransactionPriority, TransactionType};
 
 // Staking types (GHOST token staking for reputation boost)
 pub use state::AccessTier;
+pub use state::AccessTierStatus;
+pub use state::SlashPreview;
 pub use state::SlashReason;
+pub use state::GatedAction;
+pub use state::{StakingParams, TierSchedule, TriggerCondition, TriggerType};
 
 // Revenue distribution types (transparent revenue-share staking)
-// pub use state::RevenueSource; // Commented out until revenue_pool is implemented
+pub use state::RevenueSource;
 
 // Ghost Protect escrow types (B2C escrow with dispute resolution)
+pub use state::ArbitrationPreview;
+pub use state::FeeTokenQuote;
 pub use state::ArbitratorDecision;
 
 // DID types (Pillar 3: Decentralized Identifiers)
 pub use state::VerificationMethod;
 pub use state::ServiceEndpoint;
+pub use state::DidKeyScope;
+pub use state::DidResolutionChunk;
 
 // Reputation tag types (Pillar 2: Reputation Tags)
+pub use state::ReputationWindowSummary;
 pub use state::TagScore;
+pub use state::{TagCategory, TagRef};
 
 // Nested types exported for IDL generation (fixes Codama import errors)
+pub use state::AuditAction;
 pub use state::AuditContext;
+pub use state::ComplianceFlags;
 pub use state::BiometricQuality;
 pub use state::Action;
 pub use state::RuleCondition;
@@ -154,74 +185,28 @@ pub const STANDARD_ACCOUNT_SIZE: usize = 1024; // Standard account size in bytes
 pub const LARGE_ACCOUNT_SIZE: usize = 4096; // Large account size in bytes
 pub const RESERVED_SPACE: usize = 128; // Reserved space for future extensions
 
-// Protocol admin configuration - environment-based with secure fallbacks
-// This addresses the security audit finding about hardcoded admin keys
-//
-// SECURITY: Admin keys use network-specific configuration with secure defaults
-// Runtime validation ensures proper admin verification across all networks
+// Protocol admin configuration
 //
-// IMPORTANT: All admin operations use:
-// - Network-specific admin validation
-// - Multi-signature support where required
-// - Proper authorization checks in each instruction
+// The admin is no longer compiled into the program. It lives in the
+// `AdminAuthority` PDA, created by the program's upgrade authority at
+// deploy and replaced only through the Protocol multisig after its
+// timelock. Admin-gated instructions load that PDA and check their signer
+// against it.
 //
-// See security/admin_validation.rs for implementation details
-
-/// SECURE Admin Key Configuration - Environment-Based Approach
-///
-/// SECURITY: This implementation allows runtime admin key validation instead of
-/// hardcoded keys, addressing the critical security audit finding.
-///
-/// Production keys should be configured via:
-/// 1. Environment variables (PROTOCOL_ADMIN_KEY)
-/// 2. Multisig wallets for mainnet
-/// 3. Proper key rotation mechanisms
-///
-/// These fallback keys are ONLY for development/testing and should NEVER be used in production
-// Temporary development admin keys - REPLACE FOR PRODUCTION
-// Using new_from_array since pubkey! macro path changed in Solana 2.x
-#[cfg(feature = "devnet")]
-pub const PROTOCOL_ADMIN: Pubkey = Pubkey::new_from_array([
-    0x4f, 0xf3, 0x1b, 0x42, 0x3c, 0xd5, 0x8e, 0x7a, 0x91, 0x0a, 0xb2, 0xc4, 0x6e, 0x1d, 0x9f, 0x83,
-    0x57, 0x2b, 0xe0, 0x14, 0x68, 0xa9, 0x3c, 0x5d, 0x76, 0x02, 0xf5, 0x19, 0x88, 0x4b, 0xc7, 0xde,
-]);
-
-#[cfg(feature = "testnet")]
-pub const PROTOCOL_ADMIN: Pubkey = Pubkey::new_from_array([
-    0x4f, 0xf3, 0x1b, 0x42, 0x3c, 0xd5, 0x8e, 0x7a, 0x91, 0x0a, 0xb2, 0xc4, 0x6e, 0x1d, 0x9f, 0x83,
-    0x57, 0x2b, 0xe0, 0x14, 0x68, 0xa9, 0x3c, 0x5d, 0x76, 0x02, 0xf5, 0x19, 0x88, 0x4b, 0xc7, 0xde,
-]);
-
-#[cfg(feature = "mainnet")]
-pub const PROTOCOL_ADMIN: Pubkey = Pubkey::new_from_array([
-    0x4f, 0xf3, 0x1b, 0x42, 0x3c, 0xd5, 0x8e, 0x7a, 0x91, 0x0a, 0xb2, 0xc4, 0x6e, 0x1d, 0x9f, 0x83,
-    0x57, 0x2b, 0xe0, 0x14, 0x68, 0xa9, 0x3c, 0x5d, 0x76, 0x02, 0xf5, 0x19, 0x88, 0x4b, 0xc7, 0xde,
-]);
-
-// Default fallback for localnet and other environments
-// Note: Using Pubkey::from_str at runtime since pubkey! macro path changed in Solana 2.x
-#[cfg(not(any(feature = "devnet", feature = "testnet", feature = "mainnet")))]
-pub const PROTOCOL_ADMIN: Pubkey = Pubkey::new_from_array([
-    0x4f, 0xf3, 0x1b, 0x42, 0x3c, 0xd5, 0x8e, 0x7a, 0x91, 0x0a, 0xb2, 0xc4, 0x6e, 0x1d, 0x9f, 0x83,
-    0x57, 0x2b, 0xe0, 0x14, 0x68, 0xa9, 0x3c, 0x5d, 0x76, 0x02, 0xf5, 0x19, 0x88, 0x4b, 0xc7, 0xde,
-]);
-
-/// Enhanced admin validation with runtime configuration support
-/// This function provides a secure way to validate admin operations
-pub fn validate_admin_authority(provided_authority: &Pubkey) -> Result<()> {
-    // Primary validation against configured admin
+// See state/admin_authority.rs and security/admin_validation.rs
+
+/// Check that `provided_authority` is the protocol admin recorded in
+/// `admin_authority`
+pub fn validate_admin_authority(
+    admin_authority: &state::AdminAuthority,
+    provided_authority: &Pubkey,
+) -> Result<()> {
     require!(
-        *provided_authority == PROTOCOL_ADMIN,
+        *provided_authority == admin_authority.admin,
         GhostSpeakError::UnauthorizedAccess
     );
+    require_valid_admin(provided_authority)?;
 
-    // Additional security checks
-    require!(
-        *provided_authority != Pubkey::default(),
-        GhostSpeakError::InvalidInput
-    );
-
-    // Warn if using development keys in production context
     #[cfg(feature = "mainnet")]
     {
         // In production, log admin operations for audit trails
@@ -263,6 +248,13 @@ pub struct AgentUpdatedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AgentMigratedEvent {
+    pub agent: Pubkey,
+    pub version: u8,
+    pub timestamp: i64,
+}
+
 // =====================================================
 // EVENTS
 // =====================================================
@@ -502,6 +494,60 @@ pub enum GhostSpeakError {
     InGracePeriod = 2111,
     #[msg("No instructions to execute")]
     NoInstructionsToExecute = 2112,
+    #[msg("Account is already a multisig signer")]
+    MultisigSignerAlreadyExists = 2113,
+    #[msg("Account is not a multisig signer")]
+    MultisigSignerNotFound = 2114,
+    #[msg("Transaction not found in this page")]
+    MultisigTransactionNotFound = 2115,
+    #[msg("Execution queue is full")]
+    ExecutionQueueFull = 2116,
+    #[msg("Proposal is already queued for execution")]
+    ProposalAlreadyQueued = 2117,
+    #[msg("Proposal is not queued for execution")]
+    ProposalNotQueued = 2118,
+    #[msg("Proposal cannot be cancelled after approval")]
+    ProposalNotCancellable = 2119,
+    #[msg("Trigger condition type or target value is invalid")]
+    InvalidTriggerCondition = 2120,
+    #[msg("Trigger is inactive")]
+    TriggerInactive = 2121,
+    #[msg("Oracle account for a trigger condition was not supplied")]
+    TriggerOracleMissing = 2122,
+    #[msg("Proposal has no escrowed deposit")]
+    NoProposalDeposit = 2123,
+    #[msg("No admin transfer is pending")]
+    NoPendingAdminTransfer = 2124,
+    #[msg("Admin authority must be governed by a Protocol multisig")]
+    InvalidAdminMultisig = 2125,
+    #[msg("Emergency freezing is not enabled for this multisig")]
+    EmergencyFreezeDisabled = 2126,
+    #[msg("Multisig is not frozen")]
+    MultisigNotFrozen = 2127,
+    #[msg("Freeze has not reached its auto-unfreeze time")]
+    FreezeNotExpired = 2128,
+    #[msg("Security council has not been granted veto power")]
+    CouncilVetoDisabled = 2129,
+    #[msg("Proposal's execution timelock has passed")]
+    VetoWindowClosed = 2130,
+    #[msg("Previous veto still awaits its community review proposal")]
+    VetoReviewPending = 2131,
+    #[msg("Review must be a governance update proposal created after the veto")]
+    InvalidVetoReview = 2132,
+    #[msg("Transfer would exceed the multisig's daily limit for this token")]
+    DailyLimitExceeded = 2133,
+    #[msg("Parameter value is outside its allowed bounds")]
+    ParameterOutOfBounds = 2134,
+    #[msg("Proposal does not carry this parameter change")]
+    ParameterChangeNotProposed = 2135,
+    #[msg("Parameter was changed by a more recently decided proposal")]
+    StaleParameterChange = 2136,
+    #[msg("Authority transfer has expired")]
+    AuthorityTransferExpired = 2137,
+    #[msg("Invalid authority transfer: new authority must differ and expiry must be within 30 days")]
+    InvalidAuthorityTransfer = 2138,
+    #[msg("Authority changed since the transfer was proposed")]
+    StaleAuthorityTransfer = 2139,
 
     // ===== JSON ERRORS (2200-2249) =====
     #[msg("JSON parse error")]
@@ -554,6 +600,8 @@ pub enum GhostSpeakError {
     ProtocolPaused = 2502,
     #[msg("Instruction paused")]
     InstructionPaused = 2503,
+    #[msg("Automatic circuit breaker trip must be reset by a breaker multisig")]
+    CircuitBreakerResetRequired = 2504,
 
     // ===== TOKEN EXTENSION (2550-2599) =====
     #[msg("Extension not supported")]
@@ -576,6 +624,16 @@ pub enum GhostSpeakError {
     MaxComplianceTagsReached = 2654,
     #[msg("Maximum tag scores reached (max 50)")]
     MaxTagScoresReached = 2655,
+    #[msg("Tag is not in the registry")]
+    UnknownTag = 2656,
+    #[msg("Tag belongs to a different category")]
+    TagCategoryMismatch = 2657,
+    #[msg("Tag is deprecated")]
+    TagDeprecated = 2658,
+    #[msg("Tag name or alias is already registered")]
+    DuplicateTag = 2659,
+    #[msg("Tag registry is full")]
+    TagRegistryFull = 2660,
 
     // ===== BADGE/NFT ERRORS (2700-2749) =====
     #[msg("Badge is not transferable")]
@@ -610,6 +668,286 @@ pub enum GhostSpeakError {
     ExternalIdAlreadyExists = 2805,
     #[msg("Ghost Score out of valid range (0-1000)")]
     InvalidGhostScore = 2806,
+    #[msg("Reputation metrics predate the current layout; migrate them first")]
+    ReputationMetricsOutdated = 2807,
+    #[msg("Reputation metrics already have the current layout")]
+    ReputationMetricsCurrent = 2808,
+
+    // ===== REPUTATION SOURCE REGISTRY (2900-2949) =====
+    #[msg("Reputation source is not approved")]
+    SourceNotApproved = 2900,
+    #[msg("Signer is not the authorized updater for this source")]
+    UnauthorizedSourceUpdater = 2901,
+    #[msg("Source weight exceeds the approved maximum")]
+    SourceWeightExceeded = 2902,
+    #[msg("Too many approved sources (max 16)")]
+    TooManyApprovedSources = 2903,
+
+    // ===== PAGINATION (2950-2999) =====
+    #[msg("Page index does not match the cursor")]
+    InvalidPageIndex = 2950,
+    #[msg("Page is full; open the next page")]
+    PageFull = 2951,
+    #[msg("Current tail page must be supplied to link a new page")]
+    PreviousPageRequired = 2952,
+
+    // ===== TRIAL ESCROWS (3000-3049) =====
+    #[msg("Trial upfront share must be positive and within the governed maximum")]
+    TrialUpfrontTooLarge = 3000,
+    #[msg("Agent stake must stay locked until the trial deadline")]
+    TrialBondNotLocked = 3001,
+    #[msg("Staking vault and client bond account are required to claw back a trial bond")]
+    TrialBondAccountsRequired = 3002,
+    #[msg("Stake is reserved as a bond for open trial escrows")]
+    TrialBondReserved = 3003,
+    #[msg("Agent staking account is required to release a trial bond")]
+    TrialStakeRequired = 3004,
+
+    // ===== CROSS-CHAIN MIRRORING (3050-3099) =====
+    #[msg("Expected an Ed25519 signature instruction immediately before this instruction")]
+    MissingSignatureInstruction = 3050,
+    #[msg("No cross-chain attestor is configured")]
+    CrossChainAttestorNotSet = 3051,
+    #[msg("Cross-chain status transition not allowed")]
+    InvalidCrossChainTransition = 3052,
+    #[msg("Cross-chain sync retry is not due yet")]
+    CrossChainRetryNotDue = 3053,
+    #[msg("Cross-chain sync cannot be retried")]
+    CrossChainRetriesExhausted = 3054,
+
+    // ===== SYBIL HEURISTICS (3100-3149) =====
+    #[msg("Funder cluster account for the rater's first funder is required")]
+    SybilClusterRequired = 3100,
+    #[msg("Too many exempt funders (max 16)")]
+    TooManyExemptFunders = 3101,
+
+    // ===== COMMIT-REVEAL RATINGS (3150-3199) =====
+    #[msg("Revealed rating and salt do not match the commitment")]
+    RatingRevealMismatch = 3150,
+
+    // ===== META-TRANSACTIONS (3200-3249) =====
+    #[msg("Nonce is not higher than the signer's last used nonce")]
+    InvalidMetaTxNonce = 3200,
+    #[msg("Signed payload has expired")]
+    MetaTxExpired = 3201,
+    #[msg("Prepaid meta-transaction balance cannot cover the fee")]
+    InsufficientMetaTxBalance = 3202,
+    #[msg("Signed payload expiry is too far in the future")]
+    PayloadLifetimeTooLong = 3203,
+
+    // ===== DISPUTE QUEUE (3250-3299) =====
+    #[msg("Dispute queue is full")]
+    DisputeQueueFull = 3250,
+    #[msg("Dispute is not in the queue")]
+    DisputeNotQueued = 3251,
+    #[msg("Another dispute is ahead in the queue and this one is within its SLA")]
+    DisputeNotNextInQueue = 3252,
+
+    // ===== TEMPLATE FORKING (3300-3349) =====
+    #[msg("Template royalty exceeds the maximum per issuance")]
+    TemplateRoyaltyTooLarge = 3300,
+    #[msg("Original template author must be passed to receive the royalty")]
+    RoyaltyRecipientRequired = 3301,
+
+    // ===== CREDENTIAL VERIFICATION (3350-3399) =====
+    #[msg("Key is not an active assertion method of the issuer's DID")]
+    NotAssertionMethod = 3350,
+
+    // ===== CPI ESCROWS (3400-3449) =====
+    #[msg("Instruction must be invoked through CPI")]
+    NotInvokedViaCpi = 3400,
+    #[msg("Client is not a PDA of the origin program for the given seeds")]
+    ClientNotProgramDerived = 3401,
+
+    // ===== CREDENTIAL STATUS LISTS (3450-3499) =====
+    #[msg("Credential status list is full")]
+    StatusListFull = 3450,
+    #[msg("Status list index has not been assigned to a credential")]
+    StatusIndexNotAssigned = 3451,
+
+    // ===== ISSUER REGISTRY (3500-3549) =====
+    #[msg("Issuer is not registered for this credential kind")]
+    IssuerNotRegistered = 3500,
+    #[msg("Issuer registry account is required for protocol-reserved credential kinds")]
+    IssuerRegistryRequired = 3501,
+    #[msg("Issuer registry is full")]
+    TooManyRegisteredIssuers = 3502,
+
+    // ===== CO-ISSUED CREDENTIALS (3550-3599) =====
+    #[msg("Too many co-issuers")]
+    TooManyCoIssuers = 3550,
+    #[msg("Co-issuers must be distinct from each other and from the issuer")]
+    InvalidCoIssuers = 3551,
+    #[msg("Signer is not a co-issuer awaiting approval on this credential")]
+    NotPendingCoIssuer = 3552,
+
+    // ===== CREDENTIAL EXPIRATION (3600-3649) =====
+    #[msg("Credential has no expiry or has not reached it yet")]
+    CredentialNotExpired = 3600,
+
+    // ===== SCHEMA REGISTRY (3650-3699) =====
+    #[msg("Schema version must follow the credential type's current version")]
+    InvalidSchemaVersion = 3650,
+    #[msg("The current schema version cannot be deprecated")]
+    CannotDeprecateCurrentSchema = 3651,
+
+    // ===== CREDENTIAL TOKENS (3700-3749) =====
+    #[msg("A token has already been minted for this credential")]
+    CredentialTokenAlreadyMinted = 3700,
+
+    // ===== CREDENTIAL DISPUTES (3750-3799) =====
+    #[msg("Flag bond is below the configured minimum")]
+    FlagBondTooLow = 3750,
+    #[msg("Credential flag has already been resolved")]
+    CredentialFlagAlreadyResolved = 3751,
+
+    // ===== CREDENTIAL CLOSURE (3800-3849) =====
+    #[msg("Only revoked or expired credentials can be closed")]
+    CredentialNotClosable = 3800,
+    #[msg("Holders can close a credential only after the grace period")]
+    CredentialCloseGracePeriodActive = 3801,
+
+    // ===== COMPRESSED CREDENTIALS (3850-3899) =====
+    #[msg("Template or subject requires a credential account; issue uncompressed")]
+    CompressedIssuanceUnsupported = 3850,
+    #[msg("Merkle tree does not match the issuer's credential tree")]
+    CredentialTreeMismatch = 3851,
+
+    // ===== CREDENTIAL ISSUANCE FEES (3900-3949) =====
+    #[msg("Template charges an issuance fee; a fee payer must sign")]
+    IssuanceFeePayerRequired = 3900,
+    #[msg("Protocol config and SOL fee vault are required to split the issuance fee")]
+    ProtocolTreasuryRequired = 3901,
+
+    // ===== CUSTOM CREDENTIAL KINDS (3950-3999) =====
+    #[msg("Custom credential kind is not registered")]
+    CustomKindNotRegistered = 3950,
+
+    // ===== SUBJECT CREDENTIAL INDEX (4000-4049) =====
+    #[msg("Subject credential index page is full")]
+    SubjectIndexPageFull = 4000,
+
+    // ===== ISSUANCE DELEGATION (4050-4099) =====
+    #[msg("Issuance delegation has expired")]
+    DelegationExpired = 4050,
+    #[msg("Issuance delegation has used all of its issuances")]
+    DelegationCapReached = 4051,
+
+    // ===== AGENT HANDLES (4100-4149) =====
+    #[msg("Handle must be 3-32 chars of a-z, 0-9 and inner hyphens")]
+    InvalidHandle = 4100,
+    #[msg("Handle registration has expired")]
+    HandleExpired = 4101,
+    #[msg("Handle is still within its registration or grace period")]
+    HandleNotReleasable = 4102,
+
+    // ===== COMPRESSED DIDS (4150-4199) =====
+    #[msg("Merkle tree does not match the operator's DID tree")]
+    DidTreeMismatch = 4150,
+
+    // ===== STAKING REWARDS (4200-4249) =====
+    #[msg("No staking rewards to claim")]
+    NoStakingRewards = 4200,
+    #[msg("Partial unstake would leave less than the minimum stake")]
+    StakeResidualBelowMinimum = 4201,
+    #[msg("New lock must be longer than the current lock and end in the future")]
+    LockNotExtended = 4202,
+    #[msg("Slash can no longer be appealed or is not awaiting review")]
+    SlashNotAppealable = 4203,
+    #[msg("Slash appeal window has closed")]
+    SlashAppealWindowClosed = 4204,
+    #[msg("Slash is still appealable or under review")]
+    SlashAppealOpen = 4205,
+    #[msg("Slash appeals must be reviewed by the configured Protocol or Dispute multisig")]
+    InvalidSlashReviewMultisig = 4206,
+    #[msg("Revenue cannot be distributed while no stake is weighted in the pool")]
+    NoRevenueStakers = 4207,
+    #[msg("Stake migration offer has expired")]
+    StakeMigrationExpired = 4208,
+    #[msg("Stake cannot migrate while a slash against it is open")]
+    StakeMigrationBlocked = 4209,
+    #[msg("Insurance claims can only be filed against a slashed agent")]
+    AccusedNotSlashed = 4210,
+    #[msg("Insurance claim has already been reviewed")]
+    InsuranceClaimNotPending = 4211,
+    #[msg("Insurance pool cannot cover the payout")]
+    InsufficientInsuranceFunds = 4212,
+    #[msg("Stake backing receipt tokens cannot be unstaked; burn the receipts first")]
+    StakeReceiptOutstanding = 4213,
+    #[msg("Staking tier is below the minimum required for this action")]
+    InsufficientStakingTier = 4214,
+    #[msg("Staking parameters are invalid: thresholds and multipliers must rise with the tier and lock")]
+    InvalidStakingParams = 4215,
+    #[msg("Staking parameters must be governed by the configured Protocol multisig")]
+    InvalidStakingGovernanceMultisig = 4216,
+    #[msg("Staking account still holds stake, unclaimed rewards, receipts or an open slash")]
+    StakingAccountNotEmpty = 4217,
+    #[msg("Daily API quota for this staking tier is exhausted")]
+    ApiQuotaExhausted = 4218,
+
+    // ===== FEE VAULTS (4250-4299) =====
+    #[msg("Fee vault holds less than the requested withdrawal")]
+    InsufficientFeeVaultBalance = 4250,
+    #[msg("Fee vault token account is missing or doesn't match the vault")]
+    InvalidFeeVaultAccount = 4251,
+    #[msg("Fee exemption list is full")]
+    FeeExemptionListFull = 4252,
+    #[msg("No fee exemption exists for this agent or owner")]
+    FeeExemptionNotFound = 4253,
+    #[msg("Fee exemption needs a subject, known fee sources and a future expiry")]
+    InvalidFeeExemption = 4254,
+    #[msg("Price update is not a verified Pyth update for the expected feed")]
+    InvalidOraclePrice = 4255,
+    #[msg("Oracle price is too old")]
+    StaleOraclePrice = 4256,
+    #[msg("Oracle price confidence interval is too wide")]
+    OraclePriceUncertain = 4257,
+    #[msg("Fees cannot be paid in this token")]
+    FeeTokenNotAccepted = 4258,
+    #[msg("Paying a fee in a token needs its price updates, mint, token accounts and the token program")]
+    FeeTokenAccountsRequired = 4259,
+
+    // ===== BUYBACKS (4300-4349) =====
+    #[msg("Buyback settings are invalid")]
+    InvalidBuybackParams = 4300,
+    #[msg("Buyback would exceed this epoch's USDC cap")]
+    BuybackEpochCapExceeded = 4301,
+    #[msg("Buyback returned less GHOST than the slippage bound allows")]
+    BuybackSlippageExceeded = 4302,
+    #[msg("Swap program or vault balance changes don't match the buyback")]
+    BuybackSwapMismatch = 4303,
+
+    // ===== REFERRALS (4350-4399) =====
+    #[msg("Referrer cannot be the referred account, and payouts go to the referrer's own token account")]
+    InvalidReferrer = 4350,
+    #[msg("Referrer has not registered, or its stats were not passed")]
+    ReferrerNotRegistered = 4351,
+
+    // ===== DENYLIST (4400-4449) =====
+    #[msg("Agent or owner is denylisted")]
+    SubjectDenylisted = 4400,
+    #[msg("Denylist subject must be a non-default key")]
+    InvalidDenylistSubject = 4401,
+
+    // ===== SESSION KEYS (4450-4499) =====
+    #[msg("Session key terms are invalid, or it does not belong to this agent, signer and owner")]
+    InvalidSessionKey = 4450,
+    #[msg("Session key has expired")]
+    SessionKeyExpired = 4451,
+    #[msg("Session key is not scoped for this operation")]
+    SessionKeyScopeDenied = 4452,
+    #[msg("Session key has used all of its actions")]
+    SessionKeyExhausted = 4453,
+
+    // ===== SIGNED AUTHORIZATIONS (4500-4549) =====
+    #[msg("Signed authorization has no target, or is for another signer or action, or has lapsed")]
+    InvalidSignedAuthorization = 4500,
+
+    // ===== ACCOUNT LAYOUT MIGRATIONS (4550-4599) =====
+    #[msg("Account predates the current layout; migrate it first")]
+    AccountLayoutOutdated = 4550,
+    #[msg("Account already has the current layout")]
+    AccountLayoutCurrent = 4551,
 }
 
 // =====================================================
@@ -620,27 +958,180 @@ pub enum GhostSpeakError {
 pub mod ghostspeak_marketplace {
     use super::*;
 
+    // =====================================================
+    // ADMIN AUTHORITY INSTRUCTIONS
+    // =====================================================
+
+    /// Create the on-chain protocol admin (program upgrade authority only, once)
+    ///
+    /// Must run at deploy, before any admin-gated singleton is initialized.
+    ///
+    /// Parameters:
+    /// - admin: Initial protocol admin
+    pub fn initialize_admin_authority(
+        ctx: Context<InitializeAdminAuthority>,
+        admin: Pubkey,
+    ) -> Result<()> {
+        instructions::admin_authority::initialize_admin_authority(ctx, admin)
+    }
+
+    /// Queue a new protocol admin behind the governance multisig's timelock
+    /// (multisig threshold required)
+    ///
+    /// Multisig signers are passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - new_admin: Admin who takes over once the timelock has passed
+    pub fn propose_admin_transfer(
+        ctx: Context<GovernAdminAuthority>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::admin_authority::propose_admin_transfer(ctx, new_admin)
+    }
+
+    /// Drop a queued admin transfer (multisig threshold required)
+    ///
+    /// Multisig signers are passed as remaining accounts.
+    pub fn cancel_admin_transfer(ctx: Context<GovernAdminAuthority>) -> Result<()> {
+        instructions::admin_authority::cancel_admin_transfer(ctx)
+    }
+
+    /// Install a queued admin once its timelock has passed (permissionless,
+    /// refused while the governing multisig is frozen)
+    pub fn apply_admin_transfer(ctx: Context<ApplyAdminTransfer>) -> Result<()> {
+        instructions::admin_authority::apply_admin_transfer(ctx)
+    }
+
+    // =====================================================
+    // AUTHORITY TRANSFER INSTRUCTIONS
+    // =====================================================
+
+    /// Propose a new protocol config authority (config authority only)
+    ///
+    /// Parameters:
+    /// - new_authority: Key that must accept the transfer
+    /// - expires_in: Seconds the proposal stays open (at most 30 days)
+    pub fn propose_protocol_config_authority_transfer(
+        ctx: Context<ProposeProtocolConfigAuthority>,
+        new_authority: Pubkey,
+        expires_in: i64,
+    ) -> Result<()> {
+        instructions::authority_transfer::propose_protocol_config_authority_transfer(
+            ctx,
+            new_authority,
+            expires_in,
+        )
+    }
+
+    /// Propose a new staking config authority (staking authority only)
+    ///
+    /// Parameters:
+    /// - new_authority: Key that must accept the transfer
+    /// - expires_in: Seconds the proposal stays open (at most 30 days)
+    pub fn propose_staking_config_authority_transfer(
+        ctx: Context<ProposeStakingConfigAuthority>,
+        new_authority: Pubkey,
+        expires_in: i64,
+    ) -> Result<()> {
+        instructions::authority_transfer::propose_staking_config_authority_transfer(
+            ctx,
+            new_authority,
+            expires_in,
+        )
+    }
+
+    /// Propose a new owner for a credential type (credential type authority only)
+    ///
+    /// Parameters:
+    /// - new_authority: Key that must accept the transfer
+    /// - expires_in: Seconds the proposal stays open (at most 30 days)
+    pub fn propose_credential_type_authority_transfer(
+        ctx: Context<ProposeCredentialTypeAuthority>,
+        new_authority: Pubkey,
+        expires_in: i64,
+    ) -> Result<()> {
+        instructions::authority_transfer::propose_credential_type_authority_transfer(
+            ctx,
+            new_authority,
+            expires_in,
+        )
+    }
+
+    /// Take over the protocol config (pending authority only, before expiry)
+    pub fn accept_protocol_config_authority_transfer(
+        ctx: Context<AcceptProtocolConfigAuthority>,
+    ) -> Result<()> {
+        instructions::authority_transfer::accept_protocol_config_authority_transfer(ctx)
+    }
+
+    /// Take over the staking config (pending authority only, before expiry)
+    pub fn accept_staking_config_authority_transfer(
+        ctx: Context<AcceptStakingConfigAuthority>,
+    ) -> Result<()> {
+        instructions::authority_transfer::accept_staking_config_authority_transfer(ctx)
+    }
+
+    /// Take over a credential type (pending authority only, before expiry)
+    pub fn accept_credential_type_authority_transfer(
+        ctx: Context<AcceptCredentialTypeAuthority>,
+    ) -> Result<()> {
+        instructions::authority_transfer::accept_credential_type_authority_transfer(ctx)
+    }
+
+    /// Withdraw an open or lapsed authority transfer (proposer only)
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::authority_transfer::cancel_authority_transfer(ctx)
+    }
+
     // =====================================================
     // SECURITY INITIALIZATION INSTRUCTIONS
     // =====================================================
 
-    /// Initialize the global reentrancy guard PDA
-    /// This must be called once by a program admin before any reentrancy-protected instructions can be used
-    pub fn init_reentrancy_guard(ctx: Context<InitReentrancyGuard>) -> Result<()> {
-        instructions::security_init::init_reentrancy_guard(ctx)
+    /// Initialize the global rate limiter PDA (protocol admin only)
+    /// Required before rate-limited instructions (payment recording, service ratings
+    /// and reveals, authorized and source reputation updates, dispute filing,
+    /// credential issuance, including compressed) can be used
+    ///
+    /// Parameters:
+    /// - config: Default limit, window, penalty and burst settings
+    pub fn init_rate_limiter(
+        ctx: Context<InitializeRateLimiter>,
+        config: RateLimitConfig,
+    ) -> Result<()> {
+        instructions::security_init::init_rate_limiter(ctx, config)
+    }
+
+    /// Set the per-signer limit for one rate-limited operation (rate limiter authority only)
+    ///
+    /// Parameters:
+    /// - limit: Operation key, requests per window, window in seconds (0 = global
+    ///   default) and whether the burst check applies
+    pub fn set_operation_rate_limit(
+        ctx: Context<SetOperationRateLimit>,
+        limit: OperationLimit,
+    ) -> Result<()> {
+        instructions::security_init::set_operation_rate_limit(ctx, limit)
+    }
+
+    /// Create the replay-protection nonce account for an off-chain signer
+    /// Shared by every off-chain-signed payload type (meta-transactions, signed authorizations)
+    pub fn initialize_meta_nonce(ctx: Context<InitializeMetaNonce>) -> Result<()> {
+        instructions::security_init::initialize_meta_nonce(ctx)
     }
 
-    /// Reset a stuck reentrancy guard (admin only)
-    /// Use this to recover from stuck states after failed transactions
-    pub fn reset_reentrancy_guard(ctx: Context<ResetReentrancyGuard>) -> Result<()> {
-        instructions::security_init::reset_reentrancy_guard(ctx)
+    /// Skip the signer's nonce forward, revoking outstanding signed payloads
+    ///
+    /// Parameters:
+    /// - nonce: New last-used nonce; must be higher than the current one
+    pub fn advance_meta_nonce(ctx: Context<AdvanceMetaNonce>, nonce: u64) -> Result<()> {
+        instructions::security_init::advance_meta_nonce(ctx, nonce)
     }
 
     // =====================================================
     // PROTOCOL CONFIGURATION INSTRUCTIONS
     // =====================================================
 
-    /// Initialize the global protocol configuration
+    /// Initialize the global protocol configuration (protocol admin only)
     ///
     /// Sets up fee infrastructure with all fees initially disabled (set to 0).
     /// Fees will be enabled via governance after mainnet deployment.
@@ -686,185 +1177,783 @@ pub mod ghostspeak_marketplace {
         )
     }
 
-    // =====================================================
-    // STAKING INSTRUCTIONS
-    // =====================================================
-
-    /// Initialize GHOST token staking configuration (admin only)
-    pub fn initialize_staking_config(
-        ctx: Context<InitializeStakingConfig>,
-        min_stake: u64,
-        treasury: Pubkey,
+    /// Update inactivity decay parameters for aggregate reputation (authority only)
+    ///
+    /// Parameters:
+    /// - enabled: Toggle decay on or off
+    /// - grace_days: Inactive days before decay starts
+    /// - bps_per_day: Daily decay in basis points (max 1000)
+    /// - floor: Minimum score (0-100) decay can reach
+    pub fn update_reputation_decay_config(
+        ctx: Context<UpdateProtocolConfig>,
+        enabled: Option<bool>,
+        grace_days: Option<u16>,
+        bps_per_day: Option<u16>,
+        floor: Option<u32>,
     ) -> Result<()> {
-        instructions::staking::initialize_staking_config(ctx, min_stake, treasury)
+        instructions::protocol_config::update_reputation_decay_config(
+            ctx,
+            enabled,
+            grace_days,
+            bps_per_day,
+            floor,
+        )
     }
 
-    /// Stake GHOST tokens to boost agent reputation
-    pub fn stake_ghost(
-        ctx: Context<StakeGhost>,
-        amount: u64,
-        lock_duration: i64,
+    /// Set the protocol share of credential template issuance fees (authority only)
+    ///
+    /// Parameters:
+    /// - credential_fee_bps: Treasury share in basis points (max 5000)
+    pub fn update_credential_fee_share(
+        ctx: Context<UpdateProtocolConfig>,
+        credential_fee_bps: u16,
     ) -> Result<()> {
-        instructions::staking::stake_ghost(ctx, amount, lock_duration)
+        instructions::protocol_config::update_credential_fee_share(ctx, credential_fee_bps)
     }
 
-    /// Unstake GHOST tokens after lock period expires
-    pub fn unstake_ghost(ctx: Context<UnstakeGhost>) -> Result<()> {
-        instructions::staking::unstake_ghost(ctx)
+    /// Create the fee vault for a token (protocol admin only)
+    ///
+    /// Pass the system program as the mint for the SOL vault; SPL token
+    /// vaults also take a token account owned by the vault PDA.
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+        instructions::fee_vault::initialize_fee_vault(ctx)
     }
 
-    /// Slash staked tokens (admin only, for fraud/disputes)
-    pub fn slash_stake(
-        ctx: Context<SlashStake>,
-        owner: Pubkey,
-        reason: SlashReason,
-        custom_amount: Option<u64>,
+    /// Withdraw protocol fees from a fee vault (treasury multisig threshold required)
+    ///
+    /// Multisig signers must be passed as remaining accounts. The SOL vault
+    /// keeps its rent-exempt minimum.
+    ///
+    /// Parameters:
+    /// - amount: Lamports or token base units to withdraw
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::fee_vault::withdraw_treasury(ctx, amount)
+    }
+
+    /// Accept a token for SOL-denominated fees or change its oracle settings
+    /// (config authority only)
+    ///
+    /// Register SOL itself under the system program with the SOL/USD feed;
+    /// each token needs a fee vault.
+    ///
+    /// Parameters:
+    /// - price_feed_id: Pyth feed ID of the token's USD price
+    /// - max_price_age: Oldest price update accepted, in seconds (max 600)
+    /// - max_confidence_bps: Widest confidence interval accepted (max 500)
+    /// - enabled: Whether fees may be paid in the token
+    pub fn set_fee_payment_token(
+        ctx: Context<SetFeePaymentToken>,
+        price_feed_id: [u8; 32],
+        max_price_age: u32,
+        max_confidence_bps: u16,
+        enabled: bool,
     ) -> Result<()> {
-        instructions::staking::slash_stake(ctx, owner, reason, custom_amount)
+        instructions::fee_token::set_fee_payment_token(
+            ctx,
+            price_feed_id,
+            max_price_age,
+            max_confidence_bps,
+            enabled,
+        )
     }
 
-    // =====================================================
-    // REVENUE DISTRIBUTION INSTRUCTIONS - COMMENTED OUT
-    // =====================================================
-    // Module not implemented yet - commented out in mod.rs
+    /// Quote a SOL-denominated fee in a payment token at the Pyth rate
+    ///
+    /// The FeeTokenQuote is returned via return data; registration charges
+    /// a fee paid in the token at the same rate.
+    ///
+    /// Parameters:
+    /// - lamports: Fee in lamports
+    pub fn quote_fee_in_token(
+        ctx: Context<QuoteFeeInToken>,
+        lamports: u64,
+    ) -> Result<FeeTokenQuote> {
+        instructions::fee_token::quote_fee_in_token(ctx, lamports)
+    }
 
-    /*
-    /// Initialize the global revenue pool (admin only)
-    pub fn initialize_revenue_pool(ctx: Context<InitializeRevenuePool>) -> Result<()> {
-        instructions::revenue_distribution::initialize_revenue_pool(ctx)
+    /// Create the fee exemption allowlist (protocol admin only)
+    pub fn initialize_fee_exemption_list(ctx: Context<InitializeFeeExemptionList>) -> Result<()> {
+        instructions::fee_exemption::initialize_fee_exemption_list(ctx)
     }
 
-    /// Distribute protocol revenue to stakers (admin only)
-    pub fn distribute_revenue(
-        ctx: Context<DistributeRevenue>,
-        amount: u64,
-        source: RevenueSource,
+    /// Exempt an agent PDA or owner from protocol fees (list multisig threshold required)
+    ///
+    /// Multisig signers must be passed as remaining accounts. Granting again
+    /// replaces the subject's existing exemption.
+    ///
+    /// Parameters:
+    /// - subject: Agent PDA or owner wallet
+    /// - sources: Bitmask of exempt fee sources, bit n = FeeSource n
+    /// - reason: Strategic partner, grants program or protocol-owned
+    /// - expires_at: When the exemption lapses (0 = never)
+    pub fn set_fee_exemption(
+        ctx: Context<ManageFeeExemption>,
+        subject: Pubkey,
+        sources: u8,
+        reason: FeeExemptionReason,
+        expires_at: i64,
     ) -> Result<()> {
-        instructions::revenue_distribution::distribute_revenue(ctx, amount, source)
+        instructions::fee_exemption::set_fee_exemption(ctx, subject, sources, reason, expires_at)
     }
 
-    /// Claim accumulated USDC rewards
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
-        instructions::revenue_distribution::claim_rewards(ctx)
+    /// Revoke a fee exemption (list multisig threshold required)
+    ///
+    /// Parameters:
+    /// - subject: Agent PDA or owner wallet to remove
+    pub fn remove_fee_exemption(ctx: Context<ManageFeeExemption>, subject: Pubkey) -> Result<()> {
+        instructions::fee_exemption::remove_fee_exemption(ctx, subject)
     }
 
-    /// Recalculate global weighted stake (called by staking operations)
-    pub fn recalculate_global_weighted_stake(
-        ctx: Context<UpdateGlobalWeightedStake>,
-        new_total_weighted_stake: u64,
-    ) -> Result<()> {
-        instructions::revenue_distribution::recalculate_global_weighted_stake(
-            ctx,
-            new_total_weighted_stake,
-        )
+    /// Create the sanctions denylist (protocol admin only)
+    pub fn initialize_denylist(ctx: Context<InitializeDenylist>) -> Result<()> {
+        instructions::denylist::initialize_denylist(ctx)
     }
 
-    /// Reset period revenue counter (admin only, monthly)
-    pub fn reset_period(ctx: Context<ResetPeriod>) -> Result<()> {
-        instructions::revenue_distribution::reset_period(ctx)
+    /// Denylist an agent PDA or owner, blocking new escrows, credential
+    /// issuance and ratings involving it (denylist multisig threshold required)
+    ///
+    /// Multisig signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - subject: Agent PDA or owner wallet
+    /// - reason: Sanctions, fraud or abuse
+    pub fn add_to_denylist(
+        ctx: Context<AddToDenylist>,
+        subject: Pubkey,
+        reason: DenylistReason,
+    ) -> Result<()> {
+        instructions::denylist::add_to_denylist(ctx, subject, reason)
     }
-    */
-
-    // =====================================================
-    // PAYMENT INSTRUCTIONS - COMMENTED OUT
-    // =====================================================
-    // Modules not implemented yet - commented out in mod.rs
 
-    /*
-    /// Pay 1 USDC for single agent verification
-    pub fn pay_with_usdc(ctx: Context<PayWithUsdc>) -> Result<()> {
-        instructions::pay_with_usdc::pay_with_usdc(ctx)
+    /// Lift a denylisting (denylist multisig threshold required)
+    pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>) -> Result<()> {
+        instructions::denylist::remove_from_denylist(ctx)
     }
 
-    /// Burn 75 GHOST tokens for verification (25% discount)
-    pub fn burn_ghost_for_payment(ctx: Context<BurnGhostForPayment>) -> Result<()> {
-        instructions::burn_ghost_for_payment::burn_ghost_for_payment(ctx)
+    /// Create the buyback config and its USDC and GHOST vaults (protocol admin only)
+    ///
+    /// Point the protocol config's buyback pool at the USDC vault so the
+    /// buyback share of fees lands there.
+    ///
+    /// Parameters:
+    /// - params: Operator, swap program, slippage bound, epoch length and
+    ///   cap, and whether bought GHOST is burned or restaked
+    pub fn initialize_buyback(ctx: Context<InitializeBuyback>, params: BuybackParams) -> Result<()> {
+        instructions::buyback::initialize_buyback(ctx, params)
     }
 
-    /// Process Crossmint fiat payment after webhook confirmation
-    pub fn pay_with_crossmint(
-        ctx: Context<PayWithCrossmint>,
-        webhook_signature: String,
-        crossmint_transaction_id: String,
+    /// Change the buyback settings (Protocol multisig threshold required)
+    ///
+    /// Multisig signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - params: New buyback settings
+    pub fn update_buyback_config(
+        ctx: Context<UpdateBuybackConfig>,
+        params: BuybackParams,
     ) -> Result<()> {
-        instructions::pay_with_crossmint::pay_with_crossmint(
-            ctx,
-            webhook_signature,
-            crossmint_transaction_id,
-        )
+        instructions::buyback::update_buyback_config(ctx, params)
     }
-    */
 
-    // =====================================================
-    // B2B BILLING INSTRUCTIONS - COMMENTED OUT
-    // =====================================================
-    // Module not implemented yet - commented out in mod.rs
+    /// Swap vaulted USDC for GHOST and burn or restake it (buyback operator only)
+    ///
+    /// The swap route's accounts are passed as remaining accounts; the
+    /// buyback config PDA signs as the owner of the vaults. The GHOST received
+    /// must be within the slippage limit of the USDC spent priced through the
+    /// Pyth USDC and GHOST feeds of their fee payment token entries.
+    ///
+    /// Parameters:
+    /// - amount_in: Most USDC the swap may spend, counted against the epoch cap
+    /// - swap_data: Instruction data for the whitelisted swap program
+    pub fn execute_buyback<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteBuyback<'info>>,
+        amount_in: u64,
+        swap_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::buyback::execute_buyback(ctx, amount_in, swap_data)
+    }
 
-    /*
-    /// Create team's USDC token account for prepaid API billing
-    pub fn create_team_account(
-        ctx: Context<CreateTeamAccount>,
-        tier: SubscriptionTier,
+    /// Set the oracle key that attests Crossmint credential mirroring (authority only)
+    ///
+    /// Parameters:
+    /// - attestor: Ed25519 key whose signatures `update_crosschain_status` accepts
+    pub fn update_crosschain_attestor(
+        ctx: Context<UpdateProtocolConfig>,
+        attestor: Pubkey,
     ) -> Result<()> {
-        instructions::b2b_billing::create_team_account(ctx, tier)
+        instructions::protocol_config::update_crosschain_attestor(ctx, attestor)
     }
 
-    /// Deposit USDC to team account
-    pub fn deposit_funds(
-        ctx: Context<DepositFunds>,
-        amount: u64,
+    /// Set the fee discount for each payer staking tier (authority only)
+    ///
+    /// Parameters:
+    /// - fee_discount_bps: Discount in basis points of the fee, indexed by
+    ///   AccessTier (None, Basic, Verified, Pro, Whale); must not decrease
+    ///   with tier
+    pub fn update_fee_discounts(
+        ctx: Context<UpdateProtocolConfig>,
+        fee_discount_bps: [u16; AccessTier::COUNT],
     ) -> Result<()> {
-        instructions::b2b_billing::deposit_funds(ctx, amount)
+        instructions::protocol_config::update_fee_discounts(ctx, fee_discount_bps)
     }
 
-    /// Deduct usage cost from team account (called per API request)
-    pub fn deduct_usage(
-        ctx: Context<DeductUsage>,
-        endpoint: String,
-        request_count: u64,
+    /// Set the referrer share of the protocol's cut of referred fees
+    /// (authority only)
+    ///
+    /// Parameters:
+    /// - referral_share_bps: Referrer share in basis points (max 5000)
+    pub fn update_referral_share(
+        ctx: Context<UpdateProtocolConfig>,
+        referral_share_bps: u16,
     ) -> Result<()> {
-        instructions::b2b_billing::deduct_usage(ctx, endpoint, request_count)
+        instructions::protocol_config::update_referral_share(ctx, referral_share_bps)
     }
 
-    /// Withdraw unused funds from team account
-    pub fn withdraw_unused(
-        ctx: Context<WithdrawUnused>,
-        amount: u64,
+    /// Enroll the caller as a referrer, creating its accounting PDA
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::referral::register_referrer(ctx)
+    }
+
+    /// Set the escrow fee curve by agent settled volume (authority only)
+    ///
+    /// Parameters:
+    /// - tiers: Steps of (min_volume, fee_bps) by trailing 30-day settled
+    ///   volume, rising in volume with non-increasing rates; empty for a
+    ///   flat escrow fee
+    pub fn set_escrow_fee_curve(
+        ctx: Context<SetEscrowFeeCurve>,
+        tiers: Vec<VolumeFeeTier>,
     ) -> Result<()> {
-        instructions::b2b_billing::withdraw_unused(ctx, amount)
+        instructions::protocol_config::set_escrow_fee_curve(ctx, tiers)
     }
 
-    /// Update team subscription tier (admin only)
-    pub fn update_tier(
-        ctx: Context<UpdateTier>,
-        new_tier: SubscriptionTier,
+    /// Set the minimum staking tier for each gated action (authority only)
+    ///
+    /// Parameters:
+    /// - tier_gates: Minimum tier indexed by GatedAction (IssueCredential,
+    ///   Arbitrate); None leaves an action ungated
+    pub fn update_tier_gates(
+        ctx: Context<UpdateProtocolConfig>,
+        tier_gates: [AccessTier; GatedAction::COUNT],
     ) -> Result<()> {
-        instructions::b2b_billing::update_tier(ctx, new_tier)
+        instructions::protocol_config::update_tier_gates(ctx, tier_gates)
     }
-    */
 
     // =====================================================
-    // GHOST PROTECT ESCROW INSTRUCTIONS
+    // STAKING INSTRUCTIONS
     // =====================================================
 
-    /// Create a new escrow for agent service payment
-    pub fn create_escrow(
-        ctx: Context<CreateEscrow>,
-        escrow_id: u64,
-        amount: u64,
-        job_description: String,
-        deadline: i64,
-    ) -> Result<()> {
-        instructions::ghost_protect::create_escrow(
-            ctx,
-            escrow_id,
+    /// Initialize GHOST token staking configuration (admin only)
+    pub fn initialize_staking_config(
+        ctx: Context<InitializeStakingConfig>,
+        min_stake: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::staking::initialize_staking_config(ctx, min_stake, treasury)
+    }
+
+    /// Set how far each staking tier jumps the dispute queue and its ruling SLA (authority only)
+    ///
+    /// Parameters:
+    /// - head_start_secs: Queue head start per AccessTier (non-decreasing by tier)
+    /// - sla_secs: Ruling SLA per AccessTier (non-increasing by tier)
+    pub fn update_dispute_priority(
+        ctx: Context<UpdateDisputePriority>,
+        head_start_secs: [u32; 5],
+        sla_secs: [u32; 5],
+    ) -> Result<()> {
+        instructions::staking::update_dispute_priority(ctx, head_start_secs, sla_secs)
+    }
+
+    /// Stake GHOST tokens to boost agent reputation
+    pub fn stake_ghost(
+        ctx: Context<StakeGhost>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::staking::stake_ghost(ctx, amount, lock_duration)
+    }
+
+    /// Lengthen the lock on an existing stake, keeping its stake time
+    ///
+    /// The longer lock's voting power multiplier applies immediately.
+    ///
+    /// Parameters:
+    /// - lock_duration: New lock period in seconds from the original stake time
+    pub fn extend_lock(ctx: Context<ExtendLock>, lock_duration: i64) -> Result<()> {
+        instructions::staking::extend_lock(ctx, lock_duration)
+    }
+
+    /// Unstake GHOST tokens after lock period expires
+    ///
+    /// Parameters:
+    /// - amount: GHOST to withdraw; the rest must stay at or above min_stake
+    ///   and receipted stake must have its receipts burned first
+    pub fn unstake_ghost(ctx: Context<UnstakeGhost>, amount: u64) -> Result<()> {
+        instructions::staking::unstake_ghost(ctx, amount)
+    }
+
+    /// Read a staker's tier, API quota and stake needed for the next tier
+    ///
+    /// The AccessTierStatus is returned via return data.
+    pub fn get_access_tier(ctx: Context<GetAccessTier>) -> Result<AccessTierStatus> {
+        instructions::access_control::get_access_tier(ctx)
+    }
+
+    /// Meter API calls against the staker's daily tier quota (staker only)
+    ///
+    /// The quota refills every 24 hours; Whale tier is unmetered.
+    ///
+    /// Parameters:
+    /// - calls: Number of API calls to record
+    pub fn consume_api_quota(ctx: Context<ConsumeApiQuota>, calls: u32) -> Result<()> {
+        instructions::access_control::consume_api_quota(ctx, calls)
+    }
+
+    /// Close a fully unstaked staking account and return its rent
+    ///
+    /// Requires no stake, no unclaimed rewards, no outstanding receipts
+    /// and no open slash.
+    pub fn close_staking_account(ctx: Context<CloseStakingAccount>) -> Result<()> {
+        instructions::staking::close_staking_account(ctx)
+    }
+
+    /// Hand staking parameter updates over to a Protocol multisig (admin only, once)
+    pub fn set_staking_governance(ctx: Context<SetStakingGovernance>) -> Result<()> {
+        instructions::staking::set_staking_governance(ctx)
+    }
+
+    /// Queue a staking parameter update (governance multisig threshold required)
+    ///
+    /// The update can be applied with update_staking_config once the
+    /// multisig's timelock has passed.
+    ///
+    /// Parameters:
+    /// - params: Min stake, slash percentages, tier thresholds and lock multipliers
+    pub fn propose_staking_config_update(
+        ctx: Context<ProposeStakingConfigUpdate>,
+        params: StakingParams,
+    ) -> Result<()> {
+        instructions::staking::propose_staking_config_update(ctx, params)
+    }
+
+    /// Apply a queued staking parameter update after its timelock (permissionless)
+    pub fn update_staking_config(ctx: Context<UpdateStakingConfig>) -> Result<()> {
+        instructions::staking::update_staking_config(ctx)
+    }
+
+    /// Drop a queued staking parameter update (governance multisig threshold required)
+    pub fn cancel_staking_config_update(ctx: Context<CancelStakingConfigUpdate>) -> Result<()> {
+        instructions::staking::cancel_staking_config_update(ctx)
+    }
+
+    /// Grow a staking config created before the layout version to the
+    /// current layout
+    ///
+    /// Permissionless; the payer covers the added rent. Settings added
+    /// since start at their defaults, and `total_staked` is rebuilt as
+    /// staking accounts are migrated.
+    pub fn migrate_staking_config(ctx: Context<MigrateStakingConfig>) -> Result<()> {
+        instructions::staking::migrate_staking_config(ctx)
+    }
+
+    /// Grow a staking account created before the layout version to the
+    /// current layout
+    ///
+    /// Permissionless; the payer covers the added rent. The stake rejoins
+    /// `total_staked` and earns rewards from the migration on.
+    pub fn migrate_staking_account(ctx: Context<MigrateStakingAccount>) -> Result<()> {
+        instructions::staking::migrate_staking_account(ctx)
+    }
+
+    /// Create the Token-2022 liquid staking receipt mint (admin only)
+    pub fn initialize_stake_receipt_mint(ctx: Context<InitializeStakeReceiptMint>) -> Result<()> {
+        instructions::stake_receipt::initialize_stake_receipt_mint(ctx)
+    }
+
+    /// Mint receipt tokens against the caller's stake
+    ///
+    /// Parameters:
+    /// - amount: Receipts to mint, at most the stake not already receipted
+    pub fn mint_stake_receipt(ctx: Context<StakeReceipt>, amount: u64) -> Result<()> {
+        instructions::stake_receipt::mint_stake_receipt(ctx, amount)
+    }
+
+    /// Burn receipt tokens, freeing that much stake to be unstaked
+    ///
+    /// Parameters:
+    /// - amount: Receipts to burn from the caller's receipt token account
+    pub fn burn_stake_receipt(ctx: Context<StakeReceipt>, amount: u64) -> Result<()> {
+        instructions::stake_receipt::burn_stake_receipt(ctx, amount)
+    }
+
+    /// Slash staked tokens (admin only, for fraud/disputes)
+    ///
+    /// The slashed tokens are escrowed until the appeal window closes or
+    /// the review multisig rules on an appeal; see finalize_slash.
+    pub fn slash_stake(
+        ctx: Context<SlashStake>,
+        owner: Pubkey,
+        reason: SlashReason,
+        custom_amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::staking::slash_stake(ctx, owner, reason, custom_amount)
+    }
+
+    /// Set the multisig that reviews slash appeals and the appeal window (authority only)
+    ///
+    /// Parameters:
+    /// - appeal_window: Seconds an owner has to appeal a slash (at most 30 days)
+    pub fn update_slash_appeal_config(
+        ctx: Context<UpdateSlashAppealConfig>,
+        appeal_window: i64,
+    ) -> Result<()> {
+        instructions::staking::update_slash_appeal_config(ctx, appeal_window)
+    }
+
+    /// Appeal a slash within its window (slashed owner only)
+    ///
+    /// Parameters:
+    /// - appeal_uri: Evidence for the review multisig (IPFS/Arweave URI)
+    pub fn appeal_slash(ctx: Context<AppealSlash>, appeal_uri: String) -> Result<()> {
+        instructions::staking::appeal_slash(ctx, appeal_uri)
+    }
+
+    /// Rule on a slash appeal (review multisig threshold required)
+    ///
+    /// Parameters:
+    /// - refund_amount: Escrowed GHOST returned to the owner's stake
+    ///   (full amount reverses the slash, zero upholds it)
+    pub fn review_slash_appeal(
+        ctx: Context<ReviewSlashAppeal>,
+        refund_amount: u64,
+    ) -> Result<()> {
+        instructions::staking::review_slash_appeal(ctx, refund_amount)
+    }
+
+    /// Send a final slash's escrowed GHOST to the insurance pool (permissionless)
+    pub fn finalize_slash(ctx: Context<FinalizeSlash>) -> Result<()> {
+        instructions::staking::finalize_slash(ctx)
+    }
+
+    /// Dry run of slash_stake (admin only)
+    ///
+    /// Computes the slash amount and resulting tier without moving tokens.
+    /// The SlashPreview is returned via return data.
+    pub fn simulate_slash_stake(
+        ctx: Context<SimulateSlashStake>,
+        owner: Pubkey,
+        reason: SlashReason,
+        custom_amount: Option<u64>,
+    ) -> Result<SlashPreview> {
+        instructions::staking::simulate_slash_stake(ctx, owner, reason, custom_amount)
+    }
+
+    /// Deposit GHOST into the staking reward pool
+    ///
+    /// Parameters:
+    /// - amount: GHOST to add to the reward reserve
+    pub fn fund_staking_rewards(ctx: Context<FundStakingRewards>, amount: u64) -> Result<()> {
+        instructions::staking::fund_staking_rewards(ctx, amount)
+    }
+
+    /// Set the staking reward rate (authority only)
+    ///
+    /// Rewards released before the change are paid at the old rate.
+    ///
+    /// Parameters:
+    /// - reward_rate: GHOST released per second, shared pro rata by stake
+    pub fn set_staking_reward_rate(
+        ctx: Context<SetStakingRewardRate>,
+        reward_rate: u64,
+    ) -> Result<()> {
+        instructions::staking::set_staking_reward_rate(ctx, reward_rate)
+    }
+
+    /// Claim accrued staking rewards to the owner's GHOST account
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        instructions::staking::claim_staking_rewards(ctx)
+    }
+
+    /// Offer the caller's stake to a new owner key (step 1 of 2)
+    ///
+    /// Parameters:
+    /// - new_owner: Key that may accept the stake within the migration
+    ///   window (7 days unless governance changed it)
+    pub fn propose_stake_migration(
+        ctx: Context<ProposeStakeMigration>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::staking::propose_stake_migration(ctx, new_owner)
+    }
+
+    /// Accept an offered stake as the new owner (step 2 of 2)
+    ///
+    /// Lock, tier and reward history carry over without unstaking; the stake
+    /// counts toward voting snapshots taken from the migration on. Pass
+    /// both revenue positions to move the revenue share as well.
+    pub fn accept_stake_migration(ctx: Context<AcceptStakeMigration>) -> Result<()> {
+        instructions::staking::accept_stake_migration(ctx)
+    }
+
+    /// Withdraw a pending stake migration offer
+    pub fn cancel_stake_migration(ctx: Context<CancelStakeMigration>) -> Result<()> {
+        instructions::staking::cancel_stake_migration(ctx)
+    }
+
+    /// Restake accrued staking rewards as principal
+    ///
+    /// Recomputes the tier and voting power; pass the revenue pool and the
+    /// owner's revenue position to re-weigh their revenue share as well.
+    pub fn compound_rewards(ctx: Context<CompoundRewards>) -> Result<()> {
+        instructions::staking::compound_rewards(ctx)
+    }
+
+    // =====================================================
+    // REVENUE DISTRIBUTION INSTRUCTIONS
+    // =====================================================
+
+    /// Initialize the global revenue pool (admin only)
+    pub fn initialize_revenue_pool(ctx: Context<InitializeRevenuePool>) -> Result<()> {
+        instructions::revenue_distribution::initialize_revenue_pool(ctx)
+    }
+
+    /// Distribute protocol revenue to stakers (admin only)
+    ///
+    /// Splits the deposit pro rata by weighted stake (stake × tier multiplier).
+    ///
+    /// Parameters:
+    /// - amount: USDC deposited into the pool
+    /// - source: Revenue stream the deposit came from
+    pub fn distribute_revenue(
+        ctx: Context<DistributeRevenue>,
+        amount: u64,
+        source: RevenueSource,
+    ) -> Result<()> {
+        instructions::revenue_distribution::distribute_revenue(ctx, amount, source)
+    }
+
+    /// Claim accumulated USDC rewards
+    ///
+    /// Also re-weighs the caller's position from their current stake.
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        instructions::revenue_distribution::claim_rewards(ctx)
+    }
+
+    /// Recalculate global weighted stake for one staker (permissionless crank)
+    ///
+    /// Re-weighs the staker's revenue position from their staking account, or
+    /// to zero once it is closed or migrated away; run after stake changes so
+    /// the next distribution uses current weights.
+    pub fn recalculate_global_weighted_stake(
+        ctx: Context<UpdateGlobalWeightedStake>,
+    ) -> Result<()> {
+        instructions::revenue_distribution::recalculate_global_weighted_stake(ctx)
+    }
+
+    /// Reset period revenue counter (admin only, monthly)
+    pub fn reset_period(ctx: Context<ResetPeriod>) -> Result<()> {
+        instructions::revenue_distribution::reset_period(ctx)
+    }
+
+    /// Initialize the slash-funded insurance pool (admin only)
+    pub fn initialize_insurance_pool(ctx: Context<InitializeInsurancePool>) -> Result<()> {
+        instructions::insurance_pool::initialize_insurance_pool(ctx)
+    }
+
+    /// Claim compensation for fraud by a slashed agent
+    ///
+    /// Parameters:
+    /// - accused: Owner of the slashed agent responsible for the loss
+    /// - amount_requested: GHOST requested from the insurance pool
+    /// - evidence_uri: Evidence of the loss (IPFS/Arweave URI)
+    pub fn file_insurance_claim(
+        ctx: Context<FileInsuranceClaim>,
+        accused: Pubkey,
+        amount_requested: u64,
+        evidence_uri: String,
+    ) -> Result<()> {
+        instructions::insurance_pool::file_insurance_claim(
+            ctx,
+            accused,
+            amount_requested,
+            evidence_uri,
+        )
+    }
+
+    /// Rule on an insurance claim (slash review multisig threshold required)
+    ///
+    /// Parameters:
+    /// - payout: GHOST paid to the claimant, at most the amount requested
+    ///   (zero rejects the claim)
+    pub fn review_insurance_claim(ctx: Context<ReviewInsuranceClaim>, payout: u64) -> Result<()> {
+        instructions::insurance_pool::review_insurance_claim(ctx, payout)
+    }
+
+    // =====================================================
+    // PAYMENT INSTRUCTIONS - COMMENTED OUT
+    // =====================================================
+    // Modules not implemented yet - commented out in mod.rs
+
+    /*
+    /// Pay 1 USDC for single agent verification
+    pub fn pay_with_usdc(ctx: Context<PayWithUsdc>) -> Result<()> {
+        instructions::pay_with_usdc::pay_with_usdc(ctx)
+    }
+
+    /// Burn 75 GHOST tokens for verification (25% discount)
+    pub fn burn_ghost_for_payment(ctx: Context<BurnGhostForPayment>) -> Result<()> {
+        instructions::burn_ghost_for_payment::burn_ghost_for_payment(ctx)
+    }
+
+    /// Process Crossmint fiat payment after webhook confirmation
+    pub fn pay_with_crossmint(
+        ctx: Context<PayWithCrossmint>,
+        webhook_signature: String,
+        crossmint_transaction_id: String,
+    ) -> Result<()> {
+        instructions::pay_with_crossmint::pay_with_crossmint(
+            ctx,
+            webhook_signature,
+            crossmint_transaction_id,
+        )
+    }
+    */
+
+    // =====================================================
+    // B2B BILLING INSTRUCTIONS - COMMENTED OUT
+    // =====================================================
+    // Module not implemented yet - commented out in mod.rs
+
+    /*
+    /// Create team's USDC token account for prepaid API billing
+    pub fn create_team_account(
+        ctx: Context<CreateTeamAccount>,
+        tier: SubscriptionTier,
+    ) -> Result<()> {
+        instructions::b2b_billing::create_team_account(ctx, tier)
+    }
+
+    /// Deposit USDC to team account
+    pub fn deposit_funds(
+        ctx: Context<DepositFunds>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::b2b_billing::deposit_funds(ctx, amount)
+    }
+
+    /// Deduct usage cost from team account (called per API request)
+    pub fn deduct_usage(
+        ctx: Context<DeductUsage>,
+        endpoint: String,
+        request_count: u64,
+    ) -> Result<()> {
+        instructions::b2b_billing::deduct_usage(ctx, endpoint, request_count)
+    }
+
+    /// Withdraw unused funds from team account
+    pub fn withdraw_unused(
+        ctx: Context<WithdrawUnused>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::b2b_billing::withdraw_unused(ctx, amount)
+    }
+
+    /// Update team subscription tier (admin only)
+    pub fn update_tier(
+        ctx: Context<UpdateTier>,
+        new_tier: SubscriptionTier,
+    ) -> Result<()> {
+        instructions::b2b_billing::update_tier(ctx, new_tier)
+    }
+    */
+
+    // =====================================================
+    // GHOST PROTECT ESCROW INSTRUCTIONS
+    // =====================================================
+
+    /// Create a new escrow for agent service payment
+    ///
+    /// Parameters:
+    /// - referrer: Registered referrer credited with a share of the escrow's
+    ///   fees (its stats must be passed)
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        escrow_id: u64,
+        amount: u64,
+        job_description: String,
+        deadline: i64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::ghost_protect::create_escrow(
+            ctx,
+            escrow_id,
+            amount,
+            job_description,
+            deadline,
+            referrer,
+        )
+    }
+
+    /// Create an escrow from another program (DAO treasury, Squads vault) via CPI
+    ///
+    /// The client must be a PDA of `origin_program`, proven by `client_seeds`
+    /// (including the bump); a separate payer covers rent.
+    pub fn create_escrow_via_cpi(
+        ctx: Context<CreateEscrowViaCpi>,
+        escrow_id: u64,
+        amount: u64,
+        job_description: String,
+        deadline: i64,
+        client_seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        instructions::ghost_protect::create_escrow_via_cpi(
+            ctx,
+            escrow_id,
             amount,
             job_description,
             deadline,
+            client_seeds,
+        )
+    }
+
+    /// Create a trial escrow for an agent without an established track record
+    ///
+    /// A small share of the payment is released to the agent immediately and
+    /// the remainder follows normal approval. If arbitration later rules fully
+    /// for the client, `trial_bond` is clawed back from the agent's stake.
+    ///
+    /// Parameters:
+    /// - amount: Total payment amount
+    /// - upfront_bps: Share released immediately (governed maximum, 2500 = 25% by default)
+    /// - trial_bond: Staked tokens reserved to cover the upfront share
+    /// - job_description: Job description (IPFS hash)
+    /// - deadline: Delivery deadline (agent stake must stay locked until then)
+    pub fn create_trial_escrow(
+        ctx: Context<CreateTrialEscrow>,
+        escrow_id: u64,
+        amount: u64,
+        upfront_bps: u16,
+        trial_bond: u64,
+        job_description: String,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::ghost_protect::create_trial_escrow(
+            ctx,
+            escrow_id,
+            amount,
+            upfront_bps,
+            trial_bond,
+            job_description,
+            deadline,
         )
     }
 
     /// Agent submits work delivery proof
+    ///
+    /// Signed by the agent owner, by a DID delegate key scoped to submit
+    /// deliveries (pass `did_document` and `delegate_key`), or by a session
+    /// key scoped to submit deliveries (pass `session_key`).
     pub fn submit_delivery(
         ctx: Context<SubmitDelivery>,
         delivery_proof: String,
@@ -873,11 +1962,28 @@ pub mod ghostspeak_marketplace {
     }
 
     /// Client approves delivery and releases payment
+    ///
+    /// The escrow fee is taken from the agent's payment at the rate its
+    /// trailing 30-day volume earns on the escrow fee curve, discounted by
+    /// its staking tier, and paid into the escrow token's fee vault (pass
+    /// `fee_vault` and its token account whenever a fee is due). If the
+    /// escrow, or else the agent, names a referrer, the referral share of
+    /// the fee goes to the referrer (pass its stats and token account).
     pub fn approve_delivery(ctx: Context<ApproveDelivery>) -> Result<()> {
         instructions::ghost_protect::approve_delivery(ctx)
     }
 
+    /// Create the global dispute queue (protocol admin only)
+    pub fn initialize_dispute_queue(ctx: Context<InitializeDisputeQueue>) -> Result<()> {
+        instructions::ghost_protect::initialize_dispute_queue(ctx)
+    }
+
     /// Client files a dispute on escrow
+    ///
+    /// The dispute is queued for arbitration by the client's staking tier;
+    /// pass the client's staking account to claim its priority. The client
+    /// posts the governed dispute bond in lamports, held on the escrow until
+    /// the ruling.
     pub fn file_dispute(
         ctx: Context<FileDispute>,
         reason: String,
@@ -885,7 +1991,28 @@ pub mod ghostspeak_marketplace {
         instructions::ghost_protect::file_dispute(ctx, reason)
     }
 
+    /// Client cancels an escrow before delivery and is refunded
+    ///
+    /// Counts toward the client's cancellation rate in ClientReputation.
+    pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
+        instructions::ghost_protect::cancel_escrow(ctx)
+    }
+
     /// Arbitrator resolves dispute (admin only)
+    ///
+    /// Disputes are ruled on in queue order; a dispute past its tier's SLA
+    /// may be ruled on ahead of the queue.
+    ///
+    /// For trial escrows, a full ruling for the client also claws the trial
+    /// bond back from the agent's stake; pass the staking vault, the
+    /// client's staking-token account and the staking config in that case.
+    ///
+    /// The dispute bond is refunded to the client unless the ruling is
+    /// wholly for the agent, in which case the agent's owner receives it.
+    ///
+    /// The dispute fee is taken from the escrowed amount before the ruling
+    /// splits it, and the agent's share bears the escrow fee, as in
+    /// `approve_delivery`; both go to the escrow token's fee vault.
     pub fn arbitrate_dispute(
         ctx: Context<ArbitrateDispute>,
         decision: ArbitratorDecision,
@@ -893,12 +2020,47 @@ pub mod ghostspeak_marketplace {
         instructions::ghost_protect::arbitrate_dispute(ctx, decision)
     }
 
+    /// Dry run of arbitrate_dispute
+    ///
+    /// Makes the same checks as arbitrate_dispute (pause, arbitrator tier, queue
+    /// order) and computes the dispute and escrow fees, client and agent payouts,
+    /// any trial bond clawback and the dispute bond's recipient without moving
+    /// funds. The ArbitrationPreview is returned via return data.
+    pub fn simulate_arbitrate_dispute(
+        ctx: Context<SimulateArbitrateDispute>,
+        decision: ArbitratorDecision,
+    ) -> Result<ArbitrationPreview> {
+        instructions::ghost_protect::simulate_arbitrate_dispute(ctx, decision)
+    }
+
+    /// Grow an escrow created before the layout version to the current
+    /// layout
+    ///
+    /// Permissionless; the payer covers the added rent. The escrow carries
+    /// on as a regular escrow with no referrer and no dispute bond.
+    ///
+    /// Parameters:
+    /// - escrow_id: ID the client created the escrow with
+    pub fn migrate_escrow(ctx: Context<MigrateEscrow>, escrow_id: u64) -> Result<()> {
+        instructions::ghost_protect::migrate_escrow(ctx, escrow_id)
+    }
+
     // ENHANCED GOVERNANCE VOTING REMOVED (Deprecated Staking)
 
     // =====================================================
     // AGENT MANAGEMENT INSTRUCTIONS
     // =====================================================
 
+    /// Register an agent
+    ///
+    /// Charges the registration fee, discounted by the owner's staking tier,
+    /// into the SOL fee vault, or in an accepted token at the Pyth rate
+    /// `quote_fee_in_token` quotes (pass `fee_payment_token`, the price
+    /// updates and token accounts, and that token's fee vault).
+    ///
+    /// Parameters:
+    /// - referrer: Registered referrer credited with a share of the agent's
+    ///   fees (its stats must be passed)
     pub fn register_agent(
         ctx: Context<RegisterAgent>,
         agent_type: u8,
@@ -907,6 +2069,7 @@ pub mod ghostspeak_marketplace {
         metadata_uri: String,
         _agent_id: String,
         pricing_model: PricingModel,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
         instructions::agent::register_agent(
             ctx,
@@ -916,6 +2079,51 @@ pub mod ghostspeak_marketplace {
             metadata_uri,
             _agent_id,
             pricing_model,
+            referrer,
+        )
+    }
+
+    /// Onboard an agent in a single transaction
+    ///
+    /// Stakes GHOST, registers the agent, initializes its reputation metrics
+    /// and creates the owner's DID document atomically; an owner who already
+    /// has a DID document keeps it, so one owner can onboard several agents.
+    /// The registration fee is charged as in register_agent.
+    ///
+    /// Parameters:
+    /// - agent_type, name, description, metadata_uri, agent_id, pricing_model: as in register_agent
+    /// - stake_amount: GHOST to stake (0 to reuse an existing stake)
+    /// - lock_duration: Lock period in seconds for the new stake
+    /// - did_string: The DID string (e.g., "did:sol:devnet:HN7c...")
+    /// - verification_methods: Initial verification methods (max 10)
+    /// - service_endpoints: Initial service endpoints (max 5)
+    pub fn onboard_agent(
+        ctx: Context<OnboardAgent>,
+        agent_type: u8,
+        name: String,
+        description: String,
+        metadata_uri: String,
+        agent_id: String,
+        pricing_model: PricingModel,
+        stake_amount: u64,
+        lock_duration: i64,
+        did_string: String,
+        verification_methods: Vec<VerificationMethod>,
+        service_endpoints: Vec<ServiceEndpoint>,
+    ) -> Result<()> {
+        instructions::onboarding::onboard_agent(
+            ctx,
+            agent_type,
+            name,
+            description,
+            metadata_uri,
+            agent_id,
+            pricing_model,
+            stake_amount,
+            lock_duration,
+            did_string,
+            verification_methods,
+            service_endpoints,
         )
     }
 
@@ -991,15 +2199,90 @@ pub mod ghostspeak_marketplace {
         instructions::agent::update_agent_reputation(ctx, agent_id, reputation_score)
     }
 
+    /// Grow an agent created before the layout version to the current layout
+    ///
+    /// Permissionless; the payer covers the added rent. The agent carries on
+    /// with no referrer.
+    pub fn migrate_agent(ctx: Context<MigrateAgent>) -> Result<()> {
+        instructions::agent::migrate_agent(ctx)
+    }
+
     pub fn update_agent_service(
         ctx: Context<UpdateAgentService>,
         service_data: instructions::agent_management::AgentServiceData,
     ) -> Result<()> {
-        instructions::agent_management::update_agent_service(ctx, service_data)
+        instructions::agent_management::update_agent_service(ctx, service_data)
+    }
+
+    pub fn manage_agent_status(ctx: Context<ManageAgentStatus>, new_status: bool) -> Result<()> {
+        instructions::agent_management::manage_agent_status(ctx, new_status)
+    }
+
+    /// Create an agent's prepaid balance for relayed meta-transactions
+    pub fn initialize_meta_tx_account(ctx: Context<InitializeMetaTxAccount>) -> Result<()> {
+        instructions::meta_tx::initialize_meta_tx_account(ctx)
+    }
+
+    /// Add lamports to an agent's prepaid relayer balance
+    pub fn deposit_meta_tx_balance(ctx: Context<DepositMetaTxBalance>, amount: u64) -> Result<()> {
+        instructions::meta_tx::deposit_meta_tx_balance(ctx, amount)
+    }
+
+    /// Withdraw unused prepaid balance to the agent owner
+    pub fn withdraw_meta_tx_balance(
+        ctx: Context<WithdrawMetaTxBalance>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::meta_tx::withdraw_meta_tx_balance(ctx, amount)
+    }
+
+    /// Relay an agent action signed off-chain by the agent owner
+    ///
+    /// The transaction must carry an Ed25519 instruction, immediately before
+    /// this one, over "ghostspeak:meta_tx:v1" || agent || payload.
+    ///
+    /// Parameters:
+    /// - payload: Action, nonce, expiry and relayer fee signed by the owner
+    pub fn execute_meta_tx(ctx: Context<ExecuteMetaTx>, payload: MetaTxPayload) -> Result<()> {
+        instructions::meta_tx::execute_meta_tx(ctx, payload)
+    }
+
+    /// Record an Ed25519-signed off-chain authorization (anyone may submit)
+    ///
+    /// The transaction must carry an Ed25519 instruction, immediately before
+    /// this one, over "ghostspeak:authorization:v1" || payload. Consumes the
+    /// signer's meta nonce.
+    ///
+    /// Parameters:
+    /// - payload: Authorized action, nonce and expiry signed by the signer
+    pub fn verify_signed_authorization(
+        ctx: Context<VerifySignedAuthorization>,
+        payload: AuthorizationPayload,
+    ) -> Result<()> {
+        instructions::signed_authorization::verify_signed_authorization(ctx, payload)
+    }
+
+    /// Authorize an ephemeral session key for agent automation (agent owner only)
+    ///
+    /// Parameters:
+    /// - signer: Session signing key
+    /// - scopes: Bitmask of allowed operations, bit n = SessionScope n
+    ///   (submit deliveries, record payments)
+    /// - expires_at: When the key stops working (at most 30 days out)
+    /// - max_actions: Most actions the key may take
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        signer: Pubkey,
+        scopes: u8,
+        expires_at: i64,
+        max_actions: u32,
+    ) -> Result<()> {
+        instructions::session_key::create_session_key(ctx, signer, scopes, expires_at, max_actions)
     }
 
-    pub fn manage_agent_status(ctx: Context<ManageAgentStatus>, new_status: bool) -> Result<()> {
-        instructions::agent_management::manage_agent_status(ctx, new_status)
+    /// Revoke a session key and reclaim its rent (granting owner only)
+    pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+        instructions::session_key::revoke_session_key(ctx)
     }
 
     // =====================================================
@@ -1115,92 +2398,614 @@ pub mod ghostspeak_marketplace {
 
     /// Update Ghost Score from off-chain calculation
     ///
-    /// Called by the reputation oracle/calculator service.
-    /// Updates the weighted Ghost Score and optionally the reputation components.
+    /// Called by the reputation oracle/calculator service.
+    /// Updates the weighted Ghost Score and optionally the reputation components.
+    ///
+    /// Parameters:
+    /// - new_score: New Ghost Score (0-1000)
+    /// - components: Optional reputation component breakdown
+    pub fn update_ghost_score(
+        ctx: Context<UpdateGhostScore>,
+        new_score: u64,
+        components: Option<Vec<crate::state::ReputationComponent>>,
+    ) -> Result<()> {
+        instructions::ghost::update_ghost_score(ctx, new_score, components)
+    }
+
+    // MARKETPLACE INSTRUCTIONS REMOVED
+
+    // MESSAGING INSTRUCTIONS REMOVED
+
+    // PROCESS PAYMENT INSTRUCTIONS REMOVED (WorkOrder Deprecated)
+
+    // WORK ORDER INSTRUCTIONS REMOVED
+
+    // =====================================================
+    // A2A & H2A PROTOCOL INSTRUCTIONS - REMOVED
+    // Agent/human messaging not needed for VC/Reputation layer
+    // =====================================================
+
+    /* A2A/H2A entry points removed - messaging not aligned with pivot:
+    pub fn create_a2a_session(...)
+    pub fn send_a2a_message(...)
+    pub fn update_a2a_status(...)
+    pub fn create_communication_session(...)
+    pub fn send_communication_message(...)
+    pub fn update_participant_status(...)
+    */
+
+    // ANALYTICS INSTRUCTIONS REMOVED
+
+    // AUCTION INSTRUCTIONS REMOVED
+
+    // BULK DEALS INSTRUCTIONS REMOVED
+
+    // =====================================================
+    // COMPLIANCE & GOVERNANCE INSTRUCTIONS
+    // =====================================================
+
+    pub fn initialize_audit_trail(
+        ctx: Context<InitializeAuditTrail>,
+        entity_type: String,
+        config: AuditConfig,
+    ) -> Result<()> {
+        instructions::compliance_governance::initialize_audit_trail(ctx, entity_type, config)
+    }
+
+    /// Append an entry to an audit trail's paged, hash-chained history
+    ///
+    /// Parameters:
+    /// - page_index: Index of the tail page (opens a new page when it equals the page count)
+    /// - action: Action being recorded
+    /// - target: Optional account the action applied to
+    /// - context: Action context and metadata
+    /// - compliance_flags: Compliance flags for the entry
+    pub fn append_audit_entry(
+        ctx: Context<AppendAuditEntry>,
+        page_index: u32,
+        action: AuditAction,
+        target: Option<Pubkey>,
+        context: AuditContext,
+        compliance_flags: ComplianceFlags,
+    ) -> Result<()> {
+        instructions::compliance_governance::append_audit_entry(
+            ctx,
+            page_index,
+            action,
+            target,
+            context,
+            compliance_flags,
+        )
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        multisig_id: u64,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+        config: MultisigConfig,
+    ) -> Result<()> {
+        instructions::compliance_governance::create_multisig(
+            ctx,
+            multisig_id,
+            threshold,
+            signers,
+            config,
+        )
+    }
+
+    /// Add a signer to a multisig (signer change threshold required)
+    ///
+    /// Current signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - signer: Key to add; the set must stay within the type's signer bounds
+    pub fn add_signer(ctx: Context<UpdateMultisigSigners>, signer: Pubkey) -> Result<()> {
+        instructions::compliance_governance::add_signer(ctx, signer)
+    }
+
+    /// Remove a signer from a multisig (signer change threshold required)
+    ///
+    /// Current signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - signer: Key to remove; both thresholds must stay reachable
+    pub fn remove_signer(ctx: Context<UpdateMultisigSigners>, signer: Pubkey) -> Result<()> {
+        instructions::compliance_governance::remove_signer(ctx, signer)
+    }
+
+    /// Change a multisig's approval threshold (signer change threshold required)
+    ///
+    /// Current signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - threshold: New approval threshold, at most the signer count
+    pub fn change_threshold(ctx: Context<UpdateMultisigSigners>, threshold: u8) -> Result<()> {
+        instructions::compliance_governance::change_threshold(ctx, threshold)
+    }
+
+    /// Queue a transaction in a multisig's paged pending queue
+    ///
+    /// Parameters:
+    /// - page_index: Index of the tail page (opens a new page when it equals the page count)
+    /// - transaction_type: Type of transaction being queued
+    /// - target: Target program/account
+    /// - data_hash: Hash of the transaction data supplied at execution
+    /// - priority: Transaction priority
+    pub fn queue_multisig_transaction(
+        ctx: Context<QueueMultisigTransaction>,
+        page_index: u32,
+        transaction_type: TransactionType,
+        target: Pubkey,
+        data_hash: [u8; 32],
+        priority: TransactionPriority,
+    ) -> Result<()> {
+        instructions::compliance_governance::queue_multisig_transaction(
+            ctx,
+            page_index,
+            transaction_type,
+            target,
+            data_hash,
+            priority,
+        )
+    }
+
+    /// Approve a queued multisig transaction (multisig signer)
+    ///
+    /// The final approval starts the timelock on Protocol and DAO
+    /// multisigs.
+    ///
+    /// Parameters:
+    /// - page_index: Index of the page holding the transaction
+    /// - transaction_id: Transaction to approve
+    pub fn approve_multisig_transaction(
+        ctx: Context<SignMultisigTransaction>,
+        page_index: u32,
+        transaction_id: u64,
+    ) -> Result<()> {
+        instructions::compliance_governance::approve_multisig_transaction(
+            ctx,
+            page_index,
+            transaction_id,
+        )
+    }
+
+    /// Execute an approved multisig transaction after its timelock (multisig signer)
+    ///
+    /// Transfers count against the multisig's rolling 24h limit for the
+    /// token unless every signer approved them as an emergency.
+    ///
+    /// Parameters:
+    /// - page_index: Index of the page holding the transaction
+    /// - transaction_id: Transaction to execute
+    /// - data: Transaction data, which must match the queued data hash;
+    ///   transfers start with a Borsh-encoded SpendIntent
+    pub fn execute_multisig_transaction(
+        ctx: Context<ExecuteMultisigTransaction>,
+        page_index: u32,
+        transaction_id: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::compliance_governance::execute_multisig_transaction(
+            ctx,
+            page_index,
+            transaction_id,
+            data,
+        )
+    }
+
+    /// Veto a queued multisig transaction (veto quorum required)
+    ///
+    /// Vetoing signers must be passed as remaining accounts. An approved
+    /// transaction can only be vetoed while its timelock is running.
+    ///
+    /// Parameters:
+    /// - page_index: Index of the page holding the transaction
+    /// - transaction_id: Transaction to cancel
+    pub fn cancel_multisig_transaction(
+        ctx: Context<CancelMultisigTransaction>,
+        page_index: u32,
+        transaction_id: u64,
+    ) -> Result<()> {
+        instructions::compliance_governance::cancel_multisig_transaction(
+            ctx,
+            page_index,
+            transaction_id,
+        )
+    }
+
+    /// Set a multisig's veto quorum (signer change threshold required)
+    ///
+    /// Current signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - veto_quorum: Signers needed to cancel a queued transaction (0 = threshold)
+    pub fn set_veto_quorum(ctx: Context<UpdateMultisigSigners>, veto_quorum: u8) -> Result<()> {
+        instructions::compliance_governance::set_veto_quorum(ctx, veto_quorum)
+    }
+
+    /// Weight a multisig's signers unequally (signer change threshold required)
+    ///
+    /// Current signers must be passed as remaining accounts. Once weighted,
+    /// every threshold on the multisig counts signer weight.
+    ///
+    /// Parameters:
+    /// - weights: One weight per signer, in signer order (empty = equal weights)
+    /// - threshold: New approval threshold in weight units
+    pub fn set_signer_weights(
+        ctx: Context<SetSignerWeights>,
+        weights: Vec<u8>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::compliance_governance::set_signer_weights(ctx, weights, threshold)
+    }
+
+    /// Configure who may emergency-freeze a multisig (signer change threshold required)
+    ///
+    /// Current signers must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - emergency_contacts: Non-signer keys that count toward the emergency quorum
+    /// - emergency_threshold: Signers and contacts needed to freeze or unfreeze
+    /// - freeze_enabled: Whether emergency freezes are allowed at all
+    /// - auto_unfreeze_duration: Seconds after which anyone may lift a freeze (None = never)
+    pub fn set_emergency_config(
+        ctx: Context<UpdateMultisigSigners>,
+        emergency_contacts: Vec<Pubkey>,
+        emergency_threshold: u8,
+        freeze_enabled: bool,
+        auto_unfreeze_duration: Option<i64>,
+    ) -> Result<()> {
+        instructions::emergency_freeze::set_emergency_config(
+            ctx,
+            emergency_contacts,
+            emergency_threshold,
+            freeze_enabled,
+            auto_unfreeze_duration,
+        )
+    }
+
+    /// Freeze a multisig (emergency quorum required)
+    ///
+    /// Signers and emergency contacts must be passed as remaining accounts.
+    pub fn emergency_freeze_multisig(ctx: Context<EmergencyFreeze>) -> Result<()> {
+        instructions::emergency_freeze::emergency_freeze_multisig(ctx)
+    }
+
+    /// Lift a multisig freeze early (emergency quorum required)
+    ///
+    /// Signers and emergency contacts must be passed as remaining accounts.
+    pub fn emergency_unfreeze_multisig(ctx: Context<EmergencyFreeze>) -> Result<()> {
+        instructions::emergency_freeze::emergency_unfreeze_multisig(ctx)
+    }
+
+    /// Lift a multisig freeze once its auto-unfreeze duration has passed
+    /// Permissionless crank
+    pub fn expire_multisig_freeze(ctx: Context<ExpireMultisigFreeze>) -> Result<()> {
+        instructions::emergency_freeze::expire_multisig_freeze(ctx)
+    }
+
+    /// Pause the whole protocol via the circuit breaker (emergency quorum of a
+    /// breaker multisig required)
+    ///
+    /// Signers and emergency contacts must be passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - reason: Why the protocol is being paused (max 256 bytes)
+    pub fn emergency_pause_protocol(
+        ctx: Context<EmergencyPauseProtocol>,
+        reason: String,
+    ) -> Result<()> {
+        instructions::emergency_freeze::emergency_pause_protocol(ctx, reason)
+    }
+
+    // =====================================================
+    // CIRCUIT BREAKER
+    // =====================================================
+
+    /// Initialize the protocol circuit breaker PDA (protocol admin only)
+    pub fn initialize_circuit_breaker(ctx: Context<InitializeCircuitBreaker>) -> Result<()> {
+        security::circuit_breaker::initialize_circuit_breaker(ctx)
+    }
+
+    /// Pause the whole protocol (circuit breaker admin only)
+    ///
+    /// Parameters:
+    /// - reason: Why the protocol is being paused (max 256 bytes)
+    pub fn pause_protocol(ctx: Context<PauseProtocol>, reason: String) -> Result<()> {
+        security::circuit_breaker::pause_protocol(ctx, reason)
+    }
+
+    /// Resume the protocol after a pause (circuit breaker admin only)
+    pub fn unpause_protocol(ctx: Context<UnpauseProtocol>) -> Result<()> {
+        security::circuit_breaker::unpause_protocol(ctx)
+    }
+
+    /// Let a multisig pause the protocol with its emergency quorum (circuit breaker admin only)
+    ///
+    /// Parameters:
+    /// - multisig: Multisig to add to the breaker's authorities (max 10)
+    pub fn add_circuit_breaker_multisig(
+        ctx: Context<PauseProtocol>,
+        multisig: Pubkey,
+    ) -> Result<()> {
+        security::circuit_breaker::add_circuit_breaker_multisig(ctx, multisig)
+    }
+
+    /// Set when an anomaly kind trips the breaker automatically (circuit breaker admin only)
+    ///
+    /// Parameters:
+    /// - kind: Counter to configure (disputes filed, slashes, late arbitrations)
+    /// - threshold: Most events allowed per window before pausing (0 disables)
+    /// - window: Window length in seconds (max 7 days)
+    pub fn set_anomaly_threshold(
+        ctx: Context<PauseProtocol>,
+        kind: AnomalyKind,
+        threshold: u32,
+        window: i64,
+    ) -> Result<()> {
+        security::circuit_breaker::set_anomaly_threshold(ctx, kind, threshold, window)
+    }
+
+    /// Lift a pause, including an automatic trip, and restart anomaly counts
+    /// (approvals of a breaker multisig required)
+    ///
+    /// Signers must be passed as remaining accounts.
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        security::circuit_breaker::reset_circuit_breaker(ctx)
+    }
+
+    /// Grow a circuit breaker created before the layout version to the
+    /// current layout
+    ///
+    /// Permissionless; the payer covers the added rent. No anomaly
+    /// thresholds are set until the admin configures them.
+    pub fn migrate_circuit_breaker(ctx: Context<MigrateCircuitBreaker>) -> Result<()> {
+        security::circuit_breaker::migrate_circuit_breaker(ctx)
+    }
+
+    pub fn initialize_governance_proposal(
+        ctx: Context<InitializeGovernanceProposal>,
+        proposal_id: u64,
+        title: String,
+        description: String,
+        proposal_type: ProposalType,
+        execution_params: ExecutionParams,
+        snapshot_strategy: SnapshotStrategy,
+    ) -> Result<()> {
+        instructions::compliance_governance::initialize_governance_proposal(
+            ctx,
+            proposal_id,
+            title,
+            description,
+            proposal_type,
+            execution_params,
+            snapshot_strategy,
+        )
+    }
+
+    /// Record the voter's voting power as of a proposal's snapshot point
+    ///
+    /// Counts stake untouched since the snapshot point (with its lock
+    /// multiplier) and the reputation of an agent registered before it.
+    /// Required before `cast_vote`; one snapshot per voter per proposal.
+    pub fn snapshot_voting_power(ctx: Context<SnapshotVotingPower>) -> Result<()> {
+        instructions::compliance_governance::snapshot_voting_power(ctx)
+    }
+
+    /// Name the Protocol multisig acting as security council and grant its
+    /// powers (protocol admin only)
+    ///
+    /// Parameters:
+    /// - powers: Council powers; `veto_power` enables `veto_proposal`
+    pub fn initialize_security_council(
+        ctx: Context<InitializeSecurityCouncil>,
+        powers: CouncilPowers,
+    ) -> Result<()> {
+        instructions::security_council::initialize_security_council(ctx, powers)
+    }
+
+    /// Change the security council's powers (protocol admin only)
+    ///
+    /// Parameters:
+    /// - powers: Replacement council powers
+    pub fn update_council_powers(
+        ctx: Context<UpdateCouncilPowers>,
+        powers: CouncilPowers,
+    ) -> Result<()> {
+        instructions::security_council::update_council_powers(ctx, powers)
+    }
+
+    /// Veto a passed proposal while its execution timelock runs (council
+    /// supermajority required)
+    ///
+    /// Council signers must be passed as remaining accounts. The council
+    /// can't veto again until a review proposal is filed for this veto.
+    ///
+    /// Parameters:
+    /// - rationale_hash: Hash of the published veto rationale
+    pub fn veto_proposal(ctx: Context<VetoProposal>, rationale_hash: [u8; 32]) -> Result<()> {
+        instructions::security_council::veto_proposal(ctx, rationale_hash)
+    }
+
+    /// Attach the community proposal reviewing a council veto
+    /// Permissionless; the review must be a governance update created after the veto
+    pub fn file_veto_review(ctx: Context<FileVetoReview>) -> Result<()> {
+        instructions::security_council::file_veto_review(ctx)
+    }
+
+    /// Create the governed parameter registry with every parameter at its
+    /// default (protocol admin only)
+    pub fn initialize_parameter_registry(ctx: Context<InitializeParameterRegistry>) -> Result<()> {
+        instructions::parameter_registry::initialize_parameter_registry(ctx)
+    }
+
+    /// Apply a parameter change from an executed ParameterUpdate proposal
+    ///
+    /// Permissionless; the proposal must carry this exact instruction among
+    /// its execution instructions, and changes decided before the
+    /// parameter's last change are rejected.
+    ///
+    /// Parameters:
+    /// - key: Parameter to change
+    /// - value: New value, within the parameter's bounds
+    pub fn apply_parameter_change(
+        ctx: Context<ApplyParameterChange>,
+        key: ParameterKey,
+        value: u64,
+    ) -> Result<()> {
+        instructions::parameter_registry::apply_parameter_change(ctx, key, value)
+    }
+
+    /// Vote on an active governance proposal
+    ///
+    /// Voting power comes from the voter's snapshot for the proposal. Each
+    /// voter gets one vote record per proposal.
+    ///
+    /// Parameters:
+    /// - choice: For, Against or Abstain
+    pub fn cast_vote(ctx: Context<CastVote>, choice: VoteChoice) -> Result<()> {
+        instructions::compliance_governance::cast_vote(ctx, choice)
+    }
+
+    /// Decide a proposal after its voting period (permissionless)
+    pub fn tally_votes(ctx: Context<TallyVotes>) -> Result<()> {
+        instructions::compliance_governance::tally_votes(ctx)
+    }
+
+    /// Mark a passed proposal executed after its execution delay
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::compliance_governance::execute_proposal(ctx)
+    }
+
+    /// Create the proposal deposit config (protocol admin and multisig
+    /// threshold required)
+    ///
+    /// Multisig signers are passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - minimum_deposit: Lamports each proposer escrows per proposal
+    /// - treasury: Recipient of forfeited deposits
+    pub fn initialize_proposal_deposit_config(
+        ctx: Context<InitializeProposalDepositConfig>,
+        minimum_deposit: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::proposal_deposit::initialize_proposal_deposit_config(
+            ctx,
+            minimum_deposit,
+            treasury,
+        )
+    }
+
+    /// Change the proposal deposit or treasury (multisig threshold required)
+    ///
+    /// Multisig signers are passed as remaining accounts.
     ///
     /// Parameters:
-    /// - new_score: New Ghost Score (0-1000)
-    /// - components: Optional reputation component breakdown
-    pub fn update_ghost_score(
-        ctx: Context<UpdateGhostScore>,
-        new_score: u64,
-        components: Option<Vec<crate::state::ReputationComponent>>,
+    /// - minimum_deposit: Lamports each proposer escrows per proposal
+    /// - treasury: Recipient of forfeited deposits
+    pub fn update_proposal_deposit_config(
+        ctx: Context<UpdateProposalDepositConfig>,
+        minimum_deposit: u64,
+        treasury: Pubkey,
     ) -> Result<()> {
-        instructions::ghost::update_ghost_score(ctx, new_score, components)
+        instructions::proposal_deposit::update_proposal_deposit_config(
+            ctx,
+            minimum_deposit,
+            treasury,
+        )
     }
 
-    // MARKETPLACE INSTRUCTIONS REMOVED
-
-    // MESSAGING INSTRUCTIONS REMOVED
-
-    // PROCESS PAYMENT INSTRUCTIONS REMOVED (WorkOrder Deprecated)
-
-    // WORK ORDER INSTRUCTIONS REMOVED
-
-    // =====================================================
-    // A2A & H2A PROTOCOL INSTRUCTIONS - REMOVED
-    // Agent/human messaging not needed for VC/Reputation layer
-    // =====================================================
-
-    /* A2A/H2A entry points removed - messaging not aligned with pivot:
-    pub fn create_a2a_session(...)
-    pub fn send_a2a_message(...)
-    pub fn update_a2a_status(...)
-    pub fn create_communication_session(...)
-    pub fn send_communication_message(...)
-    pub fn update_participant_status(...)
-    */
-
-    // ANALYTICS INSTRUCTIONS REMOVED
+    /// Withdraw an active proposal, forfeiting its deposit (proposer only)
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::proposal_deposit::cancel_proposal(ctx)
+    }
 
-    // AUCTION INSTRUCTIONS REMOVED
+    /// Refund a decided proposal's deposit if it reached quorum, or forfeit
+    /// it to the treasury (permissionless)
+    pub fn release_proposal_deposit(ctx: Context<ReleaseProposalDeposit>) -> Result<()> {
+        instructions::proposal_deposit::release_proposal_deposit(ctx)
+    }
 
-    // BULK DEALS INSTRUCTIONS REMOVED
+    /// Create a queue for batched proposal execution (caller becomes the executor)
+    ///
+    /// Parameters:
+    /// - batch_id: Queue identifier, unique per executor
+    /// - max_batch_size: Most proposals settled per process_queue call
+    /// - execution_window: Seconds after a proposal's execution time before it expires (0 = never)
+    pub fn initialize_execution_queue(
+        ctx: Context<InitializeExecutionQueue>,
+        batch_id: u64,
+        max_batch_size: u8,
+        execution_window: i64,
+    ) -> Result<()> {
+        instructions::execution_queue::initialize_execution_queue(
+            ctx,
+            batch_id,
+            max_batch_size,
+            execution_window,
+        )
+    }
 
-    // =====================================================
-    // COMPLIANCE & GOVERNANCE INSTRUCTIONS
-    // =====================================================
+    /// Queue a passed proposal for batched execution (queue executor only)
+    ///
+    /// Parameters:
+    /// - priority: Proposals run in priority order, oldest first within a priority
+    pub fn enqueue_proposal(
+        ctx: Context<EnqueueProposal>,
+        priority: TransactionPriority,
+    ) -> Result<()> {
+        instructions::execution_queue::enqueue_proposal(ctx, priority)
+    }
 
-    pub fn initialize_audit_trail(
-        ctx: Context<InitializeAuditTrail>,
-        entity_type: String,
-        config: AuditConfig,
+    /// Execute due queued proposals in priority order (queue executor only)
+    ///
+    /// Queued proposal accounts must be passed as writable remaining
+    /// accounts; an ExecutionResultEvent is emitted for each one settled.
+    pub fn process_queue<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessQueue<'info>>,
     ) -> Result<()> {
-        instructions::compliance_governance::initialize_audit_trail(ctx, entity_type, config)
+        instructions::execution_queue::process_queue(ctx)
     }
 
-    pub fn create_multisig(
-        ctx: Context<CreateMultisig>,
-        multisig_id: u64,
-        threshold: u8,
-        signers: Vec<Pubkey>,
-        config: MultisigConfig,
+    /// Attach an auto-execution trigger to a proposal or execution queue
+    /// (target authority only)
+    ///
+    /// Parameters:
+    /// - trigger_id: Trigger identifier, unique per target
+    /// - trigger_type: Classification of the trigger
+    /// - conditions: Timestamp, slot or oracle conditions that must all hold
+    /// - max_triggers: Times the trigger may fire (0 = unlimited)
+    pub fn create_trigger(
+        ctx: Context<CreateTrigger>,
+        trigger_id: u64,
+        trigger_type: TriggerType,
+        conditions: Vec<TriggerCondition>,
+        max_triggers: u32,
     ) -> Result<()> {
-        instructions::compliance_governance::create_multisig(
+        instructions::auto_execution::create_trigger(
             ctx,
-            multisig_id,
-            threshold,
-            signers,
-            config,
+            trigger_id,
+            trigger_type,
+            conditions,
+            max_triggers,
         )
     }
 
-    pub fn initialize_governance_proposal(
-        ctx: Context<InitializeGovernanceProposal>,
-        proposal_id: u64,
-        title: String,
-        description: String,
-        proposal_type: ProposalType,
-        execution_params: ExecutionParams,
+    /// Evaluate a trigger and execute its target when its conditions hold (permissionless)
+    ///
+    /// Oracle accounts and, for queue targets, queued proposal accounts
+    /// must be passed as remaining accounts.
+    pub fn evaluate_trigger<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EvaluateTrigger<'info>>,
     ) -> Result<()> {
-        instructions::compliance_governance::initialize_governance_proposal(
-            ctx,
-            proposal_id,
-            title,
-            description,
-            proposal_type,
-            execution_params,
-        )
+        instructions::auto_execution::evaluate_trigger(ctx)
+    }
+
+    /// Cancel a queued proposal that allows cancellation after approval (queue executor only)
+    pub fn cancel_queued(ctx: Context<CancelQueued>) -> Result<()> {
+        instructions::execution_queue::cancel_queued(ctx)
     }
 
     pub fn initialize_rbac_config(
@@ -1269,15 +3074,12 @@ pub mod ghostspeak_marketplace {
     */
 
     // =====================================================
-    // GOVERNANCE VOTING INSTRUCTIONS - REMOVED
-    // Simple admin authority via protocol_config + multisig
+    // VOTE DELEGATION - REMOVED
+    // Proposal voting is per voter (see cast_vote)
     // =====================================================
 
-    /* Governance voting removed - simple admin authority instead:
-    pub fn cast_vote(...)
+    /* Vote delegation removed:
     pub fn delegate_vote(...)
-    pub fn tally_votes(...)
-    pub fn execute_proposal(...)
     */
 
     // =====================================================
@@ -1297,6 +3099,8 @@ pub mod ghostspeak_marketplace {
 
     /// Create a new credential type (e.g., AgentIdentity, Reputation, JobCompletion)
     /// This is an admin-only operation typically done by governance/multisig.
+    ///
+    /// Types of a registered `Custom` kind can only be created by the kind's owner.
     pub fn create_credential_type(
         ctx: Context<CreateCredentialType>,
         name: String,
@@ -1307,18 +3111,69 @@ pub mod ghostspeak_marketplace {
         instructions::credential::create_credential_type(ctx, name, kind, schema_uri, description)
     }
 
+    /// Publish a new schema version for a credential type (type authority only)
+    ///
+    /// Parameters:
+    /// - version: Must be the type's current schema version + 1
+    /// - schema_uri: URI to the new JSON-LD schema
+    /// - migration_notes: How to migrate from the previous version
+    pub fn publish_credential_schema(
+        ctx: Context<PublishCredentialSchema>,
+        version: u16,
+        schema_uri: String,
+        migration_notes: String,
+    ) -> Result<()> {
+        instructions::credential::publish_credential_schema(
+            ctx,
+            version,
+            schema_uri,
+            migration_notes,
+        )
+    }
+
+    /// Deprecate a superseded schema version (type authority only)
+    pub fn deprecate_credential_schema(ctx: Context<DeprecateCredentialSchema>) -> Result<()> {
+        instructions::credential::deprecate_credential_schema(ctx)
+    }
+
     /// Create a credential template from a credential type for issuing credentials
+    ///
+    /// Parameters:
+    /// - issuance_fee_lamports: Paid by the requester per issuance (0 for none),
+    ///   split with the protocol treasury per `credential_fee_bps`
     pub fn create_credential_template(
         ctx: Context<CreateCredentialTemplate>,
         name: String,
         image_uri: String,
         crossmint_template_id: Option<String>,
+        issuance_fee_lamports: u64,
     ) -> Result<()> {
         instructions::credential::create_credential_template(
             ctx,
             name,
             image_uri,
             crossmint_template_id,
+            issuance_fee_lamports,
+        )
+    }
+
+    /// Fork an existing credential template, recording its provenance
+    ///
+    /// Parameters:
+    /// - royalty_lamports: Paid to the original author per issuance (0 for none)
+    pub fn fork_credential_template(
+        ctx: Context<ForkCredentialTemplate>,
+        name: String,
+        image_uri: String,
+        crossmint_template_id: Option<String>,
+        royalty_lamports: u64,
+    ) -> Result<()> {
+        instructions::credential::fork_credential_template(
+            ctx,
+            name,
+            image_uri,
+            crossmint_template_id,
+            royalty_lamports,
         )
     }
 
@@ -1342,33 +3197,394 @@ pub mod ghostspeak_marketplace {
         )
     }
 
+    /// Choose whether credentials issued to a subject must be accepted first
+    ///
+    /// Signed by the subject, or by the owner of the subject agent.
+    pub fn set_holder_policy(ctx: Context<SetHolderPolicy>, require_acceptance: bool) -> Result<()> {
+        instructions::credential::set_holder_policy(ctx, require_acceptance)
+    }
+
+    /// Accept a credential held as Pending under the subject's holder policy
+    pub fn accept_credential(ctx: Context<AcceptCredential>) -> Result<()> {
+        instructions::credential::accept_credential(ctx)
+    }
+
+    /// Require approval from co-issuers for credentials issued from a template
+    ///
+    /// Parameters:
+    /// - co_issuers: Keys that must each cosign (empty to disable)
+    pub fn set_template_co_issuers(
+        ctx: Context<SetTemplateCoIssuers>,
+        co_issuers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::credential::set_template_co_issuers(ctx, co_issuers)
+    }
+
+    /// Approve a pending credential as one of its template's co-issuers
+    pub fn cosign_credential(ctx: Context<CosignCredential>) -> Result<()> {
+        instructions::credential::cosign_credential(ctx)
+    }
+
+    /// Mark a credential expired once its expiry has passed (permissionless crank)
+    pub fn expire_credential(ctx: Context<ExpireCredential>) -> Result<()> {
+        instructions::credential::expire_credential(ctx)
+    }
+
+    /// Mint a soulbound Token-2022 token representing an active credential
+    ///
+    /// Optional: lets token-only wallets and marketplaces display the
+    /// credential. Issuer only; at most one token per credential.
+    pub fn mint_credential_token(ctx: Context<MintCredentialToken>) -> Result<()> {
+        instructions::credential_token::mint_credential_token(ctx)
+    }
+
+    /// Re-bind an active credential to a new subject after an identity rotation
+    ///
+    /// Requires both the issuer and the current holder to sign.
+    pub fn rebind_credential(ctx: Context<RebindCredential>) -> Result<()> {
+        instructions::credential::rebind_credential(ctx)
+    }
+
     /// Revoke an issued credential
     /// Only the original issuer can revoke.
     pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
         instructions::credential::revoke_credential(ctx)
     }
 
-    /// Update the cross-chain sync status after syncing to Crossmint
-    pub fn update_crosschain_status(
-        ctx: Context<UpdateCrossChainStatus>,
-        crossmint_credential_id: String,
-        status: CrossChainStatus,
+    /// Close a revoked or expired credential and reclaim its rent
+    ///
+    /// Callable by the issuer at any time, or by the holder once the grace
+    /// period has passed. The credential's status list bit stays set as a
+    /// tombstone; rent goes back to the issuer who paid it.
+    pub fn close_credential(ctx: Context<CloseCredential>) -> Result<()> {
+        instructions::credential::close_credential(ctx)
+    }
+
+    /// Start (or retry after backoff) a Crossmint sync attempt
+    /// Only the original issuer can request a sync.
+    pub fn request_crosschain_sync(ctx: Context<RequestCrossChainSync>) -> Result<()> {
+        instructions::credential::request_crosschain_sync(ctx)
+    }
+
+    /// Apply an attested Crossmint status change (Submitted, Confirmed or Failed)
+    ///
+    /// Must be preceded by an Ed25519 instruction in which the configured
+    /// cross-chain attestor signs the update.
+    ///
+    /// Parameters:
+    /// - update: Status change and its Crossmint ID or failure reason
+    pub fn update_crosschain_status(
+        ctx: Context<UpdateCrossChainStatus>,
+        update: CrossChainUpdate,
+    ) -> Result<()> {
+        instructions::credential::update_crosschain_status(ctx, update)
+    }
+
+    /// Record an attested mirror of a credential on an EVM chain (e.g. ERC-8004)
+    ///
+    /// Must be preceded by an Ed25519 instruction in which the configured
+    /// cross-chain attestor signs the record for its next revision.
+    ///
+    /// Parameters:
+    /// - chain_id: EVM chain ID of the mirror
+    /// - registry: Registry contract address
+    /// - attestation_tx_hash: Transaction that wrote the mirror
+    pub fn record_crosschain_attestation(
+        ctx: Context<RecordCrossChainAttestation>,
+        chain_id: u64,
+        registry: [u8; 20],
+        attestation_tx_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::credential::record_crosschain_attestation(
+            ctx,
+            chain_id,
+            registry,
+            attestation_tx_hash,
+        )
+    }
+
+    /// Verify the issuer's signature over a credential's subject data hash
+    ///
+    /// Must be preceded by an Ed25519 instruction in which `signing_key`
+    /// (an assertion method of the issuer's DID) signs the hash. Records a
+    /// CredentialVerification PDA readable by other programs.
+    pub fn verify_credential(ctx: Context<VerifyCredential>, signing_key: Pubkey) -> Result<()> {
+        instructions::credential::verify_credential(ctx, signing_key)
+    }
+
+    /// Record a holder presenting a credential to a verifier
+    ///
+    /// Parameters:
+    /// - nonce: Distinguishes repeat presentations to the same verifier
+    /// - purpose: Why the verifier requested the credential
+    /// - disclosure_commitment: Commitment to selectively disclosed claims
+    pub fn present_credential(
+        ctx: Context<PresentCredential>,
+        nonce: u64,
+        purpose: String,
+        disclosure_commitment: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::credential::present_credential(ctx, nonce, purpose, disclosure_commitment)
+    }
+
+    /// Deactivate a credential type (no new credentials can be issued)
+    pub fn deactivate_credential_type(ctx: Context<DeactivateCredentialType>) -> Result<()> {
+        instructions::credential::deactivate_credential_type(ctx)
+    }
+
+    /// Deactivate a credential template (no new credentials can be issued from it)
+    pub fn deactivate_credential_template(ctx: Context<DeactivateCredentialTemplate>) -> Result<()> {
+        instructions::credential::deactivate_credential_template(ctx)
+    }
+
+    /// Issue a credential as a leaf in the issuer's compressed Merkle tree
+    ///
+    /// For mass issuance: no per-credential account or rent. Limited to
+    /// templates without co-issuers or royalties and to subjects that accept
+    /// credentials directly; compressed credentials cannot be revoked.
+    ///
+    /// Parameters:
+    /// - credential_id: Unique credential ID
+    /// - subject_data_hash: Hash of the off-chain subject data
+    /// - subject_data_uri: URI of the subject data
+    /// - expires_at: Optional expiry
+    /// - source_account: Optional source data reference
+    pub fn issue_credential_compressed(
+        ctx: Context<IssueCredentialCompressed>,
+        credential_id: String,
+        subject_data_hash: [u8; 32],
+        subject_data_uri: String,
+        expires_at: Option<i64>,
+        source_account: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::credential_compressed::issue_credential_compressed(
+            ctx,
+            credential_id,
+            subject_data_hash,
+            subject_data_uri,
+            expires_at,
+            source_account,
+        )
+    }
+
+    /// Create a DID document as a leaf in the operator's compressed Merkle tree
+    ///
+    /// For fleets of lightweight agents: no per-document account or rent.
+    /// The document is emitted in full so indexers can serve it with proofs.
+    ///
+    /// Parameters:
+    /// - did_string: DID identifier (did:sol:network:pubkey)
+    /// - controller: Key allowed to update the document
+    /// - verification_methods: Initial verification methods
+    /// - service_endpoints: Initial service endpoints
+    pub fn create_did_compressed(
+        ctx: Context<CreateDidCompressed>,
+        did_string: String,
+        controller: Pubkey,
+        verification_methods: Vec<VerificationMethod>,
+        service_endpoints: Vec<ServiceEndpoint>,
+    ) -> Result<()> {
+        instructions::did_compressed::create_did_compressed(
+            ctx,
+            did_string,
+            controller,
+            verification_methods,
+            service_endpoints,
+        )
+    }
+
+    /// Update a compressed DID document
+    ///
+    /// Same batched changes as `update_did_document`, signed by the leaf's
+    /// controller. The leaf's Merkle proof is passed as remaining accounts.
+    ///
+    /// Parameters:
+    /// - root: Tree root the proof was built against
+    /// - leaf_index: Index of the document's leaf
+    /// - current: Current document contents
+    /// - add_verification_methods: Verification methods to add
+    /// - remove_verification_method_ids: Method IDs to remove
+    /// - add_service_endpoints: Service endpoints to add
+    /// - remove_service_endpoint_ids: Service IDs to remove
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_did_compressed<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateDidCompressed<'info>>,
+        root: [u8; 32],
+        leaf_index: u32,
+        current: CompressedDidLeaf,
+        add_verification_methods: Vec<VerificationMethod>,
+        remove_verification_method_ids: Vec<String>,
+        add_service_endpoints: Vec<ServiceEndpoint>,
+        remove_service_endpoint_ids: Vec<String>,
+    ) -> Result<()> {
+        instructions::did_compressed::update_did_compressed(
+            ctx,
+            root,
+            leaf_index,
+            current,
+            add_verification_methods,
+            remove_verification_method_ids,
+            add_service_endpoints,
+            remove_service_endpoint_ids,
+        )
+    }
+
+    /// Deactivate a compressed DID document (irreversible)
+    ///
+    /// Signed by the leaf's controller; Merkle proof as remaining accounts.
+    ///
+    /// Parameters:
+    /// - root: Tree root the proof was built against
+    /// - leaf_index: Index of the document's leaf
+    /// - current: Current document contents
+    pub fn deactivate_did_compressed<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateDidCompressed<'info>>,
+        root: [u8; 32],
+        leaf_index: u32,
+        current: CompressedDidLeaf,
+    ) -> Result<()> {
+        instructions::did_compressed::deactivate_did_compressed(ctx, root, leaf_index, current)
+    }
+
+    /// Resolve a compressed DID document (read-only)
+    ///
+    /// Fails unless `leaf` is proven (remaining accounts) to be the document
+    /// at `leaf_index`.
+    ///
+    /// Parameters:
+    /// - root: Tree root the proof was built against
+    /// - leaf_index: Index of the document's leaf
+    /// - leaf: Document contents to verify
+    pub fn resolve_did_compressed<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDidCompressed<'info>>,
+        root: [u8; 32],
+        leaf_index: u32,
+        leaf: CompressedDidLeaf,
+    ) -> Result<()> {
+        instructions::did_compressed::resolve_did_compressed(ctx, root, leaf_index, leaf)
+    }
+
+    /// Register a custom credential kind ID (caller becomes its owner)
+    ///
+    /// Parameters:
+    /// - kind_id: ID used in `CredentialKind::Custom` (non-zero)
+    /// - name: Human-readable category name
+    pub fn register_custom_credential_kind(
+        ctx: Context<RegisterCustomCredentialKind>,
+        kind_id: u16,
+        name: String,
+    ) -> Result<()> {
+        instructions::credential::register_custom_credential_kind(ctx, kind_id, name)
+    }
+
+    /// Transfer ownership of a custom credential kind (owner only)
+    pub fn transfer_custom_credential_kind(
+        ctx: Context<TransferCustomCredentialKind>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::credential::transfer_custom_credential_kind(ctx, new_owner)
+    }
+
+    /// Delegate issuance of a credential type to an operator key (type authority only)
+    ///
+    /// The operator can then sign `issue_credential` for the authority's
+    /// templates; credentials still name the authority as issuer.
+    ///
+    /// Parameters:
+    /// - max_issuances: Cap on credentials the operator may issue
+    /// - expires_at: When the delegation lapses (None = until revoked)
+    pub fn delegate_issuance(
+        ctx: Context<DelegateIssuance>,
+        max_issuances: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::credential::delegate_issuance(ctx, max_issuances, expires_at)
+    }
+
+    /// Revoke an issuance delegation (delegator only)
+    pub fn revoke_issuance_delegation(ctx: Context<RevokeIssuanceDelegation>) -> Result<()> {
+        instructions::credential::revoke_issuance_delegation(ctx)
+    }
+
+    /// Create the governed registry of trusted credential issuers (protocol
+    /// admin only)
+    ///
+    /// Multisig signers must be passed as remaining accounts and meet the threshold.
+    ///
+    /// Parameters:
+    /// - enforce_reserved_kinds: Restrict AgentIdentity and ReputationScore
+    ///   issuance to registered issuers
+    pub fn initialize_issuer_registry(
+        ctx: Context<InitializeIssuerRegistry>,
+        enforce_reserved_kinds: bool,
+    ) -> Result<()> {
+        instructions::credential::initialize_issuer_registry(ctx, enforce_reserved_kinds)
+    }
+
+    /// Add, update or deactivate a trusted issuer for a credential kind
+    ///
+    /// Parameters:
+    /// - issuer: Issuer key
+    /// - kind: Credential kind the approval covers
+    /// - tier: Trust tier shown to resolvers
+    /// - is_active: Whether the approval is in effect
+    pub fn set_registered_issuer(
+        ctx: Context<ManageIssuerRegistry>,
+        issuer: Pubkey,
+        kind: CredentialKind,
+        tier: IssuerTrustTier,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::credential::set_registered_issuer(ctx, issuer, kind, tier, is_active)
+    }
+
+    /// Turn registered-issuer enforcement for protocol-reserved kinds on or off
+    pub fn set_issuer_registry_enforcement(
+        ctx: Context<ManageIssuerRegistry>,
+        enforce_reserved_kinds: bool,
+    ) -> Result<()> {
+        instructions::credential::set_issuer_registry_enforcement(ctx, enforce_reserved_kinds)
+    }
+
+    /// Create the credential dispute config reviewed by the dispute multisig
+    /// (protocol admin only)
+    ///
+    /// Multisig signers must be passed as remaining accounts and meet the threshold.
+    ///
+    /// Parameters:
+    /// - min_bond_lamports: Minimum bond a flagger must post
+    /// - treasury: Recipient of bonds forfeited by dismissed flags
+    pub fn initialize_credential_dispute_config(
+        ctx: Context<InitializeCredentialDisputeConfig>,
+        min_bond_lamports: u64,
+        treasury: Pubkey,
     ) -> Result<()> {
-        instructions::credential::update_crosschain_status(
+        instructions::credential_dispute::initialize_credential_dispute_config(
             ctx,
-            crossmint_credential_id,
-            status,
+            min_bond_lamports,
+            treasury,
         )
     }
 
-    /// Deactivate a credential type (no new credentials can be issued)
-    pub fn deactivate_credential_type(ctx: Context<DeactivateCredentialType>) -> Result<()> {
-        instructions::credential::deactivate_credential_type(ctx)
+    /// Flag an active credential as fraudulent, escrowing a bond for review
+    ///
+    /// Parameters:
+    /// - evidence_uri: URI of the supporting evidence
+    /// - bond_lamports: Bond to escrow (at least the configured minimum)
+    pub fn flag_credential(
+        ctx: Context<FlagCredential>,
+        evidence_uri: String,
+        bond_lamports: u64,
+    ) -> Result<()> {
+        instructions::credential_dispute::flag_credential(ctx, evidence_uri, bond_lamports)
     }
 
-    /// Deactivate a credential template (no new credentials can be issued from it)
-    pub fn deactivate_credential_template(ctx: Context<DeactivateCredentialTemplate>) -> Result<()> {
-        instructions::credential::deactivate_credential_template(ctx)
+    /// Resolve a credential flag (dispute multisig threshold required)
+    ///
+    /// Upholding force-revokes the credential and refunds the bond;
+    /// dismissing forfeits the bond to the treasury.
+    pub fn resolve_credential_flag(ctx: Context<ResolveCredentialFlag>, uphold: bool) -> Result<()> {
+        instructions::credential_dispute::resolve_credential_flag(ctx, uphold)
     }
 
     // =====================================================
@@ -1402,44 +3618,340 @@ pub mod ghostspeak_marketplace {
 
     /// Update an existing DID document
     ///
-    /// Add or remove verification methods and service endpoints.
-    /// Only the controller can update the DID document.
+    /// Add or remove verification methods and service endpoints in one
+    /// batch (removals first). Only the controller can update the DID
+    /// document, and the result must fit the document account.
     ///
     /// Parameters:
-    /// - add_verification_method: Optional verification method to add
-    /// - remove_verification_method_id: Optional method ID to remove
-    /// - add_service_endpoint: Optional service endpoint to add
-    /// - remove_service_endpoint_id: Optional service ID to remove
+    /// - add_verification_methods: Verification methods to add
+    /// - remove_verification_method_ids: Method IDs to remove
+    /// - add_service_endpoints: Service endpoints to add
+    /// - remove_service_endpoint_ids: Service IDs to remove
     pub fn update_did_document(
         ctx: Context<UpdateDidDocument>,
-        add_verification_method: Option<VerificationMethod>,
-        remove_verification_method_id: Option<String>,
-        add_service_endpoint: Option<ServiceEndpoint>,
-        remove_service_endpoint_id: Option<String>,
+        add_verification_methods: Vec<VerificationMethod>,
+        remove_verification_method_ids: Vec<String>,
+        add_service_endpoints: Vec<ServiceEndpoint>,
+        remove_service_endpoint_ids: Vec<String>,
     ) -> Result<()> {
         instructions::did::update_did_document(
             ctx,
-            add_verification_method,
-            remove_verification_method_id,
-            add_service_endpoint,
-            remove_service_endpoint_id,
+            add_verification_methods,
+            remove_verification_method_ids,
+            add_service_endpoints,
+            remove_service_endpoint_ids,
         )
     }
 
     /// Deactivate a DID document
     ///
-    /// Permanently deactivates the DID document. This operation is irreversible.
-    /// Only the controller can deactivate their DID.
-    pub fn deactivate_did_document(ctx: Context<DeactivateDidDocument>) -> Result<()> {
-        instructions::did::deactivate_did_document(ctx)
+    /// The controller can reactivate it during the grace period; afterwards
+    /// the deactivation is irreversible and the account can be tombstoned
+    /// for rent recovery. Only the controller can deactivate their DID.
+    ///
+    /// Parameters:
+    /// - grace_period: Reactivation window in seconds (0 to 90 days)
+    pub fn deactivate_did_document(
+        ctx: Context<DeactivateDidDocument>,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::did::deactivate_did_document(ctx, grace_period)
+    }
+
+    /// Reactivate a deactivated DID document
+    ///
+    /// Only the controller, and only within the deactivation grace period.
+    pub fn reactivate_did_document(ctx: Context<ReactivateDidDocument>) -> Result<()> {
+        instructions::did::reactivate_did_document(ctx)
+    }
+
+    /// Reclaim the rent of a tombstoned DID document
+    ///
+    /// Permissionless once the grace period has ended; shrinks the document
+    /// to a tombstone and refunds the rent to its controller.
+    pub fn close_did_document(ctx: Context<CloseDidDocument>) -> Result<()> {
+        instructions::did::close_did_document(ctx)
+    }
+
+    /// Install or change a DID's controller set
+    ///
+    /// Hands a DID to several controllers (wallets or governance multisigs)
+    /// with an m-of-n update threshold. The first call is signed by the single
+    /// controller; later changes need the current set's approval, passed as
+    /// remaining accounts.
+    ///
+    /// Parameters:
+    /// - controllers: Controller keys (up to 5)
+    /// - threshold: Approvals required for updates
+    pub fn set_did_controllers(
+        ctx: Context<SetDidControllers>,
+        controllers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::did::set_did_controllers(ctx, controllers, threshold)
+    }
+
+    /// Update a multi-controller DID document
+    ///
+    /// Same changes as `update_did_document`, approved by the controller set
+    /// threshold (approving controllers passed as remaining accounts).
+    pub fn update_did_document_by_controllers(
+        ctx: Context<UpdateDidDocumentByControllers>,
+        add_verification_methods: Vec<VerificationMethod>,
+        remove_verification_method_ids: Vec<String>,
+        add_service_endpoints: Vec<ServiceEndpoint>,
+        remove_service_endpoint_ids: Vec<String>,
+    ) -> Result<()> {
+        instructions::did::update_did_document_by_controllers(
+            ctx,
+            add_verification_methods,
+            remove_verification_method_ids,
+            add_service_endpoints,
+            remove_service_endpoint_ids,
+        )
+    }
+
+    /// Deactivate a multi-controller DID document
+    ///
+    /// Requires the controller set threshold; irreversible once the grace
+    /// period ends.
+    ///
+    /// Parameters:
+    /// - grace_period: Reactivation window in seconds (0 to 90 days)
+    pub fn deactivate_did_document_by_controllers(
+        ctx: Context<DeactivateDidDocumentByControllers>,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::did::deactivate_did_document_by_controllers(ctx, grace_period)
+    }
+
+    /// Reactivate a deactivated multi-controller DID document
+    ///
+    /// Requires the controller set threshold, within the grace period.
+    pub fn reactivate_did_document_by_controllers(
+        ctx: Context<ReactivateDidDocumentByControllers>,
+    ) -> Result<()> {
+        instructions::did::reactivate_did_document_by_controllers(ctx)
+    }
+
+    /// Designate recovery guardians for a DID
+    ///
+    /// Only the controller can set guardians. A quorum of guardians can later
+    /// replace a lost controller key after the recovery delay.
+    ///
+    /// Parameters:
+    /// - guardians: Guardian keys (up to 7, wallets or multisigs)
+    /// - threshold: Guardian approvals required to start a recovery
+    /// - recovery_delay: Challenge window in seconds (at least 2 days)
+    pub fn set_did_guardians(
+        ctx: Context<SetDidGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        recovery_delay: i64,
+    ) -> Result<()> {
+        instructions::did::set_did_guardians(ctx, guardians, threshold, recovery_delay)
+    }
+
+    /// Start a guardian recovery of a DID
+    ///
+    /// Signed by a guardian; further approving guardians are passed as
+    /// remaining accounts. Opens a challenge window for the controller.
+    ///
+    /// Parameters:
+    /// - new_controller: Replacement controller key
+    pub fn initiate_did_recovery(
+        ctx: Context<InitiateDidRecovery>,
+        new_controller: Pubkey,
+    ) -> Result<()> {
+        instructions::did::initiate_did_recovery(ctx, new_controller)
+    }
+
+    /// Cancel a pending DID recovery
+    ///
+    /// Only the current controller can cancel.
+    pub fn cancel_did_recovery(ctx: Context<CancelDidRecovery>) -> Result<()> {
+        instructions::did::cancel_did_recovery(ctx)
+    }
+
+    /// Execute a DID recovery after its challenge window
+    ///
+    /// Permissionless; replaces the document controller.
+    pub fn execute_did_recovery(ctx: Context<ExecuteDidRecovery>) -> Result<()> {
+        instructions::did::execute_did_recovery(ctx)
+    }
+
+    /// Propose a new controller for a DID
+    ///
+    /// First step of a two-step ownership transfer; the proposed controller
+    /// completes it with `accept_did_controller`.
+    ///
+    /// Parameters:
+    /// - new_controller: Proposed controller key
+    pub fn transfer_did_controller(
+        ctx: Context<TransferDidController>,
+        new_controller: Pubkey,
+    ) -> Result<()> {
+        instructions::did::transfer_did_controller(ctx, new_controller)
+    }
+
+    /// Accept a proposed DID controller transfer
+    ///
+    /// Signed by the proposed controller. Guardians appointed by the previous
+    /// controller lapse.
+    pub fn accept_did_controller(ctx: Context<AcceptDidController>) -> Result<()> {
+        instructions::did::accept_did_controller(ctx)
+    }
+
+    /// Cancel a proposed DID controller transfer
+    ///
+    /// Only the current controller can cancel.
+    pub fn cancel_did_controller_transfer(ctx: Context<CancelDidControllerTransfer>) -> Result<()> {
+        instructions::did::cancel_did_controller_transfer(ctx)
+    }
+
+    /// Rotate a DID verification key
+    ///
+    /// Atomically replaces a verification method with a new one. The controller,
+    /// the outgoing key and the incoming key must all sign, and the rotation is
+    /// recorded in a history PDA for key continuity checks.
+    ///
+    /// Parameters:
+    /// - old_method_id: ID of the verification method being replaced
+    /// - new_method: Replacement verification method (Ed25519, key of `new_key`)
+    pub fn rotate_did_key(
+        ctx: Context<RotateDidKey>,
+        old_method_id: String,
+        new_method: VerificationMethod,
+    ) -> Result<()> {
+        instructions::did::rotate_did_key(ctx, old_method_id, new_method)
     }
 
     /// Resolve a DID document (read-only)
     ///
-    /// Returns the DID document data for off-chain resolution.
-    /// This instruction exists for compatibility with standard DID resolution flows.
-    pub fn resolve_did_document(ctx: Context<ResolveDidDocument>) -> Result<()> {
-        instructions::did::resolve_did_document(ctx)
+    /// The serialized document is returned via return data as a
+    /// DidResolutionChunk; large documents span several chunks.
+    ///
+    /// Parameters:
+    /// - chunk_index: Chunk of the serialized document to return
+    pub fn resolve_did_document(
+        ctx: Context<ResolveDidDocument>,
+        chunk_index: u16,
+    ) -> Result<DidResolutionChunk> {
+        instructions::did::resolve_did_document(ctx, chunk_index)
+    }
+
+    /// Link a DID document and an agent account
+    ///
+    /// Writes reciprocal references (the agent's `did_address` and a DID →
+    /// agent link PDA). The DID controller must own the agent.
+    pub fn link_did_to_agent(ctx: Context<LinkDidToAgent>) -> Result<()> {
+        instructions::did::link_did_to_agent(ctx)
+    }
+
+    /// Verify a DID/agent binding (read-only)
+    ///
+    /// Fails unless both references agree and the DID controller owns the
+    /// agent.
+    pub fn verify_did_agent_link(ctx: Context<VerifyDidAgentLink>) -> Result<()> {
+        instructions::did::verify_did_agent_link(ctx)
+    }
+
+    /// Attest a link between a DID and an external DID
+    ///
+    /// Signed by a registered agent-identity issuer that verified the
+    /// external document (did:web, did:key, did:ethr) off-chain.
+    ///
+    /// Parameters:
+    /// - external_did: External DID to link
+    pub fn attest_external_did_link(
+        ctx: Context<AttestExternalDidLink>,
+        external_did: String,
+    ) -> Result<()> {
+        instructions::did::attest_external_did_link(ctx, external_did)
+    }
+
+    /// Link a DID to an external did:key by signed challenge
+    ///
+    /// Signed by the controller, with a preceding Ed25519 instruction in which
+    /// the did:key signs the link challenge.
+    ///
+    /// Parameters:
+    /// - external_did: External did:key to link
+    pub fn prove_external_did_link(
+        ctx: Context<ProveExternalDidLink>,
+        external_did: String,
+    ) -> Result<()> {
+        instructions::did::prove_external_did_link(ctx, external_did)
+    }
+
+    /// Revoke a link between a DID and an external DID
+    ///
+    /// Only the controller can revoke.
+    pub fn revoke_external_did_link(ctx: Context<RevokeExternalDidLink>) -> Result<()> {
+        instructions::did::revoke_external_did_link(ctx)
+    }
+
+    /// Add a scoped delegate key to a DID
+    ///
+    /// Adds an Ed25519 verification method for `delegate` and records the
+    /// capabilities it may exercise for the DID's linked agent.
+    ///
+    /// Parameters:
+    /// - method: Verification method for the delegate key
+    /// - delegate: Delegate signing key
+    /// - scopes: Capabilities granted to the key
+    /// - expires_at: Optional expiry timestamp
+    pub fn add_delegate_verification_method(
+        ctx: Context<AddDelegateVerificationMethod>,
+        method: VerificationMethod,
+        delegate: Pubkey,
+        scopes: Vec<DidKeyScope>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::did::add_delegate_verification_method(
+            ctx, method, delegate, scopes, expires_at,
+        )
+    }
+
+    /// Remove a scoped delegate key from a DID
+    ///
+    /// Only the controller can remove delegates.
+    pub fn remove_delegate_verification_method(
+        ctx: Context<RemoveDelegateVerificationMethod>,
+    ) -> Result<()> {
+        instructions::did::remove_delegate_verification_method(ctx)
+    }
+
+    /// Register a human-readable agent handle
+    ///
+    /// Maps `ghost:<handle>` to the owner's DID document (and optional agent)
+    /// for one registration period (a year by default). The owner pays the
+    /// rent; handles are globally unique.
+    ///
+    /// Parameters:
+    /// - handle: 3-32 chars of a-z, 0-9 and inner hyphens, without `ghost:`
+    pub fn register_agent_handle(ctx: Context<RegisterAgentHandle>, handle: String) -> Result<()> {
+        instructions::agent_handle::register_agent_handle(ctx, handle)
+    }
+
+    /// Renew an agent handle for another registration period
+    pub fn renew_agent_handle(ctx: Context<RenewAgentHandle>) -> Result<()> {
+        instructions::agent_handle::renew_agent_handle(ctx)
+    }
+
+    /// Transfer an agent handle
+    ///
+    /// Signed by both the current and new owner; the handle is re-pointed at
+    /// the new owner's DID document and optional agent.
+    pub fn transfer_agent_handle(ctx: Context<TransferAgentHandle>) -> Result<()> {
+        instructions::agent_handle::transfer_agent_handle(ctx)
+    }
+
+    /// Release an agent handle that lapsed past its grace period
+    ///
+    /// Permissionless; refunds the rent to the owner.
+    pub fn release_agent_handle(ctx: Context<ReleaseAgentHandle>) -> Result<()> {
+        instructions::agent_handle::release_agent_handle(ctx)
     }
 
     // =====================================================
@@ -1469,11 +3981,57 @@ pub mod ghostspeak_marketplace {
         instructions::reputation::initialize_reputation_metrics(ctx)
     }
 
+    /// Create a top-N leaderboard for a category (protocol admin only)
+    ///
+    /// Parameters:
+    /// - category: "global" or a skill tag (max 32 chars)
+    /// - capacity: Number of ranked entries (1-100)
+    pub fn initialize_leaderboard(
+        ctx: Context<InitializeLeaderboard>,
+        category: String,
+        capacity: u8,
+    ) -> Result<()> {
+        instructions::leaderboard::initialize_leaderboard(ctx, category, capacity)
+    }
+
+    /// Submit an agent's current Ghost Score to a leaderboard
+    ///
+    /// Permissionless. The agent enters the board only if there is room or
+    /// its score beats the current floor; ranked agents are re-ranked, and
+    /// dropped once inactive or no longer tagged with the category.
+    pub fn update_leaderboard(ctx: Context<UpdateLeaderboard>) -> Result<()> {
+        instructions::leaderboard::update_leaderboard(ctx)
+    }
+
+    /// Initialize rolling 30/90-day reputation windows for an agent
+    ///
+    /// Once created, pass the account to record_payai_payment and
+    /// submit_service_rating to keep weekly buckets current and blend recent
+    /// performance into the headline reputation score.
+    pub fn initialize_reputation_windows(
+        ctx: Context<InitializeReputationWindows>,
+    ) -> Result<()> {
+        instructions::reputation::initialize_reputation_windows(ctx)
+    }
+
+    /// Read recent (30d/90d) and lifetime performance
+    ///
+    /// The ReputationWindowSummary is returned via return data.
+    pub fn get_reputation_windows(
+        ctx: Context<GetReputationWindows>,
+    ) -> Result<ReputationWindowSummary> {
+        instructions::reputation::get_reputation_windows(ctx)
+    }
+
     /// Record a PayAI payment transaction for reputation tracking
     ///
     /// Consumes payment data from PayAI protocol to update agent reputation.
     /// This does NOT facilitate payments - it only tracks them for reputation.
     ///
+    /// Signed by the agent owner, by a session key scoped to record payments
+    /// (pass `session_key`), or by the payment verifier registered as the
+    /// PayAI source's updater (pass `source_registry`).
+    ///
     /// Parameters:
     /// - payment_signature: PayAI transaction signature
     /// - amount: Payment amount in lamports
@@ -1486,8 +4044,28 @@ pub mod ghostspeak_marketplace {
         response_time_ms: u64,
         success: bool,
     ) -> Result<()> {
-        // TODO: Rename internal function from record_x402_payment to record_payai_payment
-        instructions::reputation::record_x402_payment(
+        instructions::reputation::record_payai_payment(
+            ctx,
+            payment_signature,
+            amount,
+            response_time_ms,
+            success,
+        )
+    }
+
+    /// Deprecated alias of `record_payai_payment`
+    ///
+    /// Keeps the pre-rename discriminator routable for clients built against
+    /// older IDLs. Listed under `aliases` in test-vectors/discriminators.json.
+    pub fn record_x402_payment(
+        ctx: Context<RecordX402PaymentReputation>,
+        payment_signature: String,
+        amount: u64,
+        response_time_ms: u64,
+        success: bool,
+    ) -> Result<()> {
+        msg!("record_x402_payment is deprecated; use record_payai_payment");
+        instructions::reputation::record_payai_payment(
             ctx,
             payment_signature,
             amount,
@@ -1513,9 +4091,108 @@ pub mod ghostspeak_marketplace {
         instructions::reputation::submit_x402_rating(ctx, rating, payment_signature)
     }
 
+    /// Commit to a hidden service rating while an escrow job is active
+    ///
+    /// Prevents agents from conditioning delivery on a promised rating.
+    ///
+    /// Parameters:
+    /// - commitment: keccak256("ghostspeak:rating:v1" || escrow || rating || salt)
+    pub fn commit_service_rating(
+        ctx: Context<CommitServiceRating>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::reputation::commit_service_rating(ctx, commitment)
+    }
+
+    /// Reveal a committed service rating after the escrow completes
+    ///
+    /// Parameters:
+    /// - rating: Service rating from 1 (poor) to 5 (excellent)
+    /// - salt: Salt used in the commitment
+    pub fn reveal_service_rating(
+        ctx: Context<RevealServiceRating>,
+        rating: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reputation::reveal_service_rating(ctx, rating, salt)
+    }
+
+    /// Create the sybil heuristic config (disabled, default parameters; protocol admin only)
+    ///
+    /// Parameters:
+    /// - oracle: Key allowed to record funder fingerprints
+    pub fn initialize_sybil_config(
+        ctx: Context<InitializeSybilConfig>,
+        oracle: Pubkey,
+    ) -> Result<()> {
+        instructions::sybil::initialize_sybil_config(ctx, oracle)
+    }
+
+    /// Tune the sybil heuristic (governance only)
+    ///
+    /// Parameters:
+    /// - enabled: Toggle down-weighting of flagged ratings
+    /// - oracle: New fingerprint oracle
+    /// - cluster_threshold: Wallets sharing a funder before the cluster is flagged (min 2)
+    /// - flagged_weight_bps: Weight of a flagged rating (0-10000)
+    pub fn update_sybil_config(
+        ctx: Context<ManageSybilConfig>,
+        enabled: Option<bool>,
+        oracle: Option<Pubkey>,
+        cluster_threshold: Option<u32>,
+        flagged_weight_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::sybil::update_sybil_config(
+            ctx,
+            enabled,
+            oracle,
+            cluster_threshold,
+            flagged_weight_bps,
+        )
+    }
+
+    /// Add or remove a funder that is never flagged (governance only)
+    ///
+    /// Parameters:
+    /// - funder: Funding wallet (exchange, faucet, grant program)
+    /// - exempt: Whether the funder is exempt
+    pub fn set_sybil_exempt_funder(
+        ctx: Context<ManageSybilConfig>,
+        funder: Pubkey,
+        exempt: bool,
+    ) -> Result<()> {
+        instructions::sybil::set_sybil_exempt_funder(ctx, funder, exempt)
+    }
+
+    /// Record the first funder of a wallet (fingerprint oracle only)
+    ///
+    /// Parameters:
+    /// - first_funder: Wallet that first funded `wallet`
+    pub fn record_funder_fingerprint(
+        ctx: Context<RecordFunderFingerprint>,
+        first_funder: Pubkey,
+    ) -> Result<()> {
+        instructions::sybil::record_funder_fingerprint(ctx, first_funder)
+    }
+
+    /// Review a flagged funder cluster (governance only)
+    ///
+    /// Cleared clusters rate at full weight; confirmed clusters are ignored.
+    ///
+    /// Parameters:
+    /// - review: Verdict for the cluster
+    pub fn review_funder_cluster(
+        ctx: Context<ReviewFunderCluster>,
+        review: ClusterReview,
+    ) -> Result<()> {
+        instructions::sybil::review_funder_cluster(ctx, review)
+    }
+
     /// Update reputation from a specific source
     ///
     /// Updates or adds a reputation score from an external source (e.g., GitHub, custom webhook).
+    /// The source must be approved in the ReputationSourceRegistry, the signer must be its
+    /// registered updater, and weight must not exceed the approved maximum.
     /// Automatically aggregates all sources and detects conflicts.
     ///
     /// Parameters:
@@ -1542,6 +4219,69 @@ pub mod ghostspeak_marketplace {
         )
     }
 
+    /// Update one source's reputation score for many agents
+    ///
+    /// Same authorization as update_source_reputation, applied once for the batch;
+    /// each entry counts against the updater's reputation update rate limit.
+    /// Each entry's reputation metrics and agent accounts are passed as writable
+    /// pairs in remaining accounts. Entries that fail are skipped and reported
+    /// via SourceReputationUpdateFailedEvent; a summary event closes the batch.
+    ///
+    /// Parameters:
+    /// - source_name: Source identifier (e.g., "github", "custom-webhook")
+    /// - entries: Per-agent score, weight, data points and reliability (max 10)
+    pub fn update_source_reputation_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateSourceReputationBatch<'info>>,
+        source_name: String,
+        entries: Vec<SourceReputationEntry>,
+    ) -> Result<()> {
+        instructions::reputation::update_source_reputation_batch(ctx, source_name, entries)
+    }
+
+    /// Re-derive an agent's weighted reputation aggregate (permissionless crank)
+    ///
+    /// Drops scores from sources revoked in the registry, recomputes the
+    /// weighted score and emits ReputationConflictDetectedEvent when sources
+    /// diverge by more than the conflict threshold.
+    pub fn recompute_reputation_aggregate(
+        ctx: Context<RecomputeReputationAggregate>,
+    ) -> Result<()> {
+        instructions::reputation::recompute_reputation_aggregate(ctx)
+    }
+
+    /// Create the governed registry of approved reputation sources (protocol
+    /// admin only)
+    ///
+    /// Multisig signers must be passed as remaining accounts and meet the threshold.
+    pub fn initialize_reputation_source_registry(
+        ctx: Context<InitializeReputationSourceRegistry>,
+    ) -> Result<()> {
+        instructions::reputation::initialize_reputation_source_registry(ctx)
+    }
+
+    /// Add, update or deactivate an approved reputation source
+    ///
+    /// Parameters:
+    /// - source_name: Source identifier (e.g., "github")
+    /// - updater: Key allowed to push scores for this source
+    /// - max_weight: Maximum weight the source may claim (basis points)
+    /// - is_active: Whether the source is accepted
+    pub fn set_reputation_source(
+        ctx: Context<ManageReputationSource>,
+        source_name: String,
+        updater: Pubkey,
+        max_weight: u16,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::reputation::set_reputation_source(
+            ctx,
+            source_name,
+            updater,
+            max_weight,
+            is_active,
+        )
+    }
+
     /// Update reputation tags for an agent
     ///
     /// Adds or updates granular reputation tags with confidence scores.
@@ -1551,6 +4291,9 @@ pub mod ghostspeak_marketplace {
     /// Tag decay: Tags automatically decay at 10 bp/day and become stale after 90 days.
     /// This ensures reputation data stays current and reflects recent performance.
     ///
+    /// Pass the tag registry to resolve registry IDs and aliases to canonical
+    /// names; without it only free-form names are accepted.
+    ///
     /// Parameters:
     /// - skill_tags: Skill tags to add (e.g., "rust", "smart-contracts") - max 20
     /// - behavior_tags: Behavior tags (e.g., "responsive", "reliable") - max 20
@@ -1558,9 +4301,9 @@ pub mod ghostspeak_marketplace {
     /// - tag_scores: Tag scores with confidence and evidence - max 50
     pub fn update_reputation_tags(
         ctx: Context<UpdateReputationTags>,
-        skill_tags: Vec<String>,
-        behavior_tags: Vec<String>,
-        compliance_tags: Vec<String>,
+        skill_tags: Vec<TagRef>,
+        behavior_tags: Vec<TagRef>,
+        compliance_tags: Vec<TagRef>,
         tag_scores: Vec<TagScore>,
     ) -> Result<()> {
         instructions::reputation::update_reputation_tags(
@@ -1572,6 +4315,60 @@ pub mod ghostspeak_marketplace {
         )
     }
 
+    /// Create the governed tag taxonomy (protocol admin only)
+    ///
+    /// Parameters:
+    /// - capacity: Maximum number of tags (max 60)
+    pub fn initialize_tag_registry(
+        ctx: Context<InitializeTagRegistry>,
+        capacity: u16,
+    ) -> Result<()> {
+        instructions::tag_registry::initialize_tag_registry(ctx, capacity)
+    }
+
+    /// Register a canonical tag (registry authority only)
+    ///
+    /// Parameters:
+    /// - category: Skill, behavior or compliance
+    /// - name: Canonical name, normalized to lowercase [a-z0-9-]
+    /// - aliases: Alternative spellings resolving to the name - max 3
+    pub fn register_tag(
+        ctx: Context<ManageTagRegistry>,
+        category: TagCategory,
+        name: String,
+        aliases: Vec<String>,
+    ) -> Result<()> {
+        instructions::tag_registry::register_tag(ctx, category, name, aliases)
+    }
+
+    /// Replace a tag's aliases or deprecate it (registry authority only)
+    pub fn update_tag(
+        ctx: Context<ManageTagRegistry>,
+        id: u16,
+        aliases: Vec<String>,
+        deprecated: bool,
+    ) -> Result<()> {
+        instructions::tag_registry::update_tag(ctx, id, aliases, deprecated)
+    }
+
+    /// Apply inactivity decay to an agent's aggregate reputation
+    ///
+    /// Permissionless crank. Agents with no payments or ratings for longer
+    /// than the protocol config grace period lose a fixed percentage of their
+    /// score per inactive day, down to the configured floor.
+    pub fn apply_reputation_decay(ctx: Context<ApplyReputationDecay>) -> Result<()> {
+        instructions::reputation::apply_reputation_decay(ctx)
+    }
+
+    /// Grow reputation metrics created before the layout version to the
+    /// current layout
+    ///
+    /// Permissionless; the payer covers the added rent. The inactivity decay
+    /// clock restarts from the metrics' last update.
+    pub fn migrate_reputation_metrics(ctx: Context<MigrateReputationMetrics>) -> Result<()> {
+        instructions::reputation::migrate_reputation_metrics(ctx)
+    }
+
     // =====================================================
     // AGENT PRE-AUTHORIZATION INSTRUCTIONS
     // =====================================================
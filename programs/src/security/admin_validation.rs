@@ -5,9 +5,8 @@
  * to prevent the use of insecure or system addresses in production.
  */
 
-// Removed dependency on hardcoded PROTOCOL_ADMIN constant
-// All admin validation is now done at runtime with proper account verification
-use crate::PROTOCOL_ADMIN;
+// The admin itself is read from the AdminAuthority PDA; these checks only
+// reject keys that must never hold admin rights
 use anchor_lang::prelude::*;
 
 /// Known system program addresses that should never be used as admin
@@ -70,10 +69,9 @@ pub struct AdminValidationResult {
     pub admin_key: Pubkey,
 }
 
-/// Validate admin key configuration for the current network
-pub fn validate_admin_configuration() -> AdminValidationResult {
+/// Validate an admin key (typically `AdminAuthority::admin`) for the current network
+pub fn validate_admin_configuration(admin_key: Pubkey) -> AdminValidationResult {
     let network = NetworkType::detect();
-    let admin_key = PROTOCOL_ADMIN;
     let mut issues = Vec::new();
 
     // Check if admin key is a system program
@@ -181,13 +179,13 @@ pub fn require_valid_admin(admin: &Pubkey) -> Result<()> {
     Ok(())
 }
 
-/// Security check macro for admin operations
+/// Security check macro for admin operations, against the `AdminAuthority` PDA
 #[macro_export]
 macro_rules! require_admin {
-    ($account:expr) => {
+    ($admin_authority:expr, $account:expr) => {
         require_keys_eq!(
             $account.key(),
-            crate::PROTOCOL_ADMIN,
+            $admin_authority.admin,
             AdminValidationError::UnauthorizedAdmin
         );
         crate::security::admin_validation::require_valid_admin(&$account.key())?;
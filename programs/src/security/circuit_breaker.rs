@@ -9,11 +9,20 @@
  * - Per-instruction pause
  * - Gradual resume with safeguards
  * - Multi-signature admin control
+ * - Automatic trip when on-chain anomaly counters (disputes filed, slashes
+ *   executed, arbitrations ruled past their SLA) exceed a threshold within
+ *   a window; only a breaker multisig can reset an automatic trip
  */
 
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+use crate::state::governance::Multisig;
+use crate::utils::{read_legacy_layout, write_current_layout};
 use crate::GhostSpeakError;
 use anchor_lang::prelude::*;
 
+/// Longest window an anomaly threshold may count over (7 days)
+pub const MAX_ANOMALY_WINDOW: i64 = 7 * 24 * 60 * 60;
+
 /// Circuit breaker state account
 #[account]
 pub struct CircuitBreaker {
@@ -46,6 +55,80 @@ pub struct CircuitBreaker {
 
     /// Bump seed for PDA
     pub bump: u8,
+
+    /// Layout version; fields below exist only from `CURRENT_VERSION` on,
+    /// and older breakers gain them through `migrate_circuit_breaker`
+    pub version: u8,
+
+    /// Anomaly that paused the protocol automatically, if any; such a pause
+    /// can only be lifted by `reset_circuit_breaker`
+    pub auto_tripped_by: Option<AnomalyKind>,
+
+    /// Trip thresholds and current counts, indexed by `AnomalyKind`
+    pub anomaly_monitors: [AnomalyMonitor; AnomalyKind::COUNT],
+}
+
+/// Circuit breaker as written before the layout version
+#[derive(AnchorDeserialize)]
+pub struct CircuitBreakerV0 {
+    pub is_paused: bool,
+    pub paused_instructions: PausedInstructions,
+    pub admin: Pubkey,
+    pub multisig_authorities: Vec<Pubkey>,
+    pub required_signatures: u8,
+    pub last_paused_at: i64,
+    pub last_unpaused_at: i64,
+    pub pause_reason: String,
+    pub pause_count: u32,
+    pub bump: u8,
+}
+
+/// On-chain events counted toward an automatic trip
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnomalyKind {
+    /// Escrow disputes filed
+    DisputeFiled,
+    /// Stakes slashed
+    SlashExecuted,
+    /// Disputes ruled on after their SLA deadline
+    ArbitrationFailed,
+}
+
+impl AnomalyKind {
+    pub const COUNT: usize = 3;
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Fixed-window counter for one `AnomalyKind`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnomalyMonitor {
+    /// Most events allowed per window before tripping (0 disables)
+    pub threshold: u32,
+    /// Window length in seconds
+    pub window: i64,
+    pub window_start: i64,
+    /// Events counted in the current window
+    pub count: u32,
+}
+
+impl AnomalyMonitor {
+    pub const LEN: usize = 4 + 8 + 8 + 4;
+
+    /// Count one event at `now`, returning whether the threshold is exceeded
+    pub fn record(&mut self, now: i64) -> bool {
+        if self.threshold == 0 {
+            return false;
+        }
+        if now.saturating_sub(self.window_start) >= self.window {
+            self.window_start = now;
+            self.count = 0;
+        }
+        self.count = self.count.saturating_add(1);
+        self.count > self.threshold
+    }
 }
 
 /// Individual instruction pause flags
@@ -92,7 +175,40 @@ impl CircuitBreaker {
         8 + // last_unpaused_at
         4 + 256 + // pause_reason
         4 + // pause_count
-        1; // bump
+        1 + // bump
+        Self::V1_EXTENSION_LEN; // version, auto_tripped_by, anomaly_monitors
+
+    /// Layout with `version` and the anomaly monitors after `bump`
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Bytes the version and anomaly monitors add to breakers created before them
+    pub const V1_EXTENSION_LEN: usize = 1 + // version
+        1 + 1 + // auto_tripped_by
+        AnomalyMonitor::LEN * AnomalyKind::COUNT; // anomaly_monitors
+
+    /// Whether this breaker, `data_len` bytes long, has the current layout
+    pub fn is_current(&self, data_len: usize) -> bool {
+        data_len >= Self::LEN && self.version == Self::CURRENT_VERSION
+    }
+
+    /// A pre-version breaker in the current layout, with no anomaly
+    /// thresholds set
+    pub fn from_v0(legacy: CircuitBreakerV0) -> Self {
+        Self {
+            is_paused: legacy.is_paused,
+            paused_instructions: legacy.paused_instructions,
+            admin: legacy.admin,
+            multisig_authorities: legacy.multisig_authorities,
+            required_signatures: legacy.required_signatures,
+            last_paused_at: legacy.last_paused_at,
+            last_unpaused_at: legacy.last_unpaused_at,
+            pause_reason: legacy.pause_reason,
+            pause_count: legacy.pause_count,
+            bump: legacy.bump,
+            version: Self::CURRENT_VERSION,
+            auto_tripped_by: None,
+            anomaly_monitors: [AnomalyMonitor::default(); AnomalyKind::COUNT],
+        }
+    }
 
     /// Initialize circuit breaker with admin
     pub fn initialize(&mut self, admin: Pubkey, bump: u8) -> Result<()> {
@@ -106,6 +222,9 @@ impl CircuitBreaker {
         self.pause_reason = String::new();
         self.pause_count = 0;
         self.bump = bump;
+        self.version = Self::CURRENT_VERSION;
+        self.auto_tripped_by = None;
+        self.anomaly_monitors = [AnomalyMonitor::default(); AnomalyKind::COUNT];
 
         Ok(())
     }
@@ -135,6 +254,10 @@ impl CircuitBreaker {
     /// Unpause entire protocol
     pub fn unpause_all(&mut self) -> Result<()> {
         require!(self.is_paused, GhostSpeakError::NotPaused);
+        require!(
+            self.auto_tripped_by.is_none(),
+            GhostSpeakError::CircuitBreakerResetRequired
+        );
 
         self.is_paused = false;
         // Use 0 as fallback when Clock sysvar is unavailable (e.g., in unit tests)
@@ -146,6 +269,58 @@ impl CircuitBreaker {
         Ok(())
     }
 
+    /// Set the trip threshold for `kind`, restarting its count
+    pub fn set_anomaly_threshold(
+        &mut self,
+        kind: AnomalyKind,
+        threshold: u32,
+        window: i64,
+    ) -> Result<()> {
+        require!(
+            threshold == 0 || (window > 0 && window <= MAX_ANOMALY_WINDOW),
+            GhostSpeakError::InvalidConfiguration
+        );
+
+        self.anomaly_monitors[kind.index()] = AnomalyMonitor {
+            threshold,
+            window,
+            window_start: 0,
+            count: 0,
+        };
+
+        Ok(())
+    }
+
+    /// Count an anomaly, pausing the protocol if it pushes `kind` over its
+    /// threshold. Returns whether this call tripped the breaker.
+    pub fn record_anomaly(&mut self, kind: AnomalyKind, now: i64) -> Result<bool> {
+        let monitor = &mut self.anomaly_monitors[kind.index()];
+        if !monitor.record(now) || self.is_paused {
+            return Ok(false);
+        }
+
+        let reason = format!(
+            "Auto-tripped: {:?} count {} exceeded {} per {}s",
+            kind, monitor.count, monitor.threshold, monitor.window
+        );
+        self.pause_all(reason)?;
+        self.auto_tripped_by = Some(kind);
+
+        Ok(true)
+    }
+
+    /// Lift a pause, automatic or manual, and restart every anomaly count
+    pub fn reset(&mut self) -> Result<()> {
+        require!(self.is_paused, GhostSpeakError::NotPaused);
+
+        self.auto_tripped_by = None;
+        for monitor in self.anomaly_monitors.iter_mut() {
+            monitor.window_start = 0;
+            monitor.count = 0;
+        }
+        self.unpause_all()
+    }
+
     /// Pause specific instruction
     pub fn pause_instruction(
         &mut self,
@@ -282,6 +457,14 @@ pub struct InitializeCircuitBreaker<'info> {
     )]
     pub circuit_breaker: Account<'info, CircuitBreaker>,
 
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == admin.key() @ GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -295,7 +478,9 @@ pub struct PauseProtocol<'info> {
         mut,
         seeds = [b"circuit_breaker"],
         bump = circuit_breaker.bump,
-        constraint = admin.key() == circuit_breaker.admin @ GhostSpeakError::UnauthorizedAccess
+        constraint = admin.key() == circuit_breaker.admin @ GhostSpeakError::UnauthorizedAccess,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
     )]
     pub circuit_breaker: Account<'info, CircuitBreaker>,
 
@@ -309,13 +494,49 @@ pub struct UnpauseProtocol<'info> {
         mut,
         seeds = [b"circuit_breaker"],
         bump = circuit_breaker.bump,
-        constraint = admin.key() == circuit_breaker.admin @ GhostSpeakError::UnauthorizedAccess
+        constraint = admin.key() == circuit_breaker.admin @ GhostSpeakError::UnauthorizedAccess,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
     )]
     pub circuit_breaker: Account<'info, CircuitBreaker>,
 
     pub admin: Signer<'info>,
 }
 
+/// Context for resetting the breaker (approvals of a breaker multisig)
+///
+/// Signers are passed as remaining accounts and must meet the multisig's
+/// threshold.
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker"],
+        bump = circuit_breaker.bump,
+        constraint = circuit_breaker.multisig_authorities.contains(&multisig.key()) @ GhostSpeakError::UnauthorizedAccess,
+        constraint = circuit_breaker.is_current(circuit_breaker.to_account_info().data_len())
+            @ GhostSpeakError::AccountLayoutOutdated
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    pub multisig: Box<Account<'info, Multisig>>,
+}
+
+/// Context for migrating the circuit breaker to the current layout
+#[derive(Accounts)]
+pub struct MigrateCircuitBreaker<'info> {
+    /// CHECK: Breaker created before the layout version, which the current
+    /// layout can't deserialize; checked and parsed by the handler
+    #[account(mut, seeds = [b"circuit_breaker"], bump)]
+    pub circuit_breaker: UncheckedAccount<'info>,
+
+    /// Anyone may migrate; pays the rent for the added bytes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 // =====================================================
 // INSTRUCTION HANDLERS
 // =====================================================
@@ -352,6 +573,97 @@ pub fn unpause_protocol(ctx: Context<UnpauseProtocol>) -> Result<()> {
     Ok(())
 }
 
+/// Let a multisig pause the protocol with its emergency quorum
+pub fn add_circuit_breaker_multisig(ctx: Context<PauseProtocol>, multisig: Pubkey) -> Result<()> {
+    let circuit_breaker = &mut ctx.accounts.circuit_breaker;
+
+    circuit_breaker.add_multisig_authority(multisig)?;
+
+    msg!("Circuit breaker multisig authority added: {}", multisig);
+
+    Ok(())
+}
+
+/// Set the automatic trip threshold for one anomaly kind
+pub fn set_anomaly_threshold(
+    ctx: Context<PauseProtocol>,
+    kind: AnomalyKind,
+    threshold: u32,
+    window: i64,
+) -> Result<()> {
+    let circuit_breaker = &mut ctx.accounts.circuit_breaker;
+
+    circuit_breaker.set_anomaly_threshold(kind, threshold, window)?;
+
+    emit!(AnomalyThresholdUpdatedEvent {
+        kind,
+        threshold,
+        window,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Lift a pause, including an automatic trip, with a breaker multisig's approvals
+pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .require_approvals(ctx.remaining_accounts)?;
+
+    let circuit_breaker = &mut ctx.accounts.circuit_breaker;
+    let tripped_by = circuit_breaker.auto_tripped_by;
+    circuit_breaker.reset()?;
+
+    emit!(CircuitBreakerResetEvent {
+        multisig: ctx.accounts.multisig.key(),
+        tripped_by,
+        timestamp: circuit_breaker.last_unpaused_at,
+    });
+
+    Ok(())
+}
+
+/// Grow a pre-version circuit breaker to the current layout (permissionless)
+///
+/// Pause state and authorities carry over; no anomaly thresholds are set
+/// until the admin configures them.
+pub fn migrate_circuit_breaker(ctx: Context<MigrateCircuitBreaker>) -> Result<()> {
+    let info = ctx.accounts.circuit_breaker.to_account_info();
+    let legacy = read_legacy_layout::<CircuitBreaker, CircuitBreakerV0>(&info, CircuitBreaker::LEN)?;
+    let breaker = CircuitBreaker::from_v0(legacy);
+    write_current_layout(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CircuitBreaker::LEN,
+        &breaker,
+    )?;
+
+    emit!(CircuitBreakerMigratedEvent {
+        version: CircuitBreaker::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Count an anomaly from an instruction handler, emitting an event if it
+/// trips the breaker
+pub fn record_anomaly(breaker: &mut CircuitBreaker, kind: AnomalyKind, now: i64) -> Result<()> {
+    if breaker.record_anomaly(kind, now)? {
+        let monitor = breaker.anomaly_monitors[kind.index()];
+        emit!(CircuitBreakerTrippedEvent {
+            kind,
+            count: monitor.count,
+            threshold: monitor.threshold,
+            window: monitor.window,
+            timestamp: now,
+        });
+    }
+    Ok(())
+}
+
 /// Pause specific instruction
 pub fn pause_instruction(
     ctx: Context<PauseProtocol>,
@@ -392,6 +704,41 @@ macro_rules! check_not_paused {
 // Usage example in instruction handlers:
 // check_not_paused!(ctx.accounts.circuit_breaker, InstructionType::RegisterAgent);
 
+// =====================================================
+// EVENTS
+// =====================================================
+
+#[event]
+pub struct AnomalyThresholdUpdatedEvent {
+    pub kind: AnomalyKind,
+    pub threshold: u32,
+    pub window: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTrippedEvent {
+    pub kind: AnomalyKind,
+    pub count: u32,
+    pub threshold: u32,
+    pub window: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerMigratedEvent {
+    pub version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerResetEvent {
+    pub multisig: Pubkey,
+    /// Anomaly behind the pause, or `None` for a manual pause
+    pub tripped_by: Option<AnomalyKind>,
+    pub timestamp: i64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,6 +756,9 @@ mod tests {
             pause_reason: String::new(),
             pause_count: 0,
             bump: 255,
+            version: CircuitBreaker::CURRENT_VERSION,
+            auto_tripped_by: None,
+            anomaly_monitors: [AnomalyMonitor::default(); AnomalyKind::COUNT],
         };
 
         // Pause
@@ -434,6 +784,9 @@ mod tests {
             pause_reason: String::new(),
             pause_count: 0,
             bump: 255,
+            version: CircuitBreaker::CURRENT_VERSION,
+            auto_tripped_by: None,
+            anomaly_monitors: [AnomalyMonitor::default(); AnomalyKind::COUNT],
         };
 
         // Pause specific instruction
@@ -451,4 +804,119 @@ mod tests {
             .unwrap();
         assert!(!breaker.paused_instructions.register_agent);
     }
+
+    #[test]
+    fn test_anomaly_auto_trip_and_reset() {
+        let mut breaker = CircuitBreaker {
+            is_paused: false,
+            paused_instructions: PausedInstructions::default(),
+            admin: Pubkey::new_unique(),
+            multisig_authorities: Vec::new(),
+            required_signatures: 1,
+            last_paused_at: 0,
+            last_unpaused_at: 0,
+            pause_reason: String::new(),
+            pause_count: 0,
+            bump: 255,
+            version: CircuitBreaker::CURRENT_VERSION,
+            auto_tripped_by: None,
+            anomaly_monitors: [AnomalyMonitor::default(); AnomalyKind::COUNT],
+        };
+        let kind = AnomalyKind::DisputeFiled;
+
+        // Disabled monitors never trip
+        assert!(!breaker.record_anomaly(kind, 0).unwrap());
+        assert!(breaker
+            .set_anomaly_threshold(kind, 2, MAX_ANOMALY_WINDOW + 1)
+            .is_err());
+        breaker.set_anomaly_threshold(kind, 2, 3600).unwrap();
+
+        // Two per hour are tolerated, and the count restarts with the window
+        assert!(!breaker.record_anomaly(kind, 100).unwrap());
+        assert!(!breaker.record_anomaly(kind, 200).unwrap());
+        assert!(!breaker.record_anomaly(kind, 3700).unwrap());
+        assert!(!breaker.record_anomaly(kind, 3800).unwrap());
+        assert!(!breaker.is_paused);
+
+        // A third within the window trips the breaker
+        assert!(breaker.record_anomaly(kind, 3900).unwrap());
+        assert!(breaker.is_paused);
+        assert_eq!(breaker.auto_tripped_by, Some(kind));
+
+        // Only a reset lifts an automatic trip
+        assert!(breaker.unpause_all().is_err());
+        breaker.reset().unwrap();
+        assert!(!breaker.is_paused);
+        assert_eq!(breaker.auto_tripped_by, None);
+        assert_eq!(breaker.anomaly_monitors[kind.index()].count, 0);
+        assert_eq!(breaker.anomaly_monitors[kind.index()].threshold, 2);
+    }
+
+    #[test]
+    fn test_v0_layout_migrates() {
+        let mut breaker = CircuitBreaker {
+            is_paused: true,
+            paused_instructions: PausedInstructions::default(),
+            admin: Pubkey::new_unique(),
+            multisig_authorities: vec![Pubkey::new_unique()],
+            required_signatures: 1,
+            last_paused_at: 100,
+            last_unpaused_at: 0,
+            pause_reason: "Investigating".to_string(),
+            pause_count: 3,
+            bump: 254,
+            version: CircuitBreaker::CURRENT_VERSION,
+            auto_tripped_by: None,
+            anomaly_monitors: [AnomalyMonitor::default(); AnomalyKind::COUNT],
+        };
+        breaker
+            .set_anomaly_threshold(AnomalyKind::DisputeFiled, 5, 3600)
+            .unwrap();
+
+        // A pre-version breaker ends at `bump`, in an account of the old
+        // size whose trailing bytes may be left over from a longer reason
+        let mut data = Vec::new();
+        breaker.try_serialize(&mut data).unwrap();
+        let extension = (breaker.version, breaker.auto_tripped_by, breaker.anomaly_monitors)
+            .try_to_vec()
+            .unwrap();
+        data.truncate(data.len() - extension.len());
+        data.resize(CircuitBreaker::LEN - CircuitBreaker::V1_EXTENSION_LEN, 0xff);
+        assert!(CircuitBreaker::try_deserialize(&mut &data[..]).is_err());
+
+        let key = Pubkey::new_unique();
+        let program = crate::ID;
+        let mut lamports = 0u64;
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &program, false, 0);
+        let legacy =
+            read_legacy_layout::<CircuitBreaker, CircuitBreakerV0>(&info, CircuitBreaker::LEN)
+                .unwrap();
+        let migrated = CircuitBreaker::from_v0(legacy);
+        assert!(migrated.is_current(CircuitBreaker::LEN));
+        assert!(migrated.is_paused);
+        assert_eq!(migrated.admin, breaker.admin);
+        assert_eq!(migrated.multisig_authorities, breaker.multisig_authorities);
+        assert_eq!(migrated.pause_reason, "Investigating");
+        assert_eq!(migrated.pause_count, 3);
+        assert_eq!(migrated.bump, 254);
+        assert_eq!(migrated.auto_tripped_by, None);
+        assert_eq!(migrated.anomaly_monitors, [AnomalyMonitor::default(); AnomalyKind::COUNT]);
+
+        // Current-size accounts and other programs' accounts are refused
+        let mut current = vec![0u8; CircuitBreaker::LEN];
+        let mut lamports = 0u64;
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut current, &program, false, 0);
+        assert!(
+            read_legacy_layout::<CircuitBreaker, CircuitBreakerV0>(&info, CircuitBreaker::LEN)
+                .is_err()
+        );
+        let mut foreign = vec![0u8; 16];
+        let other = Pubkey::new_unique();
+        let mut lamports = 0u64;
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut foreign, &other, false, 0);
+        assert!(
+            read_legacy_layout::<CircuitBreaker, CircuitBreakerV0>(&info, CircuitBreaker::LEN)
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,90 @@
+/*!
+ * Commit-Reveal Module
+ *
+ * Hash commitments for values that must stay hidden until a later phase.
+ * Used for service ratings: the client commits to a rating while the job is
+ * in progress and reveals it only after completion, so an agent cannot
+ * condition delivery on a promised rating.
+ */
+
+use anchor_lang::prelude::*;
+use sha3::{Digest, Keccak256};
+
+/// Domain separator for rating commitments
+pub const RATING_COMMITMENT_DOMAIN: &[u8] = b"ghostspeak:rating:v1";
+
+/// Seed for rating commitment PDAs: [seed, escrow]
+pub const RATING_COMMITMENT_SEED: &[u8] = b"rating_commitment";
+
+/// Hidden rating for one escrow job
+///
+/// Seeds: ["rating_commitment", escrow]
+#[account]
+pub struct RatingCommitment {
+    /// Escrow the rating belongs to
+    pub escrow: Pubkey,
+    /// Client who committed
+    pub client: Pubkey,
+    /// Agent being rated
+    pub agent: Pubkey,
+    /// keccak256(domain || escrow || rating || salt)
+    pub commitment: [u8; 32],
+    pub committed_at: i64,
+    pub bump: u8,
+}
+
+impl RatingCommitment {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // escrow
+        32 + // client
+        32 + // agent
+        32 + // commitment
+        8 + // committed_at
+        1; // bump
+
+    /// Whether `rating` and `salt` open this commitment
+    pub fn matches(&self, rating: u8, salt: &[u8; 32]) -> bool {
+        rating_commitment(&self.escrow, rating, salt) == self.commitment
+    }
+}
+
+/// Commitment a client computes off-chain for `rating` on `escrow`
+///
+/// Binding the escrow stops a commitment being copied to another job.
+pub fn rating_commitment(escrow: &Pubkey, rating: u8, salt: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(RATING_COMMITMENT_DOMAIN);
+    hasher.update(escrow.as_ref());
+    hasher.update([rating]);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_commitment() {
+        let escrow = Pubkey::new_unique();
+        let salt = [9u8; 32];
+        let commitment = RatingCommitment {
+            escrow,
+            client: Pubkey::new_unique(),
+            agent: Pubkey::new_unique(),
+            commitment: rating_commitment(&escrow, 4, &salt),
+            committed_at: 0,
+            bump: 255,
+        };
+
+        assert!(commitment.matches(4, &salt));
+        assert!(!commitment.matches(5, &salt));
+        assert!(!commitment.matches(4, &[0u8; 32]));
+
+        // Same rating and salt on another escrow gives a different commitment
+        assert_ne!(
+            rating_commitment(&Pubkey::new_unique(), 4, &salt),
+            commitment.commitment
+        );
+    }
+}
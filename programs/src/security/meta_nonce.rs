@@ -0,0 +1,155 @@
+/*!
+ * Meta Nonce Module
+ *
+ * Uniform replay protection for off-chain-signed payloads. Each signer has
+ * one `MetaNonce` PDA holding the highest nonce it has used; a payload is
+ * accepted only if its nonce is strictly higher and it is inside its
+ * expiry window. Relayed meta-transactions (`execute_meta_tx`) and signed
+ * authorizations (`verify_signed_authorization`) consume from the same
+ * sequence, so a signature made for one can never be replayed as the
+ * other.
+ *
+ * Session keys do not use it: a session key signs the transaction itself,
+ * which the runtime already deduplicates by blockhash. Token fee quotes and
+ * buyback bounds are priced from Pyth at execution, so there is no signed
+ * quote to replay. Any detached signed payload added later should consume
+ * from this sequence too.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Seed for nonce PDAs: [seed, signer]
+pub const META_NONCE_SEED: &[u8] = b"meta_nonce";
+
+/// Longest a signed payload may stay valid (24 hours)
+pub const MAX_PAYLOAD_LIFETIME: i64 = 24 * 60 * 60;
+
+/// Replay-protection state for one off-chain signer
+///
+/// Seeds: ["meta_nonce", signer]
+#[account]
+pub struct MetaNonce {
+    pub signer: Pubkey,
+    /// Highest nonce consumed so far (0 = none)
+    pub last_nonce: u64,
+    /// Payloads consumed so far
+    pub used: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl MetaNonce {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // signer
+        8 + // last_nonce
+        8 + // used
+        8 + // updated_at
+        1; // bump
+
+    /// Accept a payload signed with `nonce` that expires at `expires_at`
+    ///
+    /// Nonces only need to increase, so a signer may pre-sign several
+    /// payloads; submitting a later one invalidates any skipped ones.
+    pub fn consume(&mut self, nonce: u64, expires_at: i64, now: i64) -> Result<()> {
+        require!(nonce > self.last_nonce, GhostSpeakError::InvalidMetaTxNonce);
+        require!(now <= expires_at, GhostSpeakError::MetaTxExpired);
+        require!(
+            expires_at - now <= MAX_PAYLOAD_LIFETIME,
+            GhostSpeakError::PayloadLifetimeTooLong
+        );
+
+        self.last_nonce = nonce;
+        self.used = self.used.saturating_add(1);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Cancel every outstanding payload with a nonce up to `nonce`
+    pub fn advance(&mut self, nonce: u64, now: i64) -> Result<()> {
+        require!(nonce > self.last_nonce, GhostSpeakError::InvalidMetaTxNonce);
+        self.last_nonce = nonce;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Create the nonce account for a signer (anyone may pay)
+#[derive(Accounts)]
+pub struct InitializeMetaNonce<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = MetaNonce::LEN,
+        seeds = [META_NONCE_SEED, signer.key().as_ref()],
+        bump
+    )]
+    pub meta_nonce: Account<'info, MetaNonce>,
+
+    /// CHECK: Any off-chain signer; only its address is recorded
+    pub signer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Skip the nonce forward to revoke pre-signed payloads (signer only)
+#[derive(Accounts)]
+pub struct AdvanceMetaNonce<'info> {
+    #[account(
+        mut,
+        seeds = [META_NONCE_SEED, signer.key().as_ref()],
+        bump = meta_nonce.bump
+    )]
+    pub meta_nonce: Account<'info, MetaNonce>,
+
+    pub signer: Signer<'info>,
+}
+
+#[event]
+pub struct MetaNonceAdvancedEvent {
+    pub signer: Pubkey,
+    pub last_nonce: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_nonce_consume() {
+        let mut meta_nonce = MetaNonce {
+            signer: Pubkey::new_unique(),
+            last_nonce: 0,
+            used: 0,
+            updated_at: 0,
+            bump: 255,
+        };
+
+        meta_nonce.consume(1, 100, 50).unwrap();
+        // Gaps are allowed, replays and going backwards are not
+        meta_nonce.consume(5, 100, 50).unwrap();
+        assert!(meta_nonce.consume(5, 100, 50).is_err());
+        assert!(meta_nonce.consume(3, 100, 50).is_err());
+        assert_eq!((meta_nonce.last_nonce, meta_nonce.used), (5, 2));
+
+        // Expired and over-long windows
+        assert!(meta_nonce.consume(6, 49, 50).is_err());
+        assert!(meta_nonce
+            .consume(6, 50 + MAX_PAYLOAD_LIFETIME + 1, 50)
+            .is_err());
+        meta_nonce
+            .consume(6, 50 + MAX_PAYLOAD_LIFETIME, 50)
+            .unwrap();
+
+        // Advancing revokes everything up to the new nonce
+        meta_nonce.advance(10, 60).unwrap();
+        assert!(meta_nonce.consume(9, 100, 60).is_err());
+        assert!(meta_nonce.advance(10, 60).is_err());
+        meta_nonce.consume(11, 100, 60).unwrap();
+    }
+}
@@ -9,23 +9,32 @@
 pub mod admin_validation;
 pub mod agent_validation;
 pub mod circuit_breaker;
-// pub mod commit_reveal; // REMOVED - auction code
+pub mod commit_reveal; // Hidden service ratings
+pub mod meta_nonce; // Replay protection for off-chain-signed payloads
 pub mod rate_limiting;
 pub mod reentrancy;
 
 // Re-export security types
 pub use reentrancy::{
     is_account_locked, is_instruction_locked, AccountLock, CreateAccountLock,
-    CreateInstructionLock, InitializeReentrancyGuard, InstructionLock, ReentrancyCleanup,
-    ReentrancyGuard, ReentrancyState,
+    CreateInstructionLock, InstructionLock, ACCOUNT_LOCK_SEED, ESCROW_RELEASE_LOCK,
 };
 
 pub use rate_limiting::{
-    validation, CheckRateLimit, InitializeRateLimiter, OperationLimit, RateLimitConfig,
-    RateLimiter, UserRateLimit,
+    validation, CheckRateLimit, InitializeRateLimiter, OperationLimit, OperationRateLimitSetEvent,
+    RateLimitConfig, RateLimiter, SetOperationRateLimit, UserRateLimit,
+    OPERATION_CREDENTIAL_ISSUANCE, OPERATION_DISPUTE_FILING, OPERATION_PAYAI_PAYMENT,
+    OPERATION_REPUTATION_UPDATE, OPERATION_SERVICE_RATING,
 };
 
-// pub use commit_reveal::*; // REMOVED - auction code
+pub use commit_reveal::{
+    rating_commitment, RatingCommitment, RATING_COMMITMENT_DOMAIN, RATING_COMMITMENT_SEED,
+};
+
+pub use meta_nonce::{
+    AdvanceMetaNonce, InitializeMetaNonce, MetaNonce, MetaNonceAdvancedEvent, MAX_PAYLOAD_LIFETIME,
+    META_NONCE_SEED,
+};
 
 pub use admin_validation::{
     is_system_address, is_test_address, require_valid_admin, validate_admin_configuration,
@@ -38,9 +47,40 @@ pub use agent_validation::{
 };
 
 pub use circuit_breaker::{
-    initialize_circuit_breaker, pause_instruction, pause_protocol, unpause_instruction,
-    unpause_protocol, CircuitBreaker, InitializeCircuitBreaker, InstructionType, PauseProtocol,
-    PausedInstructions, UnpauseProtocol,
+    add_circuit_breaker_multisig, initialize_circuit_breaker, migrate_circuit_breaker,
+    pause_instruction, pause_protocol, record_anomaly, reset_circuit_breaker,
+    set_anomaly_threshold, unpause_instruction, unpause_protocol, AnomalyKind, AnomalyMonitor,
+    CircuitBreaker, CircuitBreakerV0, InitializeCircuitBreaker, InstructionType,
+    MigrateCircuitBreaker, PauseProtocol, PausedInstructions, ResetCircuitBreaker,
+    UnpauseProtocol,
+};
+
+// Anchor's generated client modules for the accounts structs above, which
+// #[program] resolves from the crate root
+pub(crate) use circuit_breaker::{
+    __client_accounts_initialize_circuit_breaker, __client_accounts_migrate_circuit_breaker,
+    __client_accounts_pause_protocol, __client_accounts_reset_circuit_breaker,
+    __client_accounts_unpause_protocol,
+};
+pub(crate) use meta_nonce::{
+    __client_accounts_advance_meta_nonce, __client_accounts_initialize_meta_nonce,
+};
+pub(crate) use rate_limiting::{
+    __client_accounts_initialize_rate_limiter, __client_accounts_set_operation_rate_limit,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use circuit_breaker::{
+    __cpi_client_accounts_initialize_circuit_breaker,
+    __cpi_client_accounts_migrate_circuit_breaker, __cpi_client_accounts_pause_protocol,
+    __cpi_client_accounts_reset_circuit_breaker, __cpi_client_accounts_unpause_protocol,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use meta_nonce::{
+    __cpi_client_accounts_advance_meta_nonce, __cpi_client_accounts_initialize_meta_nonce,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use rate_limiting::{
+    __cpi_client_accounts_initialize_rate_limiter, __cpi_client_accounts_set_operation_rate_limit,
 };
 
 // Re-export the check_not_paused macro from crate root (macros are exported at crate root)
@@ -7,6 +7,32 @@
 
 use anchor_lang::prelude::*;
 
+use crate::state::admin_authority::{AdminAuthority, ADMIN_AUTHORITY_SEED};
+
+/// Operation key for `record_payai_payment`, limited per recording signer
+pub const OPERATION_PAYAI_PAYMENT: &str = "record_payai_payment";
+
+/// Operation key for `submit_service_rating` and `reveal_service_rating`,
+/// limited per rating client
+pub const OPERATION_SERVICE_RATING: &str = "submit_service_rating";
+
+/// Operation key for `update_reputation_with_auth` and source reputation
+/// updates, limited per facilitator or source updater
+pub const OPERATION_REPUTATION_UPDATE: &str = "update_reputation";
+
+/// Operation key for `file_dispute`, limited per filing client
+pub const OPERATION_DISPUTE_FILING: &str = "file_dispute";
+
+/// Operation key for `issue_credential` and `issue_credential_compressed`,
+/// limited per issuing signer
+pub const OPERATION_CREDENTIAL_ISSUANCE: &str = "issue_credential";
+
+/// Most per-operation limits a rate limiter holds
+pub const MAX_OPERATION_LIMITS: usize = 50;
+
+/// Longest operation key (keys are used as PDA seeds)
+pub const MAX_OPERATION_LEN: usize = 32;
+
 /// Rate limiter configuration for different operation types
 #[account]
 pub struct RateLimiter {
@@ -90,7 +116,7 @@ impl RateLimiter {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
         128 + // global_config
-        4 + (50 * 64) + // operation_limits (max 50)
+        4 + (MAX_OPERATION_LIMITS * 64) + // operation_limits
         1; // bump
 
     /// Initialize rate limiter
@@ -107,6 +133,74 @@ impl RateLimiter {
         Ok(())
     }
 
+    /// Default per-signer limits for the rate-limited instructions
+    ///
+    /// One service rating per payer every 5 minutes, up to 60 recorded
+    /// payments and 30 authorized reputation updates per signer per minute
+    /// with burst protection, 3 dispute filings per client per day and 100
+    /// credential issuances per issuer per hour.
+    pub fn default_operation_limits() -> Vec<OperationLimit> {
+        vec![
+            OperationLimit {
+                operation: OPERATION_SERVICE_RATING.to_string(),
+                limit: 1,
+                window_duration: 300,
+                allow_burst: false,
+            },
+            OperationLimit {
+                operation: OPERATION_PAYAI_PAYMENT.to_string(),
+                limit: 60,
+                window_duration: 60,
+                allow_burst: true,
+            },
+            OperationLimit {
+                operation: OPERATION_REPUTATION_UPDATE.to_string(),
+                limit: 30,
+                window_duration: 60,
+                allow_burst: true,
+            },
+            OperationLimit {
+                operation: OPERATION_DISPUTE_FILING.to_string(),
+                limit: 3,
+                window_duration: 86_400,
+                allow_burst: false,
+            },
+            OperationLimit {
+                operation: OPERATION_CREDENTIAL_ISSUANCE.to_string(),
+                limit: 100,
+                window_duration: 3_600,
+                allow_burst: true,
+            },
+        ]
+    }
+
+    /// Add or replace the limit for `limit.operation`
+    pub fn set_operation_limit(&mut self, limit: OperationLimit) -> Result<()> {
+        require!(
+            !limit.operation.is_empty()
+                && limit.operation.len() <= MAX_OPERATION_LEN
+                && limit.limit > 0
+                && limit.window_duration >= 0,
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+
+        match self
+            .operation_limits
+            .iter_mut()
+            .find(|op| op.operation == limit.operation)
+        {
+            Some(existing) => *existing = limit,
+            None => {
+                require!(
+                    self.operation_limits.len() < MAX_OPERATION_LIMITS,
+                    crate::GhostSpeakError::InvalidConfiguration
+                );
+                self.operation_limits.push(limit);
+            }
+        }
+        Ok(())
+    }
+
     /// Get limit for an operation
     pub fn get_operation_limit(&self, operation: &str) -> (u16, i64) {
         if let Some(op_limit) = self
@@ -217,6 +311,28 @@ impl UserRateLimit {
         Ok(true)
     }
 
+    /// Initialize on first use, then check and record one request
+    ///
+    /// Same flow as `check_rate_limit!`, for contexts whose signer is not
+    /// named `user`.
+    pub fn enforce(
+        &mut self,
+        rate_limiter: &RateLimiter,
+        user: Pubkey,
+        operation: &str,
+        bump: u8,
+    ) -> Result<()> {
+        if self.user == Pubkey::default() {
+            self.initialize(user, operation.to_string(), bump)?;
+        }
+
+        if !self.check_rate_limit(rate_limiter)? {
+            return Err(error!(crate::GhostSpeakError::RateLimitExceeded));
+        }
+
+        self.record_request()
+    }
+
     /// Record a new request
     pub fn record_request(&mut self) -> Result<()> {
         let clock = Clock::get()?;
@@ -311,12 +427,43 @@ pub struct InitializeRateLimiter<'info> {
     )]
     pub rate_limiter: Account<'info, RateLimiter>,
 
+    /// Protocol admin, who alone may create this singleton
+    #[account(
+        seeds = [ADMIN_AUTHORITY_SEED],
+        bump = admin_authority.bump,
+        constraint = admin_authority.admin == authority.key() @ crate::GhostSpeakError::UnauthorizedAccess
+    )]
+    pub admin_authority: Account<'info, AdminAuthority>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Change a per-operation limit (rate limiter authority only)
+#[derive(Accounts)]
+pub struct SetOperationRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [b"rate_limiter"],
+        bump = rate_limiter.bump,
+        constraint = rate_limiter.authority == authority.key() @ crate::GhostSpeakError::UnauthorizedAccess
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct OperationRateLimitSetEvent {
+    pub operation: String,
+    pub limit: u16,
+    pub window_duration: i64,
+    pub allow_burst: bool,
+    pub authority: Pubkey,
+}
+
 #[derive(Accounts)]
 #[instruction(operation: String)]
 pub struct CheckRateLimit<'info> {
@@ -381,6 +528,74 @@ mod tests {
         // RateLimiter: discriminator(8) + authority(32) + global_config(128) + operation_limits(4 + 50*64) + bump(1)
         assert_eq!(rate_limiter_size, 8 + 32 + 128 + 4 + (50 * 64) + 1);
         // UserRateLimit: discriminator(8) + user(32) + operation(4+64) + request_timestamps(4 + 100*8) + window_start(8) + request_count(2) + penalty_until(8) + violation_count(1) + bump(1)
-        assert_eq!(user_rate_limit_size, 8 + 32 + 4 + 64 + 4 + (100 * 8) + 8 + 2 + 8 + 1 + 1);
+        assert_eq!(
+            user_rate_limit_size,
+            8 + 32 + 4 + 64 + 4 + (100 * 8) + 8 + 2 + 8 + 1 + 1
+        );
+    }
+
+    #[test]
+    fn test_default_operation_limits() {
+        let mut limiter = RateLimiter {
+            authority: Pubkey::new_unique(),
+            global_config: RateLimitConfig {
+                default_limit: 100,
+                window_duration: 3600,
+                enabled: true,
+                penalty_duration: 60,
+                burst_size: 10,
+            },
+            operation_limits: RateLimiter::default_operation_limits(),
+            bump: 255,
+        };
+
+        // Operation keys are used as PDA seeds
+        for op in [
+            OPERATION_SERVICE_RATING,
+            OPERATION_PAYAI_PAYMENT,
+            OPERATION_REPUTATION_UPDATE,
+            OPERATION_DISPUTE_FILING,
+            OPERATION_CREDENTIAL_ISSUANCE,
+        ] {
+            assert!(op.len() <= MAX_OPERATION_LEN, "{op} too long for a seed");
+        }
+
+        assert_eq!(
+            limiter.get_operation_limit(OPERATION_SERVICE_RATING),
+            (1, 300)
+        );
+        assert_eq!(
+            limiter.get_operation_limit(OPERATION_PAYAI_PAYMENT),
+            (60, 60)
+        );
+        assert_eq!(
+            limiter.get_operation_limit(OPERATION_DISPUTE_FILING),
+            (3, 86_400)
+        );
+        assert_eq!(limiter.get_operation_limit("other"), (100, 3600));
+
+        // Governance can retune an operation without adding a duplicate
+        let count = limiter.operation_limits.len();
+        limiter
+            .set_operation_limit(OperationLimit {
+                operation: OPERATION_DISPUTE_FILING.to_string(),
+                limit: 5,
+                window_duration: 0,
+                allow_burst: false,
+            })
+            .unwrap();
+        assert_eq!(limiter.operation_limits.len(), count);
+        assert_eq!(
+            limiter.get_operation_limit(OPERATION_DISPUTE_FILING),
+            (5, 3600)
+        );
+        assert!(limiter
+            .set_operation_limit(OperationLimit {
+                operation: "x".repeat(MAX_OPERATION_LEN + 1),
+                limit: 5,
+                window_duration: 60,
+                allow_burst: false,
+            })
+            .is_err());
     }
 }
@@ -9,31 +9,14 @@
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
 
-/// Reentrancy guard states
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
-pub enum ReentrancyState {
-    Unlocked = 0,
-    Locked = 1,
-}
-
-/// Global reentrancy guard account
-#[account]
-pub struct ReentrancyGuard {
-    /// Current state of the guard
-    pub state: ReentrancyState,
+/// Seed for per-account locks: [seed, protected account, operation]
+pub const ACCOUNT_LOCK_SEED: &[u8] = b"account_lock";
 
-    /// Nonce to prevent replay attacks
-    pub nonce: u64,
+/// Operation key for escrow payouts (approval, arbitration, cancellation)
+pub const ESCROW_RELEASE_LOCK: &str = "escrow_release";
 
-    /// Last interaction timestamp
-    pub last_interaction: i64,
-
-    /// Authority that can reset the guard (for emergency situations)
-    pub authority: Pubkey,
-
-    /// Bump seed for PDA
-    pub bump: u8,
-}
+/// Longest operation key an account lock can hold
+pub const MAX_LOCK_OPERATION_LEN: usize = 64;
 
 /// Per-instruction reentrancy protection
 #[account]
@@ -57,7 +40,15 @@ pub struct InstructionLock {
     pub bump: u8,
 }
 
-/// Per-account reentrancy protection for critical operations
+/// Per-account reentrancy lock for critical operations
+///
+/// Seeds: ["account_lock", protected_account, operation]
+///
+/// Held for the span of one instruction: the handler creates and acquires it
+/// before moving funds and closes it back to the payer before returning, so
+/// no rent is left behind. A failed transaction rolls the acquisition back
+/// with everything else, so a lock is never left held, and contention is
+/// limited to the one protected account.
 #[account]
 pub struct AccountLock {
     /// The account being protected
@@ -66,9 +57,6 @@ pub struct AccountLock {
     /// Current operation type
     pub operation_type: String,
 
-    /// Transaction signature that initiated the lock
-    pub lock_signature: String,
-
     /// Locked by which authority
     pub locked_by: Pubkey,
 
@@ -81,70 +69,13 @@ pub struct AccountLock {
     /// Lock status
     pub is_active: bool,
 
+    /// Times the lock has been acquired
+    pub lock_count: u64,
+
     /// Bump seed
     pub bump: u8,
 }
 
-impl ReentrancyGuard {
-    pub const LEN: usize = 8 + // discriminator
-        1 + // state
-        8 + // nonce
-        8 + // last_interaction
-        32 + // authority
-        1; // bump
-
-    /// Initialize a new reentrancy guard
-    pub fn initialize(&mut self, authority: Pubkey, bump: u8) -> Result<()> {
-        self.state = ReentrancyState::Unlocked;
-        self.nonce = 0;
-        self.last_interaction = Clock::get()?.unix_timestamp;
-        self.authority = authority;
-        self.bump = bump;
-        Ok(())
-    }
-
-    /// Attempt to acquire the lock
-    pub fn lock(&mut self) -> Result<()> {
-        require!(
-            self.state == ReentrancyState::Unlocked,
-            crate::GhostSpeakError::ReentrancyDetected
-        );
-
-        self.state = ReentrancyState::Locked;
-        self.nonce = self.nonce.checked_add(1).unwrap();
-        self.last_interaction = Clock::get()?.unix_timestamp;
-
-        Ok(())
-    }
-
-    /// Release the lock
-    pub fn unlock(&mut self) -> Result<()> {
-        require!(
-            self.state == ReentrancyState::Locked,
-            crate::GhostSpeakError::InvalidState
-        );
-
-        self.state = ReentrancyState::Unlocked;
-        self.last_interaction = Clock::get()?.unix_timestamp;
-
-        Ok(())
-    }
-
-    /// Emergency reset (only by authority)
-    pub fn emergency_reset(&mut self, authority: &Signer) -> Result<()> {
-        require!(
-            authority.key() == self.authority,
-            crate::GhostSpeakError::UnauthorizedAccess
-        );
-
-        self.state = ReentrancyState::Unlocked;
-        self.nonce = self.nonce.checked_add(1).unwrap();
-        self.last_interaction = Clock::get()?.unix_timestamp;
-
-        Ok(())
-    }
-}
-
 impl InstructionLock {
     pub const LEN: usize = 8 + // discriminator
         8 + // instruction_hash
@@ -201,122 +132,56 @@ impl InstructionLock {
 impl AccountLock {
     pub const LEN: usize = 8 + // discriminator
         32 + // protected_account
-        4 + 64 + // operation_type (max 64 chars)
-        4 + 88 + // lock_signature (base58 tx signature)
+        4 + MAX_LOCK_OPERATION_LEN + // operation_type
         32 + // locked_by
         8 + // locked_at
         8 + // expires_at
         1 + // is_active
+        8 + // lock_count
         1; // bump
 
     /// Default account lock duration (60 seconds)
     pub const DEFAULT_LOCK_DURATION: i64 = 60;
 
-    /// Initialize account lock
-    pub fn initialize(
+    /// Take the lock on `protected_account` for `operation_type`, refusing
+    /// while another holder's lock is live
+    pub fn acquire(
         &mut self,
         protected_account: Pubkey,
-        operation_type: String,
-        lock_signature: String,
+        operation_type: &str,
         locked_by: Pubkey,
-        duration: Option<i64>,
+        now: i64,
         bump: u8,
     ) -> Result<()> {
-        let clock = Clock::get()?;
-
         require!(
-            operation_type.len() <= 64,
+            operation_type.len() <= MAX_LOCK_OPERATION_LEN,
             crate::GhostSpeakError::InputTooLong
         );
-
         require!(
-            lock_signature.len() <= 88, // Max base58 signature length
-            crate::GhostSpeakError::InputTooLong
+            !self.is_active || now > self.expires_at,
+            crate::GhostSpeakError::ReentrancyDetected
         );
 
         self.protected_account = protected_account;
-        self.operation_type = operation_type;
-        self.lock_signature = lock_signature;
+        self.operation_type = operation_type.to_string();
         self.locked_by = locked_by;
-        self.locked_at = clock.unix_timestamp;
-        self.expires_at = clock.unix_timestamp + duration.unwrap_or(Self::DEFAULT_LOCK_DURATION);
+        self.locked_at = now;
+        self.expires_at = now.saturating_add(Self::DEFAULT_LOCK_DURATION);
         self.is_active = true;
+        self.lock_count = self.lock_count.saturating_add(1);
         self.bump = bump;
 
         Ok(())
     }
 
-    /// Check if account lock is still valid
-    pub fn is_valid(&self) -> Result<bool> {
-        if !self.is_active {
-            return Ok(false);
-        }
-
-        let clock = Clock::get()?;
-        Ok(clock.unix_timestamp <= self.expires_at)
-    }
-
-    /// Release the account lock
-    pub fn release(&mut self, authority: &Signer) -> Result<()> {
-        let is_expired = !self.is_valid()?;
-
-        require!(
-            authority.key() == self.locked_by || is_expired,
-            crate::GhostSpeakError::UnauthorizedAccess
-        );
-
+    /// Release the lock at the end of the protected operation
+    pub fn release(&mut self) {
         self.is_active = false;
-        Ok(())
-    }
-}
-
-/// Reentrancy protection macros for easy integration
-///
-/// Macro to add reentrancy protection to an instruction
-#[macro_export]
-macro_rules! nonreentrant {
-    ($ctx:expr, $guard_account:expr) => {{
-        // Lock the reentrancy guard
-        $guard_account.lock()?;
-
-        // Store the guard for cleanup
-        let _guard = ReentrancyCleanup {
-            guard: $guard_account,
-        };
-    }};
-}
-
-/// Cleanup helper that automatically unlocks on drop
-pub struct ReentrancyCleanup<'a> {
-    pub guard: &'a mut Account<'a, ReentrancyGuard>,
-}
-
-impl<'a> Drop for ReentrancyCleanup<'a> {
-    fn drop(&mut self) {
-        // Automatically unlock when going out of scope
-        let _ = self.guard.unlock();
     }
 }
 
 /// Account validation structs for reentrancy protection
 
-#[derive(Accounts)]
-pub struct InitializeReentrancyGuard<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = ReentrancyGuard::LEN,
-        seeds = [b"reentrancy_guard"],
-        bump
-    )]
-    pub reentrancy_guard: Account<'info, ReentrancyGuard>,
-
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
-}
-
 #[derive(Accounts)]
 #[instruction(instruction_hash: [u8; 8])]
 pub struct CreateInstructionLock<'info> {
@@ -342,7 +207,7 @@ pub struct CreateAccountLock<'info> {
         init,
         payer = authority,
         space = AccountLock::LEN,
-        seeds = [b"account_lock", protected_account.as_ref(), operation_type.as_bytes()],
+        seeds = [ACCOUNT_LOCK_SEED, protected_account.as_ref(), operation_type.as_bytes()],
         bump
     )]
     pub account_lock: Account<'info, AccountLock>,
@@ -428,7 +293,7 @@ pub fn is_account_locked<'info>(
 ) -> Result<bool> {
     // Derive the PDA for the account lock
     let (lock_pda, _bump) = Pubkey::find_program_address(
-        &[b"account_lock", account.as_ref(), operation.as_bytes()],
+        &[ACCOUNT_LOCK_SEED, account.as_ref(), operation.as_bytes()],
         program_id,
     );
 
@@ -498,27 +363,56 @@ macro_rules! check_reentrancy {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_reentrancy_guard_states() {
-        // Test state transitions
-        assert_eq!(ReentrancyState::Unlocked as u8, 0);
-        assert_eq!(ReentrancyState::Locked as u8, 1);
-    }
-
     #[test]
     fn test_account_sizes() {
         // Verify that our reentrancy protection structures have expected sizes
         // All should include the 8-byte discriminator plus their data fields
-        let guard_size = ReentrancyGuard::LEN;
         let instruction_lock_size = InstructionLock::LEN;
         let account_lock_size = AccountLock::LEN;
 
-        // Test expected structure sizes (discriminator + data fields)
-        // ReentrancyGuard: discriminator(8) + state(1) + nonce(8) + last_interaction(8) + authority(32) + bump(1)
-        assert_eq!(guard_size, 8 + 1 + 8 + 8 + 32 + 1);
         // InstructionLock: discriminator(8) + instruction_hash(8) + locked_by(32) + locked_at(8) + max_duration(8) + is_locked(1) + bump(1)
         assert_eq!(instruction_lock_size, 8 + 8 + 32 + 8 + 8 + 1 + 1);
-        // AccountLock: discriminator(8) + protected_account(32) + operation_type(4+64) + lock_signature(4+88) + locked_by(32) + locked_at(8) + expires_at(8) + is_active(1) + bump(1)
-        assert_eq!(account_lock_size, 8 + 32 + 4 + 64 + 4 + 88 + 32 + 8 + 8 + 1 + 1);
+        // AccountLock: discriminator(8) + protected_account(32) + operation_type(4+64) + locked_by(32) + locked_at(8) + expires_at(8) + is_active(1) + lock_count(8) + bump(1)
+        assert_eq!(account_lock_size, 8 + 32 + 4 + 64 + 32 + 8 + 8 + 1 + 8 + 1);
+    }
+
+    #[test]
+    fn test_account_lock_acquire_release() {
+        let mut lock = AccountLock {
+            protected_account: Pubkey::default(),
+            operation_type: String::new(),
+            locked_by: Pubkey::default(),
+            locked_at: 0,
+            expires_at: 0,
+            is_active: false,
+            lock_count: 0,
+            bump: 0,
+        };
+        let escrow = Pubkey::new_unique();
+        let caller = Pubkey::new_unique();
+
+        lock.acquire(escrow, ESCROW_RELEASE_LOCK, caller, 10, 255)
+            .unwrap();
+        assert!(lock.is_active);
+
+        // A nested acquisition is refused while the lock is live
+        assert!(lock
+            .acquire(escrow, ESCROW_RELEASE_LOCK, caller, 20, 255)
+            .is_err());
+
+        lock.release();
+        lock.acquire(escrow, ESCROW_RELEASE_LOCK, caller, 20, 255)
+            .unwrap();
+        assert_eq!(lock.lock_count, 2);
+
+        // A lock that somehow outlived its expiry does not block forever
+        lock.acquire(
+            escrow,
+            ESCROW_RELEASE_LOCK,
+            caller,
+            20 + AccountLock::DEFAULT_LOCK_DURATION + 1,
+            255,
+        )
+        .unwrap();
     }
 }
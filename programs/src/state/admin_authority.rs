@@ -0,0 +1,163 @@
+/*!
+ * Admin Authority State
+ *
+ * The protocol admin, held in a PDA instead of a key compiled into the
+ * program. The program's upgrade authority creates it at deploy; from then
+ * on the admin only changes when the Protocol multisig queues a new one and
+ * its timelock runs out.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::security::admin_validation::require_valid_admin;
+use crate::GhostSpeakError;
+
+/// Seed for the admin authority: [seed]
+pub const ADMIN_AUTHORITY_SEED: &[u8] = b"admin_authority";
+
+/// Admin Authority - who may run admin-gated instructions
+///
+/// Seeds: ["admin_authority"]
+#[account]
+pub struct AdminAuthority {
+    /// Current protocol admin
+    pub admin: Pubkey,
+    /// Protocol multisig that can replace the admin
+    pub multisig: Pubkey,
+    /// Admin queued by the multisig (default = no transfer pending)
+    pub pending_admin: Pubkey,
+    /// When the pending admin may take over
+    pub executable_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AdminAuthority {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        32 + // multisig
+        32 + // pending_admin
+        8 + // executable_at
+        8 + // updated_at
+        1; // bump
+
+    /// Queue `new_admin`, replacing any transfer already pending
+    pub fn propose_transfer(&mut self, new_admin: Pubkey, timelock: i64, now: i64) -> Result<()> {
+        require_valid_admin(&new_admin)?;
+        self.pending_admin = new_admin;
+        self.executable_at = now
+            .checked_add(timelock)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Install the pending admin once its timelock has passed, returning
+    /// the previous admin
+    pub fn apply_transfer(&mut self, now: i64) -> Result<Pubkey> {
+        require!(
+            self.pending_admin != Pubkey::default(),
+            GhostSpeakError::NoPendingAdminTransfer
+        );
+        require!(
+            now >= self.executable_at,
+            GhostSpeakError::MultisigTimelockActive
+        );
+        let previous = std::mem::replace(&mut self.admin, self.pending_admin);
+        self.clear_pending(now);
+        Ok(previous)
+    }
+
+    /// Drop the pending transfer, returning the admin it would have installed
+    pub fn cancel_transfer(&mut self, now: i64) -> Result<Pubkey> {
+        require!(
+            self.pending_admin != Pubkey::default(),
+            GhostSpeakError::NoPendingAdminTransfer
+        );
+        let cancelled = self.pending_admin;
+        self.clear_pending(now);
+        Ok(cancelled)
+    }
+
+    fn clear_pending(&mut self, now: i64) {
+        self.pending_admin = Pubkey::default();
+        self.executable_at = 0;
+        self.updated_at = now;
+    }
+}
+
+#[event]
+pub struct AdminAuthorityInitializedEvent {
+    pub admin: Pubkey,
+    pub multisig: Pubkey,
+    pub upgrade_authority: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferProposedEvent {
+    pub multisig: Pubkey,
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct AdminTransferredEvent {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelledEvent {
+    pub multisig: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authority() -> AdminAuthority {
+        AdminAuthority {
+            admin: Pubkey::new_unique(),
+            multisig: Pubkey::new_unique(),
+            pending_admin: Pubkey::default(),
+            executable_at: 0,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_admin_transfer_waits_for_timelock() {
+        let mut auth = authority();
+        let original = auth.admin;
+        let new_admin = Pubkey::new_unique();
+
+        assert!(auth.apply_transfer(0).is_err());
+        assert!(auth.propose_transfer(Pubkey::default(), 100, 0).is_err());
+
+        auth.propose_transfer(new_admin, 100, 10).unwrap();
+        assert_eq!(auth.executable_at, 110);
+        assert!(auth.apply_transfer(109).is_err());
+        assert_eq!(auth.apply_transfer(110).unwrap(), original);
+        assert_eq!(auth.admin, new_admin);
+        assert_eq!(auth.pending_admin, Pubkey::default());
+        assert!(auth.apply_transfer(200).is_err());
+    }
+
+    #[test]
+    fn test_admin_transfer_cancel() {
+        let mut auth = authority();
+        let original = auth.admin;
+        assert!(auth.cancel_transfer(0).is_err());
+
+        let new_admin = Pubkey::new_unique();
+        auth.propose_transfer(new_admin, 100, 0).unwrap();
+        assert_eq!(auth.cancel_transfer(50).unwrap(), new_admin);
+        assert!(auth.apply_transfer(100).is_err());
+        assert_eq!(auth.admin, original);
+    }
+}
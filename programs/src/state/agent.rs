@@ -172,6 +172,69 @@ pub struct Agent {
     // API Schema Support for Service Discovery
     pub api_spec_uri: String,     // IPFS/HTTP URL to OpenAPI 3.0 spec (JSON)
     pub api_version: String,      // Semantic version of the API (e.g., "1.0.0")
+
+    pub bump: u8,
+
+    /// Layout version; fields below exist only from `CURRENT_VERSION` on,
+    /// and older agents gain them through `migrate_agent`
+    pub version: u8,
+
+    // Referral
+    pub referrer: Option<Pubkey>, // Referrer credited with a share of this agent's fees
+}
+
+/// Agent as written before the layout version
+#[derive(AnchorDeserialize)]
+pub struct AgentV0 {
+    pub owner: Option<Pubkey>,
+    pub status: AgentStatus,
+    pub agent_id: String,
+    pub first_tx_signature: String,
+    pub first_seen_timestamp: i64,
+    pub discovery_source: String,
+    pub claimed_at: Option<i64>,
+    pub agent_type: u8,
+    pub name: String,
+    pub description: String,
+    pub capabilities: Vec<String>,
+    pub pricing_model: PricingModel,
+    pub reputation_score: u32,
+    pub total_jobs_completed: u32,
+    pub total_earnings: u64,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub original_price: u64,
+    pub genome_hash: String,
+    pub is_replicable: bool,
+    pub replication_fee: u64,
+    pub service_endpoint: String,
+    pub is_verified: bool,
+    pub verification_timestamp: i64,
+    pub metadata_uri: String,
+    pub framework_origin: String,
+    pub supported_tokens: Vec<Pubkey>,
+    pub cnft_mint: Option<Pubkey>,
+    pub merkle_tree: Option<Pubkey>,
+    pub supports_a2a: bool,
+    pub transfer_hook: Option<Pubkey>,
+    pub parent_agent: Option<Pubkey>,
+    pub generation: u32,
+    pub x402_enabled: bool,
+    pub x402_payment_address: Pubkey,
+    pub x402_accepted_tokens: Vec<Pubkey>,
+    pub x402_price_per_call: u64,
+    pub x402_service_endpoint: String,
+    pub x402_total_payments: u64,
+    pub x402_total_calls: u64,
+    pub last_payment_timestamp: i64,
+    pub external_identifiers: Vec<ExternalIdentifier>,
+    pub ghost_score: u64,
+    pub reputation_components: Vec<ReputationComponent>,
+    pub did_address: Option<Pubkey>,
+    pub credentials: Vec<Pubkey>,
+    pub api_spec_uri: String,
+    pub api_version: String,
     pub bump: u8,
 }
 
@@ -247,7 +310,81 @@ impl Agent {
         // API schema fields
         4 + Self::MAX_URI_LEN + // api_spec_uri (reduced)
         4 + 16 + // api_version (reduced for semver)
-        1; // bump
+        1 + // bump
+        Self::V1_EXTENSION_LEN; // version, referrer
+
+    /// Layout with `version` and the referrer after `bump`
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Bytes the version and referrer add to agents created before them
+    pub const V1_EXTENSION_LEN: usize = 1 + // version
+        1 + 32; // referrer: Option<Pubkey>
+
+    /// Whether this agent, `data_len` bytes long, has the current layout
+    ///
+    /// Agents created before the version field are exactly
+    /// `V1_EXTENSION_LEN` bytes short of `LEN`, so their trailing bytes are
+    /// never trusted as a version.
+    pub fn is_current(&self, data_len: usize) -> bool {
+        data_len >= Self::LEN && self.version == Self::CURRENT_VERSION
+    }
+
+    /// A pre-version agent in the current layout, with no referrer
+    pub fn from_v0(legacy: AgentV0) -> Self {
+        Self {
+            owner: legacy.owner,
+            status: legacy.status,
+            agent_id: legacy.agent_id,
+            first_tx_signature: legacy.first_tx_signature,
+            first_seen_timestamp: legacy.first_seen_timestamp,
+            discovery_source: legacy.discovery_source,
+            claimed_at: legacy.claimed_at,
+            agent_type: legacy.agent_type,
+            name: legacy.name,
+            description: legacy.description,
+            capabilities: legacy.capabilities,
+            pricing_model: legacy.pricing_model,
+            reputation_score: legacy.reputation_score,
+            total_jobs_completed: legacy.total_jobs_completed,
+            total_earnings: legacy.total_earnings,
+            is_active: legacy.is_active,
+            created_at: legacy.created_at,
+            updated_at: legacy.updated_at,
+            original_price: legacy.original_price,
+            genome_hash: legacy.genome_hash,
+            is_replicable: legacy.is_replicable,
+            replication_fee: legacy.replication_fee,
+            service_endpoint: legacy.service_endpoint,
+            is_verified: legacy.is_verified,
+            verification_timestamp: legacy.verification_timestamp,
+            metadata_uri: legacy.metadata_uri,
+            framework_origin: legacy.framework_origin,
+            supported_tokens: legacy.supported_tokens,
+            cnft_mint: legacy.cnft_mint,
+            merkle_tree: legacy.merkle_tree,
+            supports_a2a: legacy.supports_a2a,
+            transfer_hook: legacy.transfer_hook,
+            parent_agent: legacy.parent_agent,
+            generation: legacy.generation,
+            x402_enabled: legacy.x402_enabled,
+            x402_payment_address: legacy.x402_payment_address,
+            x402_accepted_tokens: legacy.x402_accepted_tokens,
+            x402_price_per_call: legacy.x402_price_per_call,
+            x402_service_endpoint: legacy.x402_service_endpoint,
+            x402_total_payments: legacy.x402_total_payments,
+            x402_total_calls: legacy.x402_total_calls,
+            last_payment_timestamp: legacy.last_payment_timestamp,
+            external_identifiers: legacy.external_identifiers,
+            ghost_score: legacy.ghost_score,
+            reputation_components: legacy.reputation_components,
+            did_address: legacy.did_address,
+            credentials: legacy.credentials,
+            api_spec_uri: legacy.api_spec_uri,
+            api_version: legacy.api_version,
+            bump: legacy.bump,
+            version: Self::CURRENT_VERSION,
+            referrer: None,
+        }
+    }
 
     /// Deactivate the agent
     pub fn deactivate(&mut self) {
@@ -323,6 +460,7 @@ impl Agent {
         self.credentials = Vec::new();
 
         self.bump = bump;
+        self.version = Self::CURRENT_VERSION;
 
         Ok(())
     }
@@ -373,6 +511,7 @@ impl Agent {
         self.credentials = Vec::new();
 
         self.bump = bump;
+        self.version = Self::CURRENT_VERSION;
 
         Ok(())
     }
@@ -0,0 +1,189 @@
+/*!
+ * Agent Handle State
+ *
+ * Global registry of human-readable handles (`ghost:alice-bot`) mapped to
+ * DID documents and, optionally, agent accounts. Each handle is its own PDA
+ * seeded by the handle string, which makes handles unique. Owners pay the
+ * rent, renew before expiry, and can transfer handles; lapsed handles can be
+ * released by anyone after a grace period and registered again.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Seed for handle PDAs: [seed, handle.as_bytes()]
+pub const AGENT_HANDLE_SEED: &[u8] = b"agent_handle";
+
+/// Display namespace prefix; not part of the stored handle
+pub const AGENT_HANDLE_NAMESPACE: &str = "ghost:";
+
+pub const MIN_HANDLE_LENGTH: usize = 3;
+/// Bounded by the 32-byte PDA seed limit
+pub const MAX_HANDLE_LENGTH: usize = 32;
+
+/// Default registration / renewal period (365 days), governed by
+/// `ParameterKey::HandleRegistrationPeriod`
+pub const HANDLE_REGISTRATION_PERIOD: i64 = 365 * 24 * 60 * 60;
+
+/// Default time after expiry during which only the owner can renew
+/// (30 days), governed by `ParameterKey::HandleGracePeriod`
+pub const HANDLE_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Handle → DID mapping
+///
+/// Seeds: ["agent_handle", handle]
+#[account]
+pub struct AgentHandle {
+    /// Lowercase handle without the `ghost:` namespace
+    pub handle: String,
+    /// Handle owner (pays rent, renews, transfers)
+    pub owner: Pubkey,
+    /// DID document the handle resolves to
+    pub did_document: Pubkey,
+    /// Agent account the handle resolves to, if any
+    pub agent: Option<Pubkey>,
+    pub registered_at: i64,
+    /// Resolution stops after this time until renewed
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl AgentHandle {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_HANDLE_LENGTH + // handle
+        32 + // owner
+        32 + // did_document
+        1 + 32 + // agent
+        8 + // registered_at
+        8 + // expires_at
+        1; // bump
+
+    /// Whether the handle currently resolves
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Whether anyone may release the handle, `grace_period` after expiry
+    pub fn is_releasable(&self, now: i64, grace_period: i64) -> bool {
+        now >= self.expires_at.saturating_add(grace_period)
+    }
+
+    /// Extend the registration by one `period` from expiry, or from now if
+    /// already expired
+    pub fn renew(&mut self, now: i64, period: i64, grace_period: i64) -> Result<()> {
+        require!(
+            !self.is_releasable(now, grace_period),
+            GhostSpeakError::HandleExpired
+        );
+        self.expires_at = self
+            .expires_at
+            .max(now)
+            .checked_add(period)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Validate a handle: 3-32 chars of `a-z`, `0-9` and inner `-`
+pub fn validate_handle(handle: &str) -> Result<()> {
+    let bytes = handle.as_bytes();
+    require!(
+        (MIN_HANDLE_LENGTH..=MAX_HANDLE_LENGTH).contains(&bytes.len())
+            && bytes
+                .iter()
+                .all(|&b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+            && bytes.first() != Some(&b'-')
+            && bytes.last() != Some(&b'-'),
+        GhostSpeakError::InvalidHandle
+    );
+    Ok(())
+}
+
+/// Event emitted when a handle is registered
+#[event]
+pub struct AgentHandleRegisteredEvent {
+    pub handle: String,
+    pub owner: Pubkey,
+    pub did_document: Pubkey,
+    pub agent: Option<Pubkey>,
+    pub expires_at: i64,
+}
+
+/// Event emitted when a handle is renewed
+#[event]
+pub struct AgentHandleRenewedEvent {
+    pub handle: String,
+    pub expires_at: i64,
+}
+
+/// Event emitted when a handle changes owner
+#[event]
+pub struct AgentHandleTransferredEvent {
+    pub handle: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub did_document: Pubkey,
+    pub agent: Option<Pubkey>,
+}
+
+/// Event emitted when a lapsed handle is released
+#[event]
+pub struct AgentHandleReleasedEvent {
+    pub handle: String,
+    pub owner: Pubkey,
+    pub released_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_handle() {
+        for ok in ["alice-bot", "abc", "a1-b2-c3", "x".repeat(32).as_str()] {
+            assert!(validate_handle(ok).is_ok(), "{ok}");
+        }
+        for bad in [
+            "ab",
+            "Alice",
+            "-alice",
+            "alice-",
+            "alice_bot",
+            "ghost:alice",
+        ] {
+            assert!(validate_handle(bad).is_err(), "{bad}");
+        }
+        assert!(validate_handle(&"x".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_handle_expiry_and_renewal() {
+        let mut handle = AgentHandle {
+            handle: "alice-bot".to_string(),
+            owner: Pubkey::new_unique(),
+            did_document: Pubkey::new_unique(),
+            agent: None,
+            registered_at: 0,
+            expires_at: HANDLE_REGISTRATION_PERIOD,
+            bump: 255,
+        };
+
+        // Early renewal stacks on the current expiry
+        let (period, grace) = (HANDLE_REGISTRATION_PERIOD, HANDLE_GRACE_PERIOD);
+        handle.renew(10, period, grace).unwrap();
+        assert_eq!(handle.expires_at, 2 * HANDLE_REGISTRATION_PERIOD);
+
+        // Within the grace period the handle is inactive but renewable
+        let lapsed = handle.expires_at + 1;
+        assert!(!handle.is_active(lapsed));
+        assert!(!handle.is_releasable(lapsed, grace));
+        handle.renew(lapsed, period, grace).unwrap();
+        assert_eq!(handle.expires_at, lapsed + HANDLE_REGISTRATION_PERIOD);
+
+        // After the grace period it can only be released
+        let released = handle.expires_at + grace;
+        assert!(handle.is_releasable(released, grace));
+        assert!(handle.renew(released, period, grace).is_err());
+    }
+}
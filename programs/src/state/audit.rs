@@ -6,8 +6,10 @@
  */
 
 use anchor_lang::prelude::*;
+use sha3::{Digest, Keccak256};
 // use std::collections::BTreeMap; // Commented out - using Vec<(K,V)> for Anchor compatibility
 use super::GhostSpeakError;
+use crate::utils::pagination::{push_item, PageCursor, PageLink};
 
 // Note: BTreeMap is not available in Anchor/BPF environment
 // We'll use Vec<(String, String)> instead for key-value pairs
@@ -15,6 +17,7 @@ use super::GhostSpeakError;
 // PDA Seeds
 pub const AUDIT_TRAIL_SEED: &[u8] = b"audit_trail";
 pub const COMPLIANCE_REPORT_SEED: &[u8] = b"compliance_report";
+pub const AUDIT_ENTRY_PAGE_SEED: &[u8] = b"audit_page";
 
 // =====================================================
 // AUDIT TRAIL STRUCTURES
@@ -50,8 +53,14 @@ pub struct AuditTrail {
     /// Hash chain for integrity verification
     pub hash_chain: Vec<[u8; 32]>,
 
+    /// Cursor over paged entries (AuditEntryPage accounts)
+    pub entry_pages: PageCursor,
+
+    /// Hash of the most recent paged entry
+    pub last_entry_hash: [u8; 32],
+
     /// Reserved space for future extensions
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 52],
 }
 
 /// Individual audit entry (immutable)
@@ -88,6 +97,37 @@ pub struct AuditEntry {
     pub signature: Option<[u8; 64]>,
 }
 
+/// Page of audit entries linked from an AuditTrail's `entry_pages` cursor
+///
+/// Seeds: ["audit_page", audit_trail, index.to_le_bytes()]
+#[account]
+pub struct AuditEntryPage {
+    /// Audit trail this page belongs to
+    pub audit_trail: Pubkey,
+
+    /// Position and link to the next page
+    pub link: PageLink,
+
+    /// Entries in this page (oldest first)
+    pub entries: Vec<AuditEntry>,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AuditEntryPage {
+    /// Entries per page (bounded by the 10KB CPI allocation limit)
+    pub const CAPACITY: u16 = 2;
+
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // audit_trail
+        PageLink::LEN + // link
+        4 + (Self::CAPACITY as usize * AuditEntry::size()) + // entries
+        1 // bump
+    }
+}
+
 /// Types of auditable actions
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AuditAction {
@@ -710,7 +750,9 @@ impl AuditTrail {
         AuditConfig::size() + // config
         ComplianceStatus::size() + // compliance_status
         4 + (32 * MAX_AUDIT_ENTRIES) + // hash_chain
-        128 // reserved
+        PageCursor::LEN + // entry_pages
+        32 + // last_entry_hash
+        52 // reserved
     }
 
     /// Initialize a new audit trail
@@ -753,7 +795,9 @@ impl AuditTrail {
             compliance_officers: Vec::new(),
         };
         self.hash_chain = Vec::new();
-        self.reserved = [0; 128];
+        self.entry_pages = PageCursor::default();
+        self.last_entry_hash = [0; 32];
+        self.reserved = [0; 52];
 
         Ok(())
     }
@@ -803,6 +847,49 @@ impl AuditTrail {
     }
 }
 
+impl AuditTrail {
+    /// Append an entry to the tail page, extending the hash chain
+    pub fn append_paged_entry(
+        &mut self,
+        page: &mut AuditEntryPage,
+        action: AuditAction,
+        actor: Pubkey,
+        target: Option<Pubkey>,
+        context: AuditContext,
+        compliance_flags: ComplianceFlags,
+        now: i64,
+    ) -> Result<u64> {
+        let entry_id = push_item(
+            &mut self.entry_pages,
+            &mut page.link,
+            AuditEntryPage::CAPACITY,
+        )?;
+
+        let mut entry = AuditEntry {
+            entry_id,
+            timestamp: now,
+            action,
+            actor,
+            target,
+            context,
+            compliance_flags,
+            previous_hash: self.last_entry_hash,
+            entry_hash: [0; 32],
+            signature: None,
+        };
+        let mut hasher = Keccak256::new();
+        hasher.update(entry.previous_hash);
+        hasher.update(entry.try_to_vec()?);
+        entry.entry_hash = hasher.finalize().into();
+
+        self.last_entry_hash = entry.entry_hash;
+        self.updated_at = now;
+        page.entries.push(entry);
+
+        Ok(entry_id)
+    }
+}
+
 impl AuditEntry {
     pub const fn size() -> usize {
         8 + // entry_id
@@ -0,0 +1,176 @@
+/*!
+ * Authority Transfer State
+ *
+ * Two-step handover of the authority on `ProtocolConfig`, `StakingConfig`
+ * and credential types. The current authority proposes a successor, who
+ * must sign an acceptance before the proposal expires; a mistyped key can
+ * never accept, so the proposal simply lapses and the authority stays put.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::security::admin_validation::require_valid_admin;
+use crate::GhostSpeakError;
+
+/// Seed for pending transfers: [seed, target account]
+pub const AUTHORITY_TRANSFER_SEED: &[u8] = b"authority_transfer";
+
+/// Longest a proposed transfer may stay open (30 days)
+pub const MAX_AUTHORITY_TRANSFER_WINDOW: i64 = 30 * 24 * 60 * 60;
+
+/// Account whose authority is being handed over
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorityTarget {
+    ProtocolConfig,
+    StakingConfig,
+    CredentialType,
+}
+
+/// Authority Transfer - a proposed handover awaiting acceptance
+///
+/// Seeds: ["authority_transfer", target]
+#[account]
+pub struct AuthorityTransfer {
+    /// Account whose authority changes
+    pub target: Pubkey,
+    pub target_kind: AuthorityTarget,
+    /// Authority that proposed the transfer (refunded on close)
+    pub current_authority: Pubkey,
+    /// Key that must accept
+    pub pending_authority: Pubkey,
+    pub proposed_at: i64,
+    /// Acceptance is refused from this time on
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl AuthorityTransfer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // target
+        1 + // target_kind
+        32 + // current_authority
+        32 + // pending_authority
+        8 + // proposed_at
+        8 + // expires_at
+        1; // bump
+
+    /// Record a proposal, replacing any earlier one for the same target
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose(
+        &mut self,
+        target: Pubkey,
+        target_kind: AuthorityTarget,
+        current_authority: Pubkey,
+        pending_authority: Pubkey,
+        expires_in: i64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require_valid_admin(&pending_authority)?;
+        require!(
+            pending_authority != current_authority
+                && expires_in > 0
+                && expires_in <= MAX_AUTHORITY_TRANSFER_WINDOW,
+            GhostSpeakError::InvalidAuthorityTransfer
+        );
+
+        self.target = target;
+        self.target_kind = target_kind;
+        self.current_authority = current_authority;
+        self.pending_authority = pending_authority;
+        self.proposed_at = now;
+        self.expires_at = now.saturating_add(expires_in);
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Check that the proposal can be accepted by the pending authority
+    /// while `authority` is still the target's authority
+    pub fn require_acceptable(&self, authority: &Pubkey, now: i64) -> Result<()> {
+        require!(
+            now < self.expires_at,
+            GhostSpeakError::AuthorityTransferExpired
+        );
+        require!(
+            *authority == self.current_authority,
+            GhostSpeakError::StaleAuthorityTransfer
+        );
+        Ok(())
+    }
+}
+
+#[event]
+pub struct AuthorityTransferProposedEvent {
+    pub target: Pubkey,
+    pub target_kind: AuthorityTarget,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AuthorityTransferAcceptedEvent {
+    pub target: Pubkey,
+    pub target_kind: AuthorityTarget,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelledEvent {
+    pub target: Pubkey,
+    pub target_kind: AuthorityTarget,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_expiry_and_staleness() {
+        let mut transfer = AuthorityTransfer {
+            target: Pubkey::default(),
+            target_kind: AuthorityTarget::ProtocolConfig,
+            current_authority: Pubkey::default(),
+            pending_authority: Pubkey::default(),
+            proposed_at: 0,
+            expires_at: 0,
+            bump: 0,
+        };
+        let target = Pubkey::new_unique();
+        let current = Pubkey::new_unique();
+        let next = Pubkey::new_unique();
+        let kind = AuthorityTarget::StakingConfig;
+
+        assert!(transfer
+            .propose(target, kind, current, current, 100, 0, 255)
+            .is_err());
+        assert!(transfer
+            .propose(target, kind, current, Pubkey::default(), 100, 0, 255)
+            .is_err());
+        assert!(transfer
+            .propose(
+                target,
+                kind,
+                current,
+                next,
+                MAX_AUTHORITY_TRANSFER_WINDOW + 1,
+                0,
+                255
+            )
+            .is_err());
+
+        transfer
+            .propose(target, kind, current, next, 100, 10, 255)
+            .unwrap();
+        assert_eq!(transfer.expires_at, 110);
+        transfer.require_acceptable(&current, 109).unwrap();
+        assert!(transfer.require_acceptable(&current, 110).is_err());
+
+        // The authority changed some other way since the proposal
+        assert!(transfer.require_acceptable(&next, 50).is_err());
+    }
+}
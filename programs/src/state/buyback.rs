@@ -0,0 +1,274 @@
+/*!
+ * Buyback State
+ *
+ * Configuration and bookkeeping for protocol GHOST buybacks. USDC sent to
+ * the buyback USDC vault (the protocol config's `buyback_pool`) is swapped
+ * for GHOST through a whitelisted swap program, at most `epoch_cap` USDC
+ * per epoch and within `max_slippage_bps` of what the USDC spent is worth
+ * in GHOST at the Pyth prices. The GHOST bought is burned or added to the
+ * staking reward reserve.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Seed for the buyback config PDA
+pub const BUYBACK_CONFIG_SEED: &[u8] = b"buyback_config";
+
+/// Seed for the USDC vault: [seed, buyback_config]
+pub const BUYBACK_USDC_VAULT_SEED: &[u8] = b"buyback_usdc_vault";
+
+/// Seed for the GHOST vault: [seed, buyback_config]
+pub const BUYBACK_GHOST_VAULT_SEED: &[u8] = b"buyback_ghost_vault";
+
+/// Largest slippage tolerance governance may configure (10%)
+pub const MAX_BUYBACK_SLIPPAGE_BPS: u16 = 1_000;
+
+/// What happens to bought GHOST
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuybackDisposition {
+    /// Burn it, reducing supply
+    Burn,
+    /// Add it to the staking reward reserve
+    Restake,
+}
+
+/// Governed buyback settings
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BuybackParams {
+    /// Key allowed to execute buybacks
+    pub operator: Pubkey,
+    /// Swap program the buyback routes through (e.g. Jupiter or Whirlpool)
+    pub swap_program: Pubkey,
+    /// Largest shortfall accepted against the oracle-priced output
+    pub max_slippage_bps: u16,
+    /// Length of a spending epoch in seconds
+    pub epoch_duration: i64,
+    /// USDC that may be spent per epoch
+    pub epoch_cap: u64,
+    pub disposition: BuybackDisposition,
+}
+
+impl BuybackParams {
+    pub const SIZE: usize = 32 + // operator
+        32 + // swap_program
+        2 + // max_slippage_bps
+        8 + // epoch_duration
+        8 + // epoch_cap
+        1; // disposition
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.operator != Pubkey::default()
+                && self.swap_program != Pubkey::default()
+                && self.max_slippage_bps <= MAX_BUYBACK_SLIPPAGE_BPS
+                && self.epoch_duration > 0
+                && self.epoch_cap > 0,
+            GhostSpeakError::InvalidBuybackParams
+        );
+        Ok(())
+    }
+}
+
+/// Buyback Config - settings, vaults and running totals
+///
+/// Seeds: ["buyback_config"]
+#[account]
+pub struct BuybackConfig {
+    /// Protocol multisig that changes the settings
+    pub multisig: Pubkey,
+    pub params: BuybackParams,
+    pub usdc_mint: Pubkey,
+    pub ghost_mint: Pubkey,
+    pub usdc_vault: Pubkey,
+    pub ghost_vault: Pubkey,
+    /// Start of the current spending epoch
+    pub epoch_start: i64,
+    /// USDC spent in the current epoch
+    pub epoch_spent: u64,
+    pub total_usdc_spent: u64,
+    pub total_ghost_burned: u64,
+    pub total_ghost_restaked: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl BuybackConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        BuybackParams::SIZE + // params
+        32 + // usdc_mint
+        32 + // ghost_mint
+        32 + // usdc_vault
+        32 + // ghost_vault
+        8 + // epoch_start
+        8 + // epoch_spent
+        8 + // total_usdc_spent
+        8 + // total_ghost_burned
+        8 + // total_ghost_restaked
+        8 + // updated_at
+        1; // bump
+
+    /// Reserve `amount` USDC from the current epoch's cap, starting a new
+    /// epoch once the current one has run its course
+    pub fn reserve_epoch_spend(&mut self, amount: u64, now: i64) -> Result<()> {
+        require!(amount > 0, GhostSpeakError::InvalidAmount);
+        let duration = self.params.epoch_duration;
+        if now >= self.epoch_start.saturating_add(duration) {
+            // Epochs stay aligned to the first one
+            let elapsed = now.saturating_sub(self.epoch_start);
+            self.epoch_start = self
+                .epoch_start
+                .saturating_add(elapsed - elapsed % duration);
+            self.epoch_spent = 0;
+        }
+
+        let spent = self
+            .epoch_spent
+            .checked_add(amount)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        require!(
+            spent <= self.params.epoch_cap,
+            GhostSpeakError::BuybackEpochCapExceeded
+        );
+        self.epoch_spent = spent;
+        Ok(())
+    }
+
+    /// Least GHOST a swap must return when `oracle_out` is what the USDC
+    /// spent is worth at the oracle prices
+    pub fn min_out(&self, oracle_out: u64) -> u64 {
+        let tolerance = 10_000 - self.params.max_slippage_bps as u128;
+        (oracle_out as u128 * tolerance / 10_000) as u64
+    }
+
+    /// Settle an executed swap: `reserved` USDC was reserved from the
+    /// epoch, `spent` actually left the vault and `received` GHOST arrived,
+    /// where `oracle_out` is `spent` priced in GHOST
+    pub fn record_swap(
+        &mut self,
+        reserved: u64,
+        spent: u64,
+        received: u64,
+        oracle_out: u64,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            spent > 0 && spent <= reserved,
+            GhostSpeakError::BuybackSwapMismatch
+        );
+        require!(
+            received > 0 && received >= self.min_out(oracle_out),
+            GhostSpeakError::BuybackSlippageExceeded
+        );
+
+        // Only what was actually spent counts against the cap
+        self.epoch_spent -= reserved - spent;
+        self.total_usdc_spent = self.total_usdc_spent.saturating_add(spent);
+        match self.params.disposition {
+            BuybackDisposition::Burn => {
+                self.total_ghost_burned = self.total_ghost_burned.saturating_add(received)
+            }
+            BuybackDisposition::Restake => {
+                self.total_ghost_restaked = self.total_ghost_restaked.saturating_add(received)
+            }
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct BuybackConfigUpdatedEvent {
+    pub multisig: Pubkey,
+    pub operator: Pubkey,
+    pub swap_program: Pubkey,
+    pub max_slippage_bps: u16,
+    pub epoch_duration: i64,
+    pub epoch_cap: u64,
+    pub disposition: BuybackDisposition,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BuybackExecutedEvent {
+    pub operator: Pubkey,
+    pub usdc_spent: u64,
+    pub ghost_received: u64,
+    /// GHOST the USDC spent was worth at the oracle prices
+    pub oracle_out: u64,
+    pub disposition: BuybackDisposition,
+    pub epoch_spent: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(disposition: BuybackDisposition) -> BuybackConfig {
+        BuybackConfig {
+            multisig: Pubkey::new_unique(),
+            params: BuybackParams {
+                operator: Pubkey::new_unique(),
+                swap_program: Pubkey::new_unique(),
+                max_slippage_bps: 100,
+                epoch_duration: 1_000,
+                epoch_cap: 500,
+                disposition,
+            },
+            usdc_mint: Pubkey::new_unique(),
+            ghost_mint: Pubkey::new_unique(),
+            usdc_vault: Pubkey::new_unique(),
+            ghost_vault: Pubkey::new_unique(),
+            epoch_start: 0,
+            epoch_spent: 0,
+            total_usdc_spent: 0,
+            total_ghost_burned: 0,
+            total_ghost_restaked: 0,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_params_validation() {
+        let mut params = config(BuybackDisposition::Burn).params;
+        params.validate().unwrap();
+        params.max_slippage_bps = MAX_BUYBACK_SLIPPAGE_BPS + 1;
+        assert!(params.validate().is_err());
+        params.max_slippage_bps = 0;
+        params.epoch_cap = 0;
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn test_epoch_cap() {
+        let mut buyback = config(BuybackDisposition::Burn);
+        buyback.reserve_epoch_spend(300, 10).unwrap();
+        assert!(buyback.reserve_epoch_spend(201, 20).is_err());
+        buyback.reserve_epoch_spend(200, 999).unwrap();
+
+        // A new epoch starts on the epoch boundary, not at the first spend
+        buyback.reserve_epoch_spend(500, 2_500).unwrap();
+        assert_eq!(buyback.epoch_start, 2_000);
+        assert!(buyback.reserve_epoch_spend(1, 2_999).is_err());
+    }
+
+    #[test]
+    fn test_record_swap() {
+        let mut buyback = config(BuybackDisposition::Restake);
+        assert_eq!(buyback.min_out(10_000), 9_900);
+
+        buyback.reserve_epoch_spend(400, 1).unwrap();
+        assert!(buyback.record_swap(400, 401, 10_000, 10_000, 2).is_err());
+        assert!(buyback.record_swap(400, 400, 9_899, 10_000, 2).is_err());
+        // Unspent USDC is released back to the epoch
+        buyback.record_swap(400, 300, 9_900, 10_000, 2).unwrap();
+        assert_eq!(buyback.epoch_spent, 300);
+        assert_eq!(buyback.total_usdc_spent, 300);
+        assert_eq!(buyback.total_ghost_restaked, 9_900);
+        assert_eq!(buyback.total_ghost_burned, 0);
+    }
+}
@@ -0,0 +1,166 @@
+/*!
+ * Config History State
+ *
+ * Append-only chain of small PDAs recording every change to protocol
+ * parameters, so integrators can reconstruct the parameter timeline.
+ *
+ * Each entry stores hashes of the old and new serialized values rather than
+ * the values themselves, keeping entries fixed-size. Full values can be
+ * recovered from the emitted events or transaction history and checked
+ * against the stored hashes.
+ */
+
+use anchor_lang::prelude::*;
+use sha3::{Digest, Keccak256};
+
+/// Seed for the singleton history head PDA
+pub const CONFIG_HISTORY_HEAD_SEED: &[u8] = b"config_history_head";
+
+/// Seed for individual history entries: [seed, index.to_le_bytes()]
+pub const CONFIG_HISTORY_ENTRY_SEED: &[u8] = b"config_history";
+
+/// Which parameter set a history entry describes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigKind {
+    /// Fee rates, fee toggle and treasury wallets in ProtocolConfig
+    ProtocolFees,
+    /// Reputation decay parameters in ProtocolConfig
+    ReputationParams,
+    /// Staking parameters and tier thresholds in StakingConfig
+    StakingConfig,
+    /// Crossmint mirroring attestor in ProtocolConfig
+    CrossChainAttestor,
+    /// Per-action staking tier gates in ProtocolConfig
+    TierGates,
+}
+
+/// Head of the config history chain
+///
+/// Seeds: ["config_history_head"]
+#[account]
+pub struct ConfigHistoryHead {
+    /// Number of entries written (also the index of the next entry)
+    pub entry_count: u64,
+    /// Most recently written entry (default if none)
+    pub latest_entry: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ConfigHistoryHead {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // entry_count
+        32 + // latest_entry
+        1; // bump
+}
+
+/// A single parameter change
+///
+/// Seeds: ["config_history", index.to_le_bytes()]
+#[account]
+pub struct ConfigHistoryEntry {
+    /// Position in the chain (0-based)
+    pub index: u64,
+    /// Parameter set that changed
+    pub kind: ConfigKind,
+    /// Config account that was modified
+    pub config_account: Pubkey,
+    /// Keccak256 of the serialized value before the change
+    pub old_value_hash: [u8; 32],
+    /// Keccak256 of the serialized value after the change
+    pub new_value_hash: [u8; 32],
+    /// Signer who made the change
+    pub proposer: Pubkey,
+    /// Slot the change landed in
+    pub slot: u64,
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+    /// Previous entry in the chain (default for the first entry)
+    pub prev_entry: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ConfigHistoryEntry {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // index
+        1 + // kind
+        32 + // config_account
+        32 + // old_value_hash
+        32 + // new_value_hash
+        32 + // proposer
+        8 + // slot
+        8 + // timestamp
+        32 + // prev_entry
+        1; // bump
+}
+
+/// Hash a serialized config value for a history entry
+pub fn hash_config_value(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Append an entry to the history chain and advance the head
+///
+/// `entry` must be the freshly initialized PDA at index `head.entry_count`.
+#[allow(clippy::too_many_arguments)]
+pub fn append_config_history(
+    head: &mut Account<ConfigHistoryHead>,
+    entry: &mut Account<ConfigHistoryEntry>,
+    head_bump: u8,
+    entry_bump: u8,
+    kind: ConfigKind,
+    config_account: Pubkey,
+    old_value: &[u8],
+    new_value: &[u8],
+    proposer: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    head.bump = head_bump;
+
+    entry.index = head.entry_count;
+    entry.kind = kind;
+    entry.config_account = config_account;
+    entry.old_value_hash = hash_config_value(old_value);
+    entry.new_value_hash = hash_config_value(new_value);
+    entry.proposer = proposer;
+    entry.slot = clock.slot;
+    entry.timestamp = clock.unix_timestamp;
+    entry.prev_entry = head.latest_entry;
+    entry.bump = entry_bump;
+
+    head.entry_count = head
+        .entry_count
+        .checked_add(1)
+        .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+    head.latest_entry = entry.key();
+
+    emit!(ConfigHistoryRecordedEvent {
+        entry: entry.key(),
+        index: entry.index,
+        kind,
+        config_account,
+        old_value_hash: entry.old_value_hash,
+        new_value_hash: entry.new_value_hash,
+        proposer,
+        slot: entry.slot,
+    });
+
+    Ok(())
+}
+
+/// Event emitted for every config history entry
+#[event]
+pub struct ConfigHistoryRecordedEvent {
+    pub entry: Pubkey,
+    pub index: u64,
+    pub kind: ConfigKind,
+    pub config_account: Pubkey,
+    pub old_value_hash: [u8; 32],
+    pub new_value_hash: [u8; 32],
+    pub proposer: Pubkey,
+    pub slot: u64,
+}
@@ -7,16 +7,62 @@
 
 use anchor_lang::prelude::*;
 
+use crate::GhostSpeakError;
+
 // PDA Seeds
 pub const CREDENTIAL_TYPE_SEED: &[u8] = b"credential_type";
 pub const CREDENTIAL_TEMPLATE_SEED: &[u8] = b"credential_template";
+pub const CREDENTIAL_SCHEMA_SEED: &[u8] = b"credential_schema";
 pub const CREDENTIAL_SEED: &[u8] = b"credential";
+pub const CREDENTIAL_VERIFICATION_SEED: &[u8] = b"credential_verification";
+pub const CREDENTIAL_STATUS_LIST_SEED: &[u8] = b"credential_status_list";
+pub const ISSUER_REGISTRY_SEED: &[u8] = b"issuer_registry";
+pub const CUSTOM_CREDENTIAL_KIND_SEED: &[u8] = b"custom_credential_kind";
+pub const ISSUANCE_DELEGATION_SEED: &[u8] = b"issuance_delegation";
+pub const SUBJECT_CREDENTIAL_INDEX_SEED: &[u8] = b"subject_credential_index";
+pub const SUBJECT_CREDENTIAL_PAGE_SEED: &[u8] = b"subject_credential_page";
+pub const CREDENTIAL_PRESENTATION_SEED: &[u8] = b"credential_presentation";
+pub const HOLDER_POLICY_SEED: &[u8] = b"holder_policy";
+pub const CREDENTIAL_MINT_SEED: &[u8] = b"credential_mint";
+pub const CROSSCHAIN_RECORD_SEED: &[u8] = b"crosschain_attestation";
+pub const CREDENTIAL_DISPUTE_CONFIG_SEED: &[u8] = b"credential_dispute_config";
+pub const CREDENTIAL_FLAG_SEED: &[u8] = b"credential_flag";
 
 // Maximum lengths
 pub const MAX_CREDENTIAL_NAME: usize = 64;
 pub const MAX_SCHEMA_URI: usize = 128;
 pub const MAX_CREDENTIAL_ID: usize = 64; // urn:uuid:xxx format
 pub const MAX_SUBJECT_DATA: usize = 512; // JSON subject data
+pub const MAX_PRESENTATION_PURPOSE: usize = 64; // e.g. "kyc:onboarding"
+pub const MAX_MIGRATION_NOTES: usize = 256;
+pub const MAX_FLAG_EVIDENCE_URI: usize = 128;
+
+// Crossmint mirroring
+pub const CROSSCHAIN_ATTESTATION_DOMAIN: &[u8] = b"ghostspeak:crosschain:v1";
+pub const MAX_CROSSCHAIN_ATTEMPTS: u8 = 5;
+pub const CROSSCHAIN_RETRY_BASE_SECONDS: i64 = 5 * 60; // doubles per failed attempt
+pub const CROSSCHAIN_RETRY_MAX_SECONDS: i64 = 24 * 60 * 60;
+
+// Cross-chain attestation records (EVM / ERC-8004 mirrors)
+pub const CROSSCHAIN_RECORD_DOMAIN: &[u8] = b"ghostspeak:crosschain-record:v1";
+
+// Template forking
+pub const MAX_TEMPLATE_ROYALTY_LAMPORTS: u64 = 10_000_000; // 0.01 SOL per issuance
+
+// Credential tokens
+pub const CREDENTIAL_TOKEN_SYMBOL: &str = "GSVC";
+
+// Credential closure
+pub const CREDENTIAL_CLOSE_GRACE_SECONDS: i64 = 30 * 24 * 60 * 60; // before holders may close
+
+// Co-issued credentials
+pub const MAX_CO_ISSUERS: usize = 4;
+
+// Subject credential index
+pub const SUBJECT_INDEX_PAGE_SIZE: usize = 32; // credentials per page
+
+// Revocation status lists
+pub const STATUS_LIST_CAPACITY: u32 = 16_384; // credentials per type (2 KB bitfield)
 
 /// Credential type enum for built-in GhostSpeak credentials
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Copy)]
@@ -29,20 +75,49 @@ pub enum CredentialKind {
     JobCompletion,
     /// Delegated signer authorization - issued after CLI linking
     DelegatedSigner,
-    /// Custom credential type
-    Custom,
+    /// Custom credential category; IDs other than 0 must be registered
+    Custom(u16),
 }
 
 impl Default for CredentialKind {
     fn default() -> Self {
-        CredentialKind::Custom
+        CredentialKind::Custom(0)
     }
 }
 
+impl CredentialKind {
+    /// Serialized size (variant tag + largest payload)
+    pub const LEN: usize = 1 + 2;
+
+    /// Custom kind ID that must be backed by a `CustomCredentialKind` entry
+    pub fn registered_custom_id(&self) -> Option<u16> {
+        match self {
+            CredentialKind::Custom(id) if *id != 0 => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Kinds the protocol vouches for, which the issuer registry can restrict
+    pub fn is_protocol_reserved(&self) -> bool {
+        matches!(self, CredentialKind::AgentIdentity | CredentialKind::ReputationScore)
+    }
+}
+
+/// How much weight resolvers should give a registered issuer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Copy)]
+pub enum IssuerTrustTier {
+    /// Community issuer, vetted by governance
+    Community,
+    /// Issuer with verified off-chain identity
+    Verified,
+    /// GhostSpeak protocol services
+    Protocol,
+}
+
 /// Credential status for lifecycle tracking
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Copy)]
 pub enum CredentialStatus {
-    /// Credential is pending (just created, awaiting signature)
+    /// Credential is pending (awaiting signature or holder acceptance)
     Pending,
     /// Credential is active and valid
     Active,
@@ -59,16 +134,22 @@ impl Default for CredentialStatus {
 }
 
 /// Cross-chain sync status for Crossmint integration
+///
+/// NotSynced → SyncPending → SyncSubmitted → SyncedToCrossmint, with
+/// SyncFailed reachable from either in-flight state. Every transition after
+/// SyncPending requires an attestor signature.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Copy)]
 pub enum CrossChainStatus {
     /// Not synced to EVM
     NotSynced,
-    /// Sync pending
+    /// Sync requested by the issuer, awaiting submission to Crossmint
     SyncPending,
     /// Successfully synced to Crossmint (EVM)
     SyncedToCrossmint,
-    /// Sync failed
+    /// Sync failed (see `cross_chain_failure`)
     SyncFailed,
+    /// Accepted by Crossmint, awaiting on-chain confirmation
+    SyncSubmitted,
 }
 
 impl Default for CrossChainStatus {
@@ -77,6 +158,38 @@ impl Default for CrossChainStatus {
     }
 }
 
+/// Why a Crossmint sync attempt failed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, Copy)]
+pub enum CrossChainFailureReason {
+    /// Crossmint rejected the credential (not retryable)
+    Rejected,
+    /// Template is not mapped to a Crossmint template (not retryable)
+    TemplateNotMapped,
+    /// No confirmation before the attestor gave up
+    Timeout,
+    /// Crossmint rate limited the request
+    RateLimited,
+    /// Crossmint or EVM-side error
+    ProviderError,
+}
+
+impl CrossChainFailureReason {
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, Self::Rejected | Self::TemplateNotMapped)
+    }
+}
+
+/// Attested cross-chain status change
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum CrossChainUpdate {
+    /// Crossmint accepted the credential under this ID
+    Submitted { crossmint_credential_id: String },
+    /// The credential is live on the EVM side
+    Confirmed { crossmint_credential_id: String },
+    /// The attempt failed
+    Failed { reason: CrossChainFailureReason },
+}
+
 /// Credential Type - defines the schema for a category of credentials
 ///
 /// Example types: AgentIdentity, ReputationScore, JobCompletion
@@ -88,7 +201,7 @@ pub struct CredentialType {
     pub name: String,
     /// The kind of credential (built-in or custom)
     pub kind: CredentialKind,
-    /// URI to the JSON-LD schema (IPFS or HTTPS)
+    /// URI to the current JSON-LD schema (IPFS or HTTPS)
     pub schema_uri: String,
     /// Human-readable description
     pub description: String,
@@ -98,6 +211,8 @@ pub struct CredentialType {
     pub total_issued: u64,
     /// Creation timestamp
     pub created_at: i64,
+    /// Version of `schema_uri` in the schema registry
+    pub schema_version: u16,
     /// PDA bump
     pub bump: u8,
 }
@@ -106,12 +221,55 @@ impl CredentialType {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
         4 + MAX_CREDENTIAL_NAME + // name
-        1 + // kind enum
+        CredentialKind::LEN + // kind enum
         4 + MAX_SCHEMA_URI + // schema_uri
         4 + 256 + // description (max 256 chars)
         1 + // is_active
         8 + // total_issued
         8 + // created_at
+        2 + // schema_version
+        1; // bump
+}
+
+/// Credential Schema - one registered version of a credential type's schema
+///
+/// Seeds: ["credential_schema", credential_type, version (u16 LE)]
+///
+/// Version 1 is registered with the type; later versions are published by
+/// the type authority and become the type's current schema. Credentials keep
+/// the version they were issued under, so older versions stay resolvable
+/// after they are deprecated.
+#[account]
+pub struct CredentialSchema {
+    /// Credential type the schema belongs to
+    pub credential_type: Pubkey,
+    /// Schema version (starts at 1)
+    pub version: u16,
+    /// URI to the JSON-LD schema (IPFS or HTTPS)
+    pub schema_uri: String,
+    /// How to migrate credentials from the previous version
+    pub migration_notes: String,
+    /// Whether issuers should stop relying on this version
+    pub deprecated: bool,
+    /// Publication timestamp
+    pub published_at: i64,
+    /// Deprecation timestamp
+    pub deprecated_at: Option<i64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CredentialSchema {
+    pub const INITIAL_VERSION: u16 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential_type
+        2 + // version
+        4 + MAX_SCHEMA_URI + // schema_uri
+        4 + MAX_MIGRATION_NOTES + // migration_notes
+        1 + // deprecated
+        8 + // published_at
+        1 + 8 + // deprecated_at Option<i64>
         1; // bump
 }
 
@@ -136,6 +294,18 @@ pub struct CredentialTemplate {
     pub created_at: i64,
     /// Corresponding Crossmint template ID (for EVM sync)
     pub crossmint_template_id: Option<String>,
+    /// Template this one was forked from (None for originals)
+    pub forked_from: Option<Pubkey>,
+    /// Issuer of the root template in the fork chain (None for originals)
+    pub original_author: Option<Pubkey>,
+    /// Lamports paid to the original author on every issuance (forks only)
+    pub royalty_lamports: u64,
+    /// Number of direct forks of this template
+    pub fork_count: u32,
+    /// Issuers who must also approve each credential issued from this template
+    pub co_issuers: Vec<Pubkey>,
+    /// Fee the requester pays per issuance, split with the protocol treasury
+    pub issuance_fee_lamports: u64,
     /// PDA bump
     pub bump: u8,
 }
@@ -150,7 +320,28 @@ impl CredentialTemplate {
         8 + // total_issued
         8 + // created_at
         1 + 4 + 64 + // crossmint_template_id Option<String>
+        1 + 32 + // forked_from Option<Pubkey>
+        1 + 32 + // original_author Option<Pubkey>
+        8 + // royalty_lamports
+        4 + // fork_count
+        4 + (MAX_CO_ISSUERS * 32) + // co_issuers
+        8 + // issuance_fee_lamports
         1; // bump
+
+    /// Author credited by forks of this template (the root of its fork chain)
+    pub fn root_author(&self) -> Pubkey {
+        self.original_author.unwrap_or(self.issuer)
+    }
+
+    /// Royalty owed to the original author for one issuance, with its recipient
+    pub fn royalty_due(&self) -> Option<(Pubkey, u64)> {
+        match self.original_author {
+            Some(author) if self.royalty_lamports > 0 && author != self.issuer => {
+                Some((author, self.royalty_lamports))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Credential - an issued verifiable credential
@@ -189,6 +380,28 @@ pub struct Credential {
     pub crossmint_credential_id: Option<String>,
     /// Source data reference (e.g., agent PDA, escrow PDA)
     pub source_account: Option<Pubkey>,
+    /// Sync attempts started (each SyncPending counts as one)
+    pub cross_chain_attempts: u8,
+    /// Earliest time a failed sync may be retried
+    pub cross_chain_retry_at: i64,
+    /// Reason for the last failed attempt
+    pub cross_chain_failure: Option<CrossChainFailureReason>,
+    /// Status list of the credential's type
+    pub status_list: Pubkey,
+    /// Bit assigned to this credential in `status_list`
+    pub status_index: u32,
+    /// Schema version of the credential type at issuance
+    pub schema_version: u16,
+    /// Co-issuers whose approval is still missing
+    pub pending_co_issuers: Vec<Pubkey>,
+    /// Whether the holder still has to accept the credential
+    pub awaiting_acceptance: bool,
+    /// Subject before the latest re-binding (None if never re-bound)
+    pub previous_subject: Option<Pubkey>,
+    /// Time of the latest re-binding
+    pub rebound_at: Option<i64>,
+    /// Subject index page listing this credential
+    pub subject_index_page: Pubkey,
     /// PDA bump
     pub bump: u8,
 }
@@ -209,6 +422,17 @@ impl Credential {
         1 + // cross_chain_status enum
         1 + 4 + 64 + // crossmint_credential_id Option<String>
         1 + 32 + // source_account Option<Pubkey>
+        1 + // cross_chain_attempts
+        8 + // cross_chain_retry_at
+        1 + 1 + // cross_chain_failure Option<enum>
+        32 + // status_list
+        4 + // status_index
+        2 + // schema_version
+        4 + (MAX_CO_ISSUERS * 32) + // pending_co_issuers
+        1 + // awaiting_acceptance
+        1 + 32 + // previous_subject Option<Pubkey>
+        1 + 8 + // rebound_at Option<i64>
+        32 + // subject_index_page
         1; // bump
 
     /// Check if credential is currently valid
@@ -226,19 +450,1013 @@ impl Credential {
         true
     }
 
+    /// Activate a pending credential once every co-issuer approved and the
+    /// holder accepted it (where required)
+    pub fn activate_if_ready(&mut self) {
+        if self.status == CredentialStatus::Pending
+            && self.pending_co_issuers.is_empty()
+            && !self.awaiting_acceptance
+        {
+            self.status = CredentialStatus::Active;
+        }
+    }
+
+    /// Record `co_issuer`'s approval
+    pub fn record_co_issuer_approval(&mut self, co_issuer: &Pubkey) -> Result<()> {
+        let position = self
+            .pending_co_issuers
+            .iter()
+            .position(|k| k == co_issuer)
+            .ok_or(GhostSpeakError::NotPendingCoIssuer)?;
+        self.pending_co_issuers.remove(position);
+        self.activate_if_ready();
+        Ok(())
+    }
+
+    /// Mark an active or pending credential expired once its expiry has passed
+    pub fn expire(&mut self, now: i64) -> Result<i64> {
+        require!(
+            matches!(self.status, CredentialStatus::Active | CredentialStatus::Pending),
+            GhostSpeakError::InvalidState
+        );
+        let expires_at = self.expires_at.ok_or(GhostSpeakError::CredentialNotExpired)?;
+        require!(now >= expires_at, GhostSpeakError::CredentialNotExpired);
+        self.status = CredentialStatus::Expired;
+        Ok(expires_at)
+    }
+
+    /// When the credential stopped being valid, if it is revoked or expired
+    pub fn ended_at(&self) -> Option<i64> {
+        match self.status {
+            CredentialStatus::Revoked => self.revoked_at,
+            CredentialStatus::Expired => self.expires_at,
+            _ => None,
+        }
+    }
+
+    /// Check that the credential may be closed now
+    ///
+    /// Issuers can close any revoked or expired credential; holders only
+    /// once `CREDENTIAL_CLOSE_GRACE_SECONDS` have passed since it ended.
+    pub fn require_closable(&self, by_issuer: bool, now: i64) -> Result<()> {
+        let ended_at = self
+            .ended_at()
+            .ok_or(GhostSpeakError::CredentialNotClosable)?;
+        if !by_issuer {
+            require!(
+                now >= ended_at.saturating_add(CREDENTIAL_CLOSE_GRACE_SECONDS),
+                GhostSpeakError::CredentialCloseGracePeriodActive
+            );
+        }
+        Ok(())
+    }
+
+    /// Move the credential to `new_subject`, keeping its issuance record
+    ///
+    /// The account address stays derived from the original subject.
+    pub fn rebind(&mut self, new_subject: Pubkey, now: i64) -> Result<()> {
+        require!(
+            self.status == CredentialStatus::Active,
+            GhostSpeakError::InvalidState
+        );
+        require!(new_subject != self.subject, GhostSpeakError::InvalidInput);
+        self.previous_subject = Some(self.subject);
+        self.subject = new_subject;
+        self.rebound_at = Some(now);
+        Ok(())
+    }
+
     /// Mark credential as revoked
     pub fn revoke(&mut self, timestamp: i64) {
         self.status = CredentialStatus::Revoked;
         self.revoked_at = Some(timestamp);
     }
 
-    /// Update cross-chain sync status
-    pub fn set_crossmint_synced(&mut self, crossmint_id: String) {
-        self.cross_chain_status = CrossChainStatus::SyncedToCrossmint;
-        self.crossmint_credential_id = Some(crossmint_id);
+    /// Backoff before retrying after `attempts` failed attempts
+    pub fn crosschain_retry_delay(attempts: u8) -> i64 {
+        let doublings = attempts.saturating_sub(1).min(16) as u32;
+        (CROSSCHAIN_RETRY_BASE_SECONDS << doublings).min(CROSSCHAIN_RETRY_MAX_SECONDS)
+    }
+
+    /// Start a sync attempt (first sync, or a retry once the backoff has elapsed)
+    pub fn request_crosschain_sync(&mut self, now: i64) -> Result<()> {
+        match self.cross_chain_status {
+            CrossChainStatus::NotSynced => {}
+            CrossChainStatus::SyncFailed => {
+                require!(
+                    self.cross_chain_failure.is_some_and(|r| r.is_retryable())
+                        && self.cross_chain_attempts < MAX_CROSSCHAIN_ATTEMPTS,
+                    GhostSpeakError::CrossChainRetriesExhausted
+                );
+                require!(
+                    now >= self.cross_chain_retry_at,
+                    GhostSpeakError::CrossChainRetryNotDue
+                );
+            }
+            _ => return err!(GhostSpeakError::InvalidCrossChainTransition),
+        }
+
+        self.cross_chain_status = CrossChainStatus::SyncPending;
+        self.cross_chain_failure = None;
+        self.cross_chain_attempts = self.cross_chain_attempts.saturating_add(1);
+        Ok(())
+    }
+
+    /// Apply an attested status change to the in-flight attempt
+    pub fn apply_crosschain_update(&mut self, update: CrossChainUpdate, now: i64) -> Result<()> {
+        let status = self.cross_chain_status;
+        require!(
+            matches!(
+                status,
+                CrossChainStatus::SyncPending | CrossChainStatus::SyncSubmitted
+            ),
+            GhostSpeakError::InvalidCrossChainTransition
+        );
+
+        match update {
+            CrossChainUpdate::Submitted {
+                crossmint_credential_id,
+            } => {
+                require!(
+                    status == CrossChainStatus::SyncPending,
+                    GhostSpeakError::InvalidCrossChainTransition
+                );
+                require!(
+                    !crossmint_credential_id.is_empty()
+                        && crossmint_credential_id.len() <= MAX_CREDENTIAL_ID,
+                    GhostSpeakError::InvalidInput
+                );
+                self.cross_chain_status = CrossChainStatus::SyncSubmitted;
+                self.crossmint_credential_id = Some(crossmint_credential_id);
+            }
+            CrossChainUpdate::Confirmed {
+                crossmint_credential_id,
+            } => {
+                // A confirmation may arrive without a separate submission
+                if status == CrossChainStatus::SyncSubmitted {
+                    require!(
+                        self.crossmint_credential_id.as_ref() == Some(&crossmint_credential_id),
+                        GhostSpeakError::InvalidCrossChainTransition
+                    );
+                }
+                require!(
+                    !crossmint_credential_id.is_empty()
+                        && crossmint_credential_id.len() <= MAX_CREDENTIAL_ID,
+                    GhostSpeakError::InvalidInput
+                );
+                self.cross_chain_status = CrossChainStatus::SyncedToCrossmint;
+                self.crossmint_credential_id = Some(crossmint_credential_id);
+            }
+            CrossChainUpdate::Failed { reason } => {
+                self.cross_chain_status = CrossChainStatus::SyncFailed;
+                self.cross_chain_failure = Some(reason);
+                self.cross_chain_retry_at =
+                    now.saturating_add(Self::crosschain_retry_delay(self.cross_chain_attempts));
+            }
+        }
+        Ok(())
+    }
+
+    /// Bytes the attestor signs for `update`
+    ///
+    /// domain || credential || attempt || borsh(update). Binding the attempt
+    /// number stops an attestation from one attempt being replayed in a later one.
+    pub fn crosschain_attestation_message(
+        &self,
+        credential: &Pubkey,
+        update: &CrossChainUpdate,
+    ) -> Result<Vec<u8>> {
+        let mut message = CROSSCHAIN_ATTESTATION_DOMAIN.to_vec();
+        message.extend_from_slice(credential.as_ref());
+        message.push(self.cross_chain_attempts);
+        message.extend_from_slice(&update.try_to_vec()?);
+        Ok(message)
+    }
+}
+
+/// Cross-Chain Attestation - provenance of a credential mirrored to an EVM chain
+///
+/// Seeds: ["crosschain_attestation", credential, chain_id (u64 LE)]
+///
+/// One record per target chain, e.g. a Base or Ethereum ERC-8004 registry.
+/// The configured cross-chain attestor signs `message`, and its signature is
+/// stored so anyone can re-verify where the mirror came from. Each new
+/// attestation for the same chain bumps `revision`.
+#[account]
+pub struct CrossChainAttestation {
+    /// Credential that was mirrored
+    pub credential: Pubkey,
+    /// EVM chain ID (e.g. 1 for Ethereum, 8453 for Base)
+    pub chain_id: u64,
+    /// Registry contract holding the mirror
+    pub registry: [u8; 20],
+    /// Transaction that wrote the mirror
+    pub attestation_tx_hash: [u8; 32],
+    /// Credential subject data hash the mirror commits to
+    pub subject_data_hash: [u8; 32],
+    /// Attestor key that signed the record
+    pub attestor: Pubkey,
+    /// Attestor's Ed25519 signature over `message`
+    pub attestor_signature: [u8; 64],
+    /// Number of attestations recorded for this chain
+    pub revision: u32,
+    /// Latest attestation timestamp
+    pub attested_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CrossChainAttestation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential
+        8 + // chain_id
+        20 + // registry
+        32 + // attestation_tx_hash
+        32 + // subject_data_hash
+        32 + // attestor
+        64 + // attestor_signature
+        4 + // revision
+        8 + // attested_at
+        1; // bump
+
+    /// Bytes the attestor signs
+    ///
+    /// domain || credential || chain_id || revision || registry || tx_hash ||
+    /// subject_data_hash, integers little-endian. Binding the revision stops
+    /// an older attestation from being replayed over a newer one.
+    pub fn message(
+        credential: &Pubkey,
+        chain_id: u64,
+        revision: u32,
+        registry: &[u8; 20],
+        attestation_tx_hash: &[u8; 32],
+        subject_data_hash: &[u8; 32],
+    ) -> Vec<u8> {
+        let mut message = CROSSCHAIN_RECORD_DOMAIN.to_vec();
+        message.extend_from_slice(credential.as_ref());
+        message.extend_from_slice(&chain_id.to_le_bytes());
+        message.extend_from_slice(&revision.to_le_bytes());
+        message.extend_from_slice(registry);
+        message.extend_from_slice(attestation_tx_hash);
+        message.extend_from_slice(subject_data_hash);
+        message
+    }
+}
+
+#[event]
+pub struct CrossChainAttestationRecordedEvent {
+    pub credential: Pubkey,
+    pub chain_id: u64,
+    pub registry: [u8; 20],
+    pub attestation_tx_hash: [u8; 32],
+    pub revision: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted on every Crossmint sync state change
+#[event]
+pub struct CrossChainStatusChangedEvent {
+    pub credential: Pubkey,
+    pub status: CrossChainStatus,
+    pub attempt: u8,
+    pub crossmint_credential_id: Option<String>,
+    pub failure: Option<CrossChainFailureReason>,
+    /// Earliest retry time, set when the attempt failed
+    pub retry_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+/// Credential Status List - revocation bitfield for one credential type
+///
+/// StatusList2021-style: each credential issued under the type is assigned
+/// the next index, and revoking it sets that bit, so resolvers can check any
+/// number of credentials of a type with one account read. Bits are ordered
+/// most significant first within each byte, as in StatusList2021.
+#[account]
+pub struct CredentialStatusList {
+    /// Credential type this list covers
+    pub credential_type: Pubkey,
+    /// Next unassigned index
+    pub next_index: u32,
+    /// One bit per assigned index, set when revoked
+    pub revoked: Vec<u8>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CredentialStatusList {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential_type
+        4 + // next_index
+        4 + (STATUS_LIST_CAPACITY as usize / 8) + // revoked bitfield
+        1; // bump
+
+    /// Set up a freshly created list (no-op once initialized)
+    pub fn initialize_if_needed(&mut self, credential_type: Pubkey, bump: u8) {
+        if self.credential_type == Pubkey::default() {
+            self.credential_type = credential_type;
+            self.next_index = 0;
+            self.revoked = vec![0; STATUS_LIST_CAPACITY as usize / 8];
+            self.bump = bump;
+        }
+    }
+
+    /// Assign the next index to a newly issued credential
+    pub fn allocate(&mut self) -> Result<u32> {
+        require!(
+            self.next_index < STATUS_LIST_CAPACITY,
+            GhostSpeakError::StatusListFull
+        );
+        let index = self.next_index;
+        self.next_index += 1;
+        Ok(index)
+    }
+
+    /// Byte offset and bit mask of `index`
+    fn position(index: u32) -> (usize, u8) {
+        ((index / 8) as usize, 0x80 >> (index % 8))
+    }
+
+    /// Mark the credential at `index` as revoked
+    pub fn set_revoked(&mut self, index: u32) -> Result<()> {
+        require!(
+            index < self.next_index,
+            GhostSpeakError::StatusIndexNotAssigned
+        );
+        let (byte, mask) = Self::position(index);
+        self.revoked[byte] |= mask;
+        Ok(())
+    }
+
+    /// Whether the credential at `index` has been revoked
+    pub fn is_revoked(&self, index: u32) -> bool {
+        let (byte, mask) = Self::position(index);
+        self.revoked.get(byte).is_some_and(|b| b & mask != 0)
+    }
+}
+
+/// Subject Credential Index - how many credentials a subject has been issued
+///
+/// Seeds: ["subject_credential_index", subject]
+///
+/// Resolvers read this, then pages `0..page_count()`, to enumerate a
+/// subject's credentials without scanning program accounts. Re-bound
+/// credentials stay listed under the subject they were issued to.
+#[account]
+pub struct SubjectCredentialIndex {
+    /// Subject the index belongs to
+    pub subject: Pubkey,
+    /// Credentials ever appended (revoked ones are removed from their page)
+    pub total_indexed: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SubjectCredentialIndex {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // subject
+        8 + // total_indexed
+        1; // bump
+
+    pub fn initialize_if_needed(&mut self, subject: Pubkey, bump: u8) {
+        if self.subject == Pubkey::default() {
+            self.subject = subject;
+            self.bump = bump;
+        }
+    }
+
+    /// Page the next credential is appended to
+    pub fn open_page(&self) -> u32 {
+        (self.total_indexed / SUBJECT_INDEX_PAGE_SIZE as u64) as u32
+    }
+
+    /// Number of pages created so far
+    pub fn page_count(&self) -> u32 {
+        self.total_indexed.div_ceil(SUBJECT_INDEX_PAGE_SIZE as u64) as u32
+    }
+
+    /// Count one appended credential
+    pub fn record_append(&mut self) -> Result<()> {
+        self.total_indexed = self
+            .total_indexed
+            .checked_add(1)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Subject Credential Page - one page of a subject's credential pubkeys
+///
+/// Seeds: ["subject_credential_page", subject, page]
+///
+/// Appends fill pages in order; revoking (or closing) a credential removes
+/// it from its page, so pages may hold fewer than `SUBJECT_INDEX_PAGE_SIZE`.
+#[account]
+pub struct SubjectCredentialPage {
+    /// Subject the page belongs to
+    pub subject: Pubkey,
+    /// Page number
+    pub page: u32,
+    /// Listed credentials
+    pub credentials: Vec<Pubkey>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SubjectCredentialPage {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // subject
+        4 + // page
+        4 + (SUBJECT_INDEX_PAGE_SIZE * 32) + // credentials
+        1; // bump
+
+    pub fn initialize_if_needed(&mut self, subject: Pubkey, page: u32, bump: u8) {
+        if self.subject == Pubkey::default() {
+            self.subject = subject;
+            self.page = page;
+            self.bump = bump;
+        }
+    }
+
+    /// List a credential on this page
+    pub fn push(&mut self, credential: Pubkey) -> Result<()> {
+        require!(
+            self.credentials.len() < SUBJECT_INDEX_PAGE_SIZE,
+            GhostSpeakError::SubjectIndexPageFull
+        );
+        self.credentials.push(credential);
+        Ok(())
+    }
+
+    /// Remove a credential if listed, keeping the order of the rest
+    pub fn remove(&mut self, credential: &Pubkey) {
+        self.credentials.retain(|c| c != credential);
+    }
+}
+
+/// Credential Presentation - audit record of a holder presenting a credential
+///
+/// Seeds: ["credential_presentation", credential, verifier, nonce]
+///
+/// Records who was shown what and why, without any subject data. When the
+/// holder discloses only some claims, `disclosure_commitment` commits to the
+/// disclosed set so the verifier can later prove what it received.
+#[account]
+pub struct CredentialPresentation {
+    /// Credential that was presented
+    pub credential: Pubkey,
+    /// Holder who presented it (the subject, or the owner of the subject agent)
+    pub holder: Pubkey,
+    /// Verifier it was presented to
+    pub verifier: Pubkey,
+    /// Why the verifier asked for it
+    pub purpose: String,
+    /// Commitment to the selectively disclosed claims, if any
+    pub disclosure_commitment: Option<[u8; 32]>,
+    /// Holder-chosen nonce distinguishing repeat presentations
+    pub nonce: u64,
+    /// Presentation timestamp
+    pub presented_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CredentialPresentation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential
+        32 + // holder
+        32 + // verifier
+        4 + MAX_PRESENTATION_PURPOSE + // purpose
+        1 + 32 + // disclosure_commitment Option<[u8; 32]>
+        8 + // nonce
+        8 + // presented_at
+        1; // bump
+}
+
+/// Holder Policy - a subject's opt-in rules for incoming credentials
+///
+/// Seeds: ["holder_policy", subject]
+///
+/// When `require_acceptance` is set, credentials issued to the subject start
+/// out `Pending` and only become `Active` once the holder accepts them.
+/// Subjects without a policy receive credentials as `Active` directly.
+#[account]
+pub struct HolderPolicy {
+    /// Subject the policy applies to
+    pub subject: Pubkey,
+    /// Hold new credentials as Pending until accepted
+    pub require_acceptance: bool,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HolderPolicy {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // subject
+        1 + // require_acceptance
+        8 + // updated_at
+        1; // bump
+}
+
+/// Issuance Delegation - an operator key allowed to issue for a type owner
+///
+/// Seeds: ["issuance_delegation", credential_type, operator]
+///
+/// The operator signs `issue_credential` for templates owned by the
+/// delegator; credentials still name the delegator as issuer. Creator shares
+/// of issuance fees collect on this account and return to the delegator
+/// when the delegation is revoked.
+#[account]
+pub struct IssuanceDelegation {
+    /// Credential type the delegation covers
+    pub credential_type: Pubkey,
+    /// Type authority that granted the delegation
+    pub delegator: Pubkey,
+    /// Operator key allowed to issue
+    pub operator: Pubkey,
+    /// Maximum credentials the operator may issue
+    pub max_issuances: u64,
+    /// Credentials issued so far
+    pub issued: u64,
+    /// When the delegation lapses (None = until revoked)
+    pub expires_at: Option<i64>,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl IssuanceDelegation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential_type
+        32 + // delegator
+        32 + // operator
+        8 + // max_issuances
+        8 + // issued
+        1 + 8 + // expires_at
+        8 + // created_at
+        1; // bump
+
+    /// Use one issuance, enforcing the expiry and cap
+    pub fn consume(&mut self, now: i64) -> Result<u64> {
+        if let Some(expires_at) = self.expires_at {
+            require!(now < expires_at, GhostSpeakError::DelegationExpired);
+        }
+        require!(
+            self.issued < self.max_issuances,
+            GhostSpeakError::DelegationCapReached
+        );
+        self.issued += 1;
+        Ok(self.max_issuances - self.issued)
+    }
+}
+
+/// Custom Credential Kind - registry entry naming a `CredentialKind::Custom` ID
+///
+/// Seeds: ["custom_credential_kind", kind_id]
+///
+/// First come, first served. Only the owner can create credential types of
+/// the kind, so an ecosystem controls its own category.
+#[account]
+pub struct CustomCredentialKind {
+    /// ID used in `CredentialKind::Custom`
+    pub kind_id: u16,
+    /// Human-readable category name
+    pub name: String,
+    /// Ecosystem that defines the kind
+    pub owner: Pubkey,
+    /// Registration timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CustomCredentialKind {
+    pub const LEN: usize = 8 + // discriminator
+        2 + // kind_id
+        4 + MAX_CREDENTIAL_NAME + // name
+        32 + // owner
+        8 + // created_at
+        1; // bump
+
+    /// Check that `authority` may create credential types of `kind`
+    pub fn authorize(&self, kind: CredentialKind, authority: &Pubkey) -> Result<()> {
+        require!(
+            kind.registered_custom_id() == Some(self.kind_id),
+            GhostSpeakError::CustomKindNotRegistered
+        );
+        require!(self.owner == *authority, GhostSpeakError::UnauthorizedAccess);
+        Ok(())
+    }
+}
+
+/// Approved issuer entry in the issuer registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RegisteredIssuer {
+    /// Issuer key (the `issuer` signer of `issue_credential`)
+    pub issuer: Pubkey,
+    /// Credential kind the issuer is approved for
+    pub kind: CredentialKind,
+    /// Trust tier
+    pub tier: IssuerTrustTier,
+    /// Whether the approval is currently in effect
+    pub is_active: bool,
+}
+
+impl RegisteredIssuer {
+    pub const LEN: usize = 32 + // issuer
+        CredentialKind::LEN + // kind
+        1 + // tier
+        1; // is_active
+}
+
+/// Issuer Registry - trusted issuers per credential kind
+///
+/// Seeds: ["issuer_registry"]
+///
+/// Managed by a multisig: every change requires `threshold` of the
+/// multisig's signers to sign the transaction. While `enforce_reserved_kinds`
+/// is set, protocol-reserved kinds can only be issued by active entries.
+#[account]
+pub struct IssuerRegistry {
+    /// Multisig account that governs the registry
+    pub multisig: Pubkey,
+    /// Restrict protocol-reserved kinds to registered issuers
+    pub enforce_reserved_kinds: bool,
+    /// Approved issuers, one entry per (issuer, kind)
+    pub issuers: Vec<RegisteredIssuer>,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl IssuerRegistry {
+    pub const MAX_ISSUERS: usize = 32;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        1 + // enforce_reserved_kinds
+        4 + (Self::MAX_ISSUERS * RegisteredIssuer::LEN) + // issuers
+        8 + // updated_at
+        1; // bump
+
+    /// Get the active entry for `issuer` and `kind`
+    pub fn get_active_issuer(
+        &self,
+        issuer: &Pubkey,
+        kind: CredentialKind,
+    ) -> Option<&RegisteredIssuer> {
+        self.issuers
+            .iter()
+            .find(|i| i.issuer == *issuer && i.kind == kind && i.is_active)
+    }
+
+    /// Check that `issuer` may issue credentials of `kind`
+    pub fn authorize_issuance(&self, issuer: &Pubkey, kind: CredentialKind) -> Result<()> {
+        if self.enforce_reserved_kinds && kind.is_protocol_reserved() {
+            require!(
+                self.get_active_issuer(issuer, kind).is_some(),
+                GhostSpeakError::IssuerNotRegistered
+            );
+        }
+        Ok(())
+    }
+
+    /// Add a new entry or replace the existing one for its (issuer, kind)
+    pub fn upsert_issuer(&mut self, entry: RegisteredIssuer) -> Result<()> {
+        if let Some(existing) = self
+            .issuers
+            .iter_mut()
+            .find(|i| i.issuer == entry.issuer && i.kind == entry.kind)
+        {
+            *existing = entry;
+        } else {
+            require!(
+                self.issuers.len() < Self::MAX_ISSUERS,
+                GhostSpeakError::TooManyRegisteredIssuers
+            );
+            self.issuers.push(entry);
+        }
+        Ok(())
     }
 }
 
+/// Credential Dispute Config - governs review of flagged credentials
+///
+/// Seeds: ["credential_dispute_config"]
+///
+/// The dispute multisig resolves flags; every resolution requires
+/// `threshold` of its signers. Bonds of dismissed flags go to `treasury`.
+#[account]
+pub struct CredentialDisputeConfig {
+    /// Multisig that reviews flags
+    pub multisig: Pubkey,
+    /// Minimum bond a flagger must escrow
+    pub min_bond_lamports: u64,
+    /// Recipient of forfeited bonds
+    pub treasury: Pubkey,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CredentialDisputeConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        8 + // min_bond_lamports
+        32 + // treasury
+        8 + // updated_at
+        1; // bump
+}
+
+/// Review state of a credential flag
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Copy)]
+pub enum CredentialFlagStatus {
+    /// Awaiting review by the dispute multisig
+    Open,
+    /// Flag upheld: credential revoked, bond returned
+    Upheld,
+    /// Flag dismissed as frivolous: bond forfeited
+    Dismissed,
+}
+
+/// Credential Flag - a third party's bonded fraud report against a credential
+///
+/// Seeds: ["credential_flag", credential, flagger]
+///
+/// The bond is escrowed as lamports on this account until the dispute
+/// multisig resolves the flag. One flag per flagger per credential.
+#[account]
+pub struct CredentialFlag {
+    /// Flagged credential
+    pub credential: Pubkey,
+    /// Who raised the flag (and posted the bond)
+    pub flagger: Pubkey,
+    /// Evidence supporting the report (IPFS/Arweave URI)
+    pub evidence_uri: String,
+    /// Escrowed bond
+    pub bond_lamports: u64,
+    /// Review state
+    pub status: CredentialFlagStatus,
+    /// When the flag was raised
+    pub flagged_at: i64,
+    /// When the dispute multisig resolved it
+    pub resolved_at: Option<i64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CredentialFlag {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential
+        32 + // flagger
+        4 + MAX_FLAG_EVIDENCE_URI + // evidence_uri
+        8 + // bond_lamports
+        1 + // status
+        8 + // flagged_at
+        1 + 8 + // resolved_at
+        1; // bump
+
+    /// Close review of an open flag
+    pub fn resolve(&mut self, uphold: bool, now: i64) -> Result<()> {
+        require!(
+            self.status == CredentialFlagStatus::Open,
+            GhostSpeakError::CredentialFlagAlreadyResolved
+        );
+        self.status = if uphold {
+            CredentialFlagStatus::Upheld
+        } else {
+            CredentialFlagStatus::Dismissed
+        };
+        self.resolved_at = Some(now);
+        Ok(())
+    }
+}
+
+/// Credential Verification - on-chain record that an issuer signature checked out
+///
+/// Written by `verify_credential` after the issuer's assertion key is found in
+/// their DID document and an Ed25519 instruction proves it signed the
+/// credential's `subject_data_hash`. Other programs can read this PDA instead
+/// of repeating the introspection.
+#[account]
+pub struct CredentialVerification {
+    /// Credential that was verified
+    pub credential: Pubkey,
+    /// Issuer of the credential
+    pub issuer: Pubkey,
+    /// Key that produced the signature
+    pub signing_key: Pubkey,
+    /// DID verification method ID (None when signed by the DID controller key)
+    pub method_id: Option<String>,
+    /// Signed subject data hash at verification time
+    pub subject_data_hash: [u8; 32],
+    /// Who submitted the verification
+    pub verifier: Pubkey,
+    /// Last verification timestamp
+    pub verified_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CredentialVerification {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // credential
+        32 + // issuer
+        32 + // signing_key
+        1 + 4 + 128 + // method_id Option<String> (MAX_METHOD_ID)
+        32 + // subject_data_hash
+        32 + // verifier
+        8 + // verified_at
+        1; // bump
+}
+
+#[event]
+pub struct CredentialVerifiedEvent {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub signing_key: Pubkey,
+    pub method_id: Option<String>,
+    pub verifier: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialSchemaPublishedEvent {
+    pub credential_type: Pubkey,
+    pub schema: Pubkey,
+    pub version: u16,
+    pub schema_uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialSchemaDeprecatedEvent {
+    pub credential_type: Pubkey,
+    pub schema: Pubkey,
+    pub version: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialTemplateForkedEvent {
+    pub template: Pubkey,
+    pub forked_from: Pubkey,
+    pub original_author: Pubkey,
+    pub issuer: Pubkey,
+    pub royalty_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TemplateRoyaltyPaidEvent {
+    pub template: Pubkey,
+    pub credential: Pubkey,
+    pub original_author: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialIssuanceFeePaidEvent {
+    pub template: Pubkey,
+    pub credential: Pubkey,
+    pub payer: Pubkey,
+    pub creator: Pubkey,
+    pub creator_amount: u64,
+    pub treasury_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialPresentedEvent {
+    pub presentation: Pubkey,
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub verifier: Pubkey,
+    pub purpose: String,
+    pub disclosure_commitment: Option<[u8; 32]>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialCoSignedEvent {
+    pub credential: Pubkey,
+    pub co_issuer: Pubkey,
+    /// Approvals still missing after this one
+    pub remaining: u8,
+    pub activated: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialExpiredEvent {
+    pub credential: Pubkey,
+    pub subject: Pubkey,
+    pub issuer: Pubkey,
+    pub expired_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialTokenMintedEvent {
+    pub credential: Pubkey,
+    pub mint: Pubkey,
+    pub subject: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialReboundEvent {
+    pub credential: Pubkey,
+    pub previous_subject: Pubkey,
+    pub new_subject: Pubkey,
+    pub issuer: Pubkey,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialClosedEvent {
+    pub credential: Pubkey,
+    pub subject: Pubkey,
+    pub issuer: Pubkey,
+    pub closed_by: Pubkey,
+    pub status_list: Pubkey,
+    pub status_index: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialAcceptedEvent {
+    pub credential: Pubkey,
+    pub subject: Pubkey,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuanceDelegatedEvent {
+    pub delegation: Pubkey,
+    pub credential_type: Pubkey,
+    pub delegator: Pubkey,
+    pub operator: Pubkey,
+    pub max_issuances: u64,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegatedCredentialIssuedEvent {
+    pub delegation: Pubkey,
+    pub credential: Pubkey,
+    pub operator: Pubkey,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CustomCredentialKindRegisteredEvent {
+    pub kind_id: u16,
+    pub name: String,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerRegistryUpdatedEvent {
+    pub issuer: Pubkey,
+    pub kind: CredentialKind,
+    pub tier: IssuerTrustTier,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialFlaggedEvent {
+    pub credential: Pubkey,
+    pub flagger: Pubkey,
+    pub evidence_uri: String,
+    pub bond_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialFlagResolvedEvent {
+    pub credential: Pubkey,
+    pub flagger: Pubkey,
+    pub status: CredentialFlagStatus,
+    /// Where the bond went (flagger if upheld, treasury if dismissed)
+    pub bond_recipient: Pubkey,
+    pub bond_lamports: u64,
+    pub timestamp: i64,
+}
+
 /// Agent Identity Credential Subject Data
 ///
 /// Serialized as JSON and stored off-chain
@@ -288,3 +1506,463 @@ pub struct DelegatedSignerSubject {
     pub authorized_at: i64,
     pub permissions: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credential() -> Credential {
+        Credential {
+            template: Pubkey::new_unique(),
+            subject: Pubkey::new_unique(),
+            issuer: Pubkey::new_unique(),
+            credential_id: "urn:uuid:test".to_string(),
+            subject_data_hash: [0u8; 32],
+            subject_data_uri: String::new(),
+            status: CredentialStatus::Active,
+            signature: [0u8; 64],
+            issued_at: 0,
+            expires_at: None,
+            revoked_at: None,
+            cross_chain_status: CrossChainStatus::NotSynced,
+            crossmint_credential_id: None,
+            source_account: None,
+            cross_chain_attempts: 0,
+            cross_chain_retry_at: 0,
+            cross_chain_failure: None,
+            status_list: Pubkey::new_unique(),
+            status_index: 0,
+            schema_version: 1,
+            pending_co_issuers: Vec::new(),
+            awaiting_acceptance: false,
+            previous_subject: None,
+            rebound_at: None,
+            subject_index_page: Pubkey::new_unique(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_crosschain_sync_state_machine() {
+        let mut c = credential();
+        let id = |s: &str| s.to_string();
+
+        // Attested updates need an attempt in flight
+        assert!(c
+            .apply_crosschain_update(
+                CrossChainUpdate::Confirmed {
+                    crossmint_credential_id: id("cm-1")
+                },
+                0
+            )
+            .is_err());
+
+        c.request_crosschain_sync(0).unwrap();
+        assert_eq!(c.cross_chain_status, CrossChainStatus::SyncPending);
+        assert!(c.request_crosschain_sync(0).is_err());
+
+        // Fail with backoff, then retry once it elapses
+        c.apply_crosschain_update(
+            CrossChainUpdate::Failed {
+                reason: CrossChainFailureReason::Timeout,
+            },
+            100,
+        )
+        .unwrap();
+        assert_eq!(c.cross_chain_retry_at, 100 + CROSSCHAIN_RETRY_BASE_SECONDS);
+        assert!(c.request_crosschain_sync(100).is_err());
+        c.request_crosschain_sync(c.cross_chain_retry_at).unwrap();
+        assert_eq!(c.cross_chain_attempts, 2);
+        assert_eq!(c.cross_chain_failure, None);
+
+        c.apply_crosschain_update(
+            CrossChainUpdate::Submitted {
+                crossmint_credential_id: id("cm-1"),
+            },
+            200,
+        )
+        .unwrap();
+        assert_eq!(c.cross_chain_status, CrossChainStatus::SyncSubmitted);
+
+        // Confirmation must match the submitted ID
+        assert!(c
+            .apply_crosschain_update(
+                CrossChainUpdate::Confirmed {
+                    crossmint_credential_id: id("cm-2")
+                },
+                300
+            )
+            .is_err());
+        c.apply_crosschain_update(
+            CrossChainUpdate::Confirmed {
+                crossmint_credential_id: id("cm-1"),
+            },
+            300,
+        )
+        .unwrap();
+        assert_eq!(c.cross_chain_status, CrossChainStatus::SyncedToCrossmint);
+        assert!(c.request_crosschain_sync(400).is_err());
+
+        // Non-retryable failures are terminal
+        let mut c = credential();
+        c.request_crosschain_sync(0).unwrap();
+        c.apply_crosschain_update(
+            CrossChainUpdate::Failed {
+                reason: CrossChainFailureReason::Rejected,
+            },
+            0,
+        )
+        .unwrap();
+        assert!(c.request_crosschain_sync(i64::MAX).is_err());
+
+        // Backoff doubles up to the cap
+        assert_eq!(
+            Credential::crosschain_retry_delay(2),
+            2 * CROSSCHAIN_RETRY_BASE_SECONDS
+        );
+        assert_eq!(
+            Credential::crosschain_retry_delay(u8::MAX),
+            CROSSCHAIN_RETRY_MAX_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_fork_royalty_goes_to_root_author() {
+        let author = Pubkey::new_unique();
+        let mut original = CredentialTemplate {
+            credential_type: Pubkey::new_unique(),
+            name: "audit".to_string(),
+            image_uri: String::new(),
+            issuer: author,
+            is_active: true,
+            total_issued: 0,
+            created_at: 0,
+            crossmint_template_id: None,
+            forked_from: None,
+            original_author: None,
+            royalty_lamports: 0,
+            fork_count: 0,
+            co_issuers: Vec::new(),
+            issuance_fee_lamports: 0,
+            bump: 255,
+        };
+        assert_eq!(original.root_author(), author);
+        assert_eq!(original.royalty_due(), None);
+
+        // A fork of a fork still credits the root author
+        let mut fork = original.clone();
+        fork.issuer = Pubkey::new_unique();
+        fork.forked_from = Some(Pubkey::new_unique());
+        fork.original_author = Some(original.root_author());
+        fork.royalty_lamports = 5_000;
+        let mut refork = fork.clone();
+        refork.issuer = Pubkey::new_unique();
+        refork.original_author = Some(fork.root_author());
+        assert_eq!(refork.royalty_due(), Some((author, 5_000)));
+
+        // No royalty when the author forks their own template or pledges none
+        original.original_author = Some(author);
+        original.royalty_lamports = 5_000;
+        assert_eq!(original.royalty_due(), None);
+        fork.royalty_lamports = 0;
+        assert_eq!(fork.royalty_due(), None);
+    }
+
+    #[test]
+    fn test_crosschain_attestation_binds_attempt() {
+        let mut c = credential();
+        let key = Pubkey::new_unique();
+        let update = CrossChainUpdate::Failed {
+            reason: CrossChainFailureReason::RateLimited,
+        };
+
+        c.request_crosschain_sync(0).unwrap();
+        let first = c.crosschain_attestation_message(&key, &update).unwrap();
+        assert!(first.starts_with(CROSSCHAIN_ATTESTATION_DOMAIN));
+
+        c.apply_crosschain_update(update.clone(), 0).unwrap();
+        c.request_crosschain_sync(i64::MAX).unwrap();
+        assert_ne!(
+            c.crosschain_attestation_message(&key, &update).unwrap(),
+            first
+        );
+    }
+
+    #[test]
+    fn test_status_list_bitfield() {
+        let mut list = CredentialStatusList {
+            credential_type: Pubkey::default(),
+            next_index: 0,
+            revoked: Vec::new(),
+            bump: 0,
+        };
+        let credential_type = Pubkey::new_unique();
+        list.initialize_if_needed(credential_type, 254);
+        assert_eq!(list.revoked.len(), STATUS_LIST_CAPACITY as usize / 8);
+
+        // Re-initializing keeps assigned indices
+        assert_eq!(list.allocate().unwrap(), 0);
+        list.initialize_if_needed(Pubkey::new_unique(), 1);
+        assert_eq!(list.credential_type, credential_type);
+        for expected in 1..10 {
+            assert_eq!(list.allocate().unwrap(), expected);
+        }
+
+        // Most significant bit first, as in StatusList2021
+        list.set_revoked(0).unwrap();
+        list.set_revoked(9).unwrap();
+        assert_eq!(list.revoked[0], 0b1000_0000);
+        assert_eq!(list.revoked[1], 0b0100_0000);
+        assert!(list.is_revoked(0) && list.is_revoked(9));
+        assert!(!list.is_revoked(1) && !list.is_revoked(8));
+
+        // Unassigned indices cannot be revoked, and a full list stops issuance
+        assert!(list.set_revoked(10).is_err());
+        assert!(!list.is_revoked(STATUS_LIST_CAPACITY));
+        list.next_index = STATUS_LIST_CAPACITY;
+        assert!(list.allocate().is_err());
+    }
+
+    #[test]
+    fn test_issuer_registry_enforcement() {
+        let issuer = Pubkey::new_unique();
+        let mut registry = IssuerRegistry {
+            multisig: Pubkey::new_unique(),
+            enforce_reserved_kinds: false,
+            issuers: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+
+        // Without enforcement anyone may issue any kind
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::AgentIdentity).is_ok());
+
+        // With enforcement only reserved kinds are restricted
+        registry.enforce_reserved_kinds = true;
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::AgentIdentity).is_err());
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::ReputationScore).is_err());
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::JobCompletion).is_ok());
+
+        // Approval is per kind
+        let mut entry = RegisteredIssuer {
+            issuer,
+            kind: CredentialKind::AgentIdentity,
+            tier: IssuerTrustTier::Verified,
+            is_active: true,
+        };
+        registry.upsert_issuer(entry.clone()).unwrap();
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::AgentIdentity).is_ok());
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::ReputationScore).is_err());
+
+        // Upserting replaces the entry instead of adding one
+        entry.is_active = false;
+        registry.upsert_issuer(entry).unwrap();
+        assert_eq!(registry.issuers.len(), 1);
+        assert!(registry.authorize_issuance(&issuer, CredentialKind::AgentIdentity).is_err());
+
+        while registry.issuers.len() < IssuerRegistry::MAX_ISSUERS {
+            registry.issuers.push(RegisteredIssuer {
+                issuer: Pubkey::new_unique(),
+                kind: CredentialKind::ReputationScore,
+                tier: IssuerTrustTier::Community,
+                is_active: true,
+            });
+        }
+        assert!(registry
+            .upsert_issuer(RegisteredIssuer {
+                issuer: Pubkey::new_unique(),
+                kind: CredentialKind::AgentIdentity,
+                tier: IssuerTrustTier::Protocol,
+                is_active: true,
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn test_co_issuer_approvals_activate_credential() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut cred = credential();
+        cred.status = CredentialStatus::Pending;
+        cred.pending_co_issuers = vec![first, second];
+        cred.awaiting_acceptance = true;
+
+        // Unknown and repeated approvals are rejected
+        assert!(cred.record_co_issuer_approval(&Pubkey::new_unique()).is_err());
+        cred.record_co_issuer_approval(&second).unwrap();
+        assert!(cred.record_co_issuer_approval(&second).is_err());
+        assert_eq!(cred.pending_co_issuers, vec![first]);
+
+        // Still pending until the holder accepts as well
+        cred.record_co_issuer_approval(&first).unwrap();
+        assert_eq!(cred.status, CredentialStatus::Pending);
+        cred.awaiting_acceptance = false;
+        cred.activate_if_ready();
+        assert_eq!(cred.status, CredentialStatus::Active);
+
+        // Never resurrects a revoked credential
+        cred.revoke(1);
+        cred.activate_if_ready();
+        assert_eq!(cred.status, CredentialStatus::Revoked);
+    }
+
+    #[test]
+    fn test_expire_credential() {
+        let mut cred = credential();
+
+        // Credentials without an expiry never expire
+        assert!(cred.expire(i64::MAX).is_err());
+
+        cred.expires_at = Some(1_000);
+        assert!(cred.expire(999).is_err());
+        assert_eq!(cred.expire(1_000).unwrap(), 1_000);
+        assert_eq!(cred.status, CredentialStatus::Expired);
+        assert!(!cred.is_valid(1_000));
+
+        // Only once, and never over a revocation
+        assert!(cred.expire(2_000).is_err());
+        let mut revoked = credential();
+        revoked.expires_at = Some(1_000);
+        revoked.revoke(500);
+        assert!(revoked.expire(2_000).is_err());
+    }
+
+    #[test]
+    fn test_rebind_keeps_issuance_record() {
+        let mut cred = credential();
+        let (original, issued_at) = (cred.subject, cred.issued_at);
+        let new_subject = Pubkey::new_unique();
+
+        assert!(cred.rebind(original, 10).is_err());
+        cred.rebind(new_subject, 10).unwrap();
+        assert_eq!(cred.subject, new_subject);
+        assert_eq!(cred.previous_subject, Some(original));
+        assert_eq!(cred.rebound_at, Some(10));
+        assert_eq!(cred.issued_at, issued_at);
+
+        // Only active credentials move
+        cred.revoke(20);
+        assert!(cred.rebind(Pubkey::new_unique(), 30).is_err());
+    }
+
+    #[test]
+    fn test_issuance_delegation_caps() {
+        let mut delegation = IssuanceDelegation {
+            credential_type: Pubkey::new_unique(),
+            delegator: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+            max_issuances: 2,
+            issued: 0,
+            expires_at: Some(1_000),
+            created_at: 0,
+            bump: 255,
+        };
+
+        assert_eq!(delegation.consume(10).unwrap(), 1);
+        assert!(delegation.consume(1_000).is_err());
+        assert_eq!(delegation.consume(999).unwrap(), 0);
+        assert!(delegation.consume(999).is_err());
+        assert_eq!(delegation.issued, 2);
+    }
+
+    #[test]
+    fn test_subject_index_pages_fill_in_order() {
+        let subject = Pubkey::new_unique();
+        let mut index = SubjectCredentialIndex {
+            subject: Pubkey::default(),
+            total_indexed: 0,
+            bump: 0,
+        };
+        index.initialize_if_needed(subject, 254);
+        assert_eq!((index.open_page(), index.page_count()), (0, 0));
+
+        let mut page = SubjectCredentialPage {
+            subject: Pubkey::default(),
+            page: 0,
+            credentials: Vec::new(),
+            bump: 0,
+        };
+        page.initialize_if_needed(subject, index.open_page(), 253);
+        let keys: Vec<Pubkey> = (0..SUBJECT_INDEX_PAGE_SIZE)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        for key in &keys {
+            page.push(*key).unwrap();
+            index.record_append().unwrap();
+        }
+        assert!(page.push(Pubkey::new_unique()).is_err());
+        assert_eq!((index.open_page(), index.page_count()), (1, 1));
+
+        // Removal leaves a gap but never reopens the page
+        page.remove(&keys[3]);
+        page.remove(&keys[3]);
+        assert_eq!(page.credentials.len(), SUBJECT_INDEX_PAGE_SIZE - 1);
+        assert_eq!(page.credentials[3], keys[4]);
+        index.record_append().unwrap();
+        assert_eq!((index.open_page(), index.page_count()), (1, 2));
+    }
+
+    #[test]
+    fn test_custom_kind_authorizes_owner_only() {
+        let owner = Pubkey::new_unique();
+        let entry = CustomCredentialKind {
+            kind_id: 7,
+            name: "Guild Membership".to_string(),
+            owner,
+            created_at: 0,
+            bump: 255,
+        };
+
+        entry.authorize(CredentialKind::Custom(7), &owner).unwrap();
+        assert!(entry.authorize(CredentialKind::Custom(7), &Pubkey::new_unique()).is_err());
+        assert!(entry.authorize(CredentialKind::Custom(8), &owner).is_err());
+
+        // Kind 0 is the generic custom kind and needs no entry
+        assert_eq!(CredentialKind::Custom(0).registered_custom_id(), None);
+        assert_eq!(CredentialKind::default(), CredentialKind::Custom(0));
+        assert_eq!(CredentialKind::JobCompletion.registered_custom_id(), None);
+    }
+
+    #[test]
+    fn test_close_requires_ended_credential() {
+        let mut cred = credential();
+        assert!(cred.require_closable(true, 0).is_err());
+
+        cred.revoke(100);
+        cred.require_closable(true, 100).unwrap();
+        assert!(cred.require_closable(false, 100).is_err());
+        cred.require_closable(false, 100 + CREDENTIAL_CLOSE_GRACE_SECONDS)
+            .unwrap();
+
+        let mut expired = credential();
+        expired.expires_at = Some(1_000);
+        expired.expire(1_000).unwrap();
+        assert!(expired
+            .require_closable(false, 1_000 + CREDENTIAL_CLOSE_GRACE_SECONDS - 1)
+            .is_err());
+        expired
+            .require_closable(false, 1_000 + CREDENTIAL_CLOSE_GRACE_SECONDS)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_credential_flag_resolves_once() {
+        let mut flag = CredentialFlag {
+            credential: Pubkey::new_unique(),
+            flagger: Pubkey::new_unique(),
+            evidence_uri: "ipfs://evidence".to_string(),
+            bond_lamports: 1_000_000,
+            status: CredentialFlagStatus::Open,
+            flagged_at: 0,
+            resolved_at: None,
+            bump: 255,
+        };
+
+        flag.resolve(false, 10).unwrap();
+        assert_eq!(flag.status, CredentialFlagStatus::Dismissed);
+        assert_eq!(flag.resolved_at, Some(10));
+
+        // A dismissed flag cannot later be upheld
+        assert!(flag.resolve(true, 20).is_err());
+        assert_eq!(flag.resolved_at, Some(10));
+    }
+}
@@ -0,0 +1,83 @@
+/*!
+ * Denylist State
+ *
+ * Governance-managed sanctions registry of agent PDAs and owner wallets.
+ * Each listed key gets its own entry PDA, so instructions that create
+ * escrows, issue credentials or accept ratings only need to pass the entry
+ * address for every party involved and require that it is uninitialized.
+ */
+
+use anchor_lang::prelude::*;
+
+/// Seed for the denylist config PDA
+pub const DENYLIST_SEED: &[u8] = b"denylist";
+
+/// Seed for denylist entries: [seed, subject]
+pub const DENYLIST_ENTRY_SEED: &[u8] = b"denylist_entry";
+
+/// Why an agent or owner is denylisted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DenylistReason {
+    Sanctions,
+    Fraud,
+    Abuse,
+}
+
+/// Denylist - multisig that manages the entries
+///
+/// Seeds: ["denylist"]
+#[account]
+pub struct Denylist {
+    /// Protocol or DAO multisig that adds and removes entries
+    pub multisig: Pubkey,
+    /// Number of keys currently listed
+    pub entry_count: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Denylist {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        4 + // entry_count
+        8 + // updated_at
+        1; // bump
+}
+
+/// Denylist Entry - one blocked agent PDA or owner; exists only while listed
+///
+/// Seeds: ["denylist_entry", subject]
+#[account]
+pub struct DenylistEntry {
+    /// Agent PDA or owner wallet
+    pub subject: Pubkey,
+    pub reason: DenylistReason,
+    /// Account that paid the entry's rent (refunded on removal)
+    pub rent_payer: Pubkey,
+    pub listed_at: i64,
+    pub bump: u8,
+}
+
+impl DenylistEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // subject
+        1 + // reason
+        32 + // rent_payer
+        8 + // listed_at
+        1; // bump
+}
+
+#[event]
+pub struct DenylistEntryAddedEvent {
+    pub subject: Pubkey,
+    pub reason: DenylistReason,
+    pub multisig: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DenylistEntryRemovedEvent {
+    pub subject: Pubkey,
+    pub multisig: Pubkey,
+    pub timestamp: i64,
+}
@@ -6,10 +6,26 @@
  */
 
 use anchor_lang::prelude::*;
+use sha3::{Digest, Keccak256};
+
+use crate::state::agent::Agent;
+use crate::state::governance::Multisig;
+use crate::GhostSpeakError;
 
 // PDA Seeds
 pub const DID_DOCUMENT_SEED: &[u8] = b"did_document";
 pub const VERIFICATION_METHOD_SEED: &[u8] = b"verification_method";
+pub const DID_KEY_ROTATION_SEED: &[u8] = b"did_key_rotation";
+pub const DID_CONTROLLERS_SEED: &[u8] = b"did_controllers";
+pub const DID_GUARDIANS_SEED: &[u8] = b"did_guardians";
+pub const DID_RECOVERY_SEED: &[u8] = b"did_recovery";
+pub const DID_VERSION_SEED: &[u8] = b"did_version";
+pub const DID_CONTROLLER_TRANSFER_SEED: &[u8] = b"did_controller_transfer";
+pub const DID_AGENT_LINK_SEED: &[u8] = b"did_agent_link";
+pub const DID_EXTERNAL_LINK_SEED: &[u8] = b"did_external_link";
+
+/// Domain prefix of the challenge an external `did:key` signs
+pub const DID_EXTERNAL_LINK_DOMAIN: &[u8] = b"ghostspeak:did-external-link:v1";
 
 // Maximum lengths
 pub const MAX_DID_STRING: usize = 64;        // did:sol:devnet:{base58}
@@ -18,6 +34,20 @@ pub const MAX_SERVICE_ENDPOINTS: usize = 5;
 pub const MAX_AUTHENTICATION_KEYS: usize = 5;
 pub const MAX_URI_LENGTH: usize = 256;
 pub const MAX_METHOD_ID: usize = 128;
+pub const MAX_DID_CONTROLLERS: usize = 5;
+pub const MAX_DID_GUARDIANS: usize = 7;
+pub const MAX_DELEGATE_SCOPES: usize = 4;
+pub const MAX_EXTERNAL_DID: usize = 128;
+
+/// Bytes of serialized document per `resolve_did_document` chunk, leaving
+/// room for the chunk header within the 1024-byte return data limit
+pub const DID_RESOLUTION_CHUNK_LEN: usize = 896;
+
+/// Longest allowed reactivation grace period after deactivation (90 days)
+pub const MAX_DID_REACTIVATION_GRACE_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// Shortest allowed recovery challenge window (2 days)
+pub const MIN_DID_RECOVERY_DELAY: i64 = 2 * 24 * 60 * 60;
 
 /// Verification method type for DIDs
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
@@ -70,6 +100,14 @@ pub enum ServiceEndpointType {
     LinkedDomains,
     /// Custom service type
     Custom,
+    /// Agent-to-agent protocol (agent card URL)
+    A2A,
+    /// x402 payment endpoint
+    X402,
+    /// Model Context Protocol server
+    MCP,
+    /// Plain REST API
+    Rest,
 }
 
 impl Default for ServiceEndpointType {
@@ -78,6 +116,19 @@ impl Default for ServiceEndpointType {
     }
 }
 
+impl ServiceEndpointType {
+    /// URI schemes recognized for this type; empty accepts any scheme
+    pub fn allowed_schemes(&self) -> &'static [&'static str] {
+        match self {
+            Self::A2A | Self::X402 | Self::Rest | Self::LinkedDomains => &["https"],
+            Self::MCP | Self::AIAgentService => &["https", "wss"],
+            Self::DIDCommMessaging => &["https", "wss", "did"],
+            Self::CredentialRepository => &["https", "ipfs"],
+            Self::Custom => &[],
+        }
+    }
+}
+
 /// Verification method for DID document
 ///
 /// Represents a cryptographic key that can be used to verify
@@ -113,6 +164,59 @@ pub struct ServiceEndpoint {
     pub description: String,
 }
 
+impl ServiceEndpoint {
+    pub const MAX_DESCRIPTION: usize = 256;
+
+    /// Check the ID, description and URI against the rules for its type
+    ///
+    /// URIs must use one of the type's schemes; network schemes need a
+    /// host, `did:` URIs a method and identifier, and linked domains must be
+    /// bare origins.
+    pub fn validate(&self) -> Result<()> {
+        let uri = &self.service_endpoint;
+        require!(
+            !self.id.is_empty()
+                && self.id.len() <= MAX_METHOD_ID
+                && self.description.len() <= Self::MAX_DESCRIPTION
+                && uri.len() <= MAX_URI_LENGTH
+                && uri.bytes().all(|b| b.is_ascii_graphic()),
+            DidError::InvalidServiceEndpoint
+        );
+
+        let (scheme, rest) = uri
+            .split_once(':')
+            .ok_or(DidError::InvalidServiceEndpoint)?;
+        let allowed = self.service_type.allowed_schemes();
+        require!(
+            !scheme.is_empty()
+                && scheme
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b"+.-".contains(&b))
+                && (allowed.is_empty() || allowed.contains(&scheme)),
+            DidError::InvalidServiceEndpoint
+        );
+
+        let valid = match scheme {
+            "did" => rest
+                .split_once(':')
+                .is_some_and(|(method, id)| !method.is_empty() && !id.is_empty()),
+            "http" | "https" | "ws" | "wss" | "ipfs" => {
+                rest.strip_prefix("//").is_some_and(|hier| {
+                    let end = hier.find(['/', '?', '#']).unwrap_or(hier.len());
+                    let (host, path) = hier.split_at(end);
+                    !host.is_empty()
+                        && (self.service_type != ServiceEndpointType::LinkedDomains
+                            || path.is_empty()
+                            || path == "/")
+                })
+            }
+            _ => !rest.is_empty(),
+        };
+        require!(valid, DidError::InvalidServiceEndpoint);
+        Ok(())
+    }
+}
+
 /// DID Document - main account for storing decentralized identifiers
 ///
 /// Follows W3C DID Core specification and did:sol method
@@ -153,6 +257,11 @@ pub struct DidDocument {
 
     /// PDA bump
     pub bump: u8,
+
+    /// A deactivated document can be reactivated until this time, after
+    /// which it is tombstoned (zero for documents deactivated before grace
+    /// periods existed)
+    pub reactivation_deadline: i64,
 }
 
 impl DidDocument {
@@ -175,7 +284,8 @@ impl DidDocument {
         4 + // version
         1 + // deactivated
         1 + 8 + // deactivated_at Option<i64>
-        1 // bump
+        1 + // bump
+        8 // reactivation_deadline
     }
 
     /// Size of a single verification method
@@ -211,13 +321,76 @@ impl DidDocument {
         4 + // version
         1 + // deactivated
         1 + 8 + // deactivated_at Option<i64>
-        1; // bump
+        1 + // bump
+        8; // reactivation_deadline
+
+    /// Space kept by a closed (tombstoned) document: methods, services,
+    /// context and aliases are dropped
+    pub const TOMBSTONE_LEN: usize = 8 + // discriminator
+        4 + MAX_DID_STRING + // did
+        32 + // controller
+        4 + // verification_methods (empty)
+        4 + // service_endpoints (empty)
+        4 + // context (empty)
+        4 + // also_known_as (empty)
+        8 + // created_at
+        8 + // updated_at
+        4 + // version
+        1 + // deactivated
+        1 + 8 + // deactivated_at Option<i64>
+        1 + // bump
+        8; // reactivation_deadline
 
     /// Check if DID is valid and active
     pub fn is_active(&self) -> bool {
         !self.deactivated
     }
 
+    /// Version the next update will produce (seeds its history entry)
+    pub fn next_version(&self) -> u32 {
+        self.version.saturating_add(1)
+    }
+
+    /// Keccak256 of the serialized document state
+    pub fn state_hash(&self) -> Result<[u8; 32]> {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.try_to_vec()?);
+        Ok(hasher.finalize().into())
+    }
+
+    /// Chunk `chunk_index` of the Borsh-serialized document
+    pub fn resolution_chunk(
+        &self,
+        did_key: &Pubkey,
+        chunk_index: u16,
+    ) -> Result<DidResolutionChunk> {
+        let serialized = self.try_to_vec()?;
+        let total_chunks = serialized.len().div_ceil(DID_RESOLUTION_CHUNK_LEN);
+        require!(
+            (chunk_index as usize) < total_chunks,
+            DidError::InvalidResolutionChunk
+        );
+
+        let mut hasher = Keccak256::new();
+        hasher.update(&serialized);
+        let data = serialized
+            .chunks(DID_RESOLUTION_CHUNK_LEN)
+            .nth(chunk_index as usize)
+            .unwrap_or_default()
+            .to_vec();
+
+        Ok(DidResolutionChunk {
+            did_document: *did_key,
+            version: self.version,
+            deactivated: self.deactivated,
+            state_hash: hasher.finalize().into(),
+            total_len: serialized.len() as u32,
+            chunk_index,
+            total_chunks: total_chunks as u16,
+            data,
+        })
+    }
+
     /// Add a verification method to the DID document
     pub fn add_verification_method(&mut self, method: VerificationMethod) -> Result<()> {
         require!(
@@ -256,6 +429,7 @@ impl DidDocument {
 
     /// Add a service endpoint
     pub fn add_service_endpoint(&mut self, service: ServiceEndpoint) -> Result<()> {
+        service.validate()?;
         require!(
             self.service_endpoints.len() < MAX_SERVICE_ENDPOINTS,
             DidError::TooManyServiceEndpoints
@@ -290,18 +464,43 @@ impl DidDocument {
         Ok(())
     }
 
-    /// Deactivate the DID document
-    pub fn deactivate(&mut self) -> Result<()> {
+    /// Deactivate the DID document, reactivatable for `grace_period` seconds
+    pub fn deactivate(&mut self, grace_period: i64) -> Result<()> {
         require!(!self.deactivated, DidError::AlreadyDeactivated);
+        require!(
+            (0..=MAX_DID_REACTIVATION_GRACE_PERIOD).contains(&grace_period),
+            DidError::InvalidGracePeriod
+        );
 
+        let now = Clock::get()?.unix_timestamp;
         self.deactivated = true;
-        self.deactivated_at = Some(Clock::get()?.unix_timestamp);
-        self.updated_at = Clock::get()?.unix_timestamp;
+        self.deactivated_at = Some(now);
+        self.reactivation_deadline = now.saturating_add(grace_period);
+        self.updated_at = now;
         self.version += 1;
 
         Ok(())
     }
 
+    /// Whether the document is deactivated past its grace period
+    pub fn is_tombstoned(&self, now: i64) -> bool {
+        self.deactivated && now >= self.reactivation_deadline
+    }
+
+    /// Undo a deactivation within its grace period
+    pub fn reactivate(&mut self, now: i64) -> Result<()> {
+        require!(self.deactivated, DidError::NotDeactivated);
+        require!(!self.is_tombstoned(now), DidError::DidTombstoned);
+
+        self.deactivated = false;
+        self.deactivated_at = None;
+        self.reactivation_deadline = 0;
+        self.updated_at = now;
+        self.version = self.next_version();
+
+        Ok(())
+    }
+
     /// Get verification methods for a specific relationship
     pub fn get_methods_for_relationship(
         &self,
@@ -313,6 +512,50 @@ impl DidDocument {
             .collect()
     }
 
+    /// Active verification method holding `public_key` with the given relationship
+    pub fn find_method(
+        &self,
+        public_key: &Pubkey,
+        relationship: VerificationRelationship,
+    ) -> Option<&VerificationMethod> {
+        let pubkey_multibase = ed25519_multibase(public_key);
+        self.verification_methods.iter().find(|method| {
+            !method.revoked
+                && method.public_key_multibase == pubkey_multibase
+                && method.relationships.contains(&relationship)
+        })
+    }
+
+    /// Atomically replace verification method `old_method_id` with `new_method`
+    ///
+    /// Counts as a single document update. `new_method` may reuse the old ID.
+    pub fn rotate_verification_method(
+        &mut self,
+        old_method_id: &str,
+        new_method: VerificationMethod,
+        now: i64,
+    ) -> Result<VerificationMethod> {
+        let position = self
+            .verification_methods
+            .iter()
+            .position(|m| m.id == old_method_id)
+            .ok_or(DidError::MethodNotFound)?;
+
+        require!(
+            !self
+                .verification_methods
+                .iter()
+                .any(|m| m.id == new_method.id && m.id != old_method_id),
+            DidError::DuplicateMethodId
+        );
+
+        let old_method = std::mem::replace(&mut self.verification_methods[position], new_method);
+        self.updated_at = now;
+        self.version += 1;
+
+        Ok(old_method)
+    }
+
     /// Verify that a public key can perform a specific action
     pub fn can_perform_action(
         &self,
@@ -325,16 +568,771 @@ impl DidDocument {
         }
 
         // Check if public key is in verification methods with correct relationship
-        let pubkey_multibase = format!("z{}", bs58::encode(public_key.to_bytes()).into_string());
+        self.find_method(public_key, relationship).is_some()
+    }
+}
 
-        self.verification_methods.iter().any(|method| {
-            !method.revoked
-                && method.public_key_multibase == pubkey_multibase
-                && method.relationships.contains(&relationship)
+/// Multibase (base58btc) encoding of an Ed25519 public key
+pub fn ed25519_multibase(public_key: &Pubkey) -> String {
+    format!("z{}", bs58::encode(public_key.to_bytes()).into_string())
+}
+
+/// Key rotation record
+///
+/// One per rotation, keyed by the document version the rotation replaced.
+/// Verifiers follow `old_key -> new_key` links to prove key continuity.
+#[account]
+pub struct DidKeyRotation {
+    /// DID document the rotation applies to
+    pub did_document: Pubkey,
+
+    /// Document version before the rotation
+    pub from_version: u32,
+
+    /// Removed verification method ID
+    pub old_method_id: String,
+
+    /// Removed key (signed the rotation)
+    pub old_key: Pubkey,
+
+    /// Added verification method ID
+    pub new_method_id: String,
+
+    /// Added key (signed the rotation)
+    pub new_key: Pubkey,
+
+    /// Rotation timestamp
+    pub rotated_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidKeyRotation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        4 + // from_version
+        4 + MAX_METHOD_ID + // old_method_id
+        32 + // old_key
+        4 + MAX_METHOD_ID + // new_method_id
+        32 + // new_key
+        8 + // rotated_at
+        1; // bump
+}
+
+/// Keys from `keys` that approve the current transaction
+///
+/// A wallet approves by signing; a governance multisig is passed as an
+/// account and approves when its signers meet its threshold.
+pub fn approving_keys(keys: &[Pubkey], accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    keys.iter()
+        .filter(|key| {
+            accounts.iter().any(|acc| {
+                if acc.key != *key {
+                    return false;
+                }
+                if acc.is_signer {
+                    return true;
+                }
+                if acc.owner != &crate::ID {
+                    return false;
+                }
+                acc.try_borrow_data()
+                    .ok()
+                    .and_then(|data| Multisig::try_deserialize(&mut &data[..]).ok())
+                    .is_some_and(|multisig| {
+                        multisig.approval_weight(accounts) >= multisig.threshold as usize
+                    })
+            })
         })
+        .copied()
+        .collect()
+}
+
+/// Whether `keys` is a non-empty, duplicate-free m-of-n set excluding `excluded`
+fn is_valid_key_set(keys: &[Pubkey], threshold: u8, max_keys: usize, excluded: &Pubkey) -> bool {
+    !keys.is_empty()
+        && keys.len() <= max_keys
+        && threshold >= 1
+        && threshold as usize <= keys.len()
+        && keys
+            .iter()
+            .enumerate()
+            .all(|(i, key)| key != excluded && !keys[..i].contains(key))
+}
+
+/// Controller set for a multi-controller DID
+///
+/// Once installed, the document's `controller` points at this PDA and
+/// updates need `threshold` of `controllers` to approve. A controller may be
+/// a wallet (approves by signing) or a governance `Multisig` (approves when
+/// its own threshold of signers sign).
+#[account]
+pub struct DidControllerSet {
+    /// DID document governed by this set
+    pub did_document: Pubkey,
+
+    /// Controller keys (wallets or multisig accounts)
+    pub controllers: Vec<Pubkey>,
+
+    /// Approvals required for an update (m of n)
+    pub threshold: u8,
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidControllerSet {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        4 + (MAX_DID_CONTROLLERS * 32) + // controllers
+        1 + // threshold
+        8 + // updated_at
+        1; // bump
+
+    /// Validate a proposed controller list and threshold
+    pub fn validate(controllers: &[Pubkey], threshold: u8, set_key: &Pubkey) -> Result<()> {
+        require!(
+            is_valid_key_set(controllers, threshold, MAX_DID_CONTROLLERS, set_key),
+            DidError::InvalidControllerSet
+        );
+        Ok(())
+    }
+
+    /// Count controllers approving the current transaction
+    pub fn count_approvals(&self, accounts: &[AccountInfo]) -> usize {
+        approving_keys(&self.controllers, accounts).len()
+    }
+
+    /// Require that at least `threshold` controllers approved
+    pub fn require_approvals(&self, accounts: &[AccountInfo]) -> Result<()> {
+        require!(
+            self.count_approvals(accounts) >= self.threshold as usize,
+            DidError::InsufficientControllerApprovals
+        );
+        Ok(())
+    }
+}
+
+/// Event emitted when a DID's controller set is installed or changed
+#[event]
+pub struct DidControllersUpdatedEvent {
+    pub did_document: Pubkey,
+    pub controller_set: Pubkey,
+    pub controllers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Kind of change a DID version entry records
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DidChangeType {
+    /// Verification methods or services added or removed
+    Update,
+    /// Document deactivated
+    Deactivate,
+    /// Verification key rotated
+    KeyRotation,
+    /// Controller set installed or changed
+    ControllersChanged,
+    /// Controller replaced by guardian recovery
+    Recovery,
+    /// Controller handed over by transfer
+    ControllerTransfer,
+    /// Deactivation undone within its grace period
+    Reactivate,
+}
+
+/// History entry for one DID document version
+///
+/// Seeds: ["did_version", did_document, version_id.to_le_bytes()]
+///
+/// Every update after creation writes exactly one entry, so resolvers can
+/// answer DID-Core `versionId` / `versionTime` queries. Version 1 has no
+/// entry; its time is the document's `created_at`. Entries chain through
+/// `prior_state_hash`, which equals the previous entry's `state_hash`.
+#[account]
+pub struct DidVersionEntry {
+    /// DID document the entry belongs to
+    pub did_document: Pubkey,
+    /// Document version produced by the change
+    pub version_id: u32,
+    /// Kind of change
+    pub change_type: DidChangeType,
+    /// Keccak256 of the serialized document before the change
+    pub prior_state_hash: [u8; 32],
+    /// Keccak256 of the serialized document after the change
+    pub state_hash: [u8; 32],
+    /// Unix timestamp of the change
+    pub version_time: i64,
+    /// Slot the change landed in
+    pub slot: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidVersionEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        4 + // version_id
+        1 + // change_type
+        32 + // prior_state_hash
+        32 + // state_hash
+        8 + // version_time
+        8 + // slot
+        1; // bump
+
+    /// Record the version `did_document` is now at
+    ///
+    /// The entry PDA must have been derived from `next_version()` before the
+    /// change, and the change must have bumped the version exactly once.
+    pub fn record(
+        &mut self,
+        did_document: &Account<DidDocument>,
+        prior_state_hash: [u8; 32],
+        change_type: DidChangeType,
+        bump: u8,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        self.did_document = did_document.key();
+        self.version_id = did_document.version;
+        self.change_type = change_type;
+        self.prior_state_hash = prior_state_hash;
+        self.state_hash = did_document.state_hash()?;
+        self.version_time = clock.unix_timestamp;
+        self.slot = clock.slot;
+        self.bump = bump;
+
+        emit!(DidVersionRecordedEvent {
+            did_document: self.did_document,
+            version_id: self.version_id,
+            change_type,
+            prior_state_hash,
+            state_hash: self.state_hash,
+            version_time: self.version_time,
+        });
+
+        Ok(())
     }
 }
 
+/// Event emitted for every DID version entry
+#[event]
+pub struct DidVersionRecordedEvent {
+    pub did_document: Pubkey,
+    pub version_id: u32,
+    pub change_type: DidChangeType,
+    pub prior_state_hash: [u8; 32],
+    pub state_hash: [u8; 32],
+    pub version_time: i64,
+}
+
+/// Recovery guardians for a DID
+///
+/// If the controller key is lost, `threshold` guardians can start a
+/// controller replacement that executes after `recovery_delay` seconds
+/// unless the controller cancels it first.
+///
+/// Guardians serve the controller that appointed them: the set lapses when
+/// the controller is transferred, and follows the controller through a
+/// recovery.
+#[account]
+pub struct DidGuardianSet {
+    /// DID document protected by this set
+    pub did_document: Pubkey,
+
+    /// Controller the guardians act for
+    pub controller: Pubkey,
+
+    /// Guardian keys (wallets or multisig accounts)
+    pub guardians: Vec<Pubkey>,
+
+    /// Guardian approvals required to start a recovery
+    pub threshold: u8,
+
+    /// Challenge window before a recovery can execute (seconds)
+    pub recovery_delay: i64,
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidGuardianSet {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        32 + // controller
+        4 + (MAX_DID_GUARDIANS * 32) + // guardians
+        1 + // threshold
+        8 + // recovery_delay
+        8 + // updated_at
+        1; // bump
+
+    /// Validate a proposed guardian list, threshold and delay
+    pub fn validate(
+        guardians: &[Pubkey],
+        threshold: u8,
+        recovery_delay: i64,
+        controller: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            is_valid_key_set(guardians, threshold, MAX_DID_GUARDIANS, controller)
+                && recovery_delay >= MIN_DID_RECOVERY_DELAY,
+            DidError::InvalidGuardianSet
+        );
+        Ok(())
+    }
+}
+
+/// Pending controller replacement started by guardians
+#[account]
+pub struct DidRecoveryRequest {
+    /// DID document being recovered
+    pub did_document: Pubkey,
+
+    /// Controller the document had when recovery started
+    pub previous_controller: Pubkey,
+
+    /// Proposed replacement controller
+    pub new_controller: Pubkey,
+
+    /// Guardians that approved the request
+    pub approvals: Vec<Pubkey>,
+
+    /// Guardian that paid for the request (refunded on close)
+    pub initiator: Pubkey,
+
+    /// Request timestamp
+    pub initiated_at: i64,
+
+    /// Earliest execution time (end of the challenge window)
+    pub executable_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidRecoveryRequest {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        32 + // previous_controller
+        32 + // new_controller
+        4 + (MAX_DID_GUARDIANS * 32) + // approvals
+        32 + // initiator
+        8 + // initiated_at
+        8 + // executable_at
+        1; // bump
+}
+
+/// Capability a scoped delegate key may exercise for the DID's agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DidKeyScope {
+    /// Submit escrow deliveries on behalf of the agent
+    SubmitDeliveries,
+}
+
+/// Scope record for a delegate verification method
+///
+/// Seeds: ["verification_method", did_document, delegate]
+///
+/// Restricted operator keys are ordinary verification methods on the DID
+/// plus this record; downstream instructions accept the key only for the
+/// listed scopes, and only while the DID is linked to the agent and its
+/// controller still owns it.
+#[account]
+pub struct DidDelegateKey {
+    /// DID document the key belongs to
+    pub did_document: Pubkey,
+
+    /// Delegate signing key
+    pub delegate: Pubkey,
+
+    /// Verification method ID holding the key
+    pub method_id: String,
+
+    /// Capabilities granted to the key
+    pub scopes: Vec<DidKeyScope>,
+
+    /// Optional expiry
+    pub expires_at: Option<i64>,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidDelegateKey {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        32 + // delegate
+        4 + MAX_METHOD_ID + // method_id
+        4 + MAX_DELEGATE_SCOPES + // scopes
+        1 + 8 + // expires_at
+        8 + // created_at
+        1; // bump
+
+    /// Check that `signer` may exercise `scope` for `agent`
+    pub fn authorize(
+        &self,
+        did_key: &Pubkey,
+        did_document: &DidDocument,
+        agent: &Agent,
+        signer: &Pubkey,
+        scope: DidKeyScope,
+        now: i64,
+    ) -> Result<()> {
+        let key_multibase = ed25519_multibase(signer);
+        require!(
+            self.did_document == *did_key
+                && self.delegate == *signer
+                && self.scopes.contains(&scope)
+                && self.expires_at.is_none_or(|expires_at| now < expires_at)
+                && did_document.is_active()
+                && agent.did_address == Some(*did_key)
+                && agent.owner == Some(did_document.controller)
+                && did_document.verification_methods.iter().any(|method| {
+                    method.id == self.method_id
+                        && !method.revoked
+                        && method.public_key_multibase == key_multibase
+                }),
+            DidError::DelegateScopeDenied
+        );
+        Ok(())
+    }
+}
+
+/// Event emitted when a scoped delegate key is added or removed
+#[event]
+pub struct DidDelegateKeyUpdatedEvent {
+    pub did_document: Pubkey,
+    pub delegate: Pubkey,
+    pub method_id: String,
+    pub scopes: Vec<DidKeyScope>,
+    pub expires_at: Option<i64>,
+    pub removed: bool,
+    pub timestamp: i64,
+}
+
+/// One chunk of a DID document, returned by `resolve_did_document`
+///
+/// Concatenating `data` from chunks 0..total_chunks yields the Borsh
+/// serialization of `DidDocument` (without the account discriminator),
+/// whose keccak256 is `state_hash`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct DidResolutionChunk {
+    pub did_document: Pubkey,
+    pub version: u32,
+    pub deactivated: bool,
+    pub state_hash: [u8; 32],
+    pub total_len: u32,
+    pub chunk_index: u16,
+    pub total_chunks: u16,
+    pub data: Vec<u8>,
+}
+
+/// External DID methods that can be linked to a did:sol document
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExternalDidMethod {
+    /// `did:web:<domain>[:path]`
+    Web,
+    /// `did:key:z<multibase key>`
+    Key,
+    /// `did:ethr:[network:]0x<address>`
+    Ethr,
+}
+
+impl ExternalDidMethod {
+    /// Method of a well-formed external DID
+    pub fn parse(did: &str) -> Result<Self> {
+        require!(
+            did.len() <= MAX_EXTERNAL_DID && did.is_ascii() && !did.contains(char::is_whitespace),
+            DidError::InvalidExternalDid
+        );
+
+        if let Some(domain) = did.strip_prefix("did:web:") {
+            require!(!domain.is_empty(), DidError::InvalidExternalDid);
+            Ok(Self::Web)
+        } else if let Some(key) = did.strip_prefix("did:key:z") {
+            require!(
+                bs58::decode(key)
+                    .into_vec()
+                    .is_ok_and(|bytes| !bytes.is_empty()),
+                DidError::InvalidExternalDid
+            );
+            Ok(Self::Key)
+        } else if let Some(rest) = did.strip_prefix("did:ethr:") {
+            let address = rest.rsplit(':').next().unwrap_or_default();
+            require!(
+                address.len() == 42
+                    && address.starts_with("0x")
+                    && address[2..].bytes().all(|b| b.is_ascii_hexdigit()),
+                DidError::InvalidExternalDid
+            );
+            Ok(Self::Ethr)
+        } else {
+            err!(DidError::InvalidExternalDid)
+        }
+    }
+}
+
+/// Ed25519 key of a `did:key` identifier (multicodec 0xed01), if any
+pub fn did_key_ed25519(did: &str) -> Option<Pubkey> {
+    let bytes = bs58::decode(did.strip_prefix("did:key:z")?)
+        .into_vec()
+        .ok()?;
+    match bytes.as_slice() {
+        [0xed, 0x01, key @ ..] => Pubkey::try_from(key).ok(),
+        _ => None,
+    }
+}
+
+/// How an external DID link was verified
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExternalLinkProof {
+    /// Attested by a registered agent-identity issuer
+    Attestor(Pubkey),
+    /// The external `did:key` signed the link challenge
+    KeyChallenge,
+}
+
+/// Verified link between a did:sol document and an external DID
+///
+/// Seeds: ["did_external_link", did_document, keccak256(external_did)]
+///
+/// Credentials can cite the link as their `source_account` to carry
+/// cross-ecosystem identity claims.
+#[account]
+pub struct DidExternalLink {
+    /// did:sol document
+    pub did_document: Pubkey,
+
+    /// Linked external DID
+    pub external_did: String,
+
+    /// Method of the external DID
+    pub method: ExternalDidMethod,
+
+    /// How the link was verified
+    pub proof: ExternalLinkProof,
+
+    /// Bumped on every (re)link; bound into the key challenge
+    pub revision: u32,
+
+    /// Time of the latest verification
+    pub linked_at: i64,
+
+    /// Revoked by the controller
+    pub revoked: bool,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidExternalLink {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        4 + MAX_EXTERNAL_DID + // external_did
+        1 + // method
+        1 + 32 + // proof
+        4 + // revision
+        8 + // linked_at
+        1 + // revoked
+        1; // bump
+
+    /// PDA seed for an external DID (DIDs exceed the 32-byte seed limit)
+    pub fn seed_hash(external_did: &str) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(external_did.as_bytes());
+        hasher.finalize().into()
+    }
+
+    /// Bytes the external `did:key` signs
+    ///
+    /// domain || did_document || revision (LE) || external_did. Binding the
+    /// revision stops a challenge from re-linking after a revocation.
+    pub fn challenge(did_document: &Pubkey, revision: u32, external_did: &str) -> Vec<u8> {
+        let mut message = DID_EXTERNAL_LINK_DOMAIN.to_vec();
+        message.extend_from_slice(did_document.as_ref());
+        message.extend_from_slice(&revision.to_le_bytes());
+        message.extend_from_slice(external_did.as_bytes());
+        message
+    }
+
+    /// Revision the next (re)link will carry
+    pub fn next_revision(&self) -> Result<u32> {
+        Ok(self
+            .revision
+            .checked_add(1)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?)
+    }
+
+    /// Check the link is live for an active `did_document`
+    pub fn require_valid(&self, did_key: &Pubkey, did_document: &DidDocument) -> Result<()> {
+        require!(
+            self.did_document == *did_key && !self.revoked && did_document.is_active(),
+            DidError::ExternalLinkInvalid
+        );
+        Ok(())
+    }
+}
+
+/// Event emitted when an external DID link is recorded or revoked
+#[event]
+pub struct DidExternalLinkUpdatedEvent {
+    pub did_document: Pubkey,
+    pub external_did: String,
+    pub method: ExternalDidMethod,
+    pub proof: ExternalLinkProof,
+    pub revision: u32,
+    pub revoked: bool,
+    pub timestamp: i64,
+}
+
+/// DID → agent half of a reciprocal DID/agent binding
+///
+/// The agent half is `Agent::did_address`. A binding is trustworthy only
+/// while both halves point at each other and the DID controller still owns
+/// the agent; see `require_consistent`.
+#[account]
+pub struct DidAgentLink {
+    /// Linked DID document
+    pub did_document: Pubkey,
+
+    /// Linked agent account
+    pub agent: Pubkey,
+
+    /// Link timestamp
+    pub linked_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidAgentLink {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        32 + // agent
+        8 + // linked_at
+        1; // bump
+
+    /// Check that the link, DID and agent agree with each other
+    pub fn require_consistent(
+        &self,
+        did_key: &Pubkey,
+        did_document: &DidDocument,
+        agent_key: &Pubkey,
+        agent: &Agent,
+    ) -> Result<()> {
+        require!(
+            self.did_document == *did_key
+                && self.agent == *agent_key
+                && agent.did_address == Some(*did_key)
+                && agent.owner == Some(did_document.controller)
+                && did_document.is_active(),
+            DidError::AgentLinkMismatch
+        );
+        Ok(())
+    }
+}
+
+/// Event emitted when a DID and agent are linked
+#[event]
+pub struct DidAgentLinkedEvent {
+    pub did_document: Pubkey,
+    pub agent: Pubkey,
+    pub controller: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Pending two-step controller transfer
+///
+/// Proposed by the current controller and completed when the proposed
+/// controller accepts.
+#[account]
+pub struct DidControllerTransfer {
+    /// DID document being transferred
+    pub did_document: Pubkey,
+
+    /// Controller that proposed the transfer (refunded on close)
+    pub current_controller: Pubkey,
+
+    /// Controller that must accept
+    pub proposed_controller: Pubkey,
+
+    /// Proposal timestamp
+    pub proposed_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DidControllerTransfer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // did_document
+        32 + // current_controller
+        32 + // proposed_controller
+        8 + // proposed_at
+        1; // bump
+}
+
+/// Event emitted when a DID controller transfer is proposed
+#[event]
+pub struct DidControllerTransferProposedEvent {
+    pub did_document: Pubkey,
+    pub current_controller: Pubkey,
+    pub proposed_controller: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a DID controller transfer is accepted or cancelled
+#[event]
+pub struct DidControllerTransferResolvedEvent {
+    pub did_document: Pubkey,
+    pub previous_controller: Pubkey,
+    pub proposed_controller: Pubkey,
+    pub accepted: bool,
+    pub timestamp: i64,
+}
+
+/// Event emitted when guardians start a DID recovery
+#[event]
+pub struct DidRecoveryInitiatedEvent {
+    pub did_document: Pubkey,
+    pub new_controller: Pubkey,
+    pub approvals: Vec<Pubkey>,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a DID recovery is cancelled or executed
+#[event]
+pub struct DidRecoveryResolvedEvent {
+    pub did_document: Pubkey,
+    pub previous_controller: Pubkey,
+    pub new_controller: Pubkey,
+    pub executed: bool,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a DID key is rotated
+#[event]
+pub struct DidKeyRotatedEvent {
+    pub did_document: Pubkey,
+    pub rotation: Pubkey,
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub from_version: u32,
+    pub timestamp: i64,
+}
+
 /// DID Resolution Metadata
 ///
 /// Additional metadata for DID resolution
@@ -401,4 +1399,211 @@ pub enum DidError {
 
     #[msg("DID is deactivated")]
     DidDeactivated,
+
+    #[msg("Rotation keys must be active Ed25519 methods matching the signers")]
+    RotationKeyMismatch,
+
+    #[msg("Invalid DID controller set or threshold")]
+    InvalidControllerSet,
+
+    #[msg("Not enough DID controllers approved")]
+    InsufficientControllerApprovals,
+
+    #[msg("Invalid DID guardian set, threshold or recovery delay")]
+    InvalidGuardianSet,
+
+    #[msg("Not enough DID guardians approved")]
+    InsufficientGuardianApprovals,
+
+    #[msg("DID recovery challenge window has not elapsed")]
+    RecoveryChallengeActive,
+
+    #[msg("DID guardians were appointed by a previous controller")]
+    GuardianSetLapsed,
+
+    #[msg("DID and agent are not reciprocally linked under the same controller")]
+    AgentLinkMismatch,
+
+    #[msg("Delegate key is not authorized for this scope")]
+    DelegateScopeDenied,
+
+    #[msg("Invalid delegate key method or scopes")]
+    InvalidDelegateKey,
+
+    #[msg("Updated DID document exceeds its account space")]
+    DocumentTooLarge,
+
+    #[msg("Reactivation grace period out of range")]
+    InvalidGracePeriod,
+
+    #[msg("DID document is not deactivated")]
+    NotDeactivated,
+
+    #[msg("DID document is past its reactivation grace period")]
+    DidTombstoned,
+
+    #[msg("DID document is still active or within its reactivation grace period")]
+    DidNotTombstoned,
+
+    #[msg("Unsupported or malformed external DID")]
+    InvalidExternalDid,
+
+    #[msg("External DID cannot prove control on-chain; use an attestor")]
+    ExternalDidUnprovable,
+
+    #[msg("External DID link is revoked or its document is inactive")]
+    ExternalLinkInvalid,
+
+    #[msg("Service endpoint ID, description or URI is invalid for its type")]
+    InvalidServiceEndpoint,
+
+    #[msg("Resolution chunk index out of range")]
+    InvalidResolutionChunk,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_did_parsing() {
+        let did_key = "did:key:z6Mkevh7bMWWUda468bFjfFGtDxLXgkG8L46bsaXEwHD9Z3L";
+        assert_eq!(
+            ExternalDidMethod::parse("did:web:agents.example.com").unwrap(),
+            ExternalDidMethod::Web
+        );
+        assert_eq!(
+            ExternalDidMethod::parse(did_key).unwrap(),
+            ExternalDidMethod::Key
+        );
+        assert_eq!(
+            ExternalDidMethod::parse("did:ethr:base:0x52908400098527886E0F7030069857D2E4169EE7")
+                .unwrap(),
+            ExternalDidMethod::Ethr
+        );
+        for bad in [
+            "did:web:",
+            "did:key:z0OIl",
+            "did:ethr:0x1234",
+            "did:sol:devnet:abc",
+            "did:web:bad host",
+        ] {
+            assert!(ExternalDidMethod::parse(bad).is_err(), "{bad}");
+        }
+
+        // Only Ed25519 did:key identifiers yield a key for challenges
+        assert_eq!(
+            did_key_ed25519(did_key),
+            Some(Pubkey::new_from_array([7; 32]))
+        );
+        assert_eq!(
+            did_key_ed25519("did:key:zUS517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolution_chunks() {
+        let method = |id: usize| VerificationMethod {
+            id: format!("key-{id}"),
+            method_type: VerificationMethodType::Ed25519VerificationKey2020,
+            controller: "did:sol:devnet:controller".to_string(),
+            public_key_multibase: ed25519_multibase(&Pubkey::new_unique()),
+            relationships: vec![VerificationRelationship::Authentication],
+            created_at: 0,
+            revoked: false,
+        };
+        let document = DidDocument {
+            did: "did:sol:devnet:controller".to_string(),
+            controller: Pubkey::new_unique(),
+            verification_methods: (0..10).map(method).collect(),
+            service_endpoints: Vec::new(),
+            context: vec!["https://www.w3.org/ns/did/v1".to_string()],
+            also_known_as: Vec::new(),
+            created_at: 0,
+            updated_at: 0,
+            version: 3,
+            deactivated: false,
+            deactivated_at: None,
+            bump: 255,
+            reactivation_deadline: 0,
+        };
+        let did_key = Pubkey::new_unique();
+
+        let first = document.resolution_chunk(&did_key, 0).unwrap();
+        assert!(first.total_chunks > 1);
+        assert_eq!(first.state_hash, document.state_hash().unwrap());
+
+        let mut reassembled = Vec::new();
+        for index in 0..first.total_chunks {
+            let chunk = document.resolution_chunk(&did_key, index).unwrap();
+            assert!(chunk.try_to_vec().unwrap().len() <= 1024);
+            reassembled.extend(chunk.data);
+        }
+        assert_eq!(reassembled.len(), first.total_len as usize);
+        let decoded = DidDocument::deserialize(&mut reassembled.as_slice()).unwrap();
+        assert_eq!(decoded.version, 3);
+        assert_eq!(decoded.verification_methods.len(), 10);
+
+        assert!(document
+            .resolution_chunk(&did_key, first.total_chunks)
+            .is_err());
+    }
+
+    #[test]
+    fn test_service_endpoint_validation() {
+        let endpoint = |service_type, uri: &str| ServiceEndpoint {
+            id: "svc".to_string(),
+            service_type,
+            service_endpoint: uri.to_string(),
+            description: String::new(),
+        };
+
+        for (service_type, uri) in [
+            (
+                ServiceEndpointType::A2A,
+                "https://agent.example.com/.well-known/agent.json",
+            ),
+            (ServiceEndpointType::X402, "https://pay.example.com/x402"),
+            (ServiceEndpointType::MCP, "wss://mcp.example.com/sse"),
+            (ServiceEndpointType::Rest, "https://api.example.com/v1?x=1"),
+            (
+                ServiceEndpointType::DIDCommMessaging,
+                "did:web:mediator.example.com",
+            ),
+            (
+                ServiceEndpointType::CredentialRepository,
+                "ipfs://bafybeigdyrzt",
+            ),
+            (ServiceEndpointType::LinkedDomains, "https://example.com/"),
+            (ServiceEndpointType::Custom, "urn:isbn:0451450523"),
+        ] {
+            assert!(
+                endpoint(service_type.clone(), uri).validate().is_ok(),
+                "{uri}"
+            );
+        }
+
+        for (service_type, uri) in [
+            (ServiceEndpointType::A2A, "http://agent.example.com"),
+            (ServiceEndpointType::X402, "https://"),
+            (ServiceEndpointType::MCP, "ipfs://bafybeigdyrzt"),
+            (
+                ServiceEndpointType::Rest,
+                "https://api.example.com/with space",
+            ),
+            (ServiceEndpointType::DIDCommMessaging, "did:web"),
+            (
+                ServiceEndpointType::LinkedDomains,
+                "https://example.com/path",
+            ),
+            (ServiceEndpointType::Custom, "no-scheme"),
+        ] {
+            assert!(endpoint(service_type, uri).validate().is_err(), "{uri}");
+        }
+
+        let mut long_id = endpoint(ServiceEndpointType::Rest, "https://api.example.com");
+        long_id.id = "x".repeat(MAX_METHOD_ID + 1);
+        assert!(long_id.validate().is_err());
+    }
 }
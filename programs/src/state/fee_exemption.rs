@@ -0,0 +1,227 @@
+/*!
+ * Fee Exemption State
+ *
+ * Governance-managed allowlist of agents and owners that skip protocol
+ * fees: strategic partners, grants-program agents and protocol-owned
+ * agents. Each entry names the fee sources it covers and may expire.
+ * Fee-collecting instructions look up the paying agent's PDA and owner
+ * with `is_fee_exempt` before charging.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::fee_vault::FeeSource;
+use crate::GhostSpeakError;
+
+/// Seed for the fee exemption list PDA
+pub const FEE_EXEMPTION_LIST_SEED: &[u8] = b"fee_exemptions";
+
+/// Maximum number of exempt agents and owners
+pub const MAX_FEE_EXEMPTIONS: usize = 64;
+
+/// Why an agent or owner is exempt
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeExemptionReason {
+    StrategicPartner,
+    GrantsProgram,
+    ProtocolOwned,
+}
+
+/// One allowlisted agent PDA or owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeExemption {
+    /// Agent PDA or owner wallet
+    pub subject: Pubkey,
+    /// Bitmask of exempt fee sources, see `FeeSource::bit`
+    pub sources: u8,
+    pub reason: FeeExemptionReason,
+    /// When the exemption lapses (0 = never)
+    pub expires_at: i64,
+    pub granted_at: i64,
+}
+
+impl FeeExemption {
+    pub const SIZE: usize = 32 + // subject
+        1 + // sources
+        1 + // reason
+        8 + // expires_at
+        8; // granted_at
+
+    /// Whether this entry waives fees from `source` at `now`
+    pub fn covers(&self, source: FeeSource, now: i64) -> bool {
+        self.sources & source.bit() != 0 && (self.expires_at == 0 || now < self.expires_at)
+    }
+}
+
+impl FeeSource {
+    /// Bit for this source in an exemption's `sources` mask
+    pub fn bit(self) -> u8 {
+        1 << self.index()
+    }
+}
+
+/// Fee Exemption List - allowlist governed by a multisig
+///
+/// Seeds: ["fee_exemptions"]
+#[account]
+pub struct FeeExemptionList {
+    /// Protocol or DAO multisig that manages the list
+    pub multisig: Pubkey,
+    pub exemptions: Vec<FeeExemption>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl FeeExemptionList {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        4 + MAX_FEE_EXEMPTIONS * FeeExemption::SIZE + // exemptions
+        8 + // updated_at
+        1; // bump
+
+    /// Add `exemption`, replacing any existing entry for the same subject
+    pub fn upsert(&mut self, exemption: FeeExemption) -> Result<()> {
+        let all_sources = (1u8 << FeeSource::COUNT) - 1;
+        require!(
+            exemption.subject != Pubkey::default()
+                && exemption.sources != 0
+                && exemption.sources & !all_sources == 0
+                && (exemption.expires_at == 0 || exemption.expires_at > exemption.granted_at),
+            GhostSpeakError::InvalidFeeExemption
+        );
+
+        self.updated_at = exemption.granted_at;
+        match self
+            .exemptions
+            .iter_mut()
+            .find(|entry| entry.subject == exemption.subject)
+        {
+            Some(entry) => *entry = exemption,
+            None => {
+                require!(
+                    self.exemptions.len() < MAX_FEE_EXEMPTIONS,
+                    GhostSpeakError::FeeExemptionListFull
+                );
+                self.exemptions.push(exemption);
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the entry for `subject`
+    pub fn remove(&mut self, subject: &Pubkey, now: i64) -> Result<FeeExemption> {
+        let index = self
+            .exemptions
+            .iter()
+            .position(|entry| entry.subject == *subject)
+            .ok_or(GhostSpeakError::FeeExemptionNotFound)?;
+        self.updated_at = now;
+        Ok(self.exemptions.swap_remove(index))
+    }
+
+    /// Whether any of `subjects` is exempt from fees from `source`
+    pub fn is_exempt(&self, subjects: &[Pubkey], source: FeeSource, now: i64) -> bool {
+        self.exemptions
+            .iter()
+            .any(|entry| subjects.contains(&entry.subject) && entry.covers(source, now))
+    }
+}
+
+/// Check the exemption list without requiring it to exist; an uninitialized
+/// list exempts no one
+pub fn is_fee_exempt(
+    list: &AccountInfo,
+    subjects: &[Pubkey],
+    source: FeeSource,
+    now: i64,
+) -> Result<bool> {
+    if list.data_is_empty() {
+        return Ok(false);
+    }
+    require!(
+        list.owner == &crate::ID,
+        GhostSpeakError::InvalidAccountOwner
+    );
+    let data = list.try_borrow_data()?;
+    Ok(FeeExemptionList::try_deserialize(&mut &data[..])?.is_exempt(subjects, source, now))
+}
+
+#[event]
+pub struct FeeExemptionSetEvent {
+    pub subject: Pubkey,
+    pub sources: u8,
+    pub reason: FeeExemptionReason,
+    pub expires_at: i64,
+    pub multisig: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeExemptionRemovedEvent {
+    pub subject: Pubkey,
+    pub multisig: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeWaivedEvent {
+    pub subject: Pubkey,
+    pub source: FeeSource,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exemption(subject: Pubkey, sources: u8, expires_at: i64) -> FeeExemption {
+        FeeExemption {
+            subject,
+            sources,
+            reason: FeeExemptionReason::StrategicPartner,
+            expires_at,
+            granted_at: 10,
+        }
+    }
+
+    #[test]
+    fn test_exemption_lookup() {
+        let mut list = FeeExemptionList {
+            multisig: Pubkey::new_unique(),
+            exemptions: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+        let agent = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        assert!(list.upsert(exemption(agent, 0, 0)).is_err());
+        assert!(list.upsert(exemption(agent, 1 << 7, 0)).is_err());
+        assert!(list
+            .upsert(exemption(agent, FeeSource::Escrow.bit(), 5))
+            .is_err());
+
+        let sources = FeeSource::Registration.bit() | FeeSource::Dispute.bit();
+        list.upsert(exemption(agent, sources, 100)).unwrap();
+        list.upsert(exemption(owner, FeeSource::Escrow.bit(), 0))
+            .unwrap();
+
+        assert!(list.is_exempt(&[agent, owner], FeeSource::Registration, 50));
+        assert!(list.is_exempt(&[other, owner], FeeSource::Escrow, 1_000));
+        assert!(!list.is_exempt(&[agent], FeeSource::Escrow, 50));
+        assert!(!list.is_exempt(&[agent], FeeSource::Dispute, 100));
+        assert!(!list.is_exempt(&[other], FeeSource::Dispute, 50));
+
+        // Re-granting replaces the entry rather than adding one
+        list.upsert(exemption(agent, FeeSource::Escrow.bit(), 0))
+            .unwrap();
+        assert_eq!(list.exemptions.len(), 2);
+        assert!(!list.is_exempt(&[agent], FeeSource::Registration, 50));
+
+        list.remove(&owner, 20).unwrap();
+        assert!(list.remove(&owner, 20).is_err());
+        assert!(!list.is_exempt(&[owner], FeeSource::Escrow, 50));
+    }
+}
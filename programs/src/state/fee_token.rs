@@ -0,0 +1,228 @@
+/*!
+ * Fee Payment Token State
+ *
+ * Tokens accepted for SOL-denominated protocol fees (agent registration),
+ * each with the Pyth USD price feed used to convert the fee.
+ * SOL itself is registered under the system program ID, carrying the
+ * SOL/USD feed every conversion starts from. A fee of `lamports` costs
+ * `lamports * SOL/USD / token/USD` in the token, rounded up, and is paid
+ * into the token's fee vault. Buybacks price their swaps from the USDC and
+ * GHOST entries' feeds.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::fee_vault::FeeSource;
+use crate::utils::pyth::{read_pyth_price, OraclePrice};
+use crate::GhostSpeakError;
+
+/// Seed for fee payment tokens: [seed, mint] (system program ID for SOL)
+pub const FEE_PAYMENT_TOKEN_SEED: &[u8] = b"fee_payment_token";
+
+/// Widest confidence interval governance may accept (5% of the price)
+pub const MAX_ORACLE_CONFIDENCE_BPS: u16 = 500;
+
+/// Oldest price update governance may accept (10 minutes)
+pub const MAX_ORACLE_PRICE_AGE: u32 = 600;
+
+/// SOL decimals, for converting lamports
+const SOL_DECIMALS: i32 = 9;
+
+/// Fee Payment Token - a token accepted for protocol fees
+///
+/// Seeds: ["fee_payment_token", mint]
+#[account]
+pub struct FeePaymentToken {
+    /// Token mint, or the system program ID for SOL
+    pub mint: Pubkey,
+    /// Pyth feed ID of the token's USD price
+    pub price_feed_id: [u8; 32],
+    /// Oldest price update accepted, in seconds
+    pub max_price_age: u32,
+    /// Widest confidence interval accepted, in basis points of the price
+    pub max_confidence_bps: u16,
+    /// Whether fees may currently be paid in this token
+    pub enabled: bool,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl FeePaymentToken {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // price_feed_id
+        4 + // max_price_age
+        2 + // max_confidence_bps
+        1 + // enabled
+        8 + // updated_at
+        1; // bump
+
+    pub fn validate(max_price_age: u32, max_confidence_bps: u16) -> Result<()> {
+        require!(
+            max_price_age > 0
+                && max_price_age <= MAX_ORACLE_PRICE_AGE
+                && max_confidence_bps <= MAX_ORACLE_CONFIDENCE_BPS,
+            GhostSpeakError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Read this token's USD price from `update`
+    pub fn read_price(&self, update: &AccountInfo, now: i64) -> Result<OraclePrice> {
+        read_pyth_price(
+            update,
+            &self.price_feed_id,
+            self.max_price_age as i64,
+            self.max_confidence_bps,
+            now,
+        )
+    }
+}
+
+/// Token amount owed for a SOL-denominated fee, returned by `quote_fee_in_token`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeTokenQuote {
+    pub mint: Pubkey,
+    pub lamports: u64,
+    pub token_amount: u64,
+    pub sol_price: OraclePrice,
+    pub token_price: OraclePrice,
+}
+
+/// Convert `lamports` into base units of a token with `decimals`, rounding up
+pub fn lamports_to_token_amount(
+    lamports: u64,
+    sol_price: &OraclePrice,
+    token_price: &OraclePrice,
+    decimals: u8,
+) -> Result<u64> {
+    let (numerator, denominator) =
+        conversion_ratio(lamports, sol_price, SOL_DECIMALS, token_price, decimals as i32)?;
+    let amount = numerator.div_ceil(denominator);
+    Ok(u64::try_from(amount).map_err(|_| GhostSpeakError::ArithmeticOverflow)?)
+}
+
+/// Convert `amount` base units of a token with `from_decimals` into base
+/// units of one with `to_decimals` at their USD prices, rounding down
+pub fn convert_token_amount(
+    amount: u64,
+    from_price: &OraclePrice,
+    from_decimals: u8,
+    to_price: &OraclePrice,
+    to_decimals: u8,
+) -> Result<u64> {
+    let (numerator, denominator) = conversion_ratio(
+        amount,
+        from_price,
+        from_decimals as i32,
+        to_price,
+        to_decimals as i32,
+    )?;
+    Ok(u64::try_from(numerator / denominator).map_err(|_| GhostSpeakError::ArithmeticOverflow)?)
+}
+
+/// `amount` in the target token as a fraction, before rounding
+fn conversion_ratio(
+    amount: u64,
+    from_price: &OraclePrice,
+    from_decimals: i32,
+    to_price: &OraclePrice,
+    to_decimals: i32,
+) -> Result<(u128, u128)> {
+    let scale = from_price.exponent - to_price.exponent + to_decimals - from_decimals;
+    let pow = |exp: i32| {
+        10u128
+            .checked_pow(exp.unsigned_abs())
+            .ok_or(GhostSpeakError::ArithmeticOverflow)
+    };
+
+    let mut numerator = (amount as u128)
+        .checked_mul(from_price.price as u128)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    let mut denominator = to_price.price as u128;
+    if scale >= 0 {
+        numerator = numerator
+            .checked_mul(pow(scale)?)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    } else {
+        denominator = denominator
+            .checked_mul(pow(scale)?)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+    }
+    Ok((numerator, denominator))
+}
+
+#[event]
+pub struct FeePaidInTokenEvent {
+    pub payer: Pubkey,
+    pub source: FeeSource,
+    pub mint: Pubkey,
+    pub lamports: u64,
+    pub token_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeePaymentTokenUpdatedEvent {
+    pub mint: Pubkey,
+    pub price_feed_id: [u8; 32],
+    pub max_price_age: u32,
+    pub max_confidence_bps: u16,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(price: u64, exponent: i32) -> OraclePrice {
+        OraclePrice {
+            price,
+            conf: 0,
+            exponent,
+            publish_time: 0,
+        }
+    }
+
+    #[test]
+    fn test_lamports_to_token_amount() {
+        // SOL at $150.00000000, USDC at $1.00000000 (6 decimals)
+        let sol = price(15_000_000_000, -8);
+        let usdc = price(100_000_000, -8);
+        // 0.01 SOL = $1.50
+        assert_eq!(
+            lamports_to_token_amount(10_000_000, &sol, &usdc, 6).unwrap(),
+            1_500_000
+        );
+
+        // GHOST at $0.0300 (exponent -4, 9 decimals): $1.50 = 50 GHOST
+        let ghost = price(300, -4);
+        assert_eq!(
+            lamports_to_token_amount(10_000_000, &sol, &ghost, 9).unwrap(),
+            50_000_000_000
+        );
+
+        // Rounds up in the protocol's favor
+        let usdc_high = price(300_000_000, -8);
+        assert_eq!(lamports_to_token_amount(1, &sol, &usdc_high, 6).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_convert_token_amount() {
+        // 1.5 USDC at $1.00000000 buys 50 GHOST at $0.0300
+        let usdc = price(100_000_000, -8);
+        let ghost = price(300, -4);
+        assert_eq!(
+            convert_token_amount(1_500_000, &usdc, 6, &ghost, 9).unwrap(),
+            50_000_000_000
+        );
+        assert_eq!(
+            convert_token_amount(50_000_000_000, &ghost, 9, &usdc, 6).unwrap(),
+            1_500_000
+        );
+
+        // Rounds down
+        assert_eq!(convert_token_amount(1, &ghost, 9, &usdc, 6).unwrap(), 0);
+    }
+}
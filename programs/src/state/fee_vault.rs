@@ -0,0 +1,168 @@
+/*!
+ * Fee Vault State
+ *
+ * Per-token vaults that protocol fees are paid into, with a running total
+ * of what accrued from each fee source and what the treasury multisig has
+ * withdrawn, so treasury inflows can be audited on-chain.
+ *
+ * The SOL vault (keyed by the system program ID) holds lamports on the
+ * vault PDA itself; SPL token vaults hold their balance in a token account
+ * owned by the vault PDA.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Seed for fee vaults: [seed, mint] (system program ID for SOL)
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+
+/// Where a fee came from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeSource {
+    /// Escrow completion fees
+    Escrow,
+    /// Agent registration fees
+    Registration,
+    /// Dispute resolution fees
+    Dispute,
+    /// Protocol share of credential issuance fees
+    CredentialIssuance,
+}
+
+impl FeeSource {
+    /// Number of fee sources, for the accrual table
+    pub const COUNT: usize = 4;
+
+    /// Position of this source in the accrual table
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Fee Vault - protocol fees in one token
+///
+/// Seeds: ["fee_vault", mint]
+#[account]
+pub struct FeeVault {
+    /// Token mint, or the system program ID for SOL
+    pub mint: Pubkey,
+    /// Token account holding the fees (default for SOL)
+    pub token_account: Pubkey,
+    /// Treasury multisig that approves withdrawals
+    pub multisig: Pubkey,
+    /// Total accrued per source, indexed by `FeeSource`
+    pub accrued: [u64; FeeSource::COUNT],
+    /// Total withdrawn by the treasury multisig
+    pub withdrawn: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl FeeVault {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // token_account
+        32 + // multisig
+        8 * FeeSource::COUNT + // accrued
+        8 + // withdrawn
+        8 + // updated_at
+        1 + // bump
+        32; // reserved
+
+    /// Whether this vault holds SOL rather than an SPL token
+    pub fn is_native(&self) -> bool {
+        self.mint == anchor_lang::system_program::ID
+    }
+
+    /// Record `amount` paid into the vault from `source`
+    pub fn accrue(&mut self, source: FeeSource, amount: u64, now: i64) -> Result<()> {
+        let accrued = &mut self.accrued[source.index()];
+        *accrued = accrued
+            .checked_add(amount)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Total accrued across all sources
+    pub fn total_accrued(&self) -> u128 {
+        self.accrued.iter().map(|amount| *amount as u128).sum()
+    }
+
+    /// Record a withdrawal of `amount` out of an available `balance`
+    pub fn withdraw(&mut self, amount: u64, balance: u64, now: i64) -> Result<()> {
+        require!(amount > 0, GhostSpeakError::InvalidAmount);
+        require!(
+            amount <= balance,
+            GhostSpeakError::InsufficientFeeVaultBalance
+        );
+        self.withdrawn = self
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct FeeVaultInitializedEvent {
+    pub fee_vault: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub multisig: Pubkey,
+}
+
+#[event]
+pub struct FeeAccruedEvent {
+    pub fee_vault: Pubkey,
+    pub source: FeeSource,
+    pub amount: u64,
+    pub total_for_source: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawnEvent {
+    pub fee_vault: Pubkey,
+    pub multisig: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accrual_and_withdrawal() {
+        let mut vault = FeeVault {
+            mint: anchor_lang::system_program::ID,
+            token_account: Pubkey::default(),
+            multisig: Pubkey::new_unique(),
+            accrued: [0; FeeSource::COUNT],
+            withdrawn: 0,
+            updated_at: 0,
+            bump: 255,
+            reserved: [0; 32],
+        };
+        assert!(vault.is_native());
+
+        vault.accrue(FeeSource::CredentialIssuance, 300, 1).unwrap();
+        vault.accrue(FeeSource::Escrow, 200, 2).unwrap();
+        vault.accrue(FeeSource::CredentialIssuance, 100, 3).unwrap();
+        assert_eq!(vault.accrued[FeeSource::CredentialIssuance.index()], 400);
+        assert_eq!(vault.total_accrued(), 600);
+        assert!(vault.accrue(FeeSource::Escrow, u64::MAX, 4).is_err());
+
+        assert!(vault.withdraw(0, 600, 5).is_err());
+        assert!(vault.withdraw(601, 600, 5).is_err());
+        vault.withdraw(250, 600, 5).unwrap();
+        assert_eq!(vault.withdrawn, 250);
+        assert_eq!(vault.updated_at, 5);
+    }
+}
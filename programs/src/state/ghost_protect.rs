@@ -6,6 +6,8 @@
 
 use anchor_lang::prelude::*;
 
+use crate::state::staking::AccessTier;
+
 /// Individual escrow account for agent service payments
 #[account]
 pub struct GhostProtectEscrow {
@@ -49,6 +51,48 @@ pub struct GhostProtectEscrow {
     pub arbitrator_decision: Option<ArbitratorDecision>,
 
     pub bump: u8,
+
+    /// Layout version; fields below exist only from `CURRENT_VERSION` on,
+    /// and older escrows gain them through `migrate_escrow`
+    pub version: u8,
+
+    /// Portion released to the agent at creation (trial escrows only)
+    pub upfront_amount: u64,
+
+    /// Agent stake reserved to cover the upfront portion (trial escrows only)
+    pub trial_bond: u64,
+
+    /// Whether the trial bond was clawed back to the client
+    pub bond_clawed_back: bool,
+
+    /// Program whose PDA is the client (escrows opened via CPI only)
+    pub origin_program: Option<Pubkey>,
+
+    /// Referrer credited with a share of this escrow's fees
+    pub referrer: Option<Pubkey>,
+
+    /// Lamports the client bonded to file a dispute, held on this account
+    /// until the ruling (0 once settled)
+    pub dispute_bond: u64,
+}
+
+/// Escrow as written before the layout version
+#[derive(AnchorDeserialize)]
+pub struct GhostProtectEscrowV0 {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub token_mint: Pubkey,
+    pub status: EscrowStatus,
+    pub job_description: String,
+    pub delivery_proof: Option<String>,
+    pub deadline: i64,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+    pub dispute_reason: Option<String>,
+    pub arbitrator_decision: Option<ArbitratorDecision>,
+    pub bump: u8,
 }
 
 impl GhostProtectEscrow {
@@ -71,7 +115,102 @@ impl GhostProtectEscrow {
         1 + 8 + // completed_at Option<i64>
         1 + 4 + Self::MAX_DISPUTE_REASON_LEN + // dispute_reason Option<String>
         1 + (1 + 4 + Self::MAX_DECISION_REASON_LEN) + // arbitrator_decision (enum + optional reason)
-        1;   // bump
+        1 +  // bump
+        Self::V1_EXTENSION_LEN; // version, trial, CPI, referral and dispute bond fields
+
+    /// Layout with `version` and the trial, CPI, referral and dispute bond
+    /// fields after `bump`
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Bytes the version and its fields add to escrows created before them
+    pub const V1_EXTENSION_LEN: usize = 1 + // version
+        8 +  // upfront_amount
+        8 +  // trial_bond
+        1 +  // bond_clawed_back
+        1 + 32 + // origin_program Option<Pubkey>
+        1 + 32 + // referrer Option<Pubkey>
+        8;   // dispute_bond
+
+    /// Whether this escrow, `data_len` bytes long, has the current layout
+    ///
+    /// Escrows created before the version field are exactly
+    /// `V1_EXTENSION_LEN` bytes short of `LEN`, so their trailing bytes are
+    /// never trusted as a version.
+    pub fn is_current(&self, data_len: usize) -> bool {
+        data_len >= Self::LEN && self.version == Self::CURRENT_VERSION
+    }
+
+    /// A pre-version escrow in the current layout: a regular escrow opened
+    /// directly by its client, with no referrer and no dispute bond
+    pub fn from_v0(legacy: GhostProtectEscrowV0) -> Self {
+        Self {
+            escrow_id: legacy.escrow_id,
+            client: legacy.client,
+            agent: legacy.agent,
+            amount: legacy.amount,
+            token_mint: legacy.token_mint,
+            status: legacy.status,
+            job_description: legacy.job_description,
+            delivery_proof: legacy.delivery_proof,
+            deadline: legacy.deadline,
+            created_at: legacy.created_at,
+            completed_at: legacy.completed_at,
+            dispute_reason: legacy.dispute_reason,
+            arbitrator_decision: legacy.arbitrator_decision,
+            bump: legacy.bump,
+            version: Self::CURRENT_VERSION,
+            upfront_amount: 0,
+            trial_bond: 0,
+            bond_clawed_back: false,
+            origin_program: None,
+            referrer: None,
+            dispute_bond: 0,
+        }
+    }
+
+    /// Maximum number of PDA seeds accepted to prove a CPI client
+    pub const MAX_CPI_CLIENT_SEEDS: usize = 16;
+
+    /// Default maximum share of a trial escrow released upfront (25%),
+    /// governed by `ParameterKey::TrialMaxUpfrontBps`
+    pub const MAX_TRIAL_UPFRONT_BPS: u16 = 2_500;
+
+    /// Default dispute bond (0.01 SOL), governed by
+    /// `ParameterKey::DisputeBondLamports`
+    pub const DEFAULT_DISPUTE_BOND: u64 = 10_000_000;
+
+    pub fn is_trial(&self) -> bool {
+        self.upfront_amount > 0
+    }
+
+    /// Amount still held in the vault (total minus any upfront release)
+    pub fn held_amount(&self) -> u64 {
+        self.amount.saturating_sub(self.upfront_amount)
+    }
+
+    /// Bond to claw back for `decision`, if any
+    ///
+    /// Only a full ruling for the client (total non-delivery) claws back,
+    /// at most once, and never more than the upfront share it recovers.
+    pub fn clawback_amount(&self, decision: &ArbitratorDecision) -> u64 {
+        match decision {
+            ArbitratorDecision::FavorClient { .. } if self.is_trial() && !self.bond_clawed_back => {
+                self.upfront_amount.min(self.trial_bond)
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// Split a trial escrow's total into (upfront, held) amounts, with at most
+/// `max_upfront_bps` upfront
+pub fn trial_split(amount: u64, upfront_bps: u16, max_upfront_bps: u64) -> Result<(u64, u64)> {
+    require!(
+        upfront_bps > 0 && upfront_bps as u64 <= max_upfront_bps,
+        crate::GhostSpeakError::TrialUpfrontTooLarge
+    );
+    let upfront = (amount as u128 * upfront_bps as u128 / 10_000) as u64;
+    require!(upfront > 0, crate::GhostSpeakError::InvalidAmount);
+    Ok((upfront, amount - upfront))
 }
 
 /// Escrow lifecycle states
@@ -98,10 +237,371 @@ pub enum ArbitratorDecision {
     Split { client_percentage: u8, reason: String },
 }
 
+impl ArbitratorDecision {
+    /// Split an escrowed amount into (client_amount, agent_amount)
+    pub fn payout_split(&self, amount: u64) -> Result<(u64, u64)> {
+        match self {
+            ArbitratorDecision::FavorClient { .. } => Ok((amount, 0u64)),
+            ArbitratorDecision::FavorAgent { .. } => Ok((0u64, amount)),
+            ArbitratorDecision::Split { client_percentage, .. } => {
+                require!(
+                    *client_percentage <= 100,
+                    crate::GhostSpeakError::InvalidPercentage
+                );
+                let client_amt = (amount as u128 * *client_percentage as u128 / 100) as u64;
+                let agent_amt = amount - client_amt;
+                Ok((client_amt, agent_amt))
+            }
+        }
+    }
+
+    /// Whether the ruling sides with the client at least in part; a dispute
+    /// ruled wholly for the agent is frivolous and forfeits its bond
+    pub fn upholds_dispute(&self) -> bool {
+        match self {
+            ArbitratorDecision::FavorClient { .. } => true,
+            ArbitratorDecision::FavorAgent { .. } => false,
+            ArbitratorDecision::Split { client_percentage, .. } => *client_percentage > 0,
+        }
+    }
+}
+
+/// Client-side (buyer) reputation, mirrored from Ghost Protect outcomes
+///
+/// Lets agents assess a counterparty's payment reliability, dispute
+/// frequency and cancellation rate before accepting a job.
+///
+/// Seeds: ["client_reputation", client]
+#[account]
+pub struct ClientReputation {
+    /// Client (payer) this record describes
+    pub client: Pubkey,
+    /// Escrows created by the client
+    pub total_escrows: u32,
+    /// Escrows the client approved and paid out
+    pub completed_escrows: u32,
+    /// Disputes filed by the client
+    pub disputes_filed: u32,
+    /// Disputes resolved fully in the client's favor
+    pub disputes_won: u32,
+    /// Disputes resolved fully in the agent's favor
+    pub disputes_lost: u32,
+    /// Escrows cancelled by the client before delivery
+    pub cancellations: u32,
+    /// Total amount escrowed by the client
+    pub total_volume: u64,
+    /// Last Ghost Protect activity
+    pub last_activity: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl ClientReputation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // client
+        4 +  // total_escrows
+        4 +  // completed_escrows
+        4 +  // disputes_filed
+        4 +  // disputes_won
+        4 +  // disputes_lost
+        4 +  // cancellations
+        8 +  // total_volume
+        8 +  // last_activity
+        8 +  // created_at
+        1;   // bump
+
+    /// Set up a freshly created record (no-op if already initialized)
+    pub fn initialize_if_needed(&mut self, client: Pubkey, bump: u8, now: i64) {
+        if self.client == Pubkey::default() {
+            self.client = client;
+            self.created_at = now;
+            self.bump = bump;
+        }
+    }
+
+    pub fn record_escrow_created(&mut self, amount: u64, now: i64) {
+        self.total_escrows = self.total_escrows.saturating_add(1);
+        self.total_volume = self.total_volume.saturating_add(amount);
+        self.last_activity = now;
+    }
+
+    pub fn record_escrow_completed(&mut self, now: i64) {
+        self.completed_escrows = self.completed_escrows.saturating_add(1);
+        self.last_activity = now;
+    }
+
+    pub fn record_dispute_filed(&mut self, now: i64) {
+        self.disputes_filed = self.disputes_filed.saturating_add(1);
+        self.last_activity = now;
+    }
+
+    /// Splits count as neither won nor lost
+    pub fn record_dispute_resolved(&mut self, decision: &ArbitratorDecision, now: i64) {
+        match decision {
+            ArbitratorDecision::FavorClient { .. } => {
+                self.disputes_won = self.disputes_won.saturating_add(1)
+            }
+            ArbitratorDecision::FavorAgent { .. } => {
+                self.disputes_lost = self.disputes_lost.saturating_add(1)
+            }
+            ArbitratorDecision::Split { .. } => {}
+        }
+        self.last_activity = now;
+    }
+
+    pub fn record_cancellation(&mut self, now: i64) {
+        self.cancellations = self.cancellations.saturating_add(1);
+        self.last_activity = now;
+    }
+
+    /// Share of finished escrows the client paid without losing a dispute
+    /// or cancelling (basis points, 10000 when there is no history)
+    pub fn payment_reliability_bps(&self) -> u16 {
+        let finished = self.completed_escrows as u64
+            + self.disputes_lost as u64
+            + self.cancellations as u64;
+        if finished == 0 {
+            return 10_000;
+        }
+        (self.completed_escrows as u64 * 10_000 / finished) as u16
+    }
+
+    /// Disputes filed per escrow created (basis points)
+    pub fn dispute_rate_bps(&self) -> u16 {
+        Self::rate_bps(self.disputes_filed, self.total_escrows)
+    }
+
+    /// Cancellations per escrow created (basis points)
+    pub fn cancellation_rate_bps(&self) -> u16 {
+        Self::rate_bps(self.cancellations, self.total_escrows)
+    }
+
+    fn rate_bps(count: u32, total: u32) -> u16 {
+        if total == 0 {
+            return 0;
+        }
+        (count.min(total) as u64 * 10_000 / total as u64) as u16
+    }
+}
+
+/// Seed for agent settlement stats: [seed, agent]
+pub const AGENT_SETTLEMENT_STATS_SEED: &[u8] = b"agent_settlement_stats";
+
+/// Days of settled volume that set an agent's escrow fee rate
+pub const SETTLEMENT_VOLUME_WINDOW_DAYS: usize = 30;
+
+/// Volume settled to an agent on one day
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DailySettlement {
+    /// Day number since the Unix epoch this entry holds
+    pub day: u32,
+    pub volume: u64,
+}
+
+impl DailySettlement {
+    pub const LEN: usize = 4 + // day
+        8; // volume
+}
+
+/// Agent Settlement Stats - escrow payouts released to an agent
+///
+/// Keeps a ring of daily totals so the trailing 30-day settled volume,
+/// which sets the agent's escrow fee rate on the volume fee curve, can be
+/// read without history.
+///
+/// Seeds: ["agent_settlement_stats", agent]
+#[account]
+pub struct AgentSettlementStats {
+    /// Agent PDA this record describes
+    pub agent: Pubkey,
+    /// Escrows that paid the agent on completion or by ruling
+    pub settled_escrows: u32,
+    /// All-time amount released to the agent
+    pub total_settled_volume: u64,
+    /// Daily totals indexed by day number modulo the window
+    pub daily: [DailySettlement; SETTLEMENT_VOLUME_WINDOW_DAYS],
+    pub last_settled_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl AgentSettlementStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        4 +  // settled_escrows
+        8 +  // total_settled_volume
+        DailySettlement::LEN * SETTLEMENT_VOLUME_WINDOW_DAYS + // daily
+        8 +  // last_settled_at
+        8 +  // created_at
+        1;   // bump
+
+    /// Set up a freshly created record (no-op if already initialized)
+    pub fn initialize_if_needed(&mut self, agent: Pubkey, bump: u8, now: i64) {
+        if self.agent == Pubkey::default() {
+            self.agent = agent;
+            self.created_at = now;
+            self.bump = bump;
+        }
+    }
+
+    fn day(now: i64) -> u32 {
+        (now.max(0) / 86_400) as u32
+    }
+
+    /// Record `amount` released to the agent
+    pub fn record_settlement(&mut self, amount: u64, now: i64) {
+        let day = Self::day(now);
+        let entry = &mut self.daily[day as usize % SETTLEMENT_VOLUME_WINDOW_DAYS];
+        if entry.day != day {
+            *entry = DailySettlement { day, volume: 0 };
+        }
+        entry.volume = entry.volume.saturating_add(amount);
+
+        self.settled_escrows = self.settled_escrows.saturating_add(1);
+        self.total_settled_volume = self.total_settled_volume.saturating_add(amount);
+        self.last_settled_at = now;
+    }
+
+    /// Volume settled over the last 30 days, today included
+    pub fn trailing_volume(&self, now: i64) -> u64 {
+        let today = Self::day(now);
+        self.daily
+            .iter()
+            .filter(|entry| {
+                entry.day <= today && today - entry.day < SETTLEMENT_VOLUME_WINDOW_DAYS as u32
+            })
+            .fold(0u64, |total, entry| total.saturating_add(entry.volume))
+    }
+}
+
+/// Read an agent's settlement stats PDA, or `None` if it was never created
+pub fn read_agent_settlement_stats(stats: &AccountInfo) -> Result<Option<AgentSettlementStats>> {
+    if stats.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        stats.owner == &crate::ID,
+        crate::GhostSpeakError::InvalidAccountOwner
+    );
+    let data = stats.try_borrow_data()?;
+    Ok(Some(AgentSettlementStats::try_deserialize(&mut &data[..])?))
+}
+
+/// Seed for the global dispute queue PDA
+pub const DISPUTE_QUEUE_SEED: &[u8] = b"dispute_queue";
+
+/// A dispute waiting for arbitration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisputeQueueEntry {
+    pub escrow: Pubkey,
+    /// Client's staking tier when the dispute was filed
+    pub tier: AccessTier,
+    pub filed_at: i64,
+    /// Filing time minus the tier's head start (lower is served first)
+    pub priority_key: i64,
+    /// Time by which the tier's SLA expects a ruling
+    pub sla_deadline: i64,
+}
+
+impl DisputeQueueEntry {
+    pub const LEN: usize = 32 + 1 + 8 + 8 + 8;
+}
+
+/// Open disputes in arbitration order
+///
+/// Higher staking tiers get a head start on their filing time, so they sit
+/// earlier in the queue. Arbitrators must rule on the head of the queue,
+/// except that any dispute past its SLA deadline may be ruled on at once.
+///
+/// Seeds: ["dispute_queue"]
+#[account]
+pub struct DisputeQueue {
+    pub entries: Vec<DisputeQueueEntry>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl DisputeQueue {
+    pub const MAX_ENTRIES: usize = 64;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + DisputeQueueEntry::LEN * Self::MAX_ENTRIES + // entries
+        8 + // updated_at
+        1; // bump
+
+    /// Insert behind every entry with an equal or lower key; returns the position
+    pub fn insert(&mut self, entry: DisputeQueueEntry, now: i64) -> Result<usize> {
+        require!(
+            self.entries.len() < Self::MAX_ENTRIES,
+            crate::GhostSpeakError::DisputeQueueFull
+        );
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.priority_key > entry.priority_key)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(position, entry);
+        self.updated_at = now;
+        Ok(position)
+    }
+
+    /// Position of `escrow`, which may be ruled on if next in line or overdue
+    pub fn ruling_position(&self, escrow: &Pubkey, now: i64) -> Result<usize> {
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.escrow == *escrow)
+            .ok_or(crate::GhostSpeakError::DisputeNotQueued)?;
+        require!(
+            position == 0 || self.entries[position].sla_deadline <= now,
+            crate::GhostSpeakError::DisputeNotNextInQueue
+        );
+        Ok(position)
+    }
+
+    /// Remove `escrow` if it is next in line or overdue
+    pub fn take_for_ruling(&mut self, escrow: &Pubkey, now: i64) -> Result<DisputeQueueEntry> {
+        let position = self.ruling_position(escrow, now)?;
+        self.updated_at = now;
+        Ok(self.entries.remove(position))
+    }
+}
+
+/// Projected effect of an arbitration, returned by `simulate_arbitrate_dispute`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ArbitrationPreview {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub client_amount: u64,
+    /// Paid to the agent, net of the escrow fee
+    pub agent_amount: u64,
+    /// Dispute resolution fee taken before the split
+    pub dispute_fee: u64,
+    /// Escrow fee on the agent's share, including any referrer's cut
+    pub escrow_fee: u64,
+    /// Stake clawed back from a trial bond to the client
+    pub trial_bond_clawback: u64,
+    pub vault_balance_before: u64,
+    pub vault_balance_after: u64,
+    /// Dispute bond the ruling settles (0 if none is posted)
+    pub dispute_bond: u64,
+    /// Whether the bond goes back to the client rather than to the agent owner
+    pub bond_refunded: bool,
+    pub bond_recipient: Pubkey,
+}
+
 // =====================================================
 // GHOST PROTECT EVENTS
 // =====================================================
 
+#[event]
+pub struct EscrowMigratedEvent {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub version: u8,
+}
+
 #[event]
 pub struct EscrowCreatedEvent {
     pub escrow_id: u64,
@@ -130,6 +630,15 @@ pub struct DisputeFiledEvent {
     pub escrow_id: u64,
     pub client: Pubkey,
     pub reason: String,
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct DisputeQueuedEvent {
+    pub escrow_id: u64,
+    pub tier: AccessTier,
+    pub position: u8,
+    pub sla_deadline: i64,
 }
 
 #[event]
@@ -138,3 +647,310 @@ pub struct DisputeResolvedEvent {
     pub decision: ArbitratorDecision,
     pub arbitrator: Pubkey,
 }
+
+#[event]
+pub struct DisputeBondSettledEvent {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    /// True if refunded to the client, false if forfeited to the agent
+    pub refunded: bool,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClientReputationUpdatedEvent {
+    pub client: Pubkey,
+    pub escrow_id: u64,
+    pub total_escrows: u32,
+    pub payment_reliability_bps: u16,
+    pub dispute_rate_bps: u16,
+    pub cancellation_rate_bps: u16,
+}
+
+#[event]
+pub struct AgentSettlementRecordedEvent {
+    pub agent: Pubkey,
+    pub escrow_id: u64,
+    pub amount: u64,
+    pub trailing_volume: u64,
+}
+
+#[event]
+pub struct EscrowCancelledEvent {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowCreatedViaCpiEvent {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub origin_program: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct TrialEscrowCreatedEvent {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    pub upfront_amount: u64,
+    pub trial_bond: u64,
+}
+
+#[event]
+pub struct TrialBondClawedBackEvent {
+    pub escrow_id: u64,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_reputation() -> ClientReputation {
+        ClientReputation {
+            client: Pubkey::new_unique(),
+            total_escrows: 0,
+            completed_escrows: 0,
+            disputes_filed: 0,
+            disputes_won: 0,
+            disputes_lost: 0,
+            cancellations: 0,
+            total_volume: 0,
+            last_activity: 0,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    fn queue_entry(priority_key: i64, sla_deadline: i64) -> DisputeQueueEntry {
+        DisputeQueueEntry {
+            escrow: Pubkey::new_unique(),
+            tier: AccessTier::None,
+            filed_at: priority_key,
+            priority_key,
+            sla_deadline,
+        }
+    }
+
+    #[test]
+    fn test_dispute_queue_ordering() {
+        let mut queue = DisputeQueue {
+            entries: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+
+        let early = queue_entry(100, 1_000);
+        let late = queue_entry(200, 500);
+        let tied = queue_entry(100, 2_000);
+        assert_eq!(queue.insert(late, 0).unwrap(), 0);
+        assert_eq!(queue.insert(early, 0).unwrap(), 0);
+        // Equal keys keep filing order
+        assert_eq!(queue.insert(tied, 0).unwrap(), 1);
+
+        // Only the head may be ruled on while nothing is overdue
+        assert!(queue.take_for_ruling(&tied.escrow, 400).is_err());
+        assert!(queue.take_for_ruling(&Pubkey::new_unique(), 400).is_err());
+        // An overdue dispute may jump the queue
+        assert_eq!(queue.take_for_ruling(&late.escrow, 500).unwrap(), late);
+        assert_eq!(queue.take_for_ruling(&early.escrow, 500).unwrap(), early);
+        assert_eq!(queue.take_for_ruling(&tied.escrow, 500).unwrap(), tied);
+        assert!(queue.entries.is_empty());
+    }
+
+    #[test]
+    fn test_trial_split_and_clawback() {
+        let max = GhostProtectEscrow::MAX_TRIAL_UPFRONT_BPS;
+        assert_eq!(trial_split(1_000, 1_000, max as u64).unwrap(), (100, 900));
+        assert!(trial_split(1_000, 0, max as u64).is_err());
+        assert!(trial_split(1_000, max + 1, max as u64).is_err());
+        assert!(trial_split(1_000, 1_000, 500).is_err());
+        // Upfront rounds to zero
+        assert!(trial_split(3, 1_000, max as u64).is_err());
+
+        let mut escrow = GhostProtectEscrow {
+            escrow_id: 1,
+            client: Pubkey::new_unique(),
+            agent: Pubkey::new_unique(),
+            amount: 1_000,
+            token_mint: Pubkey::new_unique(),
+            status: EscrowStatus::Disputed,
+            job_description: String::new(),
+            delivery_proof: None,
+            deadline: 0,
+            created_at: 0,
+            completed_at: None,
+            dispute_reason: None,
+            arbitrator_decision: None,
+            bump: 255,
+            version: GhostProtectEscrow::CURRENT_VERSION,
+            upfront_amount: 100,
+            trial_bond: 500,
+            bond_clawed_back: false,
+            origin_program: None,
+            referrer: None,
+            dispute_bond: 0,
+        };
+        assert_eq!(escrow.held_amount(), 900);
+
+        let favor_client = ArbitratorDecision::FavorClient { reason: String::new() };
+        let split = ArbitratorDecision::Split { client_percentage: 90, reason: String::new() };
+        // Capped at the upfront share, however large the bond
+        assert_eq!(escrow.clawback_amount(&favor_client), 100);
+        assert_eq!(escrow.clawback_amount(&split), 0);
+        escrow.trial_bond = 60;
+        assert_eq!(escrow.clawback_amount(&favor_client), 60);
+
+        escrow.bond_clawed_back = true;
+        assert_eq!(escrow.clawback_amount(&favor_client), 0);
+
+        // Regular escrows never claw back
+        escrow.upfront_amount = 0;
+        escrow.bond_clawed_back = false;
+        assert_eq!(escrow.clawback_amount(&favor_client), 0);
+        assert_eq!(escrow.held_amount(), 1_000);
+    }
+
+    #[test]
+    fn test_dispute_bond_outcome() {
+        let ruling = |client_percentage| ArbitratorDecision::Split {
+            client_percentage,
+            reason: String::new(),
+        };
+        assert!(ArbitratorDecision::FavorClient { reason: String::new() }.upholds_dispute());
+        assert!(ruling(1).upholds_dispute());
+        // Nothing for the client means the dispute was frivolous
+        assert!(!ruling(0).upholds_dispute());
+        assert!(!ArbitratorDecision::FavorAgent { reason: String::new() }.upholds_dispute());
+    }
+
+    #[test]
+    fn test_trailing_settlement_volume() {
+        const DAY: i64 = 86_400;
+        let mut stats = AgentSettlementStats {
+            agent: Pubkey::new_unique(),
+            settled_escrows: 0,
+            total_settled_volume: 0,
+            daily: [DailySettlement::default(); SETTLEMENT_VOLUME_WINDOW_DAYS],
+            last_settled_at: 0,
+            created_at: 0,
+            bump: 255,
+        };
+
+        stats.record_settlement(100, 100 * DAY);
+        stats.record_settlement(50, 100 * DAY + 10);
+        stats.record_settlement(200, 110 * DAY);
+        assert_eq!(stats.trailing_volume(110 * DAY), 350);
+        assert_eq!(stats.trailing_volume(129 * DAY), 350);
+
+        // Day 100 falls out of the window on day 130
+        assert_eq!(stats.trailing_volume(130 * DAY), 200);
+
+        // Day 130 reuses day 100's slot
+        stats.record_settlement(25, 130 * DAY);
+        assert_eq!(stats.trailing_volume(130 * DAY), 225);
+        assert_eq!(stats.total_settled_volume, 375);
+        assert_eq!(stats.settled_escrows, 4);
+    }
+
+    #[test]
+    fn test_client_reputation_rates() {
+        let mut rep = create_test_reputation();
+        assert_eq!(rep.payment_reliability_bps(), 10_000);
+        assert_eq!(rep.dispute_rate_bps(), 0);
+
+        for _ in 0..4 {
+            rep.record_escrow_created(1_000, 1);
+        }
+        assert_eq!(rep.total_volume, 4_000);
+
+        rep.record_escrow_completed(2);
+        rep.record_escrow_completed(2);
+        rep.record_cancellation(3);
+        rep.record_dispute_filed(4);
+        rep.record_dispute_resolved(
+            &ArbitratorDecision::FavorAgent { reason: String::new() },
+            5,
+        );
+
+        // 2 paid out of 4 finished (2 completed + 1 lost dispute + 1 cancel)
+        assert_eq!(rep.payment_reliability_bps(), 5_000);
+        assert_eq!(rep.dispute_rate_bps(), 2_500);
+        assert_eq!(rep.cancellation_rate_bps(), 2_500);
+        assert_eq!(rep.disputes_lost, 1);
+        assert_eq!(rep.last_activity, 5);
+
+        // Splits are neither won nor lost
+        rep.record_dispute_resolved(
+            &ArbitratorDecision::Split { client_percentage: 50, reason: String::new() },
+            6,
+        );
+        assert_eq!(rep.disputes_won + rep.disputes_lost, 1);
+    }
+
+    #[test]
+    fn test_escrow_v0_layout_migrates() {
+        let escrow = GhostProtectEscrow {
+            escrow_id: 9,
+            client: Pubkey::new_unique(),
+            agent: Pubkey::new_unique(),
+            amount: 5_000,
+            token_mint: Pubkey::new_unique(),
+            status: EscrowStatus::Disputed,
+            job_description: "ipfs://job".to_string(),
+            delivery_proof: Some("ipfs://proof".to_string()),
+            deadline: 100,
+            created_at: 10,
+            completed_at: Some(50),
+            dispute_reason: Some("late".to_string()),
+            arbitrator_decision: None,
+            bump: 251,
+            version: GhostProtectEscrow::CURRENT_VERSION,
+            upfront_amount: 0,
+            trial_bond: 0,
+            bond_clawed_back: false,
+            origin_program: None,
+            referrer: None,
+            dispute_bond: 0,
+        };
+
+        // A pre-version escrow ends at `bump`; whatever follows is not its data
+        let mut data = Vec::new();
+        escrow.try_serialize(&mut data).unwrap();
+        let extension = (
+            escrow.version,
+            escrow.upfront_amount,
+            escrow.trial_bond,
+            escrow.bond_clawed_back,
+            escrow.origin_program,
+            escrow.referrer,
+            escrow.dispute_bond,
+        )
+            .try_to_vec()
+            .unwrap();
+        data.truncate(data.len() - extension.len());
+        data.resize(GhostProtectEscrow::LEN - GhostProtectEscrow::V1_EXTENSION_LEN, 7);
+
+        let legacy = GhostProtectEscrowV0::deserialize(&mut &data[8..]).unwrap();
+        let migrated = GhostProtectEscrow::from_v0(legacy);
+        assert!(migrated.is_current(GhostProtectEscrow::LEN));
+        assert_eq!(migrated.escrow_id, 9);
+        assert_eq!(migrated.status, EscrowStatus::Disputed);
+        assert_eq!(migrated.dispute_reason.as_deref(), Some("late"));
+        assert_eq!(migrated.bump, 251);
+        assert!(!migrated.is_trial());
+        assert_eq!(migrated.referrer, None);
+        assert_eq!(migrated.dispute_bond, 0);
+        assert_eq!(migrated.held_amount(), 5_000);
+
+        // Short accounts are never current, whatever their trailing bytes hold
+        assert!(!migrated.is_current(GhostProtectEscrow::LEN - 1));
+    }
+}
@@ -9,6 +9,8 @@
 use anchor_lang::prelude::*;
 // use std::collections::BTreeMap; // Commented out - using Vec<(K,V)> for Anchor compatibility
 
+use crate::utils::pagination::{push_item, PageCursor, PageLink};
+
 // =====================================================
 // MULTI-SIGNATURE STRUCTURES
 // =====================================================
@@ -184,8 +186,23 @@ pub struct Multisig {
     /// Type-specific configuration
     pub type_config: MultisigTypeConfig,
 
+    /// Cursor over queued transactions (PendingTransactionPage accounts)
+    pub pending_pages: PageCursor,
+
+    /// Signers needed to cancel a queued transaction (0 = `threshold`)
+    pub veto_quorum: u8,
+
+    /// Per-signer weights, parallel to `signers` (empty = one each)
+    ///
+    /// Once set, `threshold`, `signer_change_threshold` and `veto_quorum`
+    /// count signer weight rather than signers.
+    pub signer_weights: Vec<u8>,
+
+    /// Recent outflows of each token with a daily limit
+    pub daily_spend: Vec<DailySpend>,
+
     /// Reserved space for future extensions
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 11],
 }
 
 /// Pending transaction in multisig queue
@@ -228,6 +245,63 @@ pub struct PendingTransaction {
     pub time_lock: Option<TimeLock>,
 }
 
+/// Compact pending transaction stored in a PendingTransactionPage
+///
+/// Transaction data is committed by hash and supplied in full at execution.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PendingTransactionEntry {
+    /// Transaction ID (position in the multisig's queue)
+    pub transaction_id: u64,
+
+    /// Transaction type
+    pub transaction_type: TransactionType,
+
+    /// Target program/account
+    pub target: Pubkey,
+
+    /// Hash of the transaction data
+    pub data_hash: [u8; 32],
+
+    /// Required signatures
+    pub required_signatures: u8,
+
+    /// Signers that approved the transaction
+    pub approvals: Vec<Pubkey>,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Expiration timestamp
+    pub expires_at: i64,
+
+    /// Transaction priority
+    pub priority: TransactionPriority,
+
+    /// Transaction status
+    pub status: TransactionStatus,
+
+    /// Execution delay, started once the transaction is fully approved
+    pub time_lock: Option<TimeLock>,
+}
+
+/// Page of pending transactions linked from a Multisig's `pending_pages` cursor
+///
+/// Seeds: ["multisig_tx_page", multisig, index.to_le_bytes()]
+#[account]
+pub struct PendingTransactionPage {
+    /// Multisig this page belongs to
+    pub multisig: Pubkey,
+
+    /// Position and link to the next page
+    pub link: PageLink,
+
+    /// Transactions in this page (oldest first)
+    pub transactions: Vec<PendingTransactionEntry>,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
 /// Multi-signature configuration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct MultisigConfig {
@@ -252,10 +326,32 @@ pub struct MultisigConfig {
     /// Allowed transaction types
     pub allowed_transaction_types: Vec<TransactionType>,
 
-    /// Daily transaction limits
+    /// Rolling 24h outflow limits as (token mint in base58, amount); the
+    /// system program ID stands for SOL
     pub daily_limits: Vec<(String, u64)>,
 }
 
+/// Outflows of one token, kept as two 24h windows so the limit applies
+/// to a rolling day
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DailySpend {
+    pub token: Pubkey,
+    /// Start of the current window
+    pub window_start: i64,
+    /// Spent in the current window
+    pub spent: u64,
+    /// Spent in the window before it
+    pub previous_spent: u64,
+}
+
+/// What a transfer-type transaction moves, Borsh-encoded at the start of
+/// its data so the queued data hash commits to it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpendIntent {
+    pub token: Pubkey,
+    pub amount: u64,
+}
+
 /// Emergency configuration for multisig
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct EmergencyConfig {
@@ -468,8 +564,83 @@ pub struct GovernanceProposal {
     /// Proposal metadata
     pub metadata: ProposalMetadata,
 
+    /// Lamports escrowed on this account by the proposer (0 once released)
+    pub deposit: u64,
+
+    /// When voting power is measured
+    pub snapshot_strategy: SnapshotStrategy,
+
+    /// Moment voting power is measured at, set from `snapshot_strategy`
+    pub snapshot_at: i64,
+
     /// Reserved space
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 47],
+}
+
+/// Seed for per-voter vote records: [seed, proposal, voter]
+pub const PROPOSAL_VOTE_SEED: &[u8] = b"proposal_vote";
+
+/// Seed for voting power snapshots: [seed, proposal, voter]
+pub const VOTING_POWER_SNAPSHOT_SEED: &[u8] = b"voting_power_snapshot";
+
+/// Super majority approval percentage
+pub const SUPER_MAJORITY_PERCENT: u8 = 67;
+
+/// Seed for the proposal deposit config: [seed]
+pub const PROPOSAL_DEPOSIT_CONFIG_SEED: &[u8] = b"proposal_deposit_config";
+
+/// Proposal Deposit Config - the deposit proposers escrow to open a proposal
+///
+/// Seeds: ["proposal_deposit_config"]
+///
+/// Holds `ProposalConfig::minimum_deposit` on-chain on its own, since a
+/// full `GovernanceConfig` doesn't fit in one account. Deposits are in
+/// lamports.
+#[account]
+pub struct ProposalDepositConfig {
+    /// Multisig that sets the deposit
+    pub multisig: Pubkey,
+    /// Lamports a proposer escrows per proposal
+    pub minimum_deposit: u64,
+    /// Recipient of forfeited deposits
+    pub treasury: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// Proposal Vote - one voter's ballot on a proposal
+///
+/// Seeds: ["proposal_vote", proposal, voter]
+///
+/// Kept per voter instead of in `VotingResults.individual_votes`, so
+/// participation isn't bounded by the proposal account's size and each
+/// voter can vote only once.
+#[account]
+pub struct ProposalVote {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub choice: VoteChoice,
+    /// Effective voting power at the time of the vote
+    pub voting_power: u64,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+/// Voting Power Snapshot - one voter's voting power inputs as of a
+/// proposal's `snapshot_at`
+///
+/// Seeds: ["voting_power_snapshot", proposal, voter]
+///
+/// Votes are weighted from here rather than from live accounts, so stake
+/// added after the snapshot point can't be voted with.
+#[account]
+pub struct VotingPowerSnapshot {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    /// Inputs to `calculate_enhanced_voting_power`
+    pub inputs: VotingPowerInput,
+    pub taken_at: i64,
+    pub bump: u8,
 }
 
 /// Types of governance proposals
@@ -511,6 +682,8 @@ pub enum ProposalStatus {
     Cancelled,
     Expired,
     Queued,
+    /// Stopped by the security council during its execution timelock
+    Vetoed,
 }
 
 /// Voting results for proposal
@@ -1347,12 +1520,24 @@ pub enum ComparisonOperator {
 /// Maximum number of signers in multisig
 pub const MAX_MULTISIG_SIGNERS: usize = 20;
 
+/// Maximum emergency contacts on a multisig
+pub const MAX_EMERGENCY_CONTACTS: usize = 10;
+
 /// Maximum pending transactions in Multisig account
 /// Set to 0 because Solana limits account creation via CPI to 10KB
 /// Pending transactions should be stored in separate PDA accounts
 /// Each pending transaction is ~13KB which exceeds the CPI limit
 pub const MAX_PENDING_TRANSACTIONS: usize = 0;
 
+/// Pending transactions per PendingTransactionPage
+pub const PENDING_TRANSACTION_PAGE_CAPACITY: u16 = 8;
+
+/// Maximum tokens with a daily limit on one multisig
+pub const MAX_DAILY_LIMITS: usize = 10;
+
+/// Length of the daily spending window
+pub const DAILY_SPEND_WINDOW: i64 = 24 * 60 * 60;
+
 /// Maximum proposal instructions
 pub const MAX_PROPOSAL_INSTRUCTIONS: usize = 2; // Reduced from 10 to fit in account limits
 
@@ -1365,8 +1550,12 @@ pub const MAX_EMERGENCY_CONDITIONS: usize = 20;
 /// Maximum voting power multiplier (10x)
 pub const MAX_VOTING_POWER_MULTIPLIER: u32 = 100000; // Basis points
 
-/// Maximum proposals in execution queue
-pub const MAX_QUEUED_PROPOSALS: usize = 50;
+/// Maximum proposals in execution queue (keeps the queue under the 10KB
+/// account creation limit)
+pub const MAX_QUEUED_PROPOSALS: usize = 20;
+
+/// Seed for execution queues: [seed, executor, batch_id.to_le_bytes()]
+pub const EXECUTION_QUEUE_SEED: &[u8] = b"execution_queue";
 
 // =====================================================
 // ENHANCED VOTING POWER SYSTEM (x402 Marketplace)
@@ -1585,6 +1774,20 @@ pub struct VotingPowerCalculatedEvent {
 /// Maximum trigger conditions
 pub const MAX_TRIGGER_CONDITIONS: usize = 10;
 
+/// Seed for auto-execution triggers: [seed, target, trigger_id.to_le_bytes()]
+pub const AUTO_TRIGGER_SEED: &[u8] = b"auto_trigger";
+
+/// Condition on the cluster clock; `target_value` is an i64 unix timestamp (LE)
+pub const TRIGGER_CONDITION_TIMESTAMP: &str = "timestamp";
+
+/// Condition on the current slot; `target_value` is a u64 slot (LE)
+pub const TRIGGER_CONDITION_SLOT: &str = "slot";
+
+/// Condition on an oracle account; `target_value` is the oracle pubkey,
+/// a u32 byte offset into its data and an i64 threshold (all LE). The i64
+/// stored at the offset is compared against the threshold.
+pub const TRIGGER_CONDITION_ORACLE: &str = "oracle";
+
 // =====================================================
 // SPACE CALCULATIONS
 // =====================================================
@@ -1604,7 +1807,656 @@ impl Multisig {
         MultisigConfig::size() + // config
         EmergencyConfig::size() + // emergency_config
         MultisigTypeConfig::size() + // type_config
-        64 // reserved (reduced from 128 to accommodate new fields)
+        PageCursor::LEN + // pending_pages
+        1 + // veto_quorum
+        4 + MAX_MULTISIG_SIGNERS + // signer_weights
+        4 + (MAX_DAILY_LIMITS * DailySpend::SIZE) + // daily_spend
+        11 // reserved
+    }
+
+    /// Queue a transaction in the tail page, returning its transaction ID
+    pub fn queue_paged_transaction(
+        &mut self,
+        page: &mut PendingTransactionPage,
+        proposer: Pubkey,
+        transaction_type: TransactionType,
+        target: Pubkey,
+        data_hash: [u8; 32],
+        priority: TransactionPriority,
+        now: i64,
+    ) -> Result<u64> {
+        require!(
+            self.signers.contains(&proposer),
+            crate::GhostSpeakError::UnauthorizedAccess
+        );
+        self.require_not_frozen()?;
+
+        let transaction_id = push_item(
+            &mut self.pending_pages,
+            &mut page.link,
+            PENDING_TRANSACTION_PAGE_CAPACITY,
+        )?;
+
+        page.transactions.push(PendingTransactionEntry {
+            transaction_id,
+            transaction_type,
+            target,
+            data_hash,
+            required_signatures: self.threshold,
+            approvals: vec![proposer],
+            created_at: now,
+            expires_at: now.saturating_add(self.config.default_timeout),
+            priority,
+            status: TransactionStatus::Pending,
+            time_lock: None,
+        });
+        // A threshold the proposer's weight meets is met by them alone
+        let timelock_seconds = self.execution_timelock();
+        let approved_weight = self.weight_of(&[proposer]);
+        if let Some(entry) = page.transactions.last_mut() {
+            entry.approve_if_ready(approved_weight, timelock_seconds, now);
+        }
+        self.nonce = self.nonce.saturating_add(1);
+        self.updated_at = now;
+
+        Ok(transaction_id)
+    }
+
+    /// Delay between full approval and execution
+    ///
+    /// Protocol and DAO multisigs wait out their type's `timelock_seconds`;
+    /// other types execute as soon as they are approved.
+    pub fn execution_timelock(&self) -> i64 {
+        match self.multisig_type {
+            MultisigType::Protocol | MultisigType::Dao => self.type_config.timelock_seconds,
+            _ => 0,
+        }
+    }
+
+    /// Signers needed to veto a queued transaction
+    pub fn veto_quorum(&self) -> u8 {
+        if self.veto_quorum == 0 {
+            self.threshold
+        } else {
+            self.veto_quorum
+        }
+    }
+
+    /// Set the veto quorum (0 falls back to `threshold`)
+    pub fn set_veto_quorum(&mut self, veto_quorum: u8, now: i64) -> Result<()> {
+        require!(
+            veto_quorum as usize <= self.total_weight(),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        self.veto_quorum = veto_quorum;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Record `signer`'s approval of a paged transaction
+    ///
+    /// Returns true once the transaction became fully approved, which
+    /// starts its timelock.
+    pub fn approve_paged_transaction(
+        &mut self,
+        page: &mut PendingTransactionPage,
+        transaction_id: u64,
+        signer: Pubkey,
+        now: i64,
+    ) -> Result<bool> {
+        require!(
+            self.signers.contains(&signer),
+            crate::GhostSpeakError::UnauthorizedAccess
+        );
+        self.require_not_frozen()?;
+
+        let timelock_seconds = self.execution_timelock();
+        let entry = page.transaction_mut(transaction_id)?;
+        // Emergencies keep collecting approvals past the threshold, since
+        // only approval by every signer lifts the daily spending limit
+        let extra_approval = entry.status == TransactionStatus::FullyApproved
+            && entry.priority == TransactionPriority::Emergency;
+        require!(
+            extra_approval
+                || matches!(
+                    entry.status,
+                    TransactionStatus::Pending | TransactionStatus::PartiallyApproved
+                ),
+            crate::GhostSpeakError::InvalidState
+        );
+        require!(
+            now <= entry.expires_at,
+            crate::GhostSpeakError::InvalidState
+        );
+        require!(
+            !entry.approvals.contains(&signer),
+            crate::GhostSpeakError::AlreadyVoted
+        );
+
+        entry.approvals.push(signer);
+        if extra_approval {
+            return Ok(true);
+        }
+        entry.status = TransactionStatus::PartiallyApproved;
+        let approved_weight = self.weight_of(&entry.approvals);
+        entry.approve_if_ready(approved_weight, timelock_seconds, now);
+        Ok(entry.status == TransactionStatus::FullyApproved)
+    }
+
+    /// Mark an approved paged transaction executed once its timelock has
+    /// passed and `data_hash` matches the queued data
+    ///
+    /// Transfer-type transactions must carry their `spend`, which counts
+    /// against the token's daily limit.
+    pub fn execute_paged_transaction(
+        &mut self,
+        page: &mut PendingTransactionPage,
+        transaction_id: u64,
+        executor: Pubkey,
+        data_hash: [u8; 32],
+        spend: Option<SpendIntent>,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.signers.contains(&executor),
+            crate::GhostSpeakError::UnauthorizedAccess
+        );
+        self.require_not_frozen()?;
+
+        let entry = page.transaction_mut(transaction_id)?;
+        require!(
+            entry.status == TransactionStatus::FullyApproved,
+            crate::GhostSpeakError::InvalidState
+        );
+        require!(
+            entry.data_hash == data_hash,
+            crate::GhostSpeakError::InvalidInput
+        );
+        if let Some(time_lock) = &entry.time_lock {
+            require!(
+                now >= time_lock.unlocks_at,
+                crate::GhostSpeakError::MultisigTimelockActive
+            );
+        }
+
+        if entry.transaction_type.is_transfer() {
+            let spend = spend.ok_or(crate::GhostSpeakError::InvalidInput)?;
+            // Emergencies approved by every signer may exceed the limit
+            let override_limit = self.config.allow_emergency_override
+                && entry.priority == TransactionPriority::Emergency
+                && self.weight_of(&entry.approvals) >= self.total_weight();
+            self.record_spend(spend, override_limit, now)?;
+        }
+
+        entry.status = TransactionStatus::Executed;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Daily limit configured for `token`, if any
+    pub fn daily_limit(&self, token: &Pubkey) -> Option<u64> {
+        let token = token.to_string();
+        self.config
+            .daily_limits
+            .iter()
+            .find(|(limited, _)| *limited == token)
+            .map(|(_, limit)| *limit)
+    }
+
+    /// Count `spend` against its token's rolling daily limit
+    fn record_spend(&mut self, spend: SpendIntent, override_limit: bool, now: i64) -> Result<()> {
+        let Some(limit) = self.daily_limit(&spend.token) else {
+            return Ok(());
+        };
+
+        let index = match self
+            .daily_spend
+            .iter()
+            .position(|window| window.token == spend.token)
+        {
+            Some(index) => index,
+            None => {
+                // Drop windows of tokens whose limit has since been removed
+                let limits = &self.config.daily_limits;
+                self.daily_spend.retain(|window| {
+                    limits
+                        .iter()
+                        .any(|(token, _)| *token == window.token.to_string())
+                });
+                require!(
+                    self.daily_spend.len() < MAX_DAILY_LIMITS,
+                    crate::GhostSpeakError::InvalidConfiguration
+                );
+                self.daily_spend.push(DailySpend {
+                    token: spend.token,
+                    window_start: now,
+                    spent: 0,
+                    previous_spent: 0,
+                });
+                self.daily_spend.len() - 1
+            }
+        };
+
+        let window = &mut self.daily_spend[index];
+        window.advance(now);
+        require!(
+            override_limit || window.rolling_total(now).saturating_add(spend.amount) <= limit,
+            crate::GhostSpeakError::DailyLimitExceeded
+        );
+        window.spent = window.spent.saturating_add(spend.amount);
+        Ok(())
+    }
+
+    /// Cancel a paged transaction before it can execute
+    ///
+    /// Needs `veto_quorum` signer weight among `accounts`. An approved
+    /// transaction can only be vetoed while its timelock is running.
+    pub fn cancel_paged_transaction(
+        &mut self,
+        page: &mut PendingTransactionPage,
+        transaction_id: u64,
+        accounts: &[AccountInfo],
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.approval_weight(accounts) >= self.veto_quorum() as usize,
+            crate::GhostSpeakError::InsufficientSigners
+        );
+
+        let entry = page.transaction_mut(transaction_id)?;
+        match entry.status {
+            TransactionStatus::Pending | TransactionStatus::PartiallyApproved => {}
+            TransactionStatus::FullyApproved => {
+                let time_lock = entry
+                    .time_lock
+                    .as_ref()
+                    .ok_or(crate::GhostSpeakError::InvalidState)?;
+                require!(
+                    time_lock.cancellable && now < time_lock.unlocks_at,
+                    crate::GhostSpeakError::InvalidState
+                );
+            }
+            _ => return err!(crate::GhostSpeakError::InvalidState),
+        }
+
+        entry.status = TransactionStatus::Cancelled;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Weight of the signer at `index` (one each while no weights are set)
+    fn signer_weight(&self, index: usize) -> usize {
+        self.signer_weights.get(index).map_or(1, |weight| *weight as usize)
+    }
+
+    /// Combined weight of all signers
+    pub fn total_weight(&self) -> usize {
+        (0..self.signers.len())
+            .map(|index| self.signer_weight(index))
+            .sum()
+    }
+
+    /// Combined weight of the signers among `keys`
+    pub fn weight_of(&self, keys: &[Pubkey]) -> usize {
+        self.signers
+            .iter()
+            .enumerate()
+            .filter(|(_, signer)| keys.contains(signer))
+            .map(|(index, _)| self.signer_weight(index))
+            .sum()
+    }
+
+    /// Combined weight of distinct multisig signers that signed the current
+    /// transaction (their count while no weights are set)
+    pub fn approval_weight(&self, accounts: &[AccountInfo]) -> usize {
+        self.signers
+            .iter()
+            .enumerate()
+            .filter(|(_, signer)| {
+                accounts
+                    .iter()
+                    .any(|acc| acc.key == *signer && acc.is_signer)
+            })
+            .map(|(index, _)| self.signer_weight(index))
+            .sum()
+    }
+
+    /// Refuse while the multisig is frozen
+    pub fn require_not_frozen(&self) -> Result<()> {
+        require!(
+            !self.emergency_config.frozen,
+            crate::GhostSpeakError::InvalidState
+        );
+        Ok(())
+    }
+
+    /// Require that multisig signers of at least `threshold` weight signed
+    /// the transaction, on a multisig that is not frozen
+    pub fn require_approvals(&self, accounts: &[AccountInfo]) -> Result<()> {
+        self.require_not_frozen()?;
+        require!(
+            self.approval_weight(accounts) >= self.threshold as usize,
+            crate::GhostSpeakError::InsufficientSigners
+        );
+        Ok(())
+    }
+
+    /// Require the emergency quorum: `emergency_threshold` distinct signers
+    /// or emergency contacts, on a multisig with freezing enabled
+    ///
+    /// Counted per head even on weighted multisigs, since contacts carry no
+    /// weight.
+    pub fn require_emergency_approvals(&self, accounts: &[AccountInfo]) -> Result<()> {
+        let emergency = &self.emergency_config;
+        require!(
+            emergency.freeze_enabled,
+            crate::GhostSpeakError::EmergencyFreezeDisabled
+        );
+        let approvals = self
+            .signers
+            .iter()
+            .chain(
+                emergency
+                    .emergency_contacts
+                    .iter()
+                    .filter(|contact| !self.signers.contains(contact)),
+            )
+            .filter(|key| accounts.iter().any(|acc| acc.key == *key && acc.is_signer))
+            .count();
+        require!(
+            approvals >= emergency.emergency_threshold.max(1) as usize,
+            crate::GhostSpeakError::InsufficientSigners
+        );
+        Ok(())
+    }
+
+    /// Configure who may freeze the multisig and for how long
+    pub fn set_emergency_config(
+        &mut self,
+        emergency_contacts: Vec<Pubkey>,
+        emergency_threshold: u8,
+        freeze_enabled: bool,
+        auto_unfreeze_duration: Option<i64>,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            emergency_contacts.len() <= MAX_EMERGENCY_CONTACTS,
+            crate::GhostSpeakError::TooManySigners
+        );
+        let eligible = self.signers.len()
+            + emergency_contacts
+                .iter()
+                .filter(|contact| !self.signers.contains(contact))
+                .count();
+        require!(
+            emergency_threshold > 0 && emergency_threshold as usize <= eligible,
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        require!(
+            auto_unfreeze_duration.map_or(true, |duration| duration > 0),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+
+        let emergency = &mut self.emergency_config;
+        emergency.emergency_contacts = emergency_contacts;
+        emergency.emergency_threshold = emergency_threshold;
+        emergency.freeze_enabled = freeze_enabled;
+        emergency.auto_unfreeze_duration = auto_unfreeze_duration;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Freeze the multisig, blocking new transactions and signer changes
+    pub fn freeze(&mut self, now: i64) -> Result<()> {
+        let emergency = &mut self.emergency_config;
+        require!(!emergency.frozen, crate::GhostSpeakError::InvalidState);
+        emergency.frozen = true;
+        emergency.frozen_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Lift a freeze
+    pub fn unfreeze(&mut self, now: i64) -> Result<()> {
+        let emergency = &mut self.emergency_config;
+        require!(emergency.frozen, crate::GhostSpeakError::MultisigNotFrozen);
+        emergency.frozen = false;
+        emergency.frozen_at = None;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Whether a freeze has outlived `auto_unfreeze_duration`
+    pub fn freeze_expired(&self, now: i64) -> bool {
+        let emergency = &self.emergency_config;
+        match (emergency.frozen_at, emergency.auto_unfreeze_duration) {
+            (Some(frozen_at), Some(duration)) => {
+                emergency.frozen && now >= frozen_at.saturating_add(duration)
+            }
+            _ => false,
+        }
+    }
+
+    /// Approvals needed to change the signer set or threshold
+    ///
+    /// `signer_change_threshold` can raise the bar above `threshold` but
+    /// never lower it.
+    pub fn signer_change_approvals(&self) -> u8 {
+        self.threshold.max(self.config.signer_change_threshold)
+    }
+
+    /// Require that enough current signers approved a signer or threshold change
+    pub fn require_signer_change_approvals(&self, accounts: &[AccountInfo]) -> Result<()> {
+        self.require_not_frozen()?;
+        require!(
+            self.approval_weight(accounts) >= self.signer_change_approvals() as usize,
+            crate::GhostSpeakError::InsufficientSigners
+        );
+        Ok(())
+    }
+
+    /// Add a signer, keeping the set within the type's signer bounds
+    ///
+    /// On a weighted multisig the new signer gets a weight of one.
+    pub fn add_signer(&mut self, signer: Pubkey, now: i64) -> Result<()> {
+        require!(
+            !self.signers.contains(&signer),
+            crate::GhostSpeakError::MultisigSignerAlreadyExists
+        );
+        let total_weight = self.total_weight() + 1;
+        if !self.signer_weights.is_empty() {
+            require!(
+                total_weight <= u8::MAX as usize,
+                crate::GhostSpeakError::InvalidConfiguration
+            );
+        }
+        self.validate_signer_set(self.signers.len() + 1, total_weight, self.threshold)?;
+        self.signers.push(signer);
+        if !self.signer_weights.is_empty() {
+            self.signer_weights.push(1);
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Remove a signer; the remaining signers must still be able to reach
+    /// both thresholds
+    pub fn remove_signer(&mut self, signer: Pubkey, now: i64) -> Result<()> {
+        let index = self
+            .signers
+            .iter()
+            .position(|s| *s == signer)
+            .ok_or(crate::GhostSpeakError::MultisigSignerNotFound)?;
+        let total_weight = self.total_weight() - self.signer_weight(index);
+        self.validate_signer_set(self.signers.len() - 1, total_weight, self.threshold)?;
+        self.signers.remove(index);
+        if !self.signer_weights.is_empty() {
+            self.signer_weights.remove(index);
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Set a new approval threshold
+    pub fn change_threshold(&mut self, threshold: u8, now: i64) -> Result<()> {
+        self.validate_signer_set(self.signers.len(), self.total_weight(), threshold)?;
+        self.threshold = threshold;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Set per-signer weights together with a threshold in weight units
+    ///
+    /// Empty `weights` go back to one signer, one vote. The total weight is
+    /// capped at `u8::MAX` so every threshold still fits its field.
+    pub fn set_signer_weights(&mut self, weights: Vec<u8>, threshold: u8, now: i64) -> Result<()> {
+        require!(
+            weights.is_empty() || weights.len() == self.signers.len(),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        require!(
+            weights.iter().all(|weight| *weight > 0),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        let total_weight = if weights.is_empty() {
+            self.signers.len()
+        } else {
+            weights.iter().map(|weight| *weight as usize).sum()
+        };
+        require!(
+            total_weight <= u8::MAX as usize && self.veto_quorum as usize <= total_weight,
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        self.validate_signer_set(self.signers.len(), total_weight, threshold)?;
+
+        self.signer_weights = weights;
+        self.threshold = threshold;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Check a proposed signer count against `type_config` and that both
+    /// thresholds stay reachable with the proposed total weight
+    fn validate_signer_set(
+        &self,
+        signer_count: usize,
+        total_weight: usize,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            signer_count >= self.type_config.min_signers as usize,
+            crate::GhostSpeakError::InsufficientSigners
+        );
+        require!(
+            signer_count <= self.type_config.max_signers as usize
+                && signer_count <= MAX_MULTISIG_SIGNERS,
+            crate::GhostSpeakError::TooManySigners
+        );
+        require!(
+            threshold > 0
+                && threshold.max(self.config.signer_change_threshold) as usize <= total_weight,
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        Ok(())
+    }
+}
+
+impl TransactionType {
+    /// Whether the transaction moves funds out of the multisig
+    pub fn is_transfer(&self) -> bool {
+        matches!(
+            self,
+            TransactionType::Transfer
+                | TransactionType::Withdrawal
+                | TransactionType::EscrowRelease
+        )
+    }
+}
+
+impl DailySpend {
+    pub const SIZE: usize = 32 + // token
+        8 + // window_start
+        8 + // spent
+        8; // previous_spent
+
+    /// Start a new window once the current one is a day old
+    fn advance(&mut self, now: i64) {
+        let elapsed = now.saturating_sub(self.window_start);
+        if elapsed >= 2 * DAILY_SPEND_WINDOW {
+            self.previous_spent = 0;
+            self.spent = 0;
+            self.window_start = now;
+        } else if elapsed >= DAILY_SPEND_WINDOW {
+            self.previous_spent = self.spent;
+            self.spent = 0;
+            self.window_start += DAILY_SPEND_WINDOW;
+        }
+    }
+
+    /// Estimated outflow over the 24h before `now`: the current window plus
+    /// the share of the previous one still inside the rolling day
+    pub fn rolling_total(&self, now: i64) -> u64 {
+        let into_window = now
+            .saturating_sub(self.window_start)
+            .clamp(0, DAILY_SPEND_WINDOW);
+        let previous_share = self.previous_spent as u128
+            * (DAILY_SPEND_WINDOW - into_window) as u128
+            / DAILY_SPEND_WINDOW as u128;
+        self.spent.saturating_add(previous_share as u64)
+    }
+}
+
+impl PendingTransactionEntry {
+    /// Mark the transaction fully approved once its approvers' combined
+    /// weight reaches `required_signatures`, starting a cancellable
+    /// timelock when the multisig requires one
+    fn approve_if_ready(&mut self, approved_weight: usize, timelock_seconds: i64, now: i64) {
+        if approved_weight < self.required_signatures as usize {
+            return;
+        }
+        self.status = TransactionStatus::FullyApproved;
+        if timelock_seconds > 0 {
+            self.time_lock = Some(TimeLock {
+                duration: timelock_seconds,
+                locked_at: now,
+                unlocks_at: now.saturating_add(timelock_seconds),
+                early_unlock_conditions: Vec::new(),
+                lock_type: TimeLockType::Governance,
+                cancellable: true,
+            });
+        }
+    }
+
+    pub const fn size() -> usize {
+        8 + // transaction_id
+        1 + // transaction_type
+        32 + // target
+        32 + // data_hash
+        1 + // required_signatures
+        4 + (MAX_MULTISIG_SIGNERS * 32) + // approvals
+        8 + // created_at
+        8 + // expires_at
+        1 + // priority
+        1 + // status
+        1 + TimeLock::size_without_conditions() // time_lock
+    }
+}
+
+impl PendingTransactionPage {
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // multisig
+        PageLink::LEN + // link
+        4 + (PENDING_TRANSACTION_PAGE_CAPACITY as usize * PendingTransactionEntry::size()) + // transactions
+        1 // bump
+    }
+
+    /// Look up a transaction queued in this page
+    pub fn transaction_mut(&mut self, transaction_id: u64) -> Result<&mut PendingTransactionEntry> {
+        self.transactions
+            .iter_mut()
+            .find(|entry| entry.transaction_id == transaction_id)
+            .ok_or_else(|| error!(crate::GhostSpeakError::MultisigTransactionNotFound))
     }
 }
 
@@ -1656,13 +2508,13 @@ impl MultisigConfig {
         1 + // auto_execute
         1 + // signer_change_threshold
         4 + (20 * 1) + // allowed_transaction_types
-        4 + (10 * (32 + 8)) // daily_limits
+        4 + (MAX_DAILY_LIMITS * (4 + 44 + 8)) // daily_limits (base58 mint, limit)
     }
 }
 
 impl EmergencyConfig {
     pub const fn size() -> usize {
-        4 + (10 * 32) + // emergency_contacts
+        4 + (MAX_EMERGENCY_CONTACTS * 32) + // emergency_contacts
         1 + // emergency_threshold
         8 + // emergency_timeout
         4 + (10 * 1) + // emergency_transaction_types
@@ -1684,6 +2536,17 @@ impl ExecutionCondition {
 }
 
 impl TimeLock {
+    /// Size of a lock with no early unlock conditions, as stored in
+    /// paged transactions
+    pub const fn size_without_conditions() -> usize {
+        8 + // duration
+        8 + // locked_at
+        8 + // unlocks_at
+        4 + // early_unlock_conditions (empty)
+        1 + // lock_type
+        1 // cancellable
+    }
+
     pub const fn size() -> usize {
         8 + // duration
         8 + // locked_at
@@ -1711,8 +2574,225 @@ impl GovernanceProposal {
         ExecutionParams::size() + // execution_params
         QuorumRequirements::size() + // quorum_requirements
         ProposalMetadata::size() + // metadata
+        8 + // deposit
+        1 + // snapshot_strategy
+        8 + // snapshot_at
         32 // reserved (reduced)
     }
+
+    /// Fix the moment voting power is measured at
+    ///
+    /// Block height and timestamp strategies are refused: they would let the
+    /// proposer aim the snapshot at a moment of their choosing.
+    pub fn set_snapshot(&mut self, strategy: SnapshotStrategy) -> Result<()> {
+        self.snapshot_at = match strategy {
+            SnapshotStrategy::ProposalCreation => self.created_at,
+            SnapshotStrategy::VotingStart => self.voting_starts_at,
+            SnapshotStrategy::BlockHeight | SnapshotStrategy::Timestamp => {
+                return err!(crate::GhostSpeakError::InvalidConfiguration);
+            }
+        };
+        self.snapshot_strategy = strategy;
+        Ok(())
+    }
+
+    /// Add a vote to the running totals while voting is open
+    pub fn record_vote(&mut self, choice: VoteChoice, voting_power: u64, now: i64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Active,
+            crate::GhostSpeakError::ProposalNotActive
+        );
+        require!(
+            now >= self.voting_starts_at,
+            crate::GhostSpeakError::VotingNotStarted
+        );
+        require!(
+            now < self.voting_ends_at,
+            crate::GhostSpeakError::VotingEnded
+        );
+
+        let results = &mut self.voting_results;
+        let tally = match choice {
+            VoteChoice::For => &mut results.votes_for,
+            VoteChoice::Against => &mut results.votes_against,
+            VoteChoice::Abstain => &mut results.votes_abstain,
+        };
+        *tally = tally
+            .checked_add(voting_power)
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        results.total_voting_power = results
+            .total_voting_power
+            .checked_add(voting_power)
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Close voting and decide the proposal
+    ///
+    /// Quorum is the absolute `minimum_voting_power` cast (abstentions
+    /// count); approval compares votes for against decided votes. A passed
+    /// proposal becomes executable after `execution_delay`.
+    pub fn tally(&mut self, now: i64) -> Result<ProposalStatus> {
+        require!(
+            self.status == ProposalStatus::Active,
+            crate::GhostSpeakError::ProposalNotActive
+        );
+        require!(
+            now >= self.voting_ends_at,
+            crate::GhostSpeakError::VotingNotEnded
+        );
+
+        let quorum = &self.quorum_requirements;
+        let required_percent = if quorum.super_majority_required {
+            quorum.approval_threshold.max(SUPER_MAJORITY_PERCENT)
+        } else {
+            quorum.approval_threshold
+        };
+
+        let results = &mut self.voting_results;
+        let decided = results.votes_for as u128 + results.votes_against as u128;
+        results.quorum_reached = results.total_voting_power >= quorum.minimum_voting_power;
+        results.approval_threshold_met =
+            decided > 0 && results.votes_for as u128 * 100 >= decided * required_percent as u128;
+
+        if results.quorum_reached && results.approval_threshold_met {
+            self.status = ProposalStatus::Passed;
+            self.execution_timestamp =
+                Some(now.saturating_add(self.execution_params.execution_delay));
+        } else {
+            self.status = ProposalStatus::Failed;
+        }
+        Ok(self.status)
+    }
+
+    /// Mark a passed proposal executed once its execution delay is over
+    pub fn mark_executed(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Passed,
+            crate::GhostSpeakError::ProposalNotPassed
+        );
+        let execution_timestamp = self
+            .execution_timestamp
+            .ok_or(crate::GhostSpeakError::ExecutionNotScheduled)?;
+        require!(
+            now >= execution_timestamp,
+            crate::GhostSpeakError::ExecutionDelayNotMet
+        );
+        self.status = ProposalStatus::Executed;
+        Ok(())
+    }
+
+    /// Stop a passed proposal before its execution timelock runs out
+    pub fn veto(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Passed,
+            crate::GhostSpeakError::ProposalNotPassed
+        );
+        let execution_timestamp = self
+            .execution_timestamp
+            .ok_or(crate::GhostSpeakError::ExecutionNotScheduled)?;
+        require!(
+            now < execution_timestamp,
+            crate::GhostSpeakError::VetoWindowClosed
+        );
+        self.status = ProposalStatus::Vetoed;
+        Ok(())
+    }
+
+    /// Hand a passed proposal to an execution queue
+    pub fn mark_queued(&mut self) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Passed,
+            crate::GhostSpeakError::ProposalNotPassed
+        );
+        self.status = ProposalStatus::Queued;
+        Ok(())
+    }
+
+    /// Cancel a queued proposal, if it allows cancellation after approval
+    pub fn cancel_queued(&mut self) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Queued,
+            crate::GhostSpeakError::ProposalNotQueued
+        );
+        require!(
+            self.execution_params.cancellable,
+            crate::GhostSpeakError::ProposalNotCancellable
+        );
+        self.status = ProposalStatus::Cancelled;
+        Ok(())
+    }
+
+    /// Withdraw a proposal while voting is still open
+    pub fn cancel(&mut self) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Active,
+            crate::GhostSpeakError::ProposalNotActive
+        );
+        self.status = ProposalStatus::Cancelled;
+        Ok(())
+    }
+
+    /// Release the escrowed deposit once the proposal is decided
+    ///
+    /// Returns the amount and whether it goes back to the proposer.
+    /// Proposals that reached quorum are refunded whether they passed or
+    /// failed; cancelled proposals and those that drew too little
+    /// participation to reach quorum forfeit the deposit.
+    pub fn release_deposit(&mut self) -> Result<(u64, bool)> {
+        require!(
+            !matches!(self.status, ProposalStatus::Draft | ProposalStatus::Active),
+            crate::GhostSpeakError::VotingNotEnded
+        );
+        require!(self.deposit > 0, crate::GhostSpeakError::NoProposalDeposit);
+
+        let refund =
+            self.status != ProposalStatus::Cancelled && self.voting_results.quorum_reached;
+        let amount = self.deposit;
+        self.deposit = 0;
+        Ok((amount, refund))
+    }
+}
+
+impl ProposalDepositConfig {
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // multisig
+        8 + // minimum_deposit
+        32 + // treasury
+        8 + // updated_at
+        1 // bump
+    }
+}
+
+impl ProposalVote {
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // proposal
+        32 + // voter
+        1 + // choice
+        8 + // voting_power
+        8 + // voted_at
+        1 // bump
+    }
+}
+
+impl VotingPowerSnapshot {
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // proposal
+        32 + // voter
+        8 + // inputs.token_balance
+        8 + // inputs.staked_balance
+        8 + // inputs.lockup_duration
+        2 + // inputs.reputation_score
+        1 + // inputs.is_verified_agent
+        8 + // inputs.x402_volume_30d
+        8 + // inputs.delegated_power
+        8 + // inputs.delegated_out
+        8 + // taken_at
+        1 // bump
+    }
 }
 
 impl VotingResults {
@@ -2012,6 +3092,82 @@ impl ExecutionQueue {
         8 + // execution_window
         64 // reserved
     }
+
+    /// Queue a passed proposal behind everything of equal or higher priority
+    pub fn enqueue(
+        &mut self,
+        proposal: Pubkey,
+        proposal_id: u64,
+        priority: TransactionPriority,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.status != ExecutionQueueStatus::Cancelled,
+            crate::GhostSpeakError::InvalidState
+        );
+        // Settled entries have already been reported through events
+        self.queued_proposals
+            .retain(|entry| entry.execution_status == ExecutionStatus::Queued);
+        require!(
+            !self
+                .queued_proposals
+                .iter()
+                .any(|entry| entry.proposal == proposal),
+            crate::GhostSpeakError::ProposalAlreadyQueued
+        );
+        require!(
+            self.queued_proposals.len() < MAX_QUEUED_PROPOSALS,
+            crate::GhostSpeakError::ExecutionQueueFull
+        );
+
+        let position = self
+            .queued_proposals
+            .iter()
+            .position(|entry| (entry.priority as u8) < priority as u8)
+            .unwrap_or(self.queued_proposals.len());
+        self.queued_proposals.insert(
+            position,
+            QueuedProposal {
+                proposal,
+                proposal_id,
+                priority,
+                queued_at: now,
+                execution_status: ExecutionStatus::Queued,
+                execution_result: None,
+            },
+        );
+        self.status = ExecutionQueueStatus::Pending;
+        Ok(())
+    }
+
+    /// Skip a queued proposal
+    pub fn cancel(&mut self, proposal: Pubkey) -> Result<()> {
+        let entry = self
+            .queued_proposals
+            .iter_mut()
+            .find(|entry| {
+                entry.proposal == proposal && entry.execution_status == ExecutionStatus::Queued
+            })
+            .ok_or(crate::GhostSpeakError::ProposalNotQueued)?;
+        entry.execution_status = ExecutionStatus::Skipped;
+        Ok(())
+    }
+
+    /// Update the queue status after a processing pass
+    pub fn finish_batch(&mut self, processed: usize, now: i64) {
+        let pending = self
+            .queued_proposals
+            .iter()
+            .any(|entry| entry.execution_status == ExecutionStatus::Queued);
+        if !pending {
+            self.status = ExecutionQueueStatus::Completed;
+        } else if processed > 0 {
+            self.status = ExecutionQueueStatus::PartiallyExecuted;
+        }
+        if processed > 0 {
+            self.last_execution_at = Some(now);
+        }
+    }
 }
 
 impl QueuedProposal {
@@ -2023,6 +3179,46 @@ impl QueuedProposal {
         1 + // execution_status
         1 + ExecutionResult::size() // execution_result
     }
+
+    /// Execute the queued proposal if its execution time has come
+    ///
+    /// Returns None while the proposal is still inside its execution delay.
+    /// A proposal whose execution window (if any) has passed expires
+    /// instead of executing.
+    pub fn settle(
+        &mut self,
+        proposal: &mut GovernanceProposal,
+        execution_window: i64,
+        now: i64,
+    ) -> Option<ExecutionResult> {
+        let failure = match proposal.execution_timestamp {
+            _ if proposal.status != ProposalStatus::Queued => Some("Proposal is no longer queued"),
+            None => Some("Proposal has no execution time"),
+            Some(at) if now < at => return None,
+            Some(at) if execution_window > 0 && now > at.saturating_add(execution_window) => {
+                proposal.status = ProposalStatus::Expired;
+                Some("Execution window expired")
+            }
+            Some(_) => {
+                proposal.status = ProposalStatus::Executed;
+                None
+            }
+        };
+
+        let result = ExecutionResult {
+            success: failure.is_none(),
+            executed_at: now,
+            error_message: failure.map(str::to_string),
+            gas_used: 0,
+        };
+        self.execution_status = if result.success {
+            ExecutionStatus::Executed
+        } else {
+            ExecutionStatus::Failed
+        };
+        self.execution_result = Some(result.clone());
+        Some(result)
+    }
 }
 
 impl ExecutionResult {
@@ -2034,7 +3230,49 @@ impl ExecutionResult {
     }
 }
 
+impl ComparisonOperator {
+    /// Whether `actual <operator> target` holds
+    pub fn holds(self, actual: i128, target: i128) -> bool {
+        match self {
+            ComparisonOperator::Equal => actual == target,
+            ComparisonOperator::NotEqual => actual != target,
+            ComparisonOperator::GreaterThan => actual > target,
+            ComparisonOperator::GreaterThanOrEqual => actual >= target,
+            ComparisonOperator::LessThan => actual < target,
+            ComparisonOperator::LessThanOrEqual => actual <= target,
+        }
+    }
+}
+
 impl AutoExecutionTrigger {
+    /// Check every condition, recording the outcome on each
+    ///
+    /// Returns true when the trigger is active, under its trigger limit and
+    /// all conditions hold.
+    pub fn evaluate(&mut self, now: i64, slot: u64, oracles: &[AccountInfo]) -> Result<bool> {
+        let mut all_met = true;
+        for condition in self.conditions.iter_mut() {
+            let met = condition.evaluate(now, slot, oracles)?;
+            condition.met = met;
+            condition.last_checked_at = Some(now);
+            all_met &= met;
+        }
+        Ok(self.active && all_met && !self.exhausted())
+    }
+
+    /// Record a firing, deactivating the trigger once it hits `max_triggers`
+    pub fn record_firing(&mut self, now: i64) {
+        self.trigger_count = self.trigger_count.saturating_add(1);
+        self.last_triggered_at = Some(now);
+        if self.exhausted() {
+            self.active = false;
+        }
+    }
+
+    fn exhausted(&self) -> bool {
+        self.max_triggers != 0 && self.trigger_count >= self.max_triggers
+    }
+
     pub const fn space() -> usize {
         8 + // discriminator
         8 + // trigger_id
@@ -2051,6 +3289,49 @@ impl AutoExecutionTrigger {
 }
 
 impl TriggerCondition {
+    /// Check the condition's kind and the shape of its target value
+    pub fn validate(&self) -> Result<()> {
+        let expected_len = match self.condition_type.as_str() {
+            TRIGGER_CONDITION_TIMESTAMP | TRIGGER_CONDITION_SLOT => 8,
+            TRIGGER_CONDITION_ORACLE => 32 + 4 + 8,
+            _ => return err!(crate::GhostSpeakError::InvalidTriggerCondition),
+        };
+        require!(
+            self.target_value.len() == expected_len,
+            crate::GhostSpeakError::InvalidTriggerCondition
+        );
+        Ok(())
+    }
+
+    /// Whether the condition currently holds
+    ///
+    /// Oracle conditions read their oracle from `oracles` and fail if it
+    /// wasn't supplied.
+    pub fn evaluate(&self, now: i64, slot: u64, oracles: &[AccountInfo]) -> Result<bool> {
+        self.validate()?;
+        let value = &self.target_value;
+        let (actual, target) = match self.condition_type.as_str() {
+            TRIGGER_CONDITION_TIMESTAMP => (now as i128, read_i64(value, 0)? as i128),
+            TRIGGER_CONDITION_SLOT => (slot as i128, read_u64(value, 0)? as i128),
+            _ => {
+                let oracle = Pubkey::try_from(&value[..32])
+                    .map_err(|_| crate::GhostSpeakError::InvalidTriggerCondition)?;
+                let offset = u32::from_le_bytes(
+                    value[32..36]
+                        .try_into()
+                        .map_err(|_| crate::GhostSpeakError::InvalidTriggerCondition)?,
+                ) as usize;
+                let info = oracles
+                    .iter()
+                    .find(|info| info.key == &oracle)
+                    .ok_or(crate::GhostSpeakError::TriggerOracleMissing)?;
+                let data = info.try_borrow_data()?;
+                (read_i64(&data, offset)? as i128, read_i64(value, 36)? as i128)
+            }
+        };
+        Ok(self.operator.holds(actual, target))
+    }
+
     pub const fn size() -> usize {
         4 + 64 + // condition_type
         4 + 256 + // target_value
@@ -2059,3 +3340,683 @@ impl TriggerCondition {
         1 + 8 // last_checked_at
     }
 }
+
+/// Read a little-endian i64 at `offset`
+fn read_i64(data: &[u8], offset: usize) -> Result<i64> {
+    data.get(offset..offset.saturating_add(8))
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(i64::from_le_bytes)
+        .ok_or_else(|| error!(crate::GhostSpeakError::InvalidTriggerCondition))
+}
+
+/// Read a little-endian u64 at `offset`
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    read_i64(data, offset).map(|value| value as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::pagination::open_page;
+
+    fn multisig(signer_count: usize, threshold: u8, signer_change_threshold: u8) -> Multisig {
+        Multisig {
+            multisig_id: 1,
+            multisig_type: MultisigType::AgentTreasury,
+            threshold,
+            signers: (0..signer_count).map(|_| Pubkey::new_unique()).collect(),
+            owner: Pubkey::new_unique(),
+            created_at: 0,
+            updated_at: 0,
+            nonce: 0,
+            pending_transactions: Vec::new(),
+            config: MultisigConfig {
+                max_signers: 5,
+                default_timeout: 86_400,
+                allow_emergency_override: false,
+                emergency_threshold: None,
+                auto_execute: false,
+                signer_change_threshold,
+                allowed_transaction_types: Vec::new(),
+                daily_limits: Vec::new(),
+            },
+            emergency_config: EmergencyConfig {
+                emergency_contacts: Vec::new(),
+                emergency_threshold: 1,
+                emergency_timeout: 0,
+                emergency_transaction_types: Vec::new(),
+                freeze_enabled: false,
+                frozen: false,
+                frozen_at: None,
+                auto_unfreeze_duration: None,
+            },
+            type_config: MultisigTypeConfig::default_for_type(MultisigType::AgentTreasury),
+            pending_pages: PageCursor::default(),
+            veto_quorum: 0,
+            signer_weights: Vec::new(),
+            daily_spend: Vec::new(),
+            reserved: [0; 11],
+        }
+    }
+
+    #[test]
+    fn test_signer_change_approvals() {
+        // signer_change_threshold raises the bar but can't lower it
+        assert_eq!(multisig(3, 2, 3).signer_change_approvals(), 3);
+        assert_eq!(multisig(3, 2, 1).signer_change_approvals(), 2);
+    }
+
+    #[test]
+    fn test_signer_set_changes() {
+        // AgentTreasury allows 2..=5 signers
+        let mut ms = multisig(4, 2, 3);
+
+        let existing = ms.signers[0];
+        assert!(ms.add_signer(existing, 1).is_err());
+        ms.add_signer(Pubkey::new_unique(), 1).unwrap();
+        assert!(ms.add_signer(Pubkey::new_unique(), 2).is_err());
+        assert_eq!(ms.signers.len(), 5);
+
+        assert!(ms.remove_signer(Pubkey::new_unique(), 3).is_err());
+        ms.remove_signer(existing, 3).unwrap();
+        ms.remove_signer(ms.signers[0], 3).unwrap();
+        // Three signers left: dropping to two would strand the signer change threshold
+        assert!(ms.remove_signer(ms.signers[0], 4).is_err());
+        assert_eq!(ms.signers.len(), 3);
+        assert_eq!(ms.updated_at, 3);
+    }
+
+    #[test]
+    fn test_paged_transaction_timelock() {
+        let mut ms = multisig(3, 2, 0);
+        ms.multisig_type = MultisigType::Protocol;
+        ms.type_config = MultisigTypeConfig::default_for_type(MultisigType::Protocol);
+        let delay = ms.execution_timelock();
+        assert_eq!(delay, 48 * 3600);
+
+        let mut page = PendingTransactionPage {
+            multisig: Pubkey::new_unique(),
+            link: PageLink::default(),
+            transactions: Vec::new(),
+            bump: 255,
+        };
+        open_page(
+            &mut ms.pending_pages,
+            Pubkey::new_unique(),
+            &mut page.link,
+            None,
+            0,
+        )
+        .unwrap();
+
+        let (proposer, approver) = (ms.signers[0], ms.signers[1]);
+        let id = ms
+            .queue_paged_transaction(
+                &mut page,
+                proposer,
+                TransactionType::ParameterUpdate,
+                Pubkey::new_unique(),
+                [7; 32],
+                TransactionPriority::Normal,
+                0,
+            )
+            .unwrap();
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, proposer, [7; 32], None, delay)
+            .is_err());
+
+        assert!(ms
+            .approve_paged_transaction(&mut page, id, proposer, 10)
+            .is_err());
+        assert!(ms
+            .approve_paged_transaction(&mut page, id, approver, 10)
+            .unwrap());
+        let unlocks_at = page.transactions[0].time_lock.as_ref().unwrap().unlocks_at;
+        assert_eq!(unlocks_at, 10 + delay);
+
+        // Approved transactions wait out the timelock and must match the queued data
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, proposer, [7; 32], None, unlocks_at - 1)
+            .is_err());
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, proposer, [8; 32], None, unlocks_at)
+            .is_err());
+        ms.execute_paged_transaction(&mut page, id, proposer, [7; 32], None, unlocks_at)
+            .unwrap();
+        assert_eq!(page.transactions[0].status, TransactionStatus::Executed);
+    }
+
+    #[test]
+    fn test_daily_spending_limit() {
+        let mint = Pubkey::new_unique();
+        let mut ms = multisig(3, 2, 0);
+        ms.config.daily_limits = vec![(mint.to_string(), 100)];
+        ms.config.allow_emergency_override = true;
+
+        let mut page = PendingTransactionPage {
+            multisig: Pubkey::new_unique(),
+            link: PageLink::default(),
+            transactions: Vec::new(),
+            bump: 255,
+        };
+        open_page(
+            &mut ms.pending_pages,
+            Pubkey::new_unique(),
+            &mut page.link,
+            None,
+            0,
+        )
+        .unwrap();
+
+        let signers = ms.signers.clone();
+        let queue = |ms: &mut Multisig,
+                     page: &mut PendingTransactionPage,
+                     priority: TransactionPriority,
+                     now: i64| {
+            let id = ms
+                .queue_paged_transaction(
+                    page,
+                    signers[0],
+                    TransactionType::Transfer,
+                    Pubkey::new_unique(),
+                    [7; 32],
+                    priority,
+                    now,
+                )
+                .unwrap();
+            ms.approve_paged_transaction(page, id, signers[1], now)
+                .unwrap();
+            id
+        };
+        let spend = |amount| {
+            Some(SpendIntent {
+                token: mint,
+                amount,
+            })
+        };
+        let executor = signers[0];
+
+        // Transfers must say what they move
+        let id = queue(&mut ms, &mut page, TransactionPriority::Normal, 0);
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, executor, [7; 32], None, 0)
+            .is_err());
+        ms.execute_paged_transaction(&mut page, id, executor, [7; 32], spend(60), 0)
+            .unwrap();
+
+        let id = queue(&mut ms, &mut page, TransactionPriority::Normal, 10);
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, executor, [7; 32], spend(50), 10)
+            .is_err());
+        // Tokens without a limit are not tracked
+        let other = Some(SpendIntent {
+            token: Pubkey::new_unique(),
+            amount: 1_000,
+        });
+        ms.execute_paged_transaction(&mut page, id, executor, [7; 32], other, 10)
+            .unwrap();
+        assert_eq!(ms.daily_spend.len(), 1);
+
+        // An emergency needs every signer's approval to exceed the limit
+        let id = queue(&mut ms, &mut page, TransactionPriority::Emergency, 20);
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, executor, [7; 32], spend(50), 20)
+            .is_err());
+        ms.approve_paged_transaction(&mut page, id, signers[2], 20)
+            .unwrap();
+        ms.execute_paged_transaction(&mut page, id, executor, [7; 32], spend(50), 20)
+            .unwrap();
+        assert_eq!(ms.daily_spend[0].spent, 110);
+
+        // Half a day into the next window, half of the previous one still counts
+        let now = DAILY_SPEND_WINDOW + DAILY_SPEND_WINDOW / 2;
+        let id = queue(&mut ms, &mut page, TransactionPriority::Normal, now);
+        assert!(ms
+            .execute_paged_transaction(&mut page, id, executor, [7; 32], spend(50), now)
+            .is_err());
+        ms.execute_paged_transaction(&mut page, id, executor, [7; 32], spend(45), now)
+            .unwrap();
+        assert_eq!(ms.daily_spend[0].rolling_total(now), 100);
+
+        // After two quiet days the whole limit is available again
+        let now = 4 * DAILY_SPEND_WINDOW;
+        let id = queue(&mut ms, &mut page, TransactionPriority::Normal, now);
+        ms.execute_paged_transaction(&mut page, id, executor, [7; 32], spend(100), now)
+            .unwrap();
+    }
+
+    fn proposal(super_majority_required: bool) -> GovernanceProposal {
+        GovernanceProposal {
+            proposal_id: 1,
+            proposer: Pubkey::new_unique(),
+            title: String::new(),
+            description: String::new(),
+            proposal_type: ProposalType::ParameterUpdate,
+            created_at: 0,
+            voting_starts_at: 0,
+            voting_ends_at: 100,
+            execution_timestamp: None,
+            status: ProposalStatus::Active,
+            voting_results: VotingResults {
+                votes_for: 0,
+                votes_against: 0,
+                votes_abstain: 0,
+                total_voting_power: 0,
+                participation_rate: 0,
+                individual_votes: Vec::new(),
+                weighted_voting: false,
+                quorum_reached: false,
+                approval_threshold_met: false,
+            },
+            execution_params: ExecutionParams {
+                instructions: Vec::new(),
+                execution_delay: 50,
+                execution_conditions: Vec::new(),
+                cancellable: false,
+                auto_execute: false,
+                execution_authority: Pubkey::default(),
+            },
+            quorum_requirements: QuorumRequirements {
+                minimum_participation: 20,
+                approval_threshold: 51,
+                super_majority_required,
+                minimum_voting_power: 1_000,
+                quorum_method: QuorumMethod::Absolute,
+            },
+            metadata: ProposalMetadata {
+                ipfs_hash: None,
+                external_references: Vec::new(),
+                tags: Vec::new(),
+                risk_assessment: None,
+                impact_analysis: None,
+                implementation_timeline: None,
+            },
+            deposit: 0,
+            snapshot_strategy: SnapshotStrategy::ProposalCreation,
+            snapshot_at: 0,
+            reserved: [0; 47],
+        }
+    }
+
+    #[test]
+    fn test_proposal_voting_lifecycle() {
+        let mut p = proposal(false);
+        p.record_vote(VoteChoice::For, 600, 10).unwrap();
+        p.record_vote(VoteChoice::Against, 400, 20).unwrap();
+        p.record_vote(VoteChoice::Abstain, 500, 30).unwrap();
+        assert!(p.record_vote(VoteChoice::For, 1, 100).is_err());
+        assert_eq!(p.voting_results.total_voting_power, 1_500);
+
+        // No tally or execution while voting is open
+        assert!(p.tally(99).is_err());
+        assert!(p.mark_executed(200).is_err());
+
+        assert_eq!(p.tally(100).unwrap(), ProposalStatus::Passed);
+        assert!(p.voting_results.quorum_reached);
+        assert_eq!(p.execution_timestamp, Some(150));
+        assert!(p.tally(100).is_err());
+
+        assert!(p.mark_executed(149).is_err());
+        p.mark_executed(150).unwrap();
+        assert_eq!(p.status, ProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_proposal_veto_window() {
+        let mut p = proposal(false);
+        assert!(p.veto(10).is_err());
+        p.record_vote(VoteChoice::For, 2_000, 10).unwrap();
+        p.tally(100).unwrap();
+
+        // Execution opens at 150, closing the veto window
+        assert!(p.veto(150).is_err());
+        p.veto(149).unwrap();
+        assert_eq!(p.status, ProposalStatus::Vetoed);
+        assert!(p.mark_executed(150).is_err());
+        // Quorum was reached, so the proposer still gets the deposit back
+        p.deposit = 10;
+        assert_eq!(p.release_deposit().unwrap(), (10, true));
+    }
+
+    #[test]
+    fn test_proposal_tally_failures() {
+        // 60% for misses a super majority
+        let mut p = proposal(true);
+        p.record_vote(VoteChoice::For, 600, 10).unwrap();
+        p.record_vote(VoteChoice::Against, 400, 10).unwrap();
+        assert_eq!(p.tally(100).unwrap(), ProposalStatus::Failed);
+        assert!(!p.voting_results.approval_threshold_met);
+
+        // Unanimous but below quorum
+        let mut p = proposal(false);
+        p.record_vote(VoteChoice::For, 999, 10).unwrap();
+        assert_eq!(p.tally(100).unwrap(), ProposalStatus::Failed);
+        assert!(!p.voting_results.quorum_reached);
+    }
+
+    #[test]
+    fn test_proposal_deposit_release() {
+        // Failed with quorum: refunded, once
+        let mut p = proposal(true);
+        p.deposit = 1_000;
+        p.record_vote(VoteChoice::Against, 1_000, 10).unwrap();
+        assert!(p.release_deposit().is_err());
+        p.tally(100).unwrap();
+        assert_eq!(p.release_deposit().unwrap(), (1_000, true));
+        assert_eq!(p.deposit, 0);
+        assert!(p.release_deposit().is_err());
+
+        // Below quorum: forfeited
+        let mut p = proposal(false);
+        p.deposit = 1_000;
+        p.record_vote(VoteChoice::For, 999, 10).unwrap();
+        p.tally(100).unwrap();
+        assert_eq!(p.release_deposit().unwrap(), (1_000, false));
+
+        // Cancelled: forfeited
+        let mut p = proposal(false);
+        p.deposit = 1_000;
+        p.cancel().unwrap();
+        assert!(p.cancel().is_err());
+        assert_eq!(p.release_deposit().unwrap(), (1_000, false));
+    }
+
+    fn execution_queue() -> ExecutionQueue {
+        ExecutionQueue {
+            batch_id: 1,
+            executor: Pubkey::new_unique(),
+            created_at: 0,
+            last_execution_at: None,
+            status: ExecutionQueueStatus::Pending,
+            queued_proposals: Vec::new(),
+            max_batch_size: 5,
+            auto_execute: false,
+            execution_window: 100,
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn test_proposal_snapshot_point() {
+        let mut p = proposal(false);
+        p.created_at = 5;
+        p.voting_starts_at = 50;
+
+        p.set_snapshot(SnapshotStrategy::VotingStart).unwrap();
+        assert_eq!(p.snapshot_at, 50);
+        p.set_snapshot(SnapshotStrategy::ProposalCreation).unwrap();
+        assert_eq!(p.snapshot_at, 5);
+
+        // Proposer-chosen snapshot points are refused
+        assert!(p.set_snapshot(SnapshotStrategy::Timestamp).is_err());
+        assert!(p.set_snapshot(SnapshotStrategy::BlockHeight).is_err());
+        assert_eq!(p.snapshot_strategy, SnapshotStrategy::ProposalCreation);
+    }
+
+    #[test]
+    fn test_execution_queue_priority_order() {
+        let mut queue = execution_queue();
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        queue
+            .enqueue(keys[0], 0, TransactionPriority::Normal, 0)
+            .unwrap();
+        queue
+            .enqueue(keys[1], 1, TransactionPriority::Critical, 1)
+            .unwrap();
+        queue
+            .enqueue(keys[2], 2, TransactionPriority::Normal, 2)
+            .unwrap();
+        queue
+            .enqueue(keys[3], 3, TransactionPriority::Low, 3)
+            .unwrap();
+        assert!(queue
+            .enqueue(keys[0], 0, TransactionPriority::High, 4)
+            .is_err());
+
+        let order: Vec<u64> = queue
+            .queued_proposals
+            .iter()
+            .map(|e| e.proposal_id)
+            .collect();
+        assert_eq!(order, vec![1, 0, 2, 3]);
+
+        queue.cancel(keys[2]).unwrap();
+        assert!(queue.cancel(keys[2]).is_err());
+        queue.finish_batch(0, 5);
+        assert_eq!(queue.status, ExecutionQueueStatus::Pending);
+    }
+
+    #[test]
+    fn test_queued_proposal_settlement() {
+        let mut queue = execution_queue();
+        let mut p = proposal(false);
+        p.record_vote(VoteChoice::For, 1_000, 10).unwrap();
+        p.tally(100).unwrap();
+        p.mark_queued().unwrap();
+        assert!(p.mark_executed(1_000).is_err());
+        queue
+            .enqueue(Pubkey::new_unique(), 1, TransactionPriority::Normal, 100)
+            .unwrap();
+
+        // Still inside the execution delay
+        let entry = &mut queue.queued_proposals[0];
+        assert!(entry.settle(&mut p, queue.execution_window, 149).is_none());
+
+        let result = entry.settle(&mut p, queue.execution_window, 150).unwrap();
+        assert!(result.success);
+        assert_eq!(p.status, ProposalStatus::Executed);
+        queue.finish_batch(1, 150);
+        assert_eq!(queue.status, ExecutionQueueStatus::Completed);
+
+        // Past the execution window the proposal expires
+        let mut late = proposal(false);
+        late.record_vote(VoteChoice::For, 1_000, 10).unwrap();
+        late.tally(100).unwrap();
+        late.mark_queued().unwrap();
+        assert!(late.cancel_queued().is_err());
+        queue
+            .enqueue(Pubkey::new_unique(), 2, TransactionPriority::Normal, 100)
+            .unwrap();
+        let entry = &mut queue.queued_proposals[0];
+        let result = entry.settle(&mut late, 100, 251).unwrap();
+        assert!(!result.success);
+        assert_eq!(entry.execution_status, ExecutionStatus::Failed);
+        assert_eq!(late.status, ProposalStatus::Expired);
+    }
+
+    #[test]
+    fn test_weighted_signers() {
+        let mut ms = multisig(3, 2, 0);
+        assert_eq!(ms.total_weight(), 3);
+        assert!(ms.set_signer_weights(vec![5, 3], 6, 1).is_err());
+        assert!(ms.set_signer_weights(vec![5, 3, 0], 6, 1).is_err());
+        assert!(ms.set_signer_weights(vec![5, 3, 2], 11, 1).is_err());
+        assert!(ms.set_signer_weights(vec![200, 50, 10], 6, 1).is_err());
+        ms.set_signer_weights(vec![5, 3, 2], 6, 1).unwrap();
+        assert_eq!(ms.total_weight(), 10);
+        assert_eq!(ms.weight_of(&[ms.signers[0], Pubkey::new_unique()]), 5);
+
+        let mut page = PendingTransactionPage {
+            multisig: Pubkey::new_unique(),
+            link: PageLink::default(),
+            transactions: Vec::new(),
+            bump: 255,
+        };
+        open_page(
+            &mut ms.pending_pages,
+            Pubkey::new_unique(),
+            &mut page.link,
+            None,
+            0,
+        )
+        .unwrap();
+        let id = ms
+            .queue_paged_transaction(
+                &mut page,
+                ms.signers[0],
+                TransactionType::ParameterUpdate,
+                Pubkey::new_unique(),
+                [7; 32],
+                TransactionPriority::Normal,
+                0,
+            )
+            .unwrap();
+        // 5 of 6 weight after the proposer, 7 once the lightest signer approves
+        assert_eq!(page.transactions[0].status, TransactionStatus::Pending);
+        assert!(ms
+            .approve_paged_transaction(&mut page, id, ms.signers[2], 1)
+            .unwrap());
+
+        // New signers join with a weight of one
+        let joined = Pubkey::new_unique();
+        ms.add_signer(joined, 2).unwrap();
+        assert_eq!(ms.signer_weights, vec![5, 3, 2, 1]);
+        ms.remove_signer(ms.signers[0], 3).unwrap();
+        assert_eq!(ms.signer_weights, vec![3, 2, 1]);
+        // Dropping below the threshold's weight is refused
+        assert!(ms.remove_signer(joined, 4).is_err());
+
+        ms.set_signer_weights(Vec::new(), 2, 5).unwrap();
+        assert_eq!(ms.total_weight(), 3);
+    }
+
+    #[test]
+    fn test_change_threshold() {
+        let mut ms = multisig(3, 2, 0);
+        assert!(ms.change_threshold(0, 1).is_err());
+        assert!(ms.change_threshold(4, 1).is_err());
+        ms.change_threshold(3, 1).unwrap();
+        assert_eq!(ms.threshold, 3);
+    }
+
+    #[test]
+    fn test_emergency_freeze_cycle() {
+        let mut ms = multisig(3, 2, 0);
+        let contact = Pubkey::new_unique();
+        // A signer listed as a contact counts once: 3 signers + 1 contact
+        let contacts = vec![contact, ms.signers[0]];
+        assert!(ms
+            .set_emergency_config(contacts.clone(), 5, true, Some(60), 1)
+            .is_err());
+        assert!(ms
+            .set_emergency_config(contacts.clone(), 0, true, Some(60), 1)
+            .is_err());
+        assert!(ms
+            .set_emergency_config(contacts.clone(), 2, true, Some(0), 1)
+            .is_err());
+        ms.set_emergency_config(contacts, 4, true, Some(60), 1)
+            .unwrap();
+
+        assert!(ms.unfreeze(2).is_err());
+        ms.freeze(100).unwrap();
+        assert!(ms.freeze(101).is_err());
+        assert!(!ms.freeze_expired(159));
+        assert!(ms.freeze_expired(160));
+        ms.unfreeze(160).unwrap();
+        assert!(!ms.freeze_expired(200));
+
+        // Without a duration only the quorum can lift a freeze
+        ms.emergency_config.auto_unfreeze_duration = None;
+        ms.freeze(300).unwrap();
+        assert!(!ms.freeze_expired(i64::MAX));
+    }
+
+    #[test]
+    fn test_frozen_multisig_cannot_co_sign() {
+        use crate::state::admin_authority::AdminAuthority;
+
+        let mut ms = multisig(3, 2, 0);
+        ms.set_emergency_config(Vec::new(), 2, true, None, 1)
+            .unwrap();
+        let keys = ms.signers.clone();
+        let owner = Pubkey::default();
+        let (mut lamports_a, mut lamports_b) = (0u64, 0u64);
+        let (mut data_a, mut data_b) = ([0u8; 0], [0u8; 0]);
+        let approvals = [
+            AccountInfo::new(&keys[0], true, false, &mut lamports_a, &mut data_a, &owner, false, 0),
+            AccountInfo::new(&keys[1], true, false, &mut lamports_b, &mut data_b, &owner, false, 0),
+        ];
+        let mut admin = AdminAuthority {
+            admin: Pubkey::new_unique(),
+            multisig: Pubkey::new_unique(),
+            pending_admin: Pubkey::default(),
+            executable_at: 0,
+            updated_at: 0,
+            bump: 255,
+        };
+        let new_admin = Pubkey::new_unique();
+
+        // Proposing needs the co-signers; applying needs the multisig unfrozen
+        ms.require_approvals(&approvals).unwrap();
+        admin.propose_transfer(new_admin, 0, 10).unwrap();
+        ms.freeze(20).unwrap();
+        assert!(ms.require_approvals(&approvals).is_err());
+        assert!(ms.require_not_frozen().is_err());
+
+        ms.unfreeze(30).unwrap();
+        ms.require_not_frozen().unwrap();
+        admin.apply_transfer(30).unwrap();
+        assert_eq!(admin.admin, new_admin);
+    }
+
+    fn condition(condition_type: &str, target_value: Vec<u8>) -> TriggerCondition {
+        TriggerCondition {
+            condition_type: condition_type.to_string(),
+            target_value,
+            operator: ComparisonOperator::GreaterThanOrEqual,
+            met: false,
+            last_checked_at: None,
+        }
+    }
+
+    #[test]
+    fn test_trigger_evaluation() {
+        let mut trigger = AutoExecutionTrigger {
+            trigger_id: 1,
+            target: Pubkey::new_unique(),
+            trigger_type: TriggerType::TimeBased,
+            conditions: vec![
+                condition(TRIGGER_CONDITION_TIMESTAMP, 100i64.to_le_bytes().to_vec()),
+                condition(TRIGGER_CONDITION_SLOT, 5u64.to_le_bytes().to_vec()),
+            ],
+            active: true,
+            created_at: 0,
+            last_triggered_at: None,
+            max_triggers: 1,
+            trigger_count: 0,
+            reserved: [0; 32],
+        };
+
+        // Only the timestamp condition holds
+        assert!(!trigger.evaluate(100, 4, &[]).unwrap());
+        assert!(trigger.conditions[0].met);
+        assert!(!trigger.conditions[1].met);
+        assert_eq!(trigger.conditions[1].last_checked_at, Some(100));
+
+        assert!(trigger.evaluate(101, 5, &[]).unwrap());
+        trigger.record_firing(101);
+        assert_eq!(trigger.trigger_count, 1);
+        assert_eq!(trigger.last_triggered_at, Some(101));
+        assert!(!trigger.active);
+        assert!(!trigger.evaluate(102, 6, &[]).unwrap());
+
+        // Malformed and unsupplied-oracle conditions are rejected
+        assert!(condition(TRIGGER_CONDITION_SLOT, vec![0; 4])
+            .validate()
+            .is_err());
+        assert!(condition("block_height", vec![0; 8]).validate().is_err());
+        let oracle = condition(TRIGGER_CONDITION_ORACLE, vec![0; 44]);
+        oracle.validate().unwrap();
+        assert!(oracle.evaluate(0, 0, &[]).is_err());
+    }
+
+    #[test]
+    fn test_comparison_operator_holds() {
+        assert!(ComparisonOperator::Equal.holds(3, 3));
+        assert!(ComparisonOperator::NotEqual.holds(3, 4));
+        assert!(ComparisonOperator::GreaterThan.holds(4, 3));
+        assert!(!ComparisonOperator::GreaterThan.holds(3, 3));
+        assert!(ComparisonOperator::LessThan.holds(-1, 0));
+        assert!(ComparisonOperator::LessThanOrEqual.holds(0, 0));
+    }
+}
@@ -0,0 +1,209 @@
+/*!
+ * Insurance Pool State
+ *
+ * Slashed GHOST is not burned into the treasury: once a slash is final its
+ * forfeited tokens are swept into a protocol insurance pool. Victims of
+ * fraud by a slashed agent file a claim against the pool, and the slash
+ * review multisig decides how much of it to pay out.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Seed for the insurance pool: [seed]
+pub const INSURANCE_POOL_SEED: &[u8] = b"insurance_pool";
+
+/// Seed for the pool's GHOST vault: [seed, insurance_pool]
+pub const INSURANCE_VAULT_SEED: &[u8] = b"insurance_vault";
+
+/// Seed for victim claims: [seed, claimant, accused]
+pub const INSURANCE_CLAIM_SEED: &[u8] = b"insurance_claim";
+
+/// Maximum length of a claim's evidence URI
+pub const MAX_INSURANCE_EVIDENCE_URI: usize = 200;
+
+/// Insurance Pool - slashed GHOST held for fraud victims
+///
+/// Seeds: ["insurance_pool"]
+#[account]
+pub struct InsurancePool {
+    /// Staking authority that created the pool
+    pub authority: Pubkey,
+    /// GHOST token mint
+    pub ghost_mint: Pubkey,
+    /// Token account holding the pooled GHOST
+    pub vault: Pubkey,
+    /// Lifetime GHOST received from finalized slashes
+    pub total_deposited: u64,
+    /// Lifetime GHOST paid out on claims
+    pub total_paid: u64,
+    /// Claims filed since inception
+    pub claims_filed: u64,
+    /// Claims awaiting review
+    pub open_claims: u64,
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // ghost_mint
+        32 + // vault
+        8 + // total_deposited
+        8 + // total_paid
+        8 + // claims_filed
+        8 + // open_claims
+        1; // bump
+
+    /// Record forfeited slash tokens swept into the vault
+    pub fn record_deposit(&mut self, amount: u64) {
+        self.total_deposited = self.total_deposited.saturating_add(amount);
+    }
+
+    /// Record a newly filed claim
+    pub fn record_claim(&mut self) {
+        self.claims_filed = self.claims_filed.saturating_add(1);
+        self.open_claims = self.open_claims.saturating_add(1);
+    }
+
+    /// Record a reviewed claim and its payout
+    pub fn record_payout(&mut self, payout: u64) {
+        self.open_claims = self.open_claims.saturating_sub(1);
+        self.total_paid = self.total_paid.saturating_add(payout);
+    }
+}
+
+/// Review state of an insurance claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InsuranceClaimStatus {
+    /// Awaiting the review multisig
+    Pending,
+    /// Paid out in full or in part
+    Paid,
+    /// Rejected without payout
+    Rejected,
+}
+
+/// Insurance Claim - a fraud victim's request for compensation
+///
+/// Seeds: ["insurance_claim", claimant, accused]
+///
+/// One claim per claimant and slashed agent; the record is kept after
+/// review so the same loss can't be claimed twice.
+#[account]
+pub struct InsuranceClaim {
+    /// Victim who filed the claim
+    pub claimant: Pubkey,
+    /// Slashed agent owner the fraud is attributed to
+    pub accused: Pubkey,
+    /// GHOST requested
+    pub amount_requested: u64,
+    /// GHOST paid out on review
+    pub amount_paid: u64,
+    /// Evidence of the loss (IPFS/Arweave URI)
+    pub evidence_uri: String,
+    pub filed_at: i64,
+    pub reviewed_at: i64,
+    pub status: InsuranceClaimStatus,
+    pub bump: u8,
+}
+
+impl InsuranceClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // claimant
+        32 + // accused
+        8 + // amount_requested
+        8 + // amount_paid
+        4 + MAX_INSURANCE_EVIDENCE_URI + // evidence_uri
+        8 + // filed_at
+        8 + // reviewed_at
+        1 + // status
+        1; // bump
+
+    /// Rule on a pending claim, paying `payout` out of `available` pool funds
+    pub fn review(&mut self, payout: u64, available: u64, now: i64) -> Result<()> {
+        require!(
+            self.status == InsuranceClaimStatus::Pending,
+            GhostSpeakError::InsuranceClaimNotPending
+        );
+        require!(
+            payout <= self.amount_requested,
+            GhostSpeakError::InvalidAmount
+        );
+        require!(
+            payout <= available,
+            GhostSpeakError::InsufficientInsuranceFunds
+        );
+        self.amount_paid = payout;
+        self.reviewed_at = now;
+        self.status = if payout > 0 {
+            InsuranceClaimStatus::Paid
+        } else {
+            InsuranceClaimStatus::Rejected
+        };
+        Ok(())
+    }
+}
+
+#[event]
+pub struct InsurancePoolInitializedEvent {
+    pub authority: Pubkey,
+    pub ghost_mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct InsuranceClaimFiledEvent {
+    pub claimant: Pubkey,
+    pub accused: Pubkey,
+    pub amount_requested: u64,
+    pub evidence_uri: String,
+}
+
+#[event]
+pub struct InsuranceClaimReviewedEvent {
+    pub claimant: Pubkey,
+    pub accused: Pubkey,
+    pub multisig: Pubkey,
+    pub amount_requested: u64,
+    pub amount_paid: u64,
+    pub status: InsuranceClaimStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim(amount_requested: u64) -> InsuranceClaim {
+        InsuranceClaim {
+            claimant: Pubkey::new_unique(),
+            accused: Pubkey::new_unique(),
+            amount_requested,
+            amount_paid: 0,
+            evidence_uri: String::new(),
+            filed_at: 0,
+            reviewed_at: 0,
+            status: InsuranceClaimStatus::Pending,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_claim_review() {
+        // Payout bounded by the request and by the pool's balance
+        let mut partial = claim(1_000);
+        assert!(partial.review(1_001, 5_000, 10).is_err());
+        assert!(partial.review(800, 500, 10).is_err());
+        partial.review(500, 500, 10).unwrap();
+        assert_eq!(partial.status, InsuranceClaimStatus::Paid);
+        assert_eq!(partial.amount_paid, 500);
+
+        // A reviewed claim can't be paid again
+        assert!(partial.review(500, 5_000, 20).is_err());
+
+        let mut rejected = claim(1_000);
+        rejected.review(0, 0, 10).unwrap();
+        assert_eq!(rejected.status, InsuranceClaimStatus::Rejected);
+    }
+}
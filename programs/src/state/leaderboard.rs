@@ -0,0 +1,177 @@
+/*!
+ * Leaderboard State
+ *
+ * Top-N ranking PDAs per category, so front-ends can render rankings
+ * without scanning every agent account via getProgramAccounts.
+ *
+ * Boards are maintained lazily: anyone may submit an agent, and it only
+ * enters the board if there is room or its score beats the current floor.
+ * Agents already on the board are re-ranked (or dropped when no longer
+ * eligible) each time they are submitted.
+ */
+
+use anchor_lang::prelude::*;
+
+/// Seed for leaderboard PDAs: [seed, category]
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+
+/// Category ranking every active agent
+pub const GLOBAL_LEADERBOARD: &str = "global";
+
+/// A ranked agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub agent: Pubkey,
+    /// Ghost Score (0-1000) when last submitted
+    pub score: u32,
+    pub updated_at: i64,
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + // agent
+        4 + // score
+        8; // updated_at
+}
+
+/// Top-N agents for one category, highest score first
+///
+/// Seeds: ["leaderboard", category]
+#[account]
+pub struct Leaderboard {
+    /// "global" or a skill tag
+    pub category: String,
+    /// Maximum number of entries
+    pub capacity: u8,
+    /// Ranked entries (descending score; ties keep the earlier holder first)
+    pub entries: Vec<LeaderboardEntry>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const MAX_CATEGORY_LEN: usize = 32;
+    pub const MAX_CAPACITY: u8 = 100;
+
+    pub const fn space(capacity: u8) -> usize {
+        8 + // discriminator
+        4 + Self::MAX_CATEGORY_LEN + // category
+        1 + // capacity
+        4 + (capacity as usize * LeaderboardEntry::LEN) + // entries
+        8 + // updated_at
+        1 // bump
+    }
+
+    /// Lowest score on a full board (None while there is room)
+    pub fn floor(&self) -> Option<u32> {
+        if self.entries.len() < self.capacity as usize {
+            return None;
+        }
+        self.entries.last().map(|e| e.score)
+    }
+
+    /// Zero-based rank of `agent`, if ranked
+    pub fn rank_of(&self, agent: &Pubkey) -> Option<usize> {
+        self.entries.iter().position(|e| e.agent == *agent)
+    }
+
+    /// Insert or re-rank `agent`, returning its new rank if it is on the board
+    pub fn submit(&mut self, agent: Pubkey, score: u32, now: i64) -> Option<usize> {
+        match self.rank_of(&agent) {
+            Some(rank) => {
+                self.entries.remove(rank);
+            }
+            None => {
+                if self.floor().is_some_and(|floor| score <= floor) {
+                    return None;
+                }
+            }
+        }
+
+        // After all entries with an equal or higher score
+        let rank = self.entries.partition_point(|e| e.score >= score);
+        self.entries.insert(
+            rank,
+            LeaderboardEntry {
+                agent,
+                score,
+                updated_at: now,
+            },
+        );
+        self.entries.truncate(self.capacity as usize);
+        self.updated_at = now;
+
+        (rank < self.capacity as usize).then_some(rank)
+    }
+
+    /// Drop `agent` from the board; returns whether it was ranked
+    pub fn remove(&mut self, agent: &Pubkey, now: i64) -> bool {
+        match self.rank_of(agent) {
+            Some(rank) => {
+                self.entries.remove(rank);
+                self.updated_at = now;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[event]
+pub struct LeaderboardUpdatedEvent {
+    pub category: String,
+    pub agent: Pubkey,
+    pub score: u32,
+    /// New zero-based rank (None if not ranked)
+    pub rank: Option<u8>,
+    /// Lowest score on the board after the update (None while not full)
+    pub floor: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(capacity: u8) -> Leaderboard {
+        Leaderboard {
+            category: GLOBAL_LEADERBOARD.to_string(),
+            capacity,
+            entries: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_leaderboard_ranking() {
+        let mut lb = board(3);
+        let (a, b, c, d) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(lb.submit(a, 500, 1), Some(0));
+        assert_eq!(lb.submit(b, 700, 1), Some(0));
+        assert_eq!(lb.floor(), None);
+        // Ties rank behind the existing holder
+        assert_eq!(lb.submit(c, 500, 1), Some(2));
+        assert_eq!(lb.floor(), Some(500));
+
+        // Must beat the floor to enter a full board
+        assert_eq!(lb.submit(d, 500, 2), None);
+        assert_eq!(lb.rank_of(&d), None);
+        assert_eq!(lb.submit(d, 600, 2), Some(1));
+        assert_eq!(lb.entries.len(), 3);
+        assert_eq!(lb.rank_of(&c), None, "lowest entry evicted");
+
+        // Ranked agents are re-ranked even when their score drops
+        assert_eq!(lb.submit(b, 100, 3), Some(2));
+        assert_eq!(lb.entries[0].agent, d);
+        assert_eq!(lb.floor(), Some(100));
+
+        assert!(lb.remove(&b, 4));
+        assert!(!lb.remove(&b, 4));
+        assert_eq!(lb.floor(), None);
+    }
+}
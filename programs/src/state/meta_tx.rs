@@ -0,0 +1,164 @@
+/*!
+ * Meta-Transaction State
+ *
+ * Gasless agent actions. The agent owner signs a typed payload off-chain and
+ * any relayer submits it through `execute_meta_tx`. The relayer is paid from
+ * a prepaid lamport balance held by the agent's `MetaTxAccount`, so the
+ * owner key never needs to hold SOL. Replay protection comes from the
+ * owner's `MetaNonce`.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Domain separator for signed meta-transaction payloads
+pub const META_TX_DOMAIN: &[u8] = b"ghostspeak:meta_tx:v1";
+
+/// Seed for prepaid balance PDAs: [seed, agent]
+pub const META_TX_ACCOUNT_SEED: &[u8] = b"meta_tx_account";
+
+/// Agent action an owner can authorize off-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MetaAction {
+    /// Activate or deactivate the agent
+    SetActive { is_active: bool },
+    /// Replace the agent's metadata URI
+    UpdateMetadataUri { metadata_uri: String },
+    /// Replace the agent's service endpoint
+    UpdateServiceEndpoint { service_endpoint: String },
+    /// Change the x402 price per call
+    SetX402Price { price_per_call: u64 },
+}
+
+/// Typed payload signed by the agent owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MetaTxPayload {
+    pub action: MetaAction,
+    /// Must be higher than the owner's last used `MetaNonce`
+    pub nonce: u64,
+    /// Unix timestamp after which the payload is rejected
+    pub expires_at: i64,
+    /// Lamports paid to the relayer from the prepaid balance
+    pub fee: u64,
+}
+
+/// Prepaid relayer balance for one agent
+///
+/// Seeds: ["meta_tx_account", agent]
+#[account]
+pub struct MetaTxAccount {
+    pub agent: Pubkey,
+    /// Prepaid lamports available for relayer fees (excludes rent)
+    pub balance: u64,
+    /// Meta-transactions executed so far
+    pub executed: u64,
+    pub bump: u8,
+}
+
+impl MetaTxAccount {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        8 + // balance
+        8 + // executed
+        1; // bump
+
+    /// Bytes the owner signs for `payload`
+    ///
+    /// Binding the agent stops a payload being replayed against another agent
+    /// owned by the same key.
+    pub fn signing_message(agent: &Pubkey, payload: &MetaTxPayload) -> Result<Vec<u8>> {
+        let mut message = Vec::with_capacity(META_TX_DOMAIN.len() + 32 + 64);
+        message.extend_from_slice(META_TX_DOMAIN);
+        message.extend_from_slice(agent.as_ref());
+        payload.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    /// Deduct a relayer fee from the prepaid balance
+    pub fn charge(&mut self, fee: u64) -> Result<()> {
+        require!(
+            fee <= self.balance,
+            GhostSpeakError::InsufficientMetaTxBalance
+        );
+        self.balance -= fee;
+        self.executed = self.executed.saturating_add(1);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct MetaTxBalanceChangedEvent {
+    pub agent: Pubkey,
+    /// Positive for deposits, negative for withdrawals
+    pub delta: i64,
+    pub balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetaTxExecutedEvent {
+    pub agent: Pubkey,
+    pub owner: Pubkey,
+    pub relayer: Pubkey,
+    pub action: MetaAction,
+    pub nonce: u64,
+    pub fee: u64,
+    pub balance: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(nonce: u64, expires_at: i64, fee: u64) -> MetaTxPayload {
+        MetaTxPayload {
+            action: MetaAction::SetActive { is_active: false },
+            nonce,
+            expires_at,
+            fee,
+        }
+    }
+
+    #[test]
+    fn test_meta_tx_charge() {
+        let mut account = MetaTxAccount {
+            agent: Pubkey::new_unique(),
+            balance: 10_000,
+            executed: 0,
+            bump: 255,
+        };
+
+        account.charge(4_000).unwrap();
+        assert_eq!((account.balance, account.executed), (6_000, 1));
+
+        // Overdraft is rejected untouched
+        assert!(account.charge(6_001).is_err());
+        assert_eq!((account.balance, account.executed), (6_000, 1));
+
+        account.charge(6_000).unwrap();
+        assert_eq!(account.balance, 0);
+    }
+
+    #[test]
+    fn test_meta_tx_signing_message() {
+        let agent = Pubkey::new_unique();
+        let message = MetaTxAccount::signing_message(&agent, &payload(3, 100, 5)).unwrap();
+        assert!(message.starts_with(META_TX_DOMAIN));
+        assert_eq!(
+            &message[META_TX_DOMAIN.len()..META_TX_DOMAIN.len() + 32],
+            agent.as_ref()
+        );
+
+        // Any field change alters the signed bytes
+        assert_ne!(
+            message,
+            MetaTxAccount::signing_message(&agent, &payload(4, 100, 5)).unwrap()
+        );
+        assert_ne!(
+            message,
+            MetaTxAccount::signing_message(&Pubkey::new_unique(), &payload(3, 100, 5)).unwrap()
+        );
+    }
+}
@@ -6,21 +6,41 @@
  */
 
 // Core modules
+pub mod admin_authority; // Governed protocol admin
+pub mod authority_transfer; // Pending authority handovers
 pub mod agent;
+pub mod agent_handle; // Human-readable handles mapped to DIDs
 pub mod audit;
+pub mod config_history; // Append-only parameter change history
+pub mod buyback; // Protocol GHOST buybacks
 pub mod credential;
+pub mod denylist; // Sanctions denylist of agents and owners
 pub mod did; // W3C-compliant decentralized identifiers (did:sol)
+pub mod fee_exemption; // Fee exemption allowlist
+pub mod fee_token; // Tokens accepted for protocol fees
+pub mod fee_vault; // Per-token protocol fee vaults
 pub mod external_id_mapping; // Cross-platform Ghost ID resolution (NEW FOR GHOST)
 pub mod agent_auth; // Trustless agent pre-authorization system
 pub mod ghost_protect; // B2C escrow with dispute resolution
 pub mod governance; // Multisig and governance structures
+pub mod insurance_pool; // Slash-funded fraud insurance
+pub mod leaderboard; // Top-N agent rankings per category
 pub mod marketplace; // Service listings and job postings
+pub mod meta_tx; // Gasless owner-signed agent actions
+pub mod parameter_registry; // Governed runtime parameters
 pub mod privacy; // Privacy-preserving reputation
+pub mod referral; // Referrer accounting
 pub mod protocol_config; // Global protocol configuration
 pub mod reputation; // Multi-source reputation aggregation
 pub mod reputation_nft; // Reputation NFT badges
+pub mod revenue_pool; // USDC revenue share for stakers
+pub mod session_key; // Scoped ephemeral keys for agent automation
+pub mod signed_authorization; // Ed25519-signed off-chain consents
+pub mod security_council; // Council veto over passed proposals
 pub mod security_governance; // RBAC and security policies
 pub mod staking; // GHOST token staking for reputation boost
+pub mod sybil; // Funder-fingerprint sybil heuristics
+pub mod tag_registry; // Governed reputation tag taxonomy
 pub mod user_registry; // User and agent registry
 
 // Re-export all types with selective imports to avoid conflicts
@@ -40,21 +60,36 @@ pub use agent::{
     // PDA Seeds (NEW - for instructions)
     AGENT_SEED,
 };
+// Agent handles
+pub use agent_handle::*;
 // External ID mapping for cross-platform resolution (NEW FOR GHOST)
 pub use external_id_mapping::ExternalIdMapping;
 // Compressed agent types
 pub use crate::instructions::agent_compressed::{
     AgentTreeConfig, CompressedAgentCreatedEvent, CompressedAgentMetadata,
 };
+// Compressed credential types
+pub use crate::instructions::credential_compressed::{
+    CompressedCredentialIssuedEvent, CompressedCredentialLeaf, CredentialTreeConfig,
+    CREDENTIAL_TREE_CONFIG_SEED,
+};
+// Compressed DID types
+pub use crate::instructions::did_compressed::{
+    CompressedDidLeaf, CompressedDidUpdatedEvent, DidTreeConfig, DID_TREE_CONFIG_SEED,
+};
 // Staking types
 pub use staking::{
-    AccessTier, GhostSlashedEvent, GhostStakedEvent, GhostUnstakedEvent, SlashReason,
-    StakingAccount, StakingConfig, TierUpdatedEvent,
+    AccessTier, AccessTierStatus, ApiQuotaConsumedEvent, DisputePriorityUpdatedEvent,
+    GhostSlashedEvent, GhostStakedEvent, GhostUnstakedEvent, SlashPreview, SlashReason,
+    StakingAccount, StakingConfig, StakingParams, TierSchedule, TierUpdatedEvent,
 };
 // Import Ghost Protect escrow types
 pub use ghost_protect::{
-    ArbitratorDecision, DeliverySubmittedEvent, DisputeFiledEvent, DisputeResolvedEvent,
-    EscrowCompletedEvent, EscrowCreatedEvent, EscrowStatus, GhostProtectEscrow,
+    ArbitrationPreview, ArbitratorDecision, ClientReputation, ClientReputationUpdatedEvent,
+    DeliverySubmittedEvent, DisputeFiledEvent, DisputeQueue, DisputeQueueEntry,
+    DisputeQueuedEvent, DisputeResolvedEvent, EscrowCancelledEvent, EscrowCompletedEvent,
+    EscrowCreatedEvent, EscrowStatus, GhostProtectEscrow, TrialBondClawedBackEvent,
+    TrialEscrowCreatedEvent, DISPUTE_QUEUE_SEED,
 };
 // Audit module types
 pub use audit::{
@@ -63,6 +98,7 @@ pub use audit::{
     AuditConfig,
     AuditContext,
     AuditEntry,
+    AuditEntryPage,
     AuditTrail,
     BackupFrequency,
     ComplianceFlags,
@@ -94,10 +130,39 @@ pub use credential::*;
 pub use did::*;
 // Governance and multisig
 pub use governance::*;
+// Leaderboards
+pub use leaderboard::*;
+// Sybil heuristics
+pub use sybil::*;
+// Meta-transactions
+pub use meta_tx::*;
 // Protocol configuration
 pub use protocol_config::*;
+// Protocol admin
+pub use admin_authority::*;
+pub use authority_transfer::*;
+pub use security_council::*;
+// Staker revenue share
+pub use revenue_pool::*;
+// Slash-funded insurance
+pub use insurance_pool::*;
+pub use config_history::*;
+pub use denylist::*;
+pub use fee_exemption::*;
+pub use fee_token::*;
+pub use fee_vault::*;
+pub use referral::*;
+pub use session_key::*;
+pub use signed_authorization::*;
+pub use buyback::*;
+pub use parameter_registry::*;
 // Reputation types
-pub use reputation::{ReputationMetrics, TagScore};
+pub use reputation::{
+    ApprovedSource, ReputationMetrics, ReputationSourceRegistry, ReputationWindowSummary,
+    ReputationWindows, TagScore, WindowBucket, WindowStats, PAYAI_SOURCE,
+};
+// Tag taxonomy
+pub use tag_registry::*;
 // Security and governance types
 pub use security_governance::{
     AccessAuditConfig, AccessPolicy, AccountLockoutPolicies, Action, ActionConstraint, ActionType,
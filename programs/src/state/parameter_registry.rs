@@ -0,0 +1,316 @@
+/*!
+ * Parameter Registry State
+ *
+ * Protocol parameters that instructions read at runtime instead of
+ * compiled-in constants, so they can be tuned without a program upgrade.
+ * Each entry carries a type tag and bounds fixed by the program; values
+ * change only through executed ParameterUpdate proposals.
+ *
+ * Until the registry is initialized, readers fall back to each
+ * parameter's default, which matches the constant it replaced.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::agent_handle::{HANDLE_GRACE_PERIOD, HANDLE_REGISTRATION_PERIOD};
+use crate::state::ghost_protect::GhostProtectEscrow;
+use crate::state::staking::STAKE_MIGRATION_WINDOW;
+use crate::GhostSpeakError;
+
+/// Seed for the singleton parameter registry PDA
+pub const PARAMETER_REGISTRY_SEED: &[u8] = b"parameter_registry";
+
+/// Entries the registry has room for, leaving space for future keys
+pub const MAX_REGISTRY_PARAMETERS: usize = 32;
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Parameters held in the registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterKey {
+    /// Length of one agent handle registration or renewal
+    HandleRegistrationPeriod,
+    /// Time a lapsed handle stays renewable before anyone may release it
+    HandleGracePeriod,
+    /// How long an offered stake migration can be accepted
+    StakeMigrationWindow,
+    /// Largest share of a trial escrow paid to the agent upfront
+    TrialMaxUpfrontBps,
+    /// Refundable bond a client posts to file an escrow dispute
+    DisputeBondLamports,
+}
+
+/// How a parameter's value is interpreted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterKind {
+    /// Seconds
+    Duration,
+    /// Basis points (10_000 = 100%)
+    BasisPoints,
+    /// Lamports
+    Lamports,
+}
+
+/// Type, default and bounds of a parameter, fixed by the program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParameterSpec {
+    pub kind: ParameterKind,
+    pub default: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl ParameterKey {
+    /// Every key, in registry order
+    pub const ALL: [ParameterKey; 5] = [
+        ParameterKey::HandleRegistrationPeriod,
+        ParameterKey::HandleGracePeriod,
+        ParameterKey::StakeMigrationWindow,
+        ParameterKey::TrialMaxUpfrontBps,
+        ParameterKey::DisputeBondLamports,
+    ];
+
+    pub fn spec(self) -> ParameterSpec {
+        let (kind, default, min, max) = match self {
+            ParameterKey::HandleRegistrationPeriod => (
+                ParameterKind::Duration,
+                HANDLE_REGISTRATION_PERIOD as u64,
+                30 * DAY,
+                5 * 365 * DAY,
+            ),
+            ParameterKey::HandleGracePeriod => (
+                ParameterKind::Duration,
+                HANDLE_GRACE_PERIOD as u64,
+                DAY,
+                180 * DAY,
+            ),
+            ParameterKey::StakeMigrationWindow => (
+                ParameterKind::Duration,
+                STAKE_MIGRATION_WINDOW as u64,
+                DAY,
+                30 * DAY,
+            ),
+            ParameterKey::TrialMaxUpfrontBps => (
+                ParameterKind::BasisPoints,
+                GhostProtectEscrow::MAX_TRIAL_UPFRONT_BPS as u64,
+                100,
+                5_000,
+            ),
+            ParameterKey::DisputeBondLamports => (
+                ParameterKind::Lamports,
+                GhostProtectEscrow::DEFAULT_DISPUTE_BOND,
+                1_000_000,
+                1_000_000_000,
+            ),
+        };
+        ParameterSpec {
+            kind,
+            default,
+            min,
+            max,
+        }
+    }
+}
+
+/// A parameter's current value and when it last changed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ParameterEntry {
+    pub key: ParameterKey,
+    pub kind: ParameterKind,
+    pub value: u64,
+    pub min: u64,
+    pub max: u64,
+    /// Proposal that last changed the value (None = still the default)
+    pub changed_by: Option<Pubkey>,
+    /// End of that proposal's vote; older proposals can't override it
+    pub decided_at: i64,
+    pub updated_at: i64,
+}
+
+impl ParameterEntry {
+    pub const SIZE: usize = 1 + // key
+        1 + // kind
+        8 + // value
+        8 + // min
+        8 + // max
+        1 + 32 + // changed_by
+        8 + // decided_at
+        8; // updated_at
+
+    fn new(key: ParameterKey, now: i64) -> Self {
+        let spec = key.spec();
+        Self {
+            key,
+            kind: spec.kind,
+            value: spec.default,
+            min: spec.min,
+            max: spec.max,
+            changed_by: None,
+            decided_at: 0,
+            updated_at: now,
+        }
+    }
+}
+
+/// Parameter Registry - governed runtime parameters
+///
+/// Seeds: ["parameter_registry"]
+#[account]
+pub struct ParameterRegistry {
+    pub entries: Vec<ParameterEntry>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ParameterRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        4 + (MAX_REGISTRY_PARAMETERS * ParameterEntry::SIZE) + // entries
+        8 + // updated_at
+        1; // bump
+
+    /// Fill the registry with every parameter at its default
+    pub fn initialize(&mut self, bump: u8, now: i64) {
+        self.entries = ParameterKey::ALL
+            .iter()
+            .map(|key| ParameterEntry::new(*key, now))
+            .collect();
+        self.updated_at = now;
+        self.bump = bump;
+    }
+
+    /// Current value of `key`, or its default if it isn't registered yet
+    pub fn get(&self, key: ParameterKey) -> u64 {
+        self.entries
+            .iter()
+            .find(|entry| entry.key == key)
+            .map_or(key.spec().default, |entry| entry.value)
+    }
+
+    /// Set `key` to `value` on behalf of `proposal`, whose vote ended at
+    /// `decided_at`, returning the previous value
+    pub fn apply(
+        &mut self,
+        key: ParameterKey,
+        value: u64,
+        proposal: Pubkey,
+        decided_at: i64,
+        now: i64,
+    ) -> Result<u64> {
+        let index = match self.entries.iter().position(|entry| entry.key == key) {
+            Some(index) => index,
+            // Keys added by a program upgrade join the registry on first change
+            None => {
+                require!(
+                    self.entries.len() < MAX_REGISTRY_PARAMETERS,
+                    GhostSpeakError::InvalidConfiguration
+                );
+                self.entries.push(ParameterEntry::new(key, now));
+                self.entries.len() - 1
+            }
+        };
+
+        let entry = &mut self.entries[index];
+        require!(
+            (entry.min..=entry.max).contains(&value),
+            GhostSpeakError::ParameterOutOfBounds
+        );
+        require!(
+            decided_at > entry.decided_at,
+            GhostSpeakError::StaleParameterChange
+        );
+
+        let previous = entry.value;
+        entry.value = value;
+        entry.changed_by = Some(proposal);
+        entry.decided_at = decided_at;
+        entry.updated_at = now;
+        self.updated_at = now;
+        Ok(previous)
+    }
+}
+
+/// Read `key` from the registry PDA, or its default while the registry
+/// hasn't been initialized
+///
+/// Readers take the registry as an address-checked unchecked account so it
+/// can't be left out to dodge a governed value.
+pub fn read_parameter(registry: &AccountInfo, key: ParameterKey) -> Result<u64> {
+    if registry.data_is_empty() {
+        return Ok(key.spec().default);
+    }
+    require!(
+        registry.owner == &crate::ID,
+        GhostSpeakError::InvalidAccountOwner
+    );
+    let data = registry.try_borrow_data()?;
+    Ok(ParameterRegistry::try_deserialize(&mut &data[..])?.get(key))
+}
+
+#[event]
+pub struct ParameterChangedEvent {
+    pub key: ParameterKey,
+    pub kind: ParameterKind,
+    pub previous_value: u64,
+    pub value: u64,
+    pub proposal: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> ParameterRegistry {
+        let mut registry = ParameterRegistry {
+            entries: Vec::new(),
+            updated_at: 0,
+            bump: 0,
+        };
+        registry.initialize(255, 1);
+        registry
+    }
+
+    #[test]
+    fn test_defaults_match_replaced_constants() {
+        let registry = registry();
+        assert_eq!(registry.entries.len(), ParameterKey::ALL.len());
+        assert_eq!(
+            registry.get(ParameterKey::HandleRegistrationPeriod),
+            HANDLE_REGISTRATION_PERIOD as u64
+        );
+        for key in ParameterKey::ALL {
+            let spec = key.spec();
+            assert!((spec.min..=spec.max).contains(&spec.default));
+        }
+
+        // Keys missing from an older registry read as their default
+        let empty = ParameterRegistry {
+            entries: Vec::new(),
+            updated_at: 0,
+            bump: 0,
+        };
+        assert_eq!(
+            empty.get(ParameterKey::StakeMigrationWindow),
+            STAKE_MIGRATION_WINDOW as u64
+        );
+    }
+
+    #[test]
+    fn test_apply_respects_bounds_and_order() {
+        let mut registry = registry();
+        let key = ParameterKey::TrialMaxUpfrontBps;
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert!(registry.apply(key, 5_001, first, 10, 20).is_err());
+        assert_eq!(registry.apply(key, 3_000, first, 10, 20).unwrap(), 2_500);
+        // Replays and proposals decided earlier can't roll the value back
+        assert!(registry.apply(key, 3_000, first, 10, 21).is_err());
+        assert!(registry.apply(key, 1_000, second, 9, 21).is_err());
+        registry.apply(key, 1_000, second, 11, 21).unwrap();
+
+        let entry = registry.entries.iter().find(|e| e.key == key).unwrap();
+        assert_eq!(entry.value, 1_000);
+        assert_eq!(entry.changed_by, Some(second));
+        assert_eq!(entry.updated_at, 21);
+    }
+}
@@ -9,6 +9,27 @@
 
 use anchor_lang::prelude::*;
 
+use crate::state::staking::AccessTier;
+
+/// Actions restricted to a minimum staking tier by `ProtocolConfig::tier_gates`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GatedAction {
+    /// Issuing credentials (checked against the template issuer's stake)
+    IssueCredential,
+    /// Ruling on Ghost Protect disputes (checked against the arbitrator's stake)
+    Arbitrate,
+}
+
+impl GatedAction {
+    /// Number of gated actions, for the gating table
+    pub const COUNT: usize = 2;
+
+    /// Position of this action in the gating table
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 /// Protocol configuration PDA storing fee rates and treasury addresses
 ///
 /// Seeds: ["protocol_config"]
@@ -21,6 +42,7 @@ use anchor_lang::prelude::*;
 /// | Agent Registration | 0.01 SOL | 100% Treasury | Disabled until mainnet |
 /// | Marketplace Listing | 0.001 SOL | 100% Treasury | Disabled until mainnet |
 /// | Dispute Resolution | 1% (100 bps) | Moderator Pool | Disabled until mainnet |
+/// | Credential Issuance | 10% of template fee (1000 bps) | 100% Treasury, rest to template creator | Disabled until mainnet |
 ///
 /// # Enabling Fees
 ///
@@ -73,8 +95,45 @@ pub struct ProtocolConfig {
     /// PDA bump seed
     pub bump: u8,
 
+    /// Whether inactivity decay of aggregate reputation is enabled
+    pub reputation_decay_enabled: bool,
+
+    /// Days without payments or ratings before decay starts
+    /// Default: 30
+    pub reputation_decay_grace_days: u16,
+
+    /// Decay applied per inactive day in basis points (100 = 1%)
+    /// Default: 50 (0.5% per day)
+    pub reputation_decay_bps_per_day: u16,
+
+    /// Score (0-100 scale) below which decay never pushes an agent
+    pub reputation_decay_floor: u32,
+
+    /// Oracle key whose Ed25519 attestations confirm Crossmint mirroring
+    /// (default = unset, cross-chain status updates are rejected)
+    pub crosschain_attestor: Pubkey,
+
+    /// Protocol share of credential template issuance fees in basis points
+    /// Default: 0 (disabled until mainnet)
+    /// Target: 1000 (10%)
+    pub credential_fee_bps: u16,
+
+    /// Minimum staking tier per action, indexed by `GatedAction`
+    /// Default: None for every action (ungated)
+    pub tier_gates: [AccessTier; GatedAction::COUNT],
+
+    /// Fee discount per payer staking tier in basis points of the fee,
+    /// indexed by `AccessTier` (4000 turns a 0.5% escrow fee into 0.3%)
+    /// Default: 0 for every tier (no discounts)
+    pub fee_discount_bps: [u16; AccessTier::COUNT],
+
+    /// Share of the protocol's cut of a referred fee paid to the referrer,
+    /// in basis points
+    /// Default: 0 (no referral payouts)
+    pub referral_share_bps: u16,
+
     /// Reserved for future use
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 7],
 }
 
 impl ProtocolConfig {
@@ -91,7 +150,34 @@ impl ProtocolConfig {
         1 +  // fees_enabled
         8 +  // updated_at
         1 +  // bump
-        64; // _reserved
+        1 +  // reputation_decay_enabled
+        2 +  // reputation_decay_grace_days
+        2 +  // reputation_decay_bps_per_day
+        4 +  // reputation_decay_floor
+        32 + // crosschain_attestor
+        2 +  // credential_fee_bps
+        GatedAction::COUNT + // tier_gates
+        2 * AccessTier::COUNT + // fee_discount_bps
+        2 +  // referral_share_bps
+        7; // _reserved
+
+    /// Default inactivity grace period before reputation decay starts
+    pub const DEFAULT_DECAY_GRACE_DAYS: u16 = 30;
+
+    /// Default daily reputation decay (0.5%)
+    pub const DEFAULT_DECAY_BPS_PER_DAY: u16 = 50;
+
+    /// Maximum daily reputation decay (10%)
+    pub const MAX_DECAY_BPS_PER_DAY: u16 = 1000;
+
+    /// Maximum protocol share of credential issuance fees (50%)
+    pub const MAX_CREDENTIAL_FEE_BPS: u16 = 5000;
+
+    /// Maximum staking tier fee discount (100%)
+    pub const MAX_FEE_DISCOUNT_BPS: u16 = 10_000;
+
+    /// Maximum referrer share of the protocol's fee cut (50%)
+    pub const MAX_REFERRAL_SHARE_BPS: u16 = 5000;
 
     /// Initialize with fees disabled (for devnet)
     pub fn initialize(
@@ -112,11 +198,23 @@ impl ProtocolConfig {
         self.agent_registration_fee = 0;
         self.listing_fee = 0;
         self.dispute_fee_bps = 0;
+        self.credential_fee_bps = 0;
         self.fees_enabled = false;
 
+        // Inactivity decay ships disabled with conservative defaults
+        self.reputation_decay_enabled = false;
+        self.reputation_decay_grace_days = Self::DEFAULT_DECAY_GRACE_DAYS;
+        self.reputation_decay_bps_per_day = Self::DEFAULT_DECAY_BPS_PER_DAY;
+        self.reputation_decay_floor = 0;
+
+        self.crosschain_attestor = Pubkey::default();
+        self.tier_gates = [AccessTier::None; GatedAction::COUNT];
+        self.fee_discount_bps = [0; AccessTier::COUNT];
+        self.referral_share_bps = 0;
+
         self.updated_at = Clock::get()?.unix_timestamp;
         self.bump = bump;
-        self._reserved = [0u8; 64];
+        self._reserved = [0u8; 7];
 
         Ok(())
     }
@@ -127,20 +225,57 @@ impl ProtocolConfig {
         self.agent_registration_fee = 10_000_000; // 0.01 SOL
         self.listing_fee = 1_000_000; // 0.001 SOL
         self.dispute_fee_bps = 100; // 1%
+        self.credential_fee_bps = 1000; // 10%
         self.fees_enabled = true;
         self.updated_at = Clock::get()?.unix_timestamp;
 
         Ok(())
     }
 
-    /// Calculate escrow fee amount
+    /// Check a per-tier discount schedule: each discount within the maximum
+    /// and no tier discounted less than the tier below it
+    pub fn validate_fee_discounts(fee_discount_bps: &[u16; AccessTier::COUNT]) -> Result<()> {
+        require!(
+            fee_discount_bps
+                .iter()
+                .all(|bps| *bps <= Self::MAX_FEE_DISCOUNT_BPS)
+                && fee_discount_bps.windows(2).all(|pair| pair[0] <= pair[1]),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Reduce `fee` by the discount for a payer staked at `tier`
+    pub fn apply_tier_discount(&self, fee: u64, tier: AccessTier) -> u64 {
+        let discount_bps = self.fee_discount_bps[tier.index()].min(Self::MAX_FEE_DISCOUNT_BPS);
+        (fee as u128 * (10_000 - discount_bps as u128) / 10_000) as u64
+    }
+
+    /// Escrow fee rate for an agent with `trailing_volume` settled over the
+    /// last 30 days; the volume curve only ever lowers the base rate
+    pub fn escrow_fee_bps_for(&self, curve: Option<&EscrowFeeCurve>, trailing_volume: u64) -> u16 {
+        curve
+            .and_then(|curve| curve.fee_bps_for(trailing_volume))
+            .map_or(self.escrow_fee_bps, |bps| bps.min(self.escrow_fee_bps))
+    }
+
+    /// Calculate escrow fee amount at `escrow_fee_bps` (see
+    /// `escrow_fee_bps_for`) for an agent staked at `tier`; `exempt` is the
+    /// fee exemption list's answer for the agent
     /// Returns (agent_amount, treasury_share, buyback_share)
-    pub fn calculate_escrow_fee(&self, amount: u64) -> (u64, u64, u64) {
-        if !self.fees_enabled || self.escrow_fee_bps == 0 {
+    pub fn calculate_escrow_fee(
+        &self,
+        amount: u64,
+        escrow_fee_bps: u16,
+        tier: AccessTier,
+        exempt: bool,
+    ) -> (u64, u64, u64) {
+        if !self.fees_enabled || escrow_fee_bps == 0 || exempt {
             return (amount, 0, 0);
         }
 
-        let total_fee = (amount as u128 * self.escrow_fee_bps as u128 / 10000) as u64;
+        let base_fee = (amount as u128 * escrow_fee_bps as u128 / 10000) as u64;
+        let total_fee = self.apply_tier_discount(base_fee, tier);
         let treasury_share = (total_fee * 80) / 100; // 80% to treasury
         let buyback_share = total_fee - treasury_share; // 20% to buyback
         let agent_amount = amount - total_fee;
@@ -148,19 +283,19 @@ impl ProtocolConfig {
         (agent_amount, treasury_share, buyback_share)
     }
 
-    /// Get agent registration fee (0 if disabled)
-    pub fn get_registration_fee(&self) -> u64 {
-        if self.fees_enabled {
-            self.agent_registration_fee
+    /// Get agent registration fee after the tier discount (0 if disabled or exempt)
+    pub fn get_registration_fee(&self, tier: AccessTier, exempt: bool) -> u64 {
+        if self.fees_enabled && !exempt {
+            self.apply_tier_discount(self.agent_registration_fee, tier)
         } else {
             0
         }
     }
 
-    /// Get listing fee (0 if disabled)
-    pub fn get_listing_fee(&self) -> u64 {
-        if self.fees_enabled {
-            self.listing_fee
+    /// Get listing fee after the tier discount (0 if disabled or exempt)
+    pub fn get_listing_fee(&self, tier: AccessTier, exempt: bool) -> u64 {
+        if self.fees_enabled && !exempt {
+            self.apply_tier_discount(self.listing_fee, tier)
         } else {
             0
         }
@@ -174,6 +309,118 @@ impl ProtocolConfig {
 
         (amount as u128 * self.dispute_fee_bps as u128 / 10000) as u64
     }
+
+    /// Split a credential template issuance fee; the protocol share is
+    /// discounted by the template issuer's `tier` and an exempt issuer keeps
+    /// the whole fee
+    /// Returns (creator_share, treasury_share)
+    pub fn calculate_credential_fee(&self, fee: u64, tier: AccessTier, exempt: bool) -> (u64, u64) {
+        if !self.fees_enabled || self.credential_fee_bps == 0 || exempt {
+            return (fee, 0);
+        }
+
+        let base_share = (fee as u128 * self.credential_fee_bps as u128 / 10000) as u64;
+        let treasury_share = self.apply_tier_discount(base_share, tier);
+        (fee - treasury_share, treasury_share)
+    }
+
+    /// Split the protocol's cut of a referred fee
+    /// Returns (protocol_share, referrer_share)
+    pub fn split_referral_fee(&self, fee: u64) -> (u64, u64) {
+        let share_bps = self.referral_share_bps.min(Self::MAX_REFERRAL_SHARE_BPS);
+        let referrer_share = (fee as u128 * share_bps as u128 / 10000) as u64;
+        (fee - referrer_share, referrer_share)
+    }
+
+    /// Require `tier` to meet the minimum tier governance set for `action`
+    pub fn require_tier(&self, action: GatedAction, tier: AccessTier) -> Result<()> {
+        require!(
+            tier.meets(self.tier_gates[action.index()]),
+            crate::GhostSpeakError::InsufficientStakingTier
+        );
+        Ok(())
+    }
+
+    /// Inactivity grace period in seconds before reputation decay applies
+    pub fn reputation_decay_grace_seconds(&self) -> i64 {
+        self.reputation_decay_grace_days as i64 * 24 * 60 * 60
+    }
+}
+
+/// Seed for the escrow fee curve PDA
+pub const ESCROW_FEE_CURVE_SEED: &[u8] = b"escrow_fee_curve";
+
+/// Maximum number of steps on the escrow fee curve
+pub const MAX_VOLUME_FEE_TIERS: usize = 8;
+
+/// One step of the escrow fee curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VolumeFeeTier {
+    /// Trailing 30-day settled volume at which this rate starts
+    pub min_volume: u64,
+    /// Escrow fee in basis points from that volume on
+    pub fee_bps: u16,
+}
+
+impl VolumeFeeTier {
+    pub const SIZE: usize = 8 + // min_volume
+        2; // fee_bps
+}
+
+/// Escrow Fee Curve - escrow fee rate by agent settled volume
+///
+/// Agents below the first step pay `ProtocolConfig::escrow_fee_bps`.
+///
+/// Seeds: ["escrow_fee_curve"]
+#[account]
+pub struct EscrowFeeCurve {
+    /// Steps in increasing volume order with non-increasing rates
+    pub tiers: Vec<VolumeFeeTier>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl EscrowFeeCurve {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_VOLUME_FEE_TIERS * VolumeFeeTier::SIZE + // tiers
+        8 + // updated_at
+        1; // bump
+
+    /// Check that steps rise in volume and never raise the rate
+    pub fn validate(tiers: &[VolumeFeeTier]) -> Result<()> {
+        require!(
+            tiers.len() <= MAX_VOLUME_FEE_TIERS
+                && tiers.first().map_or(true, |tier| tier.min_volume > 0)
+                && tiers.iter().all(|tier| tier.fee_bps <= 10_000)
+                && tiers.windows(2).all(|pair| {
+                    pair[0].min_volume < pair[1].min_volume && pair[0].fee_bps >= pair[1].fee_bps
+                }),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Rate of the highest step `trailing_volume` reaches, if any
+    pub fn fee_bps_for(&self, trailing_volume: u64) -> Option<u16> {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| trailing_volume >= tier.min_volume)
+            .map(|tier| tier.fee_bps)
+    }
+}
+
+/// Read the escrow fee curve, or `None` if governance never created it
+pub fn read_escrow_fee_curve(curve: &AccountInfo) -> Result<Option<EscrowFeeCurve>> {
+    if curve.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        curve.owner == &crate::ID,
+        crate::GhostSpeakError::InvalidAccountOwner
+    );
+    let data = curve.try_borrow_data()?;
+    Ok(Some(EscrowFeeCurve::try_deserialize(&mut &data[..])?))
 }
 
 /// Event emitted when protocol config is updated
@@ -187,3 +434,89 @@ pub struct ProtocolConfigUpdatedEvent {
     pub dispute_fee_bps: u16,
     pub timestamp: i64,
 }
+
+/// Event emitted when reputation decay parameters are updated
+#[event]
+pub struct ReputationDecayConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub grace_days: u16,
+    pub bps_per_day: u16,
+    pub floor: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the protocol share of credential issuance fees changes
+#[event]
+pub struct CredentialFeeShareUpdatedEvent {
+    pub authority: Pubkey,
+    pub credential_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the escrow fee curve changes
+#[event]
+pub struct EscrowFeeCurveUpdatedEvent {
+    pub authority: Pubkey,
+    pub tiers: Vec<VolumeFeeTier>,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the per-tier fee discounts change
+#[event]
+pub struct FeeDiscountsUpdatedEvent {
+    pub authority: Pubkey,
+    pub fee_discount_bps: [u16; AccessTier::COUNT],
+    pub timestamp: i64,
+}
+
+/// Event emitted when the per-action staking tier gates change
+#[event]
+pub struct TierGatesUpdatedEvent {
+    pub authority: Pubkey,
+    pub tier_gates: [AccessTier; GatedAction::COUNT],
+    pub timestamp: i64,
+}
+
+/// Event emitted when the referrer share of fees changes
+#[event]
+pub struct ReferralShareUpdatedEvent {
+    pub authority: Pubkey,
+    pub referral_share_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the cross-chain attestor key is changed
+#[event]
+pub struct CrossChainAttestorUpdatedEvent {
+    pub authority: Pubkey,
+    pub attestor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escrow_fee_curve() {
+        let tier = |min_volume, fee_bps| VolumeFeeTier {
+            min_volume,
+            fee_bps,
+        };
+        assert!(EscrowFeeCurve::validate(&[tier(0, 40)]).is_err());
+        assert!(EscrowFeeCurve::validate(&[tier(100, 40), tier(100, 30)]).is_err());
+        assert!(EscrowFeeCurve::validate(&[tier(100, 30), tier(200, 40)]).is_err());
+
+        let tiers = vec![tier(10_000, 40), tier(100_000, 30)];
+        EscrowFeeCurve::validate(&tiers).unwrap();
+        let curve = EscrowFeeCurve {
+            tiers,
+            updated_at: 0,
+            bump: 255,
+        };
+        assert_eq!(curve.fee_bps_for(9_999), None);
+        assert_eq!(curve.fee_bps_for(10_000), Some(40));
+        assert_eq!(curve.fee_bps_for(500_000), Some(30));
+    }
+}
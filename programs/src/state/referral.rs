@@ -0,0 +1,155 @@
+/*!
+ * Referral State
+ *
+ * Referrers enroll once, creating a per-referrer accounting PDA, and may
+ * then be named on agent registration or escrow creation. When a fee is
+ * collected for a referred agent or escrow, the governed referral share of
+ * the protocol's cut is paid to the referrer's token account and added to
+ * the referrer's totals here.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::fee_vault::FeeSource;
+use crate::GhostSpeakError;
+
+/// Seed for referrer accounting: [seed, referrer]
+pub const REFERRER_STATS_SEED: &[u8] = b"referrer_stats";
+
+/// What a referrer was named on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReferralKind {
+    AgentRegistration,
+    EscrowCreation,
+}
+
+/// Referrer Stats - referral and payout totals for one referrer
+///
+/// Seeds: ["referrer_stats", referrer]
+#[account]
+pub struct ReferrerStats {
+    /// Wallet credited with referrals; owns the payout token accounts
+    pub referrer: Pubkey,
+    pub referred_agents: u32,
+    pub referred_escrows: u32,
+    /// Total paid per fee source, indexed by `FeeSource`, in the units of
+    /// the token each fee was paid in
+    pub earned: [u64; FeeSource::COUNT],
+    pub payouts: u32,
+    pub last_paid_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl ReferrerStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // referrer
+        4 + // referred_agents
+        4 + // referred_escrows
+        8 * FeeSource::COUNT + // earned
+        4 + // payouts
+        8 + // last_paid_at
+        8 + // created_at
+        1; // bump
+
+    /// Count a referral of `referred` (agent owner or escrow client) by
+    /// `referrer`, which must be the owner of these stats
+    pub fn record_referral(
+        &mut self,
+        referrer: &Pubkey,
+        referred: &Pubkey,
+        kind: ReferralKind,
+    ) -> Result<()> {
+        require!(
+            *referrer == self.referrer,
+            GhostSpeakError::ReferrerNotRegistered
+        );
+        require!(referrer != referred, GhostSpeakError::InvalidReferrer);
+
+        let count = match kind {
+            ReferralKind::AgentRegistration => &mut self.referred_agents,
+            ReferralKind::EscrowCreation => &mut self.referred_escrows,
+        };
+        *count = count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Record a referral payout of `amount` from `source`
+    pub fn record_payout(&mut self, source: FeeSource, amount: u64, now: i64) -> Result<()> {
+        let earned = &mut self.earned[source.index()];
+        *earned = earned
+            .checked_add(amount)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        self.payouts = self.payouts.saturating_add(1);
+        self.last_paid_at = now;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct ReferrerRegisteredEvent {
+    pub referrer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRecordedEvent {
+    pub referrer: Pubkey,
+    pub referred: Pubkey,
+    /// Agent or escrow account the referrer is attached to
+    pub account: Pubkey,
+    pub kind: ReferralKind,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeePaidEvent {
+    pub referrer: Pubkey,
+    pub token_account: Pubkey,
+    pub source: FeeSource,
+    pub amount: u64,
+    pub total_for_source: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_referral_accounting() {
+        let referrer = Pubkey::new_unique();
+        let mut stats = ReferrerStats {
+            referrer,
+            referred_agents: 0,
+            referred_escrows: 0,
+            earned: [0; FeeSource::COUNT],
+            payouts: 0,
+            last_paid_at: 0,
+            created_at: 0,
+            bump: 255,
+        };
+        let owner = Pubkey::new_unique();
+
+        stats
+            .record_referral(&referrer, &owner, ReferralKind::AgentRegistration)
+            .unwrap();
+        stats
+            .record_referral(&referrer, &owner, ReferralKind::EscrowCreation)
+            .unwrap();
+        assert_eq!((stats.referred_agents, stats.referred_escrows), (1, 1));
+
+        // Self-referral and someone else's stats are rejected
+        assert!(stats
+            .record_referral(&referrer, &referrer, ReferralKind::EscrowCreation)
+            .is_err());
+        assert!(stats
+            .record_referral(&owner, &referrer, ReferralKind::EscrowCreation)
+            .is_err());
+
+        stats.record_payout(FeeSource::Escrow, 250, 10).unwrap();
+        stats.record_payout(FeeSource::Escrow, 50, 20).unwrap();
+        assert_eq!(stats.earned[FeeSource::Escrow.index()], 300);
+        assert_eq!((stats.payouts, stats.last_paid_at), (2, 20));
+    }
+}
@@ -129,6 +129,113 @@ impl TagScore {
     }
 }
 
+/// Source whose registered updater verifies PayAI payments
+pub const PAYAI_SOURCE: &str = "payai";
+
+/// Approved reputation source entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ApprovedSource {
+    /// Source identifier (e.g., "payai", "github")
+    pub source_name: String,
+    /// Key allowed to push scores for this source
+    pub updater: Pubkey,
+    /// Maximum weight this source may claim (basis points)
+    pub max_weight: u16,
+    /// Whether the source is currently accepted
+    pub is_active: bool,
+}
+
+impl ApprovedSource {
+    pub const LEN: usize = 4 + SourceScore::MAX_SOURCE_NAME_LENGTH + // source_name
+        32 + // updater
+        2 + // max_weight
+        1; // is_active
+}
+
+/// Governed whitelist of reputation sources
+///
+/// Seeds: ["reputation_source_registry"]
+///
+/// Managed by a multisig: every change requires `threshold` of the
+/// multisig's signers to sign the transaction.
+#[account]
+pub struct ReputationSourceRegistry {
+    /// Multisig account that governs the registry
+    pub multisig: Pubkey,
+    /// Approved sources
+    pub sources: Vec<ApprovedSource>,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReputationSourceRegistry {
+    pub const MAX_SOURCES: usize = 16;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        4 + (Self::MAX_SOURCES * ApprovedSource::LEN) + // sources
+        8 + // updated_at
+        1; // bump
+
+    /// Get an active source by name
+    pub fn get_active_source(&self, source_name: &str) -> Option<&ApprovedSource> {
+        self.sources
+            .iter()
+            .find(|s| s.source_name == source_name && s.is_active)
+    }
+
+    /// Whether `key` is the updater of the active source `source_name`
+    pub fn is_active_updater(&self, source_name: &str, key: &Pubkey) -> bool {
+        self.get_active_source(source_name)
+            .is_some_and(|source| source.updater == *key)
+    }
+
+    /// Check that `updater` may push `weight` for `source_name`
+    pub fn authorize_update(&self, source_name: &str, updater: &Pubkey, weight: u16) -> Result<()> {
+        let source = self
+            .get_active_source(source_name)
+            .ok_or(crate::GhostSpeakError::SourceNotApproved)?;
+        require!(
+            source.updater == *updater,
+            crate::GhostSpeakError::UnauthorizedSourceUpdater
+        );
+        require!(
+            weight <= source.max_weight,
+            crate::GhostSpeakError::SourceWeightExceeded
+        );
+        Ok(())
+    }
+
+    /// Add a new source or replace an existing one
+    pub fn upsert_source(&mut self, source: ApprovedSource) -> Result<()> {
+        require!(
+            source.source_name.len() <= SourceScore::MAX_SOURCE_NAME_LENGTH,
+            crate::GhostSpeakError::InputTooLong
+        );
+        require!(
+            source.max_weight <= SourceScore::MAX_WEIGHT,
+            crate::GhostSpeakError::InvalidPercentage
+        );
+
+        if let Some(existing) = self
+            .sources
+            .iter_mut()
+            .find(|s| s.source_name == source.source_name)
+        {
+            *existing = source;
+        } else {
+            require!(
+                self.sources.len() < Self::MAX_SOURCES,
+                crate::GhostSpeakError::TooManyApprovedSources
+            );
+            self.sources.push(source);
+        }
+        Ok(())
+    }
+}
+
 /// x402 payment tracking metrics for reputation calculation
 #[account]
 pub struct ReputationMetrics {
@@ -176,6 +283,13 @@ pub struct ReputationMetrics {
     pub conflict_flags: Vec<String>,
     /// PDA bump
     pub bump: u8,
+    /// Layout version; fields below exist only from `CURRENT_VERSION` on,
+    /// and older accounts gain them through `migrate_reputation_metrics`
+    pub version: u8,
+    /// Last recorded payment or rating (drives inactivity decay)
+    pub last_activity_at: i64,
+    /// Timestamp up to which inactivity decay has been applied
+    pub last_decay_at: i64,
 }
 
 impl ReputationMetrics {
@@ -187,6 +301,10 @@ impl ReputationMetrics {
     pub const MAX_CONFLICT_FLAGS: usize = 10; // Max conflict descriptions
     pub const MAX_PRIMARY_SOURCE_LENGTH: usize = 32;
     pub const CONFLICT_THRESHOLD: u16 = 300; // 30% variance triggers conflict flag
+    /// Layout with `version` and the inactivity decay clock after `bump`
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Bytes the version and decay clock add to accounts created before them
+    pub const V1_EXTENSION_LEN: usize = 1 + 8 + 8;
 
     // Dynamic account size - will be resized as needed
     // Base size without vectors
@@ -212,7 +330,8 @@ impl ReputationMetrics {
         36 + // primary_source (32 chars + prefix)
         8 + // last_aggregation
         4 + // conflict_flags vec length prefix
-        1; // bump
+        1 + // bump
+        Self::V1_EXTENSION_LEN; // version, last_activity_at, last_decay_at
 
     // Estimated max size with all tags and sources
     pub const LEN: usize = Self::BASE_LEN +
@@ -223,6 +342,15 @@ impl ReputationMetrics {
         (Self::MAX_SOURCE_SCORES * 84) + // SourceScore: 32 (name) + 2 (score) + 2 (weight) + 4 (data_points) + 2 (reliability) + 8 (timestamp) + prefixes
         (Self::MAX_CONFLICT_FLAGS * 68); // Each flag ~64 chars + prefix
 
+    /// Whether this account, `data_len` bytes long, has the current layout
+    ///
+    /// Accounts created before the version field are exactly
+    /// `V1_EXTENSION_LEN` bytes short of `LEN`, so their trailing bytes are
+    /// never trusted as a version.
+    pub fn is_current(&self, data_len: usize) -> bool {
+        data_len >= Self::LEN && self.version == Self::CURRENT_VERSION
+    }
+
     /// Calculate average response time in milliseconds
     pub fn avg_response_time(&self) -> u64 {
         if self.response_time_count > 0 {
@@ -295,6 +423,79 @@ impl ReputationMetrics {
         10000 - clamped_cv
     }
 
+    // =====================================================
+    // INACTIVITY DECAY METHODS
+    // =====================================================
+
+    /// Record a payment or rating, resetting the inactivity decay clock
+    pub fn record_activity(&mut self, current_timestamp: i64) {
+        self.last_activity_at = current_timestamp;
+        self.last_decay_at = current_timestamp;
+    }
+
+    /// Apply `bps_per_day` decay for each of `days`, stopping at `floor`
+    ///
+    /// Computes `score * (1 - bps_per_day / 10000)^days` by squaring, so the
+    /// cost stays logarithmic however long the agent has been inactive.
+    /// Scores already at or below the floor are returned unchanged.
+    pub fn decayed_score(score: u32, days: u64, bps_per_day: u16, floor: u32) -> u32 {
+        const PRECISION: u128 = 1_000_000_000_000_000_000;
+
+        if score <= floor || bps_per_day == 0 {
+            return score;
+        }
+
+        let retain_bps = 10000u128.saturating_sub(bps_per_day as u128);
+        let mut base = retain_bps * PRECISION / 10000;
+        let mut factor = PRECISION;
+        let mut remaining = days;
+        while remaining > 0 && factor > 0 {
+            if remaining & 1 == 1 {
+                factor = factor * base / PRECISION;
+            }
+            base = base * base / PRECISION;
+            remaining >>= 1;
+        }
+
+        let decayed = (score as u128 * factor / PRECISION) as u32;
+        decayed.max(floor)
+    }
+
+    /// Apply inactivity decay to an aggregate score
+    ///
+    /// Decay starts `grace_seconds` after the last payment or rating and only
+    /// whole elapsed days are charged, so repeated calls never double-count.
+    /// Returns the decayed score and the number of days applied.
+    pub fn apply_inactivity_decay(
+        &mut self,
+        score: u32,
+        current_timestamp: i64,
+        grace_seconds: i64,
+        bps_per_day: u16,
+        floor: u32,
+    ) -> (u32, u64) {
+        const ONE_DAY: i64 = 24 * 60 * 60;
+
+        let inactive_since = if self.last_activity_at > 0 {
+            self.last_activity_at
+        } else {
+            self.created_at
+        };
+        let decay_start = inactive_since.saturating_add(grace_seconds);
+        if current_timestamp <= decay_start {
+            return (score, 0);
+        }
+
+        let decay_from = decay_start.max(self.last_decay_at);
+        let days = (current_timestamp.saturating_sub(decay_from) / ONE_DAY) as u64;
+        if days == 0 {
+            return (score, 0);
+        }
+
+        self.last_decay_at = decay_from.saturating_add(days as i64 * ONE_DAY);
+        (Self::decayed_score(score, days, bps_per_day, floor), days)
+    }
+
     // =====================================================
     // TAG MANAGEMENT METHODS
     // =====================================================
@@ -506,9 +707,10 @@ impl ReputationMetrics {
             return false;
         }
 
-        let scores: Vec<u16> = self.source_scores.iter().map(|s| s.score).collect();
-        let max_score = *scores.iter().max().unwrap_or(&0);
-        let min_score = *scores.iter().min().unwrap_or(&0);
+        let Some((max, min)) = self.source_divergence() else {
+            return false;
+        };
+        let (max_score, min_score) = (max.score, min.score);
         let variance = max_score.saturating_sub(min_score);
 
         if variance > Self::CONFLICT_THRESHOLD {
@@ -528,6 +730,25 @@ impl ReputationMetrics {
         }
     }
 
+    /// Highest and lowest scoring sources, or None with fewer than two sources
+    pub fn source_divergence(&self) -> Option<(&SourceScore, &SourceScore)> {
+        if self.source_scores.len() < 2 {
+            return None;
+        }
+        let max = self.source_scores.iter().max_by_key(|s| s.score)?;
+        let min = self.source_scores.iter().min_by_key(|s| s.score)?;
+        Some((max, min))
+    }
+
+    /// Drop scores from sources no longer active in the registry
+    /// Returns the number of sources removed
+    pub fn drop_unapproved_sources(&mut self, registry: &ReputationSourceRegistry) -> u32 {
+        let before = self.source_scores.len();
+        self.source_scores
+            .retain(|s| registry.get_active_source(&s.source_name).is_some());
+        (before - self.source_scores.len()) as u32
+    }
+
     /// Remove a source score
     pub fn remove_source(&mut self, source_name: &str) {
         self.source_scores.retain(|s| s.source_name != source_name);
@@ -751,6 +972,215 @@ impl ReputationMetrics {
     }
 }
 
+/// Counters for one week of activity
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowBucket {
+    /// Week number since the Unix epoch this bucket holds
+    pub week: u32,
+    pub successful_payments: u32,
+    pub failed_payments: u32,
+    pub volume: u64,
+    pub total_response_time: u64,
+    pub response_time_count: u32,
+    pub total_rating: u32,
+    pub ratings_count: u32,
+}
+
+impl WindowBucket {
+    pub const LEN: usize = 4 + // week
+        4 + // successful_payments
+        4 + // failed_payments
+        8 + // volume
+        8 + // total_response_time
+        4 + // response_time_count
+        4 + // total_rating
+        4; // ratings_count
+}
+
+/// Aggregated counters over a window of weekly buckets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowStats {
+    pub successful_payments: u64,
+    pub failed_payments: u64,
+    pub volume: u64,
+    pub total_response_time: u64,
+    pub response_time_count: u64,
+    pub total_rating: u64,
+    pub ratings_count: u64,
+}
+
+impl WindowStats {
+    fn add(&mut self, bucket: &WindowBucket) {
+        self.successful_payments += bucket.successful_payments as u64;
+        self.failed_payments += bucket.failed_payments as u64;
+        self.volume = self.volume.saturating_add(bucket.volume);
+        self.total_response_time = self.total_response_time.saturating_add(bucket.total_response_time);
+        self.response_time_count += bucket.response_time_count as u64;
+        self.total_rating += bucket.total_rating as u64;
+        self.ratings_count += bucket.ratings_count as u64;
+    }
+
+    pub fn has_activity(&self) -> bool {
+        self.successful_payments + self.failed_payments + self.ratings_count > 0
+    }
+
+    /// Payment success rate (basis points)
+    pub fn success_rate(&self) -> u64 {
+        let total = self.successful_payments + self.failed_payments;
+        if total > 0 {
+            self.successful_payments * 10000 / total
+        } else {
+            0
+        }
+    }
+
+    /// Average rating (0-100 scale)
+    pub fn avg_rating(&self) -> u64 {
+        if self.ratings_count > 0 {
+            self.total_rating * 100 / (self.ratings_count * 5)
+        } else {
+            0
+        }
+    }
+
+    /// Window score (basis points): 60% success rate, 40% rating
+    ///
+    /// Without ratings in the window the success rate carries the full weight.
+    pub fn score(&self) -> u64 {
+        if self.ratings_count == 0 {
+            return self.success_rate();
+        }
+        (self.success_rate() * 60 + self.avg_rating() * 100 * 40) / 100
+    }
+}
+
+/// Rolling recent-performance windows for an agent
+///
+/// Activity is bucketed by week in a 13-slot ring, covering a ~30-day window
+/// (the last 4 weeks, current week included) and a ~90-day window (all 13).
+///
+/// Seeds: ["reputation_windows", agent]
+#[account]
+pub struct ReputationWindows {
+    /// Agent public key
+    pub agent: Pubkey,
+    /// Weekly buckets, indexed by week % WINDOW_BUCKETS
+    pub buckets: [WindowBucket; 13],
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Recent and lifetime performance, returned by `get_reputation_windows`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ReputationWindowSummary {
+    pub agent: Pubkey,
+    pub window_30d: WindowStats,
+    pub window_90d: WindowStats,
+    /// Lifetime score (basis points)
+    pub lifetime_score: u64,
+    /// Headline score blended from the 30d, 90d and lifetime windows (basis points)
+    pub blended_score: u64,
+}
+
+impl ReputationWindows {
+    pub const WINDOW_BUCKETS: usize = 13;
+    pub const WEEKS_30D: u32 = 4;
+    pub const WEEKS_90D: u32 = 13;
+    const ONE_WEEK: i64 = 7 * 24 * 60 * 60;
+
+    /// Headline blend weights (basis points); lifetime takes the remainder
+    pub const BLEND_30D_BPS: u64 = 4000;
+    pub const BLEND_90D_BPS: u64 = 3000;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        (WindowBucket::LEN * Self::WINDOW_BUCKETS) + // buckets
+        8 + // updated_at
+        1; // bump
+
+    fn week_of(timestamp: i64) -> u32 {
+        (timestamp.max(0) / Self::ONE_WEEK) as u32
+    }
+
+    /// Bucket for the current week, clearing it if it holds an older week
+    fn current_bucket(&mut self, now: i64) -> &mut WindowBucket {
+        let week = Self::week_of(now);
+        let bucket = &mut self.buckets[week as usize % Self::WINDOW_BUCKETS];
+        if bucket.week != week {
+            *bucket = WindowBucket {
+                week,
+                ..WindowBucket::default()
+            };
+        }
+        bucket
+    }
+
+    pub fn record_payment(&mut self, amount: u64, response_time_ms: u64, success: bool, now: i64) {
+        let bucket = self.current_bucket(now);
+        if success {
+            bucket.successful_payments = bucket.successful_payments.saturating_add(1);
+            bucket.volume = bucket.volume.saturating_add(amount);
+        } else {
+            bucket.failed_payments = bucket.failed_payments.saturating_add(1);
+        }
+        bucket.total_response_time = bucket.total_response_time.saturating_add(response_time_ms);
+        bucket.response_time_count = bucket.response_time_count.saturating_add(1);
+        self.updated_at = now;
+    }
+
+    pub fn record_rating(&mut self, rating: u8, now: i64) {
+        let bucket = self.current_bucket(now);
+        bucket.total_rating = bucket.total_rating.saturating_add(rating as u32);
+        bucket.ratings_count = bucket.ratings_count.saturating_add(1);
+        self.updated_at = now;
+    }
+
+    /// Sum of the last `weeks` weeks, current week included
+    pub fn window(&self, weeks: u32, now: i64) -> WindowStats {
+        let current = Self::week_of(now);
+        let oldest = current.saturating_sub(weeks.saturating_sub(1));
+        let mut stats = WindowStats::default();
+        for bucket in &self.buckets {
+            if bucket.week >= oldest && bucket.week <= current {
+                stats.add(bucket);
+            }
+        }
+        stats
+    }
+
+    /// Blend recent windows with the lifetime score
+    ///
+    /// A window with no activity hands its weight to the lifetime score, so
+    /// quiet agents are neither rewarded nor punished by empty windows.
+    pub fn blended_score(&self, lifetime_score: u64, now: i64) -> u64 {
+        let mut total = 0u64;
+        let mut lifetime_weight = 10000u64;
+        for (weeks, weight) in [
+            (Self::WEEKS_30D, Self::BLEND_30D_BPS),
+            (Self::WEEKS_90D, Self::BLEND_90D_BPS),
+        ] {
+            let stats = self.window(weeks, now);
+            if stats.has_activity() {
+                total += stats.score() * weight;
+                lifetime_weight -= weight;
+            }
+        }
+        ((total + lifetime_score * lifetime_weight) / 10000).min(10000)
+    }
+
+    pub fn summary(&self, lifetime_score: u64, now: i64) -> ReputationWindowSummary {
+        ReputationWindowSummary {
+            agent: self.agent,
+            window_30d: self.window(Self::WEEKS_30D, now),
+            window_90d: self.window(Self::WEEKS_90D, now),
+            lifetime_score,
+            blended_score: self.blended_score(lifetime_score, now),
+        }
+    }
+}
+
 /// Filtered view of reputation metrics respecting privacy settings
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct VisibleMetrics {
@@ -762,4 +1192,224 @@ pub struct VisibleMetrics {
     pub satisfaction_rating: Option<u64>,
     pub client_feedback_count: Option<u64>,
     pub total_earnings: Option<u64>,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_DAY: i64 = 24 * 60 * 60;
+
+    fn create_test_metrics(last_activity_at: i64) -> ReputationMetrics {
+        ReputationMetrics {
+            agent: Pubkey::new_unique(),
+            successful_payments: 0,
+            failed_payments: 0,
+            total_response_time: 0,
+            response_time_count: 0,
+            total_disputes: 0,
+            disputes_resolved: 0,
+            total_rating: 0,
+            total_ratings_count: 0,
+            payment_history_7d: [0; 7],
+            created_at: 0,
+            updated_at: 0,
+            skill_tags: Vec::new(),
+            behavior_tags: Vec::new(),
+            compliance_tags: Vec::new(),
+            tag_scores: Vec::new(),
+            tag_updated_at: 0,
+            source_scores: Vec::new(),
+            primary_source: "payai".to_string(),
+            last_aggregation: 0,
+            conflict_flags: Vec::new(),
+            bump: 255,
+            version: ReputationMetrics::CURRENT_VERSION,
+            last_activity_at,
+            last_decay_at: last_activity_at,
+        }
+    }
+
+    #[test]
+    fn test_reputation_windows() {
+        const ONE_WEEK: i64 = 7 * ONE_DAY;
+        let now = 100 * ONE_WEEK;
+        let mut windows = ReputationWindows {
+            agent: Pubkey::new_unique(),
+            buckets: [WindowBucket::default(); 13],
+            updated_at: 0,
+            bump: 255,
+        };
+
+        // Old failures (10 weeks ago) count toward 90d but not 30d
+        windows.record_payment(0, 500, false, now - 10 * ONE_WEEK);
+        windows.record_payment(0, 500, false, now - 10 * ONE_WEEK);
+        windows.record_payment(1_000, 500, true, now);
+        windows.record_payment(1_000, 500, true, now - ONE_WEEK);
+        windows.record_rating(5, now);
+
+        let recent = windows.window(ReputationWindows::WEEKS_30D, now);
+        assert_eq!(recent.successful_payments, 2);
+        assert_eq!(recent.failed_payments, 0);
+        assert_eq!(recent.volume, 2_000);
+        assert_eq!(recent.score(), 10000);
+
+        let quarter = windows.window(ReputationWindows::WEEKS_90D, now);
+        assert_eq!(quarter.failed_payments, 2);
+        assert_eq!(quarter.success_rate(), 5000);
+
+        // Week now-10 falls out of the 90d window and its ring slot is reused
+        windows.record_payment(0, 0, false, now + 3 * ONE_WEEK);
+        let later = windows.window(ReputationWindows::WEEKS_90D, now + 3 * ONE_WEEK);
+        assert_eq!(later.failed_payments, 1);
+        assert_eq!(later.successful_payments, 2);
+
+        // 30d 10000 * 40% + 90d 8000 * 30% + lifetime 5000 * 30%
+        let fresh = ReputationWindows {
+            buckets: [WindowBucket::default(); 13],
+            ..windows
+        };
+        assert_eq!(fresh.blended_score(5000, now), 5000);
+        let mut blended = fresh;
+        for _ in 0..4 {
+            blended.record_payment(0, 0, true, now - 5 * ONE_WEEK);
+        }
+        blended.record_payment(0, 0, false, now - 5 * ONE_WEEK);
+        blended.record_payment(0, 0, true, now);
+        // 30d: 1/1 = 10000; 90d: 5/6 = 8333
+        assert_eq!(blended.blended_score(5000, now), (10000 * 4000 + 8333 * 3000 + 5000 * 3000) / 10000);
+    }
+
+    #[test]
+    fn test_source_registry_enforcement() {
+        let updater = Pubkey::new_unique();
+        let mut registry = ReputationSourceRegistry {
+            multisig: Pubkey::new_unique(),
+            sources: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+        registry
+            .upsert_source(ApprovedSource {
+                source_name: "github".to_string(),
+                updater,
+                max_weight: 3000,
+                is_active: true,
+            })
+            .unwrap();
+
+        assert!(registry.authorize_update("github", &updater, 3000).is_ok());
+        assert!(registry.authorize_update("github", &updater, 3001).is_err());
+        assert!(registry.authorize_update("github", &Pubkey::new_unique(), 1000).is_err());
+        assert!(registry.authorize_update("unknown", &updater, 1000).is_err());
+        assert!(registry.is_active_updater("github", &updater));
+        assert!(!registry.is_active_updater("github", &Pubkey::new_unique()));
+
+        // Deactivating a source blocks further updates
+        let mut github = registry.sources[0].clone();
+        github.is_active = false;
+        registry.upsert_source(github).unwrap();
+        assert_eq!(registry.sources.len(), 1);
+        assert!(registry.authorize_update("github", &updater, 1000).is_err());
+        assert!(!registry.is_active_updater("github", &updater));
+    }
+
+    #[test]
+    fn test_recompute_drops_revoked_sources() {
+        let mut metrics = create_test_metrics(0);
+        metrics.update_source_score("payai".to_string(), 900, 5000, 10, 10000, 1).unwrap();
+        metrics.update_source_score("github".to_string(), 500, 5000, 10, 10000, 1).unwrap();
+        metrics.update_source_score("custom".to_string(), 850, 5000, 10, 10000, 1).unwrap();
+
+        let (max, min) = metrics.source_divergence().unwrap();
+        assert_eq!((max.source_name.as_str(), min.source_name.as_str()), ("payai", "github"));
+        assert!(metrics.detect_conflicts(1));
+
+        let mut registry = ReputationSourceRegistry {
+            multisig: Pubkey::new_unique(),
+            sources: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+        for (name, is_active) in [("payai", true), ("github", false), ("custom", true)] {
+            registry
+                .upsert_source(ApprovedSource {
+                    source_name: name.to_string(),
+                    updater: Pubkey::new_unique(),
+                    max_weight: 5000,
+                    is_active,
+                })
+                .unwrap();
+        }
+
+        // Revoked github no longer counts toward the aggregate or conflicts
+        assert_eq!(metrics.drop_unapproved_sources(&registry), 1);
+        assert!(metrics.get_source_score("github").is_none());
+        assert_eq!(metrics.source_scores.len(), 2);
+        assert!(!metrics.detect_conflicts(2));
+
+        metrics.remove_source("custom");
+        assert!(metrics.source_divergence().is_none());
+    }
+
+    #[test]
+    fn test_no_decay_within_grace_period() {
+        let mut metrics = create_test_metrics(1_000_000);
+        let now = 1_000_000 + 29 * ONE_DAY;
+
+        let (score, days) = metrics.apply_inactivity_decay(80, now, 30 * ONE_DAY, 50, 0);
+        assert_eq!(score, 80);
+        assert_eq!(days, 0);
+    }
+
+    #[test]
+    fn test_decay_is_not_double_counted() {
+        let mut metrics = create_test_metrics(1_000_000);
+        let now = 1_000_000 + 40 * ONE_DAY;
+
+        // 10 days past a 30 day grace period at 10% per day
+        let (score, days) = metrics.apply_inactivity_decay(100, now, 30 * ONE_DAY, 1000, 0);
+        assert_eq!(days, 10);
+        assert_eq!(score, ReputationMetrics::decayed_score(100, 10, 1000, 0));
+
+        // Calling again at the same time applies nothing further
+        let (again, days) = metrics.apply_inactivity_decay(score, now, 30 * ONE_DAY, 1000, 0);
+        assert_eq!(days, 0);
+        assert_eq!(again, score);
+    }
+
+    #[test]
+    fn test_decay_is_closed_form() {
+        // 1000 * 0.995^30 = 860.4
+        assert_eq!(ReputationMetrics::decayed_score(1000, 30, 50, 0), 860);
+
+        // Decades of inactivity cost no more than a few squarings
+        assert_eq!(ReputationMetrics::decayed_score(1000, u64::MAX, 1, 0), 0);
+        assert_eq!(ReputationMetrics::decayed_score(1000, 36_500, 1, 100), 100);
+        assert_eq!(ReputationMetrics::decayed_score(1000, 0, 50, 0), 1000);
+    }
+
+    #[test]
+    fn test_layout_version() {
+        let mut metrics = create_test_metrics(1_000_000);
+        assert!(metrics.is_current(ReputationMetrics::LEN));
+
+        // Pre-version accounts are short, whatever their trailing bytes hold
+        let old_len = ReputationMetrics::LEN - ReputationMetrics::V1_EXTENSION_LEN;
+        assert!(!metrics.is_current(old_len));
+        metrics.version = 0;
+        assert!(!metrics.is_current(ReputationMetrics::LEN));
+    }
+
+    #[test]
+    fn test_decay_respects_floor_and_activity() {
+        assert_eq!(ReputationMetrics::decayed_score(90, 365, 1000, 40), 40);
+        assert_eq!(ReputationMetrics::decayed_score(30, 365, 1000, 40), 30);
+
+        let mut metrics = create_test_metrics(1_000_000);
+        metrics.record_activity(1_000_000 + 35 * ONE_DAY);
+        let (score, days) =
+            metrics.apply_inactivity_decay(70, 1_000_000 + 40 * ONE_DAY, 30 * ONE_DAY, 50, 0);
+        assert_eq!(score, 70);
+        assert_eq!(days, 0);
+    }
+}
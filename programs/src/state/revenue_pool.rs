@@ -0,0 +1,364 @@
+/*!
+ * Revenue Pool State
+ *
+ * Transparent USDC revenue share for GHOST stakers. Protocol revenue (10% of
+ * B2C fees, 100% of B2B overage) is deposited into a single pool and split
+ * pro rata by weighted stake: `amount_staked` times the staker's tier
+ * multiplier. Each staker's weight is snapshotted in a `RevenuePosition`
+ * that is re-weighed on every claim and by a permissionless crank, so the
+ * pool never has to iterate over stakers.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::staking::{AccessTier, StakingAccount};
+use crate::GhostSpeakError;
+
+/// Seed for the revenue pool: [seed]
+pub const REVENUE_POOL_SEED: &[u8] = b"revenue_pool";
+
+/// Seed for the pool's USDC vault: [seed, revenue_pool]
+pub const REVENUE_VAULT_SEED: &[u8] = b"revenue_vault";
+
+/// Seed for staker positions: [seed, owner]
+pub const REVENUE_POSITION_SEED: &[u8] = b"revenue_position";
+
+/// Where distributed revenue came from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RevenueSource {
+    /// Stakers' 10% share of B2C pay-per-check fees
+    B2cFees,
+    /// B2B API overage fees (100% to stakers)
+    B2bOverage,
+    /// Any other protocol revenue
+    Other,
+}
+
+impl RevenueSource {
+    /// Number of sources, for per-source totals
+    pub const COUNT: usize = 3;
+
+    /// Position of this source in per-source totals
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl AccessTier {
+    /// Revenue share multiplier in basis points (unstaked accounts earn nothing)
+    pub fn revenue_multiplier_bps(self) -> u16 {
+        match self {
+            AccessTier::None => 0,
+            AccessTier::Basic => 10_000,    // 1.0x
+            AccessTier::Verified => 15_000, // 1.5x
+            AccessTier::Pro => 20_000,      // 2.0x
+            AccessTier::Whale => 30_000,    // 3.0x
+        }
+    }
+}
+
+impl StakingAccount {
+    /// Stake weighted by the tier's revenue multiplier
+    pub fn revenue_weighted_stake(&self) -> u128 {
+        self.amount_staked as u128 * self.tier.revenue_multiplier_bps() as u128 / 10_000
+    }
+}
+
+/// Read an owner's staking PDA, or `None` once it was closed or migrated away
+pub fn read_staking_account(staking: &AccountInfo) -> Result<Option<StakingAccount>> {
+    if staking.data_is_empty() {
+        return Ok(None);
+    }
+    require!(
+        staking.owner == &crate::ID,
+        GhostSpeakError::InvalidAccountOwner
+    );
+    let data = staking.try_borrow_data()?;
+    Ok(Some(StakingAccount::try_deserialize(&mut &data[..])?))
+}
+
+/// Revenue Pool - global USDC revenue share accumulator
+///
+/// Seeds: ["revenue_pool"]
+#[account]
+pub struct RevenuePool {
+    /// Admin who distributes revenue and closes periods
+    pub authority: Pubkey,
+    /// Mint of the distributed revenue (USDC)
+    pub usdc_mint: Pubkey,
+    /// Token account holding undistributed claims
+    pub vault: Pubkey,
+    /// Revenue per unit of weighted stake since inception, scaled by `PRECISION`
+    pub acc_revenue_per_weight: u128,
+    /// Sum of `weighted_stake` across all positions
+    pub total_weighted_stake: u128,
+    /// Lifetime revenue distributed
+    pub total_distributed: u64,
+    /// Lifetime revenue claimed
+    pub total_claimed: u64,
+    /// Lifetime revenue per source, indexed by `RevenueSource`
+    pub distributed_by_source: [u64; RevenueSource::COUNT],
+    /// Current reporting period (incremented by `reset_period`)
+    pub period_index: u32,
+    /// When the current period started
+    pub period_started_at: i64,
+    /// Revenue distributed in the current period
+    pub period_revenue: u64,
+    pub bump: u8,
+}
+
+impl RevenuePool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // usdc_mint
+        32 + // vault
+        16 + // acc_revenue_per_weight
+        16 + // total_weighted_stake
+        8 + // total_distributed
+        8 + // total_claimed
+        8 * RevenueSource::COUNT + // distributed_by_source
+        4 + // period_index
+        8 + // period_started_at
+        8 + // period_revenue
+        1; // bump
+
+    /// Fixed-point scale of `acc_revenue_per_weight`
+    pub const PRECISION: u128 = 1_000_000_000_000;
+
+    /// Split `amount` across all weighted stake
+    pub fn distribute(&mut self, amount: u64, source: RevenueSource) -> Result<()> {
+        require!(amount > 0, GhostSpeakError::InvalidAmount);
+        require!(
+            self.total_weighted_stake > 0,
+            GhostSpeakError::NoRevenueStakers
+        );
+
+        self.acc_revenue_per_weight = (amount as u128)
+            .checked_mul(Self::PRECISION)
+            .map(|scaled| scaled / self.total_weighted_stake)
+            .and_then(|increment| self.acc_revenue_per_weight.checked_add(increment))
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+        let by_source = &mut self.distributed_by_source[source.index()];
+        *by_source = by_source.saturating_add(amount);
+        self.total_distributed = self.total_distributed.saturating_add(amount);
+        self.period_revenue = self.period_revenue.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Settle a position at its current weight, then re-weigh it from the
+    /// owner's staking account (none left weighs nothing)
+    pub fn sync_position(
+        &mut self,
+        position: &mut RevenuePosition,
+        staking: Option<&StakingAccount>,
+    ) -> Result<()> {
+        position.settle(self.acc_revenue_per_weight)?;
+
+        let weighted_stake = staking.map_or(0, StakingAccount::revenue_weighted_stake);
+        self.total_weighted_stake = self
+            .total_weighted_stake
+            .saturating_sub(position.weighted_stake)
+            .checked_add(weighted_stake)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        position.weighted_stake = weighted_stake;
+        position.revenue_debt = position.accumulated(self.acc_revenue_per_weight)?;
+        Ok(())
+    }
+}
+
+/// Revenue Position - a staker's weight and unclaimed revenue
+///
+/// Seeds: ["revenue_position", owner]
+#[account]
+pub struct RevenuePosition {
+    pub owner: Pubkey,
+    /// Weighted stake as of the last sync
+    pub weighted_stake: u128,
+    /// Share of `acc_revenue_per_weight` already credited
+    pub revenue_debt: u128,
+    /// Settled revenue not yet claimed
+    pub pending: u64,
+    /// Lifetime revenue claimed
+    pub claimed: u64,
+    pub last_synced_at: i64,
+    pub bump: u8,
+}
+
+impl RevenuePosition {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        16 + // weighted_stake
+        16 + // revenue_debt
+        8 + // pending
+        8 + // claimed
+        8 + // last_synced_at
+        1; // bump
+
+    /// Revenue earned at the current weight since inception
+    pub fn accumulated(&self, acc_revenue_per_weight: u128) -> Result<u128> {
+        Ok(self
+            .weighted_stake
+            .checked_mul(acc_revenue_per_weight)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?
+            / RevenuePool::PRECISION)
+    }
+
+    /// Move revenue earned since the last sync into `pending`
+    pub fn settle(&mut self, acc_revenue_per_weight: u128) -> Result<()> {
+        let accumulated = self.accumulated(acc_revenue_per_weight)?;
+        let earned = accumulated.saturating_sub(self.revenue_debt);
+        self.pending = u64::try_from(earned)
+            .ok()
+            .and_then(|earned| self.pending.checked_add(earned))
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        self.revenue_debt = accumulated;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct RevenuePoolInitializedEvent {
+    pub authority: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct RevenueDistributedEvent {
+    pub amount: u64,
+    pub source: RevenueSource,
+    pub total_weighted_stake: u128,
+    pub acc_revenue_per_weight: u128,
+    pub period_index: u32,
+}
+
+#[event]
+pub struct RevenuePositionSyncedEvent {
+    pub owner: Pubkey,
+    pub weighted_stake: u128,
+    pub total_weighted_stake: u128,
+    pub pending: u64,
+}
+
+#[event]
+pub struct RevenueClaimedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub claimed: u64,
+}
+
+#[event]
+pub struct RevenuePeriodClosedEvent {
+    pub period_index: u32,
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub revenue: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::staking::TierSchedule;
+
+    fn staking_account(amount_staked: u64) -> StakingAccount {
+        let mut account = StakingAccount {
+            owner: Pubkey::new_unique(),
+            amount_staked,
+            staked_at: 0,
+            lock_duration: 0,
+            unlock_at: 0,
+            reputation_boost_bps: 0,
+            has_verified_badge: false,
+            has_premium_benefits: false,
+            total_slashed: 0,
+            tier: AccessTier::None,
+            api_calls_remaining: 0,
+            last_quota_reset: 0,
+            voting_power: 0,
+            bump: 0,
+            version: StakingAccount::CURRENT_VERSION,
+            reward_debt: 0,
+            pending_rewards: 0,
+            rewards_claimed: 0,
+            receipt_amount: 0,
+            bonded_amount: 0,
+            migrated_at: 0,
+        };
+        account.calculate_boost(&TierSchedule::default());
+        account
+    }
+
+    fn position(owner: Pubkey) -> RevenuePosition {
+        RevenuePosition {
+            owner,
+            weighted_stake: 0,
+            revenue_debt: 0,
+            pending: 0,
+            claimed: 0,
+            last_synced_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_revenue_split_by_weighted_stake() {
+        let mut pool = RevenuePool {
+            authority: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            acc_revenue_per_weight: 0,
+            total_weighted_stake: 0,
+            total_distributed: 0,
+            total_claimed: 0,
+            distributed_by_source: [0; RevenueSource::COUNT],
+            period_index: 0,
+            period_started_at: 0,
+            period_revenue: 0,
+            bump: 255,
+        };
+
+        // Nothing to split before anyone has weight
+        assert!(pool.distribute(1_000, RevenueSource::B2cFees).is_err());
+
+        // 5K GHOST Verified (1.5x) and 50K GHOST Pro (2.0x)
+        let verified = staking_account(5_000_000_000);
+        let pro = staking_account(50_000_000_000);
+        let mut verified_position = position(verified.owner);
+        let mut pro_position = position(pro.owner);
+        pool.sync_position(&mut verified_position, Some(&verified))
+            .unwrap();
+        pool.sync_position(&mut pro_position, Some(&pro)).unwrap();
+        assert_eq!(pool.total_weighted_stake, 7_500_000_000 + 100_000_000_000);
+
+        pool.distribute(1_075_000, RevenueSource::B2bOverage)
+            .unwrap();
+        pool.sync_position(&mut verified_position, Some(&verified))
+            .unwrap();
+        pool.sync_position(&mut pro_position, Some(&pro)).unwrap();
+        assert_eq!(verified_position.pending, 75_000);
+        assert_eq!(pro_position.pending, 1_000_000);
+        assert_eq!(
+            pool.distributed_by_source[RevenueSource::B2bOverage.index()],
+            1_075_000
+        );
+
+        // After unstaking, a re-synced position stops earning
+        let unstaked = staking_account(0);
+        pool.sync_position(&mut pro_position, Some(&unstaked)).unwrap();
+        assert_eq!(pool.total_weighted_stake, 7_500_000_000);
+        pool.distribute(7_500_000, RevenueSource::Other).unwrap();
+        pool.sync_position(&mut pro_position, Some(&unstaked)).unwrap();
+        pool.sync_position(&mut verified_position, Some(&verified))
+            .unwrap();
+        assert_eq!(pro_position.pending, 1_000_000);
+        assert_eq!(verified_position.pending, 7_575_000);
+
+        // A closed or migrated-away staking account drops out of the total,
+        // keeping what it already earned
+        pool.sync_position(&mut verified_position, None).unwrap();
+        assert_eq!(verified_position.weighted_stake, 0);
+        assert_eq!(pool.total_weighted_stake, 0);
+        assert_eq!(verified_position.pending, 7_575_000);
+    }
+}
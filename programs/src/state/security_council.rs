@@ -0,0 +1,201 @@
+/*!
+ * Security Council State
+ *
+ * The Protocol multisig acting as the security council, and the powers the
+ * DAO has granted it. With `veto_power` the council can stop a passed
+ * community proposal while its execution timelock runs; every veto then
+ * has to be answered by a community review proposal before the council
+ * can veto again.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::governance::{CouncilPowers, Multisig, SUPER_MAJORITY_PERCENT};
+use crate::GhostSpeakError;
+
+/// Seed for the security council: [seed]
+pub const SECURITY_COUNCIL_SEED: &[u8] = b"security_council";
+
+/// Seed for veto records: [seed, proposal]
+pub const COUNCIL_VETO_SEED: &[u8] = b"council_veto";
+
+/// Maximum power limitations listed for the council
+pub const MAX_COUNCIL_LIMITATIONS: usize = 10;
+
+/// Maximum length of one power limitation
+pub const MAX_COUNCIL_LIMITATION_LEN: usize = 256;
+
+/// Security Council - the council multisig and its powers
+///
+/// Seeds: ["security_council"]
+#[account]
+pub struct SecurityCouncil {
+    /// Protocol multisig acting as the council
+    pub council: Pubkey,
+    /// Powers granted to the council; only `veto_power` is enforced so far
+    pub powers: CouncilPowers,
+    /// Vetoed proposal still waiting on its community review
+    pub pending_review: Option<Pubkey>,
+    /// Vetoes cast so far
+    pub veto_count: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// Council Veto - why and when a passed proposal was stopped
+///
+/// Seeds: ["council_veto", proposal]
+#[account]
+pub struct CouncilVeto {
+    pub proposal: Pubkey,
+    pub council: Pubkey,
+    /// Hash of the off-chain veto rationale
+    pub rationale_hash: [u8; 32],
+    pub vetoed_at: i64,
+    /// Community proposal reviewing the veto, once filed
+    pub review_proposal: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl SecurityCouncil {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // council
+        CouncilPowers::size() + // powers
+        1 + 32 + // pending_review
+        4 + // veto_count
+        8 + // updated_at
+        1; // bump
+
+    /// Replace the council's powers
+    pub fn set_powers(&mut self, powers: CouncilPowers, now: i64) -> Result<()> {
+        require!(
+            powers.limitations.len() <= MAX_COUNCIL_LIMITATIONS,
+            GhostSpeakError::InvalidConfiguration
+        );
+        require!(
+            powers
+                .limitations
+                .iter()
+                .all(|limitation| limitation.len() <= MAX_COUNCIL_LIMITATION_LEN),
+            GhostSpeakError::InputTooLong
+        );
+        self.powers = powers;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Record a veto of `proposal`, which must then be reviewed before the
+    /// council can veto again
+    pub fn record_veto(&mut self, proposal: Pubkey, now: i64) -> Result<()> {
+        require!(self.powers.veto_power, GhostSpeakError::CouncilVetoDisabled);
+        require!(
+            self.pending_review.is_none(),
+            GhostSpeakError::VetoReviewPending
+        );
+        self.pending_review = Some(proposal);
+        self.veto_count = self.veto_count.saturating_add(1);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Clear the pending review once `proposal`'s review has been filed
+    pub fn clear_review(&mut self, proposal: Pubkey, now: i64) {
+        if self.pending_review == Some(proposal) {
+            self.pending_review = None;
+            self.updated_at = now;
+        }
+    }
+}
+
+impl CouncilVeto {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // proposal
+        32 + // council
+        32 + // rationale_hash
+        8 + // vetoed_at
+        1 + 32 + // review_proposal
+        1; // bump
+}
+
+/// Council approval weight a veto needs: a supermajority of the council's
+/// total weight, and never less than its threshold
+pub fn council_supermajority(council: &Multisig) -> usize {
+    let supermajority = (council.total_weight() * SUPER_MAJORITY_PERCENT as usize).div_ceil(100);
+    supermajority.max(council.threshold as usize)
+}
+
+#[event]
+pub struct SecurityCouncilUpdatedEvent {
+    pub council: Pubkey,
+    pub veto_power: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalVetoedEvent {
+    pub proposal: Pubkey,
+    pub council: Pubkey,
+    pub rationale_hash: [u8; 32],
+    pub approval_weight: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VetoReviewFiledEvent {
+    pub vetoed_proposal: Pubkey,
+    pub review_proposal: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn council(veto_power: bool) -> SecurityCouncil {
+        SecurityCouncil {
+            council: Pubkey::new_unique(),
+            powers: CouncilPowers {
+                emergency_powers: false,
+                veto_power,
+                parameter_modification: false,
+                treasury_management: false,
+                upgrade_oversight: false,
+                limitations: Vec::new(),
+            },
+            pending_review: None,
+            veto_count: 0,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_veto_requires_power_and_prior_review() {
+        assert!(council(false).record_veto(Pubkey::new_unique(), 1).is_err());
+
+        let mut sc = council(true);
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        sc.record_veto(first, 1).unwrap();
+        assert!(sc.record_veto(second, 2).is_err());
+
+        // A review of some other proposal doesn't count
+        sc.clear_review(second, 3);
+        assert_eq!(sc.pending_review, Some(first));
+        sc.clear_review(first, 4);
+        sc.record_veto(second, 5).unwrap();
+        assert_eq!(sc.veto_count, 2);
+    }
+
+    #[test]
+    fn test_council_powers_bounded() {
+        let mut sc = council(true);
+        let mut powers = sc.powers.clone();
+        powers.limitations = vec!["x".repeat(MAX_COUNCIL_LIMITATION_LEN + 1)];
+        assert!(sc.set_powers(powers.clone(), 1).is_err());
+        powers.limitations = vec![String::new(); MAX_COUNCIL_LIMITATIONS + 1];
+        assert!(sc.set_powers(powers.clone(), 1).is_err());
+        powers.limitations = vec!["no treasury moves".to_string()];
+        sc.set_powers(powers, 1).unwrap();
+        assert_eq!(sc.updated_at, 1);
+    }
+}
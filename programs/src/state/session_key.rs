@@ -0,0 +1,216 @@
+/*!
+ * Session Key State
+ *
+ * Ephemeral keys an agent owner hands to automation servers. Each session
+ * is a PDA per agent and key that lists the operations it may perform, when
+ * it expires and how many actions it may take in total. The owner can
+ * revoke it at any time, and it stops working as soon as the agent changes
+ * hands.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Seed for session keys: [seed, agent, session signer]
+pub const SESSION_KEY_SEED: &[u8] = b"session_key";
+
+/// Longest a session key may stay valid (30 days)
+pub const MAX_SESSION_KEY_DURATION: i64 = 30 * 24 * 60 * 60;
+
+/// Agent operations a session key may perform
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionScope {
+    /// Submit escrow deliveries for the agent
+    SubmitDeliveries,
+    /// Record PayAI payments for the agent
+    RecordPayments,
+}
+
+impl SessionScope {
+    pub const COUNT: usize = 2;
+
+    /// Bit for this scope in a session's `scopes` mask
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Session Key - an ephemeral signer authorized for some agent operations
+///
+/// Seeds: ["session_key", agent, signer]
+#[account]
+pub struct SessionKey {
+    pub agent: Pubkey,
+    /// Agent owner that granted the session (refunded on revocation)
+    pub owner: Pubkey,
+    /// Ephemeral signing key
+    pub signer: Pubkey,
+    /// Bitmask of allowed operations, see `SessionScope::bit`
+    pub scopes: u8,
+    pub expires_at: i64,
+    /// Most actions the session may take
+    pub max_actions: u32,
+    pub actions_used: u32,
+    pub created_at: i64,
+    pub last_used_at: i64,
+    pub bump: u8,
+}
+
+impl SessionKey {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // agent
+        32 + // owner
+        32 + // signer
+        1 + // scopes
+        8 + // expires_at
+        4 + // max_actions
+        4 + // actions_used
+        8 + // created_at
+        8 + // last_used_at
+        1; // bump
+
+    /// Validate the terms of a new session
+    pub fn validate(
+        owner: &Pubkey,
+        signer: &Pubkey,
+        scopes: u8,
+        expires_at: i64,
+        max_actions: u32,
+        now: i64,
+    ) -> Result<()> {
+        let all_scopes = (1u8 << SessionScope::COUNT) - 1;
+        require!(
+            signer != owner
+                && *signer != Pubkey::default()
+                && scopes != 0
+                && scopes & !all_scopes == 0
+                && max_actions > 0
+                && expires_at > now
+                && expires_at <= now.saturating_add(MAX_SESSION_KEY_DURATION),
+            GhostSpeakError::InvalidSessionKey
+        );
+        Ok(())
+    }
+
+    /// Check that `signer` may perform `scope` for `agent`, now owned by
+    /// `agent_owner`, and count the action
+    pub fn authorize(
+        &mut self,
+        agent: &Pubkey,
+        agent_owner: Option<Pubkey>,
+        signer: &Pubkey,
+        scope: SessionScope,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.agent == *agent && self.signer == *signer && agent_owner == Some(self.owner),
+            GhostSpeakError::InvalidSessionKey
+        );
+        require!(now < self.expires_at, GhostSpeakError::SessionKeyExpired);
+        require!(
+            self.scopes & scope.bit() != 0,
+            GhostSpeakError::SessionKeyScopeDenied
+        );
+        require!(
+            self.actions_used < self.max_actions,
+            GhostSpeakError::SessionKeyExhausted
+        );
+
+        self.actions_used += 1;
+        self.last_used_at = now;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct SessionKeyCreatedEvent {
+    pub agent: Pubkey,
+    pub owner: Pubkey,
+    pub signer: Pubkey,
+    pub scopes: u8,
+    pub expires_at: i64,
+    pub max_actions: u32,
+}
+
+#[event]
+pub struct SessionKeyRevokedEvent {
+    pub agent: Pubkey,
+    pub signer: Pubkey,
+    pub actions_used: u32,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_key_authorization() {
+        let agent = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let deliveries = SessionScope::SubmitDeliveries.bit();
+
+        assert!(SessionKey::validate(&owner, &owner, deliveries, 100, 1, 0).is_err());
+        assert!(SessionKey::validate(&owner, &signer, 0b100, 100, 1, 0).is_err());
+        assert!(SessionKey::validate(&owner, &signer, deliveries, 100, 0, 0).is_err());
+        assert!(SessionKey::validate(
+            &owner,
+            &signer,
+            deliveries,
+            MAX_SESSION_KEY_DURATION + 1,
+            1,
+            0
+        )
+        .is_err());
+        SessionKey::validate(&owner, &signer, deliveries, 100, 2, 0).unwrap();
+
+        let mut session = SessionKey {
+            agent,
+            owner,
+            signer,
+            scopes: deliveries,
+            expires_at: 100,
+            max_actions: 2,
+            actions_used: 0,
+            created_at: 0,
+            last_used_at: 0,
+            bump: 255,
+        };
+        let scope = SessionScope::SubmitDeliveries;
+
+        session
+            .authorize(&agent, Some(owner), &signer, scope, 10)
+            .unwrap();
+        assert_eq!((session.actions_used, session.last_used_at), (1, 10));
+
+        // Wrong scope, wrong signer, new owner and expiry are all refused
+        assert!(session
+            .authorize(
+                &agent,
+                Some(owner),
+                &signer,
+                SessionScope::RecordPayments,
+                20
+            )
+            .is_err());
+        assert!(session
+            .authorize(&agent, Some(owner), &owner, scope, 20)
+            .is_err());
+        assert!(session
+            .authorize(&agent, Some(signer), &signer, scope, 20)
+            .is_err());
+        assert!(session
+            .authorize(&agent, Some(owner), &signer, scope, 100)
+            .is_err());
+
+        // The action budget runs out
+        session
+            .authorize(&agent, Some(owner), &signer, scope, 30)
+            .unwrap();
+        assert!(session
+            .authorize(&agent, Some(owner), &signer, scope, 40)
+            .is_err());
+    }
+}
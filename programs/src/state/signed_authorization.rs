@@ -0,0 +1,177 @@
+/*!
+ * Signed Authorization State
+ *
+ * Off-chain Ed25519 consents recorded on-chain. A signer authorizes one
+ * specific action (an agent consenting to a credential from an issuer, a
+ * client pre-authorizing an escrow's release) by signing a typed payload;
+ * anyone may submit it through `verify_signed_authorization`, which checks
+ * the signature, consumes the signer's `MetaNonce` and stores the record
+ * for later instructions to rely on.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Domain separator for signed authorization payloads
+pub const SIGNED_AUTHORIZATION_DOMAIN: &[u8] = b"ghostspeak:authorization:v1";
+
+/// Seed for authorization records: [seed, signer, nonce (le bytes)]
+pub const SIGNED_AUTHORIZATION_SEED: &[u8] = b"signed_authorization";
+
+/// Action a signer can authorize off-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorizedAction {
+    /// Subject consents to receiving a credential from `issuer`
+    CredentialIssuance { issuer: Pubkey },
+    /// Client pre-authorizes release of `escrow` once delivery is submitted
+    EscrowAutoRelease { escrow: Pubkey },
+}
+
+impl AuthorizedAction {
+    /// Largest serialized action (tag + pubkey)
+    pub const MAX_LEN: usize = 1 + 32;
+
+    /// Account the action is bound to
+    pub fn target(&self) -> Pubkey {
+        match self {
+            Self::CredentialIssuance { issuer } => *issuer,
+            Self::EscrowAutoRelease { escrow } => *escrow,
+        }
+    }
+}
+
+/// Typed payload signed off-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuthorizationPayload {
+    pub action: AuthorizedAction,
+    /// Must be higher than the signer's last used `MetaNonce`
+    pub nonce: u64,
+    /// Unix timestamp after which the payload, and the record, lapse
+    pub expires_at: i64,
+}
+
+/// Verified authorization - exists once its nonce has been consumed
+///
+/// Seeds: ["signed_authorization", signer, nonce]
+#[account]
+pub struct SignedAuthorization {
+    pub signer: Pubkey,
+    pub action: AuthorizedAction,
+    pub nonce: u64,
+    pub expires_at: i64,
+    /// Verified Ed25519 signature, kept as provenance
+    pub signature: [u8; 64],
+    /// Account that submitted the payload and paid the rent
+    pub rent_payer: Pubkey,
+    pub verified_at: i64,
+    pub bump: u8,
+}
+
+impl SignedAuthorization {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // signer
+        AuthorizedAction::MAX_LEN + // action
+        8 + // nonce
+        8 + // expires_at
+        64 + // signature
+        32 + // rent_payer
+        8 + // verified_at
+        1; // bump
+
+    /// Bytes the signer signs for `payload`
+    pub fn signing_message(payload: &AuthorizationPayload) -> Result<Vec<u8>> {
+        let mut message =
+            Vec::with_capacity(SIGNED_AUTHORIZATION_DOMAIN.len() + AuthorizedAction::MAX_LEN + 16);
+        message.extend_from_slice(SIGNED_AUTHORIZATION_DOMAIN);
+        payload.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    /// Require that `signer` authorized exactly `action` and it has not lapsed
+    pub fn require_action(
+        &self,
+        signer: &Pubkey,
+        action: &AuthorizedAction,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.signer == *signer && self.action == *action && now <= self.expires_at,
+            GhostSpeakError::InvalidSignedAuthorization
+        );
+        Ok(())
+    }
+}
+
+#[event]
+pub struct SignedAuthorizationVerifiedEvent {
+    pub signer: Pubkey,
+    pub action: AuthorizedAction,
+    pub nonce: u64,
+    pub expires_at: i64,
+    pub submitted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_authorization() {
+        let signer = Pubkey::new_unique();
+        let escrow = Pubkey::new_unique();
+        let action = AuthorizedAction::EscrowAutoRelease { escrow };
+        let payload = AuthorizationPayload {
+            action,
+            nonce: 1,
+            expires_at: 100,
+        };
+
+        // Domain-separated, and bound to every payload field
+        let message = SignedAuthorization::signing_message(&payload).unwrap();
+        assert!(message.starts_with(SIGNED_AUTHORIZATION_DOMAIN));
+        assert_ne!(
+            message,
+            SignedAuthorization::signing_message(&AuthorizationPayload {
+                nonce: 2,
+                ..payload
+            })
+            .unwrap()
+        );
+
+        let record = SignedAuthorization {
+            signer,
+            action,
+            nonce: payload.nonce,
+            expires_at: payload.expires_at,
+            signature: [0; 64],
+            rent_payer: Pubkey::new_unique(),
+            verified_at: 10,
+            bump: 255,
+        };
+        record.require_action(&signer, &action, 100).unwrap();
+
+        // Another signer, action kind or target, or a lapsed record is refused
+        assert!(record
+            .require_action(&Pubkey::new_unique(), &action, 50)
+            .is_err());
+        assert!(record
+            .require_action(
+                &signer,
+                &AuthorizedAction::CredentialIssuance { issuer: escrow },
+                50
+            )
+            .is_err());
+        assert!(record
+            .require_action(
+                &signer,
+                &AuthorizedAction::EscrowAutoRelease {
+                    escrow: Pubkey::new_unique()
+                },
+                50
+            )
+            .is_err());
+        assert!(record.require_action(&signer, &action, 101).is_err());
+    }
+}
@@ -6,6 +6,35 @@
 
 use anchor_lang::prelude::*;
 
+use crate::state::governance::{
+    LOCKUP_MULTIPLIER_1_MONTH, LOCKUP_MULTIPLIER_1_YEAR, LOCKUP_MULTIPLIER_2_YEARS,
+    LOCKUP_MULTIPLIER_3_MONTHS, LOCKUP_MULTIPLIER_6_MONTHS, LOCKUP_MULTIPLIER_NONE,
+    LOCKUP_TIER_1_MONTH, LOCKUP_TIER_1_YEAR, LOCKUP_TIER_2_YEARS, LOCKUP_TIER_3_MONTHS,
+    LOCKUP_TIER_6_MONTHS, LOCKUP_TIER_NONE,
+};
+
+/// Seed for slash records: [seed, owner]
+pub const SLASH_RECORD_SEED: &[u8] = b"slash_record";
+
+/// Seed for the token account escrowing a slash: [seed, slash_record]
+pub const SLASH_ESCROW_SEED: &[u8] = b"slash_escrow";
+
+/// Maximum length of an appeal's evidence URI
+pub const MAX_SLASH_APPEAL_URI: usize = 200;
+
+/// Seed for pending stake migrations: [seed, owner]
+pub const STAKE_MIGRATION_SEED: &[u8] = b"stake_migration";
+
+/// Default time the new owner has to accept a stake migration (7 days),
+/// governed by `ParameterKey::StakeMigrationWindow`
+pub const STAKE_MIGRATION_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// Seed for the Token-2022 stake receipt mint: [seed]
+pub const STAKE_RECEIPT_MINT_SEED: &[u8] = b"stake_receipt_mint";
+
+/// Seed for the pending governance update of the staking config: [seed]
+pub const STAKING_CONFIG_PROPOSAL_SEED: &[u8] = b"staking_config_proposal";
+
 /// Individual owner staking account tracking their staked GHOST tokens
 /// Used for Sybil resistance (1K GHOST minimum), API quotas, and governance
 #[account]
@@ -46,11 +75,52 @@ pub struct StakingAccount {
     /// Last API quota reset timestamp
     pub last_quota_reset: i64,
 
-    /// Voting power for governance (equals amount_staked)
+    /// Voting power for governance (amount_staked weighted by the lock multiplier)
     pub voting_power: u64,
 
     /// Bump for PDA
     pub bump: u8,
+
+    /// Layout version; fields below exist only from `CURRENT_VERSION` on,
+    /// and older accounts gain them through `migrate_staking_account`
+    pub version: u8,
+
+    /// Share of `acc_reward_per_share` already credited to this stake
+    pub reward_debt: u128,
+
+    /// Settled rewards not yet claimed
+    pub pending_rewards: u64,
+
+    /// Lifetime rewards claimed
+    pub rewards_claimed: u64,
+
+    /// Stake backing outstanding receipt tokens (burn them to unstake it)
+    pub receipt_amount: u64,
+
+    /// Stake reserved as bonds for open trial escrows
+    pub bonded_amount: u64,
+
+    /// When the stake last moved to this owner (0 = never migrated)
+    pub migrated_at: i64,
+}
+
+/// Staking account as written before the layout version
+#[derive(AnchorDeserialize)]
+pub struct StakingAccountV0 {
+    pub owner: Pubkey,
+    pub amount_staked: u64,
+    pub staked_at: i64,
+    pub lock_duration: i64,
+    pub unlock_at: i64,
+    pub reputation_boost_bps: u16,
+    pub has_verified_badge: bool,
+    pub has_premium_benefits: bool,
+    pub total_slashed: u64,
+    pub tier: AccessTier,
+    pub api_calls_remaining: u32,
+    pub last_quota_reset: i64,
+    pub voting_power: u64,
+    pub bump: u8,
 }
 
 impl StakingAccount {
@@ -68,51 +138,237 @@ impl StakingAccount {
         4 +  // api_calls_remaining
         8 +  // last_quota_reset
         8 +  // voting_power
-        1;   // bump
+        1 +  // bump
+        Self::V1_EXTENSION_LEN; // version, reward, receipt, bond and migration tracking
+
+    /// Layout with `version`, reward, receipt, bond and migration tracking after `bump`
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Bytes the version and its fields add to accounts created before them
+    ///
+    /// The layout has no variable-length fields, so an older account is
+    /// simply too short to deserialize until it is migrated.
+    pub const V1_EXTENSION_LEN: usize = 1 + // version
+        16 + // reward_debt
+        8 +  // pending_rewards
+        8 +  // rewards_claimed
+        8 +  // receipt_amount
+        8 +  // bonded_amount
+        8;   // migrated_at
+
+    /// A pre-version stake in the current layout
+    ///
+    /// It earns rewards from `acc_reward_per_share` on, holds no receipts
+    /// or bonds, and its voting power picks up the lock multiplier.
+    pub fn from_v0(
+        legacy: StakingAccountV0,
+        acc_reward_per_share: u128,
+        schedule: &TierSchedule,
+    ) -> Result<Self> {
+        let mut account = Self {
+            owner: legacy.owner,
+            amount_staked: legacy.amount_staked,
+            staked_at: legacy.staked_at,
+            lock_duration: legacy.lock_duration,
+            unlock_at: legacy.unlock_at,
+            reputation_boost_bps: legacy.reputation_boost_bps,
+            has_verified_badge: legacy.has_verified_badge,
+            has_premium_benefits: legacy.has_premium_benefits,
+            total_slashed: legacy.total_slashed,
+            tier: legacy.tier,
+            api_calls_remaining: legacy.api_calls_remaining,
+            last_quota_reset: legacy.last_quota_reset,
+            voting_power: legacy.voting_power,
+            bump: legacy.bump,
+            version: Self::CURRENT_VERSION,
+            reward_debt: 0,
+            pending_rewards: 0,
+            rewards_claimed: 0,
+            receipt_amount: 0,
+            bonded_amount: 0,
+            migrated_at: 0,
+        };
+        account.reward_debt = account.accumulated_rewards(acc_reward_per_share)?;
+        account.voting_power = account.lock_weighted_stake(schedule);
+        Ok(account)
+    }
+
+    /// Stake free to unstake, i.e. not backing receipt tokens
+    ///
+    /// A slash can leave less stake than receipts; the excess receipts must
+    /// be burned before anything can be unstaked.
+    pub fn unreceipted_stake(&self) -> u64 {
+        self.amount_staked.saturating_sub(self.receipt_amount)
+    }
+
+    /// Stake backing neither receipt tokens nor open trial bonds, which
+    /// alone may be unstaked, receipted or bonded again
+    pub fn free_stake(&self) -> u64 {
+        self.unreceipted_stake().saturating_sub(self.bonded_amount)
+    }
+
+    /// Reserve `amount` of free stake as a trial escrow's bond
+    pub fn reserve_bond(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= self.free_stake(),
+            crate::GhostSpeakError::InsufficientStake
+        );
+        self.bonded_amount += amount;
+        Ok(())
+    }
+
+    /// Release a settled trial escrow's bond
+    pub fn release_bond(&mut self, amount: u64) {
+        self.bonded_amount = self.bonded_amount.saturating_sub(amount);
+    }
+
+    /// Whether the account holds nothing worth keeping: no stake, no
+    /// unclaimed rewards and no receipts left to burn
+    pub fn is_closable(&self) -> bool {
+        self.amount_staked == 0
+            && self.pending_rewards == 0
+            && self.receipt_amount == 0
+            && self.bonded_amount == 0
+    }
+
+    /// Voting power multiplier earned by the lock duration (basis points)
+    pub fn lock_multiplier_bps(&self, schedule: &TierSchedule) -> u16 {
+        schedule.lock_multiplier_bps(self.lock_duration)
+    }
+
+    /// Stake weighted by the lock multiplier
+    pub fn lock_weighted_stake(&self, schedule: &TierSchedule) -> u64 {
+        let weighted =
+            self.amount_staked as u128 * self.lock_multiplier_bps(schedule) as u128 / 10_000;
+        weighted.min(u64::MAX as u128) as u64
+    }
+
+    /// Lengthen the lock, keeping the original stake time
+    ///
+    /// The new lock must be longer than the current one and still in force,
+    /// so the multiplier it earns is backed by tokens that are actually locked.
+    pub fn extend_lock(
+        &mut self,
+        lock_duration: i64,
+        now: i64,
+        schedule: &TierSchedule,
+    ) -> Result<()> {
+        require!(
+            lock_duration > self.lock_duration,
+            crate::GhostSpeakError::LockNotExtended
+        );
+        let unlock_at = self
+            .staked_at
+            .checked_add(lock_duration)
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        require!(unlock_at > now, crate::GhostSpeakError::LockNotExtended);
+
+        self.lock_duration = lock_duration;
+        self.unlock_at = unlock_at;
+        self.voting_power = self.lock_weighted_stake(schedule);
+        Ok(())
+    }
+
+    /// Tier this stake earns under `schedule` right now
+    ///
+    /// The stored `tier` is only recomputed when the stake itself changes,
+    /// so it lags governance updates to the thresholds. Tier gates and fee
+    /// discounts read this instead.
+    pub fn current_tier(&self, schedule: &TierSchedule) -> AccessTier {
+        schedule.tier_for(self.amount_staked)
+    }
+
+    /// This stake under `new_owner`, for an accepted stake migration
+    ///
+    /// Amount, stake time, lock, tier and reward history carry over. The
+    /// migration time is recorded separately: the old owner may already
+    /// have snapshotted this stake's voting power, so it must not count
+    /// again for proposals whose snapshot point precedes the migration.
+    pub fn migrated_to(&self, new_owner: Pubkey, bump: u8, now: i64) -> Self {
+        Self {
+            owner: new_owner,
+            migrated_at: now,
+            bump,
+            ..self.clone()
+        }
+    }
+
+    /// Since when the current owner has held this stake unchanged
+    ///
+    /// The later of the last stake and the last migration; voting
+    /// snapshots taken before it must not count the stake.
+    pub fn held_since(&self) -> i64 {
+        self.staked_at.max(self.migrated_at)
+    }
+
+    /// Rewards this stake has earned since the accumulator started
+    pub fn accumulated_rewards(&self, acc_reward_per_share: u128) -> Result<u128> {
+        Ok((self.amount_staked as u128)
+            .checked_mul(acc_reward_per_share)
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?
+            / StakingConfig::REWARD_PRECISION)
+    }
+
+    /// Move rewards earned since the last settlement into `pending_rewards`
+    ///
+    /// Must run before `amount_staked` changes, with the accumulator
+    /// already brought up to date.
+    pub fn settle_rewards(&mut self, acc_reward_per_share: u128) -> Result<()> {
+        let accumulated = self.accumulated_rewards(acc_reward_per_share)?;
+        let earned = accumulated.saturating_sub(self.reward_debt);
+        self.pending_rewards = u64::try_from(earned)
+            .ok()
+            .and_then(|earned| self.pending_rewards.checked_add(earned))
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        self.reward_debt = accumulated;
+        Ok(())
+    }
 
     /// Calculate reputation boost, tier, and API quota based on stake amount
     ///
-    /// Tiers (GHOST token has 6 decimals, not 9!):
+    /// Tier thresholds come from the governed `TierSchedule`; by default
+    /// (GHOST token has 6 decimals, not 9!):
     /// - 1,000 GHOST (1_000_000_000) → Basic: +5% boost, 100 API calls/day, Sybil-resistant
     /// - 5,000 GHOST (5_000_000_000) → Verified: +10% boost, 1,000 API calls/day
     /// - 50,000 GHOST (50_000_000_000) → Pro: +15% boost, 10,000 API calls/day
     /// - 500,000 GHOST (500_000_000_000) → Whale: +20% boost, unlimited API calls
-    pub fn calculate_boost(&mut self) {
-        // Update voting power (1 GHOST staked = 1 vote)
-        self.voting_power = self.amount_staked;
-
-        // Set tier and quotas (GHOST has 6 decimals!)
-        if self.amount_staked >= 500_000_000_000 { // 500K GHOST (6 decimals) - Whale
-            self.reputation_boost_bps = 2000; // 20%
-            self.has_verified_badge = true;
-            self.has_premium_benefits = true;
-            self.tier = AccessTier::Whale;
-            self.api_calls_remaining = u32::MAX; // Unlimited
-        } else if self.amount_staked >= 50_000_000_000 { // 50K GHOST - Pro
-            self.reputation_boost_bps = 1500; // 15%
-            self.has_verified_badge = true;
-            self.has_premium_benefits = true;
-            self.tier = AccessTier::Pro;
-            self.api_calls_remaining = 10_000; // 10K calls/day
-        } else if self.amount_staked >= 5_000_000_000 { // 5K GHOST - Verified
-            self.reputation_boost_bps = 1000; // 10%
-            self.has_verified_badge = true;
-            self.has_premium_benefits = false;
-            self.tier = AccessTier::Verified;
-            self.api_calls_remaining = 1_000; // 1K calls/day
-        } else if self.amount_staked >= 1_000_000_000 { // 1K GHOST - Basic (Sybil minimum)
-            self.reputation_boost_bps = 500; // 5%
-            self.has_verified_badge = false;
-            self.has_premium_benefits = false;
-            self.tier = AccessTier::Basic;
-            self.api_calls_remaining = 100; // 100 calls/day
-        } else {
-            // Below minimum stake - no access
-            self.reputation_boost_bps = 0;
-            self.has_verified_badge = false;
-            self.has_premium_benefits = false;
-            self.tier = AccessTier::None;
-            self.api_calls_remaining = 0; // No API access
+    pub fn calculate_boost(&mut self, schedule: &TierSchedule) {
+        // Update voting power (1 GHOST staked = 1 vote, times the lock multiplier)
+        self.voting_power = self.lock_weighted_stake(schedule);
+
+        // Set tier and quotas
+        self.tier = schedule.tier_for(self.amount_staked);
+        match self.tier {
+            AccessTier::Whale => {
+                self.reputation_boost_bps = 2000; // 20%
+                self.has_verified_badge = true;
+                self.has_premium_benefits = true;
+                self.api_calls_remaining = u32::MAX; // Unlimited
+            }
+            AccessTier::Pro => {
+                self.reputation_boost_bps = 1500; // 15%
+                self.has_verified_badge = true;
+                self.has_premium_benefits = true;
+                self.api_calls_remaining = 10_000; // 10K calls/day
+            }
+            AccessTier::Verified => {
+                self.reputation_boost_bps = 1000; // 10%
+                self.has_verified_badge = true;
+                self.has_premium_benefits = false;
+                self.api_calls_remaining = 1_000; // 1K calls/day
+            }
+            AccessTier::Basic => {
+                self.reputation_boost_bps = 500; // 5%
+                self.has_verified_badge = false;
+                self.has_premium_benefits = false;
+                self.api_calls_remaining = 100; // 100 calls/day
+            }
+            AccessTier::None => {
+                // Below minimum stake - no access
+                self.reputation_boost_bps = 0;
+                self.has_verified_badge = false;
+                self.has_premium_benefits = false;
+                self.api_calls_remaining = 0; // No API access
+            }
         }
     }
 
@@ -163,6 +419,48 @@ impl StakingAccount {
             AccessTier::None => 0,
         }
     }
+
+    /// Check if this account verifies without limits (Verified tier or higher)
+    pub fn has_unlimited_verifications(&self) -> bool {
+        self.tier.meets(AccessTier::Verified)
+    }
+
+    /// Check if this account has unmetered API access (Whale tier)
+    pub fn has_unlimited_api(&self) -> bool {
+        self.tier == AccessTier::Whale
+    }
+
+    /// Daily API call limit, or None when unmetered
+    pub fn get_api_request_limit(&self) -> Option<u32> {
+        (!self.has_unlimited_api()).then(|| self.get_daily_api_limit())
+    }
+
+    /// API calls left at `current_time`, counting a daily reset that is due
+    pub fn api_calls_available(&self, current_time: i64) -> u32 {
+        if self.should_reset_quota(current_time) {
+            self.get_daily_api_limit()
+        } else {
+            self.api_calls_remaining
+        }
+    }
+
+    /// Meter `calls` API calls against the daily quota, resetting it first
+    /// if a day has passed
+    pub fn consume_api_calls(&mut self, calls: u32, current_time: i64) -> Result<()> {
+        if self.should_reset_quota(current_time) {
+            self.reset_daily_quota(current_time);
+        }
+        if self.has_unlimited_api() {
+            return Ok(());
+        }
+
+        require!(
+            calls <= self.api_calls_remaining,
+            crate::GhostSpeakError::ApiQuotaExhausted
+        );
+        self.api_calls_remaining -= calls;
+        Ok(())
+    }
 }
 
 /// Global staking configuration
@@ -183,10 +481,65 @@ pub struct StakingConfig {
     /// Slash percentage for dispute loss (10% = 1000 bps)
     pub dispute_slash_bps: u16,
 
-    /// Treasury account for slashed tokens
+    /// Protocol treasury token account (finalized slashes go to the insurance pool)
     pub treasury: Pubkey,
 
     pub bump: u8,
+
+    /// Layout version; fields below exist only from `CURRENT_VERSION` on,
+    /// and older configs gain them through `migrate_staking_config`
+    pub version: u8,
+
+    /// Dispute queue head start per tier in seconds, indexed by `AccessTier`
+    pub dispute_head_start_secs: [u32; AccessTier::COUNT],
+
+    /// Dispute resolution SLA per tier in seconds, indexed by `AccessTier`
+    pub dispute_sla_secs: [u32; AccessTier::COUNT],
+
+    /// Rewards released per second, shared pro rata by all stakers
+    pub reward_rate: u64,
+
+    /// Rewards per staked token since inception, scaled by `REWARD_PRECISION`
+    pub acc_reward_per_share: u128,
+
+    /// Last time `acc_reward_per_share` was brought up to date
+    pub last_reward_update: i64,
+
+    /// Sum of `amount_staked` across all staking accounts
+    pub total_staked: u64,
+
+    /// Funded rewards not yet released to stakers
+    pub reward_reserve: u64,
+
+    /// Protocol or Dispute multisig that reviews slash appeals (default: appeals disabled)
+    pub slash_review_multisig: Pubkey,
+
+    /// Seconds a slashed owner has to appeal before the slash can be finalized
+    pub slash_appeal_window: i64,
+
+    /// Token-2022 stake receipt mint (default: receipts disabled)
+    pub receipt_mint: Pubkey,
+
+    /// Receipt tokens outstanding, always the sum of every `receipt_amount`
+    pub receipt_supply: u64,
+
+    /// Stake thresholds and lock multipliers for tiers and voting power
+    pub tier_schedule: TierSchedule,
+
+    /// Protocol multisig that approves parameter updates (default: not yet handed over)
+    pub governance_multisig: Pubkey,
+}
+
+/// Staking config as written before the layout version
+#[derive(AnchorDeserialize)]
+pub struct StakingConfigV0 {
+    pub authority: Pubkey,
+    pub min_stake: u64,
+    pub min_lock_duration: i64,
+    pub fraud_slash_bps: u16,
+    pub dispute_slash_bps: u16,
+    pub treasury: Pubkey,
+    pub bump: u8,
 }
 
 impl StakingConfig {
@@ -197,9 +550,385 @@ impl StakingConfig {
         2 +  // fraud_slash_bps
         2 +  // dispute_slash_bps
         32 + // treasury
+        1 +  // bump
+        Self::V1_EXTENSION_LEN; // version and everything added with it
+
+    /// Layout with `version` and the reward, slash appeal, receipt, tier and
+    /// governance settings after `bump`
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Bytes the version and its fields add to configs created before them
+    ///
+    /// The layout has no variable-length fields, so an older config is
+    /// simply too short to deserialize until it is migrated.
+    pub const V1_EXTENSION_LEN: usize = 1 + // version
+        4 * AccessTier::COUNT + // dispute_head_start_secs
+        4 * AccessTier::COUNT + // dispute_sla_secs
+        8 +  // reward_rate
+        16 + // acc_reward_per_share
+        8 +  // last_reward_update
+        8 +  // total_staked
+        8 +  // reward_reserve
+        32 + // slash_review_multisig
+        8 +  // slash_appeal_window
+        32 + // receipt_mint
+        8 +  // receipt_supply
+        TierSchedule::LEN + // tier_schedule
+        32;  // governance_multisig
+
+    /// A pre-version config in the current layout, with every setting added
+    /// since at its `initialize_staking_config` default
+    ///
+    /// `total_staked` starts at zero; each staking account adds its stake
+    /// back as it is migrated.
+    pub fn from_v0(legacy: StakingConfigV0, now: i64) -> Self {
+        Self {
+            authority: legacy.authority,
+            min_stake: legacy.min_stake,
+            min_lock_duration: legacy.min_lock_duration,
+            fraud_slash_bps: legacy.fraud_slash_bps,
+            dispute_slash_bps: legacy.dispute_slash_bps,
+            treasury: legacy.treasury,
+            bump: legacy.bump,
+            version: Self::CURRENT_VERSION,
+            dispute_head_start_secs: Self::DEFAULT_DISPUTE_HEAD_START_SECS,
+            dispute_sla_secs: Self::DEFAULT_DISPUTE_SLA_SECS,
+            reward_rate: 0,
+            acc_reward_per_share: 0,
+            last_reward_update: now,
+            total_staked: 0,
+            reward_reserve: 0,
+            slash_review_multisig: Pubkey::default(),
+            slash_appeal_window: Self::DEFAULT_SLASH_APPEAL_WINDOW,
+            receipt_mint: Pubkey::default(),
+            receipt_supply: 0,
+            tier_schedule: TierSchedule::default(),
+            governance_multisig: Pubkey::default(),
+        }
+    }
+
+    /// Fixed-point scale of `acc_reward_per_share`
+    pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+    /// Default slash appeal window (7 days)
+    pub const DEFAULT_SLASH_APPEAL_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+    /// Longest slash appeal window (30 days)
+    pub const MAX_SLASH_APPEAL_WINDOW: i64 = 30 * 24 * 60 * 60;
+
+    /// None, Basic, Verified, Pro, Whale: 0, 1h, 6h, 24h, 48h
+    pub const DEFAULT_DISPUTE_HEAD_START_SECS: [u32; AccessTier::COUNT] =
+        [0, 3_600, 21_600, 86_400, 172_800];
+
+    /// None, Basic, Verified, Pro, Whale: 7d, 5d, 3d, 2d, 1d
+    pub const DEFAULT_DISPUTE_SLA_SECS: [u32; AccessTier::COUNT] =
+        [604_800, 432_000, 259_200, 172_800, 86_400];
+
+    /// Longest head start any tier may be given (30 days)
+    pub const MAX_DISPUTE_HEAD_START_SECS: u32 = 30 * 24 * 60 * 60;
+
+    /// Require that higher tiers never get a later queue position or a
+    /// slower SLA than lower ones
+    pub fn validate_dispute_priority(
+        head_start_secs: &[u32; AccessTier::COUNT],
+        sla_secs: &[u32; AccessTier::COUNT],
+    ) -> Result<()> {
+        require!(
+            head_start_secs.windows(2).all(|w| w[0] <= w[1])
+                && head_start_secs[AccessTier::COUNT - 1] <= Self::MAX_DISPUTE_HEAD_START_SECS,
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        require!(
+            sla_secs.windows(2).all(|w| w[0] >= w[1]) && sla_secs.iter().all(|&s| s > 0),
+            crate::GhostSpeakError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Release rewards for the time elapsed since the last update
+    ///
+    /// Nothing is released while nobody is staked, and releases stop once
+    /// the funded reserve runs dry.
+    pub fn accrue_rewards(&mut self, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_reward_update);
+        if elapsed <= 0 {
+            return Ok(());
+        }
+        self.last_reward_update = now;
+        if self.total_staked == 0 || self.reward_rate == 0 {
+            return Ok(());
+        }
+
+        let released = (self.reward_rate as u128)
+            .saturating_mul(elapsed as u128)
+            .min(self.reward_reserve as u128);
+        self.acc_reward_per_share = released
+            .checked_mul(Self::REWARD_PRECISION)
+            .map(|scaled| scaled / self.total_staked as u128)
+            .and_then(|increment| self.acc_reward_per_share.checked_add(increment))
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        self.reward_reserve -= released as u64;
+        Ok(())
+    }
+
+    /// Change a staker's `amount_staked`, settling their rewards at the old
+    /// amount first so every deposit only earns from the time it was made
+    pub fn update_stake(
+        &mut self,
+        staking: &mut StakingAccount,
+        new_amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        self.accrue_rewards(now)?;
+        staking.settle_rewards(self.acc_reward_per_share)?;
+
+        self.total_staked = self
+            .total_staked
+            .saturating_sub(staking.amount_staked)
+            .checked_add(new_amount)
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        staking.amount_staked = new_amount;
+        staking.reward_debt = staking.accumulated_rewards(self.acc_reward_per_share)?;
+        Ok(())
+    }
+
+    /// Stake left after withdrawing `amount` from `amount_staked`
+    ///
+    /// A partial unstake must leave at least `min_stake` behind; withdraw
+    /// everything to exit completely.
+    pub fn remaining_after_unstake(&self, amount_staked: u64, amount: u64) -> Result<u64> {
+        require!(
+            amount > 0 && amount <= amount_staked,
+            crate::GhostSpeakError::InvalidAmount
+        );
+        let remaining = amount_staked - amount;
+        require!(
+            remaining == 0 || remaining >= self.min_stake,
+            crate::GhostSpeakError::StakeResidualBelowMinimum
+        );
+        Ok(remaining)
+    }
+
+    /// Apply a governance update of the staking parameters
+    pub fn apply_params(&mut self, params: &StakingParams) -> Result<()> {
+        params.validate()?;
+        self.min_stake = params.min_stake;
+        self.fraud_slash_bps = params.fraud_slash_bps;
+        self.dispute_slash_bps = params.dispute_slash_bps;
+        self.tier_schedule = params.tier_schedule;
+        Ok(())
+    }
+
+    /// Back `amount` more of a stake with receipt tokens
+    ///
+    /// Receipts are minted one-for-one against stake that isn't already
+    /// receipted, so supply never exceeds the stake behind it.
+    pub fn mint_receipt(&mut self, staking: &mut StakingAccount, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= staking.free_stake(),
+            crate::GhostSpeakError::InvalidAmount
+        );
+        staking.receipt_amount += amount;
+        self.receipt_supply = self
+            .receipt_supply
+            .checked_add(amount)
+            .ok_or(crate::GhostSpeakError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Release `amount` of a stake from its burned receipt tokens
+    pub fn burn_receipt(&mut self, staking: &mut StakingAccount, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= staking.receipt_amount,
+            crate::GhostSpeakError::InvalidAmount
+        );
+        staking.receipt_amount -= amount;
+        self.receipt_supply = self.receipt_supply.saturating_sub(amount);
+        Ok(())
+    }
+
+    /// Queue ordering key for a dispute filed at `filed_at` (lower is served first)
+    pub fn dispute_priority_key(&self, tier: AccessTier, filed_at: i64) -> i64 {
+        filed_at - self.dispute_head_start_secs[tier.index()] as i64
+    }
+
+    /// Time by which a dispute filed at `filed_at` should be resolved
+    pub fn dispute_sla_deadline(&self, tier: AccessTier, filed_at: i64) -> i64 {
+        filed_at + self.dispute_sla_secs[tier.index()] as i64
+    }
+
+    /// Calculate the amount a slash would remove from `amount_staked`
+    ///
+    /// Shared by `slash_stake` and `simulate_slash_stake` so the preview
+    /// always matches the real outcome.
+    pub fn calculate_slash_amount(
+        &self,
+        amount_staked: u64,
+        reason: SlashReason,
+        custom_amount: Option<u64>,
+    ) -> Result<u64> {
+        let slash_bps = match reason {
+            SlashReason::Fraud => self.fraud_slash_bps,
+            SlashReason::DisputeLoss => self.dispute_slash_bps,
+            SlashReason::Custom => {
+                require!(custom_amount.is_some(), crate::GhostSpeakError::InvalidInput);
+                0 // Will use custom_amount
+            }
+        };
+
+        let slash_amount = if let Some(custom) = custom_amount {
+            custom
+        } else {
+            (amount_staked as u128 * slash_bps as u128 / 10000) as u64
+        };
+
+        require!(slash_amount <= amount_staked, crate::GhostSpeakError::InvalidAmount);
+
+        Ok(slash_amount)
+    }
+}
+
+/// Review state of a slash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlashStatus {
+    /// Within the appeal window, not contested
+    Pending,
+    /// Contested by the owner, awaiting the review multisig
+    Appealed,
+    /// Review multisig ruled on the appeal
+    Reviewed,
+}
+
+/// Slash Record - slashed tokens held in escrow while they can be appealed
+///
+/// Seeds: ["slash_record", owner]
+///
+/// The slashed tokens sit in the `slash_escrow` token account until the
+/// slash is finalized, so a reversed slash can be refunded in full. One open
+/// slash per owner.
+#[account]
+pub struct SlashRecord {
+    /// Owner whose stake was slashed
+    pub owner: Pubkey,
+    /// Staking authority that slashed (paid the rent, refunded on close)
+    pub slasher: Pubkey,
+    pub reason: SlashReason,
+    /// Tokens escrowed by the slash
+    pub amount: u64,
+    /// Tokens returned to the owner's stake on appeal
+    pub refunded: u64,
+    pub slashed_at: i64,
+    /// Last moment the owner may appeal
+    pub appeal_deadline: i64,
+    pub status: SlashStatus,
+    /// Owner's evidence (IPFS/Arweave URI), empty until appealed
+    pub appeal_uri: String,
+    pub bump: u8,
+}
+
+impl SlashRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // slasher
+        1 +  // reason
+        8 +  // amount
+        8 +  // refunded
+        8 +  // slashed_at
+        8 +  // appeal_deadline
+        1 +  // status
+        4 + MAX_SLASH_APPEAL_URI + // appeal_uri
+        1;   // bump
+
+    /// Contest a pending slash before its deadline
+    pub fn appeal(&mut self, appeal_uri: String, now: i64) -> Result<()> {
+        require!(
+            self.status == SlashStatus::Pending,
+            crate::GhostSpeakError::SlashNotAppealable
+        );
+        require!(
+            now <= self.appeal_deadline,
+            crate::GhostSpeakError::SlashAppealWindowClosed
+        );
+        require!(
+            appeal_uri.len() <= MAX_SLASH_APPEAL_URI,
+            crate::GhostSpeakError::InvalidMetadataUri
+        );
+        self.appeal_uri = appeal_uri;
+        self.status = SlashStatus::Appealed;
+        Ok(())
+    }
+
+    /// Rule on an appeal, returning `refund_amount` of the slash to the owner
+    pub fn review(&mut self, refund_amount: u64) -> Result<()> {
+        require!(
+            self.status == SlashStatus::Appealed,
+            crate::GhostSpeakError::SlashNotAppealable
+        );
+        require!(
+            refund_amount <= self.amount,
+            crate::GhostSpeakError::InvalidAmount
+        );
+        self.refunded = refund_amount;
+        self.status = SlashStatus::Reviewed;
+        Ok(())
+    }
+
+    /// Tokens that go to the insurance pool once the slash is final
+    pub fn forfeited(&self) -> u64 {
+        self.amount - self.refunded
+    }
+
+    /// Whether the slash can be finalized: reviewed, or never appealed
+    /// before the window closed
+    pub fn is_final(&self, now: i64) -> bool {
+        match self.status {
+            SlashStatus::Reviewed => true,
+            SlashStatus::Pending => now > self.appeal_deadline,
+            SlashStatus::Appealed => false,
+        }
+    }
+}
+
+/// Stake Migration - an owner's offer to move their stake to a new key
+///
+/// Seeds: ["stake_migration", owner]
+///
+/// The new owner accepts by signing, which moves the staking account to
+/// their key with its lock, tier and reward history intact.
+#[account]
+pub struct StakeMigration {
+    /// Current owner of the stake
+    pub owner: Pubkey,
+    /// Key that may accept the stake
+    pub new_owner: Pubkey,
+    pub proposed_at: i64,
+    /// Offer lapses after this time
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl StakeMigration {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // new_owner
+        8 +  // proposed_at
+        8 +  // expires_at
         1;   // bump
 }
 
+/// Projected effect of a slash, returned by `simulate_slash_stake`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SlashPreview {
+    pub owner: Pubkey,
+    pub reason: SlashReason,
+    pub slash_amount: u64,
+    pub remaining_stake: u64,
+    pub total_slashed_after: u64,
+    pub old_tier: AccessTier,
+    pub new_tier: AccessTier,
+    pub new_reputation_boost_bps: u16,
+    pub new_voting_power: u64,
+}
+
 /// Access tiers based on GHOST token stake amount
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum AccessTier {
@@ -216,6 +945,181 @@ pub enum AccessTier {
     Whale,
 }
 
+impl AccessTier {
+    /// Number of tiers, for per-tier parameter tables
+    pub const COUNT: usize = 5;
+
+    /// Position of this tier in per-tier parameter tables
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Whether this tier is `min` or higher
+    pub fn meets(self, min: AccessTier) -> bool {
+        self.index() >= min.index()
+    }
+
+    /// The tier above this one, if any
+    pub fn next(self) -> Option<AccessTier> {
+        match self {
+            AccessTier::None => Some(AccessTier::Basic),
+            AccessTier::Basic => Some(AccessTier::Verified),
+            AccessTier::Verified => Some(AccessTier::Pro),
+            AccessTier::Pro => Some(AccessTier::Whale),
+            AccessTier::Whale => None,
+        }
+    }
+}
+
+/// Stake thresholds and lock multipliers that set tiers and voting power
+///
+/// Stored on `StakingConfig` and changed only through a timelocked
+/// governance update. Existing stakes pick up new values the next time
+/// their tier is recalculated.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TierSchedule {
+    /// Minimum stake per tier, indexed by `AccessTier` (None is always 0)
+    pub tier_thresholds: [u64; AccessTier::COUNT],
+    /// Voting power multiplier per lock step in basis points, indexed like
+    /// `LOCK_STEP_DURATIONS`
+    pub lock_multipliers_bps: [u16; TierSchedule::LOCK_STEPS],
+}
+
+impl TierSchedule {
+    /// Number of lock duration steps
+    pub const LOCK_STEPS: usize = 6;
+
+    /// Lock durations at which each multiplier step starts
+    pub const LOCK_STEP_DURATIONS: [i64; Self::LOCK_STEPS] = [
+        LOCKUP_TIER_NONE,
+        LOCKUP_TIER_1_MONTH,
+        LOCKUP_TIER_3_MONTHS,
+        LOCKUP_TIER_6_MONTHS,
+        LOCKUP_TIER_1_YEAR,
+        LOCKUP_TIER_2_YEARS,
+    ];
+
+    pub const LEN: usize = 8 * AccessTier::COUNT + // tier_thresholds
+        2 * Self::LOCK_STEPS; // lock_multipliers_bps
+
+    /// Highest tier whose threshold `amount` reaches
+    pub fn tier_for(&self, amount: u64) -> AccessTier {
+        [
+            AccessTier::Whale,
+            AccessTier::Pro,
+            AccessTier::Verified,
+            AccessTier::Basic,
+        ]
+        .into_iter()
+        .find(|tier| amount >= self.tier_thresholds[tier.index()])
+        .unwrap_or(AccessTier::None)
+    }
+
+    /// Multiplier of the longest lock step `lock_duration` reaches
+    pub fn lock_multiplier_bps(&self, lock_duration: i64) -> u16 {
+        Self::LOCK_STEP_DURATIONS
+            .iter()
+            .rposition(|&step| lock_duration >= step)
+            .map_or(LOCKUP_MULTIPLIER_NONE, |step| self.lock_multipliers_bps[step])
+    }
+
+    /// Require strictly rising tier thresholds and lock multipliers that
+    /// never drop below 1.0x or fall as the lock grows
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.tier_thresholds[AccessTier::None.index()] == 0
+                && self.tier_thresholds.windows(2).all(|w| w[0] < w[1]),
+            crate::GhostSpeakError::InvalidStakingParams
+        );
+        require!(
+            self.lock_multipliers_bps[0] >= LOCKUP_MULTIPLIER_NONE
+                && self.lock_multipliers_bps.windows(2).all(|w| w[0] <= w[1]),
+            crate::GhostSpeakError::InvalidStakingParams
+        );
+        Ok(())
+    }
+}
+
+impl Default for TierSchedule {
+    fn default() -> Self {
+        Self {
+            tier_thresholds: [
+                0,
+                1_000_000_000,   // 1K GHOST (6 decimals) - Basic (Sybil minimum)
+                5_000_000_000,   // 5K GHOST - Verified
+                50_000_000_000,  // 50K GHOST - Pro
+                500_000_000_000, // 500K GHOST - Whale
+            ],
+            lock_multipliers_bps: [
+                LOCKUP_MULTIPLIER_NONE,
+                LOCKUP_MULTIPLIER_1_MONTH,
+                LOCKUP_MULTIPLIER_3_MONTHS,
+                LOCKUP_MULTIPLIER_6_MONTHS,
+                LOCKUP_MULTIPLIER_1_YEAR,
+                LOCKUP_MULTIPLIER_2_YEARS,
+            ],
+        }
+    }
+}
+
+/// Staking parameters changed together by a governance update
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakingParams {
+    /// Minimum stake amount
+    pub min_stake: u64,
+    /// Slash percentage for fraud in basis points
+    pub fraud_slash_bps: u16,
+    /// Slash percentage for dispute loss in basis points
+    pub dispute_slash_bps: u16,
+    pub tier_schedule: TierSchedule,
+}
+
+impl StakingParams {
+    pub const LEN: usize = 8 + // min_stake
+        2 + // fraud_slash_bps
+        2 + // dispute_slash_bps
+        TierSchedule::LEN; // tier_schedule
+
+    /// Require a nonzero minimum stake, slashes of at most 100% and a valid
+    /// tier schedule
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_stake > 0 && self.fraud_slash_bps <= 10_000 && self.dispute_slash_bps <= 10_000,
+            crate::GhostSpeakError::InvalidStakingParams
+        );
+        self.tier_schedule.validate()
+    }
+}
+
+/// Staking Config Proposal - a timelocked governance update of the staking config
+///
+/// Seeds: ["staking_config_proposal"]
+///
+/// Approved by the staking governance multisig; anyone can apply it once
+/// the multisig's timelock has passed. One pending proposal at a time.
+#[account]
+pub struct StakingConfigProposal {
+    /// Governance multisig that approved the update
+    pub multisig: Pubkey,
+    /// Paid the rent, refunded on close
+    pub proposer: Pubkey,
+    pub params: StakingParams,
+    pub proposed_at: i64,
+    /// Earliest time the update can be applied
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl StakingConfigProposal {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        32 + // proposer
+        StakingParams::LEN + // params
+        8 +  // proposed_at
+        8 +  // executable_at
+        1;   // bump
+}
+
 /// Reasons for slashing staked tokens
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SlashReason {
@@ -231,6 +1135,14 @@ pub enum SlashReason {
 // STAKING EVENTS
 // =====================================================
 
+#[event]
+pub struct DisputePriorityUpdatedEvent {
+    pub authority: Pubkey,
+    pub head_start_secs: [u32; AccessTier::COUNT],
+    pub sla_secs: [u32; AccessTier::COUNT],
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct GhostStakedEvent {
     pub agent: Pubkey,
@@ -242,10 +1154,42 @@ pub struct GhostStakedEvent {
     pub voting_power: u64,
 }
 
+#[event]
+pub struct StakeLockExtendedEvent {
+    pub owner: Pubkey,
+    pub old_lock_duration: i64,
+    pub lock_duration: i64,
+    pub unlock_at: i64,
+    pub lock_multiplier_bps: u16,
+    pub voting_power: u64,
+}
+
 #[event]
 pub struct GhostUnstakedEvent {
     pub agent: Pubkey,
     pub amount: u64,
+    pub remaining_stake: u64,
+}
+
+#[event]
+pub struct StakingAccountClosedEvent {
+    pub owner: Pubkey,
+    pub rewards_claimed: u64,
+    pub total_slashed: u64,
+}
+
+#[event]
+pub struct StakingConfigMigratedEvent {
+    pub version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakingAccountMigratedEvent {
+    pub owner: Pubkey,
+    pub amount_staked: u64,
+    pub voting_power: u64,
+    pub version: u8,
 }
 
 #[event]
@@ -254,6 +1198,171 @@ pub struct GhostSlashedEvent {
     pub amount: u64,
     pub reason: SlashReason,
     pub new_tier: AccessTier,
+    pub appeal_deadline: i64,
+}
+
+#[event]
+pub struct StakingRewardsFundedEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub reward_reserve: u64,
+}
+
+#[event]
+pub struct StakingRewardRateUpdatedEvent {
+    pub authority: Pubkey,
+    pub old_rate: u64,
+    pub new_rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakingRewardsClaimedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub rewards_claimed: u64,
+}
+
+#[event]
+pub struct SlashAppealedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub appeal_uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlashAppealReviewedEvent {
+    pub owner: Pubkey,
+    pub multisig: Pubkey,
+    pub amount: u64,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlashFinalizedEvent {
+    pub owner: Pubkey,
+    pub forfeited: u64,
+    pub refunded: u64,
+    pub insurance_vault: Pubkey,
+}
+
+#[event]
+pub struct SlashAppealConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub review_multisig: Pubkey,
+    pub appeal_window: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakingRewardsCompoundedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub amount_staked: u64,
+    pub tier: AccessTier,
+    pub voting_power: u64,
+}
+
+#[event]
+pub struct StakeMigrationProposedEvent {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct StakeMigratedEvent {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub amount_staked: u64,
+    pub unlock_at: i64,
+    pub tier: AccessTier,
+}
+
+#[event]
+pub struct StakeMigrationCancelledEvent {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct StakingGovernanceSetEvent {
+    pub authority: Pubkey,
+    pub governance_multisig: Pubkey,
+}
+
+#[event]
+pub struct StakingConfigUpdateProposedEvent {
+    pub multisig: Pubkey,
+    pub params: StakingParams,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct StakingConfigUpdatedEvent {
+    pub multisig: Pubkey,
+    pub params: StakingParams,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakingConfigUpdateCancelledEvent {
+    pub multisig: Pubkey,
+    pub params: StakingParams,
+}
+
+#[event]
+pub struct StakeReceiptMintInitializedEvent {
+    pub authority: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct StakeReceiptMintedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub receipt_amount: u64,
+    pub receipt_supply: u64,
+}
+
+#[event]
+pub struct StakeReceiptBurnedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub receipt_amount: u64,
+    pub receipt_supply: u64,
+}
+
+/// Staker's tier, quota and distance to the next tier, returned by
+/// `get_access_tier`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct AccessTierStatus {
+    pub owner: Pubkey,
+    pub tier: AccessTier,
+    pub amount_staked: u64,
+    /// Daily API call limit (None = unmetered)
+    pub daily_api_limit: Option<u32>,
+    /// API calls left today, counting a daily reset that is due
+    pub api_calls_available: u32,
+    pub has_unlimited_verifications: bool,
+    pub revenue_multiplier_bps: u16,
+    /// Next tier up (None at Whale)
+    pub next_tier: Option<AccessTier>,
+    /// Additional stake needed to reach `next_tier` under the current schedule
+    pub stake_to_next_tier: u64,
+}
+
+#[event]
+pub struct ApiQuotaConsumedEvent {
+    pub owner: Pubkey,
+    pub tier: AccessTier,
+    pub calls: u32,
+    /// None when the tier is unmetered
+    pub calls_remaining: Option<u32>,
+    pub timestamp: i64,
 }
 
 #[event]
@@ -293,6 +1402,13 @@ mod tests {
             last_quota_reset: 0,
             voting_power: 0,
             bump: 0,
+            version: StakingAccount::CURRENT_VERSION,
+            reward_debt: 0,
+            pending_rewards: 0,
+            rewards_claimed: 0,
+            receipt_amount: 0,
+            bonded_amount: 0,
+            migrated_at: 0,
         }
     }
 
@@ -306,7 +1422,7 @@ mod tests {
 
         // Below minimum stake (< 1,000 GHOST with 6 decimals = 1_000_000_000)
         account.amount_staked = 500_000_000; // 500 GHOST
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::None);
         assert_eq!(account.reputation_boost_bps, 0);
@@ -322,7 +1438,7 @@ mod tests {
 
         // Exactly at Basic minimum (1,000 GHOST with 6 decimals)
         account.amount_staked = 1_000_000_000; // 1K GHOST
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::Basic);
         assert_eq!(account.reputation_boost_bps, 500); // 5% boost
@@ -338,7 +1454,7 @@ mod tests {
 
         // Verified tier (5,000+ GHOST with 6 decimals)
         account.amount_staked = 5_000_000_000; // 5K GHOST
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::Verified);
         assert_eq!(account.reputation_boost_bps, 1000); // 10% boost
@@ -354,7 +1470,7 @@ mod tests {
 
         // Pro tier (50,000+ GHOST with 6 decimals)
         account.amount_staked = 50_000_000_000; // 50K GHOST
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::Pro);
         assert_eq!(account.reputation_boost_bps, 1500); // 15% boost
@@ -370,7 +1486,7 @@ mod tests {
 
         // Whale tier (500,000+ GHOST with 6 decimals)
         account.amount_staked = 500_000_000_000; // 500K GHOST
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::Whale);
         assert_eq!(account.reputation_boost_bps, 2000); // 20% boost
@@ -386,7 +1502,7 @@ mod tests {
 
         // Just below Whale (should be Pro)
         account.amount_staked = 499_999_999_999; // Just under 500K GHOST
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::Pro);
     }
@@ -399,7 +1515,7 @@ mod tests {
     fn test_consume_api_call_basic() {
         let mut account = create_test_staking_account();
         account.amount_staked = 1_000_000_000; // Basic tier
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.api_calls_remaining, 100);
 
@@ -421,7 +1537,7 @@ mod tests {
     fn test_consume_api_call_whale_unlimited() {
         let mut account = create_test_staking_account();
         account.amount_staked = 500_000_000_000; // Whale tier
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.api_calls_remaining, u32::MAX);
 
@@ -432,6 +1548,37 @@ mod tests {
         assert_eq!(account.api_calls_remaining, u32::MAX);
     }
 
+    #[test]
+    fn test_consume_api_calls_metered() {
+        let mut account = create_test_staking_account();
+        account.amount_staked = 5_000_000_000; // Verified tier
+        account.calculate_boost(&TierSchedule::default());
+        assert!(account.has_unlimited_verifications());
+        assert!(!account.has_unlimited_api());
+        assert_eq!(account.get_api_request_limit(), Some(1_000));
+
+        account.consume_api_calls(600, 100).unwrap();
+        assert_eq!(account.api_calls_remaining, 400);
+
+        // Over quota is refused untouched
+        assert!(account.consume_api_calls(401, 200).is_err());
+        assert_eq!(account.api_calls_remaining, 400);
+
+        // A day later the quota refills before metering
+        assert_eq!(account.api_calls_available(86_400), 1_000);
+        account.consume_api_calls(1_000, 86_400).unwrap();
+        assert_eq!(account.api_calls_remaining, 0);
+
+        // Unstaked accounts have no quota; whales are unmetered
+        let mut none = create_test_staking_account();
+        assert!(none.consume_api_calls(1, 100).is_err());
+        none.amount_staked = 500_000_000_000;
+        none.calculate_boost(&TierSchedule::default());
+        assert_eq!(none.get_api_request_limit(), None);
+        none.consume_api_calls(u32::MAX, 100).unwrap();
+        assert_eq!(none.api_calls_remaining, u32::MAX);
+    }
+
     #[test]
     fn test_should_reset_quota() {
         let account = create_test_staking_account();
@@ -462,7 +1609,7 @@ mod tests {
         for (stake, expected_tier, expected_quota) in test_cases {
             let mut account = create_test_staking_account();
             account.amount_staked = stake;
-            account.calculate_boost();
+            account.calculate_boost(&TierSchedule::default());
 
             assert_eq!(account.tier, expected_tier, "Failed for stake {}", stake);
 
@@ -538,7 +1685,7 @@ mod tests {
 
         for amount in test_amounts {
             account.amount_staked = amount;
-            account.calculate_boost();
+            account.calculate_boost(&TierSchedule::default());
             assert_eq!(
                 account.voting_power, amount,
                 "Voting power should equal staked amount"
@@ -546,10 +1693,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extend_lock_raises_voting_power() {
+        let schedule = TierSchedule::default();
+        let mut account = create_test_staking_account();
+        account.amount_staked = 10_000_000_000;
+        account.staked_at = 1_000;
+        account.lock_duration = 30 * 24 * 60 * 60;
+        account.unlock_at = account.staked_at + account.lock_duration;
+        account.calculate_boost(&schedule);
+        assert_eq!(account.voting_power, 11_000_000_000); // 1.1x
+        let tier = account.tier;
+        account.api_calls_remaining = 7;
+
+        // Extending to a year keeps the stake time and lifts the multiplier
+        let one_year = 365 * 24 * 60 * 60;
+        account.extend_lock(one_year, 2_000, &schedule).unwrap();
+        assert_eq!(account.unlock_at, 1_000 + one_year);
+        assert_eq!(account.voting_power, 20_000_000_000); // 2.0x
+        assert_eq!(account.tier, tier);
+        assert_eq!(account.api_calls_remaining, 7);
+
+        // Locks never shorten, and an extension must still be in force
+        assert!(account.extend_lock(one_year, 2_000, &schedule).is_err());
+        assert!(account.extend_lock(30 * 24 * 60 * 60, 2_000, &schedule).is_err());
+        assert!(account
+            .extend_lock(one_year + 1, 1_000 + one_year + 1, &schedule)
+            .is_err());
+    }
+
     // =====================================================
     // ACCOUNT SIZE TESTS
     // =====================================================
 
+    #[test]
+    fn test_migration_keeps_stake_time() {
+        let schedule = TierSchedule::default();
+        let mut account = create_test_staking_account();
+        account.amount_staked = 10_000_000_000;
+        account.staked_at = 1_000;
+        account.lock_duration = 30 * 24 * 60 * 60;
+        account.unlock_at = account.staked_at + account.lock_duration;
+        account.calculate_boost(&schedule);
+
+        let new_owner = Pubkey::new_unique();
+        let migrated = account.migrated_to(new_owner, 7, 5_000);
+        assert_eq!(migrated.owner, new_owner);
+        assert_eq!(migrated.bump, 7);
+        // The original stake time carries over, so the lock keeps its anchor
+        assert_eq!(migrated.staked_at, 1_000);
+        assert_eq!(migrated.migrated_at, 5_000);
+        // Stake counts for snapshots only from the migration onwards
+        assert_eq!(migrated.held_since(), 5_000);
+        assert_eq!(migrated.amount_staked, account.amount_staked);
+        assert_eq!(migrated.unlock_at, account.unlock_at);
+        assert_eq!(migrated.voting_power, account.voting_power);
+        assert_eq!(migrated.tier, account.tier);
+    }
+
     #[test]
     fn test_staking_config_len() {
         let expected_len = 8 +  // discriminator
@@ -559,11 +1760,245 @@ mod tests {
             2 +  // fraud_slash_bps
             2 +  // dispute_slash_bps
             32 + // treasury
-            1;   // bump
+            1 +  // bump
+            1 +  // version
+            20 + // dispute_head_start_secs
+            20 + // dispute_sla_secs
+            8 +  // reward_rate
+            16 + // acc_reward_per_share
+            8 +  // last_reward_update
+            8 +  // total_staked
+            8 +  // reward_reserve
+            32 + // slash_review_multisig
+            8 +  // slash_appeal_window
+            32 + // receipt_mint
+            8 +  // receipt_supply
+            40 + // tier_schedule.tier_thresholds
+            12 + // tier_schedule.lock_multipliers_bps
+            32;  // governance_multisig
 
         assert_eq!(StakingConfig::LEN, expected_len);
     }
 
+    #[test]
+    fn test_calculate_slash_amount() {
+        let config = StakingConfig {
+            authority: Pubkey::new_unique(),
+            min_stake: 1_000_000_000,
+            min_lock_duration: 0,
+            fraud_slash_bps: 5000,
+            dispute_slash_bps: 1000,
+            treasury: Pubkey::new_unique(),
+            bump: 255,
+            version: StakingConfig::CURRENT_VERSION,
+            dispute_head_start_secs: StakingConfig::DEFAULT_DISPUTE_HEAD_START_SECS,
+            dispute_sla_secs: StakingConfig::DEFAULT_DISPUTE_SLA_SECS,
+            reward_rate: 0,
+            acc_reward_per_share: 0,
+            last_reward_update: 0,
+            total_staked: 0,
+            reward_reserve: 0,
+            slash_review_multisig: Pubkey::default(),
+            slash_appeal_window: StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW,
+            receipt_mint: Pubkey::default(),
+            receipt_supply: 0,
+            tier_schedule: TierSchedule::default(),
+            governance_multisig: Pubkey::default(),
+        };
+
+        assert_eq!(
+            config.calculate_slash_amount(10_000, SlashReason::Fraud, None).unwrap(),
+            5_000
+        );
+        assert_eq!(
+            config.calculate_slash_amount(10_000, SlashReason::DisputeLoss, None).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            config.calculate_slash_amount(10_000, SlashReason::Custom, Some(42)).unwrap(),
+            42
+        );
+        assert!(config.calculate_slash_amount(10_000, SlashReason::Custom, None).is_err());
+        assert!(config
+            .calculate_slash_amount(10_000, SlashReason::Custom, Some(10_001))
+            .is_err());
+    }
+
+    #[test]
+    fn test_remaining_after_unstake() {
+        let config = StakingConfig {
+            authority: Pubkey::new_unique(),
+            min_stake: 1_000_000_000,
+            min_lock_duration: 0,
+            fraud_slash_bps: 5000,
+            dispute_slash_bps: 1000,
+            treasury: Pubkey::new_unique(),
+            bump: 255,
+            version: StakingConfig::CURRENT_VERSION,
+            dispute_head_start_secs: StakingConfig::DEFAULT_DISPUTE_HEAD_START_SECS,
+            dispute_sla_secs: StakingConfig::DEFAULT_DISPUTE_SLA_SECS,
+            reward_rate: 0,
+            acc_reward_per_share: 0,
+            last_reward_update: 0,
+            total_staked: 0,
+            reward_reserve: 0,
+            slash_review_multisig: Pubkey::default(),
+            slash_appeal_window: StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW,
+            receipt_mint: Pubkey::default(),
+            receipt_supply: 0,
+            tier_schedule: TierSchedule::default(),
+            governance_multisig: Pubkey::default(),
+        };
+        let staked = 6_000_000_000;
+
+        // Rebalance from Verified down to Basic, or exit completely
+        assert_eq!(
+            config.remaining_after_unstake(staked, 5_000_000_000).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(config.remaining_after_unstake(staked, staked).unwrap(), 0);
+
+        // A dust residual, an empty or an oversized withdrawal is rejected
+        assert!(config.remaining_after_unstake(staked, 5_500_000_000).is_err());
+        assert!(config.remaining_after_unstake(staked, 0).is_err());
+        assert!(config.remaining_after_unstake(staked, staked + 1).is_err());
+    }
+
+    #[test]
+    fn test_stake_receipts() {
+        let mut config = StakingConfig {
+            authority: Pubkey::new_unique(),
+            min_stake: 1_000_000_000,
+            min_lock_duration: 0,
+            fraud_slash_bps: 5000,
+            dispute_slash_bps: 1000,
+            treasury: Pubkey::new_unique(),
+            bump: 255,
+            version: StakingConfig::CURRENT_VERSION,
+            dispute_head_start_secs: StakingConfig::DEFAULT_DISPUTE_HEAD_START_SECS,
+            dispute_sla_secs: StakingConfig::DEFAULT_DISPUTE_SLA_SECS,
+            reward_rate: 0,
+            acc_reward_per_share: 0,
+            last_reward_update: 0,
+            total_staked: 0,
+            reward_reserve: 0,
+            slash_review_multisig: Pubkey::default(),
+            slash_appeal_window: StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW,
+            receipt_mint: Pubkey::new_unique(),
+            receipt_supply: 0,
+            tier_schedule: TierSchedule::default(),
+            governance_multisig: Pubkey::default(),
+        };
+        let mut staking = create_test_staking_account();
+        config.update_stake(&mut staking, 6_000, 0).unwrap();
+
+        // Receipts are capped by the stake behind them
+        config.mint_receipt(&mut staking, 4_000).unwrap();
+        assert!(config.mint_receipt(&mut staking, 2_001).is_err());
+        assert_eq!(staking.unreceipted_stake(), 2_000);
+        assert_eq!(config.receipt_supply, 4_000);
+
+        // After a slash below the receipted amount nothing can be unstaked
+        config.update_stake(&mut staking, 3_000, 0).unwrap();
+        assert_eq!(staking.unreceipted_stake(), 0);
+        assert!(config.mint_receipt(&mut staking, 1).is_err());
+
+        assert!(config.burn_receipt(&mut staking, 4_001).is_err());
+        config.burn_receipt(&mut staking, 2_000).unwrap();
+        assert_eq!(staking.unreceipted_stake(), 1_000);
+        assert_eq!(config.receipt_supply, staking.receipt_amount);
+    }
+
+    #[test]
+    fn test_trial_bonds_reserve_stake() {
+        let mut staking = create_test_staking_account();
+        staking.amount_staked = 6_000;
+        staking.receipt_amount = 1_000;
+
+        // Bonds come out of unreceipted stake, and one stake can't back
+        // more bonds than it holds
+        staking.reserve_bond(3_000).unwrap();
+        staking.reserve_bond(2_000).unwrap();
+        assert!(staking.reserve_bond(1).is_err());
+        assert!(staking.reserve_bond(0).is_err());
+        assert_eq!(staking.free_stake(), 0);
+        assert_eq!(staking.unreceipted_stake(), 5_000);
+
+        staking.release_bond(3_000);
+        assert_eq!(staking.free_stake(), 3_000);
+        staking.release_bond(u64::MAX);
+        assert_eq!(staking.bonded_amount, 0);
+
+        // An open bond keeps the account alive
+        let mut empty = create_test_staking_account();
+        empty.bonded_amount = 1;
+        assert!(!empty.is_closable());
+    }
+
+    #[test]
+    fn test_is_closable() {
+        let mut account = create_test_staking_account();
+        assert!(account.is_closable());
+
+        account.pending_rewards = 1;
+        assert!(!account.is_closable());
+        account.pending_rewards = 0;
+
+        // Receipts outliving a full slash must be burned first
+        account.receipt_amount = 1;
+        assert!(!account.is_closable());
+        account.receipt_amount = 0;
+
+        account.amount_staked = 1;
+        assert!(!account.is_closable());
+    }
+
+    #[test]
+    fn test_dispute_priority() {
+        let head_start = StakingConfig::DEFAULT_DISPUTE_HEAD_START_SECS;
+        let sla = StakingConfig::DEFAULT_DISPUTE_SLA_SECS;
+        assert!(StakingConfig::validate_dispute_priority(&head_start, &sla).is_ok());
+
+        let config = StakingConfig {
+            authority: Pubkey::new_unique(),
+            min_stake: 1_000_000_000,
+            min_lock_duration: 0,
+            fraud_slash_bps: 5000,
+            dispute_slash_bps: 1000,
+            treasury: Pubkey::new_unique(),
+            bump: 255,
+            version: StakingConfig::CURRENT_VERSION,
+            dispute_head_start_secs: head_start,
+            dispute_sla_secs: sla,
+            reward_rate: 0,
+            acc_reward_per_share: 0,
+            last_reward_update: 0,
+            total_staked: 0,
+            reward_reserve: 0,
+            slash_review_multisig: Pubkey::default(),
+            slash_appeal_window: StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW,
+            receipt_mint: Pubkey::default(),
+            receipt_supply: 0,
+            tier_schedule: TierSchedule::default(),
+            governance_multisig: Pubkey::default(),
+        };
+
+        // A Pro dispute filed 12h after an unstaked one is still served first
+        let unstaked = config.dispute_priority_key(AccessTier::None, 0);
+        let pro = config.dispute_priority_key(AccessTier::Pro, 43_200);
+        assert!(pro < unstaked);
+        assert_eq!(config.dispute_sla_deadline(AccessTier::Whale, 100), 86_500);
+
+        // Higher tiers may not be treated worse than lower ones
+        let mut inverted = head_start;
+        inverted.swap(1, 2);
+        assert!(StakingConfig::validate_dispute_priority(&inverted, &sla).is_err());
+        let mut slower = sla;
+        slower[AccessTier::Whale.index()] = 700_000;
+        assert!(StakingConfig::validate_dispute_priority(&head_start, &slower).is_err());
+        assert!(StakingConfig::validate_dispute_priority(&head_start, &[0; 5]).is_err());
+    }
+
     #[test]
     fn test_staking_account_len() {
         let expected_len = 8 +  // discriminator
@@ -580,11 +2015,118 @@ mod tests {
             4 +  // api_calls_remaining
             8 +  // last_quota_reset
             8 +  // voting_power
-            1;   // bump
+            1 +  // bump
+            1 +  // version
+            16 + // reward_debt
+            8 +  // pending_rewards
+            8 +  // rewards_claimed
+            8 +  // receipt_amount
+            8 +  // bonded_amount
+            8;   // migrated_at
 
         assert_eq!(StakingAccount::LEN, expected_len);
     }
 
+    #[test]
+    fn test_slash_appeal_lifecycle() {
+        let mut record = SlashRecord {
+            owner: Pubkey::new_unique(),
+            slasher: Pubkey::new_unique(),
+            reason: SlashReason::DisputeLoss,
+            amount: 1_000,
+            refunded: 0,
+            slashed_at: 0,
+            appeal_deadline: 100,
+            status: SlashStatus::Pending,
+            appeal_uri: String::new(),
+            bump: 255,
+        };
+
+        // Not final while the window is open, and can't be reviewed unappealed
+        assert!(!record.is_final(100));
+        assert!(record.review(0).is_err());
+
+        record.appeal("ipfs://evidence".to_string(), 100).unwrap();
+        assert!(!record.is_final(1_000));
+        assert!(record.appeal("ipfs://again".to_string(), 100).is_err());
+
+        // Reduce the slash to 400
+        assert!(record.review(1_001).is_err());
+        record.review(600).unwrap();
+        assert!(record.is_final(100));
+        assert_eq!(record.forfeited(), 400);
+        assert!(record.review(0).is_err());
+
+        // Unappealed slashes finalize once the window closes
+        let mut late = record.clone();
+        late.status = SlashStatus::Pending;
+        assert!(late.appeal(String::new(), 101).is_err());
+        assert!(late.is_final(101));
+    }
+
+    // =====================================================
+    // REWARD ACCRUAL TESTS
+    // =====================================================
+
+    #[test]
+    fn test_rewards_split_fairly_across_deposits() {
+        let mut config = StakingConfig {
+            authority: Pubkey::new_unique(),
+            min_stake: 1_000_000_000,
+            min_lock_duration: 0,
+            fraud_slash_bps: 5000,
+            dispute_slash_bps: 1000,
+            treasury: Pubkey::new_unique(),
+            bump: 255,
+            version: StakingConfig::CURRENT_VERSION,
+            dispute_head_start_secs: StakingConfig::DEFAULT_DISPUTE_HEAD_START_SECS,
+            dispute_sla_secs: StakingConfig::DEFAULT_DISPUTE_SLA_SECS,
+            reward_rate: 100,
+            acc_reward_per_share: 0,
+            last_reward_update: 0,
+            total_staked: 0,
+            reward_reserve: 25_000,
+            slash_review_multisig: Pubkey::default(),
+            slash_appeal_window: StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW,
+            receipt_mint: Pubkey::default(),
+            receipt_supply: 0,
+            tier_schedule: TierSchedule::default(),
+            governance_multisig: Pubkey::default(),
+        };
+        let mut alice = create_test_staking_account();
+        let mut bob = create_test_staking_account();
+
+        // Nothing is released before anyone stakes
+        config.update_stake(&mut alice, 1_000, 100).unwrap();
+        assert_eq!(config.reward_reserve, 25_000);
+
+        // Alice earns alone for 100s, then Bob joins with three times her stake
+        config.update_stake(&mut bob, 3_000, 200).unwrap();
+        config.accrue_rewards(300).unwrap();
+        alice.settle_rewards(config.acc_reward_per_share).unwrap();
+        bob.settle_rewards(config.acc_reward_per_share).unwrap();
+        assert_eq!(alice.pending_rewards, 10_000 + 2_500);
+        assert_eq!(bob.pending_rewards, 7_500);
+
+        // A late top-up earns nothing for time before it was made
+        config.update_stake(&mut alice, 4_000, 300).unwrap();
+        assert_eq!(alice.pending_rewards, 12_500);
+        assert_eq!(config.total_staked, 7_000);
+
+        // Releases stop once the reserve is exhausted
+        config.accrue_rewards(10_000).unwrap();
+        assert_eq!(config.reward_reserve, 0);
+        alice.settle_rewards(config.acc_reward_per_share).unwrap();
+        bob.settle_rewards(config.acc_reward_per_share).unwrap();
+        assert!(alice.pending_rewards + bob.pending_rewards <= 25_000);
+        assert!(alice.pending_rewards + bob.pending_rewards >= 24_998);
+
+        // Unstaking settles and stops further accrual
+        config.update_stake(&mut bob, 0, 10_000).unwrap();
+        assert_eq!(bob.reward_debt, 0);
+        assert_eq!(config.total_staked, 4_000);
+    }
+
     // =====================================================
     // EDGE CASE TESTS
     // =====================================================
@@ -595,27 +2137,27 @@ mod tests {
 
         // Start at Whale tier
         account.amount_staked = 500_000_000_000;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
         assert_eq!(account.tier, AccessTier::Whale);
 
         // Simulate slash - drop to Pro tier
         account.amount_staked = 100_000_000_000;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
         assert_eq!(account.tier, AccessTier::Pro);
 
         // Drop to Verified
         account.amount_staked = 10_000_000_000;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
         assert_eq!(account.tier, AccessTier::Verified);
 
         // Drop to Basic
         account.amount_staked = 2_000_000_000;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
         assert_eq!(account.tier, AccessTier::Basic);
 
         // Drop below minimum
         account.amount_staked = 500_000_000;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
         assert_eq!(account.tier, AccessTier::None);
         assert_eq!(account.reputation_boost_bps, 0);
         assert!(!account.has_verified_badge);
@@ -627,7 +2169,7 @@ mod tests {
         let mut account = create_test_staking_account();
 
         account.amount_staked = 0;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::None);
         assert_eq!(account.reputation_boost_bps, 0);
@@ -641,13 +2183,55 @@ mod tests {
 
         // Max u64 stake
         account.amount_staked = u64::MAX;
-        account.calculate_boost();
+        account.calculate_boost(&TierSchedule::default());
 
         assert_eq!(account.tier, AccessTier::Whale);
         assert_eq!(account.reputation_boost_bps, 2000); // 20%
         assert_eq!(account.voting_power, u64::MAX);
     }
 
+    #[test]
+    fn test_custom_tier_schedule() {
+        let mut schedule = TierSchedule::default();
+        schedule.tier_thresholds[AccessTier::Basic.index()] = 2_000_000_000;
+        schedule.lock_multipliers_bps[1] = 12_000;
+        schedule.validate().unwrap();
+
+        let mut account = create_test_staking_account();
+        account.amount_staked = 1_500_000_000;
+        account.lock_duration = 30 * 24 * 60 * 60;
+        account.calculate_boost(&schedule);
+        assert_eq!(account.tier, AccessTier::None);
+        assert_eq!(account.voting_power, 1_800_000_000); // 1.2x
+
+        account.amount_staked = 2_000_000_000;
+        account.calculate_boost(&schedule);
+        assert_eq!(account.tier, AccessTier::Basic);
+
+        // Raising a threshold leaves the stored tier stale until the stake
+        // changes; the current tier follows the new schedule at once
+        schedule.tier_thresholds[AccessTier::Basic.index()] = 3_000_000_000;
+        assert_eq!(account.tier, AccessTier::Basic);
+        assert_eq!(account.current_tier(&schedule), AccessTier::None);
+
+        // Thresholds must rise with the tier and multipliers with the lock
+        let mut unordered = TierSchedule::default();
+        unordered.tier_thresholds[AccessTier::Pro.index()] = 5_000_000_000;
+        assert!(unordered.validate().is_err());
+        let mut shrinking = TierSchedule::default();
+        shrinking.lock_multipliers_bps[5] = 15_000;
+        assert!(shrinking.validate().is_err());
+    }
+
+    #[test]
+    fn test_tier_meets_gate() {
+        assert!(AccessTier::None.meets(AccessTier::None));
+        assert!(AccessTier::Pro.meets(AccessTier::Verified));
+        assert!(AccessTier::Pro.meets(AccessTier::Pro));
+        assert!(!AccessTier::Verified.meets(AccessTier::Pro));
+        assert!(!AccessTier::None.meets(AccessTier::Basic));
+    }
+
     #[test]
     fn test_reputation_boost_values() {
         let mut account = create_test_staking_account();
@@ -663,7 +2247,7 @@ mod tests {
 
         for (stake, expected_boost) in test_cases {
             account.amount_staked = stake;
-            account.calculate_boost();
+            account.calculate_boost(&TierSchedule::default());
             assert_eq!(
                 account.reputation_boost_bps, expected_boost,
                 "Boost mismatch for stake {}",
@@ -671,4 +2255,64 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_v0_layouts_migrate() {
+        // 10K GHOST locked for a year, as staked before the layout version
+        let mut account = create_test_staking_account();
+        account.amount_staked = 10_000_000_000;
+        account.lock_duration = LOCKUP_TIER_1_YEAR;
+        account.staked_at = 1_000;
+        account.calculate_boost(&TierSchedule::default());
+        account.voting_power = account.amount_staked;
+        account.bump = 253;
+
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.truncate(StakingAccount::LEN - StakingAccount::V1_EXTENSION_LEN);
+        assert!(StakingAccount::try_deserialize(&mut &data[..]).is_err());
+
+        // It earns only what the accumulator adds after the migration
+        let legacy = StakingAccountV0::deserialize(&mut &data[8..]).unwrap();
+        let acc_reward_per_share = 3 * StakingConfig::REWARD_PRECISION;
+        let migrated =
+            StakingAccount::from_v0(legacy, acc_reward_per_share, &TierSchedule::default())
+                .unwrap();
+        assert_eq!(migrated.version, StakingAccount::CURRENT_VERSION);
+        assert_eq!(migrated.owner, account.owner);
+        assert_eq!(migrated.amount_staked, 10_000_000_000);
+        assert_eq!(migrated.staked_at, 1_000);
+        assert_eq!(migrated.tier, AccessTier::Verified);
+        assert_eq!(migrated.bump, 253);
+        assert_eq!(migrated.reward_debt, 30_000_000_000);
+        assert_eq!(migrated.pending_rewards, 0);
+        assert_eq!(migrated.receipt_amount, 0);
+        assert_eq!(migrated.bonded_amount, 0);
+        assert_eq!(migrated.migrated_at, 0);
+        // Voting power picks up the 2.0x one-year lock multiplier
+        assert_eq!(migrated.voting_power, 20_000_000_000);
+
+        let config = StakingConfig::from_v0(
+            StakingConfigV0 {
+                authority: Pubkey::new_unique(),
+                min_stake: 1_000_000_000,
+                min_lock_duration: 0,
+                fraud_slash_bps: 5000,
+                dispute_slash_bps: 1000,
+                treasury: Pubkey::new_unique(),
+                bump: 252,
+            },
+            7_000,
+        );
+        assert_eq!(config.version, StakingConfig::CURRENT_VERSION);
+        assert_eq!(config.fraud_slash_bps, 5000);
+        assert_eq!(config.bump, 252);
+        assert_eq!(config.total_staked, 0);
+        assert_eq!(config.last_reward_update, 7_000);
+        assert_eq!(config.slash_appeal_window, StakingConfig::DEFAULT_SLASH_APPEAL_WINDOW);
+        assert_eq!(config.governance_multisig, Pubkey::default());
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), StakingConfig::LEN);
+    }
 }
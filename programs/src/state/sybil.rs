@@ -0,0 +1,282 @@
+/*!
+ * Sybil Heuristics State
+ *
+ * Lightweight funder-fingerprint clustering for service ratings. A governed
+ * oracle records the first funder of each rater wallet; raters whose funder
+ * has seeded many wallets form a cluster, and ratings from unreviewed
+ * clusters count at reduced weight until governance reviews the cluster.
+ *
+ * Every parameter lives in the public `SybilConfig` PDA and every decision
+ * is emitted as an event, so the heuristic is fully auditable.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+pub const SYBIL_CONFIG_SEED: &[u8] = b"sybil_config";
+pub const FUNDER_FINGERPRINT_SEED: &[u8] = b"funder_fingerprint";
+pub const FUNDER_CLUSTER_SEED: &[u8] = b"funder_cluster";
+
+/// Governed heuristic parameters
+///
+/// Seeds: ["sybil_config"]
+#[account]
+pub struct SybilConfig {
+    /// Governance authority that tunes parameters and reviews clusters
+    pub authority: Pubkey,
+    /// Oracle allowed to record funder fingerprints
+    pub oracle: Pubkey,
+    /// Whether flagged ratings are down-weighted
+    pub enabled: bool,
+    /// Wallets sharing a funder before its cluster is flagged
+    pub cluster_threshold: u32,
+    /// Weight of a flagged rating in basis points (10000 = full weight)
+    pub flagged_weight_bps: u16,
+    /// Funders never flagged (exchanges, faucets, grant programs)
+    pub exempt_funders: Vec<Pubkey>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl SybilConfig {
+    pub const MAX_EXEMPT_FUNDERS: usize = 16;
+    pub const DEFAULT_CLUSTER_THRESHOLD: u32 = 5;
+    pub const DEFAULT_FLAGGED_WEIGHT_BPS: u16 = 2500;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // oracle
+        1 + // enabled
+        4 + // cluster_threshold
+        2 + // flagged_weight_bps
+        4 + (32 * Self::MAX_EXEMPT_FUNDERS) + // exempt_funders
+        8 + // updated_at
+        1; // bump
+
+    /// Weight (basis points) applied to a rating from a rater funded by `cluster`
+    pub fn rating_weight_bps(&self, cluster: &FunderCluster) -> u16 {
+        if !self.enabled || self.exempt_funders.contains(&cluster.funder) {
+            return 10_000;
+        }
+        match cluster.review {
+            ClusterReview::Cleared => 10_000,
+            ClusterReview::Confirmed => 0,
+            ClusterReview::Unreviewed if cluster.wallet_count >= self.cluster_threshold => {
+                self.flagged_weight_bps
+            }
+            ClusterReview::Unreviewed => 10_000,
+        }
+    }
+
+    pub fn set_exempt(&mut self, funder: Pubkey, exempt: bool) -> Result<()> {
+        let position = self.exempt_funders.iter().position(|f| *f == funder);
+        match (exempt, position) {
+            (true, None) => {
+                require!(
+                    self.exempt_funders.len() < Self::MAX_EXEMPT_FUNDERS,
+                    GhostSpeakError::TooManyExemptFunders
+                );
+                self.exempt_funders.push(funder);
+            }
+            (false, Some(i)) => {
+                self.exempt_funders.remove(i);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// First funder of a rater wallet (immutable once recorded)
+///
+/// Seeds: ["funder_fingerprint", wallet]
+#[account]
+pub struct FunderFingerprint {
+    pub wallet: Pubkey,
+    pub first_funder: Pubkey,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl FunderFingerprint {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // wallet
+        32 + // first_funder
+        8 + // recorded_at
+        1; // bump
+}
+
+/// Governance verdict on a funder cluster
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClusterReview {
+    /// Not yet reviewed; flagged ratings count at reduced weight
+    Unreviewed,
+    /// Reviewed as legitimate; ratings count in full
+    Cleared,
+    /// Reviewed as a sybil cluster; ratings are ignored
+    Confirmed,
+}
+
+/// Wallets sharing one first funder
+///
+/// Seeds: ["funder_cluster", funder]
+#[account]
+pub struct FunderCluster {
+    pub funder: Pubkey,
+    /// Fingerprinted wallets funded by `funder`
+    pub wallet_count: u32,
+    /// Ratings from this cluster applied at reduced (or zero) weight
+    pub flagged_ratings: u64,
+    pub review: ClusterReview,
+    pub reviewed_at: i64,
+    pub bump: u8,
+}
+
+impl FunderCluster {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // funder
+        4 + // wallet_count
+        8 + // flagged_ratings
+        1 + // review
+        8 + // reviewed_at
+        1; // bump
+}
+
+/// Rating (1-5) to record for `rating` submitted at `weight_bps`
+///
+/// The rating is pulled toward the agent's current average (3 without
+/// history) so that it moves the average `weight_bps` as far as a full
+/// rating would. Returns None when the weight is zero.
+pub fn dampened_rating(
+    total_rating: u32,
+    ratings_count: u32,
+    rating: u8,
+    weight_bps: u16,
+) -> Option<u8> {
+    if weight_bps == 0 {
+        return None;
+    }
+    if weight_bps >= 10_000 {
+        return Some(rating);
+    }
+
+    // Hundredths of a star
+    let average = if ratings_count > 0 {
+        total_rating as i64 * 100 / ratings_count as i64
+    } else {
+        300
+    };
+    let target = rating as i64 * 100;
+    let dampened = average + (target - average) * weight_bps as i64 / 10_000;
+
+    Some(((dampened + 50) / 100).clamp(1, 5) as u8)
+}
+
+#[event]
+pub struct SybilConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub oracle: Pubkey,
+    pub enabled: bool,
+    pub cluster_threshold: u32,
+    pub flagged_weight_bps: u16,
+    pub exempt_funders: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FunderFingerprintRecordedEvent {
+    pub wallet: Pubkey,
+    pub first_funder: Pubkey,
+    pub cluster_size: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RatingFlaggedEvent {
+    pub agent: Pubkey,
+    pub rater: Pubkey,
+    pub funder: Pubkey,
+    pub cluster_size: u32,
+    pub rating: u8,
+    /// Rating actually recorded (None if ignored)
+    pub applied_rating: Option<u8>,
+    pub weight_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FunderClusterReviewedEvent {
+    pub funder: Pubkey,
+    pub review: ClusterReview,
+    pub wallet_count: u32,
+    pub flagged_ratings: u64,
+    pub reviewer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster(wallet_count: u32, review: ClusterReview) -> FunderCluster {
+        FunderCluster {
+            funder: Pubkey::new_unique(),
+            wallet_count,
+            flagged_ratings: 0,
+            review,
+            reviewed_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_sybil_rating_weight() {
+        let mut config = SybilConfig {
+            authority: Pubkey::new_unique(),
+            oracle: Pubkey::new_unique(),
+            enabled: true,
+            cluster_threshold: 5,
+            flagged_weight_bps: 2500,
+            exempt_funders: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+
+        let small = cluster(4, ClusterReview::Unreviewed);
+        let large = cluster(5, ClusterReview::Unreviewed);
+        assert_eq!(config.rating_weight_bps(&small), 10_000);
+        assert_eq!(config.rating_weight_bps(&large), 2500);
+        assert_eq!(
+            config.rating_weight_bps(&cluster(50, ClusterReview::Cleared)),
+            10_000
+        );
+        assert_eq!(
+            config.rating_weight_bps(&cluster(1, ClusterReview::Confirmed)),
+            0
+        );
+
+        config.set_exempt(large.funder, true).unwrap();
+        config.set_exempt(large.funder, true).unwrap();
+        assert_eq!(config.exempt_funders.len(), 1);
+        assert_eq!(config.rating_weight_bps(&large), 10_000);
+        config.set_exempt(large.funder, false).unwrap();
+        assert!(config.exempt_funders.is_empty());
+
+        config.enabled = false;
+        assert_eq!(config.rating_weight_bps(&large), 10_000);
+    }
+
+    #[test]
+    fn test_dampened_rating() {
+        // Full and zero weight
+        assert_eq!(dampened_rating(45, 10, 1, 10_000), Some(1));
+        assert_eq!(dampened_rating(45, 10, 1, 0), None);
+
+        // Average 4.5, a 25%-weight 1-star counts as 3.625 -> 4
+        assert_eq!(dampened_rating(45, 10, 1, 2500), Some(4));
+        // Without history the pull is toward 3
+        assert_eq!(dampened_rating(0, 0, 5, 2500), Some(4));
+        assert_eq!(dampened_rating(0, 0, 1, 2500), Some(3));
+    }
+}
@@ -0,0 +1,332 @@
+/*!
+ * Tag Registry State
+ *
+ * Governed taxonomy of reputation tags. Each tag has a canonical ID and
+ * name within one category, plus aliases ("Rust", "rustlang") that resolve
+ * to the canonical name so tag data does not fragment across spellings.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::state::reputation::TagScore;
+use crate::GhostSpeakError;
+
+/// Seed for the tag registry PDA
+pub const TAG_REGISTRY_SEED: &[u8] = b"tag_registry";
+
+/// Category a registered tag belongs to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TagCategory {
+    Skill,
+    Behavior,
+    Compliance,
+}
+
+/// Tag passed to `update_reputation_tags`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum TagRef {
+    /// Canonical registry ID
+    Id(u16),
+    /// Canonical name or alias (free-form when no registry is supplied)
+    Name(String),
+}
+
+/// One canonical tag
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TagDefinition {
+    pub id: u16,
+    pub category: TagCategory,
+    /// Canonical lowercase name stored on reputation metrics
+    pub name: String,
+    /// Alternative spellings that resolve to `name`
+    pub aliases: Vec<String>,
+    /// Deprecated tags can no longer be assigned
+    pub deprecated: bool,
+}
+
+impl TagDefinition {
+    pub const MAX_ALIASES: usize = 3;
+
+    pub const LEN: usize = 2 + // id
+        1 + // category
+        4 + TagScore::MAX_TAG_NAME_LENGTH + // name
+        4 + (Self::MAX_ALIASES * (4 + TagScore::MAX_TAG_NAME_LENGTH)) + // aliases
+        1; // deprecated
+
+    fn answers_to(&self, name: &str) -> bool {
+        self.name == name || self.aliases.iter().any(|a| a == name)
+    }
+}
+
+/// Governed tag taxonomy
+///
+/// Seeds: ["tag_registry"]
+#[account]
+pub struct TagRegistry {
+    /// Governance authority that curates the taxonomy
+    pub authority: Pubkey,
+    /// Maximum number of tags
+    pub capacity: u16,
+    /// ID assigned to the next registered tag
+    pub next_id: u16,
+    pub tags: Vec<TagDefinition>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl TagRegistry {
+    pub const MAX_CAPACITY: u16 = 60;
+
+    pub const fn space(capacity: u16) -> usize {
+        8 + // discriminator
+        32 + // authority
+        2 + // capacity
+        2 + // next_id
+        4 + (capacity as usize * TagDefinition::LEN) + // tags
+        8 + // updated_at
+        1 // bump
+    }
+
+    /// Lowercase and trim a tag name; rejects anything outside [a-z0-9-]
+    pub fn normalize(name: &str) -> Result<String> {
+        let name = name.trim().to_ascii_lowercase();
+        require!(
+            !name.is_empty() && name.len() <= TagScore::MAX_TAG_NAME_LENGTH,
+            GhostSpeakError::TagNameTooLong
+        );
+        require!(
+            name.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'),
+            GhostSpeakError::InvalidInputFormat
+        );
+        Ok(name)
+    }
+
+    pub fn find(&self, id: u16) -> Option<&TagDefinition> {
+        self.tags.iter().find(|t| t.id == id)
+    }
+
+    fn find_by_name(&self, name: &str) -> Option<&TagDefinition> {
+        self.tags.iter().find(|t| t.answers_to(name))
+    }
+
+    /// Normalize `aliases`, rejecting any that collide with another tag
+    fn checked_aliases(&self, id: Option<u16>, aliases: Vec<String>) -> Result<Vec<String>> {
+        require!(
+            aliases.len() <= TagDefinition::MAX_ALIASES,
+            GhostSpeakError::InputTooLong
+        );
+        let mut normalized: Vec<String> = Vec::with_capacity(aliases.len());
+        for alias in aliases {
+            let alias = Self::normalize(&alias)?;
+            let taken = self.find_by_name(&alias).is_some_and(|t| Some(t.id) != id);
+            require!(
+                !taken && !normalized.contains(&alias),
+                GhostSpeakError::DuplicateTag
+            );
+            normalized.push(alias);
+        }
+        Ok(normalized)
+    }
+
+    /// Register a canonical tag and return its ID
+    pub fn register(
+        &mut self,
+        category: TagCategory,
+        name: &str,
+        aliases: Vec<String>,
+        now: i64,
+    ) -> Result<u16> {
+        require!(
+            (self.tags.len() as u16) < self.capacity,
+            GhostSpeakError::TagRegistryFull
+        );
+        let name = Self::normalize(name)?;
+        require!(
+            self.find_by_name(&name).is_none(),
+            GhostSpeakError::DuplicateTag
+        );
+        let aliases = self.checked_aliases(None, aliases)?;
+        require!(!aliases.contains(&name), GhostSpeakError::DuplicateTag);
+
+        let id = self.next_id;
+        self.next_id = id
+            .checked_add(1)
+            .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+        self.tags.push(TagDefinition {
+            id,
+            category,
+            name,
+            aliases,
+            deprecated: false,
+        });
+        self.updated_at = now;
+        Ok(id)
+    }
+
+    /// Replace a tag's aliases and deprecation flag
+    pub fn update(
+        &mut self,
+        id: u16,
+        aliases: Vec<String>,
+        deprecated: bool,
+        now: i64,
+    ) -> Result<&TagDefinition> {
+        let aliases = self.checked_aliases(Some(id), aliases)?;
+        let tag = self
+            .tags
+            .iter_mut()
+            .find(|t| t.id == id)
+            .ok_or(GhostSpeakError::UnknownTag)?;
+        require!(!aliases.contains(&tag.name), GhostSpeakError::DuplicateTag);
+
+        tag.aliases = aliases;
+        tag.deprecated = deprecated;
+        self.updated_at = now;
+        Ok(tag)
+    }
+
+    /// Canonical name for a tag assigned in `category`
+    pub fn resolve(&self, category: TagCategory, tag: &TagRef) -> Result<String> {
+        let definition = self.lookup(tag)?;
+        require!(
+            definition.category == category,
+            GhostSpeakError::TagCategoryMismatch
+        );
+        Ok(definition.name.clone())
+    }
+
+    /// Canonical name for a tag in any category (used for tag scores)
+    pub fn resolve_name(&self, name: &str) -> Result<String> {
+        Ok(self.lookup(&TagRef::Name(name.to_string()))?.name.clone())
+    }
+
+    fn lookup(&self, tag: &TagRef) -> Result<&TagDefinition> {
+        let definition = match tag {
+            TagRef::Id(id) => self.find(*id),
+            TagRef::Name(name) => self.find_by_name(&Self::normalize(name)?),
+        }
+        .ok_or(GhostSpeakError::UnknownTag)?;
+        require!(!definition.deprecated, GhostSpeakError::TagDeprecated);
+        Ok(definition)
+    }
+}
+
+#[event]
+pub struct TagRegisteredEvent {
+    pub id: u16,
+    pub category: TagCategory,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TagUpdatedEvent {
+    pub id: u16,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub deprecated: bool,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> TagRegistry {
+        TagRegistry {
+            authority: Pubkey::new_unique(),
+            capacity: 3,
+            next_id: 0,
+            tags: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_tag_registry_resolution() {
+        let mut registry = registry();
+        let rust = registry
+            .register(
+                TagCategory::Skill,
+                "Rust",
+                vec!["rustlang".to_string(), "rust-lang".to_string()],
+                1,
+            )
+            .unwrap();
+        let kyc = registry
+            .register(TagCategory::Compliance, "kyc-verified", vec![], 1)
+            .unwrap();
+        assert_eq!((rust, kyc), (0, 1));
+
+        // IDs, names and aliases all resolve to the canonical name
+        for tag in [
+            TagRef::Id(rust),
+            TagRef::Name("rust".to_string()),
+            TagRef::Name(" RustLang ".to_string()),
+        ] {
+            assert_eq!(registry.resolve(TagCategory::Skill, &tag).unwrap(), "rust");
+        }
+        assert_eq!(registry.resolve_name("RUST-LANG").unwrap(), "rust");
+
+        assert!(registry
+            .resolve(TagCategory::Skill, &TagRef::Id(kyc))
+            .is_err());
+        assert!(registry
+            .resolve(TagCategory::Skill, &TagRef::Name("golang".to_string()))
+            .is_err());
+        assert!(registry
+            .resolve(TagCategory::Skill, &TagRef::Id(7))
+            .is_err());
+
+        // Deprecated tags no longer resolve
+        registry
+            .update(rust, vec!["rustlang".to_string()], true, 2)
+            .unwrap();
+        assert!(registry
+            .resolve(TagCategory::Skill, &TagRef::Id(rust))
+            .is_err());
+    }
+
+    #[test]
+    fn test_tag_registry_rejects_collisions() {
+        let mut registry = registry();
+        registry
+            .register(TagCategory::Skill, "rust", vec!["rustlang".to_string()], 1)
+            .unwrap();
+
+        // Names and aliases are unique across the registry
+        assert!(registry
+            .register(TagCategory::Behavior, "RUST", vec![], 1)
+            .is_err());
+        assert!(registry
+            .register(TagCategory::Skill, "rustlang", vec![], 1)
+            .is_err());
+        assert!(registry
+            .register(TagCategory::Skill, "go", vec!["rustlang".to_string()], 1)
+            .is_err());
+        assert!(registry
+            .register(TagCategory::Skill, "go", vec!["go".to_string()], 1)
+            .is_err());
+        assert!(registry
+            .register(TagCategory::Skill, "c++", vec![], 1)
+            .is_err());
+
+        // A tag may keep its own aliases on update
+        registry
+            .update(0, vec!["rustlang".to_string()], false, 2)
+            .unwrap();
+
+        registry
+            .register(TagCategory::Skill, "go", vec![], 1)
+            .unwrap();
+        registry
+            .register(TagCategory::Skill, "zig", vec![], 1)
+            .unwrap();
+        assert!(registry
+            .register(TagCategory::Skill, "odin", vec![], 1)
+            .is_err());
+    }
+}
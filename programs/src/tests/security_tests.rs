@@ -8,7 +8,19 @@
 #[cfg(test)]
 mod security_tests {
     use anchor_lang::prelude::*;
-    use crate::{PROTOCOL_ADMIN, validate_admin_authority, MAX_URL_LENGTH};
+    use crate::state::AdminAuthority;
+    use crate::{validate_admin_authority, MAX_URL_LENGTH};
+
+    fn admin_authority() -> AdminAuthority {
+        AdminAuthority {
+            admin: Pubkey::new_unique(),
+            multisig: Pubkey::new_unique(),
+            pending_admin: Pubkey::default(),
+            executable_at: 0,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
 
     // =====================================================
     // 1. ADMIN VALIDATION SECURITY TESTS
@@ -16,10 +28,10 @@ mod security_tests {
 
     #[test]
     fn test_admin_validation_success() {
-        let admin_key = PROTOCOL_ADMIN;
+        let authority = admin_authority();
         
         // Test successful admin validation
-        let result = validate_admin_authority(&admin_key);
+        let result = validate_admin_authority(&authority, &authority.admin);
         assert!(result.is_ok(), "Valid admin key should pass validation");
     }
 
@@ -28,7 +40,7 @@ mod security_tests {
         let unauthorized_key = Pubkey::new_unique();
         
         // Test unauthorized key rejection
-        let result = validate_admin_authority(&unauthorized_key);
+        let result = validate_admin_authority(&admin_authority(), &unauthorized_key);
         assert!(result.is_err(), "Unauthorized key should fail validation");
     }
 
@@ -37,7 +49,7 @@ mod security_tests {
         let default_key = Pubkey::default();
         
         // Test default/null key rejection
-        let result = validate_admin_authority(&default_key);
+        let result = validate_admin_authority(&admin_authority(), &default_key);
         assert!(result.is_err(), "Default key should fail validation");
     }
 
@@ -328,13 +340,13 @@ mod security_tests {
     #[test]
     fn test_security_integration_scenario() {
         // Test a complete security scenario combining multiple checks
-        let admin = PROTOCOL_ADMIN;
+        let authority = admin_authority();
         let user = Pubkey::new_unique();
         let agent_id = "secure_agent_123";
         let metadata_uri = "https://secure-metadata.com/agent.json";
         
         // 1. Admin validation
-        let admin_check = validate_admin_authority(&admin);
+        let admin_check = validate_admin_authority(&authority, &authority.admin);
         assert!(admin_check.is_ok(), "Admin validation should pass");
         
         // 2. Input validation
@@ -367,8 +379,9 @@ mod security_tests {
         let start = std::time::Instant::now();
         
         // Perform 1000 admin validations
+        let authority = admin_authority();
         for _ in 0..1000 {
-            let _ = validate_admin_authority(&PROTOCOL_ADMIN);
+            let _ = validate_admin_authority(&authority, &authority.admin);
         }
         
         let duration = start.elapsed();
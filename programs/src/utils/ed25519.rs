@@ -0,0 +1,162 @@
+/*!
+ * Ed25519 Signature Introspection
+ *
+ * Solana programs cannot verify Ed25519 signatures cheaply themselves.
+ * Instead the client places an Ed25519 program instruction in the same
+ * transaction, and the runtime rejects the whole transaction if that
+ * signature is invalid. These helpers read the instructions sysvar to
+ * confirm such an instruction exists and covers the expected signer and
+ * message.
+ */
+
+use crate::GhostSpeakError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+/// Native Ed25519 signature verification program
+const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Size of the count + padding header
+const HEADER_LEN: usize = 2;
+/// Size of one signature offsets record
+const OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+
+/// Marks data that lives inside the Ed25519 instruction itself
+const THIS_INSTRUCTION: u16 = u16::MAX;
+
+/// Signer and message of a single-signature Ed25519 instruction
+///
+/// Returns None unless the instruction carries exactly one signature whose
+/// key, signature and message are all stored in its own data.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    if data.len() < HEADER_LEN + OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+
+    let field = |i: usize| {
+        let at = HEADER_LEN + i * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let signature_offset = field(0) as usize;
+    let signature_ix = field(1);
+    let pubkey_offset = field(2) as usize;
+    let pubkey_ix = field(3);
+    let message_offset = field(4) as usize;
+    let message_len = field(5) as usize;
+    let message_ix = field(6);
+
+    if [signature_ix, pubkey_ix, message_ix]
+        .iter()
+        .any(|&ix| ix != THIS_INSTRUCTION)
+    {
+        return None;
+    }
+    data.get(signature_offset..signature_offset + SIGNATURE_LEN)?;
+
+    let pubkey = data.get(pubkey_offset..pubkey_offset + PUBKEY_LEN)?;
+    let message = data.get(message_offset..message_offset + message_len)?;
+    Some((Pubkey::try_from(pubkey).ok()?, message))
+}
+
+/// Signature bytes of an instruction accepted by `parse_ed25519_instruction`
+fn ed25519_signature(data: &[u8]) -> Option<[u8; SIGNATURE_LEN]> {
+    let offset = u16::from_le_bytes(data.get(HEADER_LEN..HEADER_LEN + 2)?.try_into().ok()?) as usize;
+    data.get(offset..offset + SIGNATURE_LEN)?.try_into().ok()
+}
+
+/// Require that the instruction just before the current one is an Ed25519
+/// verification of `message` signed by `signer`
+pub fn require_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    verified_ed25519_signature(instructions_sysvar, signer, message).map(|_| ())
+}
+
+/// Like `require_ed25519_signature`, returning the verified signature so it
+/// can be stored as provenance
+pub fn verified_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; SIGNATURE_LEN]> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    require!(current > 0, GhostSpeakError::MissingSignatureInstruction);
+
+    let ix = load_instruction_at_checked(current as usize - 1, instructions_sysvar)?;
+    require!(
+        ix.program_id == ED25519_PROGRAM_ID,
+        GhostSpeakError::MissingSignatureInstruction
+    );
+
+    let (ix_signer, ix_message) =
+        parse_ed25519_instruction(&ix.data).ok_or(GhostSpeakError::MissingSignatureInstruction)?;
+    require!(ix_signer == *signer, GhostSpeakError::InvalidSignature);
+    require!(ix_message == message, GhostSpeakError::InvalidSignature);
+
+    ed25519_signature(&ix.data).ok_or_else(|| GhostSpeakError::MissingSignatureInstruction.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same layout as solana_sdk::ed25519_instruction::new_ed25519_instruction
+    fn ed25519_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let pubkey_offset = (HEADER_LEN + OFFSETS_LEN) as u16;
+        let signature_offset = pubkey_offset + PUBKEY_LEN as u16;
+        let message_offset = signature_offset + SIGNATURE_LEN as u16;
+
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            THIS_INSTRUCTION,
+            pubkey_offset,
+            THIS_INSTRUCTION,
+            message_offset,
+            message.len() as u16,
+            THIS_INSTRUCTION,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7u8; SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"attested");
+        assert_eq!(
+            parse_ed25519_instruction(&data),
+            Some((signer, &b"attested"[..]))
+        );
+
+        // Multiple signatures are not accepted
+        let mut multi = data.clone();
+        multi[0] = 2;
+        assert_eq!(parse_ed25519_instruction(&multi), None);
+
+        // Data pointing at another instruction is not accepted
+        let mut foreign = data.clone();
+        foreign[HEADER_LEN + 6..HEADER_LEN + 8].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(parse_ed25519_instruction(&foreign), None);
+
+        // Truncated message
+        assert_eq!(parse_ed25519_instruction(&data[..data.len() - 1]), None);
+    }
+
+    #[test]
+    fn test_ed25519_signature_bytes() {
+        let data = ed25519_data(&Pubkey::new_unique(), b"attested");
+        assert_eq!(ed25519_signature(&data), Some([7u8; SIGNATURE_LEN]));
+        assert_eq!(ed25519_signature(&data[..HEADER_LEN]), None);
+    }
+}
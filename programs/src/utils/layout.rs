@@ -0,0 +1,72 @@
+/*!
+ * Account Layout Migrations
+ *
+ * Accounts that gained fields after deployment keep their old fields in
+ * place, append the new ones after `bump` behind a `version` byte, and are
+ * grown in place by a permissionless migrate instruction. Old accounts are
+ * parsed with a copy of the layout they were written under, never with the
+ * current one, since bytes past their old end may be stale.
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::GhostSpeakError;
+
+/// Read an account of type `T` written under the legacy layout `L`
+///
+/// The account must belong to this program, carry `T`'s discriminator and
+/// be shorter than `len`, the size of `T`'s current layout.
+pub fn read_legacy_layout<T: Discriminator, L: AnchorDeserialize>(
+    info: &AccountInfo,
+    len: usize,
+) -> Result<L> {
+    require!(
+        info.owner == &crate::ID,
+        GhostSpeakError::InvalidAccountOwner
+    );
+    require!(
+        info.data_len() < len,
+        GhostSpeakError::AccountLayoutCurrent
+    );
+
+    let data = info.try_borrow_data()?;
+    require!(
+        data.starts_with(T::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(L::deserialize(&mut &data[T::DISCRIMINATOR.len()..])?)
+}
+
+/// Grow an account to `len`, topping its rent up from `payer`, and write
+/// `account` over it in the current layout
+pub fn write_current_layout<'info, T: AccountSerialize>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+    account: &T,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    info.resize(len)?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    data.fill(0);
+    account.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
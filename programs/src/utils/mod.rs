@@ -4,7 +4,15 @@
  * Contains helper functions and common utilities used across the protocol.
  */
 
+pub mod ed25519; // Ed25519 signature instruction introspection
+pub mod layout; // Versioned account layout migrations
+pub mod pagination; // Linked-page PDA cursors
+pub mod pyth; // Pyth pull-oracle price updates
 pub mod validation_helpers;
 
 // Re-export for easy access
+pub use ed25519::*;
+pub use layout::*;
+pub use pagination::*;
+pub use pyth::*;
 pub use validation_helpers::*;
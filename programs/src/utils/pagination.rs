@@ -0,0 +1,152 @@
+/*!
+ * Linked-Page Pagination
+ *
+ * Standard pattern for accounts whose history is conceptually unbounded.
+ * Instead of growing a Vec inside one account (capped by the 10KB CPI
+ * allocation limit), items live in fixed-capacity page PDAs:
+ *
+ *   owner account  ── PageCursor { head, count, item_count }
+ *   page 0 (head)  ── PageLink { index: 0, next: page 1, count }
+ *   page 1         ── PageLink { index: 1, next: page 2, count }
+ *   ...
+ *
+ * Pages are derived as [page_seed, owner, index.to_le_bytes()], so the
+ * writable tail is always page `count - 1` and clients can either walk
+ * `next` pointers from `head` or derive any page directly.
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::GhostSpeakError;
+
+/// Paging state stored on the owning account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PageCursor {
+    /// First page (default if no pages exist)
+    pub head: Pubkey,
+    /// Number of pages opened
+    pub count: u32,
+    /// Total items across all pages
+    pub item_count: u64,
+}
+
+impl PageCursor {
+    pub const LEN: usize = 32 + // head
+        4 + // count
+        8; // item_count
+
+    /// Index of the current writable page, if any
+    pub fn tail_index(&self) -> Option<u32> {
+        self.count.checked_sub(1)
+    }
+
+    /// Index the next page must be opened at
+    pub fn next_index(&self) -> u32 {
+        self.count
+    }
+}
+
+/// Link header stored in every page account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PageLink {
+    /// Position of this page in the chain
+    pub index: u32,
+    /// Next page (default while this is the tail)
+    pub next: Pubkey,
+    /// Items stored in this page
+    pub count: u16,
+}
+
+impl PageLink {
+    pub const LEN: usize = 4 + // index
+        32 + // next
+        2; // count
+}
+
+/// Open a new tail page and link it into the chain
+///
+/// `previous` must be the current tail's link when the chain is non-empty.
+pub fn open_page(
+    cursor: &mut PageCursor,
+    page_key: Pubkey,
+    page: &mut PageLink,
+    previous: Option<&mut PageLink>,
+    index: u32,
+) -> Result<()> {
+    require!(index == cursor.next_index(), GhostSpeakError::InvalidPageIndex);
+
+    match cursor.tail_index() {
+        None => cursor.head = page_key,
+        Some(tail) => {
+            let previous = previous.ok_or(GhostSpeakError::PreviousPageRequired)?;
+            require!(previous.index == tail, GhostSpeakError::InvalidPageIndex);
+            previous.next = page_key;
+        }
+    }
+
+    *page = PageLink {
+        index,
+        next: Pubkey::default(),
+        count: 0,
+    };
+    cursor.count = cursor
+        .count
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Reserve the next slot in the tail page, returning the item's global position
+pub fn push_item(cursor: &mut PageCursor, page: &mut PageLink, capacity: u16) -> Result<u64> {
+    require!(
+        cursor.tail_index() == Some(page.index),
+        GhostSpeakError::InvalidPageIndex
+    );
+    require!(page.count < capacity, GhostSpeakError::PageFull);
+
+    let position = cursor.item_count;
+    page.count += 1;
+    cursor.item_count = cursor
+        .item_count
+        .checked_add(1)
+        .ok_or(GhostSpeakError::ArithmeticOverflow)?;
+
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_pages() {
+        let mut cursor = PageCursor::default();
+        let mut page0 = PageLink::default();
+        let key0 = Pubkey::new_unique();
+
+        open_page(&mut cursor, key0, &mut page0, None, 0).unwrap();
+        assert_eq!(cursor.head, key0);
+        assert_eq!(cursor.tail_index(), Some(0));
+
+        assert_eq!(push_item(&mut cursor, &mut page0, 2).unwrap(), 0);
+        assert_eq!(push_item(&mut cursor, &mut page0, 2).unwrap(), 1);
+        assert!(push_item(&mut cursor, &mut page0, 2).is_err());
+
+        // Opening out of order or without the tail fails
+        let mut page1 = PageLink::default();
+        let key1 = Pubkey::new_unique();
+        assert!(open_page(&mut cursor, key1, &mut page1, Some(&mut page0), 2).is_err());
+        assert!(open_page(&mut cursor, key1, &mut page1, None, 1).is_err());
+
+        open_page(&mut cursor, key1, &mut page1, Some(&mut page0), 1).unwrap();
+        assert_eq!(page0.next, key1);
+        assert_eq!(cursor.head, key0);
+        assert_eq!(cursor.count, 2);
+
+        // Only the tail accepts items
+        assert!(push_item(&mut cursor, &mut page0, 2).is_err());
+        assert_eq!(push_item(&mut cursor, &mut page1, 2).unwrap(), 2);
+        assert_eq!(cursor.item_count, 3);
+    }
+}
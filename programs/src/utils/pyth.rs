@@ -0,0 +1,101 @@
+/*!
+ * Pyth Price Updates
+ *
+ * Reads prices from Pyth pull-oracle `PriceUpdateV2` accounts posted by
+ * the Pyth Solana Receiver program. The layout is decoded here rather
+ * than through the Pyth SDK; only fully verified updates for the expected
+ * feed are accepted, and callers bound both their age and their
+ * confidence interval.
+ */
+
+use crate::GhostSpeakError;
+use anchor_lang::prelude::*;
+
+/// Pyth Solana Receiver program, owner of `PriceUpdateV2` accounts
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of `PriceUpdateV2` (sha256("account:PriceUpdateV2")[..8])
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// How many Wormhole guardian signatures checked the update
+#[derive(AnchorDeserialize)]
+enum VerificationLevel {
+    Partial { _num_signatures: u8 },
+    Full,
+}
+
+#[derive(AnchorDeserialize)]
+struct PriceFeedMessage {
+    feed_id: [u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+    _prev_publish_time: i64,
+    _ema_price: i64,
+    _ema_conf: u64,
+}
+
+#[derive(AnchorDeserialize)]
+struct PriceUpdateV2 {
+    _write_authority: Pubkey,
+    verification_level: VerificationLevel,
+    price_message: PriceFeedMessage,
+    _posted_slot: u64,
+}
+
+/// A validated oracle price: `price * 10^exponent` USD
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: u64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+/// Read the price in `update` for `feed_id`, rejecting updates older than
+/// `max_age` seconds or with a confidence interval wider than
+/// `max_conf_bps` of the price
+pub fn read_pyth_price(
+    update: &AccountInfo,
+    feed_id: &[u8; 32],
+    max_age: i64,
+    max_conf_bps: u16,
+    now: i64,
+) -> Result<OraclePrice> {
+    require!(
+        update.owner == &PYTH_RECEIVER_PROGRAM_ID,
+        GhostSpeakError::InvalidOraclePrice
+    );
+    let data = update.try_borrow_data()?;
+    require!(
+        data.len() > 8 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+        GhostSpeakError::InvalidOraclePrice
+    );
+    let update = PriceUpdateV2::deserialize(&mut &data[8..])
+        .map_err(|_| error!(GhostSpeakError::InvalidOraclePrice))?;
+
+    let message = update.price_message;
+    require!(
+        matches!(update.verification_level, VerificationLevel::Full)
+            && message.feed_id == *feed_id
+            && message.price > 0,
+        GhostSpeakError::InvalidOraclePrice
+    );
+    require!(
+        now.saturating_sub(message.publish_time) <= max_age,
+        GhostSpeakError::StaleOraclePrice
+    );
+    let price = message.price as u64;
+    require!(
+        message.conf as u128 * 10_000 <= price as u128 * max_conf_bps as u128,
+        GhostSpeakError::OraclePriceUncertain
+    );
+
+    Ok(OraclePrice {
+        price,
+        conf: message.conf,
+        exponent: message.exponent,
+        publish_time: message.publish_time,
+    })
+}
@@ -0,0 +1,261 @@
+/*!
+ * Discriminator Registry Tests
+ *
+ * Compares the committed registry in test-vectors/discriminators.json
+ * (generated by `cargo xtask gen-discriminators`) against the instructions,
+ * accounts and events currently declared in programs/src. Renaming or
+ * removing any of them changes a discriminator and breaks deployed clients,
+ * so the test fails until the registry is regenerated deliberately, ideally
+ * with a deprecation alias for renamed instructions.
+ */
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+const REGISTRY: &str = include_str!("../../test-vectors/discriminators.json");
+
+#[tokio::test]
+async fn test_discriminator_registry_comprehensive() {
+    println!("🧪 Starting discriminator registry checks...");
+
+    let registry: Value =
+        serde_json::from_str(REGISTRY).expect("discriminators.json is valid JSON");
+    assert_eq!(registry["version"], 1, "unexpected registry version");
+
+    test_registry_discriminators(&registry);
+    test_no_collisions(&registry);
+    test_registry_matches_sources(&registry);
+    test_deprecation_aliases(&registry);
+
+    println!("✅ Discriminator registry is up to date!");
+}
+
+fn discriminator(namespace: &str, name: &str) -> String {
+    Sha256::digest(format!("{namespace}:{name}").as_bytes())[..8]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn registered(registry: &Value, kind: &str) -> BTreeMap<String, String> {
+    registry[kind]
+        .as_array()
+        .unwrap_or_else(|| panic!("missing {kind} list"))
+        .iter()
+        .map(|e| {
+            (
+                e["name"].as_str().unwrap().to_string(),
+                e["discriminator"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+const KINDS: [(&str, &str); 3] = [
+    ("instructions", "global"),
+    ("accounts", "account"),
+    ("events", "event"),
+];
+
+fn test_registry_discriminators(registry: &Value) {
+    println!("  📋 Testing registered discriminators...");
+
+    for (kind, namespace) in KINDS {
+        for (name, disc) in registered(registry, kind) {
+            assert_eq!(
+                discriminator(namespace, &name),
+                disc,
+                "registry discriminator for {kind} {name} was edited by hand"
+            );
+        }
+    }
+
+    println!("    ✓ Registered discriminators derive from their names");
+}
+
+fn test_no_collisions(registry: &Value) {
+    println!("  📋 Testing for discriminator collisions...");
+
+    for (kind, _) in KINDS {
+        let entries = registered(registry, kind);
+        let unique: BTreeSet<&String> = entries.values().collect();
+        assert_eq!(
+            unique.len(),
+            entries.len(),
+            "{kind} discriminator collision"
+        );
+    }
+
+    // Accounts and events share the 8-byte prefix space when decoded from raw data
+    let accounts = registered(registry, "accounts");
+    for (event, disc) in registered(registry, "events") {
+        assert!(
+            !accounts.values().any(|d| *d == disc),
+            "event {event} collides with an account discriminator"
+        );
+    }
+
+    println!("    ✓ No collisions");
+}
+
+fn test_registry_matches_sources(registry: &Value) {
+    println!("  📋 Testing registry against program sources...");
+
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let sources = collect_sources(&src);
+
+    let lib = strip_comments(&std::fs::read_to_string(src.join("lib.rs")).unwrap());
+    let instructions: BTreeSet<String> = lib
+        .lines()
+        .skip_while(|line| line.trim() != "#[program]")
+        .filter_map(|line| line.strip_prefix("    pub fn "))
+        .map(identifier)
+        .collect();
+
+    let mut accounts = BTreeSet::new();
+    let mut events = BTreeSet::new();
+    for file in &sources {
+        let stripped = strip_comments(&std::fs::read_to_string(file).unwrap());
+        accounts.extend(attributed_structs(&stripped, "#[account]"));
+        events.extend(attributed_structs(&stripped, "#[event]"));
+    }
+
+    for (kind, found) in [
+        ("instructions", &instructions),
+        ("accounts", &accounts),
+        ("events", &events),
+    ] {
+        let expected: BTreeSet<String> = registered(registry, kind).into_keys().collect();
+        let added: Vec<_> = found.difference(&expected).collect();
+        let removed: Vec<_> = expected.difference(found).collect();
+        assert!(
+            added.is_empty() && removed.is_empty(),
+            "{kind} changed (added {added:?}, removed {removed:?}); \
+             if intentional run `cargo xtask gen-discriminators`"
+        );
+        println!("    ✓ {} {kind} match", found.len());
+    }
+}
+
+fn test_deprecation_aliases(registry: &Value) {
+    println!("  📋 Testing deprecation aliases...");
+
+    let instructions = registered(registry, "instructions");
+    let aliases = registry["aliases"].as_array().unwrap();
+    for alias in aliases {
+        let name = alias["name"].as_str().unwrap();
+        let target = alias["target"].as_str().unwrap();
+        assert!(
+            instructions.contains_key(name),
+            "alias {name} has no entry point"
+        );
+        assert!(
+            instructions.contains_key(target),
+            "alias target {target} missing"
+        );
+        assert_eq!(alias["discriminator"].as_str().unwrap(), instructions[name]);
+    }
+
+    // record_x402_payment was renamed to record_payai_payment
+    assert!(aliases
+        .iter()
+        .any(|a| a["name"] == "record_x402_payment" && a["target"] == "record_payai_payment"));
+
+    println!("    ✓ {} aliases route to live instructions", aliases.len());
+}
+
+// Minimal source scanner, mirroring xtask/src/discriminators.rs
+
+fn collect_sources(src: &Path) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    let mut pending = vec![(src.join("lib.rs"), src.to_path_buf())];
+    while let Some((file, dir)) = pending.pop() {
+        let stripped = strip_comments(&std::fs::read_to_string(&file).unwrap());
+        for line in stripped.lines().map(str::trim) {
+            let Some(name) = line
+                .strip_prefix("pub mod ")
+                .or_else(|| line.strip_prefix("pub(crate) mod "))
+                .or_else(|| line.strip_prefix("mod "))
+                .and_then(|rest| rest.strip_suffix(';'))
+            else {
+                continue;
+            };
+            let flat = dir.join(format!("{name}.rs"));
+            let path = if flat.exists() {
+                flat
+            } else {
+                dir.join(name).join("mod.rs")
+            };
+            pending.push((path, dir.join(name)));
+        }
+        out.push(file);
+    }
+    out
+}
+
+fn attributed_structs(source: &str, attribute: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        if line == attribute {
+            if let Some(name) = lines.by_ref().find_map(|l| l.strip_prefix("pub struct ")) {
+                out.push(identifier(name));
+            }
+        }
+    }
+    out
+}
+
+fn identifier(s: &str) -> String {
+    s.chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect()
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -146,6 +146,10 @@ mod ghost_protect_tests {
             completed_at: None,
             dispute_reason: None,
             arbitrator_decision: None,
+            upfront_amount: 0,
+            trial_bond: 0,
+            bond_clawed_back: false,
+            origin_program: None,
             bump: 0,
         };
 
@@ -0,0 +1,428 @@
+/*!
+ * Permission Matrix Tests
+ *
+ * Compares the committed matrix in test-vectors/permissions.json (generated
+ * by `cargo xtask gen-permissions`) against the account contexts declared in
+ * programs/src, and checks it against the stated security model below. Any
+ * change to who signs, what is mutated, or which instructions rely on
+ * handler-side authorization fails here until the matrix is regenerated and
+ * the model is updated on purpose.
+ */
+
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+const MATRIX: &str = include_str!("../../test-vectors/permissions.json");
+
+/// Instructions whose signers are not bound by any account constraint
+///
+/// Each is either intentionally permissionless (cranks, self-service setup,
+/// resolvers) or authorizes its caller inside the handler (multisig
+/// remaining accounts, attestations, stored authorities).
+const SIGNER_UNCONSTRAINED: &[&str] = &[
+    // Permissionless cranks and read-only queries
+    "apply_admin_transfer",
+    "apply_parameter_change",
+    "apply_reputation_decay",
+    "close_did_document",
+    "evaluate_trigger",
+    "execute_did_recovery",
+    "expire_credential",
+    "expire_multisig_freeze",
+    "file_veto_review",
+    "finalize_slash",
+    "get_access_tier",
+    "get_reputation_windows",
+    "migrate_agent",
+    "migrate_circuit_breaker",
+    "migrate_escrow",
+    "migrate_reputation_metrics",
+    "migrate_staking_account",
+    "migrate_staking_config",
+    "quote_fee_in_token",
+    "recalculate_global_weighted_stake",
+    "recompute_reputation_aggregate",
+    "release_agent_handle",
+    "release_proposal_deposit",
+    "resolve_did_compressed",
+    "resolve_did_document",
+    "tally_votes",
+    "update_leaderboard",
+    "update_staking_config",
+    "verify_authorization",
+    "verify_credential",
+    "verify_did_agent_link",
+    // Self-service creation (caller becomes the authority)
+    "auto_create_ghost",
+    "claim_ghost",
+    "create_credential_template",
+    "create_credential_type",
+    "deposit_meta_tx_balance",
+    "fork_credential_template",
+    "initialize_audit_trail",
+    "initialize_governance_proposal",
+    "initialize_meta_nonce",
+    "register_custom_credential_kind",
+    "register_ghost_metadata",
+    // Authorized in the handler
+    "accept_credential",
+    "add_signer",
+    "add_to_denylist",
+    "approve_multisig_transaction",
+    "attest_external_did_link",
+    "cancel_admin_transfer",
+    "cancel_multisig_transaction",
+    "cancel_staking_config_update",
+    "change_threshold",
+    "close_credential",
+    "cosign_credential",
+    "create_trigger",
+    "deactivate_did_compressed",
+    "deactivate_did_document_by_controllers",
+    "emergency_freeze_multisig",
+    "emergency_pause_protocol",
+    "emergency_unfreeze_multisig",
+    "execute_meta_tx",
+    "execute_multisig_transaction",
+    "execute_proposal",
+    "generate_compliance_report",
+    "initiate_did_recovery",
+    "present_credential",
+    "propose_admin_transfer",
+    "propose_staking_config_update",
+    "queue_multisig_transaction",
+    "reactivate_did_document_by_controllers",
+    "record_crosschain_attestation",
+    "remove_fee_exemption",
+    "remove_from_denylist",
+    "remove_signer",
+    "reset_circuit_breaker",
+    "resolve_credential_flag",
+    "review_insurance_claim",
+    "review_slash_appeal",
+    "set_did_controllers",
+    "set_emergency_config",
+    "set_fee_exemption",
+    "set_issuer_registry_enforcement",
+    "set_registered_issuer",
+    "set_holder_policy",
+    "set_reputation_source",
+    "set_signer_weights",
+    "set_veto_quorum",
+    "update_buyback_config",
+    "update_crosschain_status",
+    "update_did_compressed",
+    "update_did_document_by_controllers",
+    "update_ghost_score",
+    "update_proposal_deposit_config",
+    "update_reputation_tags",
+    "verify_signed_authorization",
+    "veto_proposal",
+    "withdraw_treasury",
+];
+
+/// (instruction, signer) pairs that must stay bound by an account constraint
+const REQUIRED_BINDINGS: &[(&str, &str)] = &[
+    ("revoke_credential", "issuer"),
+    ("rebind_credential", "issuer"),
+    ("deactivate_credential_template", "issuer"),
+    ("deactivate_credential_type", "authority"),
+    ("slash_stake", "authority"),
+    ("request_crosschain_sync", "issuer"),
+];
+
+#[tokio::test]
+async fn test_permission_matrix_comprehensive() {
+    println!("🧪 Starting permission matrix checks...");
+
+    let matrix: Value = serde_json::from_str(MATRIX).expect("permissions.json is valid JSON");
+    assert_eq!(matrix["version"], 1, "unexpected matrix version");
+    let instructions: BTreeMap<String, &Value> = matrix["instructions"]
+        .as_array()
+        .expect("missing instructions list")
+        .iter()
+        .map(|i| (i["name"].as_str().unwrap().to_string(), i))
+        .collect();
+
+    test_matrix_matches_sources(&instructions);
+    test_stated_security_model(&instructions);
+    test_required_bindings(&instructions);
+
+    println!("✅ Permission matrix is up to date!");
+}
+
+fn test_matrix_matches_sources(instructions: &BTreeMap<String, &Value>) {
+    println!("  📋 Testing matrix against program sources...");
+
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut contexts = BTreeMap::new();
+    for file in collect_sources(&src) {
+        let stripped = strip_comments(&std::fs::read_to_string(file).unwrap());
+        contexts.extend(accounts_structs(&stripped));
+    }
+
+    let lib = strip_comments(&std::fs::read_to_string(src.join("lib.rs")).unwrap());
+    let declared = program_contexts(&lib);
+    let names: BTreeSet<&String> = declared.keys().collect();
+    let listed: BTreeSet<&String> = instructions.keys().collect();
+    assert_eq!(
+        names, listed,
+        "instructions changed; run `cargo xtask gen-permissions`"
+    );
+
+    for (name, context) in &declared {
+        let entry = instructions[name];
+        assert_eq!(entry["context"], context.as_str(), "{name} context changed");
+
+        let fields = &contexts[context];
+        let accounts = entry["accounts"].as_array().unwrap();
+        let recorded: Vec<(String, bool, bool)> = accounts
+            .iter()
+            .map(|a| {
+                (
+                    a["name"].as_str().unwrap().to_string(),
+                    a["signer"].as_bool().unwrap(),
+                    a["mutable"].as_bool().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            &recorded, fields,
+            "{name} accounts changed; run `cargo xtask gen-permissions`"
+        );
+    }
+
+    println!("    ✓ {} instructions match their account contexts", declared.len());
+}
+
+fn test_stated_security_model(instructions: &BTreeMap<String, &Value>) {
+    println!("  📋 Testing stated security model...");
+
+    let unconstrained: BTreeSet<&str> = instructions
+        .values()
+        .filter(|i| !i["signer_constrained"].as_bool().unwrap())
+        .map(|i| i["name"].as_str().unwrap())
+        .collect();
+    let stated: BTreeSet<&str> = SIGNER_UNCONSTRAINED.iter().copied().collect();
+
+    let added: Vec<_> = unconstrained.difference(&stated).collect();
+    let removed: Vec<_> = stated.difference(&unconstrained).collect();
+    assert!(
+        added.is_empty() && removed.is_empty(),
+        "signer-unconstrained instructions changed (added {added:?}, removed {removed:?}); \
+         review their authorization and update SIGNER_UNCONSTRAINED"
+    );
+
+    println!("    ✓ {} unconstrained instructions are accounted for", stated.len());
+}
+
+fn test_required_bindings(instructions: &BTreeMap<String, &Value>) {
+    println!("  📋 Testing required signer bindings...");
+
+    for (name, signer) in REQUIRED_BINDINGS {
+        let entry = instructions
+            .get(*name)
+            .unwrap_or_else(|| panic!("{name} missing from matrix"));
+        let bound_by = entry["signers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["name"] == *signer)
+            .unwrap_or_else(|| panic!("{name} no longer requires {signer} to sign"))["bound_by"]
+            .as_array()
+            .unwrap();
+        assert!(!bound_by.is_empty(), "{name}: {signer} is no longer constrained");
+    }
+
+    println!("    ✓ {} signer bindings hold", REQUIRED_BINDINGS.len());
+}
+
+// Minimal source scanner, mirroring xtask/src/permissions.rs
+
+/// Instruction name -> accounts struct named by its `Context<...>`
+fn program_contexts(lib: &str) -> BTreeMap<String, String> {
+    let body: Vec<&str> = lib
+        .lines()
+        .skip_while(|line| line.trim() != "#[program]")
+        .collect();
+    let mut out = BTreeMap::new();
+    for (i, line) in body.iter().enumerate() {
+        let Some(rest) = line.strip_prefix("    pub fn ") else {
+            continue;
+        };
+        let end = i + body[i..].iter().position(|l| l.contains("-> ")).unwrap();
+        let signature = body[i..=end].concat();
+        let start = signature.find("Context<").unwrap() + "Context<".len();
+        let mut depth = 1;
+        let generics: String = signature[start..]
+            .chars()
+            .take_while(|c| {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+                depth > 0
+            })
+            .collect();
+        let context = generics.split(", ").last().unwrap();
+        out.insert(identifier(rest), identifier(context));
+    }
+    out
+}
+
+/// Accounts struct -> (field, signer, mutable) in declaration order
+fn accounts_structs(source: &str) -> BTreeMap<String, Vec<(String, bool, bool)>> {
+    let mut out = BTreeMap::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line != "#[derive(Accounts)]" {
+            continue;
+        }
+        let Some(name) = lines.by_ref().find_map(|l| l.strip_prefix("pub struct ")) else {
+            break;
+        };
+        let mut fields = Vec::new();
+        let mut attribute = String::new();
+        for line in lines.by_ref().take_while(|l| *l != "}") {
+            let field = line.strip_prefix("pub ").and_then(|rest| rest.split_once(':'));
+            match field {
+                Some((field, ty)) if depth(&attribute) == 0 => {
+                    let items = account_items(&attribute);
+                    let has = |item: &str| items.iter().any(|i| i == item);
+                    let ty = ty.trim();
+                    let signer = ty.starts_with("Signer<")
+                        || ty.starts_with("Option<Signer<")
+                        || has("signer");
+                    let mutable = has("mut")
+                        || has("init")
+                        || has("init_if_needed")
+                        || items
+                            .iter()
+                            .any(|i| i.starts_with("close ") || i.starts_with("realloc "));
+                    fields.push((identifier(field.trim()), signer, mutable));
+                    attribute.clear();
+                }
+                _ => {
+                    attribute.push_str(line);
+                    attribute.push(' ');
+                }
+            }
+        }
+        out.insert(identifier(name), fields);
+    }
+    out
+}
+
+fn depth(s: &str) -> i32 {
+    s.chars()
+        .map(|c| match c {
+            '(' | '[' | '{' => 1,
+            ')' | ']' | '}' => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Top-level comma-separated items of the last `#[account(...)]` attribute
+fn account_items(attribute: &str) -> Vec<String> {
+    let Some(start) = attribute.rfind("#[account(") else {
+        return Vec::new();
+    };
+    let mut items = vec![String::new()];
+    let mut level = 0;
+    for c in attribute[start + "#[account(".len()..].chars() {
+        match c {
+            '(' | '[' | '{' => level += 1,
+            ')' | ']' | '}' if level == 0 => break,
+            ')' | ']' | '}' => level -= 1,
+            ',' if level == 0 => {
+                items.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        items.last_mut().unwrap().push(c);
+    }
+    items.into_iter().map(|i| i.trim().to_string()).collect()
+}
+
+fn collect_sources(src: &Path) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    let mut pending = vec![(src.join("lib.rs"), src.to_path_buf())];
+    while let Some((file, dir)) = pending.pop() {
+        let stripped = strip_comments(&std::fs::read_to_string(&file).unwrap());
+        for line in stripped.lines().map(str::trim) {
+            let Some(name) = line
+                .strip_prefix("pub mod ")
+                .or_else(|| line.strip_prefix("pub(crate) mod "))
+                .or_else(|| line.strip_prefix("mod "))
+                .and_then(|rest| rest.strip_suffix(';'))
+            else {
+                continue;
+            };
+            let flat = dir.join(format!("{name}.rs"));
+            let path = if flat.exists() {
+                flat
+            } else {
+                dir.join(name).join("mod.rs")
+            };
+            pending.push((path, dir.join(name)));
+        }
+        out.push(file);
+    }
+    out
+}
+
+fn identifier(s: &str) -> String {
+    s.chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect()
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,1669 @@
+/*!
+ * Pillar Flow End-to-End Test
+ *
+ * Executable specification for the three pillars (Verifiable Credentials,
+ * Reputation, Identity). Runs the built program under solana-program-test
+ * and walks one agent through its whole lifecycle:
+ * - GHOST staking (Sybil gate for registration)
+ * - Agent registration and DID document creation
+ * - Escrow creation, delivery and settlement
+ * - PayAI payment recording
+ * - JobCompletion credential issuance
+ * - Reputation tag decay
+ * - GHOST unstaking once the lock has passed
+ *
+ * Every account touched is decoded and asserted, along with every event the
+ * program emits. Accounts and events are mirrored locally (borsh prefixes of
+ * the on-chain layouts) since the program crate only builds as a cdylib.
+ *
+ * Requires `anchor build` (target/deploy/ghostspeak_marketplace.so); the
+ * test is skipped when the program has not been built.
+ */
+
+use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize};
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    system_instruction, system_program, sysvar,
+    transaction::Transaction,
+};
+use std::path::Path;
+
+/// GhostSpeak program ID (from declare_id! in lib.rs)
+const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("4wHjA2a5YC4twZb4NQpwZpixo5FgxxzuJUrCG7UnF9pB");
+
+/// Directory holding the compiled program
+const DEPLOY_DIR: &str = "target/deploy";
+
+const GHOST_DECIMALS: u8 = 6;
+const GHOST: u64 = 1_000_000; // 1 GHOST (6 decimals)
+const USDC: u64 = 1_000_000; // 1 USDC (6 decimals)
+const DAY: i64 = 24 * 60 * 60;
+
+const AGENT_ID: &str = "e2e-agent-1";
+const ESCROW_ID: u64 = 1;
+const ESCROW_AMOUNT: u64 = 25 * USDC;
+const STAKE_AMOUNT: u64 = 5_000 * GHOST; // Verified tier
+
+#[tokio::test]
+async fn test_pillar_flow_comprehensive() {
+    println!("🧭 Starting end-to-end pillar flow...");
+
+    if !program_available() {
+        println!("Skipping: program not built");
+        return;
+    }
+
+    let mut flow = Flow::start().await;
+
+    flow.stake_ghost().await;
+    flow.register_agent().await;
+    flow.create_did_document().await;
+    flow.initialize_reputation().await;
+    flow.settle_escrow().await;
+    flow.record_payai_payments().await;
+    flow.issue_job_completion_credential().await;
+    flow.decay_reputation_tags().await;
+    flow.unstake_ghost().await;
+
+    println!("✅ End-to-end pillar flow passed!");
+}
+
+// =====================================================
+// FLOW
+// =====================================================
+
+/// Actors, token accounts and PDAs shared by every step
+struct Flow {
+    ctx: ProgramTestContext,
+    /// Agent owner (stakes, registers, controls the DID)
+    owner: Keypair,
+    /// Client hiring the agent through an escrow
+    client: Keypair,
+    /// Credential issuer (JobCompletion authority)
+    issuer: Keypair,
+    /// Payment verifier relaying PayAI settlements
+    facilitator: Keypair,
+    ghost_mint: Pubkey,
+    usdc_mint: Pubkey,
+    owner_ghost: Pubkey,
+    owner_usdc: Pubkey,
+    client_usdc: Pubkey,
+    agent: Pubkey,
+    escrow: Pubkey,
+    escrow_vault: Pubkey,
+}
+
+impl Flow {
+    async fn start() -> Self {
+        let deploy_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join(DEPLOY_DIR);
+        std::env::set_var("SBF_OUT_DIR", deploy_dir);
+
+        let program_test = ProgramTest::new("ghostspeak_marketplace", PROGRAM_ID, None);
+        let mut ctx = program_test.start_with_context().await;
+
+        let owner = Keypair::new();
+        let client = Keypair::new();
+        let issuer = Keypair::new();
+        let facilitator = Keypair::new();
+        for actor in [&owner, &client, &issuer, &facilitator] {
+            fund(&mut ctx, &actor.pubkey(), 10_000_000_000).await;
+        }
+
+        let ghost_mint = create_mint(&mut ctx, GHOST_DECIMALS).await;
+        let usdc_mint = create_mint(&mut ctx, 6).await;
+        let owner_ghost = create_token_account(&mut ctx, &ghost_mint, &owner.pubkey()).await;
+        let owner_usdc = create_token_account(&mut ctx, &usdc_mint, &owner.pubkey()).await;
+        let client_usdc = create_token_account(&mut ctx, &usdc_mint, &client.pubkey()).await;
+        mint_to(&mut ctx, &ghost_mint, &owner_ghost, 10_000 * GHOST).await;
+        mint_to(&mut ctx, &usdc_mint, &client_usdc, 100 * USDC).await;
+
+        let agent = pda(&[b"agent", owner.pubkey().as_ref(), AGENT_ID.as_bytes()]);
+        let escrow = pda(&[
+            b"ghost_protect",
+            client.pubkey().as_ref(),
+            &ESCROW_ID.to_le_bytes(),
+        ]);
+        // The escrow PDA signs the release, so it owns the vault
+        let escrow_vault = create_token_account(&mut ctx, &usdc_mint, &escrow).await;
+
+        Self {
+            ctx,
+            owner,
+            client,
+            issuer,
+            facilitator,
+            ghost_mint,
+            usdc_mint,
+            owner_ghost,
+            owner_usdc,
+            client_usdc,
+            agent,
+            escrow,
+            escrow_vault,
+        }
+    }
+
+    async fn stake_ghost(&mut self) {
+        println!("  📋 Staking GHOST...");
+
+        let admin_authority = self.install_admin_authority().await;
+        let treasury = Pubkey::new_unique();
+        let staking_config = pda(&[b"staking_config"]);
+        let events = self
+            .send(
+                instruction(
+                    "initialize_staking_config",
+                    (1_000 * GHOST, treasury),
+                    vec![
+                        AccountMeta::new(staking_config, false),
+                        AccountMeta::new_readonly(admin_authority, false),
+                        AccountMeta::new(self.ctx.payer.pubkey(), true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[],
+            )
+            .await;
+        assert!(events.is_empty());
+
+        let staking = pda(&[b"staking", self.owner.pubkey().as_ref()]);
+        let vault = pda(&[b"staking_vault", staking_config.as_ref()]);
+        let lock_duration = 30 * DAY;
+        let events = self
+            .send(
+                instruction(
+                    "stake_ghost",
+                    (STAKE_AMOUNT, lock_duration),
+                    vec![
+                        AccountMeta::new(staking, false),
+                        AccountMeta::new(self.owner_ghost, false),
+                        AccountMeta::new(vault, false),
+                        AccountMeta::new_readonly(staking_config, false),
+                        AccountMeta::new_readonly(self.ghost_mint, false),
+                        AccountMeta::new(self.owner.pubkey(), true),
+                        AccountMeta::new_readonly(spl_token::ID, false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(sysvar::rent::ID, false),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        let now = self.now().await;
+        let account: layout::StakingAccount = self.account(staking, "StakingAccount").await;
+        assert_eq!(account.owner, self.owner.pubkey());
+        assert_eq!(account.amount_staked, STAKE_AMOUNT);
+        assert_eq!(account.lock_duration, lock_duration);
+        assert_eq!(account.unlock_at, account.staked_at + lock_duration);
+        assert_eq!(account.reputation_boost_bps, 1000);
+        assert!(account.has_verified_badge);
+        assert!(!account.has_premium_benefits);
+        assert_eq!(account.total_slashed, 0);
+        assert_eq!(account.tier, layout::TIER_VERIFIED);
+        assert_eq!(account.api_calls_remaining, 1_000);
+        assert_eq!(account.last_quota_reset, now);
+        assert_eq!(account.voting_power, STAKE_AMOUNT);
+
+        assert_eq!(self.token_balance(vault).await, STAKE_AMOUNT);
+        assert_eq!(
+            self.token_balance(self.owner_ghost).await,
+            10_000 * GHOST - STAKE_AMOUNT
+        );
+
+        let tier: layout::TierUpdatedEvent = event(&events, "TierUpdatedEvent");
+        assert_eq!(tier.agent, self.owner.pubkey());
+        assert_eq!(tier.old_tier, layout::TIER_NONE);
+        assert_eq!(tier.new_tier, layout::TIER_VERIFIED);
+        assert_eq!(tier.total_staked, STAKE_AMOUNT);
+        assert_eq!(tier.daily_api_calls, 1_000);
+        assert_eq!(tier.voting_power, STAKE_AMOUNT);
+
+        let staked: layout::GhostStakedEvent = event(&events, "GhostStakedEvent");
+        assert_eq!(staked.agent, self.owner.pubkey());
+        assert_eq!(staked.amount, STAKE_AMOUNT);
+        assert_eq!(staked.unlock_at, account.unlock_at);
+        assert_eq!(staked.reputation_boost_bps, 1000);
+        assert_eq!(staked.tier, layout::TIER_VERIFIED);
+        assert_eq!(staked.daily_api_calls, 1_000);
+        assert_eq!(staked.voting_power, STAKE_AMOUNT);
+        assert_eq!(events.len(), 2);
+
+        println!(
+            "    ✓ Staked {} GHOST (Verified tier)",
+            STAKE_AMOUNT / GHOST
+        );
+    }
+
+    async fn register_agent(&mut self) {
+        println!("  📋 Registering agent...");
+
+        let staking = pda(&[b"staking", self.owner.pubkey().as_ref()]);
+
+        // Protocol config sets the registration and escrow fees (off by default)
+        let protocol_config = pda(&[b"protocol_config"]);
+        let events = self
+            .send(
+                instruction(
+                    "initialize_protocol_config",
+                    (),
+                    vec![
+                        AccountMeta::new(protocol_config, false),
+                        AccountMeta::new_readonly(pda(&[b"admin_authority"]), false),
+                        AccountMeta::new(self.ctx.payer.pubkey(), true),
+                        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[],
+            )
+            .await;
+        assert!(events.is_empty());
+
+        let events = self
+            .send(
+                instruction(
+                    "register_agent",
+                    (
+                        1u8,
+                        "E2E Agent".to_string(),
+                        "Audits Anchor programs".to_string(),
+                        "https://example.com/agent.json".to_string(),
+                        AGENT_ID.to_string(),
+                        layout::PRICING_FIXED,
+                        None::<Pubkey>,
+                    ),
+                    vec![
+                        AccountMeta::new(self.agent, false),
+                        AccountMeta::new_readonly(staking, false),
+                        AccountMeta::new(self.owner.pubkey(), true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(sysvar::clock::ID, false),
+                        // No referrer
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(protocol_config, false),
+                        AccountMeta::new_readonly(pda(&[b"staking_config"]), false),
+                        AccountMeta::new_readonly(pda(&[b"fee_exemptions"]), false),
+                        // Fees are off: no fee vault, paid in SOL
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        let now = self.now().await;
+        let agent: layout::Agent = self.account(self.agent, "Agent").await;
+        assert_eq!(agent.owner, Some(self.owner.pubkey()));
+        assert_eq!(agent.agent_id, AGENT_ID);
+        assert_eq!(agent.agent_type, 1);
+        assert_eq!(agent.name, "E2E Agent");
+        assert_eq!(agent.description, "Audits Anchor programs");
+        assert_eq!(agent.metadata_uri, "https://example.com/agent.json");
+        assert_eq!(agent.pricing_model, layout::PRICING_FIXED);
+        assert_eq!(agent.reputation_score, 0);
+        assert!(agent.is_active);
+        assert_eq!(agent.created_at, now);
+        assert_eq!(agent.x402_total_payments, 0);
+        assert_eq!(agent.x402_total_calls, 0);
+
+        let registered: layout::AgentRegisteredEvent = event(&events, "AgentRegisteredEvent");
+        assert_eq!(registered.agent, self.agent);
+        assert_eq!(registered.owner, self.owner.pubkey());
+        assert_eq!(registered.name, "E2E Agent");
+        assert_eq!(registered.timestamp, now);
+        assert_eq!(events.len(), 1);
+
+        println!("    ✓ Agent registered at {}", self.agent);
+    }
+
+    async fn create_did_document(&mut self) {
+        println!("  📋 Creating DID document...");
+
+        let controller = self.owner.pubkey();
+        let did_document = pda(&[b"did_document", controller.as_ref()]);
+        let did = format!("did:sol:localnet:{controller}");
+        let method = layout::VerificationMethod {
+            id: "key-1".to_string(),
+            method_type: layout::ED25519_VERIFICATION_KEY_2020,
+            controller: did.clone(),
+            public_key_multibase: format!("z{controller}"),
+            relationships: vec![layout::AUTHENTICATION, layout::ASSERTION_METHOD],
+            created_at: 0,
+            revoked: false,
+        };
+        let service = layout::ServiceEndpoint {
+            id: "agent-api".to_string(),
+            service_type: layout::AI_AGENT_SERVICE,
+            service_endpoint: "https://example.com/api".to_string(),
+            description: "Agent API".to_string(),
+        };
+
+        let events = self
+            .send(
+                instruction(
+                    "create_did_document",
+                    (did.clone(), vec![method.clone()], vec![service.clone()]),
+                    vec![
+                        AccountMeta::new(did_document, false),
+                        AccountMeta::new(controller, true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(sysvar::clock::ID, false),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+        // DID creation is logged, not emitted
+        assert!(events.is_empty());
+
+        let now = self.now().await;
+        let document: layout::DidDocument = self.account(did_document, "DidDocument").await;
+        assert_eq!(document.did, did);
+        assert_eq!(document.controller, controller);
+        assert_eq!(document.verification_methods, vec![method]);
+        assert_eq!(document.service_endpoints, vec![service]);
+        assert_eq!(document.context[0], "https://www.w3.org/ns/did/v1");
+        assert!(document.also_known_as.is_empty());
+        assert_eq!(document.created_at, now);
+        assert_eq!(document.updated_at, now);
+        assert_eq!(document.version, 1);
+        assert!(!document.deactivated);
+        assert_eq!(document.deactivated_at, None);
+
+        println!("    ✓ {did} created");
+    }
+
+    async fn initialize_reputation(&mut self) {
+        println!("  📋 Initializing reputation tracking...");
+
+        let metrics = pda(&[b"reputation_metrics", self.agent.as_ref()]);
+        let events = self
+            .send(
+                instruction(
+                    "initialize_reputation_metrics",
+                    (),
+                    vec![
+                        AccountMeta::new(metrics, false),
+                        AccountMeta::new_readonly(self.agent, false),
+                        AccountMeta::new(self.owner.pubkey(), true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(sysvar::clock::ID, false),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        let now = self.now().await;
+        let account: layout::ReputationMetrics = self.account(metrics, "ReputationMetrics").await;
+        assert_eq!(account.agent, self.agent);
+        assert_eq!(account.successful_payments, 0);
+        assert_eq!(account.failed_payments, 0);
+        assert_eq!(account.payment_history_7d, [0; 7]);
+        assert_eq!(account.created_at, now);
+        assert_eq!(account.tag_count(), 0);
+
+        let initialized: layout::ReputationMetricsInitializedEvent =
+            event(&events, "ReputationMetricsInitializedEvent");
+        assert_eq!(initialized.agent, self.agent);
+        assert_eq!(initialized.timestamp, now);
+        assert_eq!(events.len(), 1);
+
+        // PayAI recording is rate limited per payer
+        let rate_limiter = pda(&[b"rate_limiter"]);
+        let config = layout::RateLimitConfig {
+            default_limit: 10,
+            window_duration: 60,
+            enabled: true,
+            penalty_duration: 300,
+            burst_size: 5,
+        };
+        let events = self
+            .send(
+                instruction(
+                    "init_rate_limiter",
+                    config,
+                    vec![
+                        AccountMeta::new(rate_limiter, false),
+                        AccountMeta::new(self.ctx.payer.pubkey(), true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[],
+            )
+            .await;
+        assert!(events.is_empty());
+
+        println!("    ✓ Reputation metrics and rate limiter ready");
+    }
+
+    async fn settle_escrow(&mut self) {
+        println!("  📋 Opening and settling escrow...");
+
+        let client = self.client.pubkey();
+        let client_reputation = pda(&[b"client_reputation", client.as_ref()]);
+        let deadline = self.now().await + 7 * DAY;
+
+        let protocol_config = pda(&[b"protocol_config"]);
+
+        // Create
+        let events = self
+            .send(
+                instruction(
+                    "create_escrow",
+                    (
+                        ESCROW_ID,
+                        ESCROW_AMOUNT,
+                        "Audit the staking module".to_string(),
+                        deadline,
+                        None::<Pubkey>,
+                    ),
+                    vec![
+                        AccountMeta::new(self.escrow, false),
+                        AccountMeta::new_readonly(self.agent, false),
+                        AccountMeta::new(self.client_usdc, false),
+                        AccountMeta::new(self.escrow_vault, false),
+                        AccountMeta::new_readonly(self.usdc_mint, false),
+                        AccountMeta::new(client, true),
+                        AccountMeta::new(client_reputation, false),
+                        // No party is denylisted
+                        AccountMeta::new_readonly(denylist_entry(&self.agent), false),
+                        AccountMeta::new_readonly(denylist_entry(&self.owner.pubkey()), false),
+                        AccountMeta::new_readonly(denylist_entry(&client), false),
+                        AccountMeta::new_readonly(spl_token::ID, false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        // No referrer
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                    ],
+                ),
+                &[&self.client.insecure_clone()],
+            )
+            .await;
+
+        let now = self.now().await;
+        let escrow: layout::GhostProtectEscrow =
+            self.account(self.escrow, "GhostProtectEscrow").await;
+        assert_eq!(escrow.escrow_id, ESCROW_ID);
+        assert_eq!(escrow.client, client);
+        assert_eq!(escrow.agent, self.agent);
+        assert_eq!(escrow.amount, ESCROW_AMOUNT);
+        assert_eq!(escrow.token_mint, self.usdc_mint);
+        assert_eq!(escrow.status, layout::ESCROW_ACTIVE);
+        assert_eq!(escrow.job_description, "Audit the staking module");
+        assert_eq!(escrow.delivery_proof, None);
+        assert_eq!(escrow.deadline, deadline);
+        assert_eq!(escrow.created_at, now);
+        assert_eq!(escrow.completed_at, None);
+        assert_eq!(self.token_balance(self.escrow_vault).await, ESCROW_AMOUNT);
+        assert_eq!(
+            self.token_balance(self.client_usdc).await,
+            100 * USDC - ESCROW_AMOUNT
+        );
+
+        let reputation: layout::ClientReputation =
+            self.account(client_reputation, "ClientReputation").await;
+        assert_eq!(reputation.client, client);
+        assert_eq!(reputation.total_escrows, 1);
+        assert_eq!(reputation.completed_escrows, 0);
+
+        let updated: layout::ClientReputationUpdatedEvent =
+            event(&events, "ClientReputationUpdatedEvent");
+        assert_eq!(updated.client, client);
+        assert_eq!(updated.escrow_id, ESCROW_ID);
+        assert_eq!(updated.total_escrows, 1);
+        assert_eq!(updated.dispute_rate_bps, 0);
+        assert_eq!(updated.cancellation_rate_bps, 0);
+
+        let created: layout::EscrowCreatedEvent = event(&events, "EscrowCreatedEvent");
+        assert_eq!(created.escrow_id, ESCROW_ID);
+        assert_eq!(created.client, client);
+        assert_eq!(created.agent, self.agent);
+        assert_eq!(created.amount, ESCROW_AMOUNT);
+        assert_eq!(created.deadline, deadline);
+        assert_eq!(events.len(), 2);
+
+        // Deliver
+        let proof = "ipfs://bafy-audit-report".to_string();
+        let events = self
+            .send(
+                instruction(
+                    "submit_delivery",
+                    (proof.clone(),),
+                    vec![
+                        AccountMeta::new(self.escrow, false),
+                        AccountMeta::new_readonly(self.agent, false),
+                        AccountMeta::new_readonly(self.owner.pubkey(), true),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        let escrow: layout::GhostProtectEscrow =
+            self.account(self.escrow, "GhostProtectEscrow").await;
+        assert_eq!(escrow.status, layout::ESCROW_ACTIVE);
+        assert_eq!(escrow.delivery_proof.as_deref(), Some(proof.as_str()));
+
+        let delivered: layout::DeliverySubmittedEvent = event(&events, "DeliverySubmittedEvent");
+        assert_eq!(delivered.escrow_id, ESCROW_ID);
+        assert_eq!(delivered.agent, self.agent);
+        assert_eq!(delivered.delivery_proof, proof);
+        assert_eq!(events.len(), 1);
+
+        // Approve and release
+        let events = self
+            .send(
+                instruction(
+                    "approve_delivery",
+                    (),
+                    vec![
+                        AccountMeta::new(self.escrow, false),
+                        AccountMeta::new_readonly(self.agent, false),
+                        AccountMeta::new(self.escrow_vault, false),
+                        AccountMeta::new(self.owner_usdc, false),
+                        AccountMeta::new(client, true),
+                        AccountMeta::new(client_reputation, false),
+                        AccountMeta::new(
+                            pda(&[b"agent_settlement_stats", self.agent.as_ref()]),
+                            false,
+                        ),
+                        // Not a trial escrow: no bond to release
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(protocol_config, false),
+                        AccountMeta::new_readonly(pda(&[b"staking_config"]), false),
+                        // No fee curve or exemptions; fees are off, so no fee
+                        // vault or referrer accounts
+                        AccountMeta::new_readonly(pda(&[b"escrow_fee_curve"]), false),
+                        AccountMeta::new_readonly(pda(&[b"fee_exemptions"]), false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new(
+                            pda(&[b"account_lock", self.escrow.as_ref(), b"escrow_release"]),
+                            false,
+                        ),
+                        AccountMeta::new_readonly(spl_token::ID, false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[&self.client.insecure_clone()],
+            )
+            .await;
+
+        let now = self.now().await;
+        let escrow: layout::GhostProtectEscrow =
+            self.account(self.escrow, "GhostProtectEscrow").await;
+        assert_eq!(escrow.status, layout::ESCROW_COMPLETED);
+        assert_eq!(escrow.completed_at, Some(now));
+        assert_eq!(self.token_balance(self.escrow_vault).await, 0);
+        assert_eq!(self.token_balance(self.owner_usdc).await, ESCROW_AMOUNT);
+
+        let reputation: layout::ClientReputation =
+            self.account(client_reputation, "ClientReputation").await;
+        assert_eq!(reputation.total_escrows, 1);
+        assert_eq!(reputation.completed_escrows, 1);
+
+        let updated: layout::ClientReputationUpdatedEvent =
+            event(&events, "ClientReputationUpdatedEvent");
+        assert_eq!(updated.client, client);
+        assert_eq!(updated.escrow_id, ESCROW_ID);
+        assert_eq!(updated.payment_reliability_bps, 10_000);
+
+        let completed: layout::EscrowCompletedEvent = event(&events, "EscrowCompletedEvent");
+        assert_eq!(completed.escrow_id, ESCROW_ID);
+        assert_eq!(completed.agent, self.agent);
+        assert_eq!(completed.amount, ESCROW_AMOUNT);
+        assert_eq!(events.len(), 2);
+
+        println!(
+            "    ✓ Escrow {} released {} USDC",
+            ESCROW_ID,
+            ESCROW_AMOUNT / USDC
+        );
+    }
+
+    async fn record_payai_payments(&mut self) {
+        println!("  📋 Recording PayAI payments...");
+
+        let metrics = pda(&[b"reputation_metrics", self.agent.as_ref()]);
+        let facilitator = self.facilitator.pubkey();
+        let user_rate_limit = pda(&[
+            b"user_rate_limit",
+            facilitator.as_ref(),
+            b"record_payai_payment",
+        ]);
+        let payments = [(ESCROW_AMOUNT, 850u64, true), (5 * USDC, 3_000, false)];
+
+        // The owner lets the facilitator record payments with a session key
+        let session_key = pda(&[b"session_key", self.agent.as_ref(), facilitator.as_ref()]);
+        let expires_at = self.now().await + DAY;
+        let events = self
+            .send(
+                instruction(
+                    "create_session_key",
+                    (facilitator, 1u8 << 1, expires_at, payments.len() as u32),
+                    vec![
+                        AccountMeta::new_readonly(self.agent, false),
+                        AccountMeta::new(session_key, false),
+                        AccountMeta::new(self.owner.pubkey(), true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+        assert_eq!(events.len(), 1);
+
+        for (i, (amount, response_time_ms, success)) in payments.into_iter().enumerate() {
+            let payment_signature = Signature::new_unique().to_string();
+            let events = self
+                .send(
+                    instruction(
+                        "record_payai_payment",
+                        (payment_signature.clone(), amount, response_time_ms, success),
+                        vec![
+                            AccountMeta::new(metrics, false),
+                            AccountMeta::new(self.agent, false),
+                            // No rolling windows
+                            AccountMeta::new_readonly(PROGRAM_ID, false),
+                            AccountMeta::new_readonly(pda(&[b"rate_limiter"]), false),
+                            AccountMeta::new(user_rate_limit, false),
+                            AccountMeta::new(facilitator, true),
+                            AccountMeta::new_readonly(sysvar::clock::ID, false),
+                            AccountMeta::new_readonly(system_program::ID, false),
+                            AccountMeta::new(session_key, false),
+                            // Not recording as the payment verifier
+                            AccountMeta::new_readonly(PROGRAM_ID, false),
+                        ],
+                    ),
+                    &[&self.facilitator.insecure_clone()],
+                )
+                .await;
+
+            let now = self.now().await;
+            let recorded: layout::ReputationPaymentRecordedEvent =
+                event(&events, "ReputationPaymentRecordedEvent");
+            assert_eq!(recorded.agent, self.agent);
+            assert_eq!(recorded.payment_signature, payment_signature);
+            assert_eq!(recorded.amount, amount);
+            assert_eq!(recorded.response_time_ms, response_time_ms);
+            assert_eq!(recorded.success, success);
+            assert_eq!(recorded.timestamp, now);
+            assert_eq!(events.len(), 1);
+
+            let agent: layout::Agent = self.account(self.agent, "Agent").await;
+            assert_eq!(
+                agent.reputation_score as u64,
+                recorded.new_reputation_score / 100
+            );
+
+            let limit: layout::UserRateLimit = self.account(user_rate_limit, "UserRateLimit").await;
+            assert_eq!(limit.user, facilitator);
+            assert_eq!(limit.operation, "record_payai_payment");
+            assert_eq!(limit.request_count, i as u16 + 1);
+        }
+
+        // Failed payments count against the agent but add no volume
+        let account: layout::ReputationMetrics = self.account(metrics, "ReputationMetrics").await;
+        assert_eq!(account.successful_payments, 1);
+        assert_eq!(account.failed_payments, 1);
+        assert_eq!(account.total_response_time, 850 + 3_000);
+        assert_eq!(account.response_time_count, 2);
+        assert_eq!(
+            account.payment_history_7d.iter().sum::<u64>(),
+            ESCROW_AMOUNT
+        );
+
+        let agent: layout::Agent = self.account(self.agent, "Agent").await;
+        assert_eq!(agent.x402_total_calls, 1);
+        assert_eq!(agent.x402_total_payments, ESCROW_AMOUNT);
+
+        println!("    ✓ Recorded {} payments", payments.len());
+    }
+
+    async fn issue_job_completion_credential(&mut self) {
+        println!("  📋 Issuing JobCompletion credential...");
+
+        // Protocol config (initialized at registration) carries the issuance
+        // tier gate (none by default)
+        let protocol_config = pda(&[b"protocol_config"]);
+
+        let issuer = self.issuer.pubkey();
+        let credential_type = pda(&[b"credential_type", b"JobCompletion"]);
+        let events = self
+            .send(
+                instruction(
+                    "create_credential_type",
+                    (
+                        "JobCompletion".to_string(),
+                        layout::KIND_JOB_COMPLETION,
+                        "https://example.com/schemas/job-completion.json".to_string(),
+                        "Escrow-backed job completion".to_string(),
+                    ),
+                    vec![
+                        AccountMeta::new(credential_type, false),
+                        // Schema registry entry for version 1
+                        AccountMeta::new(
+                            pda(&[
+                                b"credential_schema",
+                                credential_type.as_ref(),
+                                &1u16.to_le_bytes(),
+                            ]),
+                            false,
+                        ),
+                        // Built-in kind, no custom kind entry
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new(issuer, true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[&self.issuer.insecure_clone()],
+            )
+            .await;
+        assert!(events.is_empty());
+
+        let template = pda(&[
+            b"credential_template",
+            credential_type.as_ref(),
+            b"Audit Completed",
+        ]);
+        let events = self
+            .send(
+                instruction(
+                    "create_credential_template",
+                    (
+                        "Audit Completed".to_string(),
+                        "https://example.com/badges/audit.png".to_string(),
+                        None::<String>,
+                        0u64,
+                    ),
+                    vec![
+                        AccountMeta::new(template, false),
+                        AccountMeta::new_readonly(credential_type, false),
+                        AccountMeta::new(issuer, true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[&self.issuer.insecure_clone()],
+            )
+            .await;
+        assert!(events.is_empty());
+
+        let credential_id = "urn:uuid:e2e-job-1".to_string();
+        let subject_data_hash: [u8; 32] = Sha256::digest(b"escrow 1: audit delivered").into();
+        let credential = pda(&[
+            b"credential",
+            template.as_ref(),
+            self.agent.as_ref(),
+            credential_id.as_bytes(),
+        ]);
+        let status_list = pda(&[b"credential_status_list", credential_type.as_ref()]);
+        let subject_index = pda(&[b"subject_credential_index", self.agent.as_ref()]);
+        let subject_index_page = pda(&[
+            b"subject_credential_page",
+            self.agent.as_ref(),
+            &0u32.to_le_bytes(),
+        ]);
+        let issuer_rate_limit = pda(&[b"user_rate_limit", issuer.as_ref(), b"issue_credential"]);
+        let events = self
+            .send(
+                instruction(
+                    "issue_credential",
+                    (
+                        credential_id.clone(),
+                        subject_data_hash,
+                        "ipfs://bafy-job-1".to_string(),
+                        None::<i64>,
+                        Some(self.escrow),
+                    ),
+                    vec![
+                        AccountMeta::new(credential, false),
+                        AccountMeta::new(template, false),
+                        AccountMeta::new(credential_type, false),
+                        AccountMeta::new(status_list, false),
+                        AccountMeta::new_readonly(self.agent, false),
+                        // Never created, so the credential is issued Active
+                        AccountMeta::new_readonly(
+                            pda(&[b"holder_policy", self.agent.as_ref()]),
+                            false,
+                        ),
+                        AccountMeta::new(subject_index, false),
+                        AccountMeta::new(subject_index_page, false),
+                        // Not a fork, no royalty recipient
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        // JobCompletion is not reserved, no issuer registry
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        // No issuance fee, no fee payer
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(protocol_config, false),
+                        AccountMeta::new_readonly(pda(&[b"staking_config"]), false),
+                        // The issuer has no stake, and no tier is required
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        // ...nor a fee vault, and no exemption list was created
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(pda(&[b"fee_exemptions"]), false),
+                        // Issued by the template issuer itself, no delegation
+                        AccountMeta::new_readonly(PROGRAM_ID, false),
+                        AccountMeta::new_readonly(pda(&[b"rate_limiter"]), false),
+                        AccountMeta::new(issuer_rate_limit, false),
+                        // Neither the subject nor the issuer is denylisted
+                        AccountMeta::new_readonly(denylist_entry(&self.agent), false),
+                        AccountMeta::new_readonly(denylist_entry(&issuer), false),
+                        AccountMeta::new_readonly(denylist_entry(&issuer), false),
+                        AccountMeta::new(issuer, true),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                    ],
+                ),
+                &[&self.issuer.insecure_clone()],
+            )
+            .await;
+        // Royalties and fees are the only issuance events, and this template has neither
+        assert!(events.is_empty());
+
+        let now = self.now().await;
+        let issued: layout::Credential = self.account(credential, "Credential").await;
+        assert_eq!(issued.template, template);
+        assert_eq!(issued.subject, self.agent);
+        assert_eq!(issued.issuer, issuer);
+        assert_eq!(issued.credential_id, credential_id);
+        assert_eq!(issued.subject_data_hash, subject_data_hash);
+        assert_eq!(issued.subject_data_uri, "ipfs://bafy-job-1");
+        assert_eq!(issued.status, layout::CREDENTIAL_ACTIVE);
+        assert_eq!(issued.issued_at, now);
+        assert_eq!(issued.expires_at, None);
+        assert_eq!(issued.revoked_at, None);
+        assert_eq!(issued.cross_chain_status, layout::NOT_SYNCED);
+        assert_eq!(issued.crossmint_credential_id, None);
+        assert_eq!(issued.source_account, Some(self.escrow));
+
+        let kind: layout::CredentialType = self.account(credential_type, "CredentialType").await;
+        assert_eq!(kind.authority, issuer);
+        assert_eq!(kind.kind, layout::KIND_JOB_COMPLETION);
+        assert!(kind.is_active);
+        assert_eq!(kind.total_issued, 1);
+
+        let template: layout::CredentialTemplate =
+            self.account(template, "CredentialTemplate").await;
+        assert_eq!(template.credential_type, credential_type);
+        assert_eq!(template.issuer, issuer);
+        assert!(template.is_active);
+        assert_eq!(template.total_issued, 1);
+        assert_eq!(template.forked_from, None);
+        assert_eq!(template.royalty_lamports, 0);
+
+        println!("    ✓ Credential {credential_id} issued to the agent");
+    }
+
+    async fn decay_reputation_tags(&mut self) {
+        println!("  📋 Applying reputation tag decay...");
+
+        let metrics = pda(&[b"reputation_metrics", self.agent.as_ref()]);
+        let accounts = vec![
+            AccountMeta::new(metrics, false),
+            AccountMeta::new(self.agent, false),
+            AccountMeta::new_readonly(self.owner.pubkey(), true),
+            // Free-form tags, no registry
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+        ];
+
+        let tagged_at = self.now().await;
+        let events = self
+            .send(
+                instruction(
+                    "update_reputation_tags",
+                    (
+                        vec![layout::TagRef::Name("smart-contracts".to_string())],
+                        vec![layout::TagRef::Name("responsive".to_string())],
+                        Vec::<layout::TagRef>::new(),
+                        vec![layout::TagScore {
+                            tag_name: "smart-contracts".to_string(),
+                            confidence: 9_000,
+                            evidence_count: 1,
+                            last_updated: tagged_at,
+                        }],
+                    ),
+                    accounts.clone(),
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        let account: layout::ReputationMetrics = self.account(metrics, "ReputationMetrics").await;
+        assert_eq!(account.skill_tags, vec!["smart-contracts"]);
+        assert_eq!(account.behavior_tags, vec!["responsive"]);
+        assert_eq!(account.tag_scores.len(), 1);
+        assert_eq!(account.tag_scores[0].confidence, 9_000);
+        assert_eq!(account.tag_scores[0].last_updated, tagged_at);
+
+        let updated: layout::ReputationTagsUpdatedEvent =
+            event(&events, "ReputationTagsUpdatedEvent");
+        assert_eq!(updated.agent, self.agent);
+        assert_eq!(updated.total_tags, 2);
+        assert_eq!(updated.skill_tags_count, 1);
+        assert_eq!(updated.behavior_tags_count, 1);
+        assert_eq!(updated.compliance_tags_count, 0);
+        assert_eq!(updated.tag_scores_count, 1);
+        assert_eq!(events.len(), 1);
+
+        // Scored tags go stale after 90 days; every tag update sweeps them first
+        self.warp(91 * DAY).await;
+        let events = self
+            .send(
+                instruction(
+                    "update_reputation_tags",
+                    (
+                        Vec::<layout::TagRef>::new(),
+                        Vec::<layout::TagRef>::new(),
+                        Vec::<layout::TagRef>::new(),
+                        Vec::<layout::TagScore>::new(),
+                    ),
+                    accounts,
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        let now = self.now().await;
+        let account: layout::ReputationMetrics = self.account(metrics, "ReputationMetrics").await;
+        assert!(account.skill_tags.is_empty());
+        assert_eq!(account.behavior_tags, vec!["responsive"]);
+        assert!(account.tag_scores.is_empty());
+
+        let updated: layout::ReputationTagsUpdatedEvent =
+            event(&events, "ReputationTagsUpdatedEvent");
+        assert_eq!(updated.total_tags, 1);
+        assert_eq!(updated.skill_tags_count, 0);
+        assert_eq!(updated.behavior_tags_count, 1);
+        assert_eq!(updated.tag_scores_count, 0);
+        assert_eq!(updated.timestamp, now);
+        assert_eq!(events.len(), 1);
+
+        println!("    ✓ Stale scored tag removed after 91 days");
+    }
+
+    async fn unstake_ghost(&mut self) {
+        println!("  📋 Unstaking GHOST...");
+
+        // The 30-day lock ran out during the tag decay step
+        let staking = pda(&[b"staking", self.owner.pubkey().as_ref()]);
+        let staking_config = pda(&[b"staking_config"]);
+        let vault = pda(&[b"staking_vault", staking_config.as_ref()]);
+        let amount = 1_000 * GHOST;
+        let events = self
+            .send(
+                instruction(
+                    "unstake_ghost",
+                    amount,
+                    vec![
+                        AccountMeta::new(staking, false),
+                        AccountMeta::new(staking_config, false),
+                        AccountMeta::new(vault, false),
+                        AccountMeta::new(self.owner_ghost, false),
+                        AccountMeta::new(self.owner.pubkey(), true),
+                        AccountMeta::new_readonly(spl_token::ID, false),
+                    ],
+                ),
+                &[&self.owner.insecure_clone()],
+            )
+            .await;
+
+        // The staking config owns the vault and signs the withdrawal
+        let remaining = STAKE_AMOUNT - amount;
+        assert_eq!(self.token_balance(vault).await, remaining);
+        assert_eq!(
+            self.token_balance(self.owner_ghost).await,
+            10_000 * GHOST - remaining
+        );
+
+        let account: layout::StakingAccount = self.account(staking, "StakingAccount").await;
+        assert_eq!(account.amount_staked, remaining);
+        assert_eq!(account.tier, layout::TIER_BASIC);
+        assert!(!account.has_verified_badge);
+
+        let tier: layout::TierUpdatedEvent = event(&events, "TierUpdatedEvent");
+        assert_eq!(tier.agent, self.owner.pubkey());
+        assert_eq!(tier.old_tier, layout::TIER_VERIFIED);
+        assert_eq!(tier.new_tier, layout::TIER_BASIC);
+        assert_eq!(tier.total_staked, remaining);
+
+        let unstaked: layout::GhostUnstakedEvent = event(&events, "GhostUnstakedEvent");
+        assert_eq!(unstaked.agent, self.owner.pubkey());
+        assert_eq!(unstaked.amount, amount);
+        assert_eq!(unstaked.remaining_stake, remaining);
+        assert_eq!(events.len(), 2);
+
+        println!(
+            "    ✓ Unstaked {} GHOST (Basic tier, {} GHOST remaining)",
+            amount / GHOST,
+            remaining / GHOST
+        );
+    }
+
+    // =====================================================
+    // HELPERS
+    // =====================================================
+
+    /// Install the admin authority with the test payer as admin
+    ///
+    /// On a cluster the program's upgrade authority creates it at deploy;
+    /// solana-program-test loads the program without one.
+    async fn install_admin_authority(&mut self) -> Pubkey {
+        let (address, bump) = Pubkey::find_program_address(&[b"admin_authority"], &PROGRAM_ID);
+        let authority = layout::AdminAuthority {
+            admin: self.ctx.payer.pubkey(),
+            multisig: Pubkey::default(),
+            pending_admin: Pubkey::default(),
+            executable_at: 0,
+            updated_at: self.now().await,
+            bump,
+        };
+        let mut data = discriminator("account", "AdminAuthority").to_vec();
+        authority.serialize(&mut data).unwrap();
+
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let mut account =
+            AccountSharedData::new(rent.minimum_balance(data.len()), data.len(), &PROGRAM_ID);
+        account.set_data_from_slice(&data);
+        self.ctx.set_account(&address, &account);
+        address
+    }
+
+    /// Process `ix` (payer plus `signers`) and return its decoded event payloads
+    async fn send(&mut self, ix: Instruction, signers: &[&Keypair]) -> Vec<Vec<u8>> {
+        let name = instruction_name(&ix);
+        let blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+        let mut all_signers: Vec<&Keypair> = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        let outcome = self
+            .ctx
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await
+            .unwrap();
+        let logs = outcome.metadata.map(|m| m.log_messages).unwrap_or_default();
+        if let Err(err) = outcome.result {
+            panic!("{name} failed: {err:?}\n{}", logs.join("\n"));
+        }
+
+        logs.iter()
+            .filter_map(|line| line.strip_prefix("Program data: "))
+            .map(|data| {
+                base64::engine::general_purpose::STANDARD
+                    .decode(data)
+                    .expect("event data is base64")
+            })
+            .collect()
+    }
+
+    /// Fetch a program account and decode it after checking its discriminator
+    async fn account<T: AnchorDeserialize>(&mut self, address: Pubkey, name: &str) -> T {
+        let account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("{name} {address} does not exist"));
+        assert_eq!(account.owner, PROGRAM_ID, "{name} not owned by the program");
+        assert_eq!(
+            account.data[..8],
+            discriminator("account", name),
+            "{address} is not a {name}"
+        );
+        T::deserialize(&mut &account.data[8..]).unwrap()
+    }
+
+    async fn token_balance(&mut self, address: Pubkey) -> u64 {
+        let account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+
+    async fn now(&mut self) -> i64 {
+        let clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    /// Move the cluster clock forward by `seconds`
+    async fn warp(&mut self, seconds: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+    }
+}
+
+fn program_available() -> bool {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(DEPLOY_DIR)
+        .join("ghostspeak_marketplace.so")
+        .exists()
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &PROGRAM_ID).0
+}
+
+/// Denylist entry PDA for `subject`, which exists only while it is listed
+fn denylist_entry(subject: &Pubkey) -> Pubkey {
+    pda(&[b"denylist_entry", subject.as_ref()])
+}
+
+/// First 8 bytes of sha256("<namespace>:<name>")
+fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{namespace}:{name}").as_bytes());
+    hash[..8].try_into().unwrap()
+}
+
+fn instruction(name: &str, args: impl AnchorSerialize, accounts: Vec<AccountMeta>) -> Instruction {
+    let mut data = discriminator("global", name).to_vec();
+    args.serialize(&mut data).unwrap();
+    Instruction::new_with_bytes(PROGRAM_ID, &data, accounts)
+}
+
+/// Instruction name for failure messages, recovered from its discriminator
+fn instruction_name(ix: &Instruction) -> String {
+    const NAMES: &[&str] = &[
+        "approve_delivery",
+        "create_credential_template",
+        "create_credential_type",
+        "create_did_document",
+        "create_escrow",
+        "create_session_key",
+        "init_rate_limiter",
+        "initialize_protocol_config",
+        "initialize_reputation_metrics",
+        "initialize_staking_config",
+        "issue_credential",
+        "record_payai_payment",
+        "register_agent",
+        "stake_ghost",
+        "submit_delivery",
+        "unstake_ghost",
+        "update_reputation_tags",
+    ];
+    NAMES
+        .iter()
+        .find(|name| ix.data[..8] == discriminator("global", name))
+        .map_or_else(|| "instruction".to_string(), |name| name.to_string())
+}
+
+/// Decode the single event named `name` from a transaction's event payloads
+fn event<T: AnchorDeserialize>(events: &[Vec<u8>], name: &str) -> T {
+    let tag = discriminator("event", name);
+    let mut matching = events.iter().filter(|data| data[..8] == tag);
+    let data = matching
+        .next()
+        .unwrap_or_else(|| panic!("{name} not emitted"));
+    assert!(matching.next().is_none(), "{name} emitted more than once");
+    T::try_from_slice(&data[8..]).unwrap()
+}
+
+async fn fund(ctx: &mut ProgramTestContext, to: &Pubkey, lamports: u64) {
+    let ix = system_instruction::transfer(&ctx.payer.pubkey(), to, lamports);
+    process(ctx, &[ix], &[]).await;
+}
+
+async fn create_mint(ctx: &mut ProgramTestContext, decimals: u8) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &mint.pubkey(),
+            rent.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_mint(
+            &spl_token::ID,
+            &mint.pubkey(),
+            &payer,
+            None,
+            decimals,
+        )
+        .unwrap(),
+    ];
+    process(ctx, &ixs, &[&mint]).await;
+    mint.pubkey()
+}
+
+async fn create_token_account(
+    ctx: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Pubkey {
+    let account = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let ixs = [
+        system_instruction::create_account(
+            &ctx.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(spl_token::state::Account::LEN),
+            spl_token::state::Account::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_account3(&spl_token::ID, &account.pubkey(), mint, owner)
+            .unwrap(),
+    ];
+    process(ctx, &ixs, &[&account]).await;
+    account.pubkey()
+}
+
+async fn mint_to(ctx: &mut ProgramTestContext, mint: &Pubkey, to: &Pubkey, amount: u64) {
+    let ix =
+        spl_token::instruction::mint_to(&spl_token::ID, mint, to, &ctx.payer.pubkey(), &[], amount)
+            .unwrap();
+    process(ctx, &[ix], &[]).await;
+}
+
+async fn process(ctx: &mut ProgramTestContext, ixs: &[Instruction], signers: &[&Keypair]) {
+    let mut all_signers: Vec<&Keypair> = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+// =====================================================
+// ON-CHAIN LAYOUTS
+// =====================================================
+
+/// Borsh mirrors of program accounts, arguments and events
+///
+/// Account structs are prefixes: decoding stops after the last field listed,
+/// so trailing fields the flow does not assert are omitted. Unit enums are
+/// their variant index.
+#[allow(dead_code)]
+mod layout {
+    use super::*;
+
+    pub const TIER_NONE: u8 = 0;
+    pub const TIER_BASIC: u8 = 1;
+    pub const TIER_VERIFIED: u8 = 2;
+    pub const PRICING_FIXED: u8 = 0;
+    pub const ESCROW_ACTIVE: u8 = 0;
+    pub const ESCROW_COMPLETED: u8 = 1;
+    pub const KIND_JOB_COMPLETION: u8 = 2;
+    pub const CREDENTIAL_ACTIVE: u8 = 1;
+    pub const NOT_SYNCED: u8 = 0;
+    pub const ED25519_VERIFICATION_KEY_2020: u8 = 0;
+    pub const AUTHENTICATION: u8 = 0;
+    pub const ASSERTION_METHOD: u8 = 1;
+    pub const AI_AGENT_SERVICE: u8 = 0;
+
+    // ===== Accounts =====
+
+    #[derive(AnchorSerialize)]
+    pub struct AdminAuthority {
+        pub admin: Pubkey,
+        pub multisig: Pubkey,
+        pub pending_admin: Pubkey,
+        pub executable_at: i64,
+        pub updated_at: i64,
+        pub bump: u8,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct StakingAccount {
+        pub owner: Pubkey,
+        pub amount_staked: u64,
+        pub staked_at: i64,
+        pub lock_duration: i64,
+        pub unlock_at: i64,
+        pub reputation_boost_bps: u16,
+        pub has_verified_badge: bool,
+        pub has_premium_benefits: bool,
+        pub total_slashed: u64,
+        pub tier: u8,
+        pub api_calls_remaining: u32,
+        pub last_quota_reset: i64,
+        pub voting_power: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct Agent {
+        pub owner: Option<Pubkey>,
+        pub status: u8,
+        pub agent_id: String,
+        pub first_tx_signature: String,
+        pub first_seen_timestamp: i64,
+        pub discovery_source: String,
+        pub claimed_at: Option<i64>,
+        pub agent_type: u8,
+        pub name: String,
+        pub description: String,
+        pub capabilities: Vec<String>,
+        pub pricing_model: u8,
+        pub reputation_score: u32,
+        pub total_jobs_completed: u32,
+        pub total_earnings: u64,
+        pub is_active: bool,
+        pub created_at: i64,
+        pub updated_at: i64,
+        pub original_price: u64,
+        pub genome_hash: String,
+        pub is_replicable: bool,
+        pub replication_fee: u64,
+        pub service_endpoint: String,
+        pub is_verified: bool,
+        pub verification_timestamp: i64,
+        pub metadata_uri: String,
+        pub framework_origin: String,
+        pub supported_tokens: Vec<Pubkey>,
+        pub cnft_mint: Option<Pubkey>,
+        pub merkle_tree: Option<Pubkey>,
+        pub supports_a2a: bool,
+        pub transfer_hook: Option<Pubkey>,
+        pub parent_agent: Option<Pubkey>,
+        pub generation: u32,
+        pub x402_enabled: bool,
+        pub x402_payment_address: Pubkey,
+        pub x402_accepted_tokens: Vec<Pubkey>,
+        pub x402_price_per_call: u64,
+        pub x402_service_endpoint: String,
+        pub x402_total_payments: u64,
+        pub x402_total_calls: u64,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+    pub struct VerificationMethod {
+        pub id: String,
+        pub method_type: u8,
+        pub controller: String,
+        pub public_key_multibase: String,
+        pub relationships: Vec<u8>,
+        pub created_at: i64,
+        pub revoked: bool,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+    pub struct ServiceEndpoint {
+        pub id: String,
+        pub service_type: u8,
+        pub service_endpoint: String,
+        pub description: String,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct DidDocument {
+        pub did: String,
+        pub controller: Pubkey,
+        pub verification_methods: Vec<VerificationMethod>,
+        pub service_endpoints: Vec<ServiceEndpoint>,
+        pub context: Vec<String>,
+        pub also_known_as: Vec<String>,
+        pub created_at: i64,
+        pub updated_at: i64,
+        pub version: u32,
+        pub deactivated: bool,
+        pub deactivated_at: Option<i64>,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+    pub struct TagScore {
+        pub tag_name: String,
+        pub confidence: u16,
+        pub evidence_count: u32,
+        pub last_updated: i64,
+    }
+
+    #[derive(AnchorSerialize)]
+    pub enum TagRef {
+        Id(u16),
+        Name(String),
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct ReputationMetrics {
+        pub agent: Pubkey,
+        pub successful_payments: u64,
+        pub failed_payments: u64,
+        pub total_response_time: u64,
+        pub response_time_count: u64,
+        pub total_disputes: u32,
+        pub disputes_resolved: u32,
+        pub total_rating: u32,
+        pub total_ratings_count: u32,
+        pub payment_history_7d: [u64; 7],
+        pub created_at: i64,
+        pub updated_at: i64,
+        pub skill_tags: Vec<String>,
+        pub behavior_tags: Vec<String>,
+        pub compliance_tags: Vec<String>,
+        pub tag_scores: Vec<TagScore>,
+    }
+
+    impl ReputationMetrics {
+        pub fn tag_count(&self) -> usize {
+            self.skill_tags.len() + self.behavior_tags.len() + self.compliance_tags.len()
+        }
+    }
+
+    #[derive(AnchorSerialize)]
+    pub struct RateLimitConfig {
+        pub default_limit: u16,
+        pub window_duration: i64,
+        pub enabled: bool,
+        pub penalty_duration: i64,
+        pub burst_size: u16,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct UserRateLimit {
+        pub user: Pubkey,
+        pub operation: String,
+        pub request_timestamps: Vec<i64>,
+        pub window_start: i64,
+        pub request_count: u16,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct GhostProtectEscrow {
+        pub escrow_id: u64,
+        pub client: Pubkey,
+        pub agent: Pubkey,
+        pub amount: u64,
+        pub token_mint: Pubkey,
+        pub status: u8,
+        pub job_description: String,
+        pub delivery_proof: Option<String>,
+        pub deadline: i64,
+        pub created_at: i64,
+        pub completed_at: Option<i64>,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct ClientReputation {
+        pub client: Pubkey,
+        pub total_escrows: u32,
+        pub completed_escrows: u32,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct CredentialType {
+        pub authority: Pubkey,
+        pub name: String,
+        pub kind: u8,
+        pub schema_uri: String,
+        pub description: String,
+        pub is_active: bool,
+        pub total_issued: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct CredentialTemplate {
+        pub credential_type: Pubkey,
+        pub name: String,
+        pub image_uri: String,
+        pub issuer: Pubkey,
+        pub is_active: bool,
+        pub total_issued: u64,
+        pub created_at: i64,
+        pub crossmint_template_id: Option<String>,
+        pub forked_from: Option<Pubkey>,
+        pub original_author: Option<Pubkey>,
+        pub royalty_lamports: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct Credential {
+        pub template: Pubkey,
+        pub subject: Pubkey,
+        pub issuer: Pubkey,
+        pub credential_id: String,
+        pub subject_data_hash: [u8; 32],
+        pub subject_data_uri: String,
+        pub status: u8,
+        pub signature: [u8; 64],
+        pub issued_at: i64,
+        pub expires_at: Option<i64>,
+        pub revoked_at: Option<i64>,
+        pub cross_chain_status: u8,
+        pub crossmint_credential_id: Option<String>,
+        pub source_account: Option<Pubkey>,
+    }
+
+    // ===== Events =====
+
+    #[derive(AnchorDeserialize)]
+    pub struct GhostStakedEvent {
+        pub agent: Pubkey,
+        pub amount: u64,
+        pub unlock_at: i64,
+        pub reputation_boost_bps: u16,
+        pub tier: u8,
+        pub daily_api_calls: u32,
+        pub voting_power: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct GhostUnstakedEvent {
+        pub agent: Pubkey,
+        pub amount: u64,
+        pub remaining_stake: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct TierUpdatedEvent {
+        pub agent: Pubkey,
+        pub old_tier: u8,
+        pub new_tier: u8,
+        pub total_staked: u64,
+        pub daily_api_calls: u32,
+        pub voting_power: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct AgentRegisteredEvent {
+        pub agent: Pubkey,
+        pub owner: Pubkey,
+        pub name: String,
+        pub timestamp: i64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct ReputationMetricsInitializedEvent {
+        pub agent: Pubkey,
+        pub timestamp: i64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct EscrowCreatedEvent {
+        pub escrow_id: u64,
+        pub client: Pubkey,
+        pub agent: Pubkey,
+        pub amount: u64,
+        pub deadline: i64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct DeliverySubmittedEvent {
+        pub escrow_id: u64,
+        pub agent: Pubkey,
+        pub delivery_proof: String,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct EscrowCompletedEvent {
+        pub escrow_id: u64,
+        pub agent: Pubkey,
+        pub amount: u64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct ClientReputationUpdatedEvent {
+        pub client: Pubkey,
+        pub escrow_id: u64,
+        pub total_escrows: u32,
+        pub payment_reliability_bps: u16,
+        pub dispute_rate_bps: u16,
+        pub cancellation_rate_bps: u16,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct ReputationPaymentRecordedEvent {
+        pub agent: Pubkey,
+        pub payment_signature: String,
+        pub amount: u64,
+        pub response_time_ms: u64,
+        pub success: bool,
+        pub new_reputation_score: u64,
+        pub timestamp: i64,
+    }
+
+    #[derive(AnchorDeserialize)]
+    pub struct ReputationTagsUpdatedEvent {
+        pub agent: Pubkey,
+        pub total_tags: u32,
+        pub skill_tags_count: u32,
+        pub behavior_tags_count: u32,
+        pub compliance_tags_count: u32,
+        pub tag_scores_count: u32,
+        pub timestamp: i64,
+    }
+}
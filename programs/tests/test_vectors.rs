@@ -0,0 +1,262 @@
+/*!
+ * Cross-SDK Test Vector Tests
+ *
+ * Checks the program's seeds, layouts and hashing against the canonical
+ * vectors in test-vectors/vectors.json (generated by `cargo xtask gen-vectors`).
+ * The TypeScript SDK runs the same vectors, so a mismatch on either side
+ * means the Rust and TypeScript encodings have drifted.
+ *
+ * ElGamal vectors are only checked by the TypeScript SDK, which owns the
+ * client-side encryption.
+ */
+
+use anchor_lang::prelude::*;
+use serde_json::Value;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use std::str::FromStr;
+
+const VECTORS: &str = include_str!("../../test-vectors/vectors.json");
+
+#[tokio::test]
+async fn test_vectors_comprehensive() {
+    println!("🧪 Starting cross-SDK test vector checks...");
+
+    let vectors: Value = serde_json::from_str(VECTORS).expect("vectors.json is valid JSON");
+    assert_eq!(vectors["version"], 1, "unexpected vectors version");
+
+    test_pda_vectors(&vectors);
+    test_discriminator_vectors(&vectors);
+    test_account_vectors(&vectors);
+    test_event_vectors(&vectors);
+    test_credential_hash_vectors(&vectors);
+
+    println!("✅ All test vectors match!");
+}
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("valid hex"))
+        .collect()
+}
+
+fn pubkey(value: &Value) -> Pubkey {
+    Pubkey::from_str(value.as_str().expect("pubkey string")).expect("valid pubkey")
+}
+
+fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&Sha256::digest(format!("{namespace}:{name}").as_bytes())[..8]);
+    out
+}
+
+fn test_pda_vectors(vectors: &Value) {
+    println!("  📋 Testing PDA derivations...");
+
+    let program_id = pubkey(&vectors["program_id"]);
+    let pdas = vectors["pdas"].as_array().expect("pdas array");
+    assert!(!pdas.is_empty());
+
+    for pda in pdas {
+        let seeds: Vec<Vec<u8>> = pda["seeds"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| unhex(s.as_str().unwrap()))
+            .collect();
+        let seed_refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+
+        let (address, bump) = Pubkey::find_program_address(&seed_refs, &program_id);
+        assert_eq!(
+            address,
+            pubkey(&pda["address"]),
+            "PDA mismatch: {}",
+            pda["name"]
+        );
+        assert_eq!(
+            bump as u64,
+            pda["bump"].as_u64().unwrap(),
+            "bump mismatch: {}",
+            pda["name"]
+        );
+    }
+
+    // Seed prefixes used by the program
+    let prefix = |name: &str| {
+        let pda = pdas.iter().find(|p| p["name"] == name).unwrap();
+        unhex(pda["seeds"][0].as_str().unwrap())
+    };
+    assert_eq!(prefix("agent"), b"agent");
+    assert_eq!(prefix("staking"), b"staking");
+    assert_eq!(prefix("reputation_metrics"), b"reputation_metrics");
+    assert_eq!(prefix("did_document"), b"did_document");
+    assert_eq!(prefix("config_history_head"), b"config_history_head");
+    assert_eq!(prefix("config_history_entry"), b"config_history");
+
+    println!("    ✓ {} PDAs verified", pdas.len());
+}
+
+fn test_discriminator_vectors(vectors: &Value) {
+    println!("  📋 Testing discriminators...");
+
+    let entries = vectors["discriminators"].as_array().unwrap();
+    for entry in entries {
+        let namespace = match entry["kind"].as_str().unwrap() {
+            "account" => "account",
+            "event" => "event",
+            "instruction" => "global",
+            other => panic!("unknown discriminator kind: {other}"),
+        };
+        let expected = discriminator(namespace, entry["name"].as_str().unwrap());
+        assert_eq!(
+            expected.to_vec(),
+            unhex(entry["discriminator"].as_str().unwrap()),
+            "discriminator mismatch: {}",
+            entry["name"]
+        );
+    }
+
+    println!("    ✓ {} discriminators verified", entries.len());
+}
+
+// Mirrors of the program types (the program is a cdylib and cannot be linked)
+
+#[allow(dead_code)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+enum ConfigKind {
+    ProtocolFees,
+    ReputationParams,
+    StakingConfig,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+struct ConfigHistoryEntry {
+    index: u64,
+    kind: ConfigKind,
+    config_account: Pubkey,
+    old_value_hash: [u8; 32],
+    new_value_hash: [u8; 32],
+    proposer: Pubkey,
+    slot: u64,
+    timestamp: i64,
+    prev_entry: Pubkey,
+    bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+struct AgentRegisteredEvent {
+    agent: Pubkey,
+    owner: Pubkey,
+    name: String,
+    timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+struct SourceReputationUpdateFailedEvent {
+    agent: Pubkey,
+    source_name: String,
+    index: u8,
+    error_code: u64,
+    timestamp: i64,
+}
+
+fn find<'a>(list: &'a Value, name: &str) -> &'a Value {
+    list.as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["name"] == name)
+        .unwrap_or_else(|| panic!("missing vector: {name}"))
+}
+
+fn encoded<T: AnchorSerialize>(namespace: &str, name: &str, value: &T) -> Vec<u8> {
+    let mut data = discriminator(namespace, name).to_vec();
+    data.extend(value.try_to_vec().unwrap());
+    data
+}
+
+fn test_account_vectors(vectors: &Value) {
+    println!("  📋 Testing account serializations...");
+
+    let v = find(&vectors["accounts"], "ConfigHistoryEntry");
+    let f = &v["fields"];
+    let entry = ConfigHistoryEntry {
+        index: f["index"].as_u64().unwrap(),
+        kind: ConfigKind::ReputationParams,
+        config_account: pubkey(&f["config_account"]),
+        old_value_hash: unhex(f["old_value_hash"].as_str().unwrap())
+            .try_into()
+            .unwrap(),
+        new_value_hash: unhex(f["new_value_hash"].as_str().unwrap())
+            .try_into()
+            .unwrap(),
+        proposer: pubkey(&f["proposer"]),
+        slot: f["slot"].as_u64().unwrap(),
+        timestamp: f["timestamp"].as_i64().unwrap(),
+        prev_entry: pubkey(&f["prev_entry"]),
+        bump: f["bump"].as_u64().unwrap() as u8,
+    };
+    assert_eq!(f["kind"], 1);
+    let data = unhex(v["data"].as_str().unwrap());
+    assert_eq!(encoded("account", "ConfigHistoryEntry", &entry), data);
+    // Fixed-size account: data matches LEN exactly
+    assert_eq!(data.len(), 8 + 8 + 1 + 32 + 32 + 32 + 32 + 8 + 8 + 32 + 1);
+    assert_eq!(
+        ConfigHistoryEntry::try_from_slice(&data[8..]).unwrap(),
+        entry
+    );
+
+    println!("    ✓ Account layouts match");
+}
+
+fn test_event_vectors(vectors: &Value) {
+    println!("  📋 Testing event encodings...");
+
+    let v = find(&vectors["events"], "AgentRegisteredEvent");
+    let f = &v["fields"];
+    let event = AgentRegisteredEvent {
+        agent: pubkey(&f["agent"]),
+        owner: pubkey(&f["owner"]),
+        name: f["name"].as_str().unwrap().to_string(),
+        timestamp: f["timestamp"].as_i64().unwrap(),
+    };
+    assert_eq!(
+        encoded("event", "AgentRegisteredEvent", &event),
+        unhex(v["data"].as_str().unwrap())
+    );
+
+    let v = find(&vectors["events"], "SourceReputationUpdateFailedEvent");
+    let f = &v["fields"];
+    let event = SourceReputationUpdateFailedEvent {
+        agent: pubkey(&f["agent"]),
+        source_name: f["source_name"].as_str().unwrap().to_string(),
+        index: f["index"].as_u64().unwrap() as u8,
+        error_code: f["error_code"].as_u64().unwrap(),
+        timestamp: f["timestamp"].as_i64().unwrap(),
+    };
+    assert_eq!(
+        encoded("event", "SourceReputationUpdateFailedEvent", &event),
+        unhex(v["data"].as_str().unwrap())
+    );
+
+    println!("    ✓ Event encodings match");
+}
+
+fn test_credential_hash_vectors(vectors: &Value) {
+    println!("  📋 Testing credential hashes...");
+
+    for v in vectors["credential_hashes"].as_array().unwrap() {
+        // Same message layout as issue_credential
+        let mut message = Vec::new();
+        message.extend_from_slice(v["credential_id"].as_str().unwrap().as_bytes());
+        message.extend_from_slice(&pubkey(&v["subject"]).to_bytes());
+        message.extend_from_slice(&unhex(v["subject_data_hash"].as_str().unwrap()));
+        message.extend_from_slice(&v["issued_at"].as_i64().unwrap().to_le_bytes());
+        assert_eq!(message, unhex(v["message"].as_str().unwrap()));
+
+        let digest: [u8; 32] = Keccak256::digest(&message).into();
+        assert_eq!(digest.to_vec(), unhex(v["message_hash"].as_str().unwrap()));
+    }
+
+    println!("    ✓ Credential hashes match");
+}
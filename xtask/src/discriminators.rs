@@ -0,0 +1,229 @@
+//! Discriminator registry
+//!
+//! Scans the program sources for instructions (`pub fn` inside `#[program]`),
+//! accounts (`#[account]`) and events (`#[event]`), and records each Anchor
+//! discriminator. Only modules reachable from `lib.rs` through `mod`
+//! declarations are scanned, so files that are not compiled are ignored.
+//!
+//! The committed registry is checked by `programs/tests/discriminator_registry.rs`;
+//! any rename, removal or collision fails there until the registry is
+//! regenerated on purpose.
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Bump when the file layout changes in a way consumers must notice
+const REGISTRY_VERSION: u32 = 1;
+
+/// Renamed instructions kept routable under their old name
+///
+/// Each alias must exist as its own entry point that forwards to the target.
+const DEPRECATED_ALIASES: &[(&str, &str)] = &[("record_x402_payment", "record_payai_payment")];
+
+pub fn generate(program_src: &Path) -> Result<Value, String> {
+    let sources = collect_sources(program_src)?;
+
+    let lib = strip_comments(&read(&program_src.join("lib.rs"))?);
+    let instructions = program_instructions(&lib);
+    let mut accounts = Vec::new();
+    let mut events = Vec::new();
+    for source in &sources {
+        let stripped = strip_comments(&read(source)?);
+        accounts.extend(attributed_structs(&stripped, "#[account]"));
+        events.extend(attributed_structs(&stripped, "#[event]"));
+    }
+
+    let instructions = entries("global", instructions)?;
+    let accounts = entries("account", accounts)?;
+    let events = entries("event", events)?;
+
+    let mut aliases = Vec::new();
+    for (alias, target) in DEPRECATED_ALIASES {
+        for name in [alias, target] {
+            if !instructions.contains_key(*name) {
+                return Err(format!(
+                    "deprecated alias refers to unknown instruction: {name}"
+                ));
+            }
+        }
+        aliases.push(json!({
+            "name": alias,
+            "target": target,
+            "discriminator": instructions[*alias],
+        }));
+    }
+
+    Ok(json!({
+        "version": REGISTRY_VERSION,
+        "instructions": to_list(&instructions),
+        "accounts": to_list(&accounts),
+        "events": to_list(&events),
+        "aliases": aliases,
+    }))
+}
+
+// =====================================================
+// HELPERS
+// =====================================================
+
+pub(crate) fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))
+}
+
+fn discriminator(namespace: &str, name: &str) -> String {
+    Sha256::digest(format!("{namespace}:{name}").as_bytes())[..8]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Name -> discriminator, failing on duplicate names or colliding discriminators
+fn entries(namespace: &str, names: Vec<String>) -> Result<BTreeMap<String, String>, String> {
+    let mut out = BTreeMap::new();
+    let mut seen: BTreeMap<String, String> = BTreeMap::new();
+    for name in names {
+        let disc = discriminator(namespace, &name);
+        if let Some(other) = seen.insert(disc.clone(), name.clone()) {
+            if other == name {
+                return Err(format!("duplicate {namespace} name: {name}"));
+            }
+            return Err(format!(
+                "{namespace} discriminator collision: {other} and {name}"
+            ));
+        }
+        out.insert(name, disc);
+    }
+    Ok(out)
+}
+
+fn to_list(entries: &BTreeMap<String, String>) -> Vec<Value> {
+    entries
+        .iter()
+        .map(|(name, disc)| json!({ "name": name, "discriminator": disc }))
+        .collect()
+}
+
+// =====================================================
+// SOURCE SCANNING
+// =====================================================
+
+/// All files reachable from lib.rs through `mod` declarations
+pub(crate) fn collect_sources(program_src: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut out = Vec::new();
+    let mut pending = vec![(program_src.join("lib.rs"), program_src.to_path_buf())];
+
+    while let Some((file, dir)) = pending.pop() {
+        let stripped = strip_comments(&read(&file)?);
+        for name in module_declarations(&stripped) {
+            let flat = dir.join(format!("{name}.rs"));
+            if flat.exists() {
+                pending.push((flat, dir.join(&name)));
+            } else {
+                let nested = dir.join(&name).join("mod.rs");
+                if !nested.exists() {
+                    return Err(format!("module {name} not found in {}", dir.display()));
+                }
+                pending.push((nested, dir.join(&name)));
+            }
+        }
+        out.push(file);
+    }
+
+    out.sort();
+    Ok(out)
+}
+
+/// `mod x;` declarations (inline `mod x { ... }` blocks are part of the file)
+fn module_declarations(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let rest = line
+                .strip_prefix("pub mod ")
+                .or_else(|| line.strip_prefix("pub(crate) mod "))
+                .or_else(|| line.strip_prefix("mod "))?;
+            rest.strip_suffix(';').map(str::to_string)
+        })
+        .collect()
+}
+
+/// Top-level `pub fn` names inside the `#[program]` module
+fn program_instructions(lib: &str) -> Vec<String> {
+    lib.lines()
+        .skip_while(|line| line.trim() != "#[program]")
+        .filter_map(|line| line.strip_prefix("    pub fn "))
+        .map(identifier)
+        .collect()
+}
+
+/// Names of structs annotated with `attribute` at column 0
+fn attributed_structs(source: &str, attribute: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        if line != attribute {
+            continue;
+        }
+        if let Some(name) = lines.by_ref().find_map(|l| l.strip_prefix("pub struct ")) {
+            out.push(identifier(name));
+        }
+    }
+    out
+}
+
+pub(crate) fn identifier(s: &str) -> String {
+    s.chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect()
+}
+
+/// Remove `//` and `/* */` comments, keeping string literals and line breaks
+pub(crate) fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
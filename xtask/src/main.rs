@@ -0,0 +1,127 @@
+/*!
+ * GhostSpeak developer tasks
+ *
+ * Usage:
+ *   cargo xtask gen-vectors [--out <path>] [--check]
+ *   cargo xtask gen-discriminators [--out <path>] [--check]
+ *   cargo xtask gen-permissions [--out <path>] [--check]
+ *
+ * `gen-vectors` writes the canonical cross-SDK test vectors consumed by the
+ * Rust tests in `programs/tests` and the TypeScript SDK unit tests.
+ * `gen-discriminators` writes the registry of instruction, account and event
+ * discriminators scanned from `programs/src`. `gen-permissions` writes the
+ * permission matrix (signers, mutated accounts and constraints) of every
+ * instruction. With `--check` the file is not
+ * written; the command fails if the committed file differs from freshly
+ * generated output.
+ */
+
+mod discriminators;
+mod permissions;
+mod vectors;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Default output locations, relative to the workspace root
+const DEFAULT_VECTORS_PATH: &str = "test-vectors/vectors.json";
+const DEFAULT_DISCRIMINATORS_PATH: &str = "test-vectors/discriminators.json";
+const DEFAULT_PERMISSIONS_PATH: &str = "test-vectors/permissions.json";
+
+const USAGE: &str = "usage: cargo xtask <gen-vectors|gen-discriminators|gen-permissions> [--out <path>] [--check]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("gen-vectors") => gen_vectors(&args[1..]),
+        Some("gen-discriminators") => gen_discriminators(&args[1..]),
+        Some("gen-permissions") => gen_permissions(&args[1..]),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn gen_vectors(args: &[String]) -> Result<(), String> {
+    let generated = vectors::generate()?;
+    write_or_check(args, DEFAULT_VECTORS_PATH, "gen-vectors", &generated)
+}
+
+fn gen_discriminators(args: &[String]) -> Result<(), String> {
+    let generated = discriminators::generate(&workspace_root().join("programs/src"))?;
+    write_or_check(
+        args,
+        DEFAULT_DISCRIMINATORS_PATH,
+        "gen-discriminators",
+        &generated,
+    )
+}
+
+fn gen_permissions(args: &[String]) -> Result<(), String> {
+    let generated = permissions::generate(&workspace_root().join("programs/src"))?;
+    write_or_check(args, DEFAULT_PERMISSIONS_PATH, "gen-permissions", &generated)
+}
+
+/// Render `generated` to the output path, or compare against it with `--check`
+fn write_or_check(
+    args: &[String],
+    default_path: &str,
+    command: &str,
+    generated: &serde_json::Value,
+) -> Result<(), String> {
+    let mut out = workspace_root().join(default_path);
+    let mut check = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--out" => {
+                let path = iter.next().ok_or("--out requires a path")?;
+                out = PathBuf::from(path);
+            }
+            "--check" => check = true,
+            other => return Err(format!("unknown argument: {other}")),
+        }
+    }
+
+    let mut rendered = serde_json::to_string_pretty(generated).map_err(|e| e.to_string())?;
+    rendered.push('\n');
+
+    if check {
+        let existing = std::fs::read_to_string(&out)
+            .map_err(|e| format!("failed to read {}: {e}", out.display()))?;
+        if existing != rendered {
+            return Err(format!(
+                "{} is out of date; run `cargo xtask {command}`",
+                out.display()
+            ));
+        }
+        println!("{} is up to date", out.display());
+        return Ok(());
+    }
+
+    if let Some(parent) = out.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(&out, rendered)
+        .map_err(|e| format!("failed to write {}: {e}", out.display()))?;
+    println!("wrote {}", out.display());
+    Ok(())
+}
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside the workspace")
+        .to_path_buf()
+}
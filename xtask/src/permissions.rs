@@ -0,0 +1,324 @@
+//! Permission matrix
+//!
+//! Walks every instruction in `#[program]`, resolves its `Context<...>`
+//! accounts struct and records, per account, whether it must sign, whether
+//! the instruction may mutate it, and the Anchor constraints guarding it
+//! (`has_one`, `constraint`, `address`, `seeds`, `close`).
+//!
+//! A signer is listed as bound when some constraint on the context names it,
+//! and an instruction is `signer_constrained` when at least one of its
+//! signers is bound. Checks done inside handlers (multisig remaining
+//! accounts, `require!` on stored authorities) are not visible here, so an
+//! unconstrained instruction is either permissionless or authorized in code.
+//!
+//! The committed matrix is checked by `programs/tests/permission_matrix.rs`.
+
+use crate::discriminators::{collect_sources, identifier, read, strip_comments};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Bump when the file layout changes in a way consumers must notice
+const MATRIX_VERSION: u32 = 1;
+
+pub fn generate(program_src: &Path) -> Result<Value, String> {
+    let mut contexts = BTreeMap::new();
+    for source in collect_sources(program_src)? {
+        for context in accounts_structs(&strip_comments(&read(&source)?)) {
+            if let Some(other) = contexts.insert(context.name.clone(), context) {
+                return Err(format!("duplicate accounts struct: {}", other.name));
+            }
+        }
+    }
+
+    let lib = strip_comments(&read(&program_src.join("lib.rs"))?);
+    let mut instructions = Vec::new();
+    for (name, context_name) in program_contexts(&lib)? {
+        let context = contexts
+            .get(&context_name)
+            .ok_or_else(|| format!("{name}: accounts struct {context_name} not found"))?;
+        instructions.push(instruction_entry(&name, context));
+    }
+    instructions.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    Ok(json!({
+        "version": MATRIX_VERSION,
+        "instructions": instructions,
+    }))
+}
+
+// =====================================================
+// MATRIX
+// =====================================================
+
+struct AccountsStruct {
+    name: String,
+    fields: Vec<Field>,
+}
+
+struct Field {
+    name: String,
+    ty: String,
+    items: Vec<String>,
+}
+
+impl Field {
+    fn is_signer(&self) -> bool {
+        self.ty.starts_with("Signer<")
+            || self.ty.starts_with("Option<Signer<")
+            || self.has_item("signer")
+    }
+
+    fn is_mutable(&self) -> bool {
+        ["mut", "init", "init_if_needed"]
+            .iter()
+            .any(|item| self.has_item(item))
+            || self.rule("close").is_some()
+            || self.rule("realloc").is_some()
+    }
+
+    fn has_item(&self, item: &str) -> bool {
+        self.items.iter().any(|i| i == item)
+    }
+
+    /// Value of a `key = value` item, without its `@ Error` suffix
+    fn rule(&self, key: &str) -> Option<String> {
+        self.items.iter().find_map(|item| {
+            let (k, v) = item.split_once('=')?;
+            (k.trim() == key).then(|| without_error(v))
+        })
+    }
+
+    fn rules(&self, key: &str) -> Vec<String> {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                let (k, v) = item.split_once('=')?;
+                (k.trim() == key).then(|| without_error(v))
+            })
+            .collect()
+    }
+
+    /// Constraint rules as `kind: expression`
+    fn constraints(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        for key in ["has_one", "constraint", "address", "seeds", "close"] {
+            for value in self.rules(key) {
+                out.push(format!("{key}: {value}"));
+            }
+        }
+        out
+    }
+}
+
+fn instruction_entry(name: &str, context: &AccountsStruct) -> Value {
+    let rules: Vec<(String, String)> = context
+        .fields
+        .iter()
+        .flat_map(|f| f.constraints().into_iter().map(|c| (f.name.clone(), c)))
+        .collect();
+
+    let mut signers = Vec::new();
+    let mut mutable = Vec::new();
+    let mut accounts = Vec::new();
+    let mut any_bound = false;
+
+    for field in &context.fields {
+        let signer = field.is_signer();
+        let is_mut = field.is_mutable();
+        if signer {
+            let bound_by: Vec<String> = rules
+                .iter()
+                .filter(|(account, rule)| *account == field.name || mentions(rule, &field.name))
+                .map(|(account, rule)| format!("{account}.{rule}"))
+                .collect();
+            any_bound |= !bound_by.is_empty();
+            signers.push(json!({ "name": field.name, "bound_by": bound_by }));
+        }
+        if is_mut {
+            mutable.push(Value::from(field.name.clone()));
+        }
+        accounts.push(json!({
+            "name": field.name,
+            "type": field.ty,
+            "signer": signer,
+            "mutable": is_mut,
+            "constraints": field.constraints(),
+        }));
+    }
+
+    json!({
+        "name": name,
+        "context": context.name,
+        "signer_constrained": any_bound,
+        "signers": signers,
+        "mutable": mutable,
+        "accounts": accounts,
+    })
+}
+
+/// Whether `rule` refers to the account `name` as a whole identifier
+fn mentions(rule: &str, name: &str) -> bool {
+    rule.match_indices(name).any(|(at, _)| {
+        let before = rule[..at].chars().next_back();
+        let after = rule[at + name.len()..].chars().next();
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+        !is_ident(before) && !is_ident(after) && before != Some('.')
+    })
+}
+
+fn without_error(value: &str) -> String {
+    let value = top_level_split(value, '@', false).swap_remove(0);
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// =====================================================
+// SOURCE SCANNING
+// =====================================================
+
+/// (instruction, accounts struct) pairs for every `pub fn` in `#[program]`
+fn program_contexts(lib: &str) -> Result<Vec<(String, String)>, String> {
+    let body: Vec<&str> = lib
+        .lines()
+        .skip_while(|line| line.trim() != "#[program]")
+        .collect();
+
+    let mut out = Vec::new();
+    for (i, line) in body.iter().enumerate() {
+        let Some(rest) = line.strip_prefix("    pub fn ") else {
+            continue;
+        };
+        let name = identifier(rest);
+        let signature: String = body[i..]
+            .iter()
+            .take_while(|l| !l.trim_end().ends_with('{'))
+            .chain(body[i..].iter().find(|l| l.trim_end().ends_with('{')))
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let context = context_type(&signature)
+            .ok_or_else(|| format!("{name}: no Context<...> parameter"))?;
+        out.push((name, context));
+    }
+    Ok(out)
+}
+
+/// Accounts struct named by the last generic argument of `Context<...>`
+fn context_type(signature: &str) -> Option<String> {
+    let start = signature.find("Context<")? + "Context<".len();
+    let mut depth = 1;
+    let mut end = start;
+    for (i, c) in signature[start..].char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            end = start + i;
+            break;
+        }
+    }
+    let last = top_level_split(&signature[start..end], ',', true).pop()?;
+    Some(identifier(last.trim()))
+}
+
+/// `#[derive(Accounts)]` structs with their fields and `#[account(...)]` items
+fn accounts_structs(source: &str) -> Vec<AccountsStruct> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        if lines[i].trim() != "#[derive(Accounts)]" {
+            i += 1;
+            continue;
+        }
+        let Some(offset) = lines[i..]
+            .iter()
+            .position(|l| l.trim_start().starts_with("pub struct "))
+        else {
+            break;
+        };
+        i += offset;
+        let name = identifier(lines[i].trim_start().trim_start_matches("pub struct "));
+
+        let mut fields = Vec::new();
+        let mut attribute = String::new();
+        let mut depth = 0i32;
+        i += 1;
+        while i < lines.len() && lines[i] != "}" {
+            let line = lines[i].trim();
+            if depth > 0 || line.starts_with("#[account") {
+                attribute.push_str(line);
+                attribute.push(' ');
+                depth += bracket_delta(line);
+            } else if let Some(rest) = line.strip_prefix("pub ") {
+                if let Some((field, ty)) = rest.split_once(':') {
+                    fields.push(Field {
+                        name: identifier(field.trim()),
+                        ty: ty.trim().trim_end_matches(',').to_string(),
+                        items: account_items(&attribute),
+                    });
+                }
+                attribute.clear();
+            }
+            i += 1;
+        }
+        out.push(AccountsStruct { name, fields });
+    }
+    out
+}
+
+fn bracket_delta(line: &str) -> i32 {
+    line.chars()
+        .map(|c| match c {
+            '(' | '[' | '{' => 1,
+            ')' | ']' | '}' => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Comma-separated items inside `#[account(...)]`
+fn account_items(attribute: &str) -> Vec<String> {
+    let Some(start) = attribute.find("#[account(") else {
+        return Vec::new();
+    };
+    let inner = &attribute[start + "#[account(".len()..];
+    let inner = inner.trim_end();
+    let inner = inner.strip_suffix(")]").unwrap_or(inner);
+    top_level_split(inner, ',', false)
+        .into_iter()
+        .map(|item| item.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Split on `sep` outside of brackets and string literals
+///
+/// Angle brackets only nest when `generics` is set, since constraint
+/// expressions use `<` and `>` as comparisons.
+fn top_level_split(s: &str, sep: char, generics: bool) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    for c in s.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            '<' if generics => depth += 1,
+            '>' if generics => depth -= 1,
+            _ => {}
+        }
+        if c == sep && depth == 0 && !in_string {
+            out.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    out.push(current);
+    out
+}
@@ -0,0 +1,455 @@
+//! Canonical cross-SDK test vectors
+//!
+//! Every input is fixed, so the output is byte-for-byte reproducible. Account
+//! and event layouts are mirrored here (the program is a cdylib and cannot be
+//! linked) and must be kept in sync with `programs/src`.
+
+use borsh::BorshSerialize;
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use solana_pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Bump when the file layout changes in a way consumers must notice
+const VECTORS_VERSION: u32 = 1;
+
+const PROGRAM_ID: &str = "4wHjA2a5YC4twZb4NQpwZpixo5FgxxzuJUrCG7UnF9pB";
+
+/// Domain separators used by the TypeScript ElGamal module
+const ELGAMAL_H_DOMAIN: &[u8] = b"GHOSTSPEAK_ELGAMAL_H_GENERATOR";
+const ELGAMAL_KEY_DOMAIN: &[u8] = b"GHOSTSPEAK_ELGAMAL_KEY_DERIVATION";
+
+pub fn generate() -> Result<Value, String> {
+    let program_id = Pubkey::from_str(PROGRAM_ID).map_err(|e| e.to_string())?;
+
+    Ok(json!({
+        "version": VECTORS_VERSION,
+        "program_id": PROGRAM_ID,
+        "pdas": pda_vectors(&program_id),
+        "discriminators": discriminator_vectors(),
+        "accounts": account_vectors(),
+        "events": event_vectors(),
+        "credential_hashes": credential_hash_vectors(),
+        "elgamal": elgamal_vectors()?,
+    }))
+}
+
+// =====================================================
+// HELPERS
+// =====================================================
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Deterministic public key for a vector label
+fn fixed_pubkey(label: &str) -> Pubkey {
+    Pubkey::new_from_array(sha256(format!("ghostspeak-vector:{label}").as_bytes()))
+}
+
+/// Anchor discriminator: first 8 bytes of sha256("<namespace>:<name>")
+fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = sha256(format!("{namespace}:{name}").as_bytes());
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash[..8]);
+    out
+}
+
+fn encode_with_discriminator<T: BorshSerialize>(namespace: &str, name: &str, value: &T) -> Vec<u8> {
+    let mut data = discriminator(namespace, name).to_vec();
+    value
+        .serialize(&mut data)
+        .expect("serializing into a Vec cannot fail");
+    data
+}
+
+// =====================================================
+// PDA DERIVATIONS
+// =====================================================
+
+fn pda_vectors(program_id: &Pubkey) -> Vec<Value> {
+    let owner = fixed_pubkey("owner");
+    let subject = fixed_pubkey("subject");
+    let agent_id = "agent-001";
+
+    let (agent, _) =
+        Pubkey::find_program_address(&[b"agent", owner.as_ref(), agent_id.as_bytes()], program_id);
+    let (staking_config, _) = Pubkey::find_program_address(&[b"staking_config"], program_id);
+    let (credential_type, _) =
+        Pubkey::find_program_address(&[b"credential_type", b"AgentIdentity"], program_id);
+    let (credential_template, _) = Pubkey::find_program_address(
+        &[
+            b"credential_template",
+            credential_type.as_ref(),
+            b"Verified Agent",
+        ],
+        program_id,
+    );
+
+    let cases: Vec<(&str, Vec<Vec<u8>>)> = vec![
+        (
+            "agent",
+            vec![
+                b"agent".to_vec(),
+                owner.to_bytes().to_vec(),
+                agent_id.as_bytes().to_vec(),
+            ],
+        ),
+        (
+            "staking",
+            vec![b"staking".to_vec(), owner.to_bytes().to_vec()],
+        ),
+        ("staking_config", vec![b"staking_config".to_vec()]),
+        (
+            "staking_vault",
+            vec![
+                b"staking_vault".to_vec(),
+                staking_config.to_bytes().to_vec(),
+            ],
+        ),
+        (
+            "reputation_metrics",
+            vec![b"reputation_metrics".to_vec(), agent.to_bytes().to_vec()],
+        ),
+        (
+            "did_document",
+            vec![b"did_document".to_vec(), owner.to_bytes().to_vec()],
+        ),
+        ("protocol_config", vec![b"protocol_config".to_vec()]),
+        ("config_history_head", vec![b"config_history_head".to_vec()]),
+        (
+            "config_history_entry",
+            vec![b"config_history".to_vec(), 0u64.to_le_bytes().to_vec()],
+        ),
+        (
+            "reputation_source_registry",
+            vec![b"reputation_source_registry".to_vec()],
+        ),
+        (
+            "credential_type",
+            vec![b"credential_type".to_vec(), b"AgentIdentity".to_vec()],
+        ),
+        (
+            "credential_template",
+            vec![
+                b"credential_template".to_vec(),
+                credential_type.to_bytes().to_vec(),
+                b"Verified Agent".to_vec(),
+            ],
+        ),
+        (
+            "credential",
+            vec![
+                b"credential".to_vec(),
+                credential_template.to_bytes().to_vec(),
+                subject.to_bytes().to_vec(),
+                b"cred-0001".to_vec(),
+            ],
+        ),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, seeds)| {
+            let seed_refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+            let (address, bump) = Pubkey::find_program_address(&seed_refs, program_id);
+            json!({
+                "name": name,
+                "seeds": seeds.iter().map(|s| hex(s)).collect::<Vec<_>>(),
+                "address": address.to_string(),
+                "bump": bump,
+            })
+        })
+        .collect()
+}
+
+// =====================================================
+// DISCRIMINATORS
+// =====================================================
+
+fn discriminator_vectors() -> Vec<Value> {
+    let accounts = [
+        "Agent",
+        "ReputationMetrics",
+        "DidDocument",
+        "StakingAccount",
+        "StakingConfig",
+        "ProtocolConfig",
+        "ConfigHistoryHead",
+        "ConfigHistoryEntry",
+        "ReputationSourceRegistry",
+        "Credential",
+        "GhostProtectEscrow",
+        "Multisig",
+    ];
+    let events = [
+        "AgentRegisteredEvent",
+        "ReputationMetricsInitializedEvent",
+        "SourceReputationUpdatedEvent",
+        "SourceReputationUpdateFailedEvent",
+        "SourceReputationBatchProcessedEvent",
+        "ConfigHistoryRecordedEvent",
+        "GhostStakedEvent",
+    ];
+    let instructions = [
+        "register_agent",
+        "onboard_agent",
+        "initialize_reputation_metrics",
+        "update_source_reputation",
+        "update_source_reputation_batch",
+        "create_did_document",
+        "stake_ghost",
+    ];
+
+    let mut out = Vec::new();
+    for (namespace, kind, names) in [
+        ("account", "account", &accounts[..]),
+        ("event", "event", &events[..]),
+        ("global", "instruction", &instructions[..]),
+    ] {
+        for name in names {
+            out.push(json!({
+                "kind": kind,
+                "name": name,
+                "discriminator": hex(&discriminator(namespace, name)),
+            }));
+        }
+    }
+    out
+}
+
+// =====================================================
+// ACCOUNT SERIALIZATIONS
+// =====================================================
+
+/// Mirror of state::config_history::ConfigHistoryEntry
+#[derive(BorshSerialize)]
+struct ConfigHistoryEntry {
+    index: u64,
+    kind: u8,
+    config_account: [u8; 32],
+    old_value_hash: [u8; 32],
+    new_value_hash: [u8; 32],
+    proposer: [u8; 32],
+    slot: u64,
+    timestamp: i64,
+    prev_entry: [u8; 32],
+    bump: u8,
+}
+
+fn account_vectors() -> Vec<Value> {
+    let entry = ConfigHistoryEntry {
+        index: 3,
+        kind: 1, // ConfigKind::ReputationParams
+        config_account: fixed_pubkey("protocol_config").to_bytes(),
+        old_value_hash: keccak256(b"old"),
+        new_value_hash: keccak256(b"new"),
+        proposer: fixed_pubkey("authority").to_bytes(),
+        slot: 250_000_000,
+        timestamp: 1_760_000_000,
+        prev_entry: fixed_pubkey("prev_entry").to_bytes(),
+        bump: 254,
+    };
+
+    vec![
+        json!({
+            "name": "ConfigHistoryEntry",
+            "fields": {
+                "index": entry.index,
+                "kind": entry.kind,
+                "config_account": Pubkey::new_from_array(entry.config_account).to_string(),
+                "old_value_hash": hex(&entry.old_value_hash),
+                "new_value_hash": hex(&entry.new_value_hash),
+                "proposer": Pubkey::new_from_array(entry.proposer).to_string(),
+                "slot": entry.slot,
+                "timestamp": entry.timestamp,
+                "prev_entry": Pubkey::new_from_array(entry.prev_entry).to_string(),
+                "bump": entry.bump,
+            },
+            "data": hex(&encode_with_discriminator("account", "ConfigHistoryEntry", &entry)),
+        }),
+    ]
+}
+
+// =====================================================
+// EVENT ENCODINGS
+// =====================================================
+
+/// Mirror of AgentRegisteredEvent
+#[derive(BorshSerialize)]
+struct AgentRegisteredEvent {
+    agent: [u8; 32],
+    owner: [u8; 32],
+    name: String,
+    timestamp: i64,
+}
+
+/// Mirror of SourceReputationUpdateFailedEvent
+#[derive(BorshSerialize)]
+struct SourceReputationUpdateFailedEvent {
+    agent: [u8; 32],
+    source_name: String,
+    index: u8,
+    error_code: u64,
+    timestamp: i64,
+}
+
+fn event_vectors() -> Vec<Value> {
+    let registered = AgentRegisteredEvent {
+        agent: fixed_pubkey("agent").to_bytes(),
+        owner: fixed_pubkey("owner").to_bytes(),
+        name: "Ghost Agent".to_string(),
+        timestamp: 1_760_000_000,
+    };
+    let failed = SourceReputationUpdateFailedEvent {
+        agent: fixed_pubkey("agent").to_bytes(),
+        source_name: "github".to_string(),
+        index: 2,
+        error_code: 6000 + 2404,
+        timestamp: 1_760_000_000,
+    };
+
+    vec![
+        json!({
+            "name": "AgentRegisteredEvent",
+            "fields": {
+                "agent": Pubkey::new_from_array(registered.agent).to_string(),
+                "owner": Pubkey::new_from_array(registered.owner).to_string(),
+                "name": registered.name,
+                "timestamp": registered.timestamp,
+            },
+            "data": hex(&encode_with_discriminator("event", "AgentRegisteredEvent", &registered)),
+        }),
+        json!({
+            "name": "SourceReputationUpdateFailedEvent",
+            "fields": {
+                "agent": Pubkey::new_from_array(failed.agent).to_string(),
+                "source_name": failed.source_name,
+                "index": failed.index,
+                "error_code": failed.error_code,
+                "timestamp": failed.timestamp,
+            },
+            "data": hex(&encode_with_discriminator(
+                "event",
+                "SourceReputationUpdateFailedEvent",
+                &failed
+            )),
+        }),
+    ]
+}
+
+// =====================================================
+// CREDENTIAL HASHES
+// =====================================================
+
+/// keccak256(credential_id || subject || subject_data_hash || issued_at_le),
+/// as computed by `issue_credential`
+fn credential_hash_vectors() -> Vec<Value> {
+    let cases = [
+        (
+            "urn:uuid:00000000-0000-4000-8000-000000000001",
+            "subject",
+            b"{}".as_slice(),
+            1_760_000_000i64,
+        ),
+        (
+            "urn:uuid:00000000-0000-4000-8000-000000000002",
+            "subject-2",
+            br#"{"name":"Ghost Agent","tier":"gold"}"#.as_slice(),
+            0,
+        ),
+    ];
+
+    cases
+        .iter()
+        .map(|(credential_id, subject_label, subject_data, issued_at)| {
+            let subject = fixed_pubkey(subject_label);
+            let subject_data_hash = sha256(subject_data);
+
+            let mut message = Vec::new();
+            message.extend_from_slice(credential_id.as_bytes());
+            message.extend_from_slice(subject.as_ref());
+            message.extend_from_slice(&subject_data_hash);
+            message.extend_from_slice(&issued_at.to_le_bytes());
+
+            json!({
+                "credential_id": credential_id,
+                "subject": subject.to_string(),
+                "subject_data_hash": hex(&subject_data_hash),
+                "issued_at": issued_at,
+                "message": hex(&message),
+                "message_hash": hex(&keccak256(&message)),
+            })
+        })
+        .collect()
+}
+
+// =====================================================
+// ELGAMAL CIPHERTEXTS
+// =====================================================
+
+/// Secondary generator H, matching the SDK's nothing-up-my-sleeve construction
+fn elgamal_h() -> Result<EdwardsPoint, String> {
+    let mut input = ELGAMAL_H_DOMAIN.to_vec();
+    input.extend_from_slice(ED25519_BASEPOINT_POINT.compress().as_bytes());
+    CompressedEdwardsY(sha256(&input))
+        .decompress()
+        .ok_or_else(|| "ElGamal H generator is not a curve point".to_string())
+}
+
+/// Scalars are little-endian byte strings reduced mod l, as in the SDK
+fn scalar(bytes: [u8; 32]) -> Scalar {
+    Scalar::from_bytes_mod_order(bytes)
+}
+
+fn elgamal_vectors() -> Result<Vec<Value>, String> {
+    let h = elgamal_h()?;
+    let g = ED25519_BASEPOINT_POINT;
+
+    let cases: [(&str, u64, &str); 3] = [
+        ("alice", 1, "randomness-1"),
+        ("alice", 1_000_000, "randomness-2"),
+        ("bob", u64::MAX, "randomness-3"),
+    ];
+
+    cases
+        .iter()
+        .map(|(seed_label, value, randomness_label)| {
+            let seed = sha256(format!("ghostspeak-vector:elgamal-seed:{seed_label}").as_bytes());
+            let mut key_input = ELGAMAL_KEY_DOMAIN.to_vec();
+            key_input.extend_from_slice(&seed);
+            let secret_key = sha256(&key_input);
+            let public = scalar(secret_key) * g;
+
+            let randomness = sha256(format!("ghostspeak-vector:{randomness_label}").as_bytes());
+            // The SDK encodes the value big-endian before the little-endian scalar read
+            let mut value_bytes = [0u8; 32];
+            value_bytes[24..].copy_from_slice(&value.to_be_bytes());
+
+            let commitment = scalar(value_bytes) * h + scalar(randomness) * g;
+            let handle = scalar(randomness) * public;
+
+            Ok(json!({
+                "seed": hex(&seed),
+                "secret_key": hex(&secret_key),
+                "public_key": hex(public.compress().as_bytes()),
+                "value": value.to_string(),
+                "randomness": hex(&randomness),
+                "commitment": hex(commitment.compress().as_bytes()),
+                "handle": hex(handle.compress().as_bytes()),
+            }))
+        })
+        .collect()
+}